@@ -763,15 +763,36 @@ impl Context {
                 .newBufferWithLength_options(size, options);
         }
         let buffer = buffer.expect("Metal Buffer allocation failiure");
+        buf.size = size;
         buf.allocation = Some(RealizedBufferInternal {
             mapping: None,
             mtl: buffer,
+            gpu_writes_pending: false,
         });
         Ok(())
     }
 }
 
 impl Context {
+    /// Waits for any GPU commands that write to the storage of the buffer `name` to complete, so that its contents may be
+    /// accessed by the CPU
+    pub(crate) fn wait_for_gpu_writes(&mut self, name: ObjectName<Buffer>) {
+        let Some(alloc) = &mut self.gl_state.buffer_list.get_mut(name).allocation else {
+            return;
+        };
+        if alloc.gpu_writes_pending {
+            gl_debug!("waiting for pending GPU writes to {name:?}");
+            self.platform_state.finish();
+            alloc.gpu_writes_pending = false;
+        }
+    }
+    /// Records that GPU commands writing to the storage of the buffer `name` have been encoded, so that later CPU accesses wait
+    /// for them (see [`Context::wait_for_gpu_writes`])
+    pub(crate) fn mark_gpu_writes_pending(&mut self, name: ObjectName<Buffer>) {
+        if let Some(alloc) = &mut self.gl_state.buffer_list.get_mut(name).allocation {
+            alloc.gpu_writes_pending = true;
+        }
+    }
     #[inline]
     pub(crate) fn get_buffer_binding_mut<I: MaybeIndex>(
         &mut self,
@@ -865,6 +886,9 @@ pub(crate) struct Buffer {
 pub(crate) struct RealizedBufferInternal {
     pub(crate) mapping: Option<MappingInfo>,
     pub(crate) mtl: ProtoObjRef<dyn MTLBuffer>,
+    /// Whether commands that write to this buffer on the GPU (e.g. asynchronous pixel readbacks) may still be in flight,
+    /// meaning the context must wait for them before the CPU accesses the buffer's contents
+    pub(crate) gpu_writes_pending: bool,
 }
 impl Buffer {
     // fn get_best_storage_mode_for_access_hint(access: BufferAccess, usage_hint: BufferUsage) -> MTLStorageMode {
@@ -2,6 +2,7 @@ use crate::{
     context::{Context, platform::Dirty, state::Capabilities},
     dispatch::gl_types::GLuint,
     enums::EnableCap,
+    util::run_if_changed,
 };
 
 /// ### Parameters
@@ -263,6 +264,10 @@ impl Context {
     fn set_unindexed_cap_internal(&mut self, cap: EnableCap, state: bool) {
         // TODO: track dirty caps in platform state, platform is better informed about what exactly a cap state change
         // needs to be lowered to
+        if cap == EnableCap::ScissorTest {
+            run_if_changed!(self.gl_state.scissor_test;= state => self.update_encoder());
+            return;
+        }
         let bit = match cap {
             EnableCap::LineSmooth => todo!(),
             EnableCap::PolygonSmooth => todo!(),
@@ -274,7 +279,7 @@ impl Context {
             EnableCap::StencilTest => todo!(),
             EnableCap::Dither => todo!(),
             EnableCap::Blend => todo!(),
-            EnableCap::ScissorTest => todo!(),
+            EnableCap::ScissorTest => unreachable!(),
             EnableCap::Texture1D => todo!(),
             EnableCap::Texture2D => todo!(),
             EnableCap::ColorLogicOp => todo!(),
@@ -295,7 +300,7 @@ impl Context {
             EnableCap::ClipDistance6 => todo!(),
             EnableCap::ClipDistance7 => todo!(),
             EnableCap::RasterizerDiscard => todo!(),
-            EnableCap::FramebufferSrgb => todo!(),
+            EnableCap::FramebufferSrgb => (Dirty::empty(), Capabilities::FRAMEBUFFER_SRGB),
             EnableCap::TextureRectangle => todo!(),
             EnableCap::PrimitiveRestart => todo!(),
             EnableCap::ProgramPointSize => (Dirty::empty(), Capabilities::PROGRAM_POINT_SIZE),
@@ -320,6 +325,8 @@ impl Context {
     fn set_indexed_cap_internal(&mut self, cap: EnableCap, state: bool, index: u32) {
         match cap {
             EnableCap::Blend => todo!(),
+            // OxideGL only has a single viewport
+            EnableCap::ScissorTest if index == 0 => self.set_unindexed_cap_internal(cap, state),
             EnableCap::ScissorTest => todo!(),
             _ => {
                 panic!("tried to set unindexed capabiltiy with glenable/disablei")
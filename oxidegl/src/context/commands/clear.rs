@@ -1,10 +1,25 @@
+use objc2_metal::{MTLColorWriteMask, MTLTexture};
+use objc2_quartz_core::CAMetalDrawable;
+
 use crate::{
-    context::Context,
-    dispatch::gl_types::{GLdouble, GLfloat, GLint},
-    enums::ClearBufferMask,
-    util::run_if_changed,
+    context::{
+        Context,
+        debug::{gl_trace, gl_warn},
+        error::{GlFallible, gl_assert},
+        framebuffer::{
+            AttachmentStorage, ClearScope, ClearTarget, ClearValue, Framebuffer,
+            MAX_COLOR_ATTACHMENTS,
+        },
+        gl_object::ObjectName,
+        platform::Dirty,
+        state::Capabilities,
+    },
+    dispatch::gl_types::{GLdouble, GLfloat, GLint, GLuint},
+    enums::{Buffer, ClearBufferMask},
 };
 
+use super::framebuffer::default_framebuffer_storage;
+
 impl Context {
     /// ### Parameters
     /// `mask`
@@ -57,8 +72,30 @@ impl Context {
     /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_COLOR_CLEAR_VALUE`](crate::enums::GL_COLOR_CLEAR_VALUE)
     ///
     /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_STENCIL_CLEAR_VALUE`](crate::enums::GL_STENCIL_CLEAR_VALUE)
-    pub fn oxidegl_clear(&mut self, mask: ClearBufferMask) {
-        run_if_changed!(self.gl_state.clear_values.mask;= mask => self.new_encoder());
+    pub fn oxidegl_clear(&mut self, mask: ClearBufferMask) -> GlFallible {
+        let values = self.gl_state.clear_values;
+        let draw_buffers = match self.gl_state.draw_framebuffer_binding {
+            Some(fb) => self.gl_state.framebufer_list.get(fb).draw_buffers.modes,
+            None => self.gl_state.default_draw_buffers.modes,
+        };
+        let mut clears = Vec::new();
+        if mask.contains(ClearBufferMask::COLOR_BUFFER_BIT) {
+            clears.extend(
+                (0..MAX_COLOR_ATTACHMENTS)
+                    .filter(|&idx| draw_buffers[idx as usize].is_some())
+                    .map(|idx| (ClearTarget::Color(idx), ClearValue::Float(values.color))),
+            );
+        }
+        if mask.contains(ClearBufferMask::DEPTH_BUFFER_BIT) {
+            clears.push((ClearTarget::Depth, ClearValue::Depth(values.depth)));
+        }
+        if mask.contains(ClearBufferMask::STENCIL_BUFFER_BIT) {
+            clears.push((
+                ClearTarget::Stencil,
+                ClearValue::Stencil(values.stencil.cast_signed()),
+            ));
+        }
+        self.clear_framebuffer(self.gl_state.draw_framebuffer_binding, &clears)
     }
 
     /// ### Parameters
@@ -94,7 +131,7 @@ impl Context {
         blue: GLfloat,
         alpha: GLfloat,
     ) {
-        run_if_changed!(self.gl_state.clear_values.color;= [red, green, blue, alpha] => self.new_encoder());
+        self.gl_state.clear_values.color = [red, green, blue, alpha];
     }
     /// ### Parameters
     /// `s`
@@ -111,12 +148,8 @@ impl Context {
     /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_STENCIL_CLEAR_VALUE`](crate::enums::GL_STENCIL_CLEAR_VALUE)
     ///
     /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_STENCIL_BITS`](crate::enums::GL_STENCIL_BITS)
-    #[expect(
-        clippy::cast_sign_loss,
-        reason = "we want a bitcast anyways, the numeric value doesnt matter all that much"
-    )]
     pub fn oxidegl_clear_stencil(&mut self, s: GLint) {
-        run_if_changed!(self.gl_state.clear_values.stencil;= s as u32 => self.new_encoder());
+        self.gl_state.clear_values.stencil = s.cast_unsigned();
     }
 }
 
@@ -141,17 +174,472 @@ impl Context {
 ///
 /// ### Associated Gets
 /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_CLEAR_VALUE`](crate::enums::GL_DEPTH_CLEAR_VALUE)
-#[expect(
-    clippy::float_cmp,
-    reason = "if someone sets NaN as the depth buffer clear value they have bigger problems"
-)]
 impl Context {
     // Metal depth buffer is 32 bits so we might as well just truncate here instead of storing an f64
     #[allow(clippy::cast_possible_truncation)]
     pub fn oxidegl_clear_depth(&mut self, depth: GLdouble) {
-        run_if_changed!(self.gl_state.clear_values.depth;= depth as f32 => self.new_encoder());
+        self.gl_state.clear_values.depth = depth as f32;
     }
     pub fn oxidegl_clear_depthf(&mut self, d: GLfloat) {
-        run_if_changed!(self.gl_state.clear_values.depth;= d => self.new_encoder());
+        self.gl_state.clear_values.depth = d;
+    }
+}
+
+/// ### Parameters
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glClearNamedFramebuffer***](crate::context::Context::oxidegl_clear_named_framebuffer*).
+///
+/// `buffer`
+///
+/// > Specify the buffer to clear.
+///
+/// `drawbuffer`
+///
+/// > Specify a particular draw buffer to clear.
+///
+/// `value`
+///
+/// > A pointer to the value or values to clear the buffer to.
+///
+/// `depth`
+///
+/// > The value to clear the depth buffer to.
+///
+/// `stencil`
+///
+/// > The value to clear the stencil buffer to.
+///
+/// ### Description
+/// These commands clear a specified buffer of a framebuffer to specified value(s).
+/// For [**glClearBuffer***](crate::context::Context::oxidegl_clear_buffer*),
+/// the framebuffer is the currently bound draw framebuffer object. For [**glClearNamedFramebuffer***](crate::context::Context::oxidegl_clear_named_framebuffer*),
+/// `framebuffer` is zero, indicating the default draw framebuffer, or the
+/// name of a framebuffer object.
+///
+/// `buffer` and `drawbuffer` identify the buffer to clear.
+///
+/// If `buffer` is [`GL_COLOR`](crate::enums::GL_COLOR), a particular draw
+/// buffer [`GL_DRAW_BUFFER`](crate::enums::GL_DRAW_BUFFER) `i` is specified
+/// by passing `i` as `drawbuffer`, and `value` points to a four-element vector
+/// specifying the R, G, B and A color to clear that draw buffer to. If the
+/// value of [`GL_DRAW_BUFFER`](crate::enums::GL_DRAW_BUFFER) `i` is [`GL_NONE`](crate::enums::GL_NONE),
+/// the command has no effect. Otherwise, the value of [`GL_DRAW_BUFFER`](crate::enums::GL_DRAW_BUFFER)
+/// `i` identifies one or more color buffers, each of which is cleared to the
+/// same value. Clamping and type conversion for fixed-point color buffers
+/// are performed in the same fashion as for [**glClearColor**](crate::context::Context::oxidegl_clear_color).
+/// The [***fv**](crate::context::Context::oxide*fv), [***iv**](crate::context::Context::oxide*iv)
+/// and [***uiv**](crate::context::Context::oxide*uiv) forms of these commands
+/// should be used to clear fixed- and floating-point, signed integer, and
+/// unsigned integer color buffers respectively.
+///
+/// If `buffer` is [`GL_DEPTH`](crate::enums::GL_DEPTH), `drawbuffer` must
+/// be zero, and `value` points to a single value to clear the depth buffer
+/// to. Clamping and type conversion for fixed-point depth buffers are performed
+/// in the same fashion as for [**glClearDepth**](crate::context::Context::oxidegl_clear_depth).
+/// Only the [***fv**](crate::context::Context::oxide*fv) forms of these commands
+/// should be used to clear depth buffers; other forms do not accept a `buffer`
+/// of [`GL_DEPTH`](crate::enums::GL_DEPTH).
+///
+/// If `buffer` is [`GL_STENCIL`](crate::enums::GL_STENCIL), `drawbuffer` must
+/// be zero, and `value` points to a single value to clear the stencil buffer
+/// to. Masking is performed in the same fashion as for [**glClearStencil**](crate::context::Context::oxidegl_clear_stencil).
+/// Only the [***iv**](crate::context::Context::oxide*iv) forms of these commands
+/// should be used to clear stencil buffers; be used to clear stencil buffers;
+/// other forms do not accept a `buffer` of [`GL_STENCIL`](crate::enums::GL_STENCIL).
+///
+/// [**glClearBufferfi**](crate::context::Context::oxidegl_clear_bufferfi)
+/// and [**glClearNamedFramebufferfi**](crate::context::Context::oxidegl_clear_named_framebufferfi)
+/// are used to clear the depth and stencil buffers simultaneously. `buffer`
+/// must be [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL) and `drawbuffer`
+/// must be zero. `depth` and `stencil` are the values to clear the depth and
+/// stencil buffers to, respectively. Clamping and type conversion of `depth`
+/// for fixed-point depth buffers are performed in the same fashion as for
+/// [**glClearDepth**](crate::context::Context::oxidegl_clear_depth). Masking
+/// of `stencil` for stencil buffers is performed in the same fashion as for
+/// [**glClearStencil**](crate::context::Context::oxidegl_clear_stencil). These
+/// commands are equivalent to clearing the depth and stencil buffers separately,
+/// but may be faster when a buffer of internal format [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL)
+/// is being cleared. The same per-fragment and masking operations defined
+/// for [**glClear**](crate::context::Context::oxidegl_clear) are applied.
+///
+/// The result of these commands is undefined if no conversion between the
+/// type of the specified `value` and the type of the buffer being cleared
+/// is defined (for example, if [**glClearBufferiv**](crate::context::Context::oxidegl_clear_bufferiv)
+/// is called for a fixed- or floating-point buffer, or if [**glClearBufferfv**](crate::context::Context::oxidegl_clear_bufferfv)
+/// is called for a signed or unsigned integer buffer). This is not an error.
+impl Context {
+    pub(crate) unsafe fn oxidegl_clear_bufferiv(
+        &mut self,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLint,
+    ) -> GlFallible {
+        let fb = self.gl_state.draw_framebuffer_binding;
+        // Safety: Caller ensures validity
+        unsafe { self.clear_bufferiv_internal(fb, buffer, drawbuffer, value) }
+    }
+    pub(crate) unsafe fn oxidegl_clear_bufferuiv(
+        &mut self,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLuint,
+    ) -> GlFallible {
+        let fb = self.gl_state.draw_framebuffer_binding;
+        // Safety: Caller ensures validity
+        unsafe { self.clear_bufferuiv_internal(fb, buffer, drawbuffer, value) }
+    }
+    pub(crate) unsafe fn oxidegl_clear_bufferfv(
+        &mut self,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        let fb = self.gl_state.draw_framebuffer_binding;
+        // Safety: Caller ensures validity
+        unsafe { self.clear_bufferfv_internal(fb, buffer, drawbuffer, value) }
+    }
+    pub(crate) fn oxidegl_clear_bufferfi(
+        &mut self,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        depth: GLfloat,
+        stencil: GLint,
+    ) -> GlFallible {
+        let fb = self.gl_state.draw_framebuffer_binding;
+        self.clear_bufferfi_internal(fb, buffer, drawbuffer, depth, stencil)
+    }
+    pub(crate) unsafe fn oxidegl_clear_named_framebufferiv(
+        &mut self,
+        framebuffer: GLuint,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLint,
+    ) -> GlFallible {
+        let fb = self.clear_framebuffer_by_name(framebuffer)?;
+        // Safety: Caller ensures validity
+        unsafe { self.clear_bufferiv_internal(fb, buffer, drawbuffer, value) }
+    }
+    pub(crate) unsafe fn oxidegl_clear_named_framebufferuiv(
+        &mut self,
+        framebuffer: GLuint,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLuint,
+    ) -> GlFallible {
+        let fb = self.clear_framebuffer_by_name(framebuffer)?;
+        // Safety: Caller ensures validity
+        unsafe { self.clear_bufferuiv_internal(fb, buffer, drawbuffer, value) }
+    }
+    pub(crate) unsafe fn oxidegl_clear_named_framebufferfv(
+        &mut self,
+        framebuffer: GLuint,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        let fb = self.clear_framebuffer_by_name(framebuffer)?;
+        // Safety: Caller ensures validity
+        unsafe { self.clear_bufferfv_internal(fb, buffer, drawbuffer, value) }
+    }
+    pub(crate) fn oxidegl_clear_named_framebufferfi(
+        &mut self,
+        framebuffer: GLuint,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        depth: GLfloat,
+        stencil: GLint,
+    ) -> GlFallible {
+        let fb = self.clear_framebuffer_by_name(framebuffer)?;
+        self.clear_bufferfi_internal(fb, buffer, drawbuffer, depth, stencil)
+    }
+}
+
+/// Returns the buffer cleared by a glClearBuffer* command with the given `buffer` (which is not `GL_DEPTH_STENCIL`) and `drawbuffer`
+fn clear_target(buffer: Buffer, drawbuffer: GLint) -> GlFallible<ClearTarget> {
+    if buffer == Buffer::Color {
+        gl_assert!(
+            u32::try_from(drawbuffer).is_ok_and(|idx| idx < MAX_COLOR_ATTACHMENTS),
+            InvalidValue,
+            "{drawbuffer} is not a valid draw buffer index"
+        );
+        return Ok(ClearTarget::Color(drawbuffer.cast_unsigned()));
+    }
+    gl_assert!(
+        drawbuffer == 0,
+        InvalidValue,
+        "the {buffer:?} buffer must be cleared with a drawbuffer of 0, not {drawbuffer}"
+    );
+    Ok(if buffer == Buffer::Depth {
+        ClearTarget::Depth
+    } else {
+        ClearTarget::Stencil
+    })
+}
+
+impl Context {
+    /// Resolves the framebuffer name passed to a glClearNamedFramebuffer* command, where 0 names the default framebuffer
+    fn clear_framebuffer_by_name(
+        &self,
+        framebuffer: GLuint,
+    ) -> GlFallible<Option<ObjectName<Framebuffer>>> {
+        (framebuffer != 0)
+            .then(|| self.framebuffer_by_name(framebuffer))
+            .transpose()
+    }
+    /// Shared implementation of glClearBufferiv and glClearNamedFramebufferiv
+    /// # Safety
+    /// `value` must be valid for reads of 4 `GLint`s if `buffer` is `GL_COLOR`, and of a single one if it is `GL_STENCIL`
+    unsafe fn clear_bufferiv_internal(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLint,
+    ) -> GlFallible {
+        gl_assert!(
+            matches!(buffer, Buffer::Color | Buffer::Stencil),
+            InvalidEnum,
+            "{buffer:?} can't be cleared to a signed integer value"
+        );
+        let target = clear_target(buffer, drawbuffer)?;
+        let value = if buffer == Buffer::Color {
+            // Safety: Caller ensures `value` points to a color
+            ClearValue::Int(unsafe { value.cast::<[GLint; 4]>().read() })
+        } else {
+            // Safety: Caller ensures `value` points to a stencil value
+            ClearValue::Stencil(unsafe { value.read() })
+        };
+        self.clear_framebuffer(fb, &[(target, value)])
+    }
+    /// Shared implementation of glClearBufferuiv and glClearNamedFramebufferuiv
+    /// # Safety
+    /// `value` must be valid for reads of 4 `GLuint`s
+    unsafe fn clear_bufferuiv_internal(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            buffer == Buffer::Color,
+            InvalidEnum,
+            "{buffer:?} can't be cleared to an unsigned integer value"
+        );
+        let target = clear_target(buffer, drawbuffer)?;
+        // Safety: Caller ensures `value` points to a color
+        let value = ClearValue::Uint(unsafe { value.cast::<[GLuint; 4]>().read() });
+        self.clear_framebuffer(fb, &[(target, value)])
+    }
+    /// Shared implementation of glClearBufferfv and glClearNamedFramebufferfv
+    /// # Safety
+    /// `value` must be valid for reads of 4 `GLfloat`s if `buffer` is `GL_COLOR`, and of a single one if it is `GL_DEPTH`
+    unsafe fn clear_bufferfv_internal(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        gl_assert!(
+            matches!(buffer, Buffer::Color | Buffer::Depth),
+            InvalidEnum,
+            "{buffer:?} can't be cleared to a floating point value"
+        );
+        let target = clear_target(buffer, drawbuffer)?;
+        let value = if buffer == Buffer::Color {
+            // Safety: Caller ensures `value` points to a color
+            ClearValue::Float(unsafe { value.cast::<[GLfloat; 4]>().read() })
+        } else {
+            // Safety: Caller ensures `value` points to a depth value
+            ClearValue::Depth(unsafe { value.read() })
+        };
+        self.clear_framebuffer(fb, &[(target, value)])
+    }
+    /// Shared implementation of glClearBufferfi and glClearNamedFramebufferfi
+    fn clear_bufferfi_internal(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        buffer: Buffer,
+        drawbuffer: GLint,
+        depth: GLfloat,
+        stencil: GLint,
+    ) -> GlFallible {
+        gl_assert!(
+            buffer == Buffer::DepthStencil,
+            InvalidEnum,
+            "{buffer:?} can't be cleared to a depth and a stencil value"
+        );
+        gl_assert!(
+            drawbuffer == 0,
+            InvalidValue,
+            "the depth and stencil buffers must be cleared with a drawbuffer of 0, not {drawbuffer}"
+        );
+        self.clear_framebuffer(
+            fb,
+            &[
+                (ClearTarget::Depth, ClearValue::Depth(depth)),
+                (ClearTarget::Stencil, ClearValue::Stencil(stencil)),
+            ],
+        )
+    }
+    /// Clears the buffer of the framebuffer `fb` (None: the default framebuffer) selected by each of `clears` to its value, within
+    /// the scissor box and write masks. Buffers of the draw framebuffer are cleared by drawing into its render pass if one is in
+    /// progress or the clear doesn't cover the whole buffer, and by beginning a new one that clears them with its load actions
+    /// otherwise. Images that aren't rendered to by that render pass (as well as those of other framebuffers) are cleared by render
+    /// passes of their own
+    fn clear_framebuffer(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        clears: &[(ClearTarget, ClearValue)],
+    ) -> GlFallible {
+        let current = fb == self.gl_state.draw_framebuffer_binding;
+        if current {
+            self.prepare_draw_framebuffer()?;
+        } else if let Some(fb) = fb {
+            self.assert_framebuffer_complete(fb)?;
+            if self.realize_framebuffer_storage(fb) {
+                self.new_encoder();
+                self.new_pipeline();
+            }
+        }
+        let platform = &self.platform_state;
+        let pass_open = current
+            && platform.render_encoder.is_some()
+            && !platform.dirty_state.any_set(Dirty::NEW_RENDER_ENCODER);
+        let mut drawn = Vec::new();
+        let mut folded = false;
+        let mut passes = Vec::new();
+        for &(target, value) in clears {
+            let Some(scope) = self.clear_scope(fb, target) else {
+                gl_trace!("{target:?} is fully write masked, skipping its clear");
+                continue;
+            };
+            let Some((image, in_pass)) = self.clear_image(fb, target) else {
+                continue;
+            };
+            if !value.matches_format(image.format) {
+                gl_warn!(ty: UndefinedBehavior, "clearing {target:?} (of internal format {:?}) to {value:?} has undefined results, skipping it", image.format);
+                continue;
+            }
+            let needs_draw = !scope.is_whole(image.width, image.height);
+            if !(current && in_pass) {
+                passes.push((image, value, scope));
+            } else if pass_open || needs_draw {
+                drawn.push((target, value, scope));
+            } else {
+                let clear = match fb {
+                    Some(fb) => {
+                        &mut self
+                            .gl_state
+                            .framebufer_list
+                            .get_mut(fb)
+                            .cleared_attachment(target)
+                            .expect("cleared image should be attached")
+                            .clear
+                    }
+                    None => self.gl_state.default_framebuffer_clears.clear_mut(target),
+                };
+                *clear = Some(value);
+                folded = true;
+            }
+        }
+        gl_trace!(
+            "clearing {fb:?}: {} drawn, {} in their own render pass, folding the rest: {folded}",
+            drawn.len(),
+            passes.len()
+        );
+        for (image, value, scope) in passes {
+            self.platform_state.encode_clear_pass(&image, value, &scope);
+        }
+        // partial clears are drawn into a render pass that begins with the folded clears (or just loads the framebuffer's contents),
+        // as if they had been issued after them. The passes above end any render pass that was open
+        let pass_open = pass_open && self.platform_state.render_encoder.is_some();
+        if folded || (!drawn.is_empty() && !pass_open) {
+            self.platform_state.begin_render_pass(&mut self.gl_state);
+        }
+        if !drawn.is_empty() {
+            for (target, value, scope) in drawn {
+                self.platform_state.draw_clear(target, value, &scope);
+            }
+            self.update_encoder();
+        }
+        Ok(())
+    }
+    /// Returns the parts of the buffer of the framebuffer `fb` (None: the default framebuffer) selected by `target` that a clear
+    /// affects according to the current scissor test and write masks, or None if the write masks disable the clear entirely
+    fn clear_scope(
+        &self,
+        fb: Option<ObjectName<Framebuffer>>,
+        target: ClearTarget,
+    ) -> Option<ClearScope> {
+        let state = &self.gl_state;
+        let mut scope = ClearScope {
+            scissor: state.scissor_test.then_some(state.scissor_box),
+            flip_y: fb.is_none(),
+            color_mask: MTLColorWriteMask::All,
+            stencil_mask: u32::MAX,
+        };
+        match target {
+            ClearTarget::Color(idx) => {
+                scope.color_mask = state.writemasks.color[idx as usize].into();
+                (scope.color_mask != MTLColorWriteMask::None).then_some(scope)
+            }
+            ClearTarget::Depth => state.writemasks.depth.then_some(scope),
+            ClearTarget::Stencil => {
+                scope.stencil_mask = state.writemasks.stencil_front;
+                (scope.stencil_mask & 0xFF != 0).then_some(scope)
+            }
+        }
+    }
+    /// Returns the image of the framebuffer `fb` (None: the default framebuffer) that a clear of `target` clears, if there is one, and
+    /// whether the render pass of `fb` renders to it
+    fn clear_image(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        target: ClearTarget,
+    ) -> Option<(AttachmentStorage, bool)> {
+        if let Some(fb) = fb {
+            let attachment = *self
+                .gl_state
+                .framebufer_list
+                .get_mut(fb)
+                .cleared_attachment(target)?;
+            return Some((attachment.storage(&self.gl_state)?, true));
+        }
+        let state = &self.gl_state;
+        let platform = &mut self.platform_state;
+        let drawable_tex = platform.current_drawable().texture();
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "drawable dimensions fit in a u32"
+        )]
+        let dims = (drawable_tex.width() as u32, drawable_tex.height() as u32);
+        let modes = &state.default_draw_buffers.modes;
+        // the render pass of the default framebuffer needs a color attachment, and only renders to its depth and stencil buffers
+        // while their tests are enabled
+        let rendered = |cap| modes.iter().any(Option::is_some) && state.caps.is_any_enabled(cap);
+        let (tex, in_pass) = match target {
+            ClearTarget::Color(idx) => (
+                platform.default_color_buffer(modes[idx as usize]?, dims),
+                true,
+            ),
+            ClearTarget::Depth => {
+                platform.depth_format?;
+                (
+                    platform.get_internal_depthbuffer(dims).tex.clone(),
+                    rendered(Capabilities::DEPTH_TEST),
+                )
+            }
+            ClearTarget::Stencil => {
+                platform.stencil_format?;
+                (
+                    platform.get_internal_stencilbuffer(dims).tex.clone(),
+                    rendered(Capabilities::STENCIL_TEST),
+                )
+            }
+        };
+        Some((default_framebuffer_storage(tex, dims)?, in_pass))
     }
 }
@@ -1,18 +1,19 @@
 use crate::{
     context::{
+        Context,
         commands::buffer::Buffer,
         debug::{gl_warn, with_debug_state, with_debug_state_mut},
-        error::{gl_assert, GlFallible},
-        framebuffer::Framebuffer,
+        error::{GlFallible, gl_assert},
+        framebuffer::{Framebuffer, RenderBuffer},
         gl_object::ObjectName,
         program::Program,
         shader::Shader,
+        texture::{Sampler, Texture},
         vao::Vao,
-        Context,
     },
     dispatch::{
         conversions::sizei,
-        gl_types::{GLboolean, GLchar, GLsizei, GLuint, GLvoid, GLDEBUGPROC},
+        gl_types::{GLDEBUGPROC, GLboolean, GLchar, GLenum, GLsizei, GLuint, GLvoid},
     },
     enums::{DebugSeverity, DebugSource, DebugType, GetPointervPName, ObjectIdentifier},
 };
@@ -99,11 +100,13 @@ impl Context {
             ObjectIdentifier::Program => state.set_label(self, name.cast::<Program>(), label),
             ObjectIdentifier::VertexArray => state.set_label(self, name.cast::<Vao>(), label),
             ObjectIdentifier::ProgramPipeline => todo!(),
-            ObjectIdentifier::Texture => todo!(),
-            ObjectIdentifier::Renderbuffer => todo!(),
+            ObjectIdentifier::Texture => state.set_label(self, name.cast::<Texture>(), label),
+            ObjectIdentifier::Renderbuffer => {
+                state.set_label(self, name.cast::<RenderBuffer>(), label);
+            }
             ObjectIdentifier::TransformFeedback => todo!(),
             ObjectIdentifier::Query => todo!(),
-            ObjectIdentifier::Sampler => todo!(),
+            ObjectIdentifier::Sampler => state.set_label(self, name.cast::<Sampler>(), label),
         });
         Ok(())
     }
@@ -203,11 +206,11 @@ impl Context {
             ObjectIdentifier::Program => state.get_label(name.cast::<Program>()),
             ObjectIdentifier::VertexArray => state.get_label(name.cast::<Vao>()),
             ObjectIdentifier::ProgramPipeline => todo!(),
-            ObjectIdentifier::Texture => todo!(),
-            ObjectIdentifier::Renderbuffer => todo!(),
+            ObjectIdentifier::Texture => state.get_label(name.cast::<Texture>()),
+            ObjectIdentifier::Renderbuffer => state.get_label(name.cast::<RenderBuffer>()),
             ObjectIdentifier::TransformFeedback => todo!(),
             ObjectIdentifier::Query => todo!(),
-            ObjectIdentifier::Sampler => todo!(),
+            ObjectIdentifier::Sampler => state.get_label(name.cast::<Sampler>()),
         })
         .flatten();
 
@@ -660,10 +663,10 @@ impl Context {
         &mut self,
         count: GLuint,
         buf_size: GLsizei,
-        sources: DebugSource,
-        types: DebugType,
+        sources: *mut GLenum,
+        types: *mut GLenum,
         ids: *mut GLuint,
-        severities: DebugSeverity,
+        severities: *mut GLenum,
         lengths: *mut GLsizei,
         message_log: *mut GLchar,
     ) -> GlFallible<GLuint> {
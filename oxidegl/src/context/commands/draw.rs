@@ -1,7 +1,7 @@
 use objc2_metal::{MTLPrimitiveType, MTLRenderCommandEncoder};
 
 use crate::{
-    context::Context,
+    context::{Context, error::GlFallible},
     dispatch::gl_types::{GLint, GLsizei, GLuint, GLvoid},
     enums::{DrawElementsType, PrimitiveType},
 };
@@ -50,7 +50,14 @@ impl Context {
     /// [`GL_TRIANGLE_STRIP_ADJACENCY`](crate::enums::GL_TRIANGLE_STRIP_ADJACENCY)
     /// and [`GL_TRIANGLES_ADJACENCY`](crate::enums::GL_TRIANGLES_ADJACENCY) are
     /// available only if the GL version is 3.2 or greater.
-    pub fn oxidegl_draw_arrays(&mut self, mode: PrimitiveType, first: GLint, count: GLsizei) {
+    pub fn oxidegl_draw_arrays(
+        &mut self,
+        mode: PrimitiveType,
+        first: GLint,
+        count: GLsizei,
+    ) -> GlFallible {
+        self.prepare_draw_framebuffer()?;
+        self.prepare_sampled_textures();
         //FIXME hack, move to module
         let Context {
             gl_state: state,
@@ -70,7 +77,7 @@ impl Context {
                     count as usize,
                 );
         };
-        //panic!("command oxidegl_draw_arrays not yet implemented");
+        Ok(())
     }
     /// ### Parameters
     /// `mode`
@@ -0,0 +1,3020 @@
+use core::slice;
+
+use objc2::rc::Retained;
+use objc2_foundation::NSRange;
+use objc2_metal::{
+    MTLBlitCommandEncoder, MTLCompareFunction, MTLDepthStencilDescriptor, MTLDevice, MTLLoadAction,
+    MTLOrigin, MTLPixelFormat, MTLRenderPassAttachmentDescriptor,
+    MTLRenderPassColorAttachmentDescriptor, MTLRenderPassDepthAttachmentDescriptor,
+    MTLRenderPassDescriptor, MTLRenderPassStencilAttachmentDescriptor, MTLSize,
+    MTLStencilDescriptor, MTLStencilOperation, MTLStorageMode, MTLStoreAction, MTLTexture,
+    MTLTextureDescriptor, MTLTextureType, MTLTextureUsage,
+};
+use objc2_quartz_core::CAMetalDrawable;
+
+use crate::{
+    context::{
+        Context,
+        commands::texture::ReadbackRegion,
+        debug::{gl_debug, gl_err, gl_trace, gl_warn},
+        error::{GlError, GlFallible, gl_assert},
+        framebuffer::{
+            AttachmentImage, AttachmentPoint, AttachmentStorage, DefaultBuffer, Framebuffer,
+            FramebufferAttachment as Attachment, FramebufferImages, InvalidatedRegion,
+            MAX_COLOR_ATTACHMENTS, color_attachment_index, framebuffer_status,
+        },
+        gl_object::ObjectName,
+        internal_shaders::{BlitFunction, BlitParams, depth_stencil_aspects},
+        pixel::{GlPixelTypeFormat, strip_srgb},
+        platform::{Dirty, scissor_rect},
+        state::{
+            Capabilities, MAX_3D_TEXTURE_SIZE, MAX_ARRAY_TEXTURE_LAYERS, MAX_TEXTURE_SIZE,
+            PixelAlignedRect,
+        },
+        texture::TextureExtent,
+    },
+    dispatch::{
+        conversions::{GLenumExt, GlGetItem, sizei},
+        gl_types::{GLboolean, GLenum, GLint, GLsizei, GLuint, GLvoid},
+    },
+    enums::{
+        BlitFramebufferFilter, ClearBufferMask, ColorBuffer, DrawBufferMode, FramebufferAttachment,
+        FramebufferAttachmentParameterName, FramebufferTarget, GL_COLOR_ATTACHMENT0,
+        GL_COLOR_ATTACHMENT31, GL_FRAMEBUFFER_COMPLETE, GL_INT, GL_LINEAR, GL_NONE,
+        GL_RENDERBUFFER, GL_SRGB, GL_TEXTURE, GL_TEXTURE_CUBE_MAP_POSITIVE_X, GL_UNSIGNED_INT,
+        InternalFormat, PixelFormat, PixelType, ReadBufferMode, TextureTarget,
+    },
+    util::{ProtoObjRef, run_if_changed},
+};
+
+/// Texture targets accepted by glFramebufferTexture2D
+const TEXTURE_2D_TEXTARGETS: [TextureTarget; 9] = [
+    TextureTarget::Texture2D,
+    TextureTarget::TextureRectangle,
+    TextureTarget::Texture2DMultisample,
+    TextureTarget::TextureCubeMapPositiveX,
+    TextureTarget::TextureCubeMapNegativeX,
+    TextureTarget::TextureCubeMapPositiveY,
+    TextureTarget::TextureCubeMapNegativeY,
+    TextureTarget::TextureCubeMapPositiveZ,
+    TextureTarget::TextureCubeMapNegativeZ,
+];
+
+/// ### Parameters
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glNamedFramebufferReadBuffer**](crate::context::Context::oxidegl_named_framebuffer_read_buffer)
+/// > function.
+///
+/// `mode`
+///
+/// > Specifies a color buffer. Accepted values are [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT),
+/// > [`GL_FRONT_RIGHT`](crate::enums::GL_FRONT_RIGHT), [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT),
+/// > [`GL_BACK_RIGHT`](crate::enums::GL_BACK_RIGHT), [`GL_FRONT`](crate::enums::GL_FRONT),
+/// > [`GL_BACK`](crate::enums::GL_BACK), [`GL_LEFT`](crate::enums::GL_LEFT),
+/// > [`GL_RIGHT`](crate::enums::GL_RIGHT), and the constants [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT)
+/// > *i*.
+///
+/// ### Description
+/// [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer) specifies
+/// a color buffer as the source for subsequent [**glReadPixels**](crate::context::Context::oxidegl_read_pixels),
+/// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d),
+/// [**glCopyTexSubImage1D**](crate::context::Context::oxidegl_copy_tex_sub_image1_d),
+/// [**glCopyTexSubImage2D**](crate::context::Context::oxidegl_copy_tex_sub_image2_d),
+/// and [**glCopyTexSubImage3D**](crate::context::Context::oxidegl_copy_tex_sub_image3_d)
+/// commands. `mode` accepts one of twelve or more predefined values. In a
+/// fully configured system, [`GL_FRONT`](crate::enums::GL_FRONT), [`GL_LEFT`](crate::enums::GL_LEFT),
+/// and [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT) all name the front
+/// left buffer, [`GL_FRONT_RIGHT`](crate::enums::GL_FRONT_RIGHT) and [`GL_RIGHT`](crate::enums::GL_RIGHT)
+/// name the front right buffer, and [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT)
+/// and [`GL_BACK`](crate::enums::GL_BACK) name the back left buffer. Further
+/// more, the constants [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT)
+/// *i* may be used to indicate the *i* *i* ranges from zero to the value of
+/// [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS) minus
+/// one.
+///
+/// Nonstereo double-buffered configurations have only a front left and a back
+/// left buffer. Single-buffered configurations have a front left and a front
+/// right buffer if stereo, and only a front left buffer if nonstereo. It is
+/// an error to specify a nonexistent buffer to [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer).
+///
+/// `mode` is initially [`GL_FRONT`](crate::enums::GL_FRONT) in single-buffered
+/// configurations and [`GL_BACK`](crate::enums::GL_BACK) in double-buffered
+/// configurations.
+///
+/// For [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer), the
+/// target framebuffer object is that bound to [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER).
+/// For [**glNamedFramebufferReadBuffer**](crate::context::Context::oxidegl_named_framebuffer_read_buffer),
+/// `framebuffer` must either be zero or the name of the target framebuffer
+/// object. If `framebuffer` is zero, then the default read framebuffer is
+/// affected.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+impl Context {
+    pub(crate) fn oxidegl_read_buffer(&mut self, src: ReadBufferMode) -> GlFallible {
+        match self.gl_state.read_framebuffer_binding {
+            Some(fb) => self.framebuffer_read_buffer_internal(fb, src),
+            None => self.default_read_buffer_internal(src),
+        }
+    }
+    pub(crate) fn oxidegl_named_framebuffer_read_buffer(
+        &mut self,
+        framebuffer: GLuint,
+        src: ColorBuffer,
+    ) -> GlFallible {
+        let fb = if framebuffer == 0 {
+            None
+        } else {
+            Some(self.framebuffer_by_name(framebuffer)?)
+        };
+        let src = ReadBufferMode::from_repr(src.into()).ok_or_else(|| {
+            gl_err!(ty: Error, "{src:?} is not a valid read buffer");
+            GlError::InvalidEnum.e()
+        })?;
+        match fb {
+            Some(fb) => self.framebuffer_read_buffer_internal(fb, src),
+            None => self.default_read_buffer_internal(src),
+        }
+    }
+}
+
+/// ### Parameters
+/// `x`
+///
+/// `y`
+///
+/// > Specify the window coordinates of the first pixel that is read from the
+/// > frame buffer. This location is the lower left corner of a rectangular block
+/// > of pixels.
+///
+/// `width`
+///
+/// `height`
+///
+/// > Specify the dimensions of the pixel rectangle. `width` and `height` of
+/// > one correspond to a single pixel.
+///
+/// `format`
+///
+/// > Specifies the format of the pixel data. The following symbolic values are
+/// > accepted: [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX), [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+/// > [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL), [`GL_RED`](crate::enums::GL_RED),
+/// > [`GL_GREEN`](crate::enums::GL_GREEN), [`GL_BLUE`](crate::enums::GL_BLUE),
+/// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+/// > and [`GL_BGRA`](crate::enums::GL_BGRA).
+///
+/// `type`
+///
+/// > Specifies the data type of the pixel data. Must be one of [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE),
+/// > [`GL_BYTE`](crate::enums::GL_BYTE), [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT),
+/// > [`GL_SHORT`](crate::enums::GL_SHORT), [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT),
+/// > [`GL_INT`](crate::enums::GL_INT), [`GL_HALF_FLOAT`](crate::enums::GL_HALF_FLOAT),
+/// > [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2),
+/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+/// > [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV),
+/// > [`GL_UNSIGNED_INT_24_8`](crate::enums::GL_UNSIGNED_INT_24_8), [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::enums::GL_UNSIGNED_INT_10F_11F_11F_REV),
+/// > [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::enums::GL_UNSIGNED_INT_5_9_9_9_REV),
+/// > or [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
+///
+/// `bufSize`
+///
+/// > Specifies the size of the buffer `data` for [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// > function.
+///
+/// `data`
+///
+/// > Returns the pixel data.
+///
+/// ### Description
+/// [**glReadPixels**](crate::context::Context::oxidegl_read_pixels) and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// return pixel data from the frame buffer, starting with the pixel whose
+/// lower left corner is at location( `x`, `y` ), into client memory starting
+/// at location `data`. Several parameters control the processing of the pixel
+/// data before it is placed into client memory. These parameters are set with
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store). This
+/// reference page describes the effects on [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+/// and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// of most, but not all of the parameters specified by these three commands.
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_PACK_BUFFER`](crate::enums::GL_PIXEL_PACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a block of pixels is requested, `data` is treated as a byte offset
+/// into the buffer object's data store rather than a pointer to client memory.
+///
+/// [**glReadPixels**](crate::context::Context::oxidegl_read_pixels) and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// return values from each pixel with lower left corner at `[inlineq]` `[inlineq]`
+/// `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// `format` specifies the format for the returned pixel values; accepted values
+/// are:
+///
+/// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX)
+///
+/// > Stencil values are read from the stencil buffer.
+///
+/// [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT)
+///
+/// > Depth values are read from the depth buffer. Each component is converted
+/// > to floating point such that the minimum depth value maps to 0 and the maximum
+/// > value maps to 1. Each component is clamped to the range `[inlineq]`
+///
+/// [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL)
+///
+/// > Values are taken from both the depth and stencil buffers. The `type` parameter
+/// > must be [`GL_UNSIGNED_INT_24_8`](crate::enums::GL_UNSIGNED_INT_24_8) or
+/// > [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
+///
+/// [`GL_RED`](crate::enums::GL_RED), [`GL_GREEN`](crate::enums::GL_GREEN),
+/// [`GL_BLUE`](crate::enums::GL_BLUE), [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR),
+/// [`GL_RGBA`](crate::enums::GL_RGBA), [`GL_BGRA`](crate::enums::GL_BGRA)
+///
+/// > Color values are taken from the color buffer.
+///
+/// Finally, the indices or components are converted to the proper format,
+/// as specified by `type`. If `format` is [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX)
+/// and `type` is not [`GL_FLOAT`](crate::enums::GL_FLOAT), each index is masked
+/// with the mask value given in the following table. If `type` is [`GL_FLOAT`](crate::enums::GL_FLOAT),
+/// then each integer index is converted to single-precision floating-point
+/// format.
+///
+/// If `format` is [`GL_RED`](crate::enums::GL_RED), [`GL_GREEN`](crate::enums::GL_GREEN),
+/// [`GL_BLUE`](crate::enums::GL_BLUE), [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR),
+/// [`GL_RGBA`](crate::enums::GL_RGBA), or [`GL_BGRA`](crate::enums::GL_BGRA)
+/// and `type` is not [`GL_FLOAT`](crate::enums::GL_FLOAT), each component
+/// is multiplied by the multiplier shown in the following table. If type is
+/// [`GL_FLOAT`](crate::enums::GL_FLOAT), then each component is passed as
+/// is (or converted to the client's single-precision floating-point format
+/// if it is different from the one used by the GL).
+///
+///
+/// | `type`     |* Index Mask*        |* Component Conversion*                               |
+/// |------------|---------------------|------------------------------------------------------|
+/// | [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE) | `[inlineq]` | `[inlineq]`        |
+/// | [`GL_BYTE`](crate::enums::GL_BYTE) | `[inlineq]` | `[inlineq]`                          |
+/// | [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT) | `[inlineq]` | `[inlineq]`      |
+/// | [`GL_SHORT`](crate::enums::GL_SHORT) | `[inlineq]` | `[inlineq]`                        |
+/// | [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT) | `[inlineq]` | `[inlineq]`          |
+/// | [`GL_INT`](crate::enums::GL_INT) | `[inlineq]` | `[inlineq]`                            |
+/// | [`GL_HALF_FLOAT`](crate::enums::GL_HALF_FLOAT) | none | `[inlineq]`                     |
+/// | [`GL_FLOAT`](crate::enums::GL_FLOAT) | none | `[inlineq]`                               |
+/// | [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_24_8`](crate::enums::GL_UNSIGNED_INT_24_8) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::enums::GL_UNSIGNED_INT_10F_11F_11F_REV) | -- | Special |
+/// | [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::enums::GL_UNSIGNED_INT_5_9_9_9_REV) | -- | Special |
+/// | [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV) | none | `[inlineq]` |
+///
+/// Return values are placed in memory as follows. If `format` is [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX),
+/// [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), [`GL_RED`](crate::enums::GL_RED),
+/// [`GL_GREEN`](crate::enums::GL_GREEN), or [`GL_BLUE`](crate::enums::GL_BLUE),
+/// a single value is returned and the data for the `[inlineq]` `[inlineq]`
+/// `[inlineq]` [`GL_RGB`](crate::enums::GL_RGB) and [`GL_BGR`](crate::enums::GL_BGR)
+/// return three values, [`GL_RGBA`](crate::enums::GL_RGBA) and [`GL_BGRA`](crate::enums::GL_BGRA)
+/// return four values for each pixel, with all values corresponding to a single
+/// pixel occupying contiguous space in `data`. Storage parameters set by [**glPixelStore**](crate::context::Context::oxidegl_pixel_store),
+/// such as [`GL_PACK_LSB_FIRST`](crate::enums::GL_PACK_LSB_FIRST) and [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES),
+/// affect the way that data is written into memory. See [**glPixelStore**](crate::context::Context::oxidegl_pixel_store)
+/// for a description.
+///
+/// [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels) function
+/// will only handle the call if `bufSize` is at least of the size required
+/// to store the requested data. Otherwise, it will generate a [`GL_INVALID_OPERATION`](crate::enums::GL_INVALID_OPERATION)
+/// error.
+///
+/// ### Notes
+/// Values for pixels that lie outside the window connected to the current
+/// GL context are undefined.
+///
+/// If an error is generated, no change is made to the contents of `data`.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_PACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_PACK_BUFFER_BINDING)
+impl Context {
+    pub(crate) unsafe fn oxidegl_read_pixels(
+        &mut self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        let fmt = GlPixelTypeFormat::new(r#type, format);
+        // Safety: caller ensures `pixels` is valid for writes of the requested image, or is an offset into the pixel pack buffer
+        unsafe { self.read_pixels_internal([x, y], width, height, fmt, None, pixels) }
+    }
+    pub(crate) unsafe fn oxidegl_readn_pixels(
+        &mut self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        buf_size: GLsizei,
+        data: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let fmt = GlPixelTypeFormat::new(r#type, format);
+        // Safety: caller ensures `data` is valid for writes of `buf_size` bytes, or is an offset into the pixel pack buffer
+        unsafe {
+            self.read_pixels_internal([x, y], width, height, fmt, Some(buf_size as usize), data)
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specify the target to which the framebuffer is bound for [**glCheckFramebufferStatus**](crate::context::Context::oxidegl_check_framebuffer_status),
+/// > and the target against which framebuffer completeness of `framebuffer`
+/// > is checked for [**glCheckNamedFramebufferStatus**](crate::context::Context::oxidegl_check_named_framebuffer_status).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glCheckNamedFramebufferStatus**](crate::context::Context::oxidegl_check_named_framebuffer_status)
+///
+/// ### Description
+/// [**glCheckFramebufferStatus**](crate::context::Context::oxidegl_check_framebuffer_status)
+/// and [**glCheckNamedFramebufferStatus**](crate::context::Context::oxidegl_check_named_framebuffer_status)
+/// return the completeness status of a framebuffer object when treated as
+/// a read or draw framebuffer, depending on the value of `target`.
+///
+/// For [**glCheckFramebufferStatus**](crate::context::Context::oxidegl_check_framebuffer_status),
+/// the framebuffer checked is that bound to `target`, which must be [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER),
+/// [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER) or [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER).
+/// [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER) is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+///
+/// For [**glCheckNamedFramebufferStatus**](crate::context::Context::oxidegl_check_named_framebuffer_status),
+/// `framebuffer` is zero or the name of the framebuffer object to check. If
+/// `framebuffer` is zero, then the status of the default read or draw framebuffer,
+/// as determined by `target`, is returned.
+///
+/// The return value is [`GL_FRAMEBUFFER_COMPLETE`](crate::enums::GL_FRAMEBUFFER_COMPLETE)
+/// if the specified framebuffer is complete. Otherwise, the return value is
+/// determined as follows: [`GL_FRAMEBUFFER_UNDEFINED`](crate::enums::GL_FRAMEBUFFER_UNDEFINED)
+/// > is returned if the specified framebuffer is the default read or draw framebuffer,
+/// > but the default framebuffer does not exist.
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT)
+/// > is returned if any of the framebuffer attachment points are framebuffer
+/// > incomplete.
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT)
+/// > is returned if the framebuffer does not have at least one image attached
+/// > to it.
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER)
+/// > is returned if the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// > is [`GL_NONE`](crate::enums::GL_NONE) for any color attachment point(s)
+/// > named by [`GL_DRAW_BUFFERi`](crate::enums::GL_DRAW_BUFFERi).
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER)
+/// > is returned if [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER) is not
+/// > [`GL_NONE`](crate::enums::GL_NONE) and the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// > is [`GL_NONE`](crate::enums::GL_NONE) for the color attachment point named
+/// > by [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER).
+///
+/// > [`GL_FRAMEBUFFER_UNSUPPORTED`](crate::enums::GL_FRAMEBUFFER_UNSUPPORTED)
+/// > is returned if the combination of internal formats of the attached images
+/// > violates an implementation-dependent set of restrictions.
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE)
+/// > is returned if the value of [`GL_RENDERBUFFER_SAMPLES`](crate::enums::GL_RENDERBUFFER_SAMPLES)
+/// > is not the same for all attached renderbuffers; if the value of [`GL_TEXTURE_SAMPLES`](crate::enums::GL_TEXTURE_SAMPLES)
+/// > is the not same for all attached textures; or, if the attached images are
+/// > a mix of renderbuffers and textures, the value of [`GL_RENDERBUFFER_SAMPLES`](crate::enums::GL_RENDERBUFFER_SAMPLES)
+/// > does not match the value of [`GL_TEXTURE_SAMPLES`](crate::enums::GL_TEXTURE_SAMPLES).
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE)
+/// > is also returned if the value of [`GL_TEXTURE_FIXED_SAMPLE_LOCATIONS`](crate::enums::GL_TEXTURE_FIXED_SAMPLE_LOCATIONS)
+/// > is not the same for all attached textures; or, if the attached images are
+/// > a mix of renderbuffers and textures, the value of [`GL_TEXTURE_FIXED_SAMPLE_LOCATIONS`](crate::enums::GL_TEXTURE_FIXED_SAMPLE_LOCATIONS)
+/// > is not [`GL_TRUE`](crate::enums::GL_TRUE) for all attached textures.
+///
+/// > [`GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS`](crate::enums::GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS)
+/// > is returned if any framebuffer attachment is layered, and any populated
+/// > attachment is not layered, or if all populated color attachments are not
+/// > from textures of the same target.
+///
+///
+/// Additionally, if an error occurs, zero is returned.
+impl Context {
+    pub(crate) fn oxidegl_check_framebuffer_status(&mut self, target: FramebufferTarget) -> GLenum {
+        self.bound_framebuffer(target)
+            .map_or(GL_FRAMEBUFFER_COMPLETE, |fb| {
+                self.framebuffer_completeness(fb)
+            })
+    }
+    pub(crate) fn oxidegl_check_named_framebuffer_status(
+        &mut self,
+        framebuffer: GLuint,
+        target: FramebufferTarget,
+    ) -> GlFallible<GLenum> {
+        if framebuffer == 0 {
+            gl_trace!("checked the status of the default framebuffer as {target:?}");
+            return Ok(GL_FRAMEBUFFER_COMPLETE);
+        }
+        let fb = self.framebuffer_by_name(framebuffer)?;
+        Ok(self.framebuffer_completeness(fb))
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the framebuffer is bound for [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glNamedFramebufferRenderbuffer**](crate::context::Context::oxidegl_named_framebuffer_renderbuffer).
+///
+/// `attachment`
+///
+/// > Specifies the attachment point of the framebuffer.
+///
+/// `renderbuffertarget`
+///
+/// > Specifies the renderbuffer target. Must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer`
+///
+/// > Specifies the name of an existing renderbuffer object of type `renderbuffertarget`
+/// > to attach.
+///
+/// ### Description
+/// [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer)
+/// and [**glNamedFramebufferRenderbuffer**](crate::context::Context::oxidegl_named_framebuffer_renderbuffer)
+/// attaches a renderbuffer as one of the logical buffers of the specified
+/// framebuffer object. Renderbuffers cannot be attached to the default draw
+/// and read framebuffer, so they are not valid targets of these commands.
+///
+/// For [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer),
+/// the framebuffer object is that bound to `target`, which must be [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER),
+/// [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER) or [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER).
+/// [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER) is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+///
+/// For [**glNamedFramebufferRenderbuffer**](crate::context::Context::oxidegl_named_framebuffer_renderbuffer),
+/// `framebuffer` is the name of the framebuffer object.
+///
+/// `renderbuffertarget` must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer` must be zero or the name of an existing renderbuffer object
+/// of type `renderbuffertarget`. If `renderbuffer` is not zero, then the specified
+/// renderbuffer will be used as the logical buffer identified by `attachment`
+/// of the specified framebuffer object. If `renderbuffer` is zero, then the
+/// value of `renderbuffertarget` is ignored.
+///
+/// `attachment` specifies the logical attachment of the framebuffer and must
+/// be [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i*, [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT),
+/// [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT) or [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT).
+/// *i* in may range from zero to the value of [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS)
+/// minus one. Setting `attachment` to the value [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT)
+/// is a special case causing both the depth and stencil attachments of the
+/// specified framebuffer object to be set to `renderbuffer`, which should
+/// have the base internal format [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL).
+///
+/// The value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// for the specified attachment point is set to [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER)
+/// and the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME)
+/// is set to `renderbuffer`. All other state values of specified attachment
+/// point are set to their default values. No change is made to the state of
+/// the renderbuuffer object and any previous attachment to the `attachment`
+/// logical buffer of the specified framebuffer object is broken.
+///
+/// If `renderbuffer` is zero, these commands will detach the image, if any,
+/// identified by the specified attachment point of the specified framebuffer
+/// object. All state values of the attachment point are set to their default
+/// values.
+impl Context {
+    pub(crate) fn oxidegl_framebuffer_renderbuffer(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        renderbuffertarget: GLenum,
+        renderbuffer: GLuint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_for_target(target)?;
+        self.framebuffer_renderbuffer_internal(fb, attachment, renderbuffertarget, renderbuffer)
+    }
+    pub(crate) fn oxidegl_named_framebuffer_renderbuffer(
+        &mut self,
+        framebuffer: GLuint,
+        attachment: FramebufferAttachment,
+        renderbuffertarget: GLenum,
+        renderbuffer: GLuint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_by_name(framebuffer)?;
+        self.framebuffer_renderbuffer_internal(fb, attachment, renderbuffertarget, renderbuffer)
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the framebuffer is bound for all commands
+/// > *except* [**glNamedFramebufferTexture**](crate::context::Context::oxidegl_named_framebuffer_texture).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glNamedFramebufferTexture**](crate::context::Context::oxidegl_named_framebuffer_texture).
+///
+/// `attachment`
+///
+/// > Specifies the attachment point of the framebuffer.
+///
+/// `textarget`
+///
+/// > For [**glFramebufferTexture1D**](crate::context::Context::oxidegl_framebuffer_texture1_d),
+/// > [**glFramebufferTexture2D**](crate::context::Context::oxidegl_framebuffer_texture2_d)
+/// > and [**glFramebufferTexture3D**](crate::context::Context::oxidegl_framebuffer_texture3_d),
+/// > specifies what type of texture is expected in the `texture` parameter,
+/// > or for cube map textures, which face is to be attached.
+///
+/// `texture`
+///
+/// > Specifies the name of an existing texture object to attach.
+///
+/// `level`
+///
+/// > Specifies the mipmap level of the texture object to attach.
+///
+/// ### Description
+/// These commands attach a selected mipmap level or image of a texture object
+/// as one of the logical buffers of the specified framebuffer object. Textures
+/// cannot be attached to the default draw and read framebuffer, so they are
+/// not valid targets of these commands.
+///
+/// For all commands *except* [**glNamedFramebufferTexture**](crate::context::Context::oxidegl_named_framebuffer_texture),
+/// the framebuffer object is that bound to `target`, which must be [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER),
+/// [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER), or [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER).
+/// [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER) is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+///
+/// For [**glNamedFramebufferTexture**](crate::context::Context::oxidegl_named_framebuffer_texture),
+/// `framebuffer` is the name of the framebuffer object.
+///
+/// `attachment` specifies the logical attachment of the framebuffer and must
+/// be [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i*, [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT),
+/// [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT) or [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT).
+/// *i* in [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i* may
+/// range from zero to the value of [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS)
+/// minus one. Attaching a level of a texture to [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT)
+/// is equivalent to attaching that level to both the [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT)
+/// *and* the [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT)
+/// attachment points simultaneously.
+///
+/// For [**glFramebufferTexture1D**](crate::context::Context::oxidegl_framebuffer_texture1_d),
+/// [**glFramebufferTexture2D**](crate::context::Context::oxidegl_framebuffer_texture2_d)
+/// and [**glFramebufferTexture3D**](crate::context::Context::oxidegl_framebuffer_texture3_d),
+/// `textarget` specifies what type of texture is named by `texture`, and for
+/// cube map textures, specifies the face that is to be attached. If `texture`
+/// is not zero, it must be the name of an existing texture object with effective
+/// target `textarget` unless it is a cube map texture, in which case `textarget`
+/// must be [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X)
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z).
+///
+/// If `texture` is non-zero, the specified `level` of the texture object named
+/// `texture` is attached to the framebuffer attachment point named by `attachment`.
+/// For [**glFramebufferTexture1D**](crate::context::Context::oxidegl_framebuffer_texture1_d),
+/// [**glFramebufferTexture2D**](crate::context::Context::oxidegl_framebuffer_texture2_d),
+/// and [**glFramebufferTexture3D**](crate::context::Context::oxidegl_framebuffer_texture3_d),
+/// `texture` must be zero or the name of an existing texture with an effective
+/// target of `textarget`, or `texture` must be the name of an existing cube-map
+/// texture and `textarget` must be one of [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z).
+///
+/// If `textarget` is [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE),
+/// [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
+/// then `level` must be zero.
+///
+/// If `textarget` is [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), then
+/// `level` must be greater than or equal to zero and less than or equal to
+/// $`log_2`$ of the value of [`GL_MAX_3D_TEXTURE_SIZE`](crate::enums::GL_MAX_3D_TEXTURE_SIZE).
+///
+/// If `textarget` is one of [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+/// then `level` must be greater than or equal to zero and less than or equal
+/// to $`log_2`$ of the value of [`GL_MAX_CUBE_MAP_TEXTURE_SIZE`](crate::enums::GL_MAX_CUBE_MAP_TEXTURE_SIZE).
+///
+/// For all other values of `textarget`, `level` must be greater than or equal
+/// to zero and less than or equal to $`log_2`$ of the value of [`GL_MAX_TEXTURE_SIZE`](crate::enums::GL_MAX_TEXTURE_SIZE).
+///
+/// `layer` specifies the layer of a 2-dimensional image within a 3-dimensional
+/// texture.
+///
+/// For [**glFramebufferTexture1D**](crate::context::Context::oxidegl_framebuffer_texture1_d),
+/// if `texture` is not zero, then `textarget` must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+/// For [**glFramebufferTexture2D**](crate::context::Context::oxidegl_framebuffer_texture2_d),
+/// if `texture` is not zero, `textarget` must be one of [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+/// [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+/// or [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE).
+/// For [**glFramebufferTexture3D**](crate::context::Context::oxidegl_framebuffer_texture3_d),
+/// if `texture` is not zero, then `textarget` must be [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D).
+///
+/// For [**glFramebufferTexture**](crate::context::Context::oxidegl_framebuffer_texture)
+/// and [**glNamedFramebufferTexture**](crate::context::Context::oxidegl_named_framebuffer_texture),
+/// if `texture` is the name of a three-dimensional, cube map array, cube
+/// map, one- or two-dimensional array, or two-dimensional multisample array
+/// texture, the specified texture level is an array of images, and the framebuffer
+/// attachment is considered to be *layered*.
+impl Context {
+    pub(crate) fn oxidegl_framebuffer_texture1_d(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        textarget: TextureTarget,
+        texture: GLuint,
+        level: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_for_target(target)?;
+        let image = self.texture_attachment(
+            texture,
+            level,
+            Some((textarget, &[TextureTarget::Texture1D])),
+            None,
+        )?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+    pub(crate) fn oxidegl_framebuffer_texture2_d(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        textarget: TextureTarget,
+        texture: GLuint,
+        level: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_for_target(target)?;
+        let image = self.texture_attachment(
+            texture,
+            level,
+            Some((textarget, &TEXTURE_2D_TEXTARGETS)),
+            None,
+        )?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+    pub(crate) fn oxidegl_framebuffer_texture3_d(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        textarget: TextureTarget,
+        texture: GLuint,
+        level: GLint,
+        zoffset: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_for_target(target)?;
+        let image = self.texture_attachment(
+            texture,
+            level,
+            Some((textarget, &[TextureTarget::Texture3D])),
+            Some(zoffset),
+        )?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+    pub(crate) fn oxidegl_framebuffer_texture(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        texture: GLuint,
+        level: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_for_target(target)?;
+        let image = self.texture_attachment(texture, level, None, None)?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+    pub(crate) fn oxidegl_named_framebuffer_texture(
+        &mut self,
+        framebuffer: GLuint,
+        attachment: FramebufferAttachment,
+        texture: GLuint,
+        level: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_by_name(framebuffer)?;
+        let image = self.texture_attachment(texture, level, None, None)?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the framebuffer is bound for [**glFramebufferTextureLayer**](crate::context::Context::oxidegl_framebuffer_texture_layer).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glNamedFramebufferTextureLayer**](crate::context::Context::oxidegl_named_framebuffer_texture_layer).
+///
+/// `attachment`
+///
+/// > Specifies the attachment point of the framebuffer.
+///
+/// `texture`
+///
+/// > Specifies the name of an existing texture object to attach.
+///
+/// `level`
+///
+/// > Specifies the mipmap level of the texture object to attach.
+///
+/// `layer`
+///
+/// > Specifies the layer of the texture object to attach.
+///
+/// ### Description
+/// [**glFramebufferTextureLayer**](crate::context::Context::oxidegl_framebuffer_texture_layer)
+/// and [**glNamedFramebufferTextureLayer**](crate::context::Context::oxidegl_named_framebuffer_texture_layer)
+/// attach a single layer of a three-dimensional or array texture object as
+/// one of the logical buffers of the specified framebuffer object. Textures
+/// cannot be attached to the default draw and read framebuffer, so they are
+/// not valid targets of these commands.
+///
+/// For [**glFramebufferTextureLayer**](crate::context::Context::oxidegl_framebuffer_texture_layer),
+/// the framebuffer object is that bound to `target`, which must be [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER),
+/// [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER), or [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER).
+/// [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER) is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+///
+/// For [**glNamedFramebufferTextureLayer**](crate::context::Context::oxidegl_named_framebuffer_texture_layer),
+/// `framebuffer` is the name of the framebuffer object.
+///
+/// `attachment` specifies the logical attachment of the framebuffer and must
+/// be [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i*, [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT),
+/// [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT) or [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT).
+/// *i* in [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i* may
+/// range from zero to the value of [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS)
+/// minus one. Attaching a level of a texture to [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT)
+/// is equivalent to attaching that level to both the [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT)
+/// *and* the [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT)
+/// attachment points simultaneously.
+///
+/// If `texture` is not zero, it must be the name of a three-dimensional, two-dimensional
+/// multisample array, one- or two-dimensional array, or cube map array texture.
+///
+/// If `texture` is a three-dimensional texture, then `level` must be greater
+/// than or equal to zero and less than or equal to $`log_2`$ of the value of
+/// [`GL_MAX_3D_TEXTURE_SIZE`](crate::enums::GL_MAX_3D_TEXTURE_SIZE).
+///
+/// If `texture` is a two-dimensional array texture, then `level` must be greater
+/// than or equal to zero and less than or equal to $`log_2`$ of the value of
+/// [`GL_MAX_TEXTURE_SIZE`](crate::enums::GL_MAX_TEXTURE_SIZE).
+///
+/// For cube map textures, `layer` is translated into a cube map face according
+/// to $$ face= k \bmod 6. $$ For cube map array textures, `layer` is translated
+/// into an array layer and face according to $$ layer= \left\lfloor{ layer
+/// \over 6} \right\rfloor$$ and $$ face= k \bmod 6. $$
+impl Context {
+    pub(crate) fn oxidegl_framebuffer_texture_layer(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        texture: GLuint,
+        level: GLint,
+        layer: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_for_target(target)?;
+        let image = self.texture_attachment(texture, level, None, Some(layer))?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+    pub(crate) fn oxidegl_named_framebuffer_texture_layer(
+        &mut self,
+        framebuffer: GLuint,
+        attachment: FramebufferAttachment,
+        texture: GLuint,
+        level: GLint,
+        layer: GLint,
+    ) -> GlFallible {
+        let fb = self.framebuffer_by_name(framebuffer)?;
+        let image = self.texture_attachment(texture, level, None, Some(layer))?;
+        self.framebuffer_attach(fb, attachment, image)
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the framebuffer object is bound for [**glGetFramebufferAttachmentParameteriv**](crate::context::Context::oxidegl_get_framebuffer_attachment_parameteriv).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glGetNamedFramebufferAttachmentParameteriv**](crate::context::Context::oxidegl_get_named_framebuffer_attachment_parameteriv).
+///
+/// `attachment`
+///
+/// > Specifies the attachment of the framebuffer object to query.
+///
+/// `pname`
+///
+/// > Specifies the parameter of `attachment` to query.
+///
+/// `params`
+///
+/// > Returns the value of parameter `pname` for `attachment`.
+///
+/// ### Description
+/// [**glGetFramebufferAttachmentParameteriv**](crate::context::Context::oxidegl_get_framebuffer_attachment_parameteriv)
+/// and [**glGetNamedFramebufferAttachmentParameteriv**](crate::context::Context::oxidegl_get_named_framebuffer_attachment_parameteriv)
+/// return parameters of attachments of a specified framebuffer object.
+///
+/// For [**glGetFramebufferAttachmentParameteriv**](crate::context::Context::oxidegl_get_framebuffer_attachment_parameteriv),
+/// the framebuffer object is that bound to `target`, which must be one of
+/// [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER), [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER)
+/// or [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER). [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER)
+/// is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+/// Buffers of default framebuffers may also be queried if bound to `target`.
+///
+/// For [**glGetNamedFramebufferAttachmentParameteriv**](crate::context::Context::oxidegl_get_named_framebuffer_attachment_parameteriv),
+/// `framebuffer` is the name of the framebuffer object. If `framebuffer` is
+/// zero, the default draw framebuffer is queried.
+///
+/// If the specified framebuffer is a framebuffer object, `attachment` must
+/// be one of [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT), [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT)
+/// [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT),
+/// or [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i*, where
+/// *i* is between zero and the value of [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS)
+/// minus one.
+///
+/// If the specified framebuffer is a default framebuffer, `target`, `attachment`
+/// must be one of [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT), [`GL_FRONT_RIGHT`](crate::enums::GL_FRONT_RIGHT),
+/// [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT), [`GL_BACK_RIGHT`](crate::enums::GL_BACK_RIGHT),
+/// [`GL_DEPTH`](crate::enums::GL_DEPTH) or [`GL_STENCIL`](crate::enums::GL_STENCIL),
+/// identifying the corresponding buffer.
+///
+/// If `attachment` is [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT),
+/// the same object must be bound to both the depth and stencil attachment
+/// points of the framebuffer object, and information about that object is
+/// returned.
+///
+/// Upon successful return, if `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE),
+/// then `params` will contain one of [`GL_NONE`](crate::enums::GL_NONE), [`GL_FRAMEBUFFER_DEFAULT`](crate::enums::GL_FRAMEBUFFER_DEFAULT),
+/// [`GL_TEXTURE`](crate::enums::GL_TEXTURE), or [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER),
+/// identifying the type of object which contains the attached image. Other
+/// values accepted for `pname` depend on the type of object, as described
+/// below.
+///
+/// If the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// is [`GL_NONE`](crate::enums::GL_NONE), then either no framebuffer is bound
+/// to `target`; or a default framebuffer is queried, `attachment` is [`GL_DEPTH`](crate::enums::GL_DEPTH)
+/// or [`GL_STENCIL`](crate::enums::GL_STENCIL), and the number of depth or
+/// stencil bits, respectively, is zero. In this case querying `pname` [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME)
+/// will return zero, and all other queries will generate an error.
+///
+/// If the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// is not [`GL_NONE`](crate::enums::GL_NONE), these queries apply to all other
+/// framebuffer types:
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_RED_SIZE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_RED_SIZE),
+/// > [`GL_FRAMEBUFFER_ATTACHMENT_GREEN_SIZE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_GREEN_SIZE),
+/// > [`GL_FRAMEBUFFER_ATTACHMENT_BLUE_SIZE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_BLUE_SIZE),
+/// > [`GL_FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE),
+/// > [`GL_FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE)
+/// > or [`GL_FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE),
+/// > then `params` will contain the number of bits in the corresponding red,
+/// > green, blue, alpha, depth, or stencil component of the specified attachment.
+/// > If the requested component is not present in the attachment, or if no data
+/// > storage or texture image has been specified for the attachment, then `params`
+/// > will contain zero.
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE),
+/// > then `params` will contain the format of components of the specified attachment,
+/// > one of [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_INT`](crate::enums::GL_INT),
+/// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_SIGNED_NORMALIZED`](crate::enums::GL_SIGNED_NORMALIZED),
+/// > or [`GL_UNSIGNED_NORMALIZED`](crate::enums::GL_UNSIGNED_NORMALIZED) for
+/// > floating-point, signed integer, unsigned integer, signed normalized fixed-point,
+/// > or unsigned normalized fixed-point components respectively. Only color
+/// > buffers may have integer components. If no data storage or texture image
+/// > has been specified for the attachment, then `params` will contain [`GL_NONE`](crate::enums::GL_NONE).
+/// > This query cannot be performed for a combined depth+stencil attachment,
+/// > since it does not have a single format.
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING),
+/// > then `params` will contain the encoding of components of the specified
+/// > attachment, one of [`GL_LINEAR`](crate::enums::GL_LINEAR) or [`GL_SRGB`](crate::enums::GL_SRGB)
+/// > for linear or sRGB-encoded components, respectively. Only color buffer
+/// > components may be sRGB-encoded; such components are treated as described
+/// > in the OpenGL Specification. For a default framebuffer, color encoding
+/// > is determined by the implementation. For framebuffer objects, components
+/// > are sRGB-encoded if the internal format of a color attachment is one of
+/// > the color-renderable SRGB formats. If the attachment is not a color attachment,
+/// > or if no data storage or texture image has been specified for the attachment,
+/// > then `params` will contain [`GL_LINEAR`](crate::enums::GL_LINEAR).
+///
+/// If the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// is [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER), then
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME),
+/// > `params` will contain the name of the renderbuffer object which contains
+/// > the attached image.
+///
+/// If the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE)
+/// is [`GL_TEXTURE`](crate::enums::GL_TEXTURE), then
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME),
+/// > then `params` will contain the name of the texture object which contains
+/// > the attached image.
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL),
+/// > then `params` will contain the mipmap level of the texture object which
+/// > contains the attached image.
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_CUBE_MAP_FACE`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_CUBE_MAP_FACE)
+/// > and the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME)
+/// > is the name of a cube map texture object, then `params` will contain the
+/// > cube map face of the cubemap texture object which contains the attached
+/// > image. Otherwise `params` will contain zero.
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_LAYERED`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_LAYERED),
+/// > then `params` will contain [`GL_TRUE`](crate::enums::GL_TRUE) if an entire
+/// > level of a three-dimensional texture, cube map texture, or one-or two-dimensional
+/// > array texture is attached. Otherwise, `params` will contain [`GL_FALSE`](crate::enums::GL_FALSE).
+///
+/// > If `pname` is [`GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LAYER`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_TEXTURE_LAYER);
+/// > the value of [`GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_OBJECT_NAME)
+/// > is the name of a three-dimensional, or a one- or two-dimensional array
+/// > texture; and the value of [`GL_FRAMEBUFFER_ATTACHMENT_LAYERED`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_LAYERED)
+/// > is [`GL_FALSE`](crate::enums::GL_FALSE), then `params` will contain the
+/// > texture layer which contains the attached image. Otherwise `params` will
+/// > contain zero.
+///
+/// ### Notes
+/// The [`GL_FRAMEBUFFER_ATTACHMENT_LAYERED`](crate::enums::GL_FRAMEBUFFER_ATTACHMENT_LAYERED)
+/// query is supported only if the GL version is 3.2 or greater.
+impl Context {
+    pub(crate) unsafe fn oxidegl_get_framebuffer_attachment_parameteriv(
+        &mut self,
+        target: FramebufferTarget,
+        attachment: FramebufferAttachment,
+        pname: FramebufferAttachmentParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let fb = self.bound_framebuffer(target);
+        // Safety: caller ensures params is valid for writes of a GLint
+        unsafe { self.get_framebuffer_attachment_parameter_internal(fb, attachment, pname, params) }
+    }
+    pub(crate) unsafe fn oxidegl_get_named_framebuffer_attachment_parameteriv(
+        &mut self,
+        framebuffer: GLuint,
+        attachment: FramebufferAttachment,
+        pname: FramebufferAttachmentParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let fb = if framebuffer == 0 {
+            None
+        } else {
+            Some(self.framebuffer_by_name(framebuffer)?)
+        };
+        // Safety: caller ensures params is valid for writes of a GLint
+        unsafe { self.get_framebuffer_attachment_parameter_internal(fb, attachment, pname, params) }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the framebuffer target of the binding operation.
+    ///
+    /// `framebuffer`
+    ///
+    /// > Specifies the name of the framebuffer object to bind.
+    ///
+    /// ### Description
+    /// [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
+    /// binds the framebuffer object with name `framebuffer` to the framebuffer
+    /// target specified by `target`. `target` must be either [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER),
+    /// [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER) or [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER).
+    /// If a framebuffer object is bound to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER)
+    /// or [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER), it becomes
+    /// the target for rendering or readback operations, respectively, until it
+    /// is deleted or another framebuffer is bound to the corresponding bind point.
+    /// Calling [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
+    /// with `target` set to [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER) binds
+    /// `framebuffer` to both the read and draw framebuffer targets. `framebuffer`
+    /// is the name of a framebuffer object previously returned from a call to
+    /// [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers),
+    /// or zero to break the existing binding of a framebuffer object to `target`.
+    pub(crate) fn oxidegl_bind_framebuffer(
+        &mut self,
+        target: FramebufferTarget,
+        framebuffer: GLuint,
+    ) -> GlFallible {
+        let name = ObjectName::try_from_raw(framebuffer).ok();
+        if let Some(name) = name {
+            self.gl_state
+                .framebufer_list
+                .ensure_init(name, Framebuffer::new_default)?;
+        }
+        if target != FramebufferTarget::ReadFramebuffer {
+            run_if_changed!(self.gl_state.draw_framebuffer_binding;= name => {
+                self.new_encoder();
+                self.new_pipeline();
+            });
+        }
+        if target != FramebufferTarget::DrawFramebuffer {
+            self.gl_state.read_framebuffer_binding = name;
+        }
+        gl_debug!("bound {name:?} to {target:?}");
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Number of framebuffer objects to create.
+    ///
+    /// `framebuffers`
+    ///
+    /// > Specifies an array in which names of the new framebuffer objects are stored.
+    ///
+    /// ### Description
+    /// [**glCreateFramebuffers**](crate::context::Context::oxidegl_create_framebuffers)
+    /// returns `n` previously unused framebuffer names in `framebuffers`, each
+    /// representing a new framebuffer object initialized to the default state.
+    pub(crate) unsafe fn oxidegl_create_framebuffers(
+        &mut self,
+        n: GLsizei,
+        framebuffers: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state
+                .framebufer_list
+                .create_obj(Framebuffer::new_default, n, framebuffers);
+        }
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of framebuffer objects to be deleted.
+    ///
+    /// `framebuffers`
+    ///
+    /// > A pointer to an array containing `n` framebuffer objects to be deleted.
+    ///
+    /// ### Description
+    /// [**glDeleteFramebuffers**](crate::context::Context::oxidegl_delete_framebuffers)
+    /// deletes the `n` framebuffer objects whose names are stored in the array
+    /// addressed by `framebuffers`. The name zero is reserved by the GL and is
+    /// silently ignored, should it occur in `framebuffers`, as are other unused
+    /// names. Once a framebuffer object is deleted, its name is again unused and
+    /// it has no attachments. If a framebuffer that is currently bound to one
+    /// or more of the targets [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER)
+    /// or [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER) is deleted,
+    /// it is as though [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
+    /// had been executed with the corresponding `target` and `framebuffer` zero.
+    pub(crate) unsafe fn oxidegl_delete_framebuffers(
+        &mut self,
+        n: GLsizei,
+        framebuffers: *const GLuint,
+    ) -> GlFallible {
+        sizei!(n, count);
+        if count > 0 {
+            // Safety: caller ensures that n and framebuffers form a valid u32 slice
+            for &name in unsafe { slice::from_raw_parts(framebuffers, count as usize) } {
+                if let Ok(name) = ObjectName::try_from_raw(name) {
+                    self.unbind_framebuffer_everywhere(name);
+                }
+            }
+        }
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state
+                .framebufer_list
+                .delete_objects(n, framebuffers);
+        }
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of framebuffer object names to generate.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array in which the generated framebuffer object names are
+    /// > stored.
+    ///
+    /// ### Description
+    /// [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
+    /// returns `n` framebuffer object names in `ids`. There is no guarantee that
+    /// the names form a contiguous set of integers; however, it is guaranteed
+    /// that none of the returned names was in use immediately before the call
+    /// to [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers).
+    ///
+    /// Framebuffer object names returned by a call to [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteFramebuffers**](crate::context::Context::oxidegl_delete_framebuffers).
+    ///
+    /// The names returned in `ids` are marked as used, for the purposes of [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
+    /// only, but they acquire state and type only when they are first bound.
+    pub(crate) unsafe fn oxidegl_gen_framebuffers(
+        &mut self,
+        n: GLsizei,
+        framebuffers: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller ensures validity
+        unsafe { self.gl_state.framebufer_list.gen_obj(n, framebuffers) };
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `framebuffer`
+    ///
+    /// > Specifies a value that may be the name of a framebuffer object.
+    ///
+    /// ### Description
+    /// [**glIsFramebuffer**](crate::context::Context::oxidegl_is_framebuffer)
+    /// returns [`GL_TRUE`](crate::enums::GL_TRUE) if `framebuffer` is currently
+    /// the name of a framebuffer object. If `framebuffer` is zero, or if [`framebuffer`](crate::enums::framebuffer)
+    /// is not the name of a framebuffer object, or if an error occurs, [**glIsFramebuffer**](crate::context::Context::oxidegl_is_framebuffer)
+    /// returns [`GL_FALSE`](crate::enums::GL_FALSE). If `framebuffer` is a name
+    /// returned by [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers),
+    /// by that has not yet been bound through a call to [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer),
+    /// then the name is not a framebuffer object and [**glIsFramebuffer**](crate::context::Context::oxidegl_is_framebuffer)
+    /// returns [`GL_FALSE`](crate::enums::GL_FALSE).
+    pub(crate) fn oxidegl_is_framebuffer(&mut self, framebuffer: GLuint) -> GLboolean {
+        self.gl_state.framebufer_list.is_obj(framebuffer)
+    }
+}
+
+impl Context {
+    /// Returns the framebuffer object bound to `target`, or None if the default framebuffer is bound to it
+    pub(crate) fn bound_framebuffer(
+        &self,
+        target: FramebufferTarget,
+    ) -> Option<ObjectName<Framebuffer>> {
+        match target {
+            FramebufferTarget::ReadFramebuffer => self.gl_state.read_framebuffer_binding,
+            FramebufferTarget::DrawFramebuffer | FramebufferTarget::Framebuffer => {
+                self.gl_state.draw_framebuffer_binding
+            }
+        }
+    }
+    /// Returns the framebuffer object bound to `target` for use by non-DSA framebuffer commands, returning an
+    /// `InvalidOperation` error if the default framebuffer is bound to it
+    fn framebuffer_for_target(
+        &self,
+        target: FramebufferTarget,
+    ) -> GlFallible<ObjectName<Framebuffer>> {
+        self.bound_framebuffer(target).ok_or_else(|| {
+            gl_err!(ty: Error, "the attachments of the default framebuffer (bound to {target:?}) can't be modified");
+            GlError::InvalidOperation.e()
+        })
+    }
+    /// Resolves the raw framebuffer name passed to a DSA framebuffer command, returning an `InvalidOperation` error if it does
+    /// not name an existing framebuffer object
+    pub(crate) fn framebuffer_by_name(
+        &self,
+        framebuffer: GLuint,
+    ) -> GlFallible<ObjectName<Framebuffer>> {
+        ObjectName::try_from_raw(framebuffer)
+            .ok()
+            .filter(|&name| self.gl_state.framebufer_list.is(name))
+            .ok_or_else(|| {
+                gl_err!(ty: Error, "{framebuffer} does not name an existing framebuffer object");
+                GlError::InvalidOperation.e()
+            })
+    }
+    /// Breaks every binding of the framebuffer `name` (e.g. because it is about to be deleted), reverting to the default framebuffer
+    fn unbind_framebuffer_everywhere(&mut self, name: ObjectName<Framebuffer>) {
+        if self.gl_state.draw_framebuffer_binding == Some(name) {
+            self.gl_state.draw_framebuffer_binding = None;
+            self.new_encoder();
+            self.new_pipeline();
+        }
+        if self.gl_state.read_framebuffer_binding == Some(name) {
+            self.gl_state.read_framebuffer_binding = None;
+        }
+    }
+    /// Detaches every attachment for which `detach` returns true from the currently bound framebuffer objects, as happens when
+    /// the attached texture or renderbuffer is deleted. Framebuffers that aren't bound keep their attachments
+    pub(crate) fn detach_from_bound_framebuffers(&mut self, detach: impl Fn(&Attachment) -> bool) {
+        let draw = self.gl_state.draw_framebuffer_binding;
+        for fb in [draw, self.gl_state.read_framebuffer_binding]
+            .into_iter()
+            .flatten()
+        {
+            if self
+                .gl_state
+                .framebufer_list
+                .get_mut(fb)
+                .detach_where(&detach)
+                && Some(fb) == draw
+            {
+                self.new_encoder();
+                self.new_pipeline();
+            }
+        }
+    }
+    /// Attaches `image` to (or, if it is None, detaches the image attached to) `attachment` of the framebuffer object `fb`
+    fn framebuffer_attach(
+        &mut self,
+        fb: ObjectName<Framebuffer>,
+        attachment: FramebufferAttachment,
+        image: Option<Attachment>,
+    ) -> GlFallible {
+        let Some(point) = AttachmentPoint::new(attachment) else {
+            gl_err!(ty: Error, "{attachment:?} exceeds GL_MAX_COLOR_ATTACHMENTS ({MAX_COLOR_ATTACHMENTS})");
+            return Err(GlError::InvalidOperation.e());
+        };
+        self.gl_state
+            .framebufer_list
+            .get_mut(fb)
+            .attach(point, image);
+        gl_debug!("attached {image:?} to {attachment:?} of {fb:?}");
+        if self.gl_state.draw_framebuffer_binding == Some(fb) {
+            self.new_encoder();
+            self.new_pipeline();
+        }
+        Ok(())
+    }
+    /// Shared implementation of glFramebufferRenderbuffer and glNamedFramebufferRenderbuffer
+    fn framebuffer_renderbuffer_internal(
+        &mut self,
+        fb: ObjectName<Framebuffer>,
+        attachment: FramebufferAttachment,
+        renderbuffertarget: GLenum,
+        renderbuffer: GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            renderbuffertarget == GL_RENDERBUFFER,
+            InvalidEnum,
+            "{renderbuffertarget:#X} is not GL_RENDERBUFFER"
+        );
+        let image = if renderbuffer == 0 {
+            None
+        } else {
+            Some(Attachment::renderbuffer(
+                self.renderbuffer_by_name(renderbuffer)?,
+            ))
+        };
+        self.framebuffer_attach(fb, attachment, image)
+    }
+    /// Validates the texture image selected by the parameters of a glFramebufferTexture* command and describes it as a framebuffer
+    /// attachment, or returns None if `texture` is 0 (detaching the current image). `textarget` is the texture target passed to
+    /// glFramebufferTexture1D/2D/3D and the targets that command accepts, and `layer` the layer (or 3D texture z offset) passed
+    /// to commands that attach a single layer
+    fn texture_attachment(
+        &self,
+        texture: GLuint,
+        level: GLint,
+        textarget: Option<(TextureTarget, &[TextureTarget])>,
+        layer: Option<GLint>,
+    ) -> GlFallible<Option<Attachment>> {
+        if let Some((textarget, accepted)) = textarget {
+            gl_assert!(
+                accepted.contains(&textarget),
+                InvalidEnum,
+                "{textarget:?} is not an accepted texture target for this command"
+            );
+        }
+        if texture == 0 {
+            return Ok(None);
+        }
+        let name = self.texture_by_name(texture)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        gl_assert!(
+            target != TextureTarget::TextureBuffer,
+            InvalidOperation,
+            "buffer textures can't be attached to a framebuffer"
+        );
+        let face = textarget.and_then(|(textarget, _)| textarget.cube_face());
+        if let Some((textarget, _)) = textarget {
+            let expected = if face.is_some() {
+                TextureTarget::TextureCubeMap
+            } else {
+                textarget
+            };
+            gl_assert!(
+                target == expected,
+                InvalidOperation,
+                "{name:?} has target {target:?}, which does not match {textarget:?}"
+            );
+        }
+        sizei!(level);
+        let max_size = match target {
+            TextureTarget::Texture3D => MAX_3D_TEXTURE_SIZE,
+            _ => MAX_TEXTURE_SIZE,
+        };
+        let max_levels = if matches!(
+            target,
+            TextureTarget::TextureRectangle
+                | TextureTarget::Texture2DMultisample
+                | TextureTarget::Texture2DMultisampleArray
+        ) {
+            1
+        } else {
+            u32::BITS - max_size.leading_zeros()
+        };
+        gl_assert!(
+            level < max_levels,
+            InvalidValue,
+            "{level} is not a valid mipmap level of {target:?} textures"
+        );
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "cube map face indices are less than 6"
+        )]
+        let layer = match (face, layer) {
+            (Some(face), _) => Some(face as u32),
+            (None, Some(layer)) => {
+                let max_layers = match target {
+                    TextureTarget::Texture3D => MAX_3D_TEXTURE_SIZE,
+                    TextureTarget::Texture1DArray
+                    | TextureTarget::Texture2DArray
+                    | TextureTarget::TextureCubeMapArray
+                    | TextureTarget::Texture2DMultisampleArray => MAX_ARRAY_TEXTURE_LAYERS,
+                    TextureTarget::TextureCubeMap => 6,
+                    _ => {
+                        gl_err!(ty: Error, "single layers of {target:?} textures can't be attached to a framebuffer");
+                        return Err(GlError::InvalidOperation.e());
+                    }
+                };
+                sizei!(layer);
+                gl_assert!(
+                    layer < max_layers,
+                    InvalidValue,
+                    "{layer} is not a valid layer of {target:?} textures"
+                );
+                Some(layer)
+            }
+            (None, None) => match target {
+                TextureTarget::Texture3D
+                | TextureTarget::Texture1DArray
+                | TextureTarget::Texture2DArray
+                | TextureTarget::TextureCubeMap
+                | TextureTarget::TextureCubeMapArray
+                | TextureTarget::Texture2DMultisampleArray => None,
+                _ => Some(0),
+            },
+        };
+        Ok(Some(Attachment::texture(target, name, level, layer)))
+    }
+    /// Evaluates the image attached by `attachment` for the purposes of framebuffer completeness
+    fn attached_image(&self, attachment: Option<&Attachment>) -> AttachmentImage {
+        let Some(attachment) = attachment else {
+            return AttachmentImage::None;
+        };
+        attachment
+            .object(&self.gl_state)
+            .map_or(AttachmentImage::Incomplete, |obj| {
+                obj.attached_image(attachment.level, attachment.layer)
+            })
+    }
+    /// Determines the completeness status of the framebuffer object `fb` (see [`framebuffer_status`])
+    pub(crate) fn framebuffer_completeness(&self, fb: ObjectName<Framebuffer>) -> GLenum {
+        let framebuffer = self.gl_state.framebufer_list.get(fb);
+        let images = FramebufferImages {
+            color: framebuffer
+                .color_attachments
+                .each_ref()
+                .map(|a| self.attached_image(a.as_ref())),
+            depth: self.attached_image(framebuffer.depth_attachment.as_ref()),
+            stencil: self.attached_image(framebuffer.stencil_attachment.as_ref()),
+            shared_depth_stencil: framebuffer
+                .depth_attachment
+                .zip(framebuffer.stencil_attachment)
+                .is_some_and(|(depth, stencil)| depth.same_image(&stencil)),
+        };
+        let status = framebuffer_status(&images);
+        gl_trace!("{fb:?} has completeness status {status:#X}");
+        status
+    }
+    /// Returns an `InvalidFramebufferOperation` error if the framebuffer object `fb` is not complete
+    pub(crate) fn assert_framebuffer_complete(&self, fb: ObjectName<Framebuffer>) -> GlFallible {
+        let status = self.framebuffer_completeness(fb);
+        gl_assert!(
+            status == GL_FRAMEBUFFER_COMPLETE,
+            InvalidFramebufferOperation,
+            "{fb:?} is not complete (status {status:#X})"
+        );
+        Ok(())
+    }
+    /// Prepares the current draw framebuffer for rendering. Returns an `InvalidFramebufferOperation` error if it is an incomplete
+    /// framebuffer object, and otherwise realizes the storage of the textures attached to it, starting a new render pass if any
+    /// of that storage was reallocated
+    pub(crate) fn prepare_draw_framebuffer(&mut self) -> GlFallible {
+        let Some(fb) = self.gl_state.draw_framebuffer_binding else {
+            return Ok(());
+        };
+        self.assert_framebuffer_complete(fb)?;
+        if self.realize_framebuffer_storage(fb) {
+            self.new_encoder();
+            self.new_pipeline();
+        }
+        Ok(())
+    }
+    /// Realizes the storage of the textures attached to the framebuffer object `fb`, returning whether any of it was reallocated
+    pub(crate) fn realize_framebuffer_storage(&mut self, fb: ObjectName<Framebuffer>) -> bool {
+        let textures: Vec<_> = self
+            .gl_state
+            .framebufer_list
+            .get(fb)
+            .attachments()
+            .filter_map(|(_, a)| a.texture_name())
+            .collect();
+        let mut reallocated = false;
+        for name in textures {
+            let storage = |ctx: &Self| {
+                ctx.gl_state
+                    .texture_list
+                    .get(name)
+                    .realized
+                    .as_ref()
+                    .map(|r| Retained::as_ptr(&r.mtl_tex))
+            };
+            let before = storage(self);
+            self.realize_texture(name);
+            reallocated |= storage(self) != before;
+        }
+        reallocated
+    }
+    /// Shared implementation of glGetFramebufferAttachmentParameteriv and glGetNamedFramebufferAttachmentParameteriv
+    /// # Safety
+    /// `params` must be valid for writes of a `GLint`
+    unsafe fn get_framebuffer_attachment_parameter_internal(
+        &self,
+        fb: Option<ObjectName<Framebuffer>>,
+        attachment: FramebufferAttachment,
+        pname: FramebufferAttachmentParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        use FramebufferAttachmentParameterName as P;
+        let Some(fb) = fb else {
+            gl_err!(ty: Error, "OxideGL does not support querying the attachments of the default framebuffer");
+            return Err(GlError::InvalidOperation.e());
+        };
+        let Some(point) = AttachmentPoint::new(attachment) else {
+            gl_err!(ty: Error, "{attachment:?} exceeds GL_MAX_COLOR_ATTACHMENTS ({MAX_COLOR_ATTACHMENTS})");
+            return Err(GlError::InvalidOperation.e());
+        };
+        let framebuffer = self.gl_state.framebufer_list.get(fb);
+        if point == AttachmentPoint::DepthStencil {
+            let shared = match (framebuffer.depth_attachment, framebuffer.stencil_attachment) {
+                (Some(depth), Some(stencil)) => depth.same_image(&stencil),
+                (None, None) => true,
+                _ => false,
+            };
+            gl_assert!(
+                shared,
+                InvalidOperation,
+                "the depth and stencil attachments of {fb:?} are different images"
+            );
+        }
+        let attached = framebuffer.attachment(point).copied();
+        let value = match (pname, attached) {
+            (P::FramebufferAttachmentObjectType, None) => GL_NONE,
+            (P::FramebufferAttachmentObjectType, Some(a)) => {
+                if a.target == TextureTarget::Renderbuffer {
+                    GL_RENDERBUFFER
+                } else {
+                    GL_TEXTURE
+                }
+            }
+            (P::FramebufferAttachmentObjectName, a) => a.map_or(0, |a| a.tex_name.to_raw()),
+            (_, None) => {
+                gl_err!(ty: Error, "can't query {pname:?} of {attachment:?} of {fb:?}, since nothing is attached to it");
+                return Err(GlError::InvalidOperation.e());
+            }
+            (
+                P::FramebufferAttachmentTextureLevel
+                | P::FramebufferAttachmentTextureCubeMapFace
+                | P::FramebufferAttachmentTextureLayer
+                | P::FramebufferAttachmentLayered,
+                Some(a),
+            ) if a.target == TextureTarget::Renderbuffer => {
+                gl_err!(ty: Error, "can't query {pname:?} of {attachment:?} of {fb:?}, since a renderbuffer is attached to it");
+                return Err(GlError::InvalidEnum.e());
+            }
+            (P::FramebufferAttachmentTextureLevel, Some(a)) => a.level,
+            (P::FramebufferAttachmentTextureCubeMapFace, Some(a)) => match a.layer {
+                Some(face) if a.target == TextureTarget::TextureCubeMap => {
+                    GL_TEXTURE_CUBE_MAP_POSITIVE_X + face
+                }
+                _ => 0,
+            },
+            (P::FramebufferAttachmentTextureLayer, Some(a)) => match a.target {
+                TextureTarget::Texture3D
+                | TextureTarget::Texture1DArray
+                | TextureTarget::Texture2DArray
+                | TextureTarget::TextureCubeMapArray
+                | TextureTarget::Texture2DMultisampleArray => a.layer.unwrap_or(0),
+                _ => 0,
+            },
+            (P::FramebufferAttachmentLayered, Some(a)) => u32::from(a.layer.is_none()),
+            (pname, Some(a)) => {
+                // the remaining queries describe the format of the attached image
+                let format = a
+                    .object(&self.gl_state)
+                    .and_then(|obj| obj.image_format(a.level, a.layer));
+                let sizes = format.map_or([0; 6], InternalFormat::component_sizes);
+                match pname {
+                    P::FramebufferAttachmentRedSize => sizes[0],
+                    P::FramebufferAttachmentGreenSize => sizes[1],
+                    P::FramebufferAttachmentBlueSize => sizes[2],
+                    P::FramebufferAttachmentAlphaSize => sizes[3],
+                    P::FramebufferAttachmentDepthSize => sizes[4],
+                    P::FramebufferAttachmentStencilSize => sizes[5],
+                    P::FramebufferAttachmentComponentType => {
+                        gl_assert!(
+                            point != AttachmentPoint::DepthStencil,
+                            InvalidOperation,
+                            "GL_FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE can't be queried for GL_DEPTH_STENCIL_ATTACHMENT"
+                        );
+                        match format {
+                            None => GL_NONE,
+                            Some(_) if point == AttachmentPoint::Stencil => GL_UNSIGNED_INT,
+                            Some(format) => format.component_type(),
+                        }
+                    }
+                    P::FramebufferAttachmentColorEncoding => {
+                        if format.is_some_and(InternalFormat::is_srgb) {
+                            GL_SRGB
+                        } else {
+                            GL_LINEAR
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        };
+        gl_trace!("{pname:?} of {attachment:?} of {fb:?} is {value:#X}");
+        // Safety: caller ensures params is valid for writes of a GLint
+        unsafe { value.write_out(params) };
+        Ok(())
+    }
+    /// Sets the read buffer of the framebuffer object `fb`, which may be `GL_NONE` or one of its color attachments
+    fn framebuffer_read_buffer_internal(
+        &mut self,
+        fb: ObjectName<Framebuffer>,
+        src: ReadBufferMode,
+    ) -> GlFallible {
+        if src != ReadBufferMode::None {
+            let raw = u32::from(src);
+            gl_assert!(
+                (GL_COLOR_ATTACHMENT0..=GL_COLOR_ATTACHMENT31).contains(&raw),
+                InvalidOperation,
+                "framebuffer objects can't read from {src:?}"
+            );
+            gl_assert!(
+                color_attachment_index(raw).is_some(),
+                InvalidValue,
+                "{src:?} exceeds GL_MAX_COLOR_ATTACHMENTS ({MAX_COLOR_ATTACHMENTS})"
+            );
+        }
+        self.gl_state.framebufer_list.get_mut(fb).read_buffer = src;
+        gl_debug!("set the read buffer of {fb:?} to {src:?}");
+        Ok(())
+    }
+    /// Sets the read buffer of the default framebuffer
+    fn default_read_buffer_internal(&mut self, src: ReadBufferMode) -> GlFallible {
+        gl_assert!(
+            !matches!(
+                src,
+                ReadBufferMode::ColorAttachment0
+                    | ReadBufferMode::ColorAttachment1
+                    | ReadBufferMode::ColorAttachment2
+                    | ReadBufferMode::ColorAttachment3
+                    | ReadBufferMode::ColorAttachment4
+                    | ReadBufferMode::ColorAttachment5
+                    | ReadBufferMode::ColorAttachment6
+                    | ReadBufferMode::ColorAttachment7
+                    | ReadBufferMode::ColorAttachment8
+                    | ReadBufferMode::ColorAttachment9
+                    | ReadBufferMode::ColorAttachment10
+                    | ReadBufferMode::ColorAttachment11
+                    | ReadBufferMode::ColorAttachment12
+                    | ReadBufferMode::ColorAttachment13
+                    | ReadBufferMode::ColorAttachment14
+                    | ReadBufferMode::ColorAttachment15
+            ),
+            InvalidOperation,
+            "the default framebuffer has no color attachments (tried to read from {src:?})"
+        );
+        self.gl_state.default_read_buffer = src;
+        Ok(())
+    }
+    /// Shared implementation of glReadPixels and glReadnPixels. Reads the `width` by `height` rectangle whose lower left corner is
+    /// at window coordinates `origin` from the current read framebuffer
+    /// # Safety
+    /// `pixels` must be null, valid for writes of the packed image (of at most `buf_size` bytes, if specified), or an offset into
+    /// the pixel pack buffer if one is bound
+    unsafe fn read_pixels_internal(
+        &mut self,
+        origin: [GLint; 2],
+        width: GLsizei,
+        height: GLsizei,
+        fmt: GlPixelTypeFormat,
+        buf_size: Option<usize>,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        let Some(region) = self.read_framebuffer_region(fmt.format(), origin, width, height)?
+        else {
+            return Ok(());
+        };
+        let image = TextureExtent::new(width, height, 1);
+        // Safety: caller upholds the safety requirements of read_back_internal
+        unsafe { self.read_back_internal(&region, image, 2, fmt, buf_size, pixels) }
+    }
+    /// Selects the image of the current read framebuffer that holds the data of client pixel format `format` (its depth, stencil or
+    /// read color buffer) and clips the `width` by `height` rectangle whose lower left corner is at window coordinates `origin` to it.
+    /// The returned region's client offset is the offset of the clipped rectangle within the requested one. Returns None if the image
+    /// can't be read from
+    pub(crate) fn read_framebuffer_region(
+        &mut self,
+        format: PixelFormat,
+        origin: [GLint; 2],
+        width: u32,
+        height: u32,
+    ) -> GlFallible<Option<ReadbackRegion>> {
+        if let Some(fb) = self.gl_state.read_framebuffer_binding {
+            return self.read_framebuffer_object_region(fb, format, origin, width, height);
+        }
+        let platform = &mut self.platform_state;
+        let drawable_tex = platform.current_drawable().texture();
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "drawable dimensions fit in a u32"
+        )]
+        let dims = (drawable_tex.width() as u32, drawable_tex.height() as u32);
+        let tex = match format {
+            PixelFormat::DepthComponent | PixelFormat::DepthStencil => {
+                gl_assert!(
+                    platform.depth_format.is_some(),
+                    InvalidOperation,
+                    "the default framebuffer has no depth buffer"
+                );
+                platform.get_internal_depthbuffer(dims).tex.clone()
+            }
+            PixelFormat::StencilIndex => {
+                gl_assert!(
+                    platform.stencil_format.is_some(),
+                    InvalidOperation,
+                    "the default framebuffer has no stencil buffer"
+                );
+                platform.get_internal_stencilbuffer(dims).tex.clone()
+            }
+            _ => {
+                let Some(mode) = default_color_buffer(self.gl_state.default_read_buffer) else {
+                    gl_err!(ty: Error, "cannot read color data from the default framebuffer when its read buffer is GL_NONE");
+                    return Err(GlError::InvalidOperation.e());
+                };
+                platform.default_color_buffer(mode, dims)
+            }
+        };
+        let Some(format) = InternalFormat::from_drawable_format(tex.pixelFormat()) else {
+            gl_err!(ty: Error, "OxideGL does not support reading from a default framebuffer buffer of Metal pixel format {:?}", tex.pixelFormat());
+            return Ok(None);
+        };
+        // unlike those of framebuffer objects, the images of a multisampled default framebuffer are resolved when read from
+        let tex = if tex.sampleCount() > 1 {
+            platform.resolve_texture(&tex)
+        } else {
+            tex
+        };
+
+        let clip = ReadbackClip::new(origin, width, height, dims);
+        // the default framebuffer's images are stored upside down
+        let region = ReadbackRegion {
+            tex,
+            format,
+            level: 0,
+            slices: 0..1,
+            origin: MTLOrigin {
+                x: clip.origin[0] as usize,
+                y: (dims.1 - clip.origin[1] - clip.extent.height) as usize,
+                z: 0,
+            },
+            size: clip.size(),
+            extent: clip.extent,
+            client_offset: clip.client_offset,
+            flip_y: true,
+        };
+        Ok(Some(region))
+    }
+    /// Implementation of [`read_framebuffer_region`](Self::read_framebuffer_region) for framebuffer objects. Unlike the default
+    /// framebuffer, framebuffer objects store their images in GL row order
+    fn read_framebuffer_object_region(
+        &mut self,
+        fb: ObjectName<Framebuffer>,
+        format: PixelFormat,
+        origin: [GLint; 2],
+        width: u32,
+        height: u32,
+    ) -> GlFallible<Option<ReadbackRegion>> {
+        self.assert_framebuffer_complete(fb)?;
+        let framebuffer = self.gl_state.framebufer_list.get(fb);
+        let attachment = match format {
+            PixelFormat::DepthComponent => framebuffer.depth_attachment,
+            PixelFormat::StencilIndex => framebuffer.stencil_attachment,
+            PixelFormat::DepthStencil => {
+                gl_assert!(
+                    framebuffer
+                        .depth_attachment
+                        .zip(framebuffer.stencil_attachment)
+                        .is_some_and(|(depth, stencil)| depth.same_image(&stencil)),
+                    InvalidOperation,
+                    "GL_DEPTH_STENCIL can only be read from framebuffers whose depth and stencil attachments are the same image"
+                );
+                framebuffer.depth_attachment
+            }
+            _ => {
+                let Some(idx) = color_attachment_index(framebuffer.read_buffer.into()) else {
+                    gl_err!(ty: Error, "cannot read color data from {fb:?} when its read buffer is GL_NONE");
+                    return Err(GlError::InvalidOperation.e());
+                };
+                framebuffer.color_attachments[idx as usize]
+            }
+        };
+        let Some(attachment) = attachment else {
+            gl_err!(ty: Error, "{fb:?} has no image attached to read {format:?} from");
+            return Err(GlError::InvalidOperation.e());
+        };
+        if let Some(name) = attachment.texture_name() {
+            self.realize_texture(name);
+        }
+        let Some(storage) = attachment.storage(&self.gl_state) else {
+            gl_warn!(ty: UndefinedBehavior, "read from an image of {fb:?} that has no storage (it was never rendered to or uploaded)");
+            return Ok(None);
+        };
+        gl_assert!(
+            storage.samples <= 1,
+            InvalidOperation,
+            "can't read from the multisample images of {fb:?}, resolve them with glBlitFramebuffer first"
+        );
+        let clip = ReadbackClip::new(origin, width, height, (storage.width, storage.height));
+        let (slices, z) = if storage.depth_plane {
+            (0..1, storage.slice as usize)
+        } else {
+            let slice = storage.slice as usize;
+            (slice..slice + 1, 0)
+        };
+        let region = ReadbackRegion {
+            tex: storage.tex,
+            format: storage.format,
+            level: storage.level as usize,
+            slices,
+            origin: MTLOrigin {
+                x: clip.origin[0] as usize,
+                y: clip.origin[1] as usize,
+                z,
+            },
+            size: clip.size(),
+            extent: clip.extent,
+            client_offset: clip.client_offset,
+            flip_y: false,
+        };
+        Ok(Some(region))
+    }
+    /// Returns the number of samples per pixel of the images of the draw framebuffer (`GL_SAMPLES`), which is 0 if they aren't
+    /// multisampled
+    pub(crate) fn draw_framebuffer_samples(&self) -> u32 {
+        let state = &self.gl_state;
+        let samples = match state.draw_framebuffer_binding {
+            // completeness guarantees that every attached image has the same number of samples
+            Some(fb) => state
+                .framebufer_list
+                .get(fb)
+                .attachments()
+                .find_map(|(_, a)| a.storage(state))
+                .map_or(1, |storage| storage.samples),
+            None => self.platform_state.samples,
+        };
+        if samples > 1 { samples } else { 0 }
+    }
+}
+
+/// Describes the texture `tex` that backs a buffer of the default framebuffer (of size `dims`) as attachment storage. Returns None
+/// if no internal format describes its Metal pixel format
+pub(crate) fn default_framebuffer_storage(
+    tex: ProtoObjRef<dyn MTLTexture>,
+    dims: (u32, u32),
+) -> Option<AttachmentStorage> {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "sample counts are at most 16"
+    )]
+    let samples = tex.sampleCount() as u32;
+    Some(AttachmentStorage {
+        format: InternalFormat::from_drawable_format(tex.pixelFormat())?,
+        tex,
+        level: 0,
+        slice: 0,
+        depth_plane: false,
+        width: dims.0,
+        height: dims.1,
+        layers: 1,
+        samples,
+    })
+}
+/// Returns the color buffer of the default framebuffer that the read buffer `mode` selects, or None for `GL_NONE`
+fn default_color_buffer(mode: ReadBufferMode) -> Option<DrawBufferMode> {
+    match mode {
+        ReadBufferMode::FrontLeft | ReadBufferMode::Front | ReadBufferMode::Left => {
+            Some(DrawBufferMode::FrontLeft)
+        }
+        ReadBufferMode::FrontRight | ReadBufferMode::Right => Some(DrawBufferMode::FrontRight),
+        ReadBufferMode::BackLeft | ReadBufferMode::Back => Some(DrawBufferMode::BackLeft),
+        ReadBufferMode::BackRight => Some(DrawBufferMode::BackRight),
+        ReadBufferMode::None => None,
+        // color attachments are rejected when setting the read buffer of the default framebuffer
+        _ => unreachable!(),
+    }
+}
+
+/// A readback rectangle clipped to the bounds of a framebuffer image. Client pixels outside of the image are left untouched
+struct ReadbackClip {
+    /// Lower left corner of the clipped rectangle, in window coordinates
+    origin: [u32; 2],
+    extent: TextureExtent,
+    /// Offset (in pixels and rows) of the clipped rectangle within the requested one
+    client_offset: [u32; 2],
+}
+impl ReadbackClip {
+    #[expect(
+        clippy::cast_sign_loss,
+        clippy::cast_possible_truncation,
+        reason = "values are clamped to the image's dimensions"
+    )]
+    fn new(origin: [GLint; 2], width: u32, height: u32, dims: (u32, u32)) -> Self {
+        let [x, y] = origin.map(i64::from);
+        let (fb_width, fb_height) = (i64::from(dims.0), i64::from(dims.1));
+        let x0 = x.clamp(0, fb_width);
+        let x1 = (x + i64::from(width)).clamp(x0, fb_width);
+        let y0 = y.clamp(0, fb_height);
+        let y1 = (y + i64::from(height)).clamp(y0, fb_height);
+        Self {
+            origin: [x0 as u32, y0 as u32],
+            extent: TextureExtent::new((x1 - x0) as u32, (y1 - y0) as u32, 1),
+            client_offset: [(x0 - x) as u32, (y0 - y) as u32],
+        }
+    }
+    fn size(&self) -> MTLSize {
+        MTLSize {
+            width: self.extent.width as usize,
+            height: self.extent.height as usize,
+            depth: 1,
+        }
+    }
+}
+
+/// ### Parameters
+/// `readFramebuffer`
+///
+/// > Specifies the name of the source framebuffer object for [**glBlitNamedFramebuffer**](crate::context::Context::oxidegl_blit_named_framebuffer).
+///
+/// `drawFramebuffer`
+///
+/// > Specifies the name of the destination framebuffer object for [**glBlitNamedFramebuffer**](crate::context::Context::oxidegl_blit_named_framebuffer).
+///
+/// `srcX0`
+///
+/// `srcY0`
+///
+/// `srcX1`
+///
+/// `srcY1`
+///
+/// > Specify the bounds of the source rectangle within the read buffer of the
+/// > read framebuffer.
+///
+/// `dstX0`
+///
+/// `dstY0`
+///
+/// `dstX1`
+///
+/// `dstY1`
+///
+/// > Specify the bounds of the destination rectangle within the write buffer
+/// > of the write framebuffer.
+///
+/// `mask`
+///
+/// > The bitwise OR of the flags indicating which buffers are to be copied.
+/// > The allowed flags are [`GL_COLOR_BUFFER_BIT`](crate::enums::GL_COLOR_BUFFER_BIT),
+/// > [`GL_DEPTH_BUFFER_BIT`](crate::enums::GL_DEPTH_BUFFER_BIT) and [`GL_STENCIL_BUFFER_BIT`](crate::enums::GL_STENCIL_BUFFER_BIT).
+///
+/// `filter`
+///
+/// > Specifies the interpolation to be applied if the image is stretched. Must
+/// > be [`GL_NEAREST`](crate::enums::GL_NEAREST) or [`GL_LINEAR`](crate::enums::GL_LINEAR).
+///
+/// ### Description
+/// [**glBlitFramebuffer**](crate::context::Context::oxidegl_blit_framebuffer)
+/// and [**glBlitNamedFramebuffer**](crate::context::Context::oxidegl_blit_named_framebuffer)
+/// transfer a rectangle of pixel values from one region of a read framebuffer
+/// to another region of a draw framebuffer.
+///
+/// For [**glBlitFramebuffer**](crate::context::Context::oxidegl_blit_framebuffer),
+/// the read and draw framebuffers are those bound to the [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER)
+/// and [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER) targets
+/// respectively.
+///
+/// For [**glBlitNamedFramebuffer**](crate::context::Context::oxidegl_blit_named_framebuffer),
+/// `readFramebuffer` and `drawFramebuffer` are the names of the read and draw
+/// framebuffer objects respectively. If `readFramebuffer` or `drawFramebuffer`
+/// is zero, then the default read or draw framebuffer respectively is used.
+///
+/// `mask` is the bitwise OR of a number of values indicating which buffers
+/// are to be copied. The values are [`GL_COLOR_BUFFER_BIT`](crate::enums::GL_COLOR_BUFFER_BIT),
+/// [`GL_DEPTH_BUFFER_BIT`](crate::enums::GL_DEPTH_BUFFER_BIT), and [`GL_STENCIL_BUFFER_BIT`](crate::enums::GL_STENCIL_BUFFER_BIT).
+/// The pixels corresponding to these buffers are copied from the source rectangle
+/// bounded by the locations( `srcX0`, `srcY0`) and( `srcX1`, `srcY1`) to the
+/// destination rectangle bounded by the locations( `dstX0`, `dstY0`) and( `dstX1`,
+/// `dstY1` ). The lower bounds of the rectangle are inclusive, while the upper
+/// bounds are exclusive.
+///
+/// The actual region taken from the read framebuffer is limited to the intersection
+/// of the source buffers being transferred, which may include the color buffer
+/// selected by the read buffer, the depth buffer, and/or the stencil buffer
+/// depending on mask. The actual region written to the draw framebuffer is
+/// limited to the intersection of the destination buffers being written, which
+/// may include multiple draw buffers, the depth buffer, and/or the stencil
+/// buffer depending on mask. Whether or not the source or destination regions
+/// are altered due to these limits, the scaling and offset applied to pixels
+/// being transferred is performed as though no such limits were present.
+///
+/// If the sizes of the source and destination rectangles are not equal, `filter`
+/// specifies the interpolation method that will be applied to resize the source
+/// image, and must be [`GL_NEAREST`](crate::enums::GL_NEAREST) or [`GL_LINEAR`](crate::enums::GL_LINEAR).
+/// [`GL_LINEAR`](crate::enums::GL_LINEAR) is only a valid interpolation method
+/// for the color buffer. If `filter` is not [`GL_NEAREST`](crate::enums::GL_NEAREST)
+/// and `mask` includes [`GL_DEPTH_BUFFER_BIT`](crate::enums::GL_DEPTH_BUFFER_BIT)
+/// or [`GL_STENCIL_BUFFER_BIT`](crate::enums::GL_STENCIL_BUFFER_BIT), no data
+/// is transferred and a [`GL_INVALID_OPERATION`](crate::enums::GL_INVALID_OPERATION)
+/// error is generated.
+///
+/// If `filter` is [`GL_LINEAR`](crate::enums::GL_LINEAR) and the source rectangle
+/// would require sampling outside the bounds of the source framebuffer, values
+/// are read as if the [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE)
+/// texture wrapping mode were applied.
+///
+/// When the color buffer is transferred, values are taken from the read buffer
+/// of the specified read framebuffer and written to each of the draw buffers
+/// of the specified draw framebuffer.
+///
+/// If the source and destination rectangles overlap or are the same, and the
+/// read and draw buffers are the same, the result of the operation is undefined.
+impl Context {
+    pub(crate) fn oxidegl_blit_framebuffer(
+        &mut self,
+        src_x0: GLint,
+        src_y0: GLint,
+        src_x1: GLint,
+        src_y1: GLint,
+        dst_x0: GLint,
+        dst_y0: GLint,
+        dst_x1: GLint,
+        dst_y1: GLint,
+        mask: ClearBufferMask,
+        filter: BlitFramebufferFilter,
+    ) -> GlFallible {
+        self.blit_framebuffer_internal(
+            self.gl_state.read_framebuffer_binding,
+            self.gl_state.draw_framebuffer_binding,
+            [src_x0, src_y0, src_x1, src_y1],
+            [dst_x0, dst_y0, dst_x1, dst_y1],
+            mask,
+            filter,
+        )
+    }
+    pub(crate) fn oxidegl_blit_named_framebuffer(
+        &mut self,
+        read_framebuffer: GLuint,
+        draw_framebuffer: GLuint,
+        src_x0: GLint,
+        src_y0: GLint,
+        src_x1: GLint,
+        src_y1: GLint,
+        dst_x0: GLint,
+        dst_y0: GLint,
+        dst_x1: GLint,
+        dst_y1: GLint,
+        mask: ClearBufferMask,
+        filter: BlitFramebufferFilter,
+    ) -> GlFallible {
+        let read = (read_framebuffer != 0)
+            .then(|| self.framebuffer_by_name(read_framebuffer))
+            .transpose()?;
+        let draw = (draw_framebuffer != 0)
+            .then(|| self.framebuffer_by_name(draw_framebuffer))
+            .transpose()?;
+        self.blit_framebuffer_internal(
+            read,
+            draw,
+            [src_x0, src_y0, src_x1, src_y1],
+            [dst_x0, dst_y0, dst_x1, dst_y1],
+            mask,
+            filter,
+        )
+    }
+}
+
+/// The values of a framebuffer image that a blit transfers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlitAspect {
+    Color,
+    Depth,
+    Stencil,
+}
+
+/// The images of a framebuffer that take part in a blit
+#[derive(Debug)]
+struct BlitImages {
+    /// The image of the read buffer for read framebuffers, and the images of the draw buffers for draw framebuffers
+    color: Vec<AttachmentStorage>,
+    depth: Option<AttachmentStorage>,
+    stencil: Option<AttachmentStorage>,
+    /// Whether the images are stored upside down, as those of the default framebuffer are
+    flip_y: bool,
+}
+
+/// The parameters of a framebuffer blit that are shared by every image it transfers
+#[derive(Debug)]
+struct Blit {
+    /// Corners of the source rectangle, in window coordinates of the read framebuffer
+    src: [i64; 4],
+    /// Corners of the destination rectangle, in window coordinates of the draw framebuffer
+    dst: [i64; 4],
+    src_flip_y: bool,
+    dst_flip_y: bool,
+    filter: BlitFramebufferFilter,
+    scissor: Option<PixelAlignedRect>,
+    /// Whether sRGB images are converted to and from linear color (`GL_FRAMEBUFFER_SRGB`)
+    srgb: bool,
+}
+impl Blit {
+    /// Whether the source and destination rectangles have the same size and orientation, in which case texels are transferred
+    /// without scaling or mirroring
+    fn is_unscaled(&self) -> bool {
+        let [sx0, sy0, sx1, sy1] = self.src;
+        let [dx0, dy0, dx1, dy1] = self.dst;
+        sx1 - sx0 == dx1 - dx0 && sy1 - sy0 == dy1 - dy0 && self.src_flip_y == self.dst_flip_y
+    }
+}
+
+impl Context {
+    /// Shared implementation of glBlitFramebuffer and glBlitNamedFramebuffer. `read` and `draw` are None for the default framebuffer
+    fn blit_framebuffer_internal(
+        &mut self,
+        read: Option<ObjectName<Framebuffer>>,
+        draw: Option<ObjectName<Framebuffer>>,
+        src: [GLint; 4],
+        dst: [GLint; 4],
+        mask: ClearBufferMask,
+        filter: BlitFramebufferFilter,
+    ) -> GlFallible {
+        gl_debug!("blitting {src:?} of {read:?} to {dst:?} of {draw:?} ({mask:?}, {filter:?})");
+        gl_assert!(
+            filter == BlitFramebufferFilter::Nearest
+                || !mask.intersects(
+                    ClearBufferMask::DEPTH_BUFFER_BIT | ClearBufferMask::STENCIL_BUFFER_BIT
+                ),
+            InvalidOperation,
+            "depth and stencil values can only be blitted with GL_NEAREST filtering"
+        );
+        for fb in [read, draw].into_iter().flatten() {
+            self.assert_framebuffer_complete(fb)?;
+        }
+        let read_images = self.blit_images(read, true);
+        let draw_images = self.blit_images(draw, false);
+        let read_samples = read_images
+            .color
+            .iter()
+            .chain(&read_images.depth)
+            .chain(&read_images.stencil)
+            .map(|image| image.samples)
+            .max()
+            .unwrap_or(1);
+        gl_assert!(
+            draw_images
+                .color
+                .iter()
+                .chain(&draw_images.depth)
+                .chain(&draw_images.stencil)
+                .all(|image| image.samples <= 1),
+            InvalidOperation,
+            "can't blit to a multisample framebuffer"
+        );
+        gl_assert!(
+            read_samples <= 1 || src == dst,
+            InvalidOperation,
+            "multisample framebuffers can only be blitted to a destination rectangle with the same bounds as the source"
+        );
+
+        let color = read_images
+            .color
+            .first()
+            .filter(|_| mask.contains(ClearBufferMask::COLOR_BUFFER_BIT));
+        if let Some(src_image) = color {
+            let integer_type =
+                |format: InternalFormat| format.is_integer().then(|| format.component_type());
+            gl_assert!(
+                draw_images
+                    .color
+                    .iter()
+                    .all(|image| integer_type(image.format) == integer_type(src_image.format)),
+                InvalidOperation,
+                "can't blit between integer and non-integer color buffers, or signed and unsigned integer ones"
+            );
+            gl_assert!(
+                filter == BlitFramebufferFilter::Nearest || !src_image.format.is_integer(),
+                InvalidOperation,
+                "integer color buffers can only be blitted with GL_NEAREST filtering"
+            );
+        }
+        let depth = read_images
+            .depth
+            .zip(draw_images.depth)
+            .filter(|_| mask.contains(ClearBufferMask::DEPTH_BUFFER_BIT));
+        let stencil = read_images
+            .stencil
+            .zip(draw_images.stencil)
+            .filter(|_| mask.contains(ClearBufferMask::STENCIL_BUFFER_BIT));
+        for (src_image, dst_image) in depth.iter().chain(&stencil) {
+            gl_assert!(
+                src_image.tex.pixelFormat() == dst_image.tex.pixelFormat(),
+                InvalidOperation,
+                "can't blit between depth or stencil buffers of different formats"
+            );
+        }
+
+        let blit = Blit {
+            src: src.map(i64::from),
+            dst: dst.map(i64::from),
+            src_flip_y: read_images.flip_y,
+            dst_flip_y: draw_images.flip_y,
+            filter,
+            scissor: self
+                .gl_state
+                .scissor_test
+                .then_some(self.gl_state.scissor_box),
+            srgb: self
+                .gl_state
+                .caps
+                .is_any_enabled(Capabilities::FRAMEBUFFER_SRGB),
+        };
+        if let Some(src_image) = color {
+            for dst_image in &draw_images.color {
+                self.blit_image(&blit, src_image, dst_image, BlitAspect::Color, false);
+            }
+        }
+        // depth and stencil values that share a packed depth stencil texture on both sides can be copied together
+        let packed = depth.as_ref().zip(stencil.as_ref()).is_some_and(
+            |((src_depth, dst_depth), (src_stencil, dst_stencil))| {
+                Retained::as_ptr(&src_depth.tex) == Retained::as_ptr(&src_stencil.tex)
+                    && Retained::as_ptr(&dst_depth.tex) == Retained::as_ptr(&dst_stencil.tex)
+            },
+        );
+        let mut copied_stencil = false;
+        if let Some((src_image, dst_image)) = &depth {
+            copied_stencil =
+                self.blit_image(&blit, src_image, dst_image, BlitAspect::Depth, packed) && packed;
+        }
+        if let Some((src_image, dst_image)) = &stencil
+            && !copied_stencil
+        {
+            self.blit_image(&blit, src_image, dst_image, BlitAspect::Stencil, packed);
+        }
+        Ok(())
+    }
+    /// Gathers the images of the framebuffer object `fb` (or the default framebuffer if None) that a blit reads from (if `read`
+    /// is set) or draws to, realizing the storage of its attached textures
+    fn blit_images(&mut self, fb: Option<ObjectName<Framebuffer>>, read: bool) -> BlitImages {
+        let Some(fb) = fb else {
+            return self.default_blit_images(read);
+        };
+        if self.realize_framebuffer_storage(fb) {
+            self.new_encoder();
+            self.new_pipeline();
+        }
+        let state = &self.gl_state;
+        let framebuffer = state.framebufer_list.get(fb);
+        let color = if read {
+            color_attachment_index(framebuffer.read_buffer.into())
+                .and_then(|idx| framebuffer.color_attachments[idx as usize]?.storage(state))
+                .into_iter()
+                .collect()
+        } else {
+            framebuffer
+                .draw_buffers
+                .modes
+                .iter()
+                .filter_map(|mode| {
+                    let idx = color_attachment_index((*mode)?.into())?;
+                    framebuffer.color_attachments[idx as usize]?.storage(state)
+                })
+                .collect()
+        };
+        BlitImages {
+            color,
+            depth: framebuffer.depth_attachment.and_then(|a| a.storage(state)),
+            stencil: framebuffer
+                .stencil_attachment
+                .and_then(|a| a.storage(state)),
+            flip_y: false,
+        }
+    }
+    /// Implementation of [`blit_images`](Self::blit_images) for the default framebuffer
+    fn default_blit_images(&mut self, read: bool) -> BlitImages {
+        let platform = &mut self.platform_state;
+        let drawable_tex = platform.current_drawable().texture();
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "drawable dimensions fit in a u32"
+        )]
+        let dims = (drawable_tex.width() as u32, drawable_tex.height() as u32);
+        let image = |tex: ProtoObjRef<dyn MTLTexture>| {
+            let format = tex.pixelFormat();
+            let storage = default_framebuffer_storage(tex, dims);
+            if storage.is_none() {
+                gl_warn!(ty: UndefinedBehavior, "OxideGL can't blit default framebuffer buffers of Metal pixel format {format:?}");
+            }
+            storage
+        };
+        let color = if read {
+            default_color_buffer(self.gl_state.default_read_buffer)
+                .and_then(|mode| image(platform.default_color_buffer(mode, dims)))
+                .into_iter()
+                .collect()
+        } else {
+            self.gl_state
+                .default_draw_buffers
+                .modes
+                .iter()
+                .filter_map(|mode| image(platform.default_color_buffer((*mode)?, dims)))
+                .collect()
+        };
+        let depth = if platform.depth_format.is_some() {
+            image(platform.get_internal_depthbuffer(dims).tex.clone())
+        } else {
+            None
+        };
+        let stencil = if platform.stencil_format.is_some() {
+            image(platform.get_internal_stencilbuffer(dims).tex.clone())
+        } else {
+            None
+        };
+        BlitImages {
+            color,
+            depth,
+            stencil,
+            flip_y: true,
+        }
+    }
+    /// Transfers the `aspect` values of the source rectangle of `blit` in `src` to its destination rectangle in `dst`. Copies
+    /// texels directly if that needs no scaling, mirroring, resolving or format conversion, and draws them with the internal blit
+    /// shaders otherwise. Since copies transfer whole texels, packed depth stencil images are only copied if `whole_texels` is set.
+    /// Returns whether the texels were copied
+    fn blit_image(
+        &mut self,
+        blit: &Blit,
+        src: &AttachmentStorage,
+        dst: &AttachmentStorage,
+        aspect: BlitAspect,
+        whole_texels: bool,
+    ) -> bool {
+        let format = src.tex.pixelFormat();
+        let (depth, stencil) = depth_stencil_aspects(format);
+        let copyable = src.samples <= 1
+            && format == dst.tex.pixelFormat()
+            && blit.is_unscaled()
+            && (whole_texels || !(depth && stencil))
+            && Retained::as_ptr(&src.tex) != Retained::as_ptr(&dst.tex);
+        if copyable {
+            self.copy_blit_image(blit, src, dst);
+        } else {
+            self.draw_blit_image(blit, src, dst, aspect);
+        }
+        copyable
+    }
+    /// Copies the source rectangle of the unscaled `blit` in `src` to its destination rectangle in `dst`, which have the same pixel
+    /// format. Texels outside of either image or the scissor box are left out
+    #[expect(
+        clippy::cast_sign_loss,
+        clippy::cast_possible_truncation,
+        reason = "values are clamped to the images' dimensions"
+    )]
+    fn copy_blit_image(&mut self, blit: &Blit, src: &AttachmentStorage, dst: &AttachmentStorage) {
+        let [sx0, sy0, ..] = blit.src;
+        let [dx0, dy0, dx1, dy1] = blit.dst;
+        let offset = [dx0 - sx0, dy0 - sy0];
+        let mut x = [dx0.min(dx1), dx0.max(dx1)];
+        let mut y = [dy0.min(dy1), dy0.max(dy1)];
+        let mut clip = |x0: i64, y0: i64, x1: i64, y1: i64| {
+            x = [x[0].max(x0), x[1].min(x1)];
+            y = [y[0].max(y0), y[1].min(y1)];
+        };
+        clip(0, 0, i64::from(dst.width), i64::from(dst.height));
+        clip(
+            offset[0],
+            offset[1],
+            i64::from(src.width) + offset[0],
+            i64::from(src.height) + offset[1],
+        );
+        if let Some(scissor) = blit.scissor {
+            let [sc_x, sc_y, sc_width, sc_height] = scissor.map(i64::from);
+            clip(sc_x, sc_y, sc_x + sc_width, sc_y + sc_height);
+        }
+        if x[0] >= x[1] || y[0] >= y[1] {
+            return;
+        }
+        let height = y[1] - y[0];
+        let size = MTLSize {
+            width: (x[1] - x[0]) as usize,
+            height: height as usize,
+            depth: 1,
+        };
+        // both images are stored the same way up, so the copied rows don't need to be reordered
+        let origin = |image: &AttachmentStorage, x0: i64, y0: i64| {
+            let y0 = if blit.dst_flip_y {
+                i64::from(image.height) - y0 - height
+            } else {
+                y0
+            };
+            let (slice, z) = if image.depth_plane {
+                (0, image.slice as usize)
+            } else {
+                (image.slice as usize, 0)
+            };
+            (
+                slice,
+                MTLOrigin {
+                    x: x0 as usize,
+                    y: y0 as usize,
+                    z,
+                },
+            )
+        };
+        let (src_slice, src_origin) = origin(src, x[0] - offset[0], y[0] - offset[1]);
+        let (dst_slice, dst_origin) = origin(dst, x[0], y[0]);
+        gl_trace!("copying {size:?} texels at {src_origin:?} to {dst_origin:?}");
+        self.platform_state.encode_blit(|enc| {
+            // Safety: both images have the same pixel format and sample count, and the copied regions were clipped to lie within
+            // them
+            unsafe {
+                enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toTexture_destinationSlice_destinationLevel_destinationOrigin(
+                    &src.tex,
+                    src_slice,
+                    src.level as usize,
+                    src_origin,
+                    size,
+                    &dst.tex,
+                    dst_slice,
+                    dst.level as usize,
+                    dst_origin,
+                );
+            }
+        });
+    }
+    /// Draws the source rectangle of `blit` in `src` to its destination rectangle in `dst` with the internal blit shaders, scaling,
+    /// mirroring, filtering and resolving the `aspect` values as needed
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "window coordinates are well within the exactly representable range of f32"
+    )]
+    fn draw_blit_image(
+        &mut self,
+        blit: &Blit,
+        src: &AttachmentStorage,
+        dst: &AttachmentStorage,
+        aspect: BlitAspect,
+    ) {
+        let scissor = scissor_rect(blit.scissor, (dst.width, dst.height), blit.dst_flip_y);
+        if scissor.width == 0 || scissor.height == 0 {
+            return;
+        }
+        let multisample = src.samples > 1;
+        let function = match aspect {
+            BlitAspect::Color => match (src.format.is_integer(), src.format.component_type()) {
+                (true, GL_INT) if multisample => BlitFunction::IntMultisample,
+                (true, GL_INT) => BlitFunction::Int,
+                (true, _) if multisample => BlitFunction::UintMultisample,
+                (true, _) => BlitFunction::Uint,
+                _ if multisample => BlitFunction::FloatMultisample,
+                _ if blit.filter == BlitFramebufferFilter::Linear => BlitFunction::FloatLinear,
+                _ => BlitFunction::Float,
+            },
+            BlitAspect::Depth if multisample => BlitFunction::DepthMultisample,
+            BlitAspect::Depth => BlitFunction::Depth,
+            BlitAspect::Stencil if multisample => BlitFunction::StencilMultisample,
+            BlitAspect::Stencil => BlitFunction::Stencil,
+        };
+        // without GL_FRAMEBUFFER_SRGB, sRGB encoded values are transferred as they are
+        let view_format = |tex: &ProtoObjRef<dyn MTLTexture>| {
+            let format = tex.pixelFormat();
+            let view = match aspect {
+                BlitAspect::Color if !blit.srgb => strip_srgb(format),
+                BlitAspect::Stencil => match format {
+                    MTLPixelFormat::Depth32Float_Stencil8 => MTLPixelFormat::X32_Stencil8,
+                    MTLPixelFormat::Depth24Unorm_Stencil8 => MTLPixelFormat::X24_Stencil8,
+                    f => f,
+                },
+                _ => format,
+            };
+            if view != format && !tex.usage().contains(MTLTextureUsage::PixelFormatView) {
+                gl_warn!(ty: UndefinedBehavior, "can't view a {format:?} framebuffer image as {view:?}, sRGB conversion will be applied to the blit");
+                return format;
+            }
+            view
+        };
+        let src_tex = self.blit_source_texture(src, dst, view_format(&src.tex));
+        let dst_format = view_format(&dst.tex);
+        let dst_tex = if dst_format == dst.tex.pixelFormat() {
+            dst.tex.clone()
+        } else {
+            dst.tex
+                .newTextureViewWithPixelFormat(dst_format)
+                .expect("failed to create Metal texture view")
+        };
+
+        let desc = MTLRenderPassDescriptor::new();
+        let attach = |a_desc: &MTLRenderPassAttachmentDescriptor| {
+            a_desc.setTexture(Some(&dst_tex));
+            a_desc.setLevel(dst.level as usize);
+            if dst.depth_plane {
+                a_desc.setDepthPlane(dst.slice as usize);
+            } else {
+                a_desc.setSlice(dst.slice as usize);
+            }
+            a_desc.setLoadAction(MTLLoadAction::Load);
+            a_desc.setStoreAction(MTLStoreAction::Store);
+        };
+        let ds_desc = MTLDepthStencilDescriptor::new();
+        if aspect == BlitAspect::Color {
+            let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
+            attach(&a_desc);
+            // Safety: index 0 is always a valid color attachment index
+            unsafe {
+                desc.colorAttachments()
+                    .setObject_atIndexedSubscript(Some(&a_desc), 0);
+            }
+        } else {
+            // packed depth stencil images are bound to both attachments, matching the internal pipelines
+            let (depth, stencil) = depth_stencil_aspects(dst_format);
+            if depth {
+                let a_desc = MTLRenderPassDepthAttachmentDescriptor::new();
+                attach(&a_desc);
+                desc.setDepthAttachment(Some(&a_desc));
+            }
+            if stencil {
+                let a_desc = MTLRenderPassStencilAttachmentDescriptor::new();
+                attach(&a_desc);
+                desc.setStencilAttachment(Some(&a_desc));
+            }
+            if aspect == BlitAspect::Depth {
+                ds_desc.setDepthCompareFunction(MTLCompareFunction::Always);
+                ds_desc.setDepthWriteEnabled(true);
+            } else {
+                // the fragment function exports the stencil reference value
+                let stencil_desc = MTLStencilDescriptor::new();
+                stencil_desc.setStencilCompareFunction(MTLCompareFunction::Always);
+                stencil_desc.setDepthStencilPassOperation(MTLStencilOperation::Replace);
+                stencil_desc.setWriteMask(0xFF);
+                ds_desc.setFrontFaceStencil(Some(&stencil_desc));
+                ds_desc.setBackFaceStencil(Some(&stencil_desc));
+            }
+        }
+
+        let [sx0, sy0, sx1, sy1] = blit.src.map(|v| v as f32);
+        let [dx0, dy0, dx1, dy1] = blit.dst.map(|v| v as f32);
+        let src_y = |y: f32| {
+            if blit.src_flip_y {
+                src.height as f32 - y
+            } else {
+                y
+            }
+        };
+        let (width, height) = (dst.width as f32, dst.height as f32);
+        let ndc = |x: f32, y: f32| {
+            let y = if blit.dst_flip_y { height - y } else { y };
+            [2.0 * x / width - 1.0, 1.0 - 2.0 * y / height]
+        };
+        let params = BlitParams {
+            src_min: [sx0, src_y(sy0)],
+            src_max: [sx1, src_y(sy1)],
+            dst_min: ndc(dx0, dy0),
+            dst_max: ndc(dx1, dy1),
+        };
+        let platform = &mut self.platform_state;
+        let pipeline = platform
+            .internal_pipelines
+            .pipeline(&platform.device, function, dst_format);
+        let depth_stencil = (aspect != BlitAspect::Color).then(|| {
+            platform
+                .device
+                .newDepthStencilStateWithDescriptor(&ds_desc)
+                .expect("failed to create MTLDepthStencilState")
+        });
+        gl_trace!("drawing blit with {function:?} and parameters {params:?}");
+        platform.encode_internal_draw(
+            &desc,
+            &pipeline,
+            depth_stencil.as_ref(),
+            scissor,
+            &params,
+            &src_tex,
+        );
+    }
+    /// Returns a 2D (or 2D multisample) texture of pixel format `format` that holds the image `src` for the internal blit shaders.
+    /// Images that can't be viewed as one, or that share their texture with the blit's destination image `dst` (which can't be read
+    /// from while it is drawn to), are copied to a temporary texture first
+    fn blit_source_texture(
+        &mut self,
+        src: &AttachmentStorage,
+        dst: &AttachmentStorage,
+        format: MTLPixelFormat,
+    ) -> ProtoObjRef<dyn MTLTexture> {
+        let viewable = matches!(
+            src.tex.textureType(),
+            MTLTextureType::Type2D
+                | MTLTextureType::Type2DArray
+                | MTLTextureType::TypeCube
+                | MTLTextureType::TypeCubeArray
+                | MTLTextureType::Type2DMultisample
+                | MTLTextureType::Type2DMultisampleArray
+        ) && Retained::as_ptr(&src.tex) != Retained::as_ptr(&dst.tex);
+        let texture_type = if src.samples > 1 {
+            MTLTextureType::Type2DMultisample
+        } else {
+            MTLTextureType::Type2D
+        };
+        if viewable {
+            // Safety: the level and slice lie within the storage, the view type is compatible with the storage's type and the view
+            // format is either the storage format, its non-sRGB counterpart or its stencil aspect
+            return unsafe {
+                src.tex
+                    .newTextureViewWithPixelFormat_textureType_levels_slices(
+                        format,
+                        texture_type,
+                        NSRange::new(src.level as usize, 1),
+                        NSRange::new(src.slice as usize, 1),
+                    )
+            }
+            .expect("failed to create Metal texture view");
+        }
+        gl_debug!(
+            "copying a {}x{} blit source image to a temporary texture",
+            src.width,
+            src.height
+        );
+        let platform = &mut self.platform_state;
+        let desc = MTLTextureDescriptor::new();
+        desc.setTextureType(texture_type);
+        desc.setPixelFormat(src.tex.pixelFormat());
+        desc.setStorageMode(MTLStorageMode::Private);
+        desc.setUsage(MTLTextureUsage::ShaderRead | MTLTextureUsage::PixelFormatView);
+        // Safety: the image's dimensions and sample count are valid for a texture of its format, since it already has storage
+        unsafe {
+            desc.setWidth(src.width as usize);
+            desc.setHeight(src.height as usize);
+            desc.setSampleCount(src.samples.max(1) as usize);
+        }
+        let staging = platform
+            .device
+            .newTextureWithDescriptor(&desc)
+            .expect("failed to create temporary blit texture");
+        let (slice, z) = if src.depth_plane {
+            (0, src.slice as usize)
+        } else {
+            (src.slice as usize, 0)
+        };
+        platform.encode_blit(|enc| {
+            // Safety: both textures have the same pixel format and sample count, and the copied region is the whole image
+            unsafe {
+                enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toTexture_destinationSlice_destinationLevel_destinationOrigin(
+                    &src.tex,
+                    slice,
+                    src.level as usize,
+                    MTLOrigin { x: 0, y: 0, z },
+                    MTLSize {
+                        width: src.width as usize,
+                        height: src.height as usize,
+                        depth: 1,
+                    },
+                    &staging,
+                    0,
+                    0,
+                    MTLOrigin { x: 0, y: 0, z: 0 },
+                );
+            }
+        });
+        if format == staging.pixelFormat() {
+            staging
+        } else {
+            staging
+                .newTextureViewWithPixelFormat(format)
+                .expect("failed to create Metal texture view")
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the framebuffer object is attached for [**glInvalidateFramebuffer**](crate::context::Context::oxidegl_invalidate_framebuffer).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glInvalidateNamedFramebufferData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_data).
+///
+/// `numAttachments`
+///
+/// > Specifies the number of entries in the `attachments` array.
+///
+/// `attachments`
+///
+/// > Specifies a pointer to an array identifying the attachments to be invalidated.
+///
+/// ### Description
+/// [**glInvalidateFramebuffer**](crate::context::Context::oxidegl_invalidate_framebuffer)
+/// and [**glInvalidateNamedFramebufferData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_data)
+/// invalidate the entire contents of a specified set of attachments of a framebuffer.
+///
+/// For [**glInvalidateFramebuffer**](crate::context::Context::oxidegl_invalidate_framebuffer),
+/// the framebuffer object is that bound to `target`. `target` must be [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER),
+/// [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER) or [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+/// [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER) is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+/// Default framebuffers may also be invalidated if bound to `target`.
+///
+/// For [**glInvalidateNamedFramebufferData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_data),
+/// `framebuffer` is the name of the framebuffer object. If `framebuffer` is
+/// zero, the default draw framebuffer is affected.
+///
+/// The set of attachments whose contents are to be invalidated are specified
+/// in the `attachments` array, which contains `numAttachments` elements.
+///
+/// If the specified framebuffer is a framebuffer object, each element of `attachments`
+/// must be one of [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT),
+/// [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT) [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT),
+/// or [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i*, where
+/// *i* is between zero and the value of [`GL_MAX_FRAMEBUFFER_ATTACHMENTS`](crate::enums::GL_MAX_FRAMEBUFFER_ATTACHMENTS)
+/// minus one.
+///
+/// If the specified framebuffer is a default framebuffer, each element of
+/// `attachments` must be one of [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT),
+/// [`GL_FRONT_RIGHT`](crate::enums::GL_FRONT_RIGHT), [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT),
+/// [`GL_BACK_RIGHT`](crate::enums::GL_BACK_RIGHT), [`GL_AUX`](crate::enums::GL_AUX)
+/// *i*, [`GL_ACCUM`](crate::enums::GL_ACCUM), [`GL_COLOR`](crate::enums::GL_COLOR),
+/// [`GL_DEPTH`](crate::enums::GL_DEPTH), or [`GL_STENCIL`](crate::enums::GL_STENCIL).
+/// [`GL_COLOR`](crate::enums::GL_COLOR), is treated as [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT)
+/// for a double-buffered context and [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT)
+/// for a single-buffered context. The other attachments identify the corresponding
+/// specific buffer.
+///
+/// The entire contents of each specified attachment become undefined after
+/// execution of [**glInvalidateFramebuffer**](crate::context::Context::oxidegl_invalidate_framebuffer)
+/// or [**glInvalidateNamedFramebufferData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_data).
+///
+/// If the framebuffer object is not complete, [**glInvalidateFramebuffer**](crate::context::Context::oxidegl_invalidate_framebuffer)
+/// and [**glInvalidateNamedFramebufferData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_data)
+/// may be ignored. This is not an error.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS)
+impl Context {
+    pub(crate) unsafe fn oxidegl_invalidate_framebuffer(
+        &mut self,
+        target: FramebufferTarget,
+        num_attachments: GLsizei,
+        attachments: *const GLenum,
+    ) -> GlFallible {
+        let fb = self.bound_framebuffer(target);
+        // Safety: caller ensures num_attachments and attachments form a valid GLenum slice
+        unsafe { self.invalidate_framebuffer_internal(fb, num_attachments, attachments, None) }
+    }
+    pub(crate) unsafe fn oxidegl_invalidate_named_framebuffer_data(
+        &mut self,
+        framebuffer: GLuint,
+        num_attachments: GLsizei,
+        attachments: *const GLenum,
+    ) -> GlFallible {
+        let fb = if framebuffer == 0 {
+            None
+        } else {
+            Some(self.framebuffer_by_name(framebuffer)?)
+        };
+        // Safety: caller ensures num_attachments and attachments form a valid GLenum slice
+        unsafe { self.invalidate_framebuffer_internal(fb, num_attachments, attachments, None) }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the framebuffer object is attached for [**glInvalidateSubFramebuffer**](crate::context::Context::oxidegl_invalidate_sub_framebuffer).
+///
+/// `framebuffer`
+///
+/// > Specifies the name of the framebuffer object for [**glInvalidateNamedFramebufferSubData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_sub_data).
+///
+/// `numAttachments`
+///
+/// > Specifies the number of entries in the `attachments` array.
+///
+/// `attachments`
+///
+/// > Specifies a pointer to an array identifying the attachments to be invalidated.
+///
+/// `x`
+///
+/// > Specifies the X offset of the region to be invalidated.
+///
+/// `y`
+///
+/// > Specifies the Y offset of the region to be invalidated.
+///
+/// `width`
+///
+/// > Specifies the width of the region to be invalidated.
+///
+/// `height`
+///
+/// > Specifies the height of the region to be invalidated.
+///
+/// ### Description
+/// [**glInvalidateSubFramebuffer**](crate::context::Context::oxidegl_invalidate_sub_framebuffer)
+/// and [**glInvalidateNamedFramebufferSubData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_sub_data)
+/// invalidate the contents of a specified region of a specified set of attachments
+/// of a framebuffer.
+///
+/// For [**glInvalidateSubFramebuffer**](crate::context::Context::oxidegl_invalidate_sub_framebuffer),
+/// the framebuffer object is that bound to `target`, which must be one of
+/// [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER), [`GL_READ_FRAMEBUFFER`](crate::enums::GL_READ_FRAMEBUFFER)
+/// or [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER). [`GL_FRAMEBUFFER`](crate::enums::GL_FRAMEBUFFER)
+/// is equivalent to [`GL_DRAW_FRAMEBUFFER`](crate::enums::GL_DRAW_FRAMEBUFFER).
+/// Default framebuffers may also be invalidated if bound to `target`.
+///
+/// For [**glInvalidateNamedFramebufferSubData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_sub_data),
+/// `framebuffer` is the name of the framebuffer object. If `framebuffer` is
+/// zero, the default draw framebuffer is affected.
+///
+/// The set of attachments of which a region is to be invalidated are specified
+/// in the `attachments` array, which contains `numAttachments` elements.
+///
+/// If the specified framebuffer is a framebuffer object, each element of `attachments`
+/// must be one of [`GL_DEPTH_ATTACHMENT`](crate::enums::GL_DEPTH_ATTACHMENT),
+/// [`GL_STENCIL_ATTACHMENT`](crate::enums::GL_STENCIL_ATTACHMENT) [`GL_DEPTH_STENCIL_ATTACHMENT`](crate::enums::GL_DEPTH_STENCIL_ATTACHMENT),
+/// or [`GL_COLOR_ATTACHMENT`](crate::enums::GL_COLOR_ATTACHMENT) *i*, where
+/// *i* is between zero and the value of [`GL_MAX_FRAMEBUFFER_ATTACHMENTS`](crate::enums::GL_MAX_FRAMEBUFFER_ATTACHMENTS)
+/// minus one.
+///
+/// If the specified framebuffer is a default framebuffer, each element of
+/// `attachments` must be one of [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT),
+/// [`GL_FRONT_RIGHT`](crate::enums::GL_FRONT_RIGHT), [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT),
+/// [`GL_BACK_RIGHT`](crate::enums::GL_BACK_RIGHT), [`GL_AUX`](crate::enums::GL_AUX)
+/// *i*, [`GL_ACCUM`](crate::enums::GL_ACCUM), [`GL_COLOR`](crate::enums::GL_COLOR),
+/// [`GL_DEPTH`](crate::enums::GL_DEPTH), or [`GL_STENCIL`](crate::enums::GL_STENCIL).
+/// [`GL_COLOR`](crate::enums::GL_COLOR), is treated as [`GL_BACK_LEFT`](crate::enums::GL_BACK_LEFT)
+/// for a double-buffered context and [`GL_FRONT_LEFT`](crate::enums::GL_FRONT_LEFT)
+/// for a single-buffered context. The other attachments identify the corresponding
+/// specific buffer.
+///
+/// The contents of the specified region of each specified attachment become
+/// undefined after execution of [**glInvalidateSubFramebuffer**](crate::context::Context::oxidegl_invalidate_sub_framebuffer)
+/// or [**glInvalidateNamedFramebufferSubData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_sub_data).
+/// The region to be invalidated is specified by `x`, `y`, `width` and `height`
+/// where `x` and `y` give the offset from the origin (with lower-left corner
+/// at $(0,0)$) and `width` and `height` are the width and height, respectively,
+/// of the region. Any pixels lying outside of the window allocated to the
+/// current GL context (for the default framebuffer), or outside of the attachments
+/// of the framebuffer object, are ignored. If the framebuffer object is not
+/// complete, these commands may be ignored.
+///
+/// If the framebuffer object is not complete, [**glInvalidateSubFramebuffer**](crate::context::Context::oxidegl_invalidate_sub_framebuffer)
+/// and [**glInvalidateNamedFramebufferSubData**](crate::context::Context::oxidegl_invalidate_named_framebuffer_sub_data)
+/// may be ignored. This is not an error.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COLOR_ATTACHMENTS`](crate::enums::GL_MAX_COLOR_ATTACHMENTS)
+impl Context {
+    pub(crate) unsafe fn oxidegl_invalidate_sub_framebuffer(
+        &mut self,
+        target: FramebufferTarget,
+        num_attachments: GLsizei,
+        attachments: *const GLenum,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        let fb = self.bound_framebuffer(target);
+        // Safety: caller ensures num_attachments and attachments form a valid GLenum slice
+        unsafe {
+            self.invalidate_framebuffer_internal(
+                fb,
+                num_attachments,
+                attachments,
+                Some(InvalidatedRegion {
+                    origin: [x, y],
+                    width,
+                    height,
+                }),
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_invalidate_named_framebuffer_sub_data(
+        &mut self,
+        framebuffer: GLuint,
+        num_attachments: GLsizei,
+        attachments: *const GLenum,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        let fb = if framebuffer == 0 {
+            None
+        } else {
+            Some(self.framebuffer_by_name(framebuffer)?)
+        };
+        // Safety: caller ensures num_attachments and attachments form a valid GLenum slice
+        unsafe {
+            self.invalidate_framebuffer_internal(
+                fb,
+                num_attachments,
+                attachments,
+                Some(InvalidatedRegion {
+                    origin: [x, y],
+                    width,
+                    height,
+                }),
+            )
+        }
+    }
+}
+
+impl Context {
+    /// Shared implementation of the glInvalidate\*Framebuffer\* commands, which invalidate `attachments` of the framebuffer `fb`
+    /// (None: the default framebuffer), or just their `region` if there is one. Invalidation is recorded for the attachments of the
+    /// draw framebuffer, whose next render pass (which begins once something renders to it again) doesn't load their contents; the
+    /// current one, if any, doesn't store them either. Invalidating any other framebuffer has no effect
+    /// # Safety
+    /// `num_attachments` and `attachments` must form a valid `GLenum` slice
+    unsafe fn invalidate_framebuffer_internal(
+        &mut self,
+        fb: Option<ObjectName<Framebuffer>>,
+        num_attachments: GLsizei,
+        attachments: *const GLenum,
+        region: Option<InvalidatedRegion>,
+    ) -> GlFallible {
+        sizei!(num_attachments, count);
+        if count == 0 {
+            return Ok(());
+        }
+        // Safety: caller ensures num_attachments and attachments form a valid GLenum slice
+        let attachments = unsafe { slice::from_raw_parts(attachments, count as usize) };
+        let covers = |dims| region.is_none_or(|r| r.covers(dims));
+        let current = fb == self.gl_state.draw_framebuffer_binding;
+        if let Some(fb) = fb {
+            let mut points = Vec::with_capacity(attachments.len());
+            for &attachment in attachments {
+                let attachment: FramebufferAttachment = attachment.try_into_enum()?;
+                let Some(point) = AttachmentPoint::new(attachment) else {
+                    gl_err!(ty: Error, "{attachment:?} exceeds GL_MAX_COLOR_ATTACHMENTS ({MAX_COLOR_ATTACHMENTS})");
+                    return Err(GlError::InvalidOperation.e());
+                };
+                points.push(point);
+            }
+            if !current || self.framebuffer_completeness(fb) != GL_FRAMEBUFFER_COMPLETE {
+                gl_trace!(
+                    "ignoring invalidation of {fb:?}, which isn't a complete draw framebuffer"
+                );
+                return Ok(());
+            }
+            let framebuffer = self.gl_state.framebufer_list.get(fb);
+            points.retain(
+                |&point| match self.attached_image(framebuffer.attachment(point)) {
+                    AttachmentImage::Image(image) => covers((image.width, image.height)),
+                    _ => false,
+                },
+            );
+            gl_debug!("invalidating {points:?} of {fb:?}");
+            let framebuffer = self.gl_state.framebufer_list.get_mut(fb);
+            for point in points {
+                framebuffer.invalidate(point);
+            }
+            self.prepare_draw_framebuffer()?;
+        } else {
+            let buffers = attachments
+                .iter()
+                .map(|&buffer| {
+                    DefaultBuffer::new(buffer).ok_or_else(|| {
+                        gl_err!(ty: Error, "{buffer:#X} does not name a buffer of the default framebuffer");
+                        GlError::InvalidEnum.e()
+                    })
+                })
+                .collect::<GlFallible<Vec<_>>>()?;
+            if !current {
+                gl_trace!(
+                    "ignoring invalidation of the default framebuffer, which isn't the draw framebuffer"
+                );
+                return Ok(());
+            }
+            if !covers(self.platform_state.target_defaultfb_dims()) {
+                gl_trace!("ignoring invalidation of part of the default framebuffer");
+                return Ok(());
+            }
+            gl_debug!("invalidating {buffers:?} of the default framebuffer");
+            for buffer in buffers {
+                self.gl_state
+                    .default_framebuffer_invalidations
+                    .invalidate(buffer);
+            }
+        }
+        let platform = &self.platform_state;
+        if platform.render_encoder.is_some()
+            && !platform.dirty_state.any_set(Dirty::NEW_RENDER_ENCODER)
+        {
+            let (color, depth, stencil) = self.invalidated_draw_buffers();
+            self.platform_state
+                .render_pass_stores
+                .discard(color, depth, stencil);
+        }
+        // the next pass is begun lazily, so that invalidating attachments right before presenting doesn't begin one just to
+        // load and store them
+        self.new_encoder();
+        Ok(())
+    }
+    /// Returns which of the color attachments (by draw buffer), depth and stencil attachments of the current draw framebuffer's
+    /// render pass were invalidated since it began
+    fn invalidated_draw_buffers(&self) -> ([bool; MAX_COLOR_ATTACHMENTS as usize], bool, bool) {
+        let Some(fb) = self.gl_state.draw_framebuffer_binding else {
+            let invalidations = self.gl_state.default_framebuffer_invalidations;
+            let color = self
+                .gl_state
+                .default_draw_buffers
+                .modes
+                .map(|mode| mode.is_some_and(|mode| invalidations.color(mode)));
+            return (color, invalidations.depth, invalidations.stencil);
+        };
+        let framebuffer = self.gl_state.framebufer_list.get(fb);
+        let invalidated = |a: Option<&Attachment>| a.is_some_and(|a| a.invalidated);
+        let color = framebuffer.draw_buffers.modes.map(|mode| {
+            mode.and_then(|mode| color_attachment_index(mode.into()))
+                .is_some_and(|idx| {
+                    invalidated(framebuffer.color_attachments[idx as usize].as_ref())
+                })
+        });
+        (
+            color,
+            invalidated(framebuffer.depth_attachment.as_ref()),
+            invalidated(framebuffer.stencil_attachment.as_ref()),
+        )
+    }
+}
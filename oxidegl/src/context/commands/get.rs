@@ -1544,6 +1544,10 @@ impl Context {
                     crate::context::state::MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS.write_out(ptr);
                 }
                 MaxColorAttachments => MAX_COLOR_ATTACHMENTS.write_out(ptr),
+                MaxCombinedTextureImageUnits => state.texture_units.units.len().write_out(ptr),
+                MaxTextureImageUnits | MaxVertexTextureImageUnits | MaxComputeTextureImageUnits => {
+                    state.texture_units.stage_units.write_out(ptr);
+                }
 
                 // singleton buffer bindings
                 ArrayBufferBinding => {
@@ -1620,10 +1624,96 @@ impl Context {
                     .write_out(ptr),
                 MajorVersion => 4.write_out(ptr),
                 MinorVersion => 6.write_out(ptr),
-                //todo constants for these
-                MaxTextureSize => 16384.write_out(ptr),
-                MaxTextureBufferSize => 64_000_000.write_out(ptr),
-                MaxArrayTextureLayers => 2048.write_out(ptr),
+                MaxTextureSize | MaxCubeMapTextureSize | MaxRectangleTextureSize => {
+                    crate::context::state::MAX_TEXTURE_SIZE.write_out(ptr);
+                }
+                Max3DTextureSize => crate::context::state::MAX_3D_TEXTURE_SIZE.write_out(ptr),
+                MaxTextureBufferSize => {
+                    crate::context::state::MAX_TEXTURE_BUFFER_SIZE.write_out(ptr);
+                }
+                TextureBufferOffsetAlignment => self
+                    .platform_state
+                    .props
+                    .texture_buffer_offset_alignment()
+                    .write_out(ptr),
+                MaxArrayTextureLayers => {
+                    crate::context::state::MAX_ARRAY_TEXTURE_LAYERS.write_out(ptr);
+                }
+                MaxColorTextureSamples | MaxDepthTextureSamples | MaxIntegerSamples => {
+                    self.platform_state.props.max_sample_count().write_out(ptr);
+                }
+
+                // texture bindings
+                ActiveTexture => {
+                    (crate::enums::GL_TEXTURE0 as usize + state.texture_units.active)
+                        .write_out(ptr);
+                }
+                TextureBinding1D => state.texture_units.active().texture_1d.write_out(ptr),
+                TextureBinding2D => state.texture_units.active().texture_2d.write_out(ptr),
+                TextureBinding3D => state.texture_units.active().texture_3d.write_out(ptr),
+                TextureBinding1DArray => {
+                    state.texture_units.active().texture_1d_array.write_out(ptr);
+                }
+                TextureBinding2DArray => {
+                    state.texture_units.active().texture_2d_array.write_out(ptr);
+                }
+                TextureBindingRectangle => state.texture_units.active().rectangle.write_out(ptr),
+                TextureBindingCubeMap => state.texture_units.active().cube_map.write_out(ptr),
+                TextureBindingCubeMapArray => {
+                    state.texture_units.active().cube_map_array.write_out(ptr);
+                }
+                TextureBindingBuffer => state.texture_units.active().buffer.write_out(ptr),
+                TextureBinding2DMultisample => {
+                    state
+                        .texture_units
+                        .active()
+                        .texture_2d_multisample
+                        .write_out(ptr);
+                }
+                TextureBinding2DMultisampleArray => {
+                    state
+                        .texture_units
+                        .active()
+                        .texture_2d_multisample_array
+                        .write_out(ptr);
+                }
+                SamplerBinding => {
+                    state.texture_units.units[state.texture_units.active]
+                        .sampler
+                        .write_out(ptr);
+                }
+
+                // Pixel store state
+                PackSwapBytes => state.pack_modes.swap_bytes.write_out(ptr),
+                PackLsbFirst => state.pack_modes.lsb_first.write_out(ptr),
+                PackRowLength => state.pack_modes.row_length.write_out(ptr),
+                PackImageHeight => state.pack_modes.image_height.write_out(ptr),
+                PackSkipPixels => state.pack_modes.skip_pixels.write_out(ptr),
+                PackSkipRows => state.pack_modes.skip_rows.write_out(ptr),
+                PackSkipImages => state.pack_modes.skip_images.write_out(ptr),
+                PackAlignment => state.pack_modes.alignment.write_out(ptr),
+                UnpackSwapBytes => state.unpack_modes.swap_bytes.write_out(ptr),
+                UnpackLsbFirst => state.unpack_modes.lsb_first.write_out(ptr),
+                UnpackRowLength => state.unpack_modes.row_length.write_out(ptr),
+                UnpackImageHeight => state.unpack_modes.image_height.write_out(ptr),
+                UnpackSkipPixels => state.unpack_modes.skip_pixels.write_out(ptr),
+                UnpackSkipRows => state.unpack_modes.skip_rows.write_out(ptr),
+                UnpackSkipImages => state.unpack_modes.skip_images.write_out(ptr),
+                UnpackAlignment => state.unpack_modes.alignment.write_out(ptr),
+
+                // Framebuffer state
+                ReadBuffer => match state.read_framebuffer_binding {
+                    Some(fb) => state.framebufer_list.get(fb).read_buffer.write_out(ptr),
+                    None => state.default_read_buffer.write_out(ptr),
+                },
+                DrawFramebufferBinding => state.draw_framebuffer_binding.write_out(ptr),
+                Samples => self.draw_framebuffer_samples().write_out(ptr),
+                SampleBuffers => (self.draw_framebuffer_samples() > 0).write_out(ptr),
+                ReadFramebufferBinding => state.read_framebuffer_binding.write_out(ptr),
+                RenderbufferBinding => state.renderbuffer_binding.write_out(ptr),
+                MaxRenderbufferSize => {
+                    crate::context::state::MAX_RENDERBUFFER_SIZE.write_out(ptr);
+                }
 
                 // Depth state
                 DepthWritemask => state.writemasks.depth.write_out(ptr),
@@ -1657,7 +1747,7 @@ impl Context {
 
                 // TODO: indexed viewports (scissor rect and viewport is per-viewport indexed state)
                 Viewport => state.viewport.write_out(ptr),
-                //ScissorBox => state.scissor_box.write_out(idx, ptr),
+                ScissorBox => state.scissor_box.write_out(ptr),
 
                 //Bindings
 
@@ -1677,9 +1767,6 @@ impl Context {
 
                 // 0x0BF0 => self.state.logic_op_mode.into(), // GL_LOGIC_OP_MODE
                 // 0x0C01 => RET_TYPE(type, draw_buffer.into(), // GL_DRAW_BUFFER
-                // 0x0C02 => RET_TYPE(type, read_buffer.into(), // GL_READ_BUFFER
-
-                // 0x0C10 => RET_TYPE_VAR_COUNT(type, scissor_box, 4.into(), // GL_SCISSOR_BOX
 
                 // 0x0C22 => RET_TYPE_COUNT(type, color_clear_value, 4.into(), // GL_COLOR_CLEAR_VALUE
 
@@ -1839,10 +1926,6 @@ impl Context {
                 // 0x8905 => self.state.max_program_texel_offset.into(), // GL_MAX_PROGRAM_TEXEL_OFFSET
                 // 0x8C1C => self.state.texture_binding_1d_array.into(), // GL_TEXTURE_BINDING_1D_ARRAY
                 // 0x8C1D => self.state.texture_binding_2d_array.into(), // GL_TEXTURE_BINDING_2D_ARRAY
-                // 0x84E8 => self.state.max_renderbuffer_size.into(), // GL_MAX_RENDERBUFFER_SIZE
-                // 0x8CA6 => self.state.draw_framebuffer_binding.into(), // GL_DRAW_FRAMEBUFFER_BINDING
-                // 0x8CA7 => self.state.renderbuffer_binding.into(), // GL_RENDERBUFFER_BINDING
-                // 0x8CAA => self.state.read_framebuffer_binding.into(), // GL_READ_FRAMEBUFFER_BINDING
                 // 0x85B5 => self.state.vertex_array_binding.into(), // GL_VERTEX_ARRAY_BINDING
                 // 0x8C2B => self.state.max_texture_buffer_size.into(), // GL_MAX_TEXTURE_BUFFER_SIZE
                 // 0x8C2C => self.state.texture_binding_buffer.into(), // GL_TEXTURE_BINDING_BUFFER
@@ -2090,7 +2173,7 @@ impl Context {
 /// always returns a compatible version number. The release number always describes
 /// the server.
 pub mod get_string {
-    use std::ffi::CStr;
+    use std::{ffi::CStr, ptr};
 
     use log::debug;
 
@@ -2108,12 +2191,21 @@ pub mod get_string {
                 }
             }
         };
+        /// Names of the supported extensions, as returned by glGetStringi
+        const EXTENSIONS: [&'static CStr; 1] = [c"GL_EXT_texture_compression_s3tc"];
+        /// `GL_NUM_EXTENSIONS`
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "there are only a handful of extensions"
+        )]
+        pub(crate) const NUM_EXTENSIONS: u32 = Self::EXTENSIONS.len() as u32;
         fn get_string(name: StringName) -> *const GLubyte {
             const VENDOR: &CStr = c"Charles Liske";
             const RENDERER: &CStr = c"OxideGL";
             const VERSION_PREFIX: &[u8] = b"4.6.0 OxideGL ";
 
-            const FAKEEXT: &CStr = c"EXTFakeExtensionToMakeGladHappy";
+            // space-separated list of `EXTENSIONS`
+            const EXTENSION_LIST: &CStr = c"GL_EXT_texture_compression_s3tc";
 
             const VERSION: &[u8] =
                 constcat::concat_bytes!(VERSION_PREFIX, Context::VERSION_INFO.as_bytes(), &[0]);
@@ -2123,7 +2215,7 @@ pub mod get_string {
                 StringName::Vendor => VENDOR.as_ptr().cast(),
                 StringName::Renderer => RENDERER.as_ptr().cast(),
                 StringName::Version | StringName::ShadingLanguageVersion => VERSION.as_ptr(),
-                StringName::Extensions => FAKEEXT.as_ptr().cast(),
+                StringName::Extensions => EXTENSION_LIST.as_ptr().cast(),
             }
         }
         pub(crate) fn oxidegl_get_string(&mut self, name: StringName) -> *const GLubyte {
@@ -2134,7 +2226,12 @@ pub mod get_string {
             name: StringName,
             index: GLuint,
         ) -> *const GLubyte {
-            Self::get_string(name)
+            if name != StringName::Extensions {
+                return Self::get_string(name);
+            }
+            Self::EXTENSIONS
+                .get(index as usize)
+                .map_or(ptr::null(), |ext| ext.as_ptr().cast())
         }
     }
 }
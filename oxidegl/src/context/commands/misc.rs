@@ -5,8 +5,11 @@ use crate::{
         error::{GlFallible, gl_assert},
         state::PixelAlignedRect,
     },
-    dispatch::gl_types::{GLenum, GLfloat, GLint, GLsizei, GLuint},
-    enums::ErrorCode,
+    dispatch::{
+        conversions::sizei,
+        gl_types::{GLenum, GLfloat, GLint, GLsizei, GLuint},
+    },
+    enums::{ErrorCode, PixelStoreParameter},
     util::run_if_changed,
 };
 
@@ -49,8 +52,25 @@ impl Context {
     ///
     /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
     /// [`GL_SCISSOR_TEST`](crate::enums::GL_SCISSOR_TEST)
-    pub fn oxidegl_scissor(&mut self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
-        panic!("command oxidegl_scissor not yet implemented");
+    #[expect(clippy::cast_sign_loss)]
+    pub fn oxidegl_scissor(
+        &mut self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        gl_debug!("glScissor, x {x} y {y} width {width} height {height}");
+        gl_assert!(width >= 0 && height >= 0, InvalidValue);
+        debug_assert!(x >= 0 && y >= 0, "negative base coordinate in glScissor");
+
+        run_if_changed!(self.gl_state.scissor_box;= PixelAlignedRect {
+            x: x as u32,
+            y: y as u32,
+            width: width as u32,
+            height: height as u32,
+        } => self.update_encoder());
+        Ok(())
     }
 
     /// ### Parameters
@@ -286,3 +306,369 @@ impl Context {
         r.into()
     }
 }
+
+/// ### Parameters
+/// `pname`
+///
+/// > Specifies the symbolic name of the parameter to be set. Six values affect
+/// > the packing of pixel data into memory: [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES),
+/// > [`GL_PACK_LSB_FIRST`](crate::enums::GL_PACK_LSB_FIRST), [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH),
+/// > [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT), [`GL_PACK_SKIP_PIXELS`](crate::enums::GL_PACK_SKIP_PIXELS),
+/// > [`GL_PACK_SKIP_ROWS`](crate::enums::GL_PACK_SKIP_ROWS), [`GL_PACK_SKIP_IMAGES`](crate::enums::GL_PACK_SKIP_IMAGES),
+/// > and [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT). Six more affect
+/// > the unpacking of pixel data *from* memory: [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES),
+/// > [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST), [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH),
+/// > [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT), [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS),
+/// > [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES),
+/// > and [`GL_UNPACK_ALIGNMENT`](crate::enums::GL_UNPACK_ALIGNMENT).
+///
+/// `param`
+///
+/// > Specifies the value that `pname` is set to.
+///
+/// ### Description
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) sets pixel
+/// storage modes that affect the operation of subsequent [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+/// as well as the unpacking of texture patterns (see [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d),
+/// [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d),
+/// [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d)
+/// ), [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d),
+/// [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d),
+/// [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d),
+/// [**glCompressedTexSubImage1D**](crate::context::Context::oxidegl_compressed_tex_sub_image1_d),
+/// [**glCompressedTexSubImage2D**](crate::context::Context::oxidegl_compressed_tex_sub_image2_d)
+/// or [**glCompressedTexSubImage1D**](crate::context::Context::oxidegl_compressed_tex_sub_image1_d).
+///
+/// `pname` is a symbolic constant indicating the parameter to be set, and
+/// `param` is the new value. Six of the twelve storage parameters affect how
+/// pixel data is returned to client memory. They are as follows:
+///
+/// [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES)
+///
+/// > If true, byte ordering for multibyte color components, depth components,
+/// > or stencil indices is reversed. That is, if a four-byte component consists
+/// > of bytes `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES)
+/// > is true. [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES) has no
+/// > effect on the memory order of components within a pixel, only on the order
+/// > of bytes within components or indices. For example, the three components
+/// > of a [`GL_RGB`](crate::enums::GL_RGB) format pixel are always stored with
+/// > red first, green second, and blue third, regardless of the value of [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES).
+///
+/// [`GL_PACK_LSB_FIRST`](crate::enums::GL_PACK_LSB_FIRST)
+///
+/// > If true, bits are ordered within a byte from least significant to most
+/// > significant; otherwise, the first bit in each byte is the most significant
+/// > one.
+///
+/// [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH)
+///
+/// > If greater than 0, [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH)
+/// > defines the number of pixels in a row. If the first pixel of a row is placed
+/// > at location `[inlineq]`
+///
+///
+/// > components or indices, where `[inlineq]` `[inlineq]` [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH)
+/// > if it is greater than 0, the `[inlineq]` `[inlineq]` [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT),
+/// > and `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// > `[inlineq]`
+///
+/// > components or indices.
+///
+/// > The word *component* in this description refers to the nonindex values
+/// > red, green, blue, alpha, and depth. Storage format [`GL_RGB`](crate::enums::GL_RGB),
+/// > for example, has three components per pixel: first red, then green, and
+/// > finally blue.
+///
+/// [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT)
+///
+/// > If greater than 0, [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT)
+/// > defines the number of pixels in an image three-dimensional texture volume,
+/// > where ``image`` is defined by all pixels sharing the same third dimension
+/// > index. If the first pixel of a row is placed at location `[inlineq]`
+///
+///
+/// > components or indices, where `[inlineq]` `[inlineq]` [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH)
+/// > if it is greater than 0, the `[inlineq]` [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+/// > otherwise), `[inlineq]` [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT)
+/// > if it is greater than 0, the `[inlineq]` [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+/// > routine otherwise), `[inlineq]` [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT),
+/// > and `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// > The word *component* in this description refers to the nonindex values
+/// > red, green, blue, alpha, and depth. Storage format [`GL_RGB`](crate::enums::GL_RGB),
+/// > for example, has three components per pixel: first red, then green, and
+/// > finally blue.
+///
+/// [`GL_PACK_SKIP_PIXELS`](crate::enums::GL_PACK_SKIP_PIXELS), [`GL_PACK_SKIP_ROWS`](crate::enums::GL_PACK_SKIP_ROWS),
+/// and [`GL_PACK_SKIP_IMAGES`](crate::enums::GL_PACK_SKIP_IMAGES)
+///
+/// > These values are provided as a convenience to the programmer; they provide
+/// > no functionality that cannot be duplicated simply by incrementing the pointer
+/// > passed to [**glReadPixels**](crate::context::Context::oxidegl_read_pixels).
+/// > Setting [`GL_PACK_SKIP_PIXELS`](crate::enums::GL_PACK_SKIP_PIXELS) to
+/// > `[inlineq]` `[inlineq]` `[inlineq]` [`GL_PACK_SKIP_ROWS`](crate::enums::GL_PACK_SKIP_ROWS)
+/// > to `[inlineq]` `[inlineq]` `[inlineq]` [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH)
+/// > section. Setting [`GL_PACK_SKIP_IMAGES`](crate::enums::GL_PACK_SKIP_IMAGES)
+/// > to `[inlineq]` `[inlineq]` `[inlineq]` [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT)
+/// > section.
+///
+/// [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT)
+///
+/// > Specifies the alignment requirements for the start of each pixel row in
+/// > memory. The allowable values are 1 (byte-alignment), 2 (rows aligned to
+/// > even-numbered bytes), 4 (word-alignment), and 8 (rows start on double-word
+/// > boundaries).
+///
+/// The other six of the twelve storage parameters affect how pixel data is
+/// read from client memory. These values are significant for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d),
+/// [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d),
+/// and [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d)
+///
+/// They are as follows:
+///
+/// [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES)
+///
+/// > If true, byte ordering for multibyte color components, depth components,
+/// > or stencil indices is reversed. That is, if a four-byte component consists
+/// > of bytes `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES)
+/// > is true. [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES) has
+/// > no effect on the memory order of components within a pixel, only on the
+/// > order of bytes within components or indices. For example, the three components
+/// > of a [`GL_RGB`](crate::enums::GL_RGB) format pixel are always stored with
+/// > red first, green second, and blue third, regardless of the value of [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES).
+///
+/// [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST)
+///
+/// > If true, bits are ordered within a byte from least significant to most
+/// > significant; otherwise, the first bit in each byte is the most significant
+/// > one.
+///
+/// [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH)
+///
+/// > If greater than 0, [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH)
+/// > defines the number of pixels in a row. If the first pixel of a row is placed
+/// > at location `[inlineq]`
+///
+///
+/// > components or indices, where `[inlineq]` `[inlineq]` [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH)
+/// > if it is greater than 0, the `[inlineq]` `[inlineq]` [`GL_UNPACK_ALIGNMENT`](crate::enums::GL_UNPACK_ALIGNMENT),
+/// > and `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// > `[inlineq]`
+///
+/// > components or indices.
+///
+/// > The word *component* in this description refers to the nonindex values
+/// > red, green, blue, alpha, and depth. Storage format [`GL_RGB`](crate::enums::GL_RGB),
+/// > for example, has three components per pixel: first red, then green, and
+/// > finally blue.
+///
+/// [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT)
+///
+/// > If greater than 0, [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT)
+/// > defines the number of pixels in an image of a three-dimensional texture
+/// > volume. Where ``image`` is defined by all pixel sharing the same third
+/// > dimension index. If the first pixel of a row is placed at location `[inlineq]`
+///
+///
+/// > components or indices, where `[inlineq]` `[inlineq]` [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH)
+/// > if it is greater than 0, the `[inlineq]` [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+/// > otherwise), `[inlineq]` [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT)
+/// > if it is greater than 0, the `[inlineq]` [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+/// > otherwise), `[inlineq]` [`GL_UNPACK_ALIGNMENT`](crate::enums::GL_UNPACK_ALIGNMENT),
+/// > and `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// > The word *component* in this description refers to the nonindex values
+/// > red, green, blue, alpha, and depth. Storage format [`GL_RGB`](crate::enums::GL_RGB),
+/// > for example, has three components per pixel: first red, then green, and
+/// > finally blue.
+///
+/// [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS) and [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+///
+/// > These values are provided as a convenience to the programmer; they provide
+/// > no functionality that cannot be duplicated by incrementing the pointer
+/// > passed to [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d)
+/// > or [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d).
+/// > Setting [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+/// > to `[inlineq]` `[inlineq]` `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+/// > to `[inlineq]` `[inlineq]` `[inlineq]` [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH)
+/// > section.
+///
+/// [`GL_UNPACK_ALIGNMENT`](crate::enums::GL_UNPACK_ALIGNMENT)
+///
+/// > Specifies the alignment requirements for the start of each pixel row in
+/// > memory. The allowable values are 1 (byte-alignment), 2 (rows aligned to
+/// > even-numbered bytes), 4 (word-alignment), and 8 (rows start on double-word
+/// > boundaries).
+///
+/// The following table gives the type, initial value, and range of valid values
+/// for each storage parameter that can be set with [**glPixelStore**](crate::context::Context::oxidegl_pixel_store).
+///
+///
+/// |**  |* Type*     |* Initial Value*                           |* Valid Range*        |
+/// |----|------------|-------------------------------------------|----------------------|
+/// | [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES) | boolean | false | true or false |
+/// | [`GL_PACK_LSB_FIRST`](crate::enums::GL_PACK_LSB_FIRST) | boolean | false | true or false |
+/// | [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH) | integer | 0 | `[inlineq]` |
+/// | [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT) | integer | 0 | `[inlineq]` |
+/// | [`GL_PACK_SKIP_ROWS`](crate::enums::GL_PACK_SKIP_ROWS) | integer | 0 | `[inlineq]` |
+/// | [`GL_PACK_SKIP_PIXELS`](crate::enums::GL_PACK_SKIP_PIXELS) | integer | 0 | `[inlineq]` |
+/// | [`GL_PACK_SKIP_IMAGES`](crate::enums::GL_PACK_SKIP_IMAGES) | integer | 0 | `[inlineq]` |
+/// | [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT) | integer | 4 | 1, 2, 4, or 8 |
+/// | [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES) | boolean | false | true or false |
+/// | [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST) | boolean | false | true or false |
+/// | [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH) | integer | 0 | `[inlineq]` |
+/// | [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT) | integer | 0 | `[inlineq]` |
+/// | [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS) | integer | 0 | `[inlineq]` |
+/// | [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS) | integer | 0 | `[inlineq]` |
+/// | [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES) | integer | 0 | `[inlineq]` |
+/// | [`GL_UNPACK_ALIGNMENT`](crate::enums::GL_UNPACK_ALIGNMENT) | integer | 4 | 1, 2, 4, or 8 |
+///
+/// [**glPixelStoref**](crate::context::Context::oxidegl_pixel_storef) can
+/// be used to set any pixel store parameter. If the parameter type is boolean,
+/// then if `param` is 0, the parameter is false; otherwise it is set to true.
+/// If `pname` is an integer type parameter, `param` is rounded to the nearest
+/// integer.
+///
+/// Likewise, [**glPixelStorei**](crate::context::Context::oxidegl_pixel_storei)
+/// can also be used to set any of the pixel store parameters. Boolean parameters
+/// are set to false if `param` is 0 and true otherwise.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_SWAP_BYTES`](crate::enums::GL_PACK_SWAP_BYTES)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_LSB_FIRST`](crate::enums::GL_PACK_LSB_FIRST)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_ROW_LENGTH`](crate::enums::GL_PACK_ROW_LENGTH)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_IMAGE_HEIGHT`](crate::enums::GL_PACK_IMAGE_HEIGHT)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_SKIP_ROWS`](crate::enums::GL_PACK_SKIP_ROWS)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_SKIP_PIXELS`](crate::enums::GL_PACK_SKIP_PIXELS)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_SKIP_IMAGES`](crate::enums::GL_PACK_SKIP_IMAGES)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_SWAP_BYTES`](crate::enums::GL_UNPACK_SWAP_BYTES)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_UNPACK_ALIGNMENT`](crate::enums::GL_UNPACK_ALIGNMENT)
+impl Context {
+    pub(crate) fn oxidegl_pixel_storef(
+        &mut self,
+        pname: PixelStoreParameter,
+        param: GLfloat,
+    ) -> GlFallible {
+        let param = if matches!(
+            pname,
+            PixelStoreParameter::PackSwapBytes
+                | PixelStoreParameter::PackLsbFirst
+                | PixelStoreParameter::UnpackSwapBytes
+                | PixelStoreParameter::UnpackLsbFirst
+        ) {
+            GLint::from(param != 0.0)
+        } else {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "out of range values are saturated and rejected by glPixelStorei"
+            )]
+            {
+                param.round() as GLint
+            }
+        };
+        self.oxidegl_pixel_storei(pname, param)
+    }
+    pub(crate) fn oxidegl_pixel_storei(
+        &mut self,
+        pname: PixelStoreParameter,
+        param: GLint,
+    ) -> GlFallible {
+        use PixelStoreMode as P;
+        gl_debug!("glPixelStore {pname:?} = {param}");
+        let (modes, parameter) = match pname {
+            PixelStoreParameter::PackSwapBytes => (&mut self.gl_state.pack_modes, P::SwapBytes),
+            PixelStoreParameter::PackLsbFirst => (&mut self.gl_state.pack_modes, P::LsbFirst),
+            PixelStoreParameter::PackRowLength => (&mut self.gl_state.pack_modes, P::RowLength),
+            PixelStoreParameter::PackImageHeight => (&mut self.gl_state.pack_modes, P::ImageHeight),
+            PixelStoreParameter::PackSkipPixels => (&mut self.gl_state.pack_modes, P::SkipPixels),
+            PixelStoreParameter::PackSkipRows => (&mut self.gl_state.pack_modes, P::SkipRows),
+            PixelStoreParameter::PackSkipImages => (&mut self.gl_state.pack_modes, P::SkipImages),
+            PixelStoreParameter::PackAlignment => (&mut self.gl_state.pack_modes, P::Alignment),
+            PixelStoreParameter::UnpackSwapBytes => (&mut self.gl_state.unpack_modes, P::SwapBytes),
+            PixelStoreParameter::UnpackLsbFirst => (&mut self.gl_state.unpack_modes, P::LsbFirst),
+            PixelStoreParameter::UnpackRowLength => (&mut self.gl_state.unpack_modes, P::RowLength),
+            PixelStoreParameter::UnpackImageHeight => {
+                (&mut self.gl_state.unpack_modes, P::ImageHeight)
+            }
+            PixelStoreParameter::UnpackSkipPixels => {
+                (&mut self.gl_state.unpack_modes, P::SkipPixels)
+            }
+            PixelStoreParameter::UnpackSkipRows => (&mut self.gl_state.unpack_modes, P::SkipRows),
+            PixelStoreParameter::UnpackSkipImages => {
+                (&mut self.gl_state.unpack_modes, P::SkipImages)
+            }
+            PixelStoreParameter::UnpackAlignment => (&mut self.gl_state.unpack_modes, P::Alignment),
+        };
+        if let P::SwapBytes | P::LsbFirst = parameter {
+            let value = param != 0;
+            if parameter == P::SwapBytes {
+                modes.swap_bytes = value;
+            } else {
+                modes.lsb_first = value;
+            }
+            return Ok(());
+        }
+        sizei!(param);
+        match parameter {
+            P::Alignment => {
+                gl_assert!(
+                    matches!(param, 1 | 2 | 4 | 8),
+                    InvalidValue,
+                    "pixel store alignment must be 1, 2, 4 or 8 (got {param})"
+                );
+                modes.alignment = param;
+            }
+            P::RowLength => modes.row_length = param,
+            P::ImageHeight => modes.image_height = param,
+            P::SkipPixels => modes.skip_pixels = param,
+            P::SkipRows => modes.skip_rows = param,
+            P::SkipImages => modes.skip_images = param,
+            P::SwapBytes | P::LsbFirst => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+/// A pixel storage parameter, independent of whether it applies to packing or unpacking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PixelStoreMode {
+    SwapBytes,
+    LsbFirst,
+    RowLength,
+    ImageHeight,
+    SkipPixels,
+    SkipRows,
+    SkipImages,
+    Alignment,
+}
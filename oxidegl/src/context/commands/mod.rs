@@ -3,10 +3,14 @@ pub mod caps;
 pub mod clear;
 pub mod debug;
 pub mod draw;
+pub mod framebuffer;
 pub mod get;
 pub mod misc;
 pub mod programs;
+pub mod renderbuffer;
+pub mod sampler;
 pub mod shaders;
+pub mod texture;
 #[allow(clippy::missing_safety_doc, clippy::missing_errors_doc)]
 pub mod unimplemented;
 pub mod vao;
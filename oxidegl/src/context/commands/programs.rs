@@ -269,7 +269,11 @@ impl Context {
             .gl_state
             .program_list
             .get_program_raw_mut(&self.gl_state.shader_list, program)?;
-        program.link(&mut self.gl_state.shader_list, &self.platform_state.device);
+        program.link(
+            &mut self.gl_state.shader_list,
+            &self.platform_state.device,
+            &self.platform_state.props,
+        );
         Ok(())
     }
     /// ### Parameters
@@ -0,0 +1,540 @@
+use core::slice;
+
+use crate::{
+    context::{
+        Context,
+        debug::{gl_debug, gl_err, gl_trace},
+        error::{GlError, GlFallible, gl_assert},
+        framebuffer::RenderBuffer,
+        gl_object::ObjectName,
+        state::MAX_RENDERBUFFER_SIZE,
+    },
+    dispatch::{
+        conversions::{GlGetItem, sizei},
+        gl_types::{GLboolean, GLenum, GLint, GLsizei, GLuint},
+    },
+    enums::{GL_RENDERBUFFER, InternalFormat, RenderbufferParameterName},
+};
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the renderbuffer object is bound for [**glGetRenderbufferParameteriv**](crate::context::Context::oxidegl_get_renderbuffer_parameteriv).
+/// > `target` must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer`
+///
+/// > Specifies the name of the renderbuffer object for [**glGetNamedRenderbufferParameteriv**](crate::context::Context::oxidegl_get_named_renderbuffer_parameteriv).
+///
+/// `pname`
+///
+/// > Specifies the parameter of the renderbuffer object to query.
+///
+/// `params`
+///
+/// > Returns the value of parameter `pname` for the renderbuffer object.
+///
+/// ### Description
+/// [**glGetRenderbufferParameteriv**](crate::context::Context::oxidegl_get_renderbuffer_parameteriv)
+/// and [**glGetNamedRenderbufferParameteriv**](crate::context::Context::oxidegl_get_named_renderbuffer_parameteriv)
+/// query parameters of a specified renderbuffer object.
+///
+/// For [**glGetRenderbufferParameteriv**](crate::context::Context::oxidegl_get_renderbuffer_parameteriv),
+/// the renderbuffer object is that bound to `target`, which must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+///
+/// For [**glGetNamedRenderbufferParameteriv**](crate::context::Context::oxidegl_get_named_renderbuffer_parameteriv),
+/// `renderbuffer` is the name of the renderbuffer object.
+///
+/// Upon successful return, `param` will contain the value of the renderbuffer
+/// parameter specified by `pname`, as described below.
+///
+/// [`GL_RENDERBUFFER_WIDTH`](crate::enums::GL_RENDERBUFFER_WIDTH), [`GL_RENDERBUFFER_HEIGHT`](crate::enums::GL_RENDERBUFFER_HEIGHT),
+/// [`GL_RENDERBUFFER_INTERNAL_FORMAT`](crate::enums::GL_RENDERBUFFER_INTERNAL_FORMAT)
+/// or [`GL_RENDERBUFFER_SAMPLES`](crate::enums::GL_RENDERBUFFER_SAMPLES)
+///
+/// > `params` returns the width in pixels, the height in pixels, internal format,
+/// > or the number of samples, respectively, of the image of the specified renderbuffer
+/// > object.
+///
+/// [`GL_RENDERBUFFER_RED_SIZE`](crate::enums::GL_RENDERBUFFER_RED_SIZE), [`GL_RENDERBUFFER_GREEN_SIZE`](crate::enums::GL_RENDERBUFFER_GREEN_SIZE),
+/// [`GL_RENDERBUFFER_BLUE_SIZE`](crate::enums::GL_RENDERBUFFER_BLUE_SIZE),
+/// [`GL_RENDERBUFFER_ALPHA_SIZE`](crate::enums::GL_RENDERBUFFER_ALPHA_SIZE),
+/// [`GL_RENDERBUFFER_DEPTH_SIZE`](crate::enums::GL_RENDERBUFFER_DEPTH_SIZE)
+/// or [`GL_RENDERBUFFER_STENCIL_SIZE`](crate::enums::GL_RENDERBUFFER_STENCIL_SIZE)
+///
+/// > `params` returns the actual resolution in bits (not the resolution specified
+/// > when the image was defined) for the red, green, blue, alpha, depth or stencil
+/// > components, respectively, of the image of the renderbuffer object.
+impl Context {
+    pub(crate) unsafe fn oxidegl_get_renderbuffer_parameteriv(
+        &mut self,
+        target: GLenum,
+        pname: RenderbufferParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let rb = self.bound_renderbuffer(target)?;
+        // Safety: Caller ensures validity
+        unsafe { self.get_renderbuffer_parameter_internal(rb, pname, params) };
+        Ok(())
+    }
+    pub(crate) unsafe fn oxidegl_get_named_renderbuffer_parameteriv(
+        &mut self,
+        renderbuffer: GLuint,
+        pname: RenderbufferParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let rb = self.renderbuffer_by_name(renderbuffer)?;
+        // Safety: Caller ensures validity
+        unsafe { self.get_renderbuffer_parameter_internal(rb, pname, params) };
+        Ok(())
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies a binding target of the allocation for [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// > function. Must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer`
+///
+/// > Specifies the name of the renderbuffer object for [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
+/// > function.
+///
+/// `internalformat`
+///
+/// > Specifies the internal format to use for the renderbuffer object's image.
+///
+/// `width`
+///
+/// > Specifies the width of the renderbuffer, in pixels.
+///
+/// `height`
+///
+/// > Specifies the height of the renderbuffer, in pixels.
+///
+/// ### Description
+/// [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// is equivalent to calling [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// with the `samples` set to zero, and [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
+/// is equivalent to calling [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// with the samples set to zero.
+///
+/// For [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage),
+/// the target of the operation, specified by `target` must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+/// For [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage),
+/// `renderbuffer` must be a name of an existing renderbuffer object. `internalformat`
+/// specifies the internal format to be used for the renderbuffer object's
+/// storage and must be a color-renderable, depth-renderable, or stencil-renderable
+/// format. `width` and `height` are the dimensions, in pixels, of the renderbuffer.
+/// Both `width` and `height` must be less than or equal to the value of [`GL_MAX_RENDERBUFFER_SIZE`](crate::enums::GL_MAX_RENDERBUFFER_SIZE).
+///
+/// Upon success, [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// and [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
+/// delete any existing data store for the renderbuffer image and the contents
+/// of the data store after calling [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// are undefined.
+impl Context {
+    pub(crate) fn oxidegl_renderbuffer_storage(
+        &mut self,
+        target: GLenum,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        let rb = self.bound_renderbuffer(target)?;
+        self.renderbuffer_storage_internal(rb, 0, internalformat, width, height)
+    }
+    pub(crate) fn oxidegl_named_renderbuffer_storage(
+        &mut self,
+        renderbuffer: GLuint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        let rb = self.renderbuffer_by_name(renderbuffer)?;
+        self.renderbuffer_storage_internal(rb, 0, internalformat, width, height)
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies a binding target of the allocation for [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// > function. Must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer`
+///
+/// > Specifies the name of the renderbuffer object for [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// > function.
+///
+/// `samples`
+///
+/// > Specifies the number of samples to be used for the renderbuffer object's
+/// > storage.
+///
+/// `internalformat`
+///
+/// > Specifies the internal format to use for the renderbuffer object's image.
+///
+/// `width`
+///
+/// > Specifies the width of the renderbuffer, in pixels.
+///
+/// `height`
+///
+/// > Specifies the height of the renderbuffer, in pixels.
+///
+/// ### Description
+/// [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// and [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// establish the data storage, format, dimensions and number of samples of
+/// a renderbuffer object's image.
+///
+/// For [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample),
+/// the target of the operation, specified by `target` must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+/// For [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample),
+/// `renderbuffer` must be an ID of an existing renderbuffer object. `internalformat`
+/// specifies the internal format to be used for the renderbuffer object's
+/// storage and must be a color-renderable, depth-renderable, or stencil-renderable
+/// format. `width` and `height` are the dimensions, in pixels, of the renderbuffer.
+/// Both `width` and `height` must be less than or equal to the value of [`GL_MAX_RENDERBUFFER_SIZE`](crate::enums::GL_MAX_RENDERBUFFER_SIZE).
+/// `samples` specifies the number of samples to be used for the renderbuffer
+/// object's image, and must be less than or equal to the value of [`GL_MAX_SAMPLES`](crate::enums::GL_MAX_SAMPLES).
+/// If `internalformat` is a signed or unsigned integer format then `samples`
+/// must be less than or equal to the value of [`GL_MAX_INTEGER_SAMPLES`](crate::enums::GL_MAX_INTEGER_SAMPLES).
+///
+/// Upon success, [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// and [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// delete any existing data store for the renderbuffer image and the contents
+/// of the data store after calling either of the functions are undefined.
+impl Context {
+    pub(crate) fn oxidegl_renderbuffer_storage_multisample(
+        &mut self,
+        target: GLenum,
+        samples: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        let rb = self.bound_renderbuffer(target)?;
+        self.renderbuffer_storage_internal(rb, samples, internalformat, width, height)
+    }
+    pub(crate) fn oxidegl_named_renderbuffer_storage_multisample(
+        &mut self,
+        renderbuffer: GLuint,
+        samples: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        let rb = self.renderbuffer_by_name(renderbuffer)?;
+        self.renderbuffer_storage_internal(rb, samples, internalformat, width, height)
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the renderbuffer target of the binding operation. `target` must
+    /// > be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+    ///
+    /// `renderbuffer`
+    ///
+    /// > Specifies the name of the renderbuffer object to bind.
+    ///
+    /// ### Description
+    /// [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
+    /// binds the renderbuffer object with name `renderbuffer` to the renderbuffer
+    /// target specified by `target`. `target` must be [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER).
+    /// `renderbuffer` is the name of a renderbuffer object previously returned
+    /// from a call to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers),
+    /// or zero to break the existing binding of a renderbuffer object to `target`.
+    pub(crate) fn oxidegl_bind_renderbuffer(
+        &mut self,
+        target: GLenum,
+        renderbuffer: GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            target == GL_RENDERBUFFER,
+            InvalidEnum,
+            "{target:#X} is not GL_RENDERBUFFER"
+        );
+        let name = ObjectName::try_from_raw(renderbuffer).ok();
+        if let Some(name) = name {
+            self.gl_state
+                .renderbuffer_list
+                .ensure_init(name, RenderBuffer::new_default)?;
+        }
+        self.gl_state.renderbuffer_binding = name;
+        gl_debug!("bound {name:?} to GL_RENDERBUFFER");
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Number of renderbuffer objects to create.
+    ///
+    /// `renderbuffers`
+    ///
+    /// > Specifies an array in which names of the new renderbuffer objects are stored.
+    ///
+    /// ### Description
+    /// [**glCreateRenderbuffers**](crate::context::Context::oxidegl_create_renderbuffers)
+    /// returns `n` previously unused renderbuffer object names in `renderbuffers`,
+    /// each representing a new renderbuffer object initialized to the default
+    /// state.
+    pub(crate) unsafe fn oxidegl_create_renderbuffers(
+        &mut self,
+        n: GLsizei,
+        renderbuffers: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state
+                .renderbuffer_list
+                .create_obj(RenderBuffer::new_default, n, renderbuffers);
+        }
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of renderbuffer objects to be deleted.
+    ///
+    /// `renderbuffers`
+    ///
+    /// > A pointer to an array containing `n` renderbuffer objects to be deleted.
+    ///
+    /// ### Description
+    /// [**glDeleteRenderbuffers**](crate::context::Context::oxidegl_delete_renderbuffers)
+    /// deletes the `n` renderbuffer objects whose names are stored in the array
+    /// addressed by `renderbuffers`. The name zero is reserved by the GL and is
+    /// silently ignored, should it occur in `renderbuffers`, as are other unused
+    /// names. Once a renderbuffer object is deleted, its name is again unused
+    /// and it has no contents. If a renderbuffer that is currently bound to the
+    /// target [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER) is deleted, it
+    /// is as though [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
+    /// had been executed with a `target` of [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER)
+    /// and a `name` of zero.
+    ///
+    /// If a renderbuffer object is attached to one or more attachment points in
+    /// the currently bound framebuffer, then it as if [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer)
+    /// had been called, with a `renderbuffer` of zero for each attachment point
+    /// to which this image was attached in the currently bound framebuffer. In
+    /// other words, this renderbuffer object is first detached from all attachment
+    /// ponits in the currently bound framebuffer. Note that the renderbuffer image
+    /// is specifically *not* detached from any non-bound framebuffers.
+    pub(crate) unsafe fn oxidegl_delete_renderbuffers(
+        &mut self,
+        n: GLsizei,
+        renderbuffers: *const GLuint,
+    ) -> GlFallible {
+        sizei!(n, count);
+        if count > 0 {
+            // Safety: caller ensures that n and renderbuffers form a valid u32 slice
+            for &name in unsafe { slice::from_raw_parts(renderbuffers, count as usize) } {
+                if let Ok(name) = ObjectName::try_from_raw(name) {
+                    if self.gl_state.renderbuffer_binding == Some(name) {
+                        self.gl_state.renderbuffer_binding = None;
+                    }
+                    self.detach_from_bound_framebuffers(|a| a.renderbuffer_name() == Some(name));
+                }
+            }
+        }
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state
+                .renderbuffer_list
+                .delete_objects(n, renderbuffers);
+        }
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of renderbuffer object names to generate.
+    ///
+    /// `renderbuffers`
+    ///
+    /// > Specifies an array in which the generated renderbuffer object names are
+    /// > stored.
+    ///
+    /// ### Description
+    /// [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
+    /// returns `n` renderbuffer object names in `renderbuffers`. There is no guarantee
+    /// that the names form a contiguous set of integers; however, it is guaranteed
+    /// that none of the returned names was in use immediately before the call
+    /// to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers).
+    ///
+    /// Renderbuffer object names returned by a call to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteRenderbuffers**](crate::context::Context::oxidegl_delete_renderbuffers).
+    ///
+    /// The names returned in `renderbuffers` are marked as used, for the purposes
+    /// of [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
+    /// only, but they acquire state and type only when they are first bound.
+    pub(crate) unsafe fn oxidegl_gen_renderbuffers(
+        &mut self,
+        n: GLsizei,
+        renderbuffers: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller ensures validity
+        unsafe { self.gl_state.renderbuffer_list.gen_obj(n, renderbuffers) };
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `renderbuffer`
+    ///
+    /// > Specifies a value that may be the name of a renderbuffer object.
+    ///
+    /// ### Description
+    /// [**glIsRenderbuffer**](crate::context::Context::oxidegl_is_renderbuffer)
+    /// returns [`GL_TRUE`](crate::enums::GL_TRUE) if `renderbuffer` is currently
+    /// the name of a renderbuffer object. If `renderbuffer` is zero, or if `renderbuffer`
+    /// is not the name of a renderbuffer object, or if an error occurs, [**glIsRenderbuffer**](crate::context::Context::oxidegl_is_renderbuffer)
+    /// returns [`GL_FALSE`](crate::enums::GL_FALSE). If `renderbuffer` is a name
+    /// returned by [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers),
+    /// by that has not yet been bound through a call to [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
+    /// or [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer),
+    /// then the name is not a renderbuffer object and [**glIsRenderbuffer**](crate::context::Context::oxidegl_is_renderbuffer)
+    /// returns [`GL_FALSE`](crate::enums::GL_FALSE).
+    pub(crate) fn oxidegl_is_renderbuffer(&mut self, renderbuffer: GLuint) -> GLboolean {
+        self.gl_state.renderbuffer_list.is_obj(renderbuffer)
+    }
+}
+
+impl Context {
+    /// Returns the renderbuffer bound to `target`, which must be `GL_RENDERBUFFER`
+    fn bound_renderbuffer(&self, target: GLenum) -> GlFallible<ObjectName<RenderBuffer>> {
+        gl_assert!(
+            target == GL_RENDERBUFFER,
+            InvalidEnum,
+            "{target:#X} is not GL_RENDERBUFFER"
+        );
+        self.gl_state.renderbuffer_binding.ok_or_else(|| {
+            gl_err!(ty: Error, "no renderbuffer object is bound to GL_RENDERBUFFER");
+            GlError::InvalidOperation.e()
+        })
+    }
+    /// Returns the renderbuffer object named `renderbuffer`, or an error if it doesn't name an existing renderbuffer object
+    pub(crate) fn renderbuffer_by_name(
+        &self,
+        renderbuffer: GLuint,
+    ) -> GlFallible<ObjectName<RenderBuffer>> {
+        ObjectName::try_from_raw(renderbuffer)
+            .ok()
+            .filter(|&name| self.gl_state.renderbuffer_list.is(name))
+            .ok_or_else(|| {
+                gl_err!(ty: Error, "{renderbuffer} does not name an existing renderbuffer object");
+                GlError::InvalidOperation.e()
+            })
+    }
+    /// Shared implementation of glRenderbufferStorage(Multisample) and glNamedRenderbufferStorage(Multisample)
+    fn renderbuffer_storage_internal(
+        &mut self,
+        rb: ObjectName<RenderBuffer>,
+        samples: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(samples);
+        sizei!(width);
+        sizei!(height);
+        gl_assert!(
+            width <= MAX_RENDERBUFFER_SIZE && height <= MAX_RENDERBUFFER_SIZE,
+            InvalidValue,
+            "{width}x{height} exceeds GL_MAX_RENDERBUFFER_SIZE ({MAX_RENDERBUFFER_SIZE})"
+        );
+        gl_assert!(
+            internalformat.is_color_renderable()
+                || (internalformat.has_mtl_texture_format()
+                    && (internalformat.has_depth() || internalformat.has_stencil())),
+            InvalidEnum,
+            "{internalformat:?} is not a color, depth or stencil renderable internal format"
+        );
+        // the sample count actually allocated may exceed the requested count, and is what GL_RENDERBUFFER_SAMPLES reports
+        let samples = if samples == 0 {
+            0
+        } else {
+            self.platform_state
+                .props
+                .sample_count_at_least(samples)
+                .ok_or_else(|| {
+                    gl_err!(ty: Error, "requested sample count ({samples}) exceeds GL_MAX_SAMPLES");
+                    GlError::InvalidValue.e()
+                })?
+        };
+        self.gl_state.renderbuffer_list.get_mut(rb).set_storage(
+            &self.platform_state,
+            internalformat,
+            width,
+            height,
+            samples,
+        );
+        gl_debug!(
+            "specified {width}x{height} {internalformat:?} storage with {samples} samples for {rb:?}"
+        );
+        if let Some(fb) = self.gl_state.draw_framebuffer_binding
+            && self
+                .gl_state
+                .framebufer_list
+                .get(fb)
+                .attachments()
+                .any(|(_, a)| a.renderbuffer_name() == Some(rb))
+        {
+            self.new_encoder();
+            self.new_pipeline();
+        }
+        Ok(())
+    }
+    /// Shared implementation of glGetRenderbufferParameteriv and glGetNamedRenderbufferParameteriv
+    /// # Safety
+    /// `params` must be valid for writes of a `GLint`
+    unsafe fn get_renderbuffer_parameter_internal(
+        &self,
+        rb: ObjectName<RenderBuffer>,
+        pname: RenderbufferParameterName,
+        params: *mut GLint,
+    ) {
+        use RenderbufferParameterName as P;
+        let renderbuffer = self.gl_state.renderbuffer_list.get(rb);
+        // zero-sized renderbuffers have no image whose components could be sized
+        let sizes = if renderbuffer.storage.is_some() {
+            renderbuffer.format.component_sizes()
+        } else {
+            [0; 6]
+        };
+        let value = match pname {
+            P::RenderbufferWidth => renderbuffer.width,
+            P::RenderbufferHeight => renderbuffer.height,
+            P::RenderbufferInternalFormat => renderbuffer.format.into(),
+            P::RenderbufferSamples => renderbuffer.samples,
+            P::RenderbufferRedSize => sizes[0],
+            P::RenderbufferGreenSize => sizes[1],
+            P::RenderbufferBlueSize => sizes[2],
+            P::RenderbufferAlphaSize => sizes[3],
+            P::RenderbufferDepthSize => sizes[4],
+            P::RenderbufferStencilSize => sizes[5],
+        };
+        gl_trace!("{pname:?} of {rb:?} is {value}");
+        // Safety: caller ensures params is valid for writes of a GLint
+        unsafe { value.write_out(params) };
+    }
+}
@@ -0,0 +1,861 @@
+use core::slice;
+
+use crate::{
+    context::{
+        Context,
+        commands::texture::{normalize_color_component, normalized_border_color},
+        debug::{gl_debug, gl_err},
+        error::{GlError, GlFallible, gl_assert},
+        gl_object::ObjectName,
+        texture::{BorderColor, Sampler},
+    },
+    dispatch::{
+        conversions::{GlDstType, GlGetItem, SrcType, sizei},
+        gl_types::{GLboolean, GLfloat, GLint, GLsizei, GLuint},
+    },
+    enums::SamplerParameter,
+};
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of sampler objects to be deleted.
+    ///
+    /// `samplers`
+    ///
+    /// > Specifies an array of sampler objects to be deleted.
+    ///
+    /// ### Description
+    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers)
+    /// deletes `n` sampler objects named by the elements of the array `samplers`.
+    /// After a sampler object is deleted, its name is again unused. If a sampler
+    /// object that is currently bound to a sampler unit is deleted, it is as though
+    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) is called
+    /// with unit set to the unit the sampler is bound to and sampler zero. Unused
+    /// names in samplers are silently ignored, as is the reserved name zero.
+    ///
+    /// ### Notes
+    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers)
+    /// is available only if the GL version is 3.3 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glIsSampler**](crate::context::Context::oxidegl_is_sampler)
+    pub(crate) unsafe fn oxidegl_delete_samplers(
+        &mut self,
+        count: GLsizei,
+        samplers: *const GLuint,
+    ) -> GlFallible {
+        sizei!(count, len);
+        if len > 0 {
+            // Safety: caller ensures that count and samplers form a valid u32 slice
+            for &name in unsafe { slice::from_raw_parts(samplers, len as usize) } {
+                if let Ok(name) = ObjectName::try_from_raw(name) {
+                    self.unbind_sampler_everywhere(name);
+                }
+            }
+        }
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state.sampler_list.delete_objects(count, samplers);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of sampler object names to generate.
+    ///
+    /// `samplers`
+    ///
+    /// > Specifies an array in which the generated sampler object names are stored.
+    ///
+    /// ### Description
+    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) returns
+    /// `n` sampler object names in `samplers`. There is no guarantee that the
+    /// names form a contiguous set of integers; however, it is guaranteed that
+    /// none of the returned names was in use immediately before the call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
+    ///
+    /// Sampler object names returned by a call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers).
+    ///
+    /// The names returned in `samplers` are marked as used, for the purposes of
+    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) only,
+    /// but they acquire state and type only when they are first bound.
+    ///
+    /// ### Notes
+    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) is available
+    /// only if the GL version is 3.3 or higher.
+    pub(crate) unsafe fn oxidegl_gen_samplers(
+        &mut self,
+        count: GLsizei,
+        samplers: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(count >= 0, InvalidValue);
+        // Unlike most other object types, names returned by glGenSamplers immediately name sampler objects
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state
+                .sampler_list
+                .create_obj(Sampler::new_named, count, samplers);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Number of sampler objects to create.
+    ///
+    /// `samplers`
+    ///
+    /// > Specifies an array in which names of the new sampler objects are stored.
+    ///
+    /// ### Description
+    /// [**glCreateSamplers**](crate::context::Context::oxidegl_create_samplers)
+    /// returns `n` previously unused sampler names in `samplers`, each representing
+    /// a new sampler object initialized to the default state.
+    pub(crate) unsafe fn oxidegl_create_samplers(
+        &mut self,
+        n: GLsizei,
+        samplers: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state
+                .sampler_list
+                .create_obj(Sampler::new_named, n, samplers);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `id`
+    ///
+    /// > Specifies a value that may be the name of a sampler object.
+    ///
+    /// ### Description
+    /// [**glIsSampler**](crate::context::Context::oxidegl_is_sampler) returns
+    /// [`GL_TRUE`](crate::enums::GL_TRUE) if `id` is currently the name of a sampler
+    /// object. If `id` is zero, or is a non-zero value that is not currently the
+    /// name of a sampler object, or if an error occurs, [**glIsSampler**](crate::context::Context::oxidegl_is_sampler)
+    /// returns [`GL_FALSE`](crate::enums::GL_FALSE).
+    ///
+    /// A name returned by [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers),
+    /// is the name of a sampler object.
+    ///
+    /// ### Notes
+    /// [**glIsSampler**](crate::context::Context::oxidegl_is_sampler) is available
+    /// only if the GL version is 3.3 or higher.
+    pub(crate) fn oxidegl_is_sampler(&mut self, sampler: GLuint) -> GLboolean {
+        self.gl_state.sampler_list.is_obj(sampler)
+    }
+    /// ### Parameters
+    /// `unit`
+    ///
+    /// > Specifies the index of the texture unit to which the sampler is bound.
+    ///
+    /// `sampler`
+    ///
+    /// > Specifies the name of a sampler.
+    ///
+    /// ### Description
+    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) binds
+    /// `sampler` to the texture unit at index `unit`. `sampler` must be zero or
+    /// the name of a sampler object previously returned from a call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
+    /// `unit` must be less than the value of [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS).
+    ///
+    /// When a sampler object is bound to a texture unit, its state supersedes
+    /// that of the texture object bound to that texture unit. If the sampler name
+    /// zero is bound to a texture unit, the currently bound texture's sampler
+    /// state becomes active. A single sampler object may be bound to multiple
+    /// texture units simultaneously.
+    ///
+    /// ### Notes
+    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) is available
+    /// only if the GL version is 3.3 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SAMPLER_BINDING`](crate::enums::GL_SAMPLER_BINDING)
+    pub(crate) fn oxidegl_bind_sampler(&mut self, unit: GLuint, sampler: GLuint) -> GlFallible {
+        let unit = self.gl_state.texture_units.index(unit)?;
+        let name = (sampler != 0)
+            .then(|| self.sampler_by_name(sampler))
+            .transpose()?;
+        self.bind_sampler_internal(unit, name);
+        gl_debug!("bound {name:?} to texture unit {unit}");
+        Ok(())
+    }
+    /// ### Parameters
+    /// `first`
+    ///
+    /// > Specifies the first sampler unit to which a sampler object is to be bound.
+    ///
+    /// `count`
+    ///
+    /// > Specifies the number of samplers to bind.
+    ///
+    /// `samplers`
+    ///
+    /// > Specifies the address of an array of names of existing sampler objects.
+    ///
+    /// ### Description
+    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) binds
+    /// samplers from an array of existing sampler objects to a specified number
+    /// of consecutive sampler units. `count` specifies the number of sampler objects
+    /// whose names are stored in the array `samplers`. That number of sampler
+    /// names is read from the array and bound to the `count` consecutive sampler
+    /// units starting from `first`.
+    ///
+    /// If the name zero appears in the `samplers` array, any existing binding
+    /// to the sampler unit is reset. Any non-zero entry in `samplers` must be
+    /// the name of an existing sampler object. When a non-zero entry in `samplers`
+    /// is present, that sampler object is bound to the corresponding sampler unit.
+    /// If `samplers` is [`NULL`](crate::enums::NULL) then it is as if an appropriately
+    /// sized array containing only zeros had been specified.
+    ///
+    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) is
+    /// equivalent to the following pseudo code:
+    ///
+    /// Each entry in `samplers` will be checked individually and if found to be
+    /// invalid, the state for that sampler unit will not be changed and an error
+    /// will be generated. However, the state for other sampler units referenced
+    /// by the command will still be updated.
+    ///
+    /// ### Notes
+    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) is
+    /// available only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SAMPLER_BINDING`](crate::enums::GL_SAMPLER_BINDING)
+    pub(crate) unsafe fn oxidegl_bind_samplers(
+        &mut self,
+        first: GLuint,
+        count: GLsizei,
+        samplers: *const GLuint,
+    ) -> GlFallible {
+        sizei!(count);
+        let end = first as usize + count as usize;
+        let units = self.gl_state.texture_units.units.len();
+        gl_assert!(
+            end <= units,
+            InvalidOperation,
+            "texture units {first}..{end} exceed the number of texture units ({units})"
+        );
+        let names = if samplers.is_null() {
+            None
+        } else {
+            // Safety: caller ensures that count and samplers form a valid u32 slice if samplers is not null
+            Some(unsafe { slice::from_raw_parts(samplers, count as usize) })
+        };
+        // invalid names only leave their own unit untouched, so report the last error after binding the rest
+        let mut result = Ok(());
+        for (i, unit) in (first as usize..end).enumerate() {
+            let name = names
+                .map(|names| names[i])
+                .filter(|&sampler| sampler != 0)
+                .map(|sampler| self.sampler_by_name(sampler))
+                .transpose();
+            match name {
+                Ok(name) => self.bind_sampler_internal(unit, name),
+                Err(e) => result = Err(e),
+            }
+        }
+        result
+    }
+}
+
+/// ### Parameters
+/// `sampler`
+///
+/// > Specifies the sampler object whose parameter to modify.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a sampler parameter. `pname` can be one
+/// > of the following: [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S),
+/// > [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T), [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R),
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER),
+/// > [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR), [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD),
+/// > [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD), [`GL_TEXTURE_LOD_BIAS`](crate::enums::GL_TEXTURE_LOD_BIAS)
+/// > [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE), or
+/// > [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC).
+///
+/// `param`
+///
+/// > For the scalar commands, specifies the value of `pname`.
+///
+/// `params`
+///
+/// > For the vector commands( [**glSamplerParameter*v**](crate::context::Context::oxidegl_sampler_parameter*v)
+/// > ), specifies a pointer to an array where the value or values of `pname`
+/// > are stored.
+///
+/// ### Description
+/// [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter)
+/// assigns the value or values in `params` to the sampler parameter specified
+/// as `pname`. `sampler` specifies the sampler object to be modified, and
+/// must be the name of a sampler object previously returned from a call to
+/// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers). The
+/// following symbols are accepted in `pname`:
+///
+/// [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+///
+/// > The texture minifying function is used whenever the pixel being textured
+/// > maps to an area greater than one texture element. There are six defined
+/// > minifying functions. Two of them use the nearest one or nearest four texture
+/// > elements to compute the texture value. The other four use mipmaps.
+///
+/// > A mipmap is an ordered set of arrays representing the same image at progressively
+/// > lower resolutions. If the texture has dimensions `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// > [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// > or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
+/// > with the *level* argument indicating the order of the mipmaps. Level 0
+/// > is the original texture; level `[inlineq]` `[inlineq]`
+///
+/// > `params` supplies a function for minifying the texture as one of the following:
+/// > [`GL_NEAREST`](crate::enums::GL_NEAREST)
+///
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the center of the pixel being textured.
+///
+/// > [`GL_LINEAR`](crate::enums::GL_LINEAR)
+///
+/// >> Returns the weighted average of the four texture elements that are closest
+/// >> to the center of the pixel being textured. These can include border texture
+/// >> elements, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
+///
+/// > [`GL_NEAREST_MIPMAP_NEAREST`](crate::enums::GL_NEAREST_MIPMAP_NEAREST)
+///
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::enums::GL_NEAREST) criterion
+/// >> (the texture element nearest to the center of the pixel) to produce a texture
+/// >> value.
+///
+/// > [`GL_LINEAR_MIPMAP_NEAREST`](crate::enums::GL_LINEAR_MIPMAP_NEAREST)
+///
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::enums::GL_LINEAR) criterion
+/// >> (a weighted average of the four texture elements that are closest to the
+/// >> center of the pixel) to produce a texture value.
+///
+/// > [`GL_NEAREST_MIPMAP_LINEAR`](crate::enums::GL_NEAREST_MIPMAP_LINEAR)
+///
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::enums::GL_NEAREST) criterion
+/// >> (the texture element nearest to the center of the pixel) to produce a texture
+/// >> value from each mipmap. The final texture value is a weighted average of
+/// >> those two values.
+///
+/// > [`GL_LINEAR_MIPMAP_LINEAR`](crate::enums::GL_LINEAR_MIPMAP_LINEAR)
+///
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::enums::GL_LINEAR) criterion
+/// >> (a weighted average of the four texture elements that are closest to the
+/// >> center of the pixel) to produce a texture value from each mipmap. The final
+/// >> texture value is a weighted average of those two values.
+///
+///
+/// > As more texture elements are sampled in the minification process, fewer
+/// > aliasing artifacts will be apparent. While the [`GL_NEAREST`](crate::enums::GL_NEAREST)
+/// > and [`GL_LINEAR`](crate::enums::GL_LINEAR) minification functions can be
+/// > faster than the other four, they sample only one or four texture elements
+/// > to determine the texture value of the pixel being rendered and can produce
+/// > moire patterns or ragged transitions. The initial value of [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+/// > is [`GL_NEAREST_MIPMAP_LINEAR`](crate::enums::GL_NEAREST_MIPMAP_LINEAR).
+///
+/// [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER)
+///
+/// > The texture magnification function is used when the pixel being textured
+/// > maps to an area less than or equal to one texture element. It sets the
+/// > texture magnification function to either [`GL_NEAREST`](crate::enums::GL_NEAREST)
+/// > or [`GL_LINEAR`](crate::enums::GL_LINEAR) (see below). [`GL_NEAREST`](crate::enums::GL_NEAREST)
+/// > is generally faster than [`GL_LINEAR`](crate::enums::GL_LINEAR), but it
+/// > can produce textured images with sharper edges because the transition between
+/// > texture elements is not as smooth. The initial value of [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER)
+/// > is [`GL_LINEAR`](crate::enums::GL_LINEAR). [`GL_NEAREST`](crate::enums::GL_NEAREST)
+///
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the center of the pixel being textured.
+///
+/// > [`GL_LINEAR`](crate::enums::GL_LINEAR)
+///
+/// >> Returns the weighted average of the four texture elements that are closest
+/// >> to the center of the pixel being textured. These can include border texture
+/// >> elements, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
+///
+///
+///
+/// [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD)
+///
+/// > Sets the minimum level-of-detail parameter. This floating-point value limits
+/// > the selection of highest resolution mipmap (lowest mipmap level). The initial
+/// > value is -1000.
+///
+///
+/// [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD)
+///
+/// > Sets the maximum level-of-detail parameter. This floating-point value limits
+/// > the selection of the lowest resolution mipmap (highest mipmap level). The
+/// > initial value is 1000.
+///
+///
+/// [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+///
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::enums::GL_REPEAT),
+/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > [`GL_CLAMP_TO_BORDER`](crate::enums::GL_CLAMP_TO_BORDER) causes the `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE)
+/// > causes `[inlineq]` `[inlineq]` `[inlineq]` [`GL_REPEAT`](crate::enums::GL_REPEAT)
+/// > causes the integer part of the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT)
+/// > causes the `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE)
+/// > causes the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT)
+/// > for one repetition of the texture, at which point the coordinate to be
+/// > clamped as in [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE). Initially,
+/// > [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S) is set to [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+///
+/// [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T)
+///
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::enums::GL_REPEAT),
+/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T) is set
+/// > to [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R)
+///
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::enums::GL_REPEAT),
+/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R) is set
+/// > to [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+///
+/// > The data in `params` specifies four values that define the border values
+/// > that should be used for border texels. If a texel is sampled from the border
+/// > of the texture, the values of [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+/// > are interpreted as an RGBA color to match the texture's internal format
+/// > and substituted for the non-existent texel data. If the texture contains
+/// > depth components, the first component of [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+/// > is interpreted as a depth value. The initial value is `[inlineq]`
+///
+/// [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE)
+///
+/// > Specifies the texture comparison mode for currently bound textures. That
+/// > is, a texture whose internal format is [`GL_DEPTH_COMPONENT_*`](crate::enums::GL_DEPTH_COMPONENT_*);
+/// > see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
+/// > Permissible values are: [`GL_COMPARE_REF_TO_TEXTURE`](crate::enums::GL_COMPARE_REF_TO_TEXTURE)
+///
+/// >> Specifies that the interpolated and clamped `[inlineq]` [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC)
+/// >> for details of how the comparison is evaluated. The result of the comparison
+/// >> is assigned to the red channel.
+///
+/// > [`GL_NONE`](crate::enums::GL_NONE)
+///
+/// >> Specifies that the red channel should be assigned the appropriate value
+/// >> from the currently bound texture.
+///
+///
+/// [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC)
+///
+/// > Specifies the comparison operator used when [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE)
+/// > is set to [`GL_COMPARE_REF_TO_TEXTURE`](crate::enums::GL_COMPARE_REF_TO_TEXTURE).
+/// > Permissible values are:
+/// > |* Texture Comparison Function*                                  |* Computed result*                            |
+/// > |----------------------------------------------------------------|----------------------------------------------|
+/// > | [`GL_LEQUAL`](crate::enums::GL_LEQUAL)                         | `[inlineq]`                                  |
+/// > | [`GL_GEQUAL`](crate::enums::GL_GEQUAL)                         | `[inlineq]`                                  |
+/// > | [`GL_LESS`](crate::enums::GL_LESS)                             | `[inlineq]`                                  |
+/// > | [`GL_GREATER`](crate::enums::GL_GREATER)                       | `[inlineq]`                                  |
+/// > | [`GL_EQUAL`](crate::enums::GL_EQUAL)                           | `[inlineq]`                                  |
+/// > | [`GL_NOTEQUAL`](crate::enums::GL_NOTEQUAL)                     | `[inlineq]`                                  |
+/// > | [`GL_ALWAYS`](crate::enums::GL_ALWAYS)                         | `[inlineq]`                                  |
+/// > | [`GL_NEVER`](crate::enums::GL_NEVER)                           | `[inlineq]`                                  |
+///
+/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// ### Notes
+/// [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter)
+/// is available only if the GL version is 3.3 or higher.
+///
+/// If a sampler object is bound to a texture unit and that unit is used to
+/// sample from a texture, the parameters in the sampler are used to sample
+/// from the texture, rather than the equivalent parameters in the texture
+/// object bound to that unit. This introduces the possibility of sampling
+/// from the same texture object with different sets of sampler state, which
+/// may lead to a condition where a texture is *incomplete* with respect to
+/// one sampler object and not with respect to another. Thus, completeness
+/// can be considered a function of a sampler object and a texture object bound
+/// to a single texture unit, rather than a property of the texture object
+/// itself.
+///
+/// [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE) is available
+/// only if the GL version is 4.4 or greater.
+///
+/// ### Associated Gets
+/// [**glGetSamplerParameter**](crate::context::Context::oxidegl_get_sampler_parameter)
+impl Context {
+    pub(crate) fn oxidegl_sampler_parameteri(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: GLint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: `param` is a single value
+        unsafe {
+            self.sampler_parameter_internal(
+                name,
+                pname,
+                &raw const param,
+                false,
+                normalized_border_color,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_sampler_parameteriv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `param` points to enough values for `pname`
+        unsafe {
+            self.sampler_parameter_internal(name, pname, param, true, normalized_border_color)
+        }
+    }
+    pub(crate) fn oxidegl_sampler_parameterf(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: GLfloat,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: `param` is a single value
+        unsafe {
+            self.sampler_parameter_internal(
+                name,
+                pname,
+                &raw const param,
+                false,
+                BorderColor::Float,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_sampler_parameterfv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLfloat,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `param` points to enough values for `pname`
+        unsafe { self.sampler_parameter_internal(name, pname, param, true, BorderColor::Float) }
+    }
+    pub(crate) unsafe fn oxidegl_sampler_parameter_iiv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `param` points to enough values for `pname`
+        unsafe { self.sampler_parameter_internal(name, pname, param, true, BorderColor::Int) }
+    }
+    pub(crate) unsafe fn oxidegl_sampler_parameter_iuiv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLuint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `param` points to enough values for `pname`
+        unsafe { self.sampler_parameter_internal(name, pname, param, true, BorderColor::Uint) }
+    }
+}
+
+/// ### Parameters
+/// `sampler`
+///
+/// > Specifies name of the sampler object from which to retrieve parameters.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a sampler parameter. [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER),
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD),
+/// > [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD), [`GL_TEXTURE_LOD_BIAS`](crate::enums::GL_TEXTURE_LOD_BIAS),
+/// > [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S), [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T),
+/// > [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R), [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR),
+/// > [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE), and
+/// > [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC) are
+/// > accepted.
+///
+/// `params`
+///
+/// > Returns the sampler parameters.
+///
+/// ### Description
+/// [**glGetSamplerParameter**](crate::context::Context::oxidegl_get_sampler_parameter)
+/// returns in `params` the value or values of the sampler parameter specified
+/// as `pname`. `sampler` defines the target sampler, and must be the name
+/// of an existing sampler object, returned from a previous call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
+/// `pname` accepts the same symbols as [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter),
+/// with the same interpretations:
+///
+/// [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER)
+///
+/// > Returns the single-valued texture magnification filter, a symbolic constant.
+/// > The initial value is [`GL_LINEAR`](crate::enums::GL_LINEAR).
+///
+/// [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+///
+/// > Returns the single-valued texture minification filter, a symbolic constant.
+/// > The initial value is [`GL_NEAREST_MIPMAP_LINEAR`](crate::enums::GL_NEAREST_MIPMAP_LINEAR).
+///
+/// [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD)
+///
+/// > Returns the single-valued texture minimum level-of-detail value. The initial
+/// > value is `[inlineq]`
+///
+/// [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD)
+///
+/// > Returns the single-valued texture maximum level-of-detail value. The initial
+/// > value is 1000.
+///
+/// [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+///
+/// > Returns the single-valued wrapping function for texture coordinate `[inlineq]`
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T)
+///
+/// > Returns the single-valued wrapping function for texture coordinate `[inlineq]`
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R)
+///
+/// > Returns the single-valued wrapping function for texture coordinate `[inlineq]`
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+///
+/// > Returns four integer or floating-point numbers that comprise the RGBA color
+/// > of the texture border. Floating-point values are returned in the range
+/// > `[inlineq]` `[inlineq]`
+///
+/// [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE)
+///
+/// > Returns a single-valued texture comparison mode, a symbolic constant. The
+/// > initial value is [`GL_NONE`](crate::enums::GL_NONE). See [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter).
+///
+/// [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC)
+///
+/// > Returns a single-valued texture comparison function, a symbolic constant.
+/// > The initial value is [`GL_LEQUAL`](crate::enums::GL_LEQUAL). See [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter).
+///
+/// ### Notes
+/// If an error is generated, no change is made to the contents of `params`.
+///
+/// [**glGetSamplerParameter**](crate::context::Context::oxidegl_get_sampler_parameter)
+/// is available only if the GL version is 3.3 or higher.
+impl Context {
+    pub(crate) unsafe fn oxidegl_get_sampler_parameteriv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_sampler_parameter_internal(name, pname, params, true) };
+        Ok(())
+    }
+    pub(crate) unsafe fn oxidegl_get_sampler_parameter_iiv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_sampler_parameter_internal(name, pname, params, false) };
+        Ok(())
+    }
+    pub(crate) unsafe fn oxidegl_get_sampler_parameterfv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        params: *mut GLfloat,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_sampler_parameter_internal(name, pname, params, false) };
+        Ok(())
+    }
+    pub(crate) unsafe fn oxidegl_get_sampler_parameter_iuiv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        params: *mut GLuint,
+    ) -> GlFallible {
+        let name = self.sampler_by_name(sampler)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_sampler_parameter_internal(name, pname, params, false) };
+        Ok(())
+    }
+}
+
+impl Context {
+    /// Returns the name of the sampler object named by `sampler`, or an `InvalidOperation` error if it does not name an
+    /// existing sampler object
+    pub(crate) fn sampler_by_name(&self, sampler: GLuint) -> GlFallible<ObjectName<Sampler>> {
+        ObjectName::try_from_raw(sampler)
+            .ok()
+            .filter(|&name| self.gl_state.sampler_list.is(name))
+            .ok_or_else(|| {
+                gl_err!(ty: Error, "{sampler} does not name an existing sampler object");
+                GlError::InvalidOperation.e()
+            })
+    }
+    fn bind_sampler_internal(&mut self, unit: usize, name: Option<ObjectName<Sampler>>) {
+        let state = &self.gl_state;
+        let prev = state.texture_units.units[unit].sampler;
+        let emulated = |sampler: Option<ObjectName<Sampler>>| {
+            sampler.is_some_and(|name| {
+                state
+                    .sampler_list
+                    .get(name)
+                    .params
+                    .shader_emulation()
+                    .is_some()
+            })
+        };
+        // (un)binding a sampler object changes the effective sampler emulation state of every texture on the unit if
+        // either the old or new sampler state needs shader-side emulation
+        if prev != name
+            && (emulated(prev)
+                || emulated(name)
+                || state.texture_units.units[unit]
+                    .textures
+                    .iter()
+                    .chain(state.texture_units.defaults.iter())
+                    .any(|tex| {
+                        state
+                            .texture_list
+                            .get(tex)
+                            .sampling_state
+                            .shader_emulation()
+                            .is_some()
+                    }))
+        {
+            self.new_pipeline();
+        }
+        self.gl_state.texture_units.units[unit].sampler = name;
+    }
+    /// Breaks every texture unit binding of the given sampler (e.g. because it was deleted)
+    fn unbind_sampler_everywhere(&mut self, name: ObjectName<Sampler>) {
+        for unit in 0..self.gl_state.texture_units.units.len() {
+            if self.gl_state.texture_units.units[unit].sampler == Some(name) {
+                self.bind_sampler_internal(unit, None);
+            }
+        }
+    }
+    /// Shared implementation of the glSamplerParameter* commands. `params` points to a single value for the scalar commands
+    /// (`vector` is false) and to as many values as `pname` takes for the vector commands. `color` converts border colors as
+    /// the particular command specifies them
+    unsafe fn sampler_parameter_internal<T: SrcType<f32> + SrcType<i32> + SrcType<u32>>(
+        &mut self,
+        name: ObjectName<Sampler>,
+        pname: SamplerParameter,
+        params: *const T,
+        vector: bool,
+        color: fn([T; 4]) -> BorderColor,
+    ) -> GlFallible {
+        let sampler = &mut self.gl_state.sampler_list.get_mut(name).params;
+        let prev_emulation = sampler.shader_emulation();
+        if pname == SamplerParameter::TextureBorderColor {
+            gl_assert!(
+                vector,
+                InvalidEnum,
+                "GL_TEXTURE_BORDER_COLOR may only be set with the vector glSamplerParameter commands"
+            );
+            // Safety: caller ensures `params` points to 4 values for border colors
+            let c = unsafe { params.cast::<[T; 4]>().read_unaligned() };
+            sampler.set_border_color(color(c));
+        } else {
+            // Safety: caller ensures `params` points to at least one value
+            sampler.sampler_param(pname, unsafe { params.read() })?;
+        }
+        // a change in sampler emulation state changes the specialization of any shader that samples through this sampler
+        if sampler.shader_emulation() != prev_emulation
+            && self
+                .gl_state
+                .texture_units
+                .units
+                .iter()
+                .any(|unit| unit.sampler == Some(name))
+        {
+            self.new_pipeline();
+        }
+        Ok(())
+    }
+    /// Shared implementation of the glGetSamplerParameter* commands. `normalize_color` converts floating point border colors
+    /// to normalized integers, as glGetSamplerParameteriv does
+    unsafe fn get_sampler_parameter_internal<T: GlDstType>(
+        &self,
+        name: ObjectName<Sampler>,
+        pname: SamplerParameter,
+        params: *mut T,
+        normalize_color: bool,
+    ) {
+        let sampler = &self.gl_state.sampler_list.get(name).params;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe {
+            match pname {
+                SamplerParameter::TextureMagFilter => {
+                    u32::from(sampler.mag_filter).write_out(params);
+                }
+                SamplerParameter::TextureMinFilter => {
+                    u32::from(sampler.min_filter).write_out(params);
+                }
+                SamplerParameter::TextureWrapS => u32::from(sampler.wrap_mode_s).write_out(params),
+                SamplerParameter::TextureWrapT => u32::from(sampler.wrap_mode_t).write_out(params),
+                SamplerParameter::TextureWrapR => u32::from(sampler.wrap_mode_r).write_out(params),
+                SamplerParameter::TextureBorderColor => match sampler.border_color {
+                    BorderColor::Float(c) if normalize_color => {
+                        c.map(normalize_color_component).write_out(params);
+                    }
+                    BorderColor::Float(c) => c.write_out(params),
+                    BorderColor::Int(c) => c.write_out(params),
+                    BorderColor::Uint(c) => c.write_out(params),
+                },
+                SamplerParameter::TextureMinLod => sampler.min_lod.write_out(params),
+                SamplerParameter::TextureMaxLod => sampler.max_lod.write_out(params),
+                SamplerParameter::TextureLodBias => sampler.lod_bias.write_out(params),
+                SamplerParameter::TextureCompareMode => {
+                    u32::from(sampler.compare_mode).write_out(params);
+                }
+                SamplerParameter::TextureCompareFunc => {
+                    u32::from(sampler.compare_func).write_out(params);
+                }
+                SamplerParameter::TextureMaxAnisotropy => {
+                    sampler.max_anisotropy.as_float().write_out(params);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,10031 @@
+use core::{ops::Range, ptr::NonNull, slice};
+
+use objc2::rc::Retained;
+use objc2_foundation::NSRange;
+use objc2_metal::{
+    MTLBlitCommandEncoder, MTLBlitOption, MTLBuffer, MTLClearColor, MTLDevice, MTLLoadAction,
+    MTLOrigin, MTLPixelFormat, MTLRenderPassAttachmentDescriptor,
+    MTLRenderPassColorAttachmentDescriptor, MTLRenderPassDescriptor, MTLResourceOptions, MTLSize,
+    MTLStoreAction, MTLTexture, MTLTextureType,
+};
+
+use crate::{
+    context::{
+        Context,
+        commands::buffer::Buffer,
+        debug::{gl_debug, gl_err, gl_warn},
+        error::{GlError, GlFallible, gl_assert},
+        gl_object::ObjectName,
+        pixel::{
+            COMPRESSED_BLOCK_DIM, ClearValue, GlPixelTypeFormat, clear_value,
+            convert_from_internal, convert_internal, convert_to_internal, decompress,
+            mtl_texel_size,
+        },
+        platform::PlatformState,
+        program::SampledKind,
+        state::{
+            MAX_3D_TEXTURE_SIZE, MAX_ARRAY_TEXTURE_LAYERS, MAX_TEXTURE_BUFFER_SIZE,
+            MAX_TEXTURE_SIZE,
+        },
+        texture::{
+            BorderColor, PendingWrite, RealizedTexture, Texture, TextureBufferRange, TextureExtent,
+            TextureLevel, TextureViewRange,
+        },
+    },
+    dispatch::{
+        conversions::{GLenumExt, GlDstType, GlEnumGroup, GlGetItem, SrcType, sizei},
+        gl_types::{
+            GLboolean, GLenum, GLfloat, GLint, GLintptr, GLsizei, GLsizeiptr, GLuint, GLvoid,
+        },
+    },
+    enums::{
+        CopyImageSubDataTarget, GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE, GL_NONE, GL_TEXTURE0,
+        GetTextureParameter, InternalFormat, PixelFormat, PixelType, SamplerParameter, ShaderType,
+        SizedInternalFormat, TextureMinFilter, TextureParameterName, TextureTarget,
+        TextureWrapMode,
+    },
+    util::ProtoObjRef,
+};
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target to which the texture is bound. Must be one of [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+    /// > [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+    /// > [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+    /// > [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+    /// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// > [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// > or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY).
+    ///
+    /// `texture`
+    ///
+    /// > Specifies the name of a texture.
+    ///
+    /// ### Description
+    /// [**glBindTexture**](crate::context::Context::oxidegl_bind_texture) lets
+    /// you create or use a named texture. Calling [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// with `target` set to [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+    /// [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE),
+    /// [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+    /// and `texture` set to the name of the new texture binds the texture name
+    /// to the target. When a texture is bound to a target, the previous binding
+    /// for that target is automatically broken.
+    ///
+    /// Texture names are unsigned integers. The value zero is reserved to represent
+    /// the default texture for each texture target. Texture names and the corresponding
+    /// texture contents are local to the shared object space of the current GL
+    /// rendering context; two rendering contexts share texture names only if they
+    /// explicitly enable sharing between contexts through the appropriate GL windows
+    /// interfaces functions.
+    ///
+    /// You must use [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// to generate a set of new texture names.
+    ///
+    /// When a texture is first bound, it assumes the specified target: A texture
+    /// first bound to [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D) becomes one-dimensional
+    /// texture, a texture first bound to [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D)
+    /// becomes two-dimensional texture, a texture first bound to [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D)
+    /// becomes three-dimensional texture, a texture first bound to [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY)
+    /// becomes one-dimensional array texture, a texture first bound to [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY)
+    /// becomes two-dimensional array texture, a texture first bound to [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE)
+    /// becomes rectangle texture, a texture first bound to [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP)
+    /// becomes a cube-mapped texture, a texture first bound to [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// becomes a cube-mapped array texture, a texture first bound to [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER)
+    /// becomes a buffer texture, a texture first bound to [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// becomes a two-dimensional multisampled texture, and a texture first bound
+    /// to [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+    /// becomes a two-dimensional multisampled array texture. The state of a one-dimensional
+    /// texture immediately after it is first bound is equivalent to the state
+    /// of the default [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D) at GL initialization,
+    /// and similarly for the other texture types.
+    ///
+    /// While a texture is bound, GL operations on the target to which it is bound
+    /// affect the bound texture, and queries of the target to which it is bound
+    /// return state from the bound texture. In effect, the texture targets become
+    /// aliases for the textures currently bound to them, and the texture name
+    /// zero refers to the default textures that were bound to them at initialization.
+    ///
+    /// A texture binding created with [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// remains active until a different texture is bound to the same target, or
+    /// until the bound texture is deleted with [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures).
+    ///
+    /// Once created, a named texture may be re-bound to its same original target
+    /// as often as needed. It is usually much faster to use [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// to bind an existing named texture to one of the texture targets than it
+    /// is to reload the texture image using [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+    /// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+    /// or another similar function.
+    ///
+    /// ### Notes
+    /// The [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// and [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+    /// targets are available only if the GL version is 3.2 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub(crate) fn oxidegl_bind_texture(
+        &mut self,
+        target: TextureTarget,
+        texture: GLuint,
+    ) -> GlFallible {
+        let unit = self.gl_state.texture_units.active;
+        let Some(name) = ObjectName::try_from_raw(texture).ok() else {
+            self.bind_texture_internal(unit, target, None)?;
+            gl_debug!("unbound texture from target {target:?}");
+            return Ok(());
+        };
+        // validate target before potentially initializing the texture object
+        self.gl_state.texture_units.active().get(target)?;
+        gl_assert!(
+            !self.gl_state.texture_units.is_default(name),
+            InvalidOperation,
+            "{name:?} is not a texture name returned by glGenTextures"
+        );
+        self.gl_state
+            .texture_list
+            .ensure_init(name, |name| Texture::new_named(name, target))?;
+        let tex_target = self.gl_state.texture_list.get(name).target;
+        gl_assert!(
+            tex_target == target,
+            InvalidOperation,
+            "{name:?} was previously bound to {tex_target:?} and may not be bound to {target:?}"
+        );
+        self.bind_texture_internal(unit, target, Some(name))?;
+        gl_debug!("bound {name:?} to target {target:?}");
+        Ok(())
+    }
+
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the effective texture target of each created texture.
+    ///
+    /// `n`
+    ///
+    /// > Number of texture objects to create.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies an array in which names of the new texture objects are stored.
+    ///
+    /// ### Description
+    /// [**glCreateTextures**](crate::context::Context::oxidegl_create_textures)
+    /// returns `n` previously unused texture names in `textures`, each representing
+    /// a new texture object of the dimensionality and type specified by `target`
+    /// and initialized to the default values for that texture type.
+    ///
+    /// `target` must be one of [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+    /// [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+    /// [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+    /// [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+    /// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY).
+    pub(crate) unsafe fn oxidegl_create_textures(
+        &mut self,
+        target: TextureTarget,
+        n: GLsizei,
+        textures: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // validate that the target is a bindable texture target
+        self.gl_state.texture_units.active().get(target)?;
+        // Safety: Caller ensures validity
+        unsafe {
+            self.gl_state.texture_list.create_obj(
+                move |name| Texture::new_named(name, target),
+                n,
+                textures,
+            );
+        }
+        Ok(())
+    }
+
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of textures to be deleted.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies an array of textures to be deleted.
+    ///
+    /// ### Description
+    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures)
+    /// deletes `n` textures named by the elements of the array `textures`. After
+    /// a texture is deleted, it has no contents or dimensionality, and its name
+    /// is free for reuse (for example by [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// ). If a texture that is currently bound is deleted, the binding reverts
+    /// to 0 (the default texture).
+    ///
+    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures)
+    /// silently ignores 0's and names that do not correspond to existing textures.
+    ///
+    /// ### Associated Gets
+    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
+    pub(crate) unsafe fn oxidegl_delete_textures(
+        &mut self,
+        n: GLsizei,
+        textures: *const GLuint,
+    ) -> GlFallible {
+        sizei!(n, count);
+        if count > 0 {
+            // Safety: caller ensures that n and textures form a valid u32 slice
+            for &name in unsafe { slice::from_raw_parts(textures, count as usize) } {
+                // names of default textures don't name textures as far as the application is concerned, so they are
+                // silently ignored like any other unused name
+                let Ok(name) = ObjectName::try_from_raw(name) else {
+                    continue;
+                };
+                if self.gl_state.texture_units.is_default(name) {
+                    continue;
+                }
+                self.unbind_texture_everywhere(name);
+                self.gl_state.texture_list.delete(name);
+                gl_debug!("deleted {name:?}");
+            }
+        }
+        Ok(())
+    }
+
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of texture names to be generated.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies an array in which the generated texture names are stored.
+    ///
+    /// ### Description
+    /// [**glGenTextures**](crate::context::Context::oxidegl_gen_textures) returns
+    /// `n` texture names in `textures`. There is no guarantee that the names form
+    /// a contiguous set of integers; however, it is guaranteed that none of the
+    /// returned names was in use immediately before the call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures).
+    ///
+    /// The generated textures have no dimensionality; they assume the dimensionality
+    /// of the texture target to which they are first bound (see [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// ).
+    ///
+    /// Texture names returned by a call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures).
+    ///
+    /// ### Associated Gets
+    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
+    pub(crate) unsafe fn oxidegl_gen_textures(
+        &mut self,
+        n: GLsizei,
+        textures: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller ensures validity
+        unsafe { self.gl_state.texture_list.gen_obj(n, textures) };
+        Ok(())
+    }
+
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > Specifies a value that may be the name of a texture.
+    ///
+    /// ### Description
+    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture) returns
+    /// [`GL_TRUE`](crate::enums::GL_TRUE) if `texture` is currently the name of
+    /// a texture. If `texture` is zero, or is a non-zero value that is not currently
+    /// the name of a texture, or if an error occurs, [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
+    /// returns [`GL_FALSE`](crate::enums::GL_FALSE).
+    ///
+    /// A name returned by [**glGenTextures**](crate::context::Context::oxidegl_gen_textures),
+    /// but not yet associated with a texture by calling [**glBindTexture**](crate::context::Context::oxidegl_bind_texture),
+    /// is not the name of a texture.
+    pub(crate) fn oxidegl_is_texture(&mut self, texture: GLuint) -> GLboolean {
+        self.texture_by_name(texture).is_ok()
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glTexStorage1D**](crate::context::Context::oxidegl_tex_storage1_d).
+/// > Must be one of [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D) or [`GL_PROXY_TEXTURE_1D`](crate::enums::GL_PROXY_TEXTURE_1D).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureStorage1D**](crate::context::Context::oxidegl_texture_storage1_d).
+/// > The effective target of `texture` must be one of the valid non-proxy `target`
+/// > values above.
+///
+/// `levels`
+///
+/// > Specify the number of texture levels.
+///
+/// `internalformat`
+///
+/// > Specifies the sized internal format to be used to store texture image data.
+///
+/// `width`
+///
+/// > Specifies the width of the texture, in texels.
+///
+/// ### Description
+/// [**glTexStorage1D**](crate::context::Context::oxidegl_tex_storage1_d) and
+/// [**glTextureStorage1D**](crate::context::Context::oxidegl_texture_storage1_d)
+/// specify the storage requirements for all levels of a one-dimensional texture
+/// simultaneously. Once a texture is specified with this command, the format
+/// and dimensions of all levels become immutable unless it is a proxy texture.
+/// The contents of the image may still be modified, however, its storage requirements
+/// may not change. Such a texture is referred to as an *immutable-format*
+/// texture.
+///
+/// Calling [**glTexStorage1D**](crate::context::Context::oxidegl_tex_storage1_d)
+/// is equivalent, assuming no errors are generated, to executing the following
+/// pseudo-code:
+///
+/// Calling [**glTextureStorage1D**](crate::context::Context::oxidegl_texture_storage1_d)
+/// is equivalent to the above pseudo-code, where `target` is the effective
+/// target of `texture` and it is as if `texture` were bound to `target` for
+/// the purposes of [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
+///
+/// Since no texture data is actually provided, the values used in the pseudo-code
+/// for `format` and `type` are irrelevant and may be considered to be any
+/// values that are legal for the chosen `internalformat` enumerant. `internalformat`
+/// must be one of the sized internal formats given in Table 1 below, one of
+/// the sized depth-component formats [`GL_DEPTH_COMPONENT32F`](crate::enums::GL_DEPTH_COMPONENT32F),
+/// [`GL_DEPTH_COMPONENT24`](crate::enums::GL_DEPTH_COMPONENT24), or [`GL_DEPTH_COMPONENT16`](crate::enums::GL_DEPTH_COMPONENT16),
+/// one of the combined depth-stencil formats, [`GL_DEPTH32F_STENCIL8`](crate::enums::GL_DEPTH32F_STENCIL8),
+/// or [`GL_DEPTH24_STENCIL8`](crate::enums::GL_DEPTH24_STENCIL8), or the
+/// stencil-only format, [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8).
+/// Upon success, the value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+/// becomes [`GL_TRUE`](crate::enums::GL_TRUE). The value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+/// may be discovered by calling [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+/// with `pname` set to [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT).
+/// No further changes to the dimensions or format of the texture object may
+/// be made. Using any command that might alter the dimensions or format of
+/// the texture object (such as [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
+/// or another call to [**glTexStorage1D**](crate::context::Context::oxidegl_tex_storage1_d))
+/// will result in the generation of a [`GL_INVALID_OPERATION`](crate::enums::GL_INVALID_OPERATION)
+/// error, even if it would not, in fact, alter the dimensions or format of
+/// the object.
+///
+/// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+/// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+/// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+/// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+/// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+/// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+/// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+/// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+/// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+/// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+/// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+/// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+/// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+/// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+/// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+/// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+/// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+/// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+/// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+/// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+/// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+/// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+/// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+/// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+/// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+/// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+/// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+///
+///
+/// ### Notes
+/// [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8) is accepted for
+/// `internalformat` only if the GL version is 4.4 or higher.
+impl Context {
+    pub(crate) fn oxidegl_tex_storage1_d(
+        &mut self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+    ) -> GlFallible {
+        sizei!(levels);
+        sizei!(width);
+        self.tex_storage_internal(
+            target,
+            StorageDims::One,
+            levels,
+            internalformat,
+            TextureExtent::new(width, 1, 1),
+        )
+    }
+    pub(crate) fn oxidegl_texture_storage1_d(
+        &mut self,
+        texture: GLuint,
+        levels: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+    ) -> GlFallible {
+        sizei!(levels);
+        sizei!(width);
+        let name = self.texture_by_name(texture)?;
+        self.texture_storage_internal(
+            name,
+            StorageDims::One,
+            levels,
+            None,
+            internalformat,
+            TextureExtent::new(width, 1, 1),
+        )
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d).
+/// > Must be one of [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// > [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D), [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY),
+/// > [`GL_PROXY_TEXTURE_RECTANGLE`](crate::enums::GL_PROXY_TEXTURE_RECTANGLE),
+/// > or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureStorage2D**](crate::context::Context::oxidegl_texture_storage2_d).
+/// > The effective target of `texture` must be one of the valid non-proxy `target`
+/// > values above.
+///
+/// `levels`
+///
+/// > Specify the number of texture levels.
+///
+/// `internalformat`
+///
+/// > Specifies the sized internal format to be used to store texture image data.
+///
+/// `width`
+///
+/// > Specifies the width of the texture, in texels.
+///
+/// `height`
+///
+/// > Specifies the height of the texture, in texels.
+///
+/// ### Description
+/// [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d) and
+/// [**glTextureStorage2D**](crate::context::Context::oxidegl_texture_storage2_d)
+/// specify the storage requirements for all levels of a two-dimensional texture
+/// or one-dimensional texture array simultaneously. Once a texture is specified
+/// with this command, the format and dimensions of all levels become immutable
+/// unless it is a proxy texture. The contents of the image may still be modified,
+/// however, its storage requirements may not change. Such a texture is referred
+/// to as an *immutable-format* texture.
+///
+/// The behavior of [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d)
+/// depends on the `target` parameter. When `target` is [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+/// [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D), [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE),
+/// [`GL_PROXY_TEXTURE_RECTANGLE`](crate::enums::GL_PROXY_TEXTURE_RECTANGLE)
+/// or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP),
+/// calling [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d)
+/// is equivalent, assuming no errors are generated, to executing the following
+/// pseudo-code:
+///
+/// When `target` is [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d) is
+/// equivalent to:
+///
+/// When `target` is [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY)
+/// or [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY),
+/// [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d) is
+/// equivalent to:
+///
+/// Calling [**glTextureStorage2D**](crate::context::Context::oxidegl_texture_storage2_d)
+/// is equivalent to the above pseudo-code, where `target` is the effective
+/// target of `texture` and it is as if `texture` were bound to `target` for
+/// the purposes of [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d).
+///
+/// Since no texture data is actually provided, the values used in the pseudo-code
+/// for `format` and `type` are irrelevant and may be considered to be any
+/// values that are legal for the chosen `internalformat` enumerant. `internalformat`
+/// must be one of the sized internal formats given in Table 1 below, one of
+/// the sized depth-component formats [`GL_DEPTH_COMPONENT32F`](crate::enums::GL_DEPTH_COMPONENT32F),
+/// [`GL_DEPTH_COMPONENT24`](crate::enums::GL_DEPTH_COMPONENT24), or [`GL_DEPTH_COMPONENT16`](crate::enums::GL_DEPTH_COMPONENT16),
+/// one of the combined depth-stencil formats, [`GL_DEPTH32F_STENCIL8`](crate::enums::GL_DEPTH32F_STENCIL8),
+/// or [`GL_DEPTH24_STENCIL8`](crate::enums::GL_DEPTH24_STENCIL8), or the
+/// stencil-only format, [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8).
+/// Upon success, the value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+/// becomes [`GL_TRUE`](crate::enums::GL_TRUE). The value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+/// may be discovered by calling [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+/// with `pname` set to [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT).
+/// No further changes to the dimensions or format of the texture object may
+/// be made. Using any command that might alter the dimensions or format of
+/// the texture object (such as [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+/// or another call to [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d))
+/// will result in the generation of a [`GL_INVALID_OPERATION`](crate::enums::GL_INVALID_OPERATION)
+/// error, even if it would not, in fact, alter the dimensions or format of
+/// the object.
+///
+/// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+/// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+/// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+/// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+/// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+/// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+/// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+/// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+/// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+/// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+/// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+/// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+/// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+/// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+/// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+/// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+/// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+/// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+/// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+/// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+/// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+/// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+/// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+/// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+/// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+/// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+/// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+///
+///
+/// ### Notes
+/// [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8) is accepted for
+/// `internalformat` only if the GL version is 4.4 or higher.
+impl Context {
+    pub(crate) fn oxidegl_tex_storage2_d(
+        &mut self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(levels);
+        sizei!(width);
+        sizei!(height);
+        self.tex_storage_internal(
+            target,
+            StorageDims::Two,
+            levels,
+            internalformat,
+            TextureExtent::new(width, height, 1),
+        )
+    }
+    pub(crate) fn oxidegl_texture_storage2_d(
+        &mut self,
+        texture: GLuint,
+        levels: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(levels);
+        sizei!(width);
+        sizei!(height);
+        let name = self.texture_by_name(texture)?;
+        self.texture_storage_internal(
+            name,
+            StorageDims::Two,
+            levels,
+            None,
+            internalformat,
+            TextureExtent::new(width, height, 1),
+        )
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glTexStorage2DMultisample**](crate::context::Context::oxidegl_tex_storage2_d_multisample).
+/// > Must be one of [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE)
+/// > or [`GL_PROXY_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_PROXY_TEXTURE_2D_MULTISAMPLE).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureStorage2DMultisample**](crate::context::Context::oxidegl_texture_storage2_d_multisample).
+/// > The effective target of `texture` must be one of the valid non-proxy `target`
+/// > values above.
+///
+/// `samples`
+///
+/// > Specify the number of samples in the texture.
+///
+/// `internalformat`
+///
+/// > Specifies the sized internal format to be used to store texture image data.
+///
+/// `width`
+///
+/// > Specifies the width of the texture, in texels.
+///
+/// `height`
+///
+/// > Specifies the height of the texture, in texels.
+///
+/// `fixedsamplelocations`
+///
+/// > Specifies whether the image will use identical sample locations and the
+/// > same number of samples for all texels in the image, and the sample locations
+/// > will not depend on the internal format or size of the image.
+///
+/// ### Description
+/// [**glTexStorage2DMultisample**](crate::context::Context::oxidegl_tex_storage2_d_multisample)
+/// and [**glTextureStorage2DMultisample**](crate::context::Context::oxidegl_texture_storage2_d_multisample)
+/// specify the storage requirements for a two-dimensional multisample texture.
+/// Once a texture is specified with this command, its format and dimensions
+/// become immutable unless it is a proxy texture. The contents of the image
+/// may still be modified, however, its storage requirements may not change.
+/// Such a texture is referred to as an *immutable-format* texture.
+///
+/// `samples` specifies the number of samples to be used for the texture and
+/// must be greater than zero and less than or equal to the value of [`GL_MAX_SAMPLES`](crate::enums::GL_MAX_SAMPLES).
+/// `internalformat` must be a color-renderable, depth-renderable, or stencil-renderable
+/// format. `width` and `height` specify the width and height, respectively,
+/// of the texture. If `fixedsamplelocations` is [`GL_TRUE`](crate::enums::GL_TRUE),
+/// the image will use identical sample locations and the same number of samples
+/// for all texels in the image, and the sample locations will not depend on
+/// the internal format or size of the image.
+///
+/// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+/// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+/// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+/// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+/// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+/// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+/// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+/// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+/// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+/// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+/// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+/// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+/// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+/// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+/// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+/// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+/// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+/// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+/// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+/// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+/// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+/// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+/// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+/// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+/// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+/// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+/// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+///
+impl Context {
+    pub(crate) fn oxidegl_tex_storage2_d_multisample(
+        &mut self,
+        target: TextureTarget,
+        samples: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        fixedsamplelocations: GLboolean,
+    ) -> GlFallible {
+        sizei!(samples);
+        sizei!(width);
+        sizei!(height);
+        self.tex_storage_multisample_internal(
+            target,
+            StorageDims::Two,
+            samples,
+            internalformat,
+            TextureExtent::new(width, height, 1),
+        )
+    }
+    pub(crate) fn oxidegl_texture_storage2_d_multisample(
+        &mut self,
+        texture: GLuint,
+        samples: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        fixedsamplelocations: GLboolean,
+    ) -> GlFallible {
+        sizei!(samples);
+        sizei!(width);
+        sizei!(height);
+        let name = self.texture_by_name(texture)?;
+        self.texture_storage_internal(
+            name,
+            StorageDims::Two,
+            1,
+            Some(samples),
+            internalformat,
+            TextureExtent::new(width, height, 1),
+        )
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d).
+/// > Must be one of [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+/// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// > [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D), [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_2D_ARRAY)
+/// > or [`GL_PROXY_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureStorage3D**](crate::context::Context::oxidegl_texture_storage3_d).
+/// > The effective target of `texture` must be one of the valid non-proxy `target`
+/// > values above.
+///
+/// `levels`
+///
+/// > Specify the number of texture levels.
+///
+/// `internalformat`
+///
+/// > Specifies the sized internal format to be used to store texture image data.
+///
+/// `width`
+///
+/// > Specifies the width of the texture, in texels.
+///
+/// `height`
+///
+/// > Specifies the height of the texture, in texels.
+///
+/// `depth`
+///
+/// > Specifies the depth of the texture, in texels.
+///
+/// ### Description
+/// [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d) and
+/// [**glTextureStorage3D**](crate::context::Context::oxidegl_texture_storage3_d)
+/// specify the storage requirements for all levels of a three-dimensional,
+/// two-dimensional array or cube-map array texture simultaneously. Once a
+/// texture is specified with this command, the format and dimensions of all
+/// levels become immutable unless it is a proxy texture. The contents of the
+/// image may still be modified, however, its storage requirements may not
+/// change. Such a texture is referred to as an *immutable-format* texture.
+///
+/// The behavior of [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d)
+/// depends on the `target` parameter. When `target` is [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+/// or [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D), calling
+/// [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d) is
+/// equivalent, assuming no errors are generated, to executing the following
+/// pseudo-code:
+///
+/// When `target` is [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+/// [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_2D_ARRAY),
+/// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// or [`GL_PROXY_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP_ARRAY),
+/// [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d) is
+/// equivalent to:
+///
+/// Calling [**glTextureStorage3D**](crate::context::Context::oxidegl_texture_storage3_d)
+/// is equivalent to the above pseudo-code, where `target` is the effective
+/// target of `texture` and it is as if `texture` were bound to `target` for
+/// the purposes of [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
+///
+/// Since no texture data is actually provided, the values used in the pseudo-code
+/// for `format` and `type` are irrelevant and may be considered to be any
+/// values that are legal for the chosen `internalformat` enumerant. `internalformat`
+/// must be one of the sized internal formats given in Table 1 below, one of
+/// the sized depth-component formats [`GL_DEPTH_COMPONENT32F`](crate::enums::GL_DEPTH_COMPONENT32F),
+/// [`GL_DEPTH_COMPONENT24`](crate::enums::GL_DEPTH_COMPONENT24), or [`GL_DEPTH_COMPONENT16`](crate::enums::GL_DEPTH_COMPONENT16),
+/// one of the combined depth-stencil formats, [`GL_DEPTH32F_STENCIL8`](crate::enums::GL_DEPTH32F_STENCIL8),
+/// or [`GL_DEPTH24_STENCIL8`](crate::enums::GL_DEPTH24_STENCIL8), or the
+/// stencil-only format, [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8).
+/// Upon success, the value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+/// becomes [`GL_TRUE`](crate::enums::GL_TRUE). The value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+/// may be discovered by calling [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+/// with `pname` set to [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT).
+/// No further changes to the dimensions or format of the texture object may
+/// be made. Using any command that might alter the dimensions or format of
+/// the texture object (such as [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+/// or another call to [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d))
+/// will result in the generation of a [`GL_INVALID_OPERATION`](crate::enums::GL_INVALID_OPERATION)
+/// error, even if it would not, in fact, alter the dimensions or format of
+/// the object.
+///
+/// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+/// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+/// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+/// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+/// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+/// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+/// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+/// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+/// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+/// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+/// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+/// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+/// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+/// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+/// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+/// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+/// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+/// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+/// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+/// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+/// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+/// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+/// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+/// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+/// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+/// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+/// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+///
+///
+/// ### Notes
+/// [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8) is accepted for
+/// `internalformat` only if the GL version is 4.4 or higher.
+impl Context {
+    pub(crate) fn oxidegl_tex_storage3_d(
+        &mut self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ) -> GlFallible {
+        sizei!(levels);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        self.tex_storage_internal(
+            target,
+            StorageDims::Three,
+            levels,
+            internalformat,
+            TextureExtent::new(width, height, depth),
+        )
+    }
+    pub(crate) fn oxidegl_texture_storage3_d(
+        &mut self,
+        texture: GLuint,
+        levels: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ) -> GlFallible {
+        sizei!(levels);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        let name = self.texture_by_name(texture)?;
+        self.texture_storage_internal(
+            name,
+            StorageDims::Three,
+            levels,
+            None,
+            internalformat,
+            TextureExtent::new(width, height, depth),
+        )
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glTexStorage3DMultisample**](crate::context::Context::oxidegl_tex_storage3_d_multisample).
+/// > Must be one of [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+/// > or [`GL_PROXY_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_PROXY_TEXTURE_2D_MULTISAMPLE_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureStorage3DMultisample**](crate::context::Context::oxidegl_texture_storage3_d_multisample).
+/// > The effective target of `texture` must be one of the valid non-proxy `target`
+/// > values above.
+///
+/// `samples`
+///
+/// > Specify the number of samples in the texture.
+///
+/// `internalformat`
+///
+/// > Specifies the sized internal format to be used to store texture image data.
+///
+/// `width`
+///
+/// > Specifies the width of the texture, in texels.
+///
+/// `height`
+///
+/// > Specifies the height of the texture, in texels.
+///
+/// `depth`
+///
+/// > Specifies the depth of the texture, in layers.
+///
+/// `fixedsamplelocations`
+///
+/// > Specifies whether the image will use identical sample locations and the
+/// > same number of samples for all texels in the image, and the sample locations
+/// > will not depend on the internal format or size of the image.
+///
+/// ### Description
+/// [**glTexStorage3DMultisample**](crate::context::Context::oxidegl_tex_storage3_d_multisample)
+/// and [**glTextureStorage3DMultisample**](crate::context::Context::oxidegl_texture_storage3_d_multisample)
+/// specify the storage requirements for a two-dimensional multisample array
+/// texture. Once a texture is specified with this command, its format and
+/// dimensions become immutable unless it is a proxy texture. The contents
+/// of the image may still be modified, however, its storage requirements may
+/// not change. Such a texture is referred to as an *immutable-format* texture.
+///
+/// `samples` specifies the number of samples to be used for the texture and
+/// must be greater than zero and less than or equal to the value of [`GL_MAX_SAMPLES`](crate::enums::GL_MAX_SAMPLES).
+/// `internalformat` must be a color-renderable, depth-renderable, or stencil-renderable
+/// format. `width` and `height` specify the width and height, respectively,
+/// of the texture and `depth` specifies the depth (or the number of layers)
+/// of the texture. If `fixedsamplelocations` is [`GL_TRUE`](crate::enums::GL_TRUE),
+/// the image will use identical sample locations and the same number of samples
+/// for all texels in the image, and the sample locations will not depend on
+/// the internal format or size of the image.
+///
+/// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+/// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+/// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+/// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+/// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+/// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+/// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+/// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+/// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+/// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+/// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+/// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+/// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+/// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+/// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+/// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+/// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+/// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+/// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+/// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+/// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+/// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+/// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+/// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+/// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+/// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+/// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+/// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+///
+///
+/// ### Associated Gets
+/// [**glGetInteger**](crate::context::Context::oxidegl_get_integer) with arguments
+/// [`GL_MAX_TEXTURE_SIZE`](crate::enums::GL_MAX_TEXTURE_SIZE), [`GL_MAX_ARRAY_TEXTURE_LEVELS`](crate::enums::GL_MAX_ARRAY_TEXTURE_LEVELS),
+/// [`GL_TEXTURE_VIEW_MIN_LAYER`](crate::enums::GL_TEXTURE_VIEW_MIN_LAYER),
+/// [`GL_TEXTURE_VIEW_NUM_LAYERS`](crate::enums::GL_TEXTURE_VIEW_NUM_LAYERS),
+/// or [`GL_TEXTURE_IMMUTABLE_LEVELS`](crate::enums::GL_TEXTURE_IMMUTABLE_LEVELS).
+impl Context {
+    pub(crate) fn oxidegl_tex_storage3_d_multisample(
+        &mut self,
+        target: TextureTarget,
+        samples: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        fixedsamplelocations: GLboolean,
+    ) -> GlFallible {
+        sizei!(samples);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        self.tex_storage_multisample_internal(
+            target,
+            StorageDims::Three,
+            samples,
+            internalformat,
+            TextureExtent::new(width, height, depth),
+        )
+    }
+    pub(crate) fn oxidegl_texture_storage3_d_multisample(
+        &mut self,
+        texture: GLuint,
+        samples: GLsizei,
+        internalformat: SizedInternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        fixedsamplelocations: GLboolean,
+    ) -> GlFallible {
+        sizei!(samples);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        let name = self.texture_by_name(texture)?;
+        self.texture_storage_internal(
+            name,
+            StorageDims::Three,
+            1,
+            Some(samples),
+            internalformat,
+            TextureExtent::new(width, height, depth),
+        )
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D)
+    /// > or [`GL_PROXY_TEXTURE_1D`](crate::enums::GL_PROXY_TEXTURE_1D).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the number of color components in the texture. Must be one of
+    /// > base internal formats given in Table 1, one of the sized internal formats
+    /// > given in Table 2, or one of the compressed internal formats given in Table
+    /// > 3, below.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. All implementations support texture
+    /// > images that are at least 1024 texels wide. The height of the 1D texture
+    /// > image is 1.
+    ///
+    /// `border`
+    ///
+    /// > This value must be 0.
+    ///
+    /// `format`
+    ///
+    /// > Specifies the format of the pixel data. The following symbolic values are
+    /// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+    /// > [`GL_BGRA`](crate::enums::GL_BGRA), [`GL_RED_INTEGER`](crate::enums::GL_RED_INTEGER),
+    /// > [`GL_RG_INTEGER`](crate::enums::GL_RG_INTEGER), [`GL_RGB_INTEGER`](crate::enums::GL_RGB_INTEGER),
+    /// > [`GL_BGR_INTEGER`](crate::enums::GL_BGR_INTEGER), [`GL_RGBA_INTEGER`](crate::enums::GL_RGBA_INTEGER),
+    /// > [`GL_BGRA_INTEGER`](crate::enums::GL_BGRA_INTEGER), [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX),
+    /// > [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL).
+    ///
+    /// `type`
+    ///
+    /// > Specifies the data type of the pixel data. The following symbolic values
+    /// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+    /// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+    /// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+    /// > [`GL_HALF_FLOAT`](crate::enums::GL_HALF_FLOAT), [`GL_FLOAT`](crate::enums::GL_FLOAT),
+    /// > [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2), [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+    /// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+    /// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+    /// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+    /// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+    ///
+    /// `data`
+    ///
+    /// > Specifies a pointer to the image data in memory.
+    ///
+    /// ### Description
+    /// Texturing maps a portion of a specified texture image onto each graphical
+    /// primitive for which texturing is enabled. To enable and disable one-dimensional
+    /// texturing, call [**glEnable**](crate::context::Context::oxidegl_enable)
+    /// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
+    /// [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+    ///
+    /// Texture images are defined with [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
+    /// The arguments describe the parameters of the texture image, such as width,
+    /// width of the border, level-of-detail number (see [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+    /// ), and the internal resolution and format used to store the image. The
+    /// last three arguments describe how the image is represented in memory.
+    ///
+    /// If `target` is [`GL_PROXY_TEXTURE_1D`](crate::enums::GL_PROXY_TEXTURE_1D),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
+    ///
+    /// If `target` is [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), data is
+    /// read from `data` as a sequence of signed or unsigned bytes, shorts, or
+    /// longs, or single-precision floating-point values, depending on `type`. These
+    /// values are grouped into sets of one, two, three, or four values, depending
+    /// on `format`, to form elements. Each data byte is treated as eight 1-bit
+    /// elements, with bit ordering determined by [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST)
+    /// (see [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) ).
+    ///
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
+    ///
+    /// The first element corresponds to the left end of the texture array. Subsequent
+    /// elements progress left-to-right through the remaining texels in the texture
+    /// array. The final element corresponds to the right end of the texture array.
+    ///
+    /// `format` determines the composition of each element in `data`. It can assume
+    /// one of these symbolic values:
+    ///
+    /// [`GL_RED`](crate::enums::GL_RED)
+    ///
+    /// > Each element is a single red component. The GL converts it to floating
+    /// > point and assembles it into an RGBA element by attaching 0 for green and
+    /// > blue, and 1 for alpha. Each component is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RG`](crate::enums::GL_RG)
+    ///
+    /// > Each element is a single red/green double The GL converts it to floating
+    /// > point and assembles it into an RGBA element by attaching 0 for blue, and
+    /// > 1 for alpha. Each component is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RGB`](crate::enums::GL_RGB)
+    ///
+    /// [`GL_BGR`](crate::enums::GL_BGR)
+    ///
+    /// > Each element is an RGB triple. The GL converts it to floating point and
+    /// > assembles it into an RGBA element by attaching 1 for alpha. Each component
+    /// > is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RGBA`](crate::enums::GL_RGBA)
+    ///
+    /// [`GL_BGRA`](crate::enums::GL_BGRA)
+    ///
+    /// > Each element contains all four components. Each component clamped to the
+    /// > range \[0,1\].
+    ///
+    /// [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT)
+    ///
+    /// > Each element is a single depth value. The GL converts it to floating point
+    /// > and clamps to the range \[0,1\].
+    ///
+    /// If an application wants to store the texture at a certain resolution or
+    /// in a certain format, it can request the resolution and format with `internalformat`.
+    /// The GL will choose an internal representation that closely approximates
+    /// that requested by `internalformat`, but it may not match exactly. (The
+    /// representations specified by [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// [`GL_RGB`](crate::enums::GL_RGB) and [`GL_RGBA`](crate::enums::GL_RGBA)
+    /// must match exactly.)
+    ///
+    /// `internalformat` may be one of the base internal formats shown in Table
+    /// 1, below
+    ///
+    /// | *Base Internal Format*                               | *RGBA, Depth and Stencil Values*                                    | *Internal Components*                              |
+    /// |------------------------------------------------------|---------------------------------------------------------------------|----------------------------------------------------|
+    /// | [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT) | Depth                                                           | D                                                  |
+    /// | [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL) | Depth, Stencil                                                      | D, S                                               |
+    /// | [`GL_RED`](crate::enums::GL_RED)                     | Red                                                                 | R                                                  |
+    /// | [`GL_RG`](crate::enums::GL_RG)                       | Red, Green                                                          | R, G                                               |
+    /// | [`GL_RGB`](crate::enums::GL_RGB)                     | Red, Green, Blue                                                    | R, G, B                                            |
+    /// | [`GL_RGBA`](crate::enums::GL_RGBA)                   | Red, Green, Blue, Alpha                                             | R, G, B, A                                         |
+    ///
+    ///
+    /// `internalformat` may also be one of the sized internal formats shown in
+    /// Table 2, below
+    ///
+    /// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+    /// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+    /// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+    /// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+    /// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+    /// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+    /// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+    /// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+    /// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+    /// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+    /// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+    /// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+    /// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+    /// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+    /// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+    /// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+    /// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+    /// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+    /// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+    /// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+    /// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+    /// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+    /// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+    /// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+    /// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+    /// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+    /// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+    /// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+    /// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+    /// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+    /// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+    /// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+    /// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+    /// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+    /// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+    /// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+    /// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+    /// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+    /// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+    /// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+    /// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+    /// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+    /// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+    /// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+    /// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+    /// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+    /// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+    /// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+    /// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+    /// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+    /// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+    /// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+    /// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+    /// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+    /// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+    /// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+    /// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+    /// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+    /// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+    /// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+    /// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+    /// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+    /// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+    ///
+    ///
+    /// Finally, `internalformat` may also be one of the generic or compressed
+    /// texture formats shown in Table 3 below
+    ///
+    /// | *Compressed Internal Format*                                  | *Base Internal Format*                               | *Type*     |
+    /// |---------------------------------------------------------------|------------------------------------------------------|------------|
+    /// | [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED)        | [`GL_RED`](crate::enums::GL_RED)                     | Generic    |
+    /// | [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG)          | [`GL_RG`](crate::enums::GL_RG)                       | Generic    |
+    /// | [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB)        | [`GL_RGB`](crate::enums::GL_RGB)                     | Generic    |
+    /// | [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | Generic    |
+    /// | [`GL_COMPRESSED_SRGB`](crate::enums::GL_COMPRESSED_SRGB)      | [`GL_RGB`](crate::enums::GL_RGB)                     | Generic    |
+    /// | [`GL_COMPRESSED_SRGB_ALPHA`](crate::enums::GL_COMPRESSED_SRGB_ALPHA) | [`GL_RGBA`](crate::enums::GL_RGBA)            | Generic    |
+    /// | [`GL_COMPRESSED_RED_RGTC1`](crate::enums::GL_COMPRESSED_RED_RGTC1) | [`GL_RED`](crate::enums::GL_RED)                | Specific   |
+    /// | [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::enums::GL_COMPRESSED_SIGNED_RED_RGTC1) | [`GL_RED`](crate::enums::GL_RED)  | Specific   |
+    /// | [`GL_COMPRESSED_RG_RGTC2`](crate::enums::GL_COMPRESSED_RG_RGTC2) | [`GL_RG`](crate::enums::GL_RG)                    | Specific   |
+    /// | [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::enums::GL_COMPRESSED_SIGNED_RG_RGTC2) | [`GL_RG`](crate::enums::GL_RG)      | Specific   |
+    /// | [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_RGBA_BPTC_UNORM) | [`GL_RGBA`](crate::enums::GL_RGBA)  | Specific   |
+    /// | [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM) | [`GL_RGBA`](crate::enums::GL_RGBA) | Specific |
+    /// | [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT) | [`GL_RGB`](crate::enums::GL_RGB) | Specific |
+    /// | [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) | [`GL_RGB`](crate::enums::GL_RGB) | Specific |
+    ///
+    ///
+    /// If the `internalformat` parameter is one of the generic compressed formats,
+    /// [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED), [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG),
+    /// [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB), or [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA),
+    /// the GL will replace the internal format with the symbolic constant for
+    /// a specific internal format and compress the texture before storage. If
+    /// no corresponding internal format is available, or the GL can not compress
+    /// that image for any reason, the internal format is instead replaced with
+    /// a corresponding base internal format.
+    ///
+    /// If the `internalformat` parameter is [`GL_SRGB`](crate::enums::GL_SRGB),
+    /// [`GL_SRGB8`](crate::enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::enums::GL_SRGB_ALPHA)
+    /// or [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8), the texture is treated
+    /// as if the red, green, or blue components are encoded in the sRGB color
+    /// space. Any alpha component is left unchanged. The conversion from the sRGB
+    /// encoded component `[inlineq]` `[inlineq]`
+    ///
+    /// `[inlineq]`
+    ///
+    /// Assume `[inlineq]`
+    ///
+    /// Use the [`GL_PROXY_TEXTURE_1D`](crate::enums::GL_PROXY_TEXTURE_1D) target
+    /// to try out a resolution and format. The implementation will update and
+    /// recompute its best match for the requested storage resolution and format.
+    /// To then query this state, call [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+    /// If the texture cannot be accommodated, texture state is set to 0.
+    ///
+    /// A one-component texture image uses only the red component of the RGBA color
+    /// from `data`. A two-component image uses the R and A values. A three-component
+    /// image uses the R, G, and B values. A four-component image uses all of the
+    /// RGBA components.
+    ///
+    /// Image-based shadowing can be enabled by comparing texture r coordinates
+    /// to depth texture values to generate a boolean result. See [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+    /// for details on texture comparison.
+    ///
+    /// ### Notes
+    /// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) modes
+    /// affect texture images.
+    ///
+    /// `data` may be a null pointer. In this case texture memory is allocated
+    /// to accommodate a texture of width `width`. You can then download subtextures
+    /// to initialize the texture memory. The image is undefined if the program
+    /// tries to apply an uninitialized portion of the texture image to a primitive.
+    ///
+    /// [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d) specifies
+    /// the one-dimensional texture for the current texture unit, specified with
+    /// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+    ///
+    /// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) may be used for `format`
+    /// only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    pub(crate) unsafe fn oxidegl_tex_image1_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        border: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.tex_image_internal(
+                target,
+                StorageDims::One,
+                level,
+                internalformat,
+                TextureExtent::new(width, 1, 1),
+                border,
+                format,
+                r#type,
+                pixels,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// > [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+    /// > [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY),
+    /// > [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_PROXY_TEXTURE_RECTANGLE`](crate::enums::GL_PROXY_TEXTURE_RECTANGLE),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+    /// > or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image. If `target` is [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE)
+    /// > or [`GL_PROXY_TEXTURE_RECTANGLE`](crate::enums::GL_PROXY_TEXTURE_RECTANGLE),
+    /// > `level` must be 0.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the number of color components in the texture. Must be one of
+    /// > base internal formats given in Table 1, one of the sized internal formats
+    /// > given in Table 2, or one of the compressed internal formats given in Table
+    /// > 3, below.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. All implementations support texture
+    /// > images that are at least 1024 texels wide.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture image, or the number of layers in a
+    /// > texture array, in the case of the [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY)
+    /// > and [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY)
+    /// > targets. All implementations support 2D texture images that are at least
+    /// > 1024 texels high, and texture arrays that are at least 256 layers deep.
+    ///
+    /// `border`
+    ///
+    /// > This value must be 0.
+    ///
+    /// `format`
+    ///
+    /// > Specifies the format of the pixel data. The following symbolic values are
+    /// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+    /// > [`GL_BGRA`](crate::enums::GL_BGRA), [`GL_RED_INTEGER`](crate::enums::GL_RED_INTEGER),
+    /// > [`GL_RG_INTEGER`](crate::enums::GL_RG_INTEGER), [`GL_RGB_INTEGER`](crate::enums::GL_RGB_INTEGER),
+    /// > [`GL_BGR_INTEGER`](crate::enums::GL_BGR_INTEGER), [`GL_RGBA_INTEGER`](crate::enums::GL_RGBA_INTEGER),
+    /// > [`GL_BGRA_INTEGER`](crate::enums::GL_BGRA_INTEGER), [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX),
+    /// > [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL).
+    ///
+    /// `type`
+    ///
+    /// > Specifies the data type of the pixel data. The following symbolic values
+    /// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+    /// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+    /// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+    /// > [`GL_HALF_FLOAT`](crate::enums::GL_HALF_FLOAT), [`GL_FLOAT`](crate::enums::GL_FLOAT),
+    /// > [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2), [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+    /// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+    /// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+    /// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+    /// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+    ///
+    /// `data`
+    ///
+    /// > Specifies a pointer to the image data in memory.
+    ///
+    /// ### Description
+    /// Texturing allows elements of an image array to be read by shaders.
+    ///
+    /// To define texture images, call [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d).
+    /// The arguments describe the parameters of the texture image, such as height,
+    /// width, width of the border, level-of-detail number (see [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+    /// ), and number of color components provided. The last three arguments describe
+    /// how the image is represented in memory.
+    ///
+    /// If `target` is [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D),
+    /// [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY),
+    /// [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP),
+    /// or [`GL_PROXY_TEXTURE_RECTANGLE`](crate::enums::GL_PROXY_TEXTURE_RECTANGLE),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
+    ///
+    /// If `target` is [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE)
+    /// or one of the [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP)
+    /// targets, data is read from `data` as a sequence of signed or unsigned bytes,
+    /// shorts, or longs, or single-precision floating-point values, depending
+    /// on `type`. These values are grouped into sets of one, two, three, or four
+    /// values, depending on `format`, to form elements. Each data byte is treated
+    /// as eight 1-bit elements, with bit ordering determined by [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST)
+    /// (see [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) ).
+    ///
+    /// If `target` is [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+    /// data is interpreted as an array of one-dimensional images.
+    ///
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
+    ///
+    /// The first element corresponds to the lower left corner of the texture image.
+    /// Subsequent elements progress left-to-right through the remaining texels
+    /// in the lowest row of the texture image, and then in successively higher
+    /// rows of the texture image. The final element corresponds to the upper right
+    /// corner of the texture image.
+    ///
+    /// `format` determines the composition of each element in `data`. It can assume
+    /// one of these symbolic values:
+    ///
+    /// [`GL_RED`](crate::enums::GL_RED)
+    ///
+    /// > Each element is a single red component. The GL converts it to floating
+    /// > point and assembles it into an RGBA element by attaching 0 for green and
+    /// > blue, and 1 for alpha. Each component is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RG`](crate::enums::GL_RG)
+    ///
+    /// > Each element is a red/green double. The GL converts it to floating point
+    /// > and assembles it into an RGBA element by attaching 0 for blue, and 1 for
+    /// > alpha. Each component is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RGB`](crate::enums::GL_RGB)
+    ///
+    /// [`GL_BGR`](crate::enums::GL_BGR)
+    ///
+    /// > Each element is an RGB triple. The GL converts it to floating point and
+    /// > assembles it into an RGBA element by attaching 1 for alpha. Each component
+    /// > is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RGBA`](crate::enums::GL_RGBA)
+    ///
+    /// [`GL_BGRA`](crate::enums::GL_BGRA)
+    ///
+    /// > Each element contains all four components. Each component is clamped to
+    /// > the range \[0,1\].
+    ///
+    /// [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT)
+    ///
+    /// > Each element is a single depth value. The GL converts it to floating point
+    /// > and clamps to the range \[0,1\].
+    ///
+    /// [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL)
+    ///
+    /// > Each element is a pair of depth and stencil values. The depth component
+    /// > of the pair is interpreted as in [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT).
+    /// > The stencil component is interpreted based on specified the depth+ stencil
+    /// > internal format.
+    ///
+    /// If an application wants to store the texture at a certain resolution or
+    /// in a certain format, it can request the resolution and format with `internalformat`.
+    /// The GL will choose an internal representation that closely approximates
+    /// that requested by `internalformat`, but it may not match exactly. (The
+    /// representations specified by [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// [`GL_RGB`](crate::enums::GL_RGB), and [`GL_RGBA`](crate::enums::GL_RGBA)
+    /// must match exactly.)
+    ///
+    /// `internalformat` may be one of the base internal formats shown in Table
+    /// 1, below
+    ///
+    /// | *Base Internal Format*                               | *RGBA, Depth and Stencil Values*                                    | *Internal Components*                              |
+    /// |------------------------------------------------------|---------------------------------------------------------------------|----------------------------------------------------|
+    /// | [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT) | Depth                                                           | D                                                  |
+    /// | [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL) | Depth, Stencil                                                      | D, S                                               |
+    /// | [`GL_RED`](crate::enums::GL_RED)                     | Red                                                                 | R                                                  |
+    /// | [`GL_RG`](crate::enums::GL_RG)                       | Red, Green                                                          | R, G                                               |
+    /// | [`GL_RGB`](crate::enums::GL_RGB)                     | Red, Green, Blue                                                    | R, G, B                                            |
+    /// | [`GL_RGBA`](crate::enums::GL_RGBA)                   | Red, Green, Blue, Alpha                                             | R, G, B, A                                         |
+    ///
+    ///
+    /// `internalformat` may also be one of the sized internal formats shown in
+    /// Table 2, below
+    ///
+    /// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+    /// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+    /// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+    /// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+    /// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+    /// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+    /// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+    /// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+    /// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+    /// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+    /// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+    /// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+    /// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+    /// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+    /// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+    /// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+    /// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+    /// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+    /// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+    /// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+    /// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+    /// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+    /// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+    /// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+    /// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+    /// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+    /// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+    /// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+    /// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+    /// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+    /// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+    /// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+    /// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+    /// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+    /// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+    /// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+    /// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+    /// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+    /// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+    /// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+    /// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+    /// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+    /// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+    /// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+    /// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+    /// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+    /// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+    /// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+    /// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+    /// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+    /// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+    /// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+    /// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+    /// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+    /// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+    /// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+    /// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+    /// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+    /// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+    /// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+    /// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+    /// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+    /// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+    ///
+    ///
+    /// Finally, `internalformat` may also be one of the generic or compressed
+    /// texture formats shown in Table 3 below
+    ///
+    /// | *Compressed Internal Format*                                  | *Base Internal Format*                               | *Type*     |
+    /// |---------------------------------------------------------------|------------------------------------------------------|------------|
+    /// | [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED)        | [`GL_RED`](crate::enums::GL_RED)                     | Generic    |
+    /// | [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG)          | [`GL_RG`](crate::enums::GL_RG)                       | Generic    |
+    /// | [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB)        | [`GL_RGB`](crate::enums::GL_RGB)                     | Generic    |
+    /// | [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | Generic    |
+    /// | [`GL_COMPRESSED_SRGB`](crate::enums::GL_COMPRESSED_SRGB)      | [`GL_RGB`](crate::enums::GL_RGB)                     | Generic    |
+    /// | [`GL_COMPRESSED_SRGB_ALPHA`](crate::enums::GL_COMPRESSED_SRGB_ALPHA) | [`GL_RGBA`](crate::enums::GL_RGBA)            | Generic    |
+    /// | [`GL_COMPRESSED_RED_RGTC1`](crate::enums::GL_COMPRESSED_RED_RGTC1) | [`GL_RED`](crate::enums::GL_RED)                | Specific   |
+    /// | [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::enums::GL_COMPRESSED_SIGNED_RED_RGTC1) | [`GL_RED`](crate::enums::GL_RED)  | Specific   |
+    /// | [`GL_COMPRESSED_RG_RGTC2`](crate::enums::GL_COMPRESSED_RG_RGTC2) | [`GL_RG`](crate::enums::GL_RG)                    | Specific   |
+    /// | [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::enums::GL_COMPRESSED_SIGNED_RG_RGTC2) | [`GL_RG`](crate::enums::GL_RG)      | Specific   |
+    /// | [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_RGBA_BPTC_UNORM) | [`GL_RGBA`](crate::enums::GL_RGBA)  | Specific   |
+    /// | [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM) | [`GL_RGBA`](crate::enums::GL_RGBA) | Specific |
+    /// | [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT) | [`GL_RGB`](crate::enums::GL_RGB) | Specific |
+    /// | [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) | [`GL_RGB`](crate::enums::GL_RGB) | Specific |
+    ///
+    ///
+    /// If the `internalformat` parameter is one of the generic compressed formats,
+    /// [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED), [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG),
+    /// [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB), or [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA),
+    /// the GL will replace the internal format with the symbolic constant for
+    /// a specific internal format and compress the texture before storage. If
+    /// no corresponding internal format is available, or the GL can not compress
+    /// that image for any reason, the internal format is instead replaced with
+    /// a corresponding base internal format.
+    ///
+    /// If the `internalformat` parameter is [`GL_SRGB`](crate::enums::GL_SRGB),
+    /// [`GL_SRGB8`](crate::enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::enums::GL_SRGB_ALPHA),
+    /// or [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8), the texture is
+    /// treated as if the red, green, or blue components are encoded in the sRGB
+    /// color space. Any alpha component is left unchanged. The conversion from
+    /// the sRGB encoded component `[inlineq]` `[inlineq]`
+    ///
+    /// `[inlineq]`
+    ///
+    /// Assume `[inlineq]`
+    ///
+    /// Use the [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D), [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY),
+    /// [`GL_PROXY_TEXTURE_RECTANGLE`](crate::enums::GL_PROXY_TEXTURE_RECTANGLE),
+    /// or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP)
+    /// target to try out a resolution and format. The implementation will update
+    /// and recompute its best match for the requested storage resolution and format.
+    /// To then query this state, call [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+    /// If the texture cannot be accommodated, texture state is set to 0.
+    ///
+    /// A one-component texture image uses only the red component of the RGBA color
+    /// extracted from `data`. A two-component image uses the R and G values. A
+    /// three-component image uses the R, G, and B values. A four-component image
+    /// uses all of the RGBA components.
+    ///
+    /// Image-based shadowing can be enabled by comparing texture r coordinates
+    /// to depth texture values to generate a boolean result. See [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+    /// for details on texture comparison.
+    ///
+    /// ### Notes
+    /// The [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) mode
+    /// affects texture images.
+    ///
+    /// `data` may be a null pointer. In this case, texture memory is allocated
+    /// to accommodate a texture of width `width` and height `height`. You can
+    /// then download subtextures to initialize this texture memory. The image
+    /// is undefined if the user tries to apply an uninitialized portion of the
+    /// texture image to a primitive.
+    ///
+    /// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d) specifies
+    /// the two-dimensional texture for the current texture unit, specified with
+    /// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+    ///
+    /// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) may be used for `format`
+    /// only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    pub(crate) unsafe fn oxidegl_tex_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.tex_image_internal(
+                target,
+                StorageDims::Two,
+                level,
+                internalformat,
+                TextureExtent::new(width, height, 1),
+                border,
+                format,
+                r#type,
+                pixels,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be one of [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+    /// > [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY)
+    /// > or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_2D_ARRAY).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level `[inlineq]` `[inlineq]`
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the number of color components in the texture. Must be one of
+    /// > base internal formats given in Table 1, one of the sized internal formats
+    /// > given in Table 2, or one of the compressed internal formats given in Table
+    /// > 3, below.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. All implementations support 3D
+    /// > texture images that are at least 16 texels wide.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture image. All implementations support
+    /// > 3D texture images that are at least 256 texels high.
+    ///
+    /// `depth`
+    ///
+    /// > Specifies the depth of the texture image, or the number of layers in a
+    /// > texture array. All implementations support 3D texture images that are at
+    /// > least 256 texels deep, and texture arrays that are at least 256 layers
+    /// > deep.
+    ///
+    /// `border`
+    ///
+    /// > This value must be 0.
+    ///
+    /// `format`
+    ///
+    /// > Specifies the format of the pixel data. The following symbolic values are
+    /// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+    /// > [`GL_BGRA`](crate::enums::GL_BGRA), [`GL_RED_INTEGER`](crate::enums::GL_RED_INTEGER),
+    /// > [`GL_RG_INTEGER`](crate::enums::GL_RG_INTEGER), [`GL_RGB_INTEGER`](crate::enums::GL_RGB_INTEGER),
+    /// > [`GL_BGR_INTEGER`](crate::enums::GL_BGR_INTEGER), [`GL_RGBA_INTEGER`](crate::enums::GL_RGBA_INTEGER),
+    /// > [`GL_BGRA_INTEGER`](crate::enums::GL_BGRA_INTEGER), [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX),
+    /// > [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL).
+    ///
+    /// `type`
+    ///
+    /// > Specifies the data type of the pixel data. The following symbolic values
+    /// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+    /// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+    /// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+    /// > [`GL_HALF_FLOAT`](crate::enums::GL_HALF_FLOAT), [`GL_FLOAT`](crate::enums::GL_FLOAT),
+    /// > [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2), [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+    /// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+    /// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+    /// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+    /// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+    ///
+    /// `data`
+    ///
+    /// > Specifies a pointer to the image data in memory.
+    ///
+    /// ### Description
+    /// Texturing maps a portion of a specified texture image onto each graphical
+    /// primitive for which texturing is enabled. To enable and disable three-dimensional
+    /// texturing, call [**glEnable**](crate::context::Context::oxidegl_enable)
+    /// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
+    /// [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D).
+    ///
+    /// To define texture images, call [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
+    /// The arguments describe the parameters of the texture image, such as height,
+    /// width, depth, width of the border, level-of-detail number (see [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+    /// ), and number of color components provided. The last three arguments describe
+    /// how the image is represented in memory.
+    ///
+    /// If `target` is [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
+    ///
+    /// If `target` is [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), data is
+    /// read from `data` as a sequence of signed or unsigned bytes, shorts, or
+    /// longs, or single-precision floating-point values, depending on `type`. These
+    /// values are grouped into sets of one, two, three, or four values, depending
+    /// on `format`, to form elements. Each data byte is treated as eight 1-bit
+    /// elements, with bit ordering determined by [`GL_UNPACK_LSB_FIRST`](crate::enums::GL_UNPACK_LSB_FIRST)
+    /// (see [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) ).
+    ///
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
+    ///
+    /// The first element corresponds to the lower left corner of the texture image.
+    /// Subsequent elements progress left-to-right through the remaining texels
+    /// in the lowest row of the texture image, and then in successively higher
+    /// rows of the texture image. The final element corresponds to the upper right
+    /// corner of the texture image.
+    ///
+    /// `format` determines the composition of each element in `data`. It can assume
+    /// one of these symbolic values:
+    ///
+    /// [`GL_RED`](crate::enums::GL_RED)
+    ///
+    /// > Each element is a single red component. The GL converts it to floating
+    /// > point and assembles it into an RGBA element by attaching 0 for green and
+    /// > blue, and 1 for alpha. Each component is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RG`](crate::enums::GL_RG)
+    ///
+    /// > Each element is a red and green pair. The GL converts each to floating
+    /// > point and assembles it into an RGBA element by attaching 0 for blue, and
+    /// > 1 for alpha. Each component is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RGB`](crate::enums::GL_RGB)
+    ///
+    /// [`GL_BGR`](crate::enums::GL_BGR)
+    ///
+    /// > Each element is an RGB triple. The GL converts it to floating point and
+    /// > assembles it into an RGBA element by attaching 1 for alpha. Each component
+    /// > is clamped to the range \[0,1\].
+    ///
+    /// [`GL_RGBA`](crate::enums::GL_RGBA)
+    ///
+    /// [`GL_BGRA`](crate::enums::GL_BGRA)
+    ///
+    /// > Each element contains all four components. Each component is clamped to
+    /// > the range \[0,1\].
+    ///
+    /// If an application wants to store the texture at a certain resolution or
+    /// in a certain format, it can request the resolution and format with `internalformat`.
+    /// The GL will choose an internal representation that closely approximates
+    /// that requested by `internalformat`, but it may not match exactly. (The
+    /// representations specified by [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// [`GL_RGB`](crate::enums::GL_RGB), and [`GL_RGBA`](crate::enums::GL_RGBA)
+    /// must match exactly.)
+    ///
+    /// `internalformat` may be one of the base internal formats shown in Table
+    /// 1, below
+    ///
+    /// | *Base Internal Format*                               | *RGBA, Depth and Stencil Values*                                    | *Internal Components*                              |
+    /// |------------------------------------------------------|---------------------------------------------------------------------|----------------------------------------------------|
+    /// | [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT) | Depth                                                           | D                                                  |
+    /// | [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL) | Depth, Stencil                                                      | D, S                                               |
+    /// | [`GL_RED`](crate::enums::GL_RED)                     | Red                                                                 | R                                                  |
+    /// | [`GL_RG`](crate::enums::GL_RG)                       | Red, Green                                                          | R, G                                               |
+    /// | [`GL_RGB`](crate::enums::GL_RGB)                     | Red, Green, Blue                                                    | R, G, B                                            |
+    /// | [`GL_RGBA`](crate::enums::GL_RGBA)                   | Red, Green, Blue, Alpha                                             | R, G, B, A                                         |
+    ///
+    ///
+    /// `internalformat` may also be one of the sized internal formats shown in
+    /// Table 2, below
+    ///
+    /// | *Sized Internal Format*                               | *Base Internal Format*                               | *Red Bits*       | *Green Bits*        | *Blue Bits*       | *Alpha Bits*        | *Shared Bits*        |
+    /// |-------------------------------------------------------|------------------------------------------------------|------------------|---------------------|-------------------|---------------------|----------------------|
+    /// | [`GL_R8`](crate::enums::GL_R8)                        | [`GL_RED`](crate::enums::GL_RED)                     | 8                |                     |                   |                     |                      |
+    /// | [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM)            | [`GL_RED`](crate::enums::GL_RED)                     | s8               |                     |                   |                     |                      |
+    /// | [`GL_R16`](crate::enums::GL_R16)                      | [`GL_RED`](crate::enums::GL_RED)                     | 16               |                     |                   |                     |                      |
+    /// | [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM)          | [`GL_RED`](crate::enums::GL_RED)                     | s16              |                     |                   |                     |                      |
+    /// | [`GL_RG8`](crate::enums::GL_RG8)                      | [`GL_RG`](crate::enums::GL_RG)                       | 8                | 8                   |                   |                     |                      |
+    /// | [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM)          | [`GL_RG`](crate::enums::GL_RG)                       | s8               | s8                  |                   |                     |                      |
+    /// | [`GL_RG16`](crate::enums::GL_RG16)                    | [`GL_RG`](crate::enums::GL_RG)                       | 16               | 16                  |                   |                     |                      |
+    /// | [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM)        | [`GL_RG`](crate::enums::GL_RG)                       | s16              | s16                 |                   |                     |                      |
+    /// | [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2)            | [`GL_RGB`](crate::enums::GL_RGB)                     | 3                | 3                   | 2                 |                     |                      |
+    /// | [`GL_RGB4`](crate::enums::GL_RGB4)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 |                     |                      |
+    /// | [`GL_RGB5`](crate::enums::GL_RGB5)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 5                | 5                   | 5                 |                     |                      |
+    /// | [`GL_RGB8`](crate::enums::GL_RGB8)                    | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+    /// | [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM)        | [`GL_RGB`](crate::enums::GL_RGB)                     | s8               | s8                  | s8                |                     |                      |
+    /// | [`GL_RGB10`](crate::enums::GL_RGB10)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 10               | 10                  | 10                |                     |                      |
+    /// | [`GL_RGB12`](crate::enums::GL_RGB12)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 12               | 12                  | 12                |                     |                      |
+    /// | [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM)      | [`GL_RGB`](crate::enums::GL_RGB)                     | 16               | 16                  | 16                |                     |                      |
+    /// | [`GL_RGBA2`](crate::enums::GL_RGBA2)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 2                | 2                   | 2                 | 2                   |                      |
+    /// | [`GL_RGBA4`](crate::enums::GL_RGBA4)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 4                | 4                   | 4                 | 4                   |                      |
+    /// | [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 5                | 5                   | 5                 | 1                   |                      |
+    /// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+    /// | [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | s8               | s8                  | s8                | s8                  |                      |
+    /// | [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 10               | 10                  | 10                | 2                   |                      |
+    /// | [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI)        | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui10             | ui10                | ui10              | ui2                 |                      |
+    /// | [`GL_RGBA12`](crate::enums::GL_RGBA12)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 12               | 12                  | 12                | 12                  |                      |
+    /// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 16               | 16                  | 16                | 16                  |                      |
+    /// | [`GL_SRGB8`](crate::enums::GL_SRGB8)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | 8                | 8                   | 8                 |                     |                      |
+    /// | [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8)    | [`GL_RGBA`](crate::enums::GL_RGBA)                   | 8                | 8                   | 8                 | 8                   |                      |
+    /// | [`GL_R16F`](crate::enums::GL_R16F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f16              |                     |                   |                     |                      |
+    /// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f16              | f16                 |                   |                     |                      |
+    /// | [`GL_RGB16F`](crate::enums::GL_RGB16F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f16              | f16                 | f16               |                     |                      |
+    /// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f16              | f16                 | f16               | f16                 |                      |
+    /// | [`GL_R32F`](crate::enums::GL_R32F)                    | [`GL_RED`](crate::enums::GL_RED)                     | f32              |                     |                   |                     |                      |
+    /// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | [`GL_RG`](crate::enums::GL_RG)                       | f32              | f32                 |                   |                     |                      |
+    /// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | [`GL_RGB`](crate::enums::GL_RGB)                     | f32              | f32                 | f32               |                     |                      |
+    /// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | f32              | f32                 | f32               | f32                 |                      |
+    /// | [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F) | [`GL_RGB`](crate::enums::GL_RGB)                    | f11              | f11                 | f10               |                     |                      |
+    /// | [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5)              | [`GL_RGB`](crate::enums::GL_RGB)                     | 9                | 9                   | 9                 |                     | 5                    |
+    /// | [`GL_R8I`](crate::enums::GL_R8I)                      | [`GL_RED`](crate::enums::GL_RED)                     | i8               |                     |                   |                     |                      |
+    /// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | [`GL_RED`](crate::enums::GL_RED)                     | ui8              |                     |                   |                     |                      |
+    /// | [`GL_R16I`](crate::enums::GL_R16I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i16              |                     |                   |                     |                      |
+    /// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui16             |                     |                   |                     |                      |
+    /// | [`GL_R32I`](crate::enums::GL_R32I)                    | [`GL_RED`](crate::enums::GL_RED)                     | i32              |                     |                   |                     |                      |
+    /// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | [`GL_RED`](crate::enums::GL_RED)                     | ui32             |                     |                   |                     |                      |
+    /// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | [`GL_RG`](crate::enums::GL_RG)                       | i8               | i8                  |                   |                     |                      |
+    /// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | [`GL_RG`](crate::enums::GL_RG)                       | ui8              | ui8                 |                   |                     |                      |
+    /// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i16              | i16                 |                   |                     |                      |
+    /// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui16             | ui16                |                   |                     |                      |
+    /// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | [`GL_RG`](crate::enums::GL_RG)                       | i32              | i32                 |                   |                     |                      |
+    /// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | [`GL_RG`](crate::enums::GL_RG)                       | ui32             | ui32                |                   |                     |                      |
+    /// | [`GL_RGB8I`](crate::enums::GL_RGB8I)                  | [`GL_RGB`](crate::enums::GL_RGB)                     | i8               | i8                  | i8                |                     |                      |
+    /// | [`GL_RGB8UI`](crate::enums::GL_RGB8UI)                | [`GL_RGB`](crate::enums::GL_RGB)                     | ui8              | ui8                 | ui8               |                     |                      |
+    /// | [`GL_RGB16I`](crate::enums::GL_RGB16I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i16              | i16                 | i16               |                     |                      |
+    /// | [`GL_RGB16UI`](crate::enums::GL_RGB16UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui16             | ui16                | ui16              |                     |                      |
+    /// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | [`GL_RGB`](crate::enums::GL_RGB)                     | i32              | i32                 | i32               |                     |                      |
+    /// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | [`GL_RGB`](crate::enums::GL_RGB)                     | ui32             | ui32                | ui32              |                     |                      |
+    /// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i8               | i8                  | i8                | i8                  |                      |
+    /// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui8              | ui8                 | ui8               | ui8                 |                      |
+    /// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i16              | i16                 | i16               | i16                 |                      |
+    /// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui16             | ui16                | ui16              | ui16                |                      |
+    /// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | [`GL_RGBA`](crate::enums::GL_RGBA)                   | i32              | i32                 | i32               | i32                 |                      |
+    /// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | [`GL_RGBA`](crate::enums::GL_RGBA)                   | ui32             | ui32                | ui32              | ui32                |                      |
+    ///
+    ///
+    /// Finally, `internalformat` may also be one of the generic or compressed
+    /// texture formats shown in Table 3 below
+    ///
+    /// | *Compressed Internal Format*                                  | *Base Internal Format*                               | *Type*     |
+    /// |---------------------------------------------------------------|------------------------------------------------------|------------|
+    /// | [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED)        | [`GL_RED`](crate::enums::GL_RED)                     | Generic    |
+    /// | [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG)          | [`GL_RG`](crate::enums::GL_RG)                       | Generic    |
+    /// | [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB)        | [`GL_RGB`](crate::enums::GL_RGB)                     | Generic    |
+    /// | [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA)      | [`GL_RGBA`](crate::enums::GL_RGBA)                   | Generic    |
+    /// | [`GL_COMPRESSED_SRGB`](crate::enums::GL_COMPRESSED_SRGB)      | [`GL_RGB`](crate::enums::GL_RGB)                     | Generic    |
+    /// | [`GL_COMPRESSED_SRGB_ALPHA`](crate::enums::GL_COMPRESSED_SRGB_ALPHA) | [`GL_RGBA`](crate::enums::GL_RGBA)            | Generic    |
+    /// | [`GL_COMPRESSED_RED_RGTC1`](crate::enums::GL_COMPRESSED_RED_RGTC1) | [`GL_RED`](crate::enums::GL_RED)                | Specific   |
+    /// | [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::enums::GL_COMPRESSED_SIGNED_RED_RGTC1) | [`GL_RED`](crate::enums::GL_RED)  | Specific   |
+    /// | [`GL_COMPRESSED_RG_RGTC2`](crate::enums::GL_COMPRESSED_RG_RGTC2) | [`GL_RG`](crate::enums::GL_RG)                    | Specific   |
+    /// | [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::enums::GL_COMPRESSED_SIGNED_RG_RGTC2) | [`GL_RG`](crate::enums::GL_RG)      | Specific   |
+    /// | [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_RGBA_BPTC_UNORM) | [`GL_RGBA`](crate::enums::GL_RGBA)  | Specific   |
+    /// | [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM) | [`GL_RGBA`](crate::enums::GL_RGBA) | Specific |
+    /// | [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT) | [`GL_RGB`](crate::enums::GL_RGB) | Specific |
+    /// | [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) | [`GL_RGB`](crate::enums::GL_RGB) | Specific |
+    ///
+    ///
+    /// If the `internalformat` parameter is one of the generic compressed formats,
+    /// [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED), [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG),
+    /// [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB), or [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA),
+    /// the GL will replace the internal format with the symbolic constant for
+    /// a specific internal format and compress the texture before storage. If
+    /// no corresponding internal format is available, or the GL can not compress
+    /// that image for any reason, the internal format is instead replaced with
+    /// a corresponding base internal format.
+    ///
+    /// If the `internalformat` parameter is [`GL_SRGB`](crate::enums::GL_SRGB),
+    /// [`GL_SRGB8`](crate::enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::enums::GL_SRGB_ALPHA),
+    /// or [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8), the texture is
+    /// treated as if the red, green, blue, or luminance components are encoded
+    /// in the sRGB color space. Any alpha component is left unchanged. The conversion
+    /// from the sRGB encoded component `[inlineq]` `[inlineq]`
+    ///
+    /// `[inlineq]`
+    ///
+    /// Assume `[inlineq]`
+    ///
+    /// Use the [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D) target
+    /// to try out a resolution and format. The implementation will update and
+    /// recompute its best match for the requested storage resolution and format.
+    /// To then query this state, call [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+    /// If the texture cannot be accommodated, texture state is set to 0.
+    ///
+    /// A one-component texture image uses only the red component of the RGBA color
+    /// extracted from `data`. A two-component image uses the R and A values. A
+    /// three-component image uses the R, G, and B values. A four-component image
+    /// uses all of the RGBA components.
+    ///
+    /// ### Notes
+    /// The [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) mode
+    /// affects texture images.
+    ///
+    /// `data` may be a null pointer. In this case texture memory is allocated
+    /// to accommodate a texture of width `width`, height `height`, and depth `depth`.
+    /// You can then download subtextures to initialize this texture memory. The
+    /// image is undefined if the user tries to apply an uninitialized portion
+    /// of the texture image to a primitive.
+    ///
+    /// [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d) specifies
+    /// the three-dimensional texture for the current texture unit, specified with
+    /// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+    ///
+    /// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) may be used for `format`
+    /// only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    pub(crate) unsafe fn oxidegl_tex_image3_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.tex_image_internal(
+                target,
+                StorageDims::Three,
+                level,
+                internalformat,
+                TextureExtent::new(width, height, depth),
+                border,
+                format,
+                r#type,
+                pixels,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d).
+/// > Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureSubImage1D**](crate::context::Context::oxidegl_texture_sub_image1_d).
+/// > The effective target of `texture` must be one of the valid `target` values
+/// > above.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `format`
+///
+/// > Specifies the format of the pixel data. The following symbolic values are
+/// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+/// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+/// > [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), and [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX).
+///
+/// `type`
+///
+/// > Specifies the data type of the pixel data. The following symbolic values
+/// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+/// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+/// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+/// > [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2),
+/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+/// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+///
+/// `pixels`
+///
+/// > Specifies a pointer to the image data in memory.
+///
+/// ### Description
+/// Texturing maps a portion of a specified texture image onto each graphical
+/// primitive for which texturing is enabled. To enable or disable one-dimensional
+/// texturing, call [**glEnable**](crate::context::Context::oxidegl_enable)
+/// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
+/// [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+///
+/// [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d)
+/// and [**glTextureSubImage1D**](crate::context::Context::oxidegl_texture_sub_image1_d)
+/// redefine a contiguous subregion of an existing one-dimensional texture
+/// image. The texels referenced by `pixels` replace the portion of the existing
+/// texture array with x indices `xoffset` and `[inlineq]`
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is specified, `pixels` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// ### Notes
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) modes
+/// affect texture images.
+///
+/// [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d)
+/// and [**glTextureSubImage1D**](crate::context::Context::oxidegl_texture_sub_image1_d)
+/// specify a one-dimensional subtexture for the current texture unit, specified
+/// with [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+///
+/// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) is accepted for `format`
+/// only if the GL version is 4.4 or higher.
+///
+/// ### Associated Gets
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+impl Context {
+    pub(crate) unsafe fn oxidegl_tex_sub_image1_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        width: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        let (name, face) = self.sub_image_target(target, StorageDims::One)?;
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [xoffset, 0, 0],
+                TextureExtent::new(width, 1, 1),
+                GlPixelTypeFormat::new(r#type, format),
+                pixels,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_texture_sub_image1_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        width: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::One)?;
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, 0, 0],
+                TextureExtent::new(width, 1, 1),
+                GlPixelTypeFormat::new(r#type, format),
+                pixels,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d).
+/// > Must be [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+/// > or [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureSubImage2D**](crate::context::Context::oxidegl_texture_sub_image2_d).
+/// > The effective target of `texture` must be one of the valid `target` values
+/// > above.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `yoffset`
+///
+/// > Specifies a texel offset in the y direction within the texture array.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `height`
+///
+/// > Specifies the height of the texture subimage.
+///
+/// `format`
+///
+/// > Specifies the format of the pixel data. The following symbolic values are
+/// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+/// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+/// > [`GL_BGRA`](crate::enums::GL_BGRA), [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+/// > and [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX).
+///
+/// `type`
+///
+/// > Specifies the data type of the pixel data. The following symbolic values
+/// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+/// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+/// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+/// > [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2),
+/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+/// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+///
+/// `pixels`
+///
+/// > Specifies a pointer to the image data in memory.
+///
+/// ### Description
+/// Texturing maps a portion of a specified texture image onto each graphical
+/// primitive for which texturing is enabled.
+///
+/// [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d)
+/// and [**glTextureSubImage2D**](crate::context::Context::oxidegl_texture_sub_image2_d)
+/// redefine a contiguous subregion of an existing two-dimensional or one-dimensional
+/// array texture image. The texels referenced by `pixels` replace the portion
+/// of the existing texture array with x indices `xoffset` and `[inlineq]`
+/// `yoffset` and `[inlineq]`
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is specified, `pixels` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// ### Notes
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) modes
+/// affect texture images.
+///
+/// [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d)
+/// and [**glTextureSubImage3D**](crate::context::Context::oxidegl_texture_sub_image3_d)
+/// specify a two-dimensional subtexture for the current texture unit, specified
+/// with [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+///
+/// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) is accepted for `format`
+/// only if the GL version is 4.4 or higher.
+///
+/// ### Associated Gets
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+impl Context {
+    pub(crate) unsafe fn oxidegl_tex_sub_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        let (name, face) = self.sub_image_target(target, StorageDims::Two)?;
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [xoffset, yoffset, 0],
+                TextureExtent::new(width, height, 1),
+                GlPixelTypeFormat::new(r#type, format),
+                pixels,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_texture_sub_image2_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::Two)?;
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, yoffset, 0],
+                TextureExtent::new(width, height, 1),
+                GlPixelTypeFormat::new(r#type, format),
+                pixels,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d).
+/// > Must be [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D) or [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureSubImage3D**](crate::context::Context::oxidegl_texture_sub_image3_d).
+/// > The effective target of `texture` must be one of the valid `target` values
+/// > above.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `yoffset`
+///
+/// > Specifies a texel offset in the y direction within the texture array.
+///
+/// `zoffset`
+///
+/// > Specifies a texel offset in the z direction within the texture array.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `height`
+///
+/// > Specifies the height of the texture subimage.
+///
+/// `depth`
+///
+/// > Specifies the depth of the texture subimage.
+///
+/// `format`
+///
+/// > Specifies the format of the pixel data. The following symbolic values are
+/// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+/// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+/// > [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), and [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX).
+///
+/// `type`
+///
+/// > Specifies the data type of the pixel data. The following symbolic values
+/// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+/// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+/// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+/// > [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2),
+/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+/// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+///
+/// `pixels`
+///
+/// > Specifies a pointer to the image data in memory.
+///
+/// ### Description
+/// Texturing maps a portion of a specified texture image onto each graphical
+/// primitive for which texturing is enabled.
+///
+/// [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d)
+/// and [**glTextureSubImage3D**](crate::context::Context::oxidegl_texture_sub_image3_d)
+/// redefine a contiguous subregion of an existing three-dimensional or two-dimensioanl
+/// array texture image. The texels referenced by `pixels` replace the portion
+/// of the existing texture array with x indices `xoffset` and `[inlineq]`
+/// `yoffset` and `[inlineq]` `zoffset` and `[inlineq]`
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is specified, `pixels` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// ### Notes
+/// The [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) modes
+/// affect texture images.
+///
+/// [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d)
+/// and [**glTextureSubImage3D**](crate::context::Context::oxidegl_texture_sub_image3_d)
+/// specify a three-dimensional or two-dimensional array subtexture for the
+/// current texture unit, specified with [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+///
+/// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) is accepted for `format`
+/// only if the GL version is 4.4 or higher.
+///
+/// ### Associated Gets
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+impl Context {
+    pub(crate) unsafe fn oxidegl_tex_sub_image3_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        let (name, face) = self.sub_image_target(target, StorageDims::Three)?;
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [xoffset, yoffset, zoffset],
+                TextureExtent::new(width, height, depth),
+                GlPixelTypeFormat::new(r#type, format),
+                pixels,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_texture_sub_image3_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::Three)?;
+        // Safety: caller ensures `pixels` is valid for reads of the specified image (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, yoffset, zoffset],
+                TextureExtent::new(width, height, depth),
+                GlPixelTypeFormat::new(r#type, format),
+                pixels,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+/// > and [**glGetnCompressedTexImage**](crate::context::Context::oxidegl_getn_compressed_tex_image)
+/// > functions. [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+/// > [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// > and [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+/// > [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE) are accepted.
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glGetCompressedTextureImage**](crate::context::Context::oxidegl_get_compressed_texture_image)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number of the desired image. Level 0 is the
+/// > base image level. Level $n$ is the $n$-th mipmap reduction image.
+///
+/// `bufSize`
+///
+/// > Specifies the size of the buffer `pixels` for [**glGetCompressedTextureImage**](crate::context::Context::oxidegl_get_compressed_texture_image)
+/// > and [**glGetnCompressedTexImage**](crate::context::Context::oxidegl_getn_compressed_tex_image)
+/// > functions.
+///
+/// `pixels`
+///
+/// > Returns the compressed texture image.
+///
+/// ### Description
+/// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+/// and [**glGetnCompressedTexImage**](crate::context::Context::oxidegl_getn_compressed_tex_image)
+/// return the compressed texture image associated with `target` and `lod`
+/// into `pixels`. [**glGetCompressedTextureImage**](crate::context::Context::oxidegl_get_compressed_texture_image)
+/// serves the same purpose, but instead of taking a texture target, it takes
+/// the ID of the texture object. `pixels` should be an array of `bufSize`
+/// bytes for [**glGetnCompresedTexImage**](crate::context::Context::oxidegl_getn_compresed_tex_image)
+/// and [**glGetCompressedTextureImage**](crate::context::Context::oxidegl_get_compressed_texture_image)
+/// functions, and of [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+/// bytes in case of [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image).
+/// If the actual data takes less space than `bufSize`, the remaining bytes
+/// will not be touched. `target` specifies the texture target, to which the
+/// texture the data the function should extract the data from is bound to.
+/// `lod` specifies the level-of-detail number of the desired image.
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_PACK_BUFFER`](crate::enums::GL_PIXEL_PACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is requested, `pixels` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// To minimize errors, first verify that the texture is compressed by calling
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED).
+/// If the texture is compressed, you can determine the amount of memory required
+/// to store the compressed texture by calling [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE).
+/// Finally, retrieve the internal format of the texture by calling [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT).
+/// To store the texture for later use, associate the internal format and
+/// size with the retrieved texture image. These data can be used by the respective
+/// texture or subtexture loading routine used for loading `target` textures.
+///
+/// ### Associated Gets
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_PACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_PACK_BUFFER_BINDING)
+impl Context {
+    pub(crate) unsafe fn oxidegl_get_compressed_tex_image(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        img: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        let (name, face) = self.readback_target(target)?;
+        // Safety: caller ensures `img` is valid for writes of the specified image (or is an offset into the pixel pack buffer)
+        unsafe { self.get_texture_image_internal(name, level, face, [0; 3], None, None, None, img) }
+    }
+    pub(crate) unsafe fn oxidegl_get_compressed_texture_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        buf_size: GLsizei,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(buf_size);
+        let name = self.texture_by_name(texture)?;
+        self.check_readback_texture(name)?;
+        // Safety: caller ensures `pixels` is valid for writes of `buf_size` bytes (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                level,
+                None,
+                [0; 3],
+                None,
+                None,
+                Some(buf_size as usize),
+                pixels,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_getn_compressed_tex_image(
+        &mut self,
+        target: TextureTarget,
+        lod: GLint,
+        buf_size: GLsizei,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(lod);
+        sizei!(buf_size);
+        let (name, face) = self.readback_target(target)?;
+        // Safety: caller ensures `pixels` is valid for writes of `buf_size` bytes (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                lod,
+                face,
+                [0; 3],
+                None,
+                None,
+                Some(buf_size as usize),
+                pixels,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+/// > and [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image)
+/// > functions. [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+/// > [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+/// > and [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+/// > are acceptable.
+///
+/// `texture`
+///
+/// > Specifies the texture object name.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number of the desired image. Level 0 is the
+/// > base image level. Level `[inlineq]` `[inlineq]`
+///
+/// `format`
+///
+/// > Specifies a pixel format for the returned data. The supported formats are
+/// > [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX), [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+/// > [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL), [`GL_RED`](crate::enums::GL_RED),
+/// > [`GL_GREEN`](crate::enums::GL_GREEN), [`GL_BLUE`](crate::enums::GL_BLUE),
+/// > [`GL_RG`](crate::enums::GL_RG), [`GL_RGB`](crate::enums::GL_RGB), [`GL_RGBA`](crate::enums::GL_RGBA),
+/// > [`GL_BGR`](crate::enums::GL_BGR), [`GL_BGRA`](crate::enums::GL_BGRA), [`GL_RED_INTEGER`](crate::enums::GL_RED_INTEGER),
+/// > [`GL_GREEN_INTEGER`](crate::enums::GL_GREEN_INTEGER), [`GL_BLUE_INTEGER`](crate::enums::GL_BLUE_INTEGER),
+/// > [`GL_RG_INTEGER`](crate::enums::GL_RG_INTEGER), [`GL_RGB_INTEGER`](crate::enums::GL_RGB_INTEGER),
+/// > [`GL_RGBA_INTEGER`](crate::enums::GL_RGBA_INTEGER), [`GL_BGR_INTEGER`](crate::enums::GL_BGR_INTEGER),
+/// > [`GL_BGRA_INTEGER`](crate::enums::GL_BGRA_INTEGER).
+///
+/// `type`
+///
+/// > Specifies a pixel type for the returned data. The supported types are [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE),
+/// > [`GL_BYTE`](crate::enums::GL_BYTE), [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT),
+/// > [`GL_SHORT`](crate::enums::GL_SHORT), [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT),
+/// > [`GL_INT`](crate::enums::GL_INT), [`GL_HALF_FLOAT`](crate::enums::GL_HALF_FLOAT),
+/// > [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2),
+/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+/// > [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV),
+/// > [`GL_UNSIGNED_INT_24_8`](crate::enums::GL_UNSIGNED_INT_24_8), [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::enums::GL_UNSIGNED_INT_10F_11F_11F_REV),
+/// > [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::enums::GL_UNSIGNED_INT_5_9_9_9_REV),
+/// > and [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
+///
+/// `bufSize`
+///
+/// > Specifies the size of the buffer `pixels` for [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image)
+/// > and [**glGetTextureImage**](crate::context::Context::oxidegl_get_texture_image)
+/// > functions.
+///
+/// `pixels`
+///
+/// > Returns the texture image. Should be a pointer to an array of the type
+/// > specified by `type`.
+///
+/// ### Description
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image)
+/// and [**glGetTextureImage**](crate::context::Context::oxidegl_get_texture_image)
+/// functions return a texture image into `pixels`. For [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+/// and [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image),
+/// `target` specifies whether the desired texture image is one specified by
+/// [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)( [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D)
+/// ), [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)( [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D)
+/// or any of [`GL_TEXTURE_CUBE_MAP_*`](crate::enums::GL_TEXTURE_CUBE_MAP_*)
+/// ), or [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)(
+/// [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+/// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+/// ). For [**glGetTextureImage**](crate::context::Context::oxidegl_get_texture_image),
+/// `texture` specifies the texture object name. In addition to types of textures
+/// accepted by [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+/// and [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image),
+/// the function also accepts cube map texture objects (with effective target
+/// [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP) ). `level` specifies
+/// the level-of-detail number of the desired image. `format` and `type` specify
+/// the format and type of the desired image array. See the reference page
+/// for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d) for
+/// a description of the acceptable values for the `format` and `type` parameters,
+/// respectively. For glGetnTexImage and glGetTextureImage functions, bufSize
+/// tells the size of the buffer to receive the retrieved pixel data. [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image)
+/// and [**glGetTextureImage**](crate::context::Context::oxidegl_get_texture_image)
+/// do not write more than `bufSize` bytes into `pixels`.
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_PACK_BUFFER`](crate::enums::GL_PIXEL_PACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is requested, `pixels` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// To understand the operation of [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image),
+/// consider the selected internal four-component texture image to be an RGBA
+/// color buffer the size of the image. The semantics of [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+/// are then identical to those of [**glReadPixels**](crate::context::Context::oxidegl_read_pixels),
+/// with the exception that no pixel transfer operations are performed, when
+/// called with the same `format` and `type`, with *x* and *y* set to 0, *width*
+/// set to the width of the texture image and *height* set to 1 for 1D images,
+/// or to the height of the texture image for 2D images.
+///
+/// If the selected texture image does not contain four components, the following
+/// mappings are applied. Single-component textures are treated as RGBA buffers
+/// with red set to the single-component value, green set to 0, blue set to
+/// 0, and alpha set to 1. Two-component textures are treated as RGBA buffers
+/// with red set to the value of component zero, alpha set to the value of
+/// component one, and green and blue set to 0. Finally, three-component textures
+/// are treated as RGBA buffers with red set to component zero, green set to
+/// component one, blue set to component two, and alpha set to 1.
+///
+/// To determine the required size of `pixels`, use [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// to determine the dimensions of the internal texture image, then scale the
+/// required number of pixels by the storage required for each pixel, based
+/// on `format` and `type`. Be sure to take the pixel storage parameters into
+/// account, especially [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT).
+///
+/// If [**glGetTextureImage**](crate::context::Context::oxidegl_get_texture_image)
+/// is used against a cube map texture object, the texture is treated as a
+/// three-dimensional image of a depth of 6, where the cube map faces are ordered
+/// as image layers, in an order presented in the table below:
+///
+/// | Layer number        | Cube Map Face        |
+/// |---------------------|----------------------|
+/// | 0                   | `GL_TEXTURE_CUBE_MAP_POSITIVE_X` |
+/// | 1                   | `GL_TEXTURE_CUBE_MAP_NEGATIVE_X` |
+/// | 2                   | `GL_TEXTURE_CUBE_MAP_POSITIVE_Y` |
+/// | 3                   | `GL_TEXTURE_CUBE_MAP_NEGATIVE_Y` |
+/// | 4                   | `GL_TEXTURE_CUBE_MAP_POSITIVE_Z` |
+/// | 5                   | `GL_TEXTURE_CUBE_MAP_NEGATIVE_Z` |
+///
+/// ### Notes
+/// If an error is generated, no change is made to the contents of `pixels`.
+///
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image) and
+/// [**glGetnTexImage**](crate::context::Context::oxidegl_getn_tex_image) return
+/// the texture image for the active texture unit.
+///
+/// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX) is accepted for `format`
+/// only if the GL version is 4.4 or greater.
+///
+/// ### Associated Gets
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_WIDTH`](crate::enums::GL_TEXTURE_WIDTH)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_HEIGHT`](crate::enums::GL_TEXTURE_HEIGHT)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with arguments [`GL_PACK_ALIGNMENT`](crate::enums::GL_PACK_ALIGNMENT)
+/// and others
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_PACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_PACK_BUFFER_BINDING)
+impl Context {
+    pub(crate) unsafe fn oxidegl_get_tex_image(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        let (name, face) = self.readback_target(target)?;
+        // Safety: caller ensures `pixels` is valid for writes of the specified image (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                level,
+                face,
+                [0; 3],
+                None,
+                Some(GlPixelTypeFormat::new(r#type, format)),
+                None,
+                pixels,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_get_texture_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        buf_size: GLsizei,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(buf_size);
+        let name = self.texture_by_name(texture)?;
+        self.check_readback_texture(name)?;
+        // Safety: caller ensures `pixels` is valid for writes of `buf_size` bytes (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                level,
+                None,
+                [0; 3],
+                None,
+                Some(GlPixelTypeFormat::new(r#type, format)),
+                Some(buf_size as usize),
+                pixels,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_getn_tex_image(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        buf_size: GLsizei,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(buf_size);
+        let (name, face) = self.readback_target(target)?;
+        // Safety: caller ensures `pixels` is valid for writes of `buf_size` bytes (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                level,
+                face,
+                [0; 3],
+                None,
+                Some(GlPixelTypeFormat::new(r#type, format)),
+                Some(buf_size as usize),
+                pixels,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > Specifies the name of the source texture object. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+    /// > [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// > [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+    /// > [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// > or [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE). In specific,
+    /// > buffer and multisample textures are not permitted.
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level $n$ is the $n$th mipmap reduction image.
+    ///
+    /// `xoffset`
+    ///
+    /// > Specifies a texel offset in the x direction within the texture array.
+    ///
+    /// `yoffset`
+    ///
+    /// > Specifies a texel offset in the y direction within the texture array.
+    ///
+    /// `zoffset`
+    ///
+    /// > Specifies a texel offset in the z direction within the texture array.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture subimage. Must be a multiple of the
+    /// > compressed block's width, unless the `offset` is zero and the size equals
+    /// > the texture image size.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture subimage. Must be a multiple of the
+    /// > compressed block's height, unless the `offset` is zero and the size equals
+    /// > the texture image size.
+    ///
+    /// `depth`
+    ///
+    /// > Specifies the depth of the texture subimage. Must be a multiple of the
+    /// > compressed block's depth, unless the `offset` is zero and the size equals
+    /// > the texture image size.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the size of the buffer to receive the retrieved pixel data.
+    ///
+    /// `pixels`
+    ///
+    /// > Returns the texture subimage. Should be a pointer to an array of the type
+    /// > specified by type.
+    ///
+    /// ### Description
+    /// [**glGetCompressedTextureSubImage**](crate::context::Context::oxidegl_get_compressed_texture_sub_image)
+    /// can be used to obtain a sub-region of a compressed texture image instead
+    /// of the whole image, as long as the compressed data are arranged into fixed-size
+    /// blocks of texels. `texture` is the name of the texture object, and must
+    /// not be a buffer or multisample texture. The effective `target` is the value
+    /// of [`GL_TEXTURE_TARGET`](crate::enums::GL_TEXTURE_TARGET) for texture.
+    /// `level` and `pixels` have the same meaning as the corresponding arguments
+    /// of [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d).
+    /// `bufSize` indicates the size of the buffer to receive the retrieved pixel
+    /// data.
+    ///
+    /// For cube map textures, the behavior is as though [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+    /// were called once for each requested face (selected by `zoffset` and `depth`,
+    /// as described below) with target corresponding to the requested texture
+    /// cube map face as indicated by the table presented below. `pixels` is offset
+    /// appropriately for each successive image.
+    ///
+    /// | Layer number        | Cube Map Face        |
+    /// |---------------------|----------------------|
+    /// | 0                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X) |
+    /// | 1                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X) |
+    /// | 2                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y) |
+    /// | 3                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y) |
+    /// | 4                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z) |
+    /// | 5                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z) |
+    ///
+    /// `xoffset`, `yoffset` and `zoffset` indicate the position of the subregion
+    /// to return. `width`, `height` and `depth` indicate the size of the region
+    /// to return. These arguments have the same meaning as for [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d),
+    /// though there are extra restrictions, described in the errors section below.
+    ///
+    /// The mapping between the `xoffset`, `yoffset`, `zoffset`, `width`, `height`
+    /// and `depth` parameters and the faces, layers, and layer-faces for cube
+    /// map, array, and cube map array textures is the same as for glGetTextureSubImage.
+    ///
+    /// The `xoffset`, `yoffset`, `zoffset` offsets and `width`, `height` and `depth`
+    /// sizes must be multiples of the values of [`GL_PACK_COMPRESSED_BLOCK_WIDTH`](crate::enums::GL_PACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_PACK_COMPRESSED_BLOCK_HEIGHT`](crate::enums::GL_PACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_PACK_COMPRESSED_BLOCK_DEPTH`](crate::enums::GL_PACK_COMPRESSED_BLOCK_DEPTH)
+    /// respectively, unless `offset` is zero and the corresponding `size` is the
+    /// same as the texture size in that dimension.
+    ///
+    /// Pixel storage modes are treated as for [**glGetCompressedTexSubImage**](crate::context::Context::oxidegl_get_compressed_tex_sub_image).
+    /// The texel at( `xoffset`, `yoffset`, `zoffset`) will be stored at the location
+    /// indicated by `pixels` and the current pixel packing parameters.
+    pub(crate) unsafe fn oxidegl_get_compressed_texture_sub_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        buf_size: GLsizei,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        sizei!(buf_size);
+        let name = self.texture_by_name(texture)?;
+        self.check_readback_texture(name)?;
+        // Safety: caller ensures `pixels` is valid for writes of `buf_size` bytes (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, yoffset, zoffset],
+                Some(TextureExtent::new(width, height, depth)),
+                None,
+                Some(buf_size as usize),
+                pixels,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > Specifies the name of the source texture object. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+    /// > [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// > [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+    /// > [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// > or [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE). In specific,
+    /// > buffer and multisample textures are not permitted.
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level $n$ is the $n$th mipmap reduction image.
+    ///
+    /// `xoffset`
+    ///
+    /// > Specifies a texel offset in the x direction within the texture array.
+    ///
+    /// `yoffset`
+    ///
+    /// > Specifies a texel offset in the y direction within the texture array.
+    ///
+    /// `zoffset`
+    ///
+    /// > Specifies a texel offset in the z direction within the texture array.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture subimage.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture subimage.
+    ///
+    /// `depth`
+    ///
+    /// > Specifies the depth of the texture subimage.
+    ///
+    /// `format`
+    ///
+    /// > Specifies the format of the pixel data. The following symbolic values are
+    /// > accepted: [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG),
+    /// > [`GL_RGB`](crate::enums::GL_RGB), [`GL_BGR`](crate::enums::GL_BGR), [`GL_RGBA`](crate::enums::GL_RGBA),
+    /// > [`GL_BGRA`](crate::enums::GL_BGRA), [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT)
+    /// > and [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX).
+    ///
+    /// `type`
+    ///
+    /// > Specifies the data type of the pixel data. The following symbolic values
+    /// > are accepted: [`GL_UNSIGNED_BYTE`](crate::enums::GL_UNSIGNED_BYTE), [`GL_BYTE`](crate::enums::GL_BYTE),
+    /// > [`GL_UNSIGNED_SHORT`](crate::enums::GL_UNSIGNED_SHORT), [`GL_SHORT`](crate::enums::GL_SHORT),
+    /// > [`GL_UNSIGNED_INT`](crate::enums::GL_UNSIGNED_INT), [`GL_INT`](crate::enums::GL_INT),
+    /// > [`GL_FLOAT`](crate::enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::enums::GL_UNSIGNED_BYTE_3_3_2),
+    /// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4),
+    /// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+    /// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::enums::GL_UNSIGNED_SHORT_5_5_5_1),
+    /// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+    /// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+    /// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::enums::GL_UNSIGNED_INT_10_10_10_2),
+    /// > and [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::enums::GL_UNSIGNED_INT_2_10_10_10_REV).
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the size of the buffer to receive the retrieved pixel data.
+    ///
+    /// `pixels`
+    ///
+    /// > Returns the texture subimage. Should be a pointer to an array of the type
+    /// > specified by `type`.
+    ///
+    /// ### Description
+    /// [**glGetTextureSubImage**](crate::context::Context::oxidegl_get_texture_sub_image)
+    /// returns a texture subimage into pixels.
+    ///
+    /// `texture` is the name of the source texture object and must not be a buffer
+    /// or multisample texture. The effective `target` parameter is the value of
+    /// [`GL_TEXTURE_TARGET`](crate::enums::GL_TEXTURE_TARGET) for texture. `Level`,
+    /// `format`, `type` and `pixels` have the same meaning as for [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image).
+    /// `bufSize` is the size of the buffer to receive the retrieved pixel data.
+    ///
+    /// For cube map textures, the behavior is as though [**`GetTextureImage`**](crate::context::Context::oxide_get_texture_image)
+    /// were called, but only texels from the requested cube map faces (selected
+    /// by `zoffset` and `depth`, as described below) were returned.
+    ///
+    /// `xoffset`, `yoffset` and `zoffset` values indicate the position of the
+    /// subregion to return. `width`, `height` and `depth` indicate the size of
+    /// the region to return. These parameters have the same meaning as for [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d),
+    /// though for one- and two-dimensional textures there are extra restrictions,
+    /// described in the errors section below.
+    ///
+    /// For one-dimensional array textures, `yoffset` is interpreted as the first
+    /// layer to access and `height` is the number of layers to access.
+    ///
+    /// For two-dimensional array textures, `zoffset` is interpreted as the first
+    /// layer to access and `depth` is the number of layers to access.
+    ///
+    /// Cube map textures are treated as an array of six slices in the z-dimension,
+    /// where the value of `zoffset` is interpreted as specifying the cube map
+    /// face for the corresponding layer (as presented in the table below) and
+    /// `depth` is the number of faces to access:
+    ///
+    /// | Layer number        | Cube Map Face        |
+    /// |---------------------|----------------------|
+    /// | 0                   | `GL_TEXTURE_CUBE_MAP_POSITIVE_X` |
+    /// | 1                   | `GL_TEXTURE_CUBE_MAP_NEGATIVE_X` |
+    /// | 2                   | `GL_TEXTURE_CUBE_MAP_POSITIVE_Y` |
+    /// | 3                   | `GL_TEXTURE_CUBE_MAP_NEGATIVE_Y` |
+    /// | 4                   | `GL_TEXTURE_CUBE_MAP_POSITIVE_Z` |
+    /// | 5                   | `GL_TEXTURE_CUBE_MAP_NEGATIVE_Z` |
+    ///
+    ///
+    /// For cube map array textures, `zoffset` is the first layer-face to access,
+    /// and `depth` is the number of layer-faces to access. A layer-face described
+    /// by $k$ is translated into an array layer and face according to $$ layer
+    ///= \left\lfloor{ layer \over 6} \right\rfloor$$ and $$ face= k \bmod 6. $$
+    ///
+    /// Component groups from the specified sub-region are packed and placed into
+    /// memory as described for [**glGetTextureImage**](crate::context::Context::oxidegl_get_texture_image),
+    /// starting with the texel at( `xoffset`, `yoffset`, `zoffset` ).
+    pub(crate) unsafe fn oxidegl_get_texture_sub_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        buf_size: GLsizei,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        sizei!(buf_size);
+        let name = self.texture_by_name(texture)?;
+        self.check_readback_texture(name)?;
+        // Safety: caller ensures `pixels` is valid for writes of `buf_size` bytes (or is an offset into the pixel pack buffer)
+        unsafe {
+            self.get_texture_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, yoffset, zoffset],
+                Some(TextureExtent::new(width, height, depth)),
+                Some(GlPixelTypeFormat::new(r#type, format)),
+                Some(buf_size as usize),
+                pixels,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target, to which the texture is bound, for [**glCompressedTexSubImage1D**](crate::context::Context::oxidegl_compressed_tex_sub_image1_d)
+/// > function. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glCompressedTextureSubImage1D**](crate::context::Context::oxidegl_compressed_texture_sub_image1_d)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `format`
+///
+/// > Specifies the format of the compressed image data stored at address `data`.
+///
+/// `imageSize`
+///
+/// > Specifies the number of unsigned bytes of image data starting at the address
+/// > specified by `data`.
+///
+/// `data`
+///
+/// > Specifies a pointer to the compressed image data in memory.
+///
+/// ### Description
+/// Texturing allows elements of an image array to be read by shaders.
+///
+/// [**glCompressedTexSubImage1D**](crate::context::Context::oxidegl_compressed_tex_sub_image1_d)
+/// and [**glCompressedTextureSubImage1D**](crate::context::Context::oxidegl_compressed_texture_sub_image1_d)
+/// redefine a contiguous subregion of an existing one-dimensional texture
+/// image. The texels referenced by `data` replace the portion of the existing
+/// texture array with x indices `xoffset` and `[inlineq]`
+///
+/// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::enums::GL_RGTC))
+/// or an extension-specified compressed-texture format. The `format` of the
+/// compressed texture image is selected by the GL implementation that compressed
+/// it (see [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
+/// ), and should be queried at the time the texture was compressed with [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is specified, `data` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// ### Associated Gets
+/// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+/// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+impl Context {
+    pub(crate) unsafe fn oxidegl_compressed_tex_sub_image1_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        width: GLsizei,
+        format: InternalFormat,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(image_size);
+        sizei!(width);
+        let (name, face) = self.sub_image_target(target, StorageDims::One)?;
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [xoffset, 0, 0],
+                TextureExtent::new(width, 1, 1),
+                format,
+                image_size,
+                data,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_compressed_texture_sub_image1_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        width: GLsizei,
+        format: InternalFormat,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(image_size);
+        sizei!(width);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::One)?;
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, 0, 0],
+                TextureExtent::new(width, 1, 1),
+                format,
+                image_size,
+                data,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glCompressedTexSubImage2D**](crate::context::Context::oxidegl_compressed_tex_sub_image2_d)
+/// > function. Must be [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// > or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glCompressedTextureSubImage2D**](crate::context::Context::oxidegl_compressed_texture_sub_image2_d)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `yoffset`
+///
+/// > Specifies a texel offset in the y direction within the texture array.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `height`
+///
+/// > Specifies the height of the texture subimage.
+///
+/// `format`
+///
+/// > Specifies the format of the compressed image data stored at address `data`.
+///
+/// `imageSize`
+///
+/// > Specifies the number of unsigned bytes of image data starting at the address
+/// > specified by `data`.
+///
+/// `data`
+///
+/// > Specifies a pointer to the compressed image data in memory.
+///
+/// ### Description
+/// Texturing allows elements of an image array to be read by shaders.
+///
+/// [**glCompressedTexSubImage2D**](crate::context::Context::oxidegl_compressed_tex_sub_image2_d)
+/// and [**glCompressedTextureSubImage2D**](crate::context::Context::oxidegl_compressed_texture_sub_image2_d)
+/// redefine a contiguous subregion of an existing two-dimensional texture
+/// image. The texels referenced by `data` replace the portion of the existing
+/// texture array with x indices `xoffset` and `[inlineq]` `yoffset` and `[inlineq]`
+///
+/// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::enums::GL_RGTC))
+/// or an extension-specified compressed-texture format. The `format` of the
+/// compressed texture image is selected by the GL implementation that compressed
+/// it (see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
+/// and should be queried at the time the texture was compressed with [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is specified, `data` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// ### Associated Gets
+/// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+/// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+impl Context {
+    pub(crate) unsafe fn oxidegl_compressed_tex_sub_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: InternalFormat,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(image_size);
+        sizei!(width);
+        sizei!(height);
+        let (name, face) = self.sub_image_target(target, StorageDims::Two)?;
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [xoffset, yoffset, 0],
+                TextureExtent::new(width, height, 1),
+                format,
+                image_size,
+                data,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_compressed_texture_sub_image2_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: InternalFormat,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(image_size);
+        sizei!(width);
+        sizei!(height);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::Two)?;
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, yoffset, 0],
+                TextureExtent::new(width, height, 1),
+                format,
+                image_size,
+                data,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d)
+/// > function. Must be [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+/// > [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), or [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glCompressedTextureSubImage3D**](crate::context::Context::oxidegl_compressed_texture_sub_image3_d)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `yoffset`
+///
+/// > Specifies a texel offset in the y direction within the texture array.
+///
+/// `zoffset`
+///
+/// > Specifies a texel offset in the z direction within the texture array.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `height`
+///
+/// > Specifies the height of the texture subimage.
+///
+/// `depth`
+///
+/// > Specifies the depth of the texture subimage.
+///
+/// `format`
+///
+/// > Specifies the format of the compressed image data stored at address `data`.
+///
+/// `imageSize`
+///
+/// > Specifies the number of unsigned bytes of image data starting at the address
+/// > specified by `data`.
+///
+/// `data`
+///
+/// > Specifies a pointer to the compressed image data in memory.
+///
+/// ### Description
+/// Texturing allows elements of an image array to be read by shaders.
+///
+/// [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d)
+/// and [**glCompressedTextureSubImage3D**](crate::context::Context::oxidegl_compressed_texture_sub_image3_d)
+/// redefine a contiguous subregion of an existing three-dimensional texture
+/// image. The texels referenced by `data` replace the portion of the existing
+/// texture array with x indices `xoffset` and `[inlineq]` `yoffset` and `[inlineq]`
+/// `zoffset` and `[inlineq]`
+///
+/// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::enums::GL_RGTC))
+/// or an extension-specified compressed-texture format. The `format` of the
+/// compressed texture image is selected by the GL implementation that compressed
+/// it (see [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d))
+/// and should be queried at the time the texture was compressed with [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+///
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a texture image is specified, `data` is treated as a byte offset
+/// into the buffer object's data store.
+///
+/// ### Associated Gets
+/// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+/// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+impl Context {
+    pub(crate) unsafe fn oxidegl_compressed_tex_sub_image3_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: InternalFormat,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(image_size);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        let (name, face) = self.sub_image_target(target, StorageDims::Three)?;
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [xoffset, yoffset, zoffset],
+                TextureExtent::new(width, height, depth),
+                format,
+                image_size,
+                data,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_compressed_texture_sub_image3_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: InternalFormat,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(image_size);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::Three)?;
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                None,
+                [xoffset, yoffset, zoffset],
+                TextureExtent::new(width, height, depth),
+                format,
+                image_size,
+                data,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D)
+    /// > or [`GL_PROXY_TEXTURE_1D`](crate::enums::GL_PROXY_TEXTURE_1D).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the format of the compressed image data stored at address `data`.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. All implementations support texture
+    /// > images that are at least 64 texels wide. The height of the 1D texture image
+    /// > is 1.
+    ///
+    /// `border`
+    ///
+    /// > This value must be 0.
+    ///
+    /// `imageSize`
+    ///
+    /// > Specifies the number of unsigned bytes of image data starting at the address
+    /// > specified by `data`.
+    ///
+    /// `data`
+    ///
+    /// > Specifies a pointer to the compressed image data in memory.
+    ///
+    /// ### Description
+    /// Texturing allows elements of an image array to be read by shaders.
+    ///
+    /// [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d)
+    /// loads a previously defined, and retrieved, compressed one-dimensional texture
+    /// image if `target` is [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D) (see
+    /// [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d) ).
+    ///
+    /// If `target` is [`GL_PROXY_TEXTURE_1D`](crate::enums::GL_PROXY_TEXTURE_1D),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
+    ///
+    /// `internalformat` must be an extension-specified compressed-texture format.
+    /// When a texture is loaded with [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
+    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB))
+    /// the GL selects from one of its extensions supporting compressed textures.
+    /// In order to load the compressed texture image using [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d),
+    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+    ///
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
+    ///
+    /// If the compressed data are arranged into fixed-size blocks of texels, the
+    /// pixel storage modes can be used to select a sub-rectangle from a larger
+    /// containing rectangle. These pixel storage modes operate in the same way
+    /// as they do for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
+    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
+    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+    ///
+    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH),
+    /// [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS),
+    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT) and [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES)
+    /// are ignored for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+    /// and [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT), `[inlineq]`
+    /// [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES), `[inlineq]`
+    ///
+    /// When selecting a sub-rectangle from a compressed image, the value of [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+    /// > must be a multiple of `[inlineq]`
+    ///
+    ///
+    /// `imageSize` must be equal to:
+    ///
+    /// `[inlineq]`
+    ///
+    /// ### Associated Gets
+    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_NUM_COMPRESSED_TEXTURE_FORMATS`](crate::enums::GL_NUM_COMPRESSED_TEXTURE_FORMATS)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_COMPRESSED_TEXTURE_FORMATS`](crate::enums::GL_COMPRESSED_TEXTURE_FORMATS)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    ///
+    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+    pub(crate) unsafe fn oxidegl_compressed_tex_image1_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_tex_image_internal(
+                target,
+                StorageDims::One,
+                level,
+                internalformat,
+                TextureExtent::new(width, 1, 1),
+                border,
+                image_size,
+                data,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// > [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+    /// > [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+    /// > or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the format of the compressed image data stored at address `data`.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. All implementations support 2D
+    /// > texture and cube map texture images that are at least 16384 texels wide.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture image. All implementations support
+    /// > 2D texture and cube map texture images that are at least 16384 texels high.
+    ///
+    /// `border`
+    ///
+    /// > This value must be 0.
+    ///
+    /// `imageSize`
+    ///
+    /// > Specifies the number of unsigned bytes of image data starting at the address
+    /// > specified by `data`.
+    ///
+    /// `data`
+    ///
+    /// > Specifies a pointer to the compressed image data in memory.
+    ///
+    /// ### Description
+    /// Texturing allows elements of an image array to be read by shaders.
+    ///
+    /// [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d)
+    /// loads a previously defined, and retrieved, compressed two-dimensional texture
+    /// image if `target` is [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), or
+    /// one of the cube map faces such as [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X).
+    /// (see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+    /// ).
+    ///
+    /// If `target` is [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+    /// `data` is treated as an array of compressed 1D textures.
+    ///
+    /// If `target` is [`GL_PROXY_TEXTURE_2D`](crate::enums::GL_PROXY_TEXTURE_2D),
+    /// [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_1D_ARRAY)
+    /// or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::enums::GL_PROXY_TEXTURE_CUBE_MAP),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
+    ///
+    /// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::enums::GL_RGTC))
+    /// or an extension-specified compressed-texture format. When a texture is
+    /// loaded with [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB)
+    /// ), the GL selects from one of its extensions supporting compressed textures.
+    /// In order to load the compressed texture image using [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d),
+    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+    ///
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
+    ///
+    /// If the compressed data are arranged into fixed-size blocks of texels, the
+    /// pixel storage modes can be used to select a sub-rectangle from a larger
+    /// containing rectangle. These pixel storage modes operate in the same way
+    /// as they do for [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d).
+    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
+    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+    ///
+    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH),
+    /// [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS),
+    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT) and [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES)
+    /// are ignored for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+    /// and [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT), `[inlineq]`
+    /// [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES), `[inlineq]`
+    ///
+    /// When selecting a sub-rectangle from a compressed image: The value of [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+    /// > must be a multiple of `[inlineq]`
+    ///
+    /// > the value of [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+    /// > must be a multiple of `[inlineq]`
+    ///
+    ///
+    /// `imageSize` must be equal to:
+    ///
+    /// `[inlineq]`
+    ///
+    /// ### Notes
+    /// The specific compressed internal formats [`GL_COMPRESSED_RGB8_ETC2`](crate::enums::GL_COMPRESSED_RGB8_ETC2),
+    /// [`GL_COMPRESSED_SRGB8_ETC2`](crate::enums::GL_COMPRESSED_SRGB8_ETC2), [`GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2`](crate::enums::GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2),
+    /// [`GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2`](crate::enums::GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2),
+    /// [`GL_COMPRESSED_RGBA8_ETC2_EAC`](crate::enums::GL_COMPRESSED_RGBA8_ETC2_EAC),
+    /// [`GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC`](crate::enums::GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC),
+    /// [`GL_COMPRESSED_R11_EAC`](crate::enums::GL_COMPRESSED_R11_EAC), [`GL_COMPRESSED_SIGNED_R11_EAC`](crate::enums::GL_COMPRESSED_SIGNED_R11_EAC),
+    /// [`GL_COMPRESSED_RG11_EAC`](crate::enums::GL_COMPRESSED_RG11_EAC), and [`GL_COMPRESSED_SIGNED_RG11_EAC`](crate::enums::GL_COMPRESSED_SIGNED_RG11_EAC)
+    /// are available only if the GL version is 4.3 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    ///
+    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+    pub(crate) unsafe fn oxidegl_compressed_tex_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_tex_image_internal(
+                target,
+                StorageDims::Two,
+                level,
+                internalformat,
+                TextureExtent::new(width, height, 1),
+                border,
+                image_size,
+                data,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+    /// > [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY)
+    /// > or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_2D_ARRAY).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the format of the compressed image data stored at address `data`.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. All implementations support 3D
+    /// > texture images that are at least 16 texels wide.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture image. All implementations support
+    /// > 3D texture images that are at least 16 texels high.
+    ///
+    /// `depth`
+    ///
+    /// > Specifies the depth of the texture image. All implementations support 3D
+    /// > texture images that are at least 16 texels deep.
+    ///
+    /// `border`
+    ///
+    /// > This value must be 0.
+    ///
+    /// `imageSize`
+    ///
+    /// > Specifies the number of unsigned bytes of image data starting at the address
+    /// > specified by `data`.
+    ///
+    /// `data`
+    ///
+    /// > Specifies a pointer to the compressed image data in memory.
+    ///
+    /// ### Description
+    /// Texturing allows elements of an image array to be read by shaders.
+    ///
+    /// [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d)
+    /// loads a previously defined, and retrieved, compressed three-dimensional
+    /// texture image if `target` is [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D)
+    /// (see [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+    /// ).
+    ///
+    /// If `target` is [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+    /// `data` is treated as an array of compressed 2D textures.
+    ///
+    /// If `target` is [`GL_PROXY_TEXTURE_3D`](crate::enums::GL_PROXY_TEXTURE_3D)
+    /// or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::enums::GL_PROXY_TEXTURE_2D_ARRAY),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
+    ///
+    /// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::enums::GL_RGTC))
+    /// or an extension-specified compressed-texture format. When a texture is
+    /// loaded with [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB)
+    /// ), the GL selects from one of its extensions supporting compressed textures.
+    /// In order to load the compressed texture image using [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d),
+    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
+    ///
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
+    ///
+    /// If the compressed data are arranged into fixed-size blocks of texels, the
+    /// pixel storage modes can be used to select a sub-rectangle from a larger
+    /// containing rectangle. These pixel storage modes operate in the same way
+    /// as they do for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
+    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
+    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+    ///
+    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH),
+    /// [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS),
+    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT) and [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES)
+    /// are ignored for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+    /// and [`GL_UNPACK_ROW_LENGTH`](crate::enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::enums::GL_UNPACK_IMAGE_HEIGHT), `[inlineq]`
+    /// [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES), `[inlineq]`
+    ///
+    /// When selecting a sub-rectangle from a compressed image: the value of [`GL_UNPACK_SKIP_PIXELS`](crate::enums::GL_UNPACK_SKIP_PIXELS)
+    /// > must be a multiple of `[inlineq]`
+    ///
+    /// > the value of [`GL_UNPACK_SKIP_ROWS`](crate::enums::GL_UNPACK_SKIP_ROWS)
+    /// > must be a multiple of `[inlineq]`
+    ///
+    /// > the value of [`GL_UNPACK_SKIP_IMAGES`](crate::enums::GL_UNPACK_SKIP_IMAGES)
+    /// > must be a multiple of `[inlineq]`
+    ///
+    ///
+    /// `imageSize` must be equal to:
+    ///
+    /// `[inlineq]`
+    ///
+    /// ### Associated Gets
+    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::enums::GL_TEXTURE_COMPRESSED)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    ///
+    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::enums::GL_TEXTURE_INTERNAL_FORMAT)
+    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+    pub(crate) unsafe fn oxidegl_compressed_tex_image3_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        // Safety: caller ensures `data` is valid for reads of `image_size` bytes (or is an offset into the pixel unpack buffer)
+        unsafe {
+            self.compressed_tex_image_internal(
+                target,
+                StorageDims::Three,
+                level,
+                internalformat,
+                TextureExtent::new(width, height, depth),
+                border,
+                image_size,
+                data,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glGenerateMipmap**](crate::context::Context::oxidegl_generate_mipmap).
+/// > Must be one of [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+/// > [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// > or [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glGenerateTextureMipmap**](crate::context::Context::oxidegl_generate_texture_mipmap).
+///
+/// ### Description
+/// [**glGenerateMipmap**](crate::context::Context::oxidegl_generate_mipmap)
+/// and [**glGenerateTextureMipmap**](crate::context::Context::oxidegl_generate_texture_mipmap)
+/// generates mipmaps for the specified texture object. For [**glGenerateMipmap**](crate::context::Context::oxidegl_generate_mipmap),
+/// the texture object that is bound to `target`. For [**glGenerateTextureMipmap**](crate::context::Context::oxidegl_generate_texture_mipmap),
+/// `texture` is the name of the texture object.
+///
+/// For cube map and cube map array textures, the texture object must be cube
+/// complete or cube array complete respectively.
+///
+/// Mipmap generation replaces texel image levels $level_{base}+ 1$ through
+/// $q$ with images derived from the $level_{base}$ image, regardless of their
+/// previous contents. All other mimap images, including the $level_{base}$
+/// image, are left unchanged by this computation.
+///
+/// The internal formats of the derived mipmap images all match those of the
+/// $level_{base}$ image. The contents of the derived images are computed by
+/// repeated, filtered reduction of the $level_{base}$ image. For one- and
+/// two-dimensional array and cube map array textures, each layer is filtered
+/// independently.
+///
+/// ### Notes
+/// Cube map array textures are accepted only if the GL version is 4.0 or higher.
+impl Context {
+    pub(crate) fn oxidegl_generate_mipmap(&mut self, target: TextureTarget) -> GlFallible {
+        gl_assert!(
+            generates_mipmaps(target),
+            InvalidEnum,
+            "{target:?} is not a valid target for mipmap generation"
+        );
+        let name = self.bound_texture(target)?;
+        self.generate_mipmap_internal(name)
+    }
+    pub(crate) fn oxidegl_generate_texture_mipmap(&mut self, texture: GLuint) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        gl_assert!(
+            generates_mipmaps(target),
+            InvalidOperation,
+            "{name:?} has target {target:?}, which does not support mipmap generation"
+        );
+        self.generate_mipmap_internal(name)
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > Specifies the texture object to be initialized as a view.
+    ///
+    /// `target`
+    ///
+    /// > Specifies the target to be used for the newly initialized texture.
+    ///
+    /// `origtexture`
+    ///
+    /// > Specifies the name of a texture object of which to make a view.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the internal format for the newly created view.
+    ///
+    /// `minlevel`
+    ///
+    /// > Specifies lowest level of detail of the view.
+    ///
+    /// `numlevels`
+    ///
+    /// > Specifies the number of levels of detail to include in the view.
+    ///
+    /// `minlayer`
+    ///
+    /// > Specifies the index of the first layer to include in the view.
+    ///
+    /// `numlayers`
+    ///
+    /// > Specifies the number of layers to include in the view.
+    ///
+    /// ### Description
+    /// [**glTextureView**](crate::context::Context::oxidegl_texture_view) initializes
+    /// a texture object as an alias, or view of another texture object, sharing
+    /// some or all of the parent texture's data store with the initialized texture.
+    /// `texture` specifies a name previously reserved by a successful call to
+    /// [**glGenTextures**](crate::context::Context::oxidegl_gen_textures) but
+    /// that has not yet been bound or given a target. `target` specifies the target
+    /// for the newly initialized texture and must be compatible with the target
+    /// of the parent texture, given in `origtexture` as specified in the following
+    /// table:
+    ///
+    /// | Original Target                           | Compatible New Targets                               |
+    /// |-------------------------------------------|------------------------------------------------------|
+    /// | [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D) | [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY) |
+    /// | [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D) | [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY) |
+    /// | [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D) | [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D)  |
+    /// | [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP) | [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY) |
+    /// | [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE) | [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE) |
+    /// | [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER) | *none*                                  |
+    /// | [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY) | [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY) |
+    /// | [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY) | [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY) |
+    /// | [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY) | [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY) |
+    /// | [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE) | [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE), [`GL_TEXTURE_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_MULTISAMPLE_ARRAY) |
+    /// | [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY) | [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE), [`GL_TEXTURE_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_MULTISAMPLE_ARRAY) |
+    ///
+    /// The value of [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+    /// for `origtexture` must be [`GL_TRUE`](crate::enums::GL_TRUE). After initialization,
+    /// `texture` inherits the data store of the parent texture, `origtexture`
+    /// and is usable as a normal texture object with target `target`. Data in
+    /// the shared store is reinterpreted with the new internal format specified
+    /// by `internalformat`. `internalformat` must be compatible with the internal
+    /// format of the parent texture as specified in the following table:
+    ///
+    /// | Class    | Internal Formats                            |
+    /// |----------|---------------------------------------------|
+    /// | 128-bit  | [`GL_RGBA32F`](crate::enums::GL_RGBA32F), [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI), [`GL_RGBA32I`](crate::enums::GL_RGBA32I) |
+    /// | 96-bit   | [`GL_RGB32F`](crate::enums::GL_RGB32F), [`GL_RGB32UI`](crate::enums::GL_RGB32UI), [`GL_RGB32I`](crate::enums::GL_RGB32I) |
+    /// | 64-bit   | [`GL_RGBA16F`](crate::enums::GL_RGBA16F), [`GL_RG32F`](crate::enums::GL_RG32F), [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI), [`GL_RG32UI`](crate::enums::GL_RG32UI), [`GL_RGBA16I`](crate::enums::GL_RGBA16I), [`GL_RG32I`](crate::enums::GL_RG32I), [`GL_RGBA16`](crate::enums::GL_RGBA16), [`GL_RGBA16_SNORM`](crate::enums::GL_RGBA16_SNORM) |
+    /// | 48-bit   | [`GL_RGB16`](crate::enums::GL_RGB16), [`GL_RGB16_SNORM`](crate::enums::GL_RGB16_SNORM), [`GL_RGB16F`](crate::enums::GL_RGB16F), [`GL_RGB16UI`](crate::enums::GL_RGB16UI), [`GL_RGB16I`](crate::enums::GL_RGB16I) |
+    /// | 32-bit   | [`GL_RG16F`](crate::enums::GL_RG16F), [`GL_R11F_G11F_B10F`](crate::enums::GL_R11F_G11F_B10F), [`GL_R32F`](crate::enums::GL_R32F), [`GL_RGB10_A2UI`](crate::enums::GL_RGB10_A2UI), [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI), [`GL_RG16UI`](crate::enums::GL_RG16UI), [`GL_R32UI`](crate::enums::GL_R32UI), [`GL_RGBA8I`](crate::enums::GL_RGBA8I), [`GL_RG16I`](crate::enums::GL_RG16I), [`GL_R32I`](crate::enums::GL_R32I), [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2), [`GL_RGBA8`](crate::enums::GL_RGBA8), [`GL_RG16`](crate::enums::GL_RG16), [`GL_RGBA8_SNORM`](crate::enums::GL_RGBA8_SNORM), [`GL_RG16_SNORM`](crate::enums::GL_RG16_SNORM), [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8), [`GL_RGB9_E5`](crate::enums::GL_RGB9_E5) |
+    /// | 24-bit   | [`GL_RGB8`](crate::enums::GL_RGB8), [`GL_RGB8_SNORM`](crate::enums::GL_RGB8_SNORM), [`GL_SRGB8`](crate::enums::GL_SRGB8), [`GL_RGB8UI`](crate::enums::GL_RGB8UI), [`GL_RGB8I`](crate::enums::GL_RGB8I) |
+    /// | 16-bit   | [`GL_R16F`](crate::enums::GL_R16F), [`GL_RG8UI`](crate::enums::GL_RG8UI), [`GL_R16UI`](crate::enums::GL_R16UI), [`GL_RG8I`](crate::enums::GL_RG8I), [`GL_R16I`](crate::enums::GL_R16I), [`GL_RG8`](crate::enums::GL_RG8), [`GL_R16`](crate::enums::GL_R16), [`GL_RG8_SNORM`](crate::enums::GL_RG8_SNORM), [`GL_R16_SNORM`](crate::enums::GL_R16_SNORM) |
+    /// | 8-bit    | [`GL_R8UI`](crate::enums::GL_R8UI), [`GL_R8I`](crate::enums::GL_R8I), [`GL_R8`](crate::enums::GL_R8), [`GL_R8_SNORM`](crate::enums::GL_R8_SNORM) |
+    /// | [`GL_RGTC1_RED`](crate::enums::GL_RGTC1_RED) | [`GL_COMPRESSED_RED_RGTC1`](crate::enums::GL_COMPRESSED_RED_RGTC1), [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::enums::GL_COMPRESSED_SIGNED_RED_RGTC1) |
+    /// | [`GL_RGTC2_RG`](crate::enums::GL_RGTC2_RG) | [`GL_COMPRESSED_RG_RGTC2`](crate::enums::GL_COMPRESSED_RG_RGTC2), [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::enums::GL_COMPRESSED_SIGNED_RG_RGTC2) |
+    /// | [`GL_BPTC_UNORM`](crate::enums::GL_BPTC_UNORM) | [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_RGBA_BPTC_UNORM), [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM) |
+    /// | [`GL_BPTC_FLOAT`](crate::enums::GL_BPTC_FLOAT) | [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT), [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) |
+    ///
+    /// If the original texture is an array or has multiple mipmap levels, the
+    /// parameters `minlayer`, `numlayers`, `minlevel`, and `numlevels` control
+    /// which of those slices and levels are considered part of the texture. The
+    /// `minlevel` and `minlayer` parameters are relative to the view of the original
+    /// texture. If `numlayers` or `numlevels` extend beyond the original texture,
+    /// they are clamped to the max extent of the original texture.
+    ///
+    /// If the new texture's target is [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+    /// the clamped `numlayers` must be equal to 6. If the new texture's target
+    /// is [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// then `numlayers` counts layer-faces rather than layers, and the clamped
+    /// `numlayers` must be a multiple of 6. If the new texture's target is [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP)
+    /// or [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// the width and height of the original texture's levels must be equal.
+    ///
+    /// When the original texture's target is [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+    /// the layer parameters are interpreted in the same order as if it were a
+    /// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// with 6 layer-faces.
+    ///
+    /// If `target` is [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE),
+    /// or [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE),
+    /// `numlayers` must equal 1.
+    ///
+    /// The dimensions of the original texture must be less than or equal to the
+    /// maximum supported dimensions of the new target. For example, if the original
+    /// texture has a [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY)
+    /// target and its width is greater than [`GL_MAX_CUBE_MAP_TEXTURE_SIZE`](crate::enums::GL_MAX_CUBE_MAP_TEXTURE_SIZE),
+    /// an error will be generated if [**glTextureView**](crate::context::Context::oxidegl_texture_view)
+    /// is called to create a [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP)
+    /// view.
+    ///
+    /// Texture commands that take a `level` or `layer` parameter, such as [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d),
+    /// interpret that parameter to be relative to the view of the texture. i.e.
+    /// the mipmap level of the data store that would be updated via [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d)
+    /// would be the sum of `level` and the value of [`GL_TEXTURE_VIEW_MIN_LEVEL`](crate::enums::GL_TEXTURE_VIEW_MIN_LEVEL).
+    ///
+    /// ### Associated Gets
+    /// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) with
+    /// arguments [`GL_TEXTURE_VIEW_MIN_LEVEL`](crate::enums::GL_TEXTURE_VIEW_MIN_LEVEL),
+    /// [`GL_TEXTURE_VIEW_NUM_LEVELS`](crate::enums::GL_TEXTURE_VIEW_NUM_LEVELS),
+    /// [`GL_TEXTURE_VIEW_MIN_LAYER`](crate::enums::GL_TEXTURE_VIEW_MIN_LAYER),
+    /// [`GL_TEXTURE_VIEW_NUM_LAYERS`](crate::enums::GL_TEXTURE_VIEW_NUM_LAYERS),
+    /// or [`GL_TEXTURE_IMMUTABLE_LEVELS`](crate::enums::GL_TEXTURE_IMMUTABLE_LEVELS).
+    pub(crate) fn oxidegl_texture_view(
+        &mut self,
+        texture: GLuint,
+        target: TextureTarget,
+        origtexture: GLuint,
+        internalformat: SizedInternalFormat,
+        minlevel: GLuint,
+        numlevels: GLuint,
+        minlayer: GLuint,
+        numlayers: GLuint,
+    ) -> GlFallible {
+        let Ok(name) = ObjectName::try_from_raw(texture) else {
+            gl_err!(ty: Error, "texture views may not be created with the default texture object");
+            return Err(GlError::InvalidValue.e());
+        };
+        gl_assert!(
+            self.gl_state.texture_list.is_reserved(name),
+            InvalidOperation,
+            "{name:?} is not a texture name returned by glGenTextures"
+        );
+        gl_assert!(
+            !self.gl_state.texture_list.is(name),
+            InvalidOperation,
+            "{name:?} has already been bound or given a target and can't be initialized as a texture view"
+        );
+        let origin_name = ObjectName::try_from_raw(origtexture)
+            .ok()
+            .filter(|&n| self.gl_state.texture_list.is(n))
+            .ok_or_else(|| {
+                gl_err!(ty: Error, "{origtexture} does not name an existing texture object");
+                GlError::InvalidValue.e()
+            })?;
+        let origin = self.gl_state.texture_list.get(origin_name);
+        gl_assert!(
+            origin.immutable_format,
+            InvalidOperation,
+            "texture views may only be created from immutable-format textures, which {origin_name:?} is not"
+        );
+        let storage = origin
+            .realized
+            .as_ref()
+            .expect("immutable-format textures should have storage");
+        gl_assert!(
+            origin.target.accepts_view(target),
+            InvalidOperation,
+            "a {target:?} texture view can't be created from {origin_name:?}, which has target {:?}",
+            origin.target
+        );
+        let format: InternalFormat = internalformat.try_into_enum()?;
+        let origin_format = storage.format;
+        let compatible = match (format.view_class(), origin_format.view_class()) {
+            (Some(class), Some(origin_class)) => class == origin_class,
+            _ => format == origin_format,
+        };
+        gl_assert!(
+            compatible,
+            InvalidOperation,
+            "internal format {format:?} is not view-compatible with {origin_name:?}'s internal format {origin_format:?}"
+        );
+        let origin_layers = storage.extent.layers(origin.target);
+        gl_assert!(
+            minlevel < storage.levels && minlayer < origin_layers,
+            InvalidValue,
+            "view minimum level {minlevel} or layer {minlayer} exceeds the {} levels or {origin_layers} layers of {origin_name:?}",
+            storage.levels
+        );
+        let numlevels = numlevels.min(storage.levels - minlevel);
+        let numlayers = numlayers.min(origin_layers - minlayer);
+        gl_assert!(
+            numlevels > 0 && numlayers > 0,
+            InvalidValue,
+            "texture views must include at least one level and layer"
+        );
+        let layers_valid = match target {
+            TextureTarget::TextureCubeMap => numlayers == 6,
+            TextureTarget::TextureCubeMapArray => numlayers.is_multiple_of(6),
+            TextureTarget::Texture1DArray
+            | TextureTarget::Texture2DArray
+            | TextureTarget::Texture2DMultisampleArray => true,
+            _ => numlayers == 1,
+        };
+        gl_assert!(
+            layers_valid,
+            InvalidValue,
+            "{target:?} texture views can't have {numlayers} layers"
+        );
+        let mip = storage.extent.mip(origin.target, minlevel);
+        if matches!(
+            target,
+            TextureTarget::TextureCubeMap | TextureTarget::TextureCubeMapArray
+        ) {
+            gl_assert!(
+                mip.width == mip.height,
+                InvalidOperation,
+                "cube map texture views must have square images, but {origin_name:?} has extent {mip:?}"
+            );
+        }
+        let extent = match target {
+            TextureTarget::Texture1D => TextureExtent::new(mip.width, 1, 1),
+            TextureTarget::Texture1DArray => TextureExtent::new(mip.width, numlayers, 1),
+            TextureTarget::Texture2DArray
+            | TextureTarget::TextureCubeMapArray
+            | TextureTarget::Texture2DMultisampleArray => {
+                TextureExtent::new(mip.width, mip.height, numlayers)
+            }
+            TextureTarget::Texture3D => mip,
+            _ => TextureExtent::new(mip.width, mip.height, 1),
+        };
+        let range = TextureViewRange {
+            min_level: origin.view.map_or(0, |v| v.min_level) + minlevel,
+            num_levels: numlevels,
+            min_layer: origin.view.map_or(0, |v| v.min_layer) + minlayer,
+            num_layers: numlayers,
+        };
+        let view = storage.new_view(
+            target,
+            format,
+            extent,
+            minlevel..minlevel + numlevels,
+            minlayer..minlayer + numlayers,
+        );
+        self.gl_state
+            .texture_list
+            .ensure_init(name, |name| Texture::new_named(name, target))?;
+        self.gl_state
+            .texture_list
+            .get_mut(name)
+            .make_view(view, range);
+        gl_debug!("initialized {name:?} as a view of {range:?} of {origin_name:?}");
+        Ok(())
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glCopyTexSubImage1D**](crate::context::Context::oxidegl_copy_tex_sub_image1_d)
+/// > function. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glCopyTextureSubImage1D**](crate::context::Context::oxidegl_copy_texture_sub_image1_d)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies the texel offset within the texture array.
+///
+/// `x`
+///
+/// `y`
+///
+/// > Specify the window coordinates of the left corner of the row of pixels
+/// > to be copied.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// ### Description
+/// [**glCopyTexSubImage1D**](crate::context::Context::oxidegl_copy_tex_sub_image1_d)
+/// and [**glCopyTextureSubImage1D**](crate::context::Context::oxidegl_copy_texture_sub_image1_d)
+/// replace a portion of a one-dimensional texture image with pixels from the
+/// current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER) (rather than from
+/// main memory, as is the case for [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d)
+/// ). For [**glCopyTexSubImage1D**](crate::context::Context::oxidegl_copy_tex_sub_image1_d),
+/// the texture object that is bound to `target` will be used for the process.
+/// For [**glCopyTextureSubImage1D**](crate::context::Context::oxidegl_copy_texture_sub_image1_d),
+/// `texture` tells which texture object should be used for the purpose of
+/// the call.
+///
+/// The screen-aligned pixel row with left corner at( `x` ,\ `y` ), and with
+/// length `width` replaces the portion of the texture array with x indices
+/// `xoffset` through `[inlineq]`
+///
+/// The pixels in the row are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+/// had been called, but the process stops just before final conversion. At
+/// this point, all pixel component values are clamped to the range `[inlineq]`
+///
+/// It is not an error to specify a subtexture with zero width, but such a
+/// specification has no effect. If any of the pixels within the specified
+/// row of the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER) are
+/// outside the read window associated with the current rendering context,
+/// then the values obtained for those pixels are undefined.
+///
+/// No change is made to the *internalformat* or *width* parameters of the
+/// specified texture array or to texel values outside the specified subregion.
+///
+/// ### Notes
+/// The [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) mode
+/// affects texture images.
+///
+/// ### Associated Gets
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+impl Context {
+    pub(crate) fn oxidegl_copy_tex_sub_image1_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        let (name, face) = self.sub_image_target(target, StorageDims::One)?;
+        self.copy_tex_sub_image_internal(
+            name,
+            level,
+            face,
+            [xoffset, 0, 0],
+            [x, y],
+            TextureExtent::new(width, 1, 1),
+        )
+    }
+    pub(crate) fn oxidegl_copy_texture_sub_image1_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::One)?;
+        self.copy_tex_sub_image_internal(
+            name,
+            level,
+            None,
+            [xoffset, 0, 0],
+            [x, y],
+            TextureExtent::new(width, 1, 1),
+        )
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glCopyTexSubImage2D**](crate::context::Context::oxidegl_copy_tex_sub_image2_d)
+/// > function. Must be [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+/// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+/// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+/// > or [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glCopyTextureSubImage2D**](crate::context::Context::oxidegl_copy_texture_sub_image2_d)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `yoffset`
+///
+/// > Specifies a texel offset in the y direction within the texture array.
+///
+/// `x`
+///
+/// `y`
+///
+/// > Specify the window coordinates of the lower left corner of the rectangular
+/// > region of pixels to be copied.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `height`
+///
+/// > Specifies the height of the texture subimage.
+///
+/// ### Description
+/// [**glCopyTexSubImage2D**](crate::context::Context::oxidegl_copy_tex_sub_image2_d)
+/// and [**glCopyTextureSubImage2D**](crate::context::Context::oxidegl_copy_texture_sub_image2_d)
+/// replace a rectangular portion of a two-dimensional texture image, cube-map
+/// texture image, rectangular image, or a linear portion of a number of slices
+/// of a one-dimensional array texture with pixels from the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+/// (rather than from main memory, as is the case for [**glTexSubImage2D**](crate::context::Context::oxidegl_tex_sub_image2_d)
+/// ).
+///
+/// The screen-aligned pixel rectangle with lower left corner at `[inlineq]`
+/// `width` and height `height` replaces the portion of the texture array with
+/// x indices `xoffset` through `[inlineq]` `yoffset` through `[inlineq]` `level`.
+///
+/// The pixels in the rectangle are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+/// had been called, but the process stops just before final conversion. At
+/// this point, all pixel component values are clamped to the range $\[0,1\]$
+/// and then converted to the texture's internal format for storage in the
+/// texel array.
+///
+/// The destination rectangle in the texture array may not include any texels
+/// outside the texture array as it was originally specified. It is not an
+/// error to specify a subtexture with zero width or height, but such a specification
+/// has no effect.
+///
+/// When `target` is [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY)
+/// then the y coordinate and height are treated as the start slice and number
+/// of slices to modify, respectively.
+///
+/// If any of the pixels within the specified rectangle of the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+/// are outside the read window associated with the current rendering context,
+/// then the values obtained for those pixels are undefined.
+///
+/// No change is made to the *internalformat*, *width* or *height* parameters
+/// of the specified texture array, or to texel values outside the specified
+/// subregion.
+///
+/// ### Notes
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) modes
+/// affect texture images.
+///
+/// ### Associated Gets
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+impl Context {
+    pub(crate) fn oxidegl_copy_tex_sub_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        let (name, face) = self.sub_image_target(target, StorageDims::Two)?;
+        self.copy_tex_sub_image_internal(
+            name,
+            level,
+            face,
+            [xoffset, yoffset, 0],
+            [x, y],
+            TextureExtent::new(width, height, 1),
+        )
+    }
+    pub(crate) fn oxidegl_copy_texture_sub_image2_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::Two)?;
+        self.copy_tex_sub_image_internal(
+            name,
+            level,
+            None,
+            [xoffset, yoffset, 0],
+            [x, y],
+            TextureExtent::new(width, height, 1),
+        )
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glCopyTexSubImage3D**](crate::context::Context::oxidegl_copy_tex_sub_image3_d)
+/// > function. Must be [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY)
+/// > or [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glCopyTextureSubImage3D**](crate::context::Context::oxidegl_copy_texture_sub_image3_d)
+/// > function.
+///
+/// `level`
+///
+/// > Specifies the level-of-detail number. Level 0 is the base image level.
+/// > Level *n* is the *n* th mipmap reduction image.
+///
+/// `xoffset`
+///
+/// > Specifies a texel offset in the x direction within the texture array.
+///
+/// `yoffset`
+///
+/// > Specifies a texel offset in the y direction within the texture array.
+///
+/// `zoffset`
+///
+/// > Specifies a texel offset in the z direction within the texture array.
+///
+/// `x`
+///
+/// `y`
+///
+/// > Specify the window coordinates of the lower left corner of the rectangular
+/// > region of pixels to be copied.
+///
+/// `width`
+///
+/// > Specifies the width of the texture subimage.
+///
+/// `height`
+///
+/// > Specifies the height of the texture subimage.
+///
+/// ### Description
+/// [**glCopyTexSubImage3D**](crate::context::Context::oxidegl_copy_tex_sub_image3_d)
+/// and [**glCopyTextureSubImage3D**](crate::context::Context::oxidegl_copy_texture_sub_image3_d)
+/// functions replace a rectangular portion of a three-dimensional or two-dimensional
+/// array texture image with pixels from the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+/// (rather than from main memory, as is the case for [**glTexSubImage3D**](crate::context::Context::oxidegl_tex_sub_image3_d)
+/// ).
+///
+/// The screen-aligned pixel rectangle with lower left corner at( `x`, `y`)
+/// and with width `width` and height `height` replaces the portion of the
+/// texture array with x indices `xoffset` through `[inlineq]` `yoffset` through
+/// `[inlineq]` `zoffset` and at the mipmap level specified by `level`.
+///
+/// The pixels in the rectangle are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+/// had been called, but the process stops just before final conversion. At
+/// this point, all pixel component values are clamped to the range `[inlineq]`
+///
+/// The destination rectangle in the texture array may not include any texels
+/// outside the texture array as it was originally specified. It is not an
+/// error to specify a subtexture with zero width or height, but such a specification
+/// has no effect.
+///
+/// If any of the pixels within the specified rectangle of the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+/// are outside the read window associated with the current rendering context,
+/// then the values obtained for those pixels are undefined.
+///
+/// No change is made to the *internalformat*, *width*, *height*, *depth*, or
+/// *border* parameters of the specified texture array or to texel values outside
+/// the specified subregion.
+///
+/// ### Notes
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store) modes
+/// affect texture images.
+///
+/// ### Associated Gets
+/// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+impl Context {
+    pub(crate) fn oxidegl_copy_tex_sub_image3_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        let (name, face) = self.sub_image_target(target, StorageDims::Three)?;
+        self.copy_tex_sub_image_internal(
+            name,
+            level,
+            face,
+            [xoffset, yoffset, zoffset],
+            [x, y],
+            TextureExtent::new(width, height, 1),
+        )
+    }
+    pub(crate) fn oxidegl_copy_texture_sub_image3_d(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        let name = self.texture_by_name(texture)?;
+        self.check_sub_image_texture(name, StorageDims::Three)?;
+        self.copy_tex_sub_image_internal(
+            name,
+            level,
+            None,
+            [xoffset, yoffset, zoffset],
+            [x, y],
+            TextureExtent::new(width, height, 1),
+        )
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `srcName`
+    ///
+    /// > The name of a texture or renderbuffer object from which to copy.
+    ///
+    /// `srcTarget`
+    ///
+    /// > The target representing the namespace of the source name `srcName`.
+    ///
+    /// `srcLevel`
+    ///
+    /// > The mipmap level to read from the source.
+    ///
+    /// `srcX`
+    ///
+    /// > The X coordinate of the left edge of the souce region to copy.
+    ///
+    /// `srcY`
+    ///
+    /// > The Y coordinate of the top edge of the souce region to copy.
+    ///
+    /// `srcZ`
+    ///
+    /// > The Z coordinate of the near edge of the souce region to copy.
+    ///
+    /// `dstName`
+    ///
+    /// > The name of a texture or renderbuffer object to which to copy.
+    ///
+    /// `dstTarget`
+    ///
+    /// > The target representing the namespace of the destination name `dstName`.
+    ///
+    /// `dstX`
+    ///
+    /// > The X coordinate of the left edge of the destination region.
+    ///
+    /// `dstY`
+    ///
+    /// > The Y coordinate of the top edge of the destination region.
+    ///
+    /// `dstZ`
+    ///
+    /// > The Z coordinate of the near edge of the destination region.
+    ///
+    /// `srcWidth`
+    ///
+    /// > The width of the region to be copied.
+    ///
+    /// `srcHeight`
+    ///
+    /// > The height of the region to be copied.
+    ///
+    /// `srcDepth`
+    ///
+    /// > The depth of the region to be copied.
+    ///
+    /// ### Description
+    /// [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
+    /// may be used to copy data from one image (i.e. texture or renderbuffer)
+    /// to another. [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
+    /// does not perform general-purpose conversions such as scaling, resizing,
+    /// blending, color-space, or format conversions. It should be considered to
+    /// operate in a manner similar to a CPU memcpy. `CopyImageSubData` can copy
+    /// between images with different internal formats, provided the formats are
+    /// compatible.
+    ///
+    /// [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
+    /// also allows copying between certain types of compressed and uncompressed
+    /// internal formats. This copy does not perform on-the-fly compression or
+    /// decompression. When copying from an uncompressed internal format to a compressed
+    /// internal format, each texel of uncompressed data becomes a single block
+    /// of compressed data. When copying from a compressed internal format to an
+    /// uncompressed internal format, a block of compressed data becomes a single
+    /// texel of uncompressed data. The texel size of the uncompressed format must
+    /// be the same size the block size of the compressed formats. Thus it is permitted
+    /// to copy between a 128-bit uncompressed format and a compressed format which
+    /// uses 8-bit 4x4 blocks, or between a 64-bit uncompressed format and a compressed
+    /// format which uses 4-bit 4x4 blocks.
+    ///
+    /// The source object is identified by `srcName` and `srcTarget` and the destination
+    /// object is identified by `dstName` and `dstTarget`. The interpretation of
+    /// the name depends on the value of the corresponding `target` parameter.
+    /// If `target` is [`GL_RENDERBUFFER`](crate::enums::GL_RENDERBUFFER), the
+    /// name is interpreted as the name of a renderbuffer object. If the target
+    /// parameter is a texture target, the name is interpreted as a texture object.
+    /// All non-proxy texture targets are accepted, with the exception of [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER)
+    /// and the cubemap face selectors.
+    ///
+    /// `srcLevel` and `dstLevel` identify the source and destination level of
+    /// detail. For textures, this must be a valid level of detail in the texture
+    /// object. For renderbuffers, this value must be zero.
+    ///
+    /// `srcX`, `srcY`, and `srcZ` specify the lower left texel coordinates of
+    /// a `srcWidth` -wide by `srcHeight` -high by `srcDepth` -deep rectangular
+    /// subregion of the source texel array. Similarly, `dstX`, `dstY` and `dstZ`
+    /// specify the coordinates of a subregion of the destination texel array.
+    /// The source and destination subregions must be contained entirely within
+    /// the specified level of the corresponding image objects.
+    ///
+    /// The dimensions are always specified in texels, even for compressed texture
+    /// formats. However, it should be noted that if only one of the source and
+    /// destination textures is compressed then the number of texels touched in
+    /// the compressed image will be a factor of the block size larger than in
+    /// the uncompressed image.
+    ///
+    /// Slices of a [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+    /// [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D) and faces of [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP)
+    /// are all compatible provided they share a compatible internal format, and
+    /// multiple slices or faces may be copied between these objects with a single
+    /// call by specifying the starting slice with `srcZ` and `dstZ`, and the number
+    /// of slices to be copied with `srcDepth`. Cubemap textures always have six
+    /// faces which are selected by a zero-based face index.
+    ///
+    /// For the purposes of `CopyImageSubData`, two internal formats are considered
+    /// compatible if any of the following conditions are met: the formats are
+    /// > the same,
+    ///
+    /// > the formats are considered compatible according to the compatibility rules
+    /// > used for texture views as defined in section 3.9.X. In particular, if both
+    /// > internal formats are listed in the same entry of Table 3.X.2, they are
+    /// > considered compatible, or
+    ///
+    /// > one format is compressed and the other is uncompressed and Table 4.X.1
+    /// > lists the two formats in the same row.
+    ///
+    ///
+    /// |* Texel/ Block Size*                             |* Uncompressed Internal Format*                                   |* Compressed Internal Format(s)*                                   |
+    /// |-------------------------------------------------|------------------------------------------------------------------|-------------------------------------------------------------------|
+    /// | 64-bit                                          | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI), [`GL_RGBA32I`](crate::enums::GL_RGBA32I), [`GL_RGBA32F`](crate::enums::GL_RGBA32F) | [`GL_COMPRESSED_RGBA_S3TC_DXT3_EXT`](crate::enums::GL_COMPRESSED_RGBA_S3TC_DXT3_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT), [`GL_COMPRESSED_RGBA_S3TC_DXT5_EXT`](crate::enums::GL_COMPRESSED_RGBA_S3TC_DXT5_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT), [`GL_COMPRESSED_RG_RGTC2`](crate::enums::GL_COMPRESSED_RG_RGTC2), [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::enums::GL_COMPRESSED_SIGNED_RG_RGTC2), [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_RGBA_BPTC_UNORM), [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM), [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT), [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) |
+    /// | 128-bit                                         | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI), [`GL_RGBA16I`](crate::enums::GL_RGBA16I), [`GL_RGBA16F`](crate::enums::GL_RGBA16F), [`GL_RG32F`](crate::enums::GL_RG32F), [`GL_RG32UI`](crate::enums::GL_RG32UI), [`GL_RG32I`](crate::enums::GL_RG32I), [`GL_RGBA16`](crate::enums::GL_RGBA16), [`GL_RGBA16_SNORM`](crate::enums::GL_RGBA16_SNORM) | [`GL_COMPRESSED_RGB_S3TC_DXT1_EXT`](crate::enums::GL_COMPRESSED_RGB_S3TC_DXT1_EXT), [`GL_COMPRESSED_SRGB_S3TC_DXT1_EXT`](crate::enums::GL_COMPRESSED_SRGB_S3TC_DXT1_EXT), [`GL_COMPRESSED_RGBA_S3TC_DXT1_EXT`](crate::enums::GL_COMPRESSED_RGBA_S3TC_DXT1_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT`](crate::enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT), [`GL_COMPRESSED_RED_RGTC1`](crate::enums::GL_COMPRESSED_RED_RGTC1), [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::enums::GL_COMPRESSED_SIGNED_RED_RGTC1) |
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
+    pub(crate) fn oxidegl_copy_image_sub_data(
+        &mut self,
+        src_name: GLuint,
+        src_target: CopyImageSubDataTarget,
+        src_level: GLint,
+        src_x: GLint,
+        src_y: GLint,
+        src_z: GLint,
+        dst_name: GLuint,
+        dst_target: CopyImageSubDataTarget,
+        dst_level: GLint,
+        dst_x: GLint,
+        dst_y: GLint,
+        dst_z: GLint,
+        src_width: GLsizei,
+        src_height: GLsizei,
+        src_depth: GLsizei,
+    ) -> GlFallible {
+        sizei!(src_width);
+        sizei!(src_height);
+        sizei!(src_depth);
+        let extent = TextureExtent::new(src_width, src_height, src_depth);
+        let (src, src_level) = self.copy_image_endpoint(src_name, src_target, src_level)?;
+        let (dst, dst_level) = self.copy_image_endpoint(dst_name, dst_target, dst_level)?;
+        let (src_image, src_faces, src_offset) =
+            self.image_region(src, src_level, None, [src_x, src_y, src_z], extent)?;
+
+        let dst_tex = self.gl_state.texture_list.get(dst);
+        let dst_image = dst_tex
+            .image(dst_level, 0)
+            .expect("copy destination level should have been validated");
+        let (src_format, dst_format) = (src_image.format, dst_image.format);
+        gl_assert!(
+            src_format.is_copy_compatible(dst_format),
+            InvalidOperation,
+            "can't copy between images of incompatible internal formats {src_format:?} and {dst_format:?}"
+        );
+        let samples = |tex: &Texture| tex.realized.as_ref().map_or(1, |r| r.samples);
+        let src_samples = samples(self.gl_state.texture_list.get(src));
+        gl_assert!(
+            src_samples == samples(dst_tex),
+            InvalidOperation,
+            "can't copy between images with different sample counts"
+        );
+        // each texel of an uncompressed image corresponds to a block of a compressed one
+        #[expect(clippy::cast_possible_truncation, reason = "block dimensions are tiny")]
+        let block = COMPRESSED_BLOCK_DIM as u32;
+        let expand = |blocks: u32, offset: GLint, limit: u32| {
+            let texels = blocks * block;
+            // blocks at the edge of a level may only partially lie within it
+            match u32::try_from(offset) {
+                Ok(o) if o + texels > limit && o + texels - limit < block => limit - o,
+                _ => texels,
+            }
+        };
+        let dst_extent = match (
+            src_format.is_block_compressed(),
+            dst_format.is_block_compressed(),
+        ) {
+            (true, false) => TextureExtent::new(
+                src_width.div_ceil(block),
+                src_height.div_ceil(block),
+                src_depth,
+            ),
+            (false, true) => TextureExtent::new(
+                expand(src_width, dst_x, dst_image.extent.width),
+                expand(src_height, dst_y, dst_image.extent.height),
+                src_depth,
+            ),
+            _ => extent,
+        };
+        let (dst_image, dst_faces, dst_offset) =
+            self.image_region(dst, dst_level, None, [dst_x, dst_y, dst_z], dst_extent)?;
+        if src_format.is_block_compressed() {
+            check_block_alignment(src_image, [src_offset[0], src_offset[1]], extent)
+                .map_err(|_| GlError::InvalidValue.e())?;
+        }
+        if dst_format.is_block_compressed() {
+            check_block_alignment(dst_image, [dst_offset[0], dst_offset[1]], dst_extent)
+                .map_err(|_| GlError::InvalidValue.e())?;
+        }
+        if extent.is_empty() {
+            return Ok(());
+        }
+
+        let (Some((src_tex, src_storage)), Some((dst_tex, dst_storage))) = (
+            self.level_storage(src, src_level, src_faces.start),
+            self.level_storage(dst, dst_level, dst_faces.start),
+        ) else {
+            gl_warn!("skipping copy from {src:?} to {dst:?}: a level is not backed by storage");
+            return Ok(());
+        };
+        let src_target = self.gl_state.texture_list.get(src).target;
+        let dst_target = self.gl_state.texture_list.get(dst).target;
+        let (slices, origin, size) = storage_region(src_target, src_faces, src_offset, extent);
+        let region = ReadbackRegion {
+            tex: src_tex,
+            format: src_format,
+            level: src_storage as usize,
+            slices,
+            origin,
+            size,
+            extent,
+            client_offset: [0; 2],
+            flip_y: false,
+        };
+        gl_debug!("copying {region:?} to level {dst_level} of {dst:?}");
+        self.copy_image_region(
+            &region,
+            &dst_tex,
+            dst_storage,
+            dst_target,
+            dst_format,
+            storage_region(dst_target, dst_faces, dst_offset, dst_extent),
+        );
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the internal format of the texture. Must be one of the following
+    /// > symbolic constants: [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED),
+    /// > [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG), [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB),
+    /// > [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA). [`GL_COMPRESSED_SRGB`](crate::enums::GL_COMPRESSED_SRGB),
+    /// > [`GL_COMPRESSED_SRGB_ALPHA`](crate::enums::GL_COMPRESSED_SRGB_ALPHA). [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+    /// > [`GL_DEPTH_COMPONENT16`](crate::enums::GL_DEPTH_COMPONENT16), [`GL_DEPTH_COMPONENT24`](crate::enums::GL_DEPTH_COMPONENT24),
+    /// > [`GL_DEPTH_COMPONENT32`](crate::enums::GL_DEPTH_COMPONENT32), [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8),
+    /// > [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG), [`GL_RGB`](crate::enums::GL_RGB),
+    /// > [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2), [`GL_RGB4`](crate::enums::GL_RGB4),
+    /// > [`GL_RGB5`](crate::enums::GL_RGB5), [`GL_RGB8`](crate::enums::GL_RGB8),
+    /// > [`GL_RGB10`](crate::enums::GL_RGB10), [`GL_RGB12`](crate::enums::GL_RGB12),
+    /// > [`GL_RGB16`](crate::enums::GL_RGB16), [`GL_RGBA`](crate::enums::GL_RGBA),
+    /// > [`GL_RGBA2`](crate::enums::GL_RGBA2), [`GL_RGBA4`](crate::enums::GL_RGBA4),
+    /// > [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1), [`GL_RGBA8`](crate::enums::GL_RGBA8),
+    /// > [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2), [`GL_RGBA12`](crate::enums::GL_RGBA12),
+    /// > [`GL_RGBA16`](crate::enums::GL_RGBA16), [`GL_SRGB`](crate::enums::GL_SRGB),
+    /// > [`GL_SRGB8`](crate::enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::enums::GL_SRGB_ALPHA),
+    /// > or [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8).
+    ///
+    /// `x`
+    ///
+    /// `y`
+    ///
+    /// > Specify the window coordinates of the left corner of the row of pixels
+    /// > to be copied.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. The height of the texture image
+    /// > is 1.
+    ///
+    /// `border`
+    ///
+    /// > Must be 0.
+    ///
+    /// ### Description
+    /// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d)
+    /// defines a one-dimensional texture image with pixels from the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER).
+    ///
+    /// The screen-aligned pixel row with left corner at `[inlineq]` `[inlineq]`
+    /// `level`. `internalformat` specifies the internal format of the texture
+    /// array.
+    ///
+    /// The pixels in the row are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+    /// had been called, but the process stops just before final conversion. At
+    /// this point all pixel component values are clamped to the range `[inlineq]`
+    ///
+    /// Pixel ordering is such that lower `[inlineq]`
+    ///
+    /// If any of the pixels within the specified row of the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+    /// are outside the window associated with the current rendering context, then
+    /// the values obtained for those pixels are undefined.
+    ///
+    /// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d)
+    /// defines a one-dimensional texture image with pixels from the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER).
+    ///
+    /// When `internalformat` is one of the sRGB types, the GL does not automatically
+    /// convert the source pixels to the sRGB color space. In this case, the [**glPixelMap**](crate::context::Context::oxidegl_pixel_map)
+    /// function can be used to accomplish the conversion.
+    ///
+    /// ### Notes
+    /// 1, 2, 3, and 4 are not accepted values for `internalformat`.
+    ///
+    /// An image with 0 width indicates a NULL texture.
+    ///
+    /// [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8) is accepted for
+    /// `internalformat` only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    pub(crate) fn oxidegl_copy_tex_image1_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        border: GLint,
+    ) -> GlFallible {
+        sizei!(width);
+        gl_assert!(
+            StorageDims::One.accepts_image(target, false),
+            InvalidEnum,
+            "{target:?} is not a valid target for glCopyTexImage1D"
+        );
+        self.copy_tex_image_internal(
+            target,
+            level,
+            internalformat,
+            [x, y],
+            TextureExtent::new(width, 1, 1),
+            border,
+        )
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+    /// > or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z).
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
+    ///
+    /// `internalformat`
+    ///
+    /// > Specifies the internal format of the texture. Must be one of the following
+    /// > symbolic constants: [`GL_COMPRESSED_RED`](crate::enums::GL_COMPRESSED_RED),
+    /// > [`GL_COMPRESSED_RG`](crate::enums::GL_COMPRESSED_RG), [`GL_COMPRESSED_RGB`](crate::enums::GL_COMPRESSED_RGB),
+    /// > [`GL_COMPRESSED_RGBA`](crate::enums::GL_COMPRESSED_RGBA). [`GL_COMPRESSED_SRGB`](crate::enums::GL_COMPRESSED_SRGB),
+    /// > [`GL_COMPRESSED_SRGB_ALPHA`](crate::enums::GL_COMPRESSED_SRGB_ALPHA). [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+    /// > [`GL_DEPTH_COMPONENT16`](crate::enums::GL_DEPTH_COMPONENT16), [`GL_DEPTH_COMPONENT24`](crate::enums::GL_DEPTH_COMPONENT24),
+    /// > [`GL_DEPTH_COMPONENT32`](crate::enums::GL_DEPTH_COMPONENT32), [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8),
+    /// > [`GL_RED`](crate::enums::GL_RED), [`GL_RG`](crate::enums::GL_RG), [`GL_RGB`](crate::enums::GL_RGB),
+    /// > [`GL_R3_G3_B2`](crate::enums::GL_R3_G3_B2), [`GL_RGB4`](crate::enums::GL_RGB4),
+    /// > [`GL_RGB5`](crate::enums::GL_RGB5), [`GL_RGB8`](crate::enums::GL_RGB8),
+    /// > [`GL_RGB10`](crate::enums::GL_RGB10), [`GL_RGB12`](crate::enums::GL_RGB12),
+    /// > [`GL_RGB16`](crate::enums::GL_RGB16), [`GL_RGBA`](crate::enums::GL_RGBA),
+    /// > [`GL_RGBA2`](crate::enums::GL_RGBA2), [`GL_RGBA4`](crate::enums::GL_RGBA4),
+    /// > [`GL_RGB5_A1`](crate::enums::GL_RGB5_A1), [`GL_RGBA8`](crate::enums::GL_RGBA8),
+    /// > [`GL_RGB10_A2`](crate::enums::GL_RGB10_A2), [`GL_RGBA12`](crate::enums::GL_RGBA12),
+    /// > [`GL_RGBA16`](crate::enums::GL_RGBA16), [`GL_SRGB`](crate::enums::GL_SRGB),
+    /// > [`GL_SRGB8`](crate::enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::enums::GL_SRGB_ALPHA),
+    /// > or [`GL_SRGB8_ALPHA8`](crate::enums::GL_SRGB8_ALPHA8).
+    ///
+    /// `x`
+    ///
+    /// `y`
+    ///
+    /// > Specify the window coordinates of the lower left corner of the rectangular
+    /// > region of pixels to be copied.
+    ///
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image.
+    ///
+    /// `height`
+    ///
+    /// > Specifies the height of the texture image.
+    ///
+    /// `border`
+    ///
+    /// > Must be 0.
+    ///
+    /// ### Description
+    /// [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
+    /// defines a two-dimensional texture image, or cube-map texture image with
+    /// pixels from the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER).
+    ///
+    /// The screen-aligned pixel rectangle with lower left corner at( `x`, `y`)
+    /// and with a width of `[inlineq]` `[inlineq]` `level`. `internalformat`
+    /// specifies the internal format of the texture array.
+    ///
+    /// The pixels in the rectangle are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+    /// had been called, but the process stops just before final conversion. At
+    /// this point all pixel component values are clamped to the range `[inlineq]`
+    ///
+    /// Pixel ordering is such that lower `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+    ///
+    /// If any of the pixels within the specified rectangle of the current [`GL_READ_BUFFER`](crate::enums::GL_READ_BUFFER)
+    /// are outside the window associated with the current rendering context, then
+    /// the values obtained for those pixels are undefined.
+    ///
+    /// When `internalformat` is one of the sRGB types, the GL does not automatically
+    /// convert the source pixels to the sRGB color space. In this case, the [**glPixelMap**](crate::context::Context::oxidegl_pixel_map)
+    /// function can be used to accomplish the conversion.
+    ///
+    /// ### Notes
+    /// 1, 2, 3, and 4 are not accepted values for `internalformat`.
+    ///
+    /// An image with height or width of 0 indicates a NULL texture.
+    ///
+    /// [`GL_STENCIL_INDEX8`](crate::enums::GL_STENCIL_INDEX8) is accepted for
+    /// `internalformat` only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    pub(crate) fn oxidegl_copy_tex_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+    ) -> GlFallible {
+        sizei!(width);
+        sizei!(height);
+        gl_assert!(
+            StorageDims::Two.accepts_image(target, false),
+            InvalidEnum,
+            "{target:?} is not a valid target for glCopyTexImage2D"
+        );
+        self.copy_tex_image_internal(
+            target,
+            level,
+            internalformat,
+            [x, y],
+            TextureExtent::new(width, height, 1),
+            border,
+        )
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > The name of an existing texture object containing the image to be cleared.
+    ///
+    /// `level`
+    ///
+    /// > The level of `texture` containing the region to be cleared.
+    ///
+    /// `format`
+    ///
+    /// > The format of the data whose address in memory is given by `data`.
+    ///
+    /// `type`
+    ///
+    /// > The type of the data whose address in memory is given by `data`.
+    ///
+    /// `data`
+    ///
+    /// > The address in memory of the data to be used to clear the specified region.
+    ///
+    /// ### Description
+    /// [**glClearTexImage**](crate::context::Context::oxidegl_clear_tex_image)
+    /// fills all an image contained in a texture with an application supplied
+    /// value. `texture` must be the name of an existing texture. Further, `texture`
+    /// may not be the name of a buffer texture, nor may its internal format be
+    /// compressed.
+    ///
+    /// `format` and `type` specify the format and type of the source data and
+    /// are interpreted as they are for [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
+    /// Textures with a base internal format of [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+    /// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX), or [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL)
+    /// require depth component, stencil, or depth-stencil component data respectively.
+    /// Textures with other base internal formats require RGBA formats. Textures
+    /// with integer internal formats require integer data.
+    ///
+    /// `data` is a pointer to an array of between one and four components of texel
+    /// data that will be used as the source for the constant fill value. The elements
+    /// of data are converted by the GL into the internal format of the texture
+    /// image (that was specified when the level was defined by any of the [**glTexImage***](crate::context::Context::oxidegl_tex_image*),
+    /// [**glTexStorage***](crate::context::Context::oxidegl_tex_storage*) or [**glCopyTexImage***](crate::context::Context::oxidegl_copy_tex_image*)
+    /// commands), and then used to fill the specified range of the destination
+    /// texture level. If `data` is [`NULL`](crate::enums::NULL), then the pointer
+    /// is ignored and the sub-range of the texture image is filled with zeros.
+    /// If texture is a multisample texture, all the samples in a texel are cleared
+    /// to the value specified by data.
+    ///
+    /// ### Notes
+    /// [**glClearTexImage**](crate::context::Context::oxidegl_clear_tex_image)
+    /// is available only if the GL version is 4.4 or greater.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetInternalformat**](crate::context::Context::oxidegl_get_internalformat)
+    pub(crate) unsafe fn oxidegl_clear_tex_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        sizei!(level);
+        let tex = self.gl_state.texture_list.get(name);
+        let image = tex.image(level, 0).ok_or_else(|| {
+            gl_err!(ty: Error, "level {level} of {name:?} has not been specified");
+            GlError::InvalidOperation.e()
+        })?;
+        // cube maps are cleared like 6-layer arrays
+        let extent = if tex.target == TextureTarget::TextureCubeMap {
+            TextureExtent {
+                depth: 6,
+                ..image.extent
+            }
+        } else {
+            image.extent
+        };
+        // Safety: forwarded from caller
+        unsafe {
+            self.clear_tex_internal(
+                name,
+                level,
+                [0; 3],
+                extent,
+                GlPixelTypeFormat::new(r#type, format),
+                data,
+            )
+        }
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > The name of an existing texture object containing the image to be cleared.
+    ///
+    /// `level`
+    ///
+    /// > The level of `texture` containing the region to be cleared.
+    ///
+    /// `xoffset`
+    ///
+    /// > The coordinate of the left edge of the region to be cleared.
+    ///
+    /// `yoffset`
+    ///
+    /// > The coordinate of the lower edge of the region to be cleared.
+    ///
+    /// `zoffset`
+    ///
+    /// > The coordinate of the front of the region to be cleared.
+    ///
+    /// `width`
+    ///
+    /// > The width of the region to be cleared.
+    ///
+    /// `height`
+    ///
+    /// > The height of the region to be cleared.
+    ///
+    /// `depth`
+    ///
+    /// > The depth of the region to be cleared.
+    ///
+    /// `format`
+    ///
+    /// > The format of the data whose address in memory is given by `data`.
+    ///
+    /// `type`
+    ///
+    /// > The type of the data whose address in memory is given by `data`.
+    ///
+    /// `data`
+    ///
+    /// > The address in memory of the data to be used to clear the specified region.
+    ///
+    /// ### Description
+    /// [**glClearTexSubImage**](crate::context::Context::oxidegl_clear_tex_sub_image)
+    /// fills all or part of an image contained in a texture with an application
+    /// supplied value. `texture` must be the name of an existing texture. Further,
+    /// `texture` may not be the name of a buffer texture, nor may its internal
+    /// format be compressed.
+    ///
+    /// Arguments `xoffset`, `yoffset`, and `zoffset` specify the lower left texel
+    /// coordinates of a width-wide by height-high by depth-deep rectangular subregion
+    /// of the texel array.
+    ///
+    /// For one-dimensional array textures, `yoffset` is interpreted as the first
+    /// layer to be cleared and `height` is the number of layers to clear. For
+    /// two-dimensional array textures, `zoffset` is interpreted as the first layer
+    /// to be cleared and `depth` is the number of layers to clear. Cube map textures
+    /// are treated as an array of six slices in the z-dimension, where the value
+    /// of `zoffset` is interpreted as specifying the cube map face for the corresponding
+    /// layer and `depth` is the number of faces to clear. For cube map array textures,
+    /// `zoffset` is the first layer-face to clear, and `depth` is the number of
+    /// layer-faces to clear. Each layer-face is translated into an array layer
+    /// and a cube map face as described in the OpenGL Specification.
+    ///
+    /// Negative values of `xoffset`, `yoffset`, and `zoffset` correspond to the
+    /// coordinates of border texels. Taking `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` `[inlineq]` `[inlineq]` `width`, `height`, `depth`, and the
+    /// border width, border height, and border depth of the texel array and taking
+    /// `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `xoffset`, `yoffset`, `zoffset`, `width`, `height`, and `depth` argument
+    /// values, any of the following relationships generates a [`GL_INVALID_OPERATION`](crate::enums::GL_INVALID_OPERATION)
+    /// error:
+    ///
+    /// For texture types that do not have certain dimensions, this command treats
+    /// those dimensions as having a size of 1. For example, to clear a portion
+    /// of a two-dimensional texture, use `zoffset` equal to zero and `depth` equal
+    /// to one.
+    ///
+    /// `format` and `type` specify the format and type of the source data and
+    /// are interpreted as they are for [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
+    /// Textures with a base internal format of [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT),
+    /// [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX), or [`GL_DEPTH_STENCIL`](crate::enums::GL_DEPTH_STENCIL)
+    /// require depth component, stencil, or depth-stencil component data respectively.
+    /// Textures with other base internal formats require RGBA formats. Textures
+    /// with integer internal formats require integer data.
+    ///
+    /// `data` is a pointer to an array of between one and four components of texel
+    /// data that will be used as the source for the constant fill value. The elements
+    /// of data are converted by the GL into the internal format of the texture
+    /// image (that was specified when the level was defined by any of the [**glTexImage***](crate::context::Context::oxidegl_tex_image*),
+    /// [**glTexStorage***](crate::context::Context::oxidegl_tex_storage*) or [**glCopyTexImage***](crate::context::Context::oxidegl_copy_tex_image*)
+    /// commands), and then used to fill the specified range of the destination
+    /// texture level. If `data` is [`NULL`](crate::enums::NULL), then the pointer
+    /// is ignored and the sub-range of the texture image is filled with zeros.
+    /// If texture is a multisample texture, all the samples in a texel are cleared
+    /// to the value specified by data.
+    ///
+    /// ### Notes
+    /// [**glClearTexSubImage**](crate::context::Context::oxidegl_clear_tex_sub_image)
+    /// is available only if the GL version is 4.4 or greater.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetInternalformat**](crate::context::Context::oxidegl_get_internalformat)
+    pub(crate) unsafe fn oxidegl_clear_tex_sub_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        sizei!(level);
+        sizei!(width);
+        sizei!(height);
+        sizei!(depth);
+        // Safety: forwarded from caller
+        unsafe {
+            self.clear_tex_internal(
+                name,
+                level,
+                [xoffset, yoffset, zoffset],
+                TextureExtent::new(width, height, depth),
+                GlPixelTypeFormat::new(r#type, format),
+                data,
+            )
+        }
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glTexBuffer**](crate::context::Context::oxidegl_tex_buffer).
+/// > Must be [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureBuffer**](crate::context::Context::oxidegl_texture_buffer).
+///
+/// `internalformat`
+///
+/// > Specifies the internal format of the data in the store belonging to `buffer`.
+///
+/// `buffer`
+///
+/// > Specifies the name of the buffer object whose storage to attach to the
+/// > active buffer texture.
+///
+/// ### Description
+/// [**glTexBuffer**](crate::context::Context::oxidegl_tex_buffer) and [**glTextureBuffer**](crate::context::Context::oxidegl_texture_buffer)
+/// attaches the data store of a specified buffer object to a specified texture
+/// object, and specify the storage format for the texture image found in the
+/// buffer object. The texture object must be a buffer texture.
+///
+/// If `buffer` is zero, any buffer object attached to the buffer texture is
+/// detached and no new buffer object is attached. If `buffer` is non-zero,
+/// it must be the name of an existing buffer object.
+///
+/// `internalformat` specifies the storage format, and must be one of the following
+/// sized internal formats:
+///
+/// | *Sized Internal Format*                               | *Base Type*       | *Components*        | *Norm*     | 0  | 1  | 2  | 3  |
+/// |-------------------------------------------------------|-------------------|---------------------|------------|----|----|----|----|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | ubyte             | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | ushort            | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | half              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | float             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | byte              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | short             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | int               | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | ubyte             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | ushort            | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | uint              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | ubyte             | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | ushort            | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | half              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | float             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | byte              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | short             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | int               | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | ubyte             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | ushort            | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | uint              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | float             | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | int               | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | uint              | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | uint              | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | short             | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | half              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | float             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | byte              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | short             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | int               | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | ubyte             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | ushort            | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | uint              | 4                   | NO         | R  | G  | B  | A  |
+///
+/// When a buffer object is attached to a buffer texture, the buffer object's
+/// data store is taken as the texture's texel array. The number of texels
+/// in the buffer texture's texel array is given by $$ \left\lfloor{ size \over
+///{ components \times sizeof(base\_type)}} \right\rfloor $$ where $size$ is
+/// the size of the buffer object in basic machine units (the value of [`GL_BUFFER_SIZE`](crate::enums::GL_BUFFER_SIZE)
+/// for `buffer` ), and $components$ and $base\_type$ are the element count
+/// and base data type for elements, as specified in the table above. The number
+/// of texels in the texel array is then clamped to the value of the implementation-dependent
+/// limit [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::enums::GL_MAX_TEXTURE_BUFFER_SIZE).
+/// When a buffer texture is accessed in a shader, the results of a texel
+/// fetch are undefined if the specified texel coordinate is negative, or greater
+/// than or equal to the clamped number of texels in the texel array.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::enums::GL_MAX_TEXTURE_BUFFER_SIZE)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_BUFFER`](crate::enums::GL_TEXTURE_BINDING_BUFFER)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_BUFFER_DATA_STORE_BINDING`](crate::enums::GL_TEXTURE_BUFFER_DATA_STORE_BINDING)
+impl Context {
+    pub(crate) fn oxidegl_tex_buffer(
+        &mut self,
+        target: TextureTarget,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            target == TextureTarget::TextureBuffer,
+            InvalidEnum,
+            "{target:?} is not a valid target for glTexBuffer"
+        );
+        let name = self.bound_texture(target)?;
+        self.tex_buffer_internal(name, internalformat, buffer, None)
+    }
+    pub(crate) fn oxidegl_texture_buffer(
+        &mut self,
+        texture: GLuint,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        self.tex_buffer_internal(name, internalformat, buffer, None)
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture object is bound for [**glTexBufferRange**](crate::context::Context::oxidegl_tex_buffer_range).
+/// > Must be [`GL_TEXTURE_BUFFER`](crate::enums::GL_TEXTURE_BUFFER).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureBufferRange**](crate::context::Context::oxidegl_texture_buffer_range).
+///
+/// `internalformat`
+///
+/// > Specifies the internal format of the data in the store belonging to `buffer`.
+///
+/// `buffer`
+///
+/// > Specifies the name of the buffer object whose storage to attach to the
+/// > active buffer texture.
+///
+/// `offset`
+///
+/// > Specifies the offset of the start of the range of the buffer's data store
+/// > to attach.
+///
+/// `size`
+///
+/// > Specifies the size of the range of the buffer's data store to attach.
+///
+/// ### Description
+/// [**glTexBufferRange**](crate::context::Context::oxidegl_tex_buffer_range)
+/// and [**glTextureBufferRange**](crate::context::Context::oxidegl_texture_buffer_range)
+/// attach a range of the data store of a specified buffer object to a specified
+/// texture object, and specify the storage format for the texture image found
+/// in the buffer object. The texture object must be a buffer texture.
+///
+/// If `buffer` is zero, any buffer object attached to the buffer texture is
+/// detached and no new buffer object is attached. If `buffer` is non-zero,
+/// it must be the name of an existing buffer object.
+///
+/// The start and size of the range are specified by `offset` and `size` respectively,
+/// both measured in basic machine units. `offset` must be greater than or
+/// equal to zero, `size` must be greater than zero, and the sum of `offset`
+/// and `size` must not exceed the value of [`GL_BUFFER_SIZE`](crate::enums::GL_BUFFER_SIZE)
+/// for `buffer`. Furthermore, `offset` must be an integer multiple of the
+/// value of [`GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`](crate::enums::GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT).
+///
+/// `internalformat` specifies the storage format, and must be one of the following
+/// sized internal formats:
+///
+/// | *Sized Internal Format*                               | *Base Type*       | *Components*        | *Norm*     | 0  | 1  | 2  | 3  |
+/// |-------------------------------------------------------|-------------------|---------------------|------------|----|----|----|----|
+/// | [`GL_R8`](crate::enums::GL_R8)                        | ubyte             | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16`](crate::enums::GL_R16)                      | ushort            | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16F`](crate::enums::GL_R16F)                    | half              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32F`](crate::enums::GL_R32F)                    | float             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8I`](crate::enums::GL_R8I)                      | byte              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16I`](crate::enums::GL_R16I)                    | short             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32I`](crate::enums::GL_R32I)                    | int               | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8UI`](crate::enums::GL_R8UI)                    | ubyte             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16UI`](crate::enums::GL_R16UI)                  | ushort            | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32UI`](crate::enums::GL_R32UI)                  | uint              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_RG8`](crate::enums::GL_RG8)                      | ubyte             | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16`](crate::enums::GL_RG16)                    | ushort            | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16F`](crate::enums::GL_RG16F)                  | half              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32F`](crate::enums::GL_RG32F)                  | float             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8I`](crate::enums::GL_RG8I)                    | byte              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16I`](crate::enums::GL_RG16I)                  | short             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32I`](crate::enums::GL_RG32I)                  | int               | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8UI`](crate::enums::GL_RG8UI)                  | ubyte             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16UI`](crate::enums::GL_RG16UI)                | ushort            | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32UI`](crate::enums::GL_RG32UI)                | uint              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RGB32F`](crate::enums::GL_RGB32F)                | float             | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32I`](crate::enums::GL_RGB32I)                | int               | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32UI`](crate::enums::GL_RGB32UI)              | uint              | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGBA8`](crate::enums::GL_RGBA8)                  | uint              | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16`](crate::enums::GL_RGBA16)                | short             | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16F`](crate::enums::GL_RGBA16F)              | half              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32F`](crate::enums::GL_RGBA32F)              | float             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8I`](crate::enums::GL_RGBA8I)                | byte              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16I`](crate::enums::GL_RGBA16I)              | short             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32I`](crate::enums::GL_RGBA32I)              | int               | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8UI`](crate::enums::GL_RGBA8UI)              | ubyte             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16UI`](crate::enums::GL_RGBA16UI)            | ushort            | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32UI`](crate::enums::GL_RGBA32UI)            | uint              | 4                   | NO         | R  | G  | B  | A  |
+///
+/// When a range of a buffer object is attached to a buffer texture, the specified
+/// range of the buffer object's data store is taken as the texture's texel
+/// array. The number of texels in the buffer texture's texel array is given
+/// by $$ \left\lfloor{ size \over{ components \times sizeof(base\_type)}} \right\rfloor
+/// $$ where $components$ and $base\_type$ are the element count and base data
+/// type for elements, as specified in the table above. The number of texels
+/// in the texel array is then clamped to the value of the implementation-dependent
+/// limit [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::enums::GL_MAX_TEXTURE_BUFFER_SIZE).
+/// When a buffer texture is accessed in a shader, the results of a texel
+/// fetch are undefined if the specified texel coordinate is negative, or greater
+/// than or equal to the clamped number of texels in the texel array.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`](crate::enums::GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with arguments [`GL_TEXTURE_BUFFER_OFFSET`](crate::enums::GL_TEXTURE_BUFFER_OFFSET)
+/// or [`GL_TEXTURE_BUFFER_SIZE`](crate::enums::GL_TEXTURE_BUFFER_SIZE).
+impl Context {
+    pub(crate) fn oxidegl_tex_buffer_range(
+        &mut self,
+        target: TextureTarget,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) -> GlFallible {
+        gl_assert!(
+            target == TextureTarget::TextureBuffer,
+            InvalidEnum,
+            "{target:?} is not a valid target for glTexBufferRange"
+        );
+        let name = self.bound_texture(target)?;
+        self.tex_buffer_internal(name, internalformat, buffer, Some((offset, size)))
+    }
+    pub(crate) fn oxidegl_texture_buffer_range(
+        &mut self,
+        texture: GLuint,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        self.tex_buffer_internal(name, internalformat, buffer, Some((offset, size)))
+    }
+}
+
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+/// > functions. Must be one of [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+/// > [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+/// > [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// > [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
+/// > [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// > or [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE).
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
+/// > functions.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a single-valued texture parameter. `pname`
+/// > can be one of the following: [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::enums::GL_DEPTH_STENCIL_TEXTURE_MODE),
+/// > [`GL_TEXTURE_BASE_LEVEL`](crate::enums::GL_TEXTURE_BASE_LEVEL), [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC),
+/// > [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE), [`GL_TEXTURE_LOD_BIAS`](crate::enums::GL_TEXTURE_LOD_BIAS),
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER),
+/// > [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD), [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD),
+/// > [`GL_TEXTURE_MAX_LEVEL`](crate::enums::GL_TEXTURE_MAX_LEVEL), [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R),
+/// > [`GL_TEXTURE_SWIZZLE_G`](crate::enums::GL_TEXTURE_SWIZZLE_G), [`GL_TEXTURE_SWIZZLE_B`](crate::enums::GL_TEXTURE_SWIZZLE_B),
+/// > [`GL_TEXTURE_SWIZZLE_A`](crate::enums::GL_TEXTURE_SWIZZLE_A), [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S),
+/// > [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T), or [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R).
+///
+/// > For the vector commands( [**glTexParameter*v**](crate::context::Context::oxidegl_tex_parameter*v)
+/// > ), `pname` can also be one of [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+/// > or [`GL_TEXTURE_SWIZZLE_RGBA`](crate::enums::GL_TEXTURE_SWIZZLE_RGBA).
+///
+/// `param`
+///
+/// > For the scalar commands, specifies the value of `pname`.
+///
+/// `params`
+///
+/// > For the vector commands, specifies a pointer to an array where the value
+/// > or values of `pname` are stored.
+///
+/// ### Description
+/// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) and
+/// [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
+/// assign the value or values in `params` to the texture parameter specified
+/// as `pname`. For [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter),
+/// `target` defines the target texture, either [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+/// [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D),
+/// [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
+/// [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// or [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE). The following
+/// symbols are accepted in `pname`:
+///
+/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
+///
+/// > Specifies the mode used to read from depth-stencil format textures. `params`
+/// > must be one of [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT)
+/// > or [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX). If the depth stencil
+/// > mode is [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT), then
+/// > reads from depth-stencil format textures will return the depth component
+/// > of the texel in `[inlineq]` [`GL_STENCIL_INDEX`](crate::enums::GL_STENCIL_INDEX)
+/// > then the stencil component is returned in `[inlineq]` [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT).
+///
+/// [`GL_TEXTURE_BASE_LEVEL`](crate::enums::GL_TEXTURE_BASE_LEVEL)
+///
+/// > Specifies the index of the lowest defined mipmap level. This is an integer
+/// > value. The initial value is 0.
+///
+///
+/// [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+///
+/// > The data in `params` specifies four values that define the border values
+/// > that should be used for border texels. If a texel is sampled from the border
+/// > of the texture, the values of [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+/// > are interpreted as an RGBA color to match the texture's internal format
+/// > and substituted for the non-existent texel data. If the texture contains
+/// > depth components, the first component of [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+/// > is interpreted as a depth value. The initial value is `[inlineq]`
+///
+/// > If the values for [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+/// > are specified with [**glTexParameterIiv**](crate::context::Context::oxidegl_tex_parameter_iiv)
+/// > or [**glTexParameterIuiv**](crate::context::Context::oxidegl_tex_parameter_iuiv),
+/// > the values are stored unmodified with an internal data type of integer.
+/// > If specified with [**glTexParameteriv**](crate::context::Context::oxidegl_tex_parameteriv),
+/// > they are converted to floating point with the following equation: `[inlineq]`
+/// > [**glTexParameterfv**](crate::context::Context::oxidegl_tex_parameterfv),
+/// > they are stored unmodified as floating-point values.
+///
+/// [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC)
+///
+/// > Specifies the comparison operator used when [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE)
+/// > is set to [`GL_COMPARE_REF_TO_TEXTURE`](crate::enums::GL_COMPARE_REF_TO_TEXTURE).
+/// > Permissible values are:
+/// > |* Texture Comparison Function*                                  |* Computed result*                            |
+/// > |----------------------------------------------------------------|----------------------------------------------|
+/// > | [`GL_LEQUAL`](crate::enums::GL_LEQUAL)                         | `[inlineq]`                                  |
+/// > | [`GL_GEQUAL`](crate::enums::GL_GEQUAL)                         | `[inlineq]`                                  |
+/// > | [`GL_LESS`](crate::enums::GL_LESS)                             | `[inlineq]`                                  |
+/// > | [`GL_GREATER`](crate::enums::GL_GREATER)                       | `[inlineq]`                                  |
+/// > | [`GL_EQUAL`](crate::enums::GL_EQUAL)                           | `[inlineq]`                                  |
+/// > | [`GL_NOTEQUAL`](crate::enums::GL_NOTEQUAL)                     | `[inlineq]`                                  |
+/// > | [`GL_ALWAYS`](crate::enums::GL_ALWAYS)                         | `[inlineq]`                                  |
+/// > | [`GL_NEVER`](crate::enums::GL_NEVER)                           | `[inlineq]`                                  |
+///
+/// > `[inlineq]` `[inlineq]` `[inlineq]`
+///
+/// [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE)
+///
+/// > Specifies the texture comparison mode for currently bound depth textures.
+/// > That is, a texture whose internal format is [`GL_DEPTH_COMPONENT_*`](crate::enums::GL_DEPTH_COMPONENT_*);
+/// > see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
+/// > Permissible values are: [`GL_COMPARE_REF_TO_TEXTURE`](crate::enums::GL_COMPARE_REF_TO_TEXTURE)
+///
+/// >> Specifies that the interpolated and clamped `[inlineq]` [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC)
+/// >> for details of how the comparison is evaluated. The result of the comparison
+/// >> is assigned to the red channel.
+///
+/// > [`GL_NONE`](crate::enums::GL_NONE)
+///
+/// >> Specifies that the red channel should be assigned the appropriate value
+/// >> from the currently bound depth texture.
+///
+///
+/// [`GL_TEXTURE_LOD_BIAS`](crate::enums::GL_TEXTURE_LOD_BIAS)
+///
+/// > `params` specifies a fixed bias value that is to be added to the level-of-detail
+/// > parameter for the texture before texture sampling. The specified value
+/// > is added to the shader-supplied bias value (if any) and subsequently clamped
+/// > into the implementation-defined range `[inlineq]` `[inlineq]` [`GL_MAX_TEXTURE_LOD_BIAS`](crate::enums::GL_MAX_TEXTURE_LOD_BIAS).
+/// > The initial value is 0.0.
+///
+/// [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+///
+/// > The texture minifying function is used whenever the level-of-detail function
+/// > used when sampling from the texture determines that the texture should
+/// > be minified. There are six defined minifying functions. Two of them use
+/// > either the nearest texture elements or a weighted average of multiple texture
+/// > elements to compute the texture value. The other four use mipmaps.
+///
+/// > A mipmap is an ordered set of arrays representing the same image at progressively
+/// > lower resolutions. If the texture has dimensions `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// > [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// > or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
+/// > with the *level* argument indicating the order of the mipmaps. Level 0
+/// > is the original texture; level `[inlineq]` `[inlineq]`
+///
+/// > `params` supplies a function for minifying the texture as one of the following:
+/// > [`GL_NEAREST`](crate::enums::GL_NEAREST)
+///
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the specified texture coordinates.
+///
+/// > [`GL_LINEAR`](crate::enums::GL_LINEAR)
+///
+/// >> Returns the weighted average of the four texture elements that are closest
+/// >> to the specified texture coordinates. These can include items wrapped or
+/// >> repeated from other parts of a texture, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
+///
+/// > [`GL_NEAREST_MIPMAP_NEAREST`](crate::enums::GL_NEAREST_MIPMAP_NEAREST)
+///
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::enums::GL_NEAREST) criterion
+/// >> (the texture element closest to the specified texture coordinates) to produce
+/// >> a texture value.
+///
+/// > [`GL_LINEAR_MIPMAP_NEAREST`](crate::enums::GL_LINEAR_MIPMAP_NEAREST)
+///
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::enums::GL_LINEAR) criterion
+/// >> (a weighted average of the four texture elements that are closest to the
+/// >> specified texture coordinates) to produce a texture value.
+///
+/// > [`GL_NEAREST_MIPMAP_LINEAR`](crate::enums::GL_NEAREST_MIPMAP_LINEAR)
+///
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::enums::GL_NEAREST) criterion
+/// >> (the texture element closest to the specified texture coordinates) to produce
+/// >> a texture value from each mipmap. The final texture value is a weighted
+/// >> average of those two values.
+///
+/// > [`GL_LINEAR_MIPMAP_LINEAR`](crate::enums::GL_LINEAR_MIPMAP_LINEAR)
+///
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::enums::GL_LINEAR) criterion
+/// >> (a weighted average of the texture elements that are closest to the specified
+/// >> texture coordinates) to produce a texture value from each mipmap. The final
+/// >> texture value is a weighted average of those two values.
+///
+///
+/// > As more texture elements are sampled in the minification process, fewer
+/// > aliasing artifacts will be apparent. While the [`GL_NEAREST`](crate::enums::GL_NEAREST)
+/// > and [`GL_LINEAR`](crate::enums::GL_LINEAR) minification functions can be
+/// > faster than the other four, they sample only one or multiple texture elements
+/// > to determine the texture value of the pixel being rendered and can produce
+/// > moire patterns or ragged transitions. The initial value of [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+/// > is [`GL_NEAREST_MIPMAP_LINEAR`](crate::enums::GL_NEAREST_MIPMAP_LINEAR).
+///
+///
+/// [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER)
+///
+/// > The texture magnification function is used whenever the level-of-detail
+/// > function used when sampling from the texture determines that the texture
+/// > should be magified. It sets the texture magnification function to either
+/// > [`GL_NEAREST`](crate::enums::GL_NEAREST) or [`GL_LINEAR`](crate::enums::GL_LINEAR)
+/// > (see below). [`GL_NEAREST`](crate::enums::GL_NEAREST) is generally faster
+/// > than [`GL_LINEAR`](crate::enums::GL_LINEAR), but it can produce textured
+/// > images with sharper edges because the transition between texture elements
+/// > is not as smooth. The initial value of [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER)
+/// > is [`GL_LINEAR`](crate::enums::GL_LINEAR). [`GL_NEAREST`](crate::enums::GL_NEAREST)
+///
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the specified texture coordinates.
+///
+/// > [`GL_LINEAR`](crate::enums::GL_LINEAR)
+///
+/// >> Returns the weighted average of the texture elements that are closest to
+/// >> the specified texture coordinates. These can include items wrapped or repeated
+/// >> from other parts of a texture, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
+///
+///
+///
+/// [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD)
+///
+/// > Sets the minimum level-of-detail parameter. This floating-point value limits
+/// > the selection of highest resolution mipmap (lowest mipmap level). The initial
+/// > value is -1000.
+///
+///
+/// [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD)
+///
+/// > Sets the maximum level-of-detail parameter. This floating-point value limits
+/// > the selection of the lowest resolution mipmap (highest mipmap level). The
+/// > initial value is 1000.
+///
+///
+/// [`GL_TEXTURE_MAX_LEVEL`](crate::enums::GL_TEXTURE_MAX_LEVEL)
+///
+/// > Sets the index of the highest defined mipmap level. This is an integer
+/// > value. The initial value is 1000.
+///
+///
+/// [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+///
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` are [`GL_RED`](crate::enums::GL_RED),
+/// > [`GL_GREEN`](crate::enums::GL_GREEN), [`GL_BLUE`](crate::enums::GL_BLUE),
+/// > [`GL_ALPHA`](crate::enums::GL_ALPHA), [`GL_ZERO`](crate::enums::GL_ZERO)
+/// > and [`GL_ONE`](crate::enums::GL_ONE). If [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_RED`](crate::enums::GL_RED), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_GREEN`](crate::enums::GL_GREEN), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_BLUE`](crate::enums::GL_BLUE), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_ALPHA`](crate::enums::GL_ALPHA), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_ZERO`](crate::enums::GL_ZERO), the value for `[inlineq]` `[inlineq]`
+/// > [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R) is [`GL_ONE`](crate::enums::GL_ONE),
+/// > the value for `[inlineq]` `[inlineq]` [`GL_RED`](crate::enums::GL_RED).
+///
+///
+/// [`GL_TEXTURE_SWIZZLE_G`](crate::enums::GL_TEXTURE_SWIZZLE_G)
+///
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
+/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R).
+/// > The initial value is [`GL_GREEN`](crate::enums::GL_GREEN).
+///
+///
+/// [`GL_TEXTURE_SWIZZLE_B`](crate::enums::GL_TEXTURE_SWIZZLE_B)
+///
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
+/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R).
+/// > The initial value is [`GL_BLUE`](crate::enums::GL_BLUE).
+///
+///
+/// [`GL_TEXTURE_SWIZZLE_A`](crate::enums::GL_TEXTURE_SWIZZLE_A)
+///
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
+/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R).
+/// > The initial value is [`GL_ALPHA`](crate::enums::GL_ALPHA).
+///
+///
+/// [`GL_TEXTURE_SWIZZLE_RGBA`](crate::enums::GL_TEXTURE_SWIZZLE_RGBA)
+///
+/// > Sets the swizzles that will be applied to the `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `params` and their effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R),
+/// > except that all channels are specified simultaneously. Setting the value
+/// > of [`GL_TEXTURE_SWIZZLE_RGBA`](crate::enums::GL_TEXTURE_SWIZZLE_RGBA) is
+/// > equivalent (assuming no errors are generated) to setting the parameters
+/// > of each of [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R),
+/// > [`GL_TEXTURE_SWIZZLE_G`](crate::enums::GL_TEXTURE_SWIZZLE_G), [`GL_TEXTURE_SWIZZLE_B`](crate::enums::GL_TEXTURE_SWIZZLE_B),
+/// > and [`GL_TEXTURE_SWIZZLE_A`](crate::enums::GL_TEXTURE_SWIZZLE_A) successively.
+///
+///
+/// [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+///
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_CLAMP_TO_BORDER`](crate::enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT),
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE) causes `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_CLAMP_TO_BORDER`](crate::enums::GL_CLAMP_TO_BORDER)
+/// > evaluates `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE).
+/// > However, in cases where clamping would have occurred in [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE)
+/// > mode, the fetched texel data is substituted with the values specified by
+/// > [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR). [`GL_REPEAT`](crate::enums::GL_REPEAT)
+/// > causes the integer part of the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT)
+/// > causes the `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE)
+/// > causes the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT)
+/// > for one repetition of the texture, at which point the coordinate to be
+/// > clamped as in [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE). Initially,
+/// > [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S) is set to [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+///
+/// [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T)
+///
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_CLAMP_TO_BORDER`](crate::enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT),
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T) is set
+/// > to [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+///
+/// [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R)
+///
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_CLAMP_TO_BORDER`](crate::enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::enums::GL_MIRRORED_REPEAT),
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R) is set
+/// > to [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// ### Notes
+/// Suppose that a program attempts to sample from a texture and has set [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+/// to one of the functions that requires a mipmap. If either the dimensions
+/// of the texture images currently defined (with previous calls to [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d))
+/// do not follow the proper sequence for mipmaps (described above), or there
+/// are fewer texture images defined than are needed, or the set of texture
+/// images have differing numbers of texture components, then the texture is
+/// considered *incomplete*.
+///
+/// Linear filtering accesses the four nearest texture elements only in 2D
+/// textures. In 1D textures, linear filtering accesses the two nearest texture
+/// elements. In 3D textures, linear filtering accesses the eight nearest texture
+/// elements.
+///
+/// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) specifies
+/// the texture parameters for the active texture unit, specified by calling
+/// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+/// [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
+/// specifies the texture parameters for the texture object with ID `texture`.
+///
+/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
+/// is available only if the GL version is 4.3 or greater.
+///
+/// [`GL_MIRROR_CLAMP_TO_EDGE`](crate::enums::GL_MIRROR_CLAMP_TO_EDGE) is available
+/// only if the GL version is 4.4 or greater.
+///
+/// ### Associated Gets
+/// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+///
+/// [**glGetTextureParameter**](crate::context::Context::oxidegl_get_texture_parameter)
+///
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+///
+/// [**glGetTextureLevelParameter**](crate::context::Context::oxidegl_get_texture_level_parameter)
+impl Context {
+    pub(crate) fn oxidegl_tex_parameterf(
+        &mut self,
+        target: TextureTarget,
+        pname: TextureParameterName,
+        param: GLfloat,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: `param` is a single value
+        unsafe {
+            self.tex_parameter_internal(name, pname, &raw const param, false, BorderColor::Float)
+        }
+    }
+    pub(crate) unsafe fn oxidegl_tex_parameterfv(
+        &mut self,
+        target: TextureTarget,
+        pname: TextureParameterName,
+        params: *const GLfloat,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, params, true, BorderColor::Float) }
+    }
+    pub(crate) fn oxidegl_tex_parameteri(
+        &mut self,
+        target: TextureTarget,
+        pname: TextureParameterName,
+        param: GLint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: `param` is a single value
+        unsafe {
+            self.tex_parameter_internal(
+                name,
+                pname,
+                &raw const param,
+                false,
+                normalized_border_color,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_tex_parameteriv(
+        &mut self,
+        target: TextureTarget,
+        pname: TextureParameterName,
+        params: *const GLint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, params, true, normalized_border_color) }
+    }
+    pub(crate) unsafe fn oxidegl_tex_parameter_iiv(
+        &mut self,
+        target: TextureTarget,
+        pname: TextureParameterName,
+        params: *const GLint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, params, true, BorderColor::Int) }
+    }
+    pub(crate) unsafe fn oxidegl_tex_parameter_iuiv(
+        &mut self,
+        target: TextureTarget,
+        pname: TextureParameterName,
+        params: *const GLuint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, params, true, BorderColor::Uint) }
+    }
+    pub(crate) fn oxidegl_texture_parameterf(
+        &mut self,
+        texture: GLuint,
+        pname: TextureParameterName,
+        param: GLfloat,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: `param` is a single value
+        unsafe {
+            self.tex_parameter_internal(name, pname, &raw const param, false, BorderColor::Float)
+        }
+    }
+    pub(crate) unsafe fn oxidegl_texture_parameterfv(
+        &mut self,
+        texture: GLuint,
+        pname: TextureParameterName,
+        param: *const GLfloat,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `param` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, param, true, BorderColor::Float) }
+    }
+    pub(crate) fn oxidegl_texture_parameteri(
+        &mut self,
+        texture: GLuint,
+        pname: TextureParameterName,
+        param: GLint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: `param` is a single value
+        unsafe {
+            self.tex_parameter_internal(
+                name,
+                pname,
+                &raw const param,
+                false,
+                normalized_border_color,
+            )
+        }
+    }
+    pub(crate) unsafe fn oxidegl_texture_parameter_iiv(
+        &mut self,
+        texture: GLuint,
+        pname: TextureParameterName,
+        params: *const GLint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `params` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, params, true, BorderColor::Int) }
+    }
+    pub(crate) unsafe fn oxidegl_texture_parameter_iuiv(
+        &mut self,
+        texture: GLuint,
+        pname: TextureParameterName,
+        params: *const GLuint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `params` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, params, true, BorderColor::Uint) }
+    }
+    pub(crate) unsafe fn oxidegl_texture_parameteriv(
+        &mut self,
+        texture: GLuint,
+        pname: TextureParameterName,
+        param: *const GLint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `param` points to enough values for `pname`
+        unsafe { self.tex_parameter_internal(name, pname, param, true, normalized_border_color) }
+    }
+}
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target to which the texture is bound for [**glGetTexParameterfv**](crate::context::Context::oxidegl_get_tex_parameterfv),
+/// > [**glGetTexParameteriv**](crate::context::Context::oxidegl_get_tex_parameteriv),
+/// > [**glGetTexParameterIiv**](crate::context::Context::oxidegl_get_tex_parameter_iiv),
+/// > and [**glGetTexParameterIuiv**](crate::context::Context::oxidegl_get_tex_parameter_iuiv)
+/// > functions. [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D), [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY),
+/// > [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+/// > [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// > [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
+/// > [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// > [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), and [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+/// > are accepted.
+///
+/// `texture`
+///
+/// > Specifies the texture object name for [**glGetTextureParameterfv**](crate::context::Context::oxidegl_get_texture_parameterfv),
+/// > [**glGetTextureParameteriv**](crate::context::Context::oxidegl_get_texture_parameteriv),
+/// > [**glGetTextureParameterIiv**](crate::context::Context::oxidegl_get_texture_parameter_iiv),
+/// > and [**glGetTextureParameterIuiv**](crate::context::Context::oxidegl_get_texture_parameter_iuiv)
+/// > functions.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a texture parameter. [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::enums::GL_DEPTH_STENCIL_TEXTURE_MODE),
+/// > [`GL_IMAGE_FORMAT_COMPATIBILITY_TYPE`](crate::enums::GL_IMAGE_FORMAT_COMPATIBILITY_TYPE),
+/// > [`GL_TEXTURE_BASE_LEVEL`](crate::enums::GL_TEXTURE_BASE_LEVEL), [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR),
+/// > [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE), [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC),
+/// > [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT),
+/// > [`GL_TEXTURE_IMMUTABLE_LEVELS`](crate::enums::GL_TEXTURE_IMMUTABLE_LEVELS),
+/// > [`GL_TEXTURE_LOD_BIAS`](crate::enums::GL_TEXTURE_LOD_BIAS), [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER),
+/// > [`GL_TEXTURE_MAX_LEVEL`](crate::enums::GL_TEXTURE_MAX_LEVEL), [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD),
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD),
+/// > [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R), [`GL_TEXTURE_SWIZZLE_G`](crate::enums::GL_TEXTURE_SWIZZLE_G),
+/// > [`GL_TEXTURE_SWIZZLE_B`](crate::enums::GL_TEXTURE_SWIZZLE_B), [`GL_TEXTURE_SWIZZLE_A`](crate::enums::GL_TEXTURE_SWIZZLE_A),
+/// > [`GL_TEXTURE_SWIZZLE_RGBA`](crate::enums::GL_TEXTURE_SWIZZLE_RGBA), [`GL_TEXTURE_TARGET`](crate::enums::GL_TEXTURE_TARGET),
+/// > [`GL_TEXTURE_VIEW_MIN_LAYER`](crate::enums::GL_TEXTURE_VIEW_MIN_LAYER),
+/// > [`GL_TEXTURE_VIEW_MIN_LEVEL`](crate::enums::GL_TEXTURE_VIEW_MIN_LEVEL),
+/// > [`GL_TEXTURE_VIEW_NUM_LAYERS`](crate::enums::GL_TEXTURE_VIEW_NUM_LAYERS),
+/// > [`GL_TEXTURE_VIEW_NUM_LEVELS`](crate::enums::GL_TEXTURE_VIEW_NUM_LEVELS),
+/// > [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S), [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T),
+/// > and [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R) are accepted.
+///
+/// `params`
+///
+/// > Returns the texture parameters.
+///
+/// ### Description
+/// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+/// and [**glGetTextureParameter**](crate::context::Context::oxidegl_get_texture_parameter)
+/// return in `params` the value or values of the texture parameter specified
+/// as `pname`. `target` defines the target texture. [`GL_TEXTURE_1D`](crate::enums::GL_TEXTURE_1D),
+/// [`GL_TEXTURE_2D`](crate::enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::enums::GL_TEXTURE_3D),
+/// [`GL_TEXTURE_1D_ARRAY`](crate::enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::enums::GL_TEXTURE_2D_ARRAY),
+/// [`GL_TEXTURE_RECTANGLE`](crate::enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::enums::GL_TEXTURE_CUBE_MAP),
+/// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// [`GL_TEXTURE_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+/// specify one-, two-, or three-dimensional, one-dimensional array, two-dimensional
+/// array, rectangle, cube-mapped or cube-mapped array, two-dimensional multisample,
+/// or two-dimensional multisample array texturing, respectively. `pname` accepts
+/// the same symbols as [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter),
+/// with the same interpretations:
+///
+/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
+///
+/// > Returns the single-value depth stencil texture mode, a symbolic constant.
+/// > The initial value is [`GL_DEPTH_COMPONENT`](crate::enums::GL_DEPTH_COMPONENT).
+///
+/// [`GL_TEXTURE_MAG_FILTER`](crate::enums::GL_TEXTURE_MAG_FILTER)
+///
+/// > Returns the single-valued texture magnification filter, a symbolic constant.
+/// > The initial value is [`GL_LINEAR`](crate::enums::GL_LINEAR).
+///
+/// [`GL_TEXTURE_MIN_FILTER`](crate::enums::GL_TEXTURE_MIN_FILTER)
+///
+/// > Returns the single-valued texture minification filter, a symbolic constant.
+/// > The initial value is [`GL_NEAREST_MIPMAP_LINEAR`](crate::enums::GL_NEAREST_MIPMAP_LINEAR).
+///
+/// [`GL_TEXTURE_MIN_LOD`](crate::enums::GL_TEXTURE_MIN_LOD)
+///
+/// > Returns the single-valued texture minimum level-of-detail value. The initial
+/// > value is `[inlineq]`
+///
+/// [`GL_TEXTURE_MAX_LOD`](crate::enums::GL_TEXTURE_MAX_LOD)
+///
+/// > Returns the single-valued texture maximum level-of-detail value. The initial
+/// > value is 1000.
+///
+/// [`GL_TEXTURE_BASE_LEVEL`](crate::enums::GL_TEXTURE_BASE_LEVEL)
+///
+/// > Returns the single-valued base texture mipmap level. The initial value
+/// > is 0.
+///
+/// [`GL_TEXTURE_MAX_LEVEL`](crate::enums::GL_TEXTURE_MAX_LEVEL)
+///
+/// > Returns the single-valued maximum texture mipmap array level. The initial
+/// > value is 1000.
+///
+/// [`GL_TEXTURE_SWIZZLE_R`](crate::enums::GL_TEXTURE_SWIZZLE_R)
+///
+/// > Returns the red component swizzle. The initial value is [`GL_RED`](crate::enums::GL_RED).
+///
+/// [`GL_TEXTURE_SWIZZLE_G`](crate::enums::GL_TEXTURE_SWIZZLE_G)
+///
+/// > Returns the green component swizzle. The initial value is [`GL_GREEN`](crate::enums::GL_GREEN).
+///
+/// [`GL_TEXTURE_SWIZZLE_B`](crate::enums::GL_TEXTURE_SWIZZLE_B)
+///
+/// > Returns the blue component swizzle. The initial value is [`GL_BLUE`](crate::enums::GL_BLUE).
+///
+/// [`GL_TEXTURE_SWIZZLE_A`](crate::enums::GL_TEXTURE_SWIZZLE_A)
+///
+/// > Returns the alpha component swizzle. The initial value is [`GL_ALPHA`](crate::enums::GL_ALPHA).
+///
+/// [`GL_TEXTURE_SWIZZLE_RGBA`](crate::enums::GL_TEXTURE_SWIZZLE_RGBA)
+///
+/// > Returns the component swizzle for all channels in a single query.
+///
+/// [`GL_TEXTURE_WRAP_S`](crate::enums::GL_TEXTURE_WRAP_S)
+///
+/// > Returns the single-valued wrapping function for texture coordinate `[inlineq]`
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_WRAP_T`](crate::enums::GL_TEXTURE_WRAP_T)
+///
+/// > Returns the single-valued wrapping function for texture coordinate `[inlineq]`
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_WRAP_R`](crate::enums::GL_TEXTURE_WRAP_R)
+///
+/// > Returns the single-valued wrapping function for texture coordinate `[inlineq]`
+/// > [`GL_REPEAT`](crate::enums::GL_REPEAT).
+///
+/// [`GL_TEXTURE_BORDER_COLOR`](crate::enums::GL_TEXTURE_BORDER_COLOR)
+///
+/// > Returns four integer or floating-point numbers that comprise the RGBA color
+/// > of the texture border. Floating-point values are returned in the range
+/// > `[inlineq]` `[inlineq]`
+///
+/// [`GL_TEXTURE_COMPARE_MODE`](crate::enums::GL_TEXTURE_COMPARE_MODE)
+///
+/// > Returns a single-valued texture comparison mode, a symbolic constant. The
+/// > initial value is [`GL_NONE`](crate::enums::GL_NONE). See [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter).
+///
+/// [`GL_TEXTURE_COMPARE_FUNC`](crate::enums::GL_TEXTURE_COMPARE_FUNC)
+///
+/// > Returns a single-valued texture comparison function, a symbolic constant.
+/// > The initial value is [`GL_LEQUAL`](crate::enums::GL_LEQUAL). See [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter).
+///
+/// [`GL_TEXTURE_VIEW_MIN_LEVEL`](crate::enums::GL_TEXTURE_VIEW_MIN_LEVEL)
+///
+/// > Returns a single-valued base level of a texture view relative to its parent.
+/// > The initial value is 0. See [**glTextureView**](crate::context::Context::oxidegl_texture_view).
+///
+/// [`GL_TEXTURE_VIEW_NUM_LEVELS`](crate::enums::GL_TEXTURE_VIEW_NUM_LEVELS)
+///
+/// > Returns a single-valued number of levels of detail of a texture view. See
+/// > [**glTextureView**](crate::context::Context::oxidegl_texture_view).
+///
+/// [`GL_TEXTURE_VIEW_MIN_LAYER`](crate::enums::GL_TEXTURE_VIEW_MIN_LAYER)
+///
+/// > Returns a single-valued first level of a texture array view relative to
+/// > its parent. See [**glTextureView**](crate::context::Context::oxidegl_texture_view).
+///
+/// [`GL_TEXTURE_VIEW_NUM_LAYERS`](crate::enums::GL_TEXTURE_VIEW_NUM_LAYERS)
+///
+/// > Returns a single-valued number of layers in a texture array view. See [**glTextureView**](crate::context::Context::oxidegl_texture_view).
+///
+/// [`GL_TEXTURE_IMMUTABLE_LEVELS`](crate::enums::GL_TEXTURE_IMMUTABLE_LEVELS)
+///
+/// > Returns a single-valued number of immutable texture levels in a texture
+/// > view. See [**glTextureView**](crate::context::Context::oxidegl_texture_view).
+///
+/// In addition to the parameters that may be set with [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter),
+/// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+/// and [**glGetTextureParameter**](crate::context::Context::oxidegl_get_texture_parameter)
+/// accept the following read-only parameters:
+///
+/// [`GL_IMAGE_FORMAT_COMPATIBILITY_TYPE`](crate::enums::GL_IMAGE_FORMAT_COMPATIBILITY_TYPE)
+///
+/// > Returns the matching criteria use for the texture when used as an image
+/// > texture. Can return [`GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE`](crate::enums::GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE),
+/// > [`GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS`](crate::enums::GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS)
+/// > or [`GL_NONE`](crate::enums::GL_NONE).
+///
+/// [`GL_TEXTURE_IMMUTABLE_FORMAT`](crate::enums::GL_TEXTURE_IMMUTABLE_FORMAT)
+///
+/// > Returns non-zero if the texture has an immutable format. Textures become
+/// > immutable if their storage is specified with [**glTexStorage1D**](crate::context::Context::oxidegl_tex_storage1_d),
+/// > [**glTexStorage2D**](crate::context::Context::oxidegl_tex_storage2_d) or
+/// > [**glTexStorage3D**](crate::context::Context::oxidegl_tex_storage3_d). The
+/// > initial value is [`GL_FALSE`](crate::enums::GL_FALSE).
+///
+/// [`GL_TEXTURE_TARGET`](crate::enums::GL_TEXTURE_TARGET)
+///
+/// > Returns the effective target of the texture object. For [**glGetTex*Parameter**](crate::context::Context::oxidegl_get_tex*_parameter)
+/// > functions, this is the target parameter. For [**glGetTextureParameter***](crate::context::Context::oxidegl_get_texture_parameter*),
+/// > it is the target to which the texture was initially bound when it was
+/// > created, or the value of the target parameter to the call to [**glCreateTextures**](crate::context::Context::oxidegl_create_textures)
+/// > which created the texture.
+///
+/// ### Notes
+/// If an error is generated, no change is made to the contents of `params`.
+///
+/// [`GL_IMAGE_FORMAT_COMPATIBILITY_TYPE`](crate::enums::GL_IMAGE_FORMAT_COMPATIBILITY_TYPE)
+/// is available only if the GL version is 4.2 or greater.
+///
+/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::enums::GL_DEPTH_STENCIL_TEXTURE_MODE),
+/// [`GL_TEXTURE_VIEW_MIN_LEVEL`](crate::enums::GL_TEXTURE_VIEW_MIN_LEVEL),
+/// [`GL_TEXTURE_VIEW_NUM_LEVELS`](crate::enums::GL_TEXTURE_VIEW_NUM_LEVELS),
+/// [`GL_TEXTURE_VIEW_MIN_LAYER`](crate::enums::GL_TEXTURE_VIEW_MIN_LAYER),
+/// [`GL_TEXTURE_VIEW_NUM_LAYERS`](crate::enums::GL_TEXTURE_VIEW_NUM_LAYERS)
+/// and [`GL_TEXTURE_IMMUTABLE_LEVELS`](crate::enums::GL_TEXTURE_IMMUTABLE_LEVELS)
+/// are available only if the GL version is 4.3 or greater.
+///
+/// [`GL_TEXTURE_TARGET`](crate::enums::GL_TEXTURE_TARGET) are available only
+/// if the GL version is 4.5 or greater.
+impl Context {
+    pub(crate) unsafe fn oxidegl_get_tex_parameterfv(
+        &mut self,
+        target: TextureTarget,
+        pname: GetTextureParameter,
+        params: *mut GLfloat,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, false) }
+    }
+    pub(crate) unsafe fn oxidegl_get_tex_parameteriv(
+        &mut self,
+        target: TextureTarget,
+        pname: GetTextureParameter,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, true) }
+    }
+    pub(crate) unsafe fn oxidegl_get_tex_parameter_iiv(
+        &mut self,
+        target: TextureTarget,
+        pname: GetTextureParameter,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, false) }
+    }
+    pub(crate) unsafe fn oxidegl_get_tex_parameter_iuiv(
+        &mut self,
+        target: TextureTarget,
+        pname: GetTextureParameter,
+        params: *mut GLuint,
+    ) -> GlFallible {
+        let name = self.bound_texture(target)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, false) }
+    }
+    pub(crate) unsafe fn oxidegl_get_texture_parameterfv(
+        &mut self,
+        texture: GLuint,
+        pname: GetTextureParameter,
+        params: *mut GLfloat,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, false) }
+    }
+    pub(crate) unsafe fn oxidegl_get_texture_parameter_iiv(
+        &mut self,
+        texture: GLuint,
+        pname: GetTextureParameter,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, false) }
+    }
+    pub(crate) unsafe fn oxidegl_get_texture_parameter_iuiv(
+        &mut self,
+        texture: GLuint,
+        pname: GetTextureParameter,
+        params: *mut GLuint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, false) }
+    }
+    pub(crate) unsafe fn oxidegl_get_texture_parameteriv(
+        &mut self,
+        texture: GLuint,
+        pname: GetTextureParameter,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let name = self.texture_by_name(texture)?;
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe { self.get_tex_parameter_internal(name, pname, params, true) }
+    }
+}
+/// Checks that a sampler parameter value may be used with a rectangle texture, which can't be mipmapped or repeated
+fn check_rectangle_sampling(
+    pname: SamplerParameter,
+    param: impl SrcType<f32> + SrcType<i32> + SrcType<u32>,
+) -> GlFallible {
+    let valid = match pname {
+        SamplerParameter::TextureWrapS
+        | SamplerParameter::TextureWrapT
+        | SamplerParameter::TextureWrapR => {
+            let mode: TextureWrapMode = param.try_into_enum()?;
+            matches!(
+                mode,
+                TextureWrapMode::ClampToEdge | TextureWrapMode::ClampToBorder
+            )
+        }
+        SamplerParameter::TextureMinFilter => {
+            let filter: TextureMinFilter = param.try_into_enum()?;
+            matches!(filter, TextureMinFilter::Nearest | TextureMinFilter::Linear)
+        }
+        _ => true,
+    };
+    gl_assert!(
+        valid,
+        InvalidEnum,
+        "invalid {pname:?} for a rectangle texture"
+    );
+    Ok(())
+}
+/// Converts a border color specified with glTexParameteriv, whose components are normalized signed integers
+pub(crate) fn normalized_border_color(c: [GLint; 4]) -> BorderColor {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "normalized values don't need the full precision"
+    )]
+    BorderColor::Float(c.map(|v| (v as f32 / GLint::MAX as f32).max(-1.0)))
+}
+/// Converts a floating point color component to a normalized signed integer (the inverse of [`normalized_border_color`])
+pub(crate) fn normalize_color_component(v: f32) -> GLint {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "value is clamped to the representable range"
+    )]
+    let v = (f64::from(v.clamp(-1.0, 1.0)) * f64::from(GLint::MAX)).round() as GLint;
+    v
+}
+
+impl Context {
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > Specifies which texture unit to make active. The number of texture units
+    /// > is implementation dependent, but must be at least 80. `texture` must be
+    /// > one of [`GL_TEXTURE`](crate::enums::GL_TEXTURE) *i*, where *i* ranges from
+    /// > zero to the value of [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS)
+    /// > minus one. The initial value is [`GL_TEXTURE0`](crate::enums::GL_TEXTURE0).
+    ///
+    /// ### Description
+    /// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture)
+    /// selects which texture unit subsequent texture state calls will affect.
+    /// The number of texture units an implementation supports is implementation
+    /// dependent, but must be at least 80.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_ACTIVE_TEXTURE`](crate::enums::GL_ACTIVE_TEXTURE),
+    /// or [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS).
+    pub(crate) fn oxidegl_active_texture(&mut self, texture: GLenum) -> GlFallible {
+        // there are only tokens up to GL_TEXTURE31, but units past it are still selected by GL_TEXTURE0 + i
+        let unit = texture.wrapping_sub(GL_TEXTURE0) as usize;
+        let count = self.gl_state.texture_units.units.len();
+        gl_assert!(
+            unit < count,
+            InvalidEnum,
+            "texture unit {unit} exceeds the number of texture units ({count})"
+        );
+        self.gl_state.texture_units.active = unit;
+        gl_debug!("made texture unit {unit} active");
+        Ok(())
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `first`
+    ///
+    /// > Specifies the first texture unit to which a texture is to be bound.
+    ///
+    /// `count`
+    ///
+    /// > Specifies the number of textures to bind.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies the address of an array of names of existing texture objects.
+    ///
+    /// ### Description
+    /// [**glBindTextures**](crate::context::Context::oxidegl_bind_textures) binds
+    /// an array of existing texture objects to a specified number of consecutive
+    /// texture units. `count` specifies the number of texture objects whose names
+    /// are stored in the array `textures`. That number of texture names are read
+    /// from the array and bound to the `count` consecutive texture units starting
+    /// from `first`. The target, or type of texture is deduced from the texture
+    /// object and each texture is bound to the corresponding target of the texture
+    /// unit. If the name zero appears in the `textures` array, any existing binding
+    /// to any target of the texture unit is reset and the default texture for
+    /// that target is bound in its place. Any non-zero entry in `textures` must
+    /// be the name of an existing texture object. If `textures` is [`NULL`](crate::enums::NULL)
+    /// then it is as if an appropriately sized array containing only zeros had
+    /// been specified.
+    ///
+    /// With the exception that the active texture selector maintains its current
+    /// value, [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
+    /// is equivalent to the following pseudo code:
+    ///
+    /// Each entry in `textures` will be checked individually and if found to be
+    /// invalid, the state for that texture unit will not be changed and an error
+    /// will be generated. However, the state for other texture units referenced
+    /// by the command will still be updated.
+    ///
+    /// ### Notes
+    /// [**glBindTextures**](crate::context::Context::oxidegl_bind_textures) is
+    /// available only if the GL version is 4.4 or higher.
+    ///
+    /// Note that because [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
+    /// cannot create new textures (even if a name passed has been previously generated
+    /// by call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// ), names passed to [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
+    /// must have been bound at least once previously via a call to [**glBindTexture**](crate::context::Context::oxidegl_bind_texture).
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub(crate) unsafe fn oxidegl_bind_textures(
+        &mut self,
+        first: GLuint,
+        count: GLsizei,
+        textures: *const GLuint,
+    ) -> GlFallible {
+        sizei!(count);
+        let end = u64::from(first) + u64::from(count);
+        gl_assert!(
+            end <= self.gl_state.texture_units.units.len() as u64,
+            InvalidOperation,
+            "texture units {first}..{end} exceed the number of texture units"
+        );
+        let mut res = Ok(());
+        for i in 0..count {
+            let unit = (first + i) as usize;
+            let texture = if textures.is_null() {
+                0
+            } else {
+                // Safety: caller ensures `textures` points to `count` texture names
+                unsafe { textures.add(i as usize).read() }
+            };
+            // an invalid entry leaves its texture unit untouched, but the remaining units are still bound
+            if let Err(e) = self.bind_texture_to_unit(unit, texture) {
+                res = Err(e);
+            }
+        }
+        res
+    }
+}
+
+impl Context {
+    /// ### Parameters
+    /// `unit`
+    ///
+    /// > Specifies the texture unit, to which the texture object should be bound
+    /// > to.
+    ///
+    /// `texture`
+    ///
+    /// > Specifies the name of a texture.
+    ///
+    /// ### Description
+    /// [**glBindTextureUnit**](crate::context::Context::oxidegl_bind_texture_unit)
+    /// binds an existing texture object to the texture unit numbered `unit`.
+    ///
+    /// `texture` must be zero or the name of an existing texture object. When
+    /// `texture` is the name of an existing texture object, that object is bound
+    /// to the target, in the corresponding texture unit, that was specified when
+    /// the object was created. When `texture` is zero, each of the targets enumerated
+    /// at the beginning of this section is reset to its default texture for the
+    /// corresponding texture image unit.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE)
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub(crate) fn oxidegl_bind_texture_unit(
+        &mut self,
+        unit: GLuint,
+        texture: GLuint,
+    ) -> GlFallible {
+        let unit = self.gl_state.texture_units.index(unit)?;
+        self.bind_texture_to_unit(unit, texture)
+    }
+}
+
+/// Every texture target that has a binding point on each texture unit
+const BINDABLE_TEXTURE_TARGETS: [TextureTarget; 11] = [
+    TextureTarget::Texture1D,
+    TextureTarget::Texture2D,
+    TextureTarget::Texture3D,
+    TextureTarget::Texture1DArray,
+    TextureTarget::Texture2DArray,
+    TextureTarget::TextureRectangle,
+    TextureTarget::TextureCubeMap,
+    TextureTarget::TextureCubeMapArray,
+    TextureTarget::TextureBuffer,
+    TextureTarget::Texture2DMultisample,
+    TextureTarget::Texture2DMultisampleArray,
+];
+
+/// Dimensionality of a glTexStorage* or glTexImage* command, which determines the texture targets it accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StorageDims {
+    One,
+    Two,
+    Three,
+}
+
+impl Context {
+    /// Returns the name of the texture bound to `target`, for use by non-DSA texture commands. This is the default texture of
+    /// `target` (created on first use) if no other texture is bound to it
+    pub(crate) fn bound_texture(
+        &mut self,
+        target: TextureTarget,
+    ) -> GlFallible<ObjectName<Texture>> {
+        if let Some(name) = self.gl_state.texture_units.active().get(target)? {
+            return Ok(name);
+        }
+        let units = &mut self.gl_state.texture_units;
+        let default = units.defaults.get_mut(target)?;
+        Ok(*default.get_or_insert_with(|| {
+            gl_debug!("creating the default texture of {target:?}");
+            self.gl_state
+                .texture_list
+                .new_obj(|name| Texture::new_named(name, target))
+        }))
+    }
+    /// Resolves the raw texture name passed to a DSA texture command, returning an `InvalidOperation` error if it does not name
+    /// an existing texture object
+    pub(crate) fn texture_by_name(&self, texture: GLuint) -> GlFallible<ObjectName<Texture>> {
+        ObjectName::try_from_raw(texture)
+            .ok()
+            .filter(|&name| {
+                self.gl_state.texture_list.is(name) && !self.gl_state.texture_units.is_default(name)
+            })
+            .ok_or_else(|| {
+                gl_err!(ty: Error, "{texture} does not name an existing texture object");
+                GlError::InvalidOperation.e()
+            })
+    }
+    /// Binds `texture` to `target` on the texture unit with index `unit`, returning an `InvalidEnum` error if `target` is not
+    /// a texture binding point
+    pub(crate) fn bind_texture_internal(
+        &mut self,
+        unit: usize,
+        target: TextureTarget,
+        texture: Option<ObjectName<Texture>>,
+    ) -> GlFallible {
+        let slot = self.gl_state.texture_units.units[unit]
+            .textures
+            .get_mut(target)?;
+        let prev = core::mem::replace(slot, texture);
+        // textures with shader-emulated sampling state change the specialization of the shaders that sample them
+        let emulated = |name: Option<ObjectName<Texture>>| {
+            name.is_some_and(|name| {
+                self.gl_state
+                    .texture_list
+                    .get(name)
+                    .sampling_state
+                    .shader_emulation()
+                    .is_some()
+            })
+        };
+        // unbinding a texture uses the default texture of the target instead
+        let default = self.gl_state.texture_units.defaults.get(target)?;
+        if prev != texture && (emulated(prev.or(default)) || emulated(texture.or(default))) {
+            self.new_pipeline();
+        }
+        Ok(())
+    }
+    /// Unbinds every texture from every target of the texture unit with index `unit`
+    fn unbind_texture_unit(&mut self, unit: usize) {
+        for target in BINDABLE_TEXTURE_TARGETS {
+            self.bind_texture_internal(unit, target, None)
+                .expect("bindable texture targets should be accepted by texture bindings");
+        }
+    }
+    /// Binds the texture named by `texture` to its own target on the texture unit with index `unit`, or unbinds every
+    /// target of that unit if `texture` is 0
+    fn bind_texture_to_unit(&mut self, unit: usize, texture: GLuint) -> GlFallible {
+        if texture == 0 {
+            self.unbind_texture_unit(unit);
+            gl_debug!("unbound all textures from texture unit {unit}");
+            return Ok(());
+        }
+        let name = self.texture_by_name(texture)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        self.bind_texture_internal(unit, target, Some(name))?;
+        gl_debug!("bound {name:?} to target {target:?} of texture unit {unit}");
+        Ok(())
+    }
+    /// Breaks every binding of the texture `name` on every texture unit and detaches it from the bound framebuffers (e.g. because
+    /// it is about to be deleted)
+    fn unbind_texture_everywhere(&mut self, name: ObjectName<Texture>) {
+        if self
+            .gl_state
+            .texture_list
+            .get_opt(name)
+            .is_some_and(|tex| tex.sampling_state.shader_emulation().is_some())
+        {
+            self.new_pipeline();
+        }
+        self.gl_state.texture_units.unbind_texture(name);
+        self.detach_from_bound_framebuffers(|a| a.texture_name() == Some(name));
+    }
+    /// Prepares the textures the current program samples from for a draw call. Realizes the backing storage of complete
+    /// textures, and creates the fallback textures that are bound in place of incomplete (or missing) ones
+    pub(crate) fn prepare_sampled_textures(&mut self) {
+        if self.gl_state.program_binding.is_none() {
+            return;
+        }
+        let mut fallbacks = Vec::new();
+        for shader_type in [ShaderType::VertexShader, ShaderType::FragmentShader] {
+            // index into the stage's bindings instead of iterating them, since realizing textures needs mutable access to
+            // the state they are borrowed from
+            for idx in 0.. {
+                let Some(binding) =
+                    PlatformState::sampled_image_binding(&self.gl_state, shader_type, idx)
+                else {
+                    break;
+                };
+                if let Some(name) = PlatformState::complete_unit_texture(&self.gl_state, &binding) {
+                    self.realize_texture(name);
+                    if self
+                        .gl_state
+                        .texture_list
+                        .get_mut(name)
+                        .sampled_texture()
+                        .is_some()
+                    {
+                        continue;
+                    }
+                }
+                let (unit, target) = (binding.unit, binding.target);
+                let bound = self.gl_state.texture_units.texture(unit as usize, target);
+                // units that already fell back during the previous draw were reported then
+                if !self
+                    .platform_state
+                    .reported_fallbacks
+                    .contains(&(unit, target, bound))
+                {
+                    match bound {
+                        Some(name) => {
+                            gl_debug!(
+                                "{name:?} bound to {target:?} of texture unit {unit} is incomplete, sampling it will return (0, 0, 0, 1)"
+                            );
+                        }
+                        None => {
+                            gl_debug!(
+                                "no texture is bound to {target:?} of texture unit {unit}, sampling it will return (0, 0, 0, 1)"
+                            );
+                        }
+                    }
+                }
+                fallbacks.push((unit, target, bound));
+                self.ensure_fallback_texture(target, binding.kind);
+            }
+        }
+        self.platform_state.reported_fallbacks = fallbacks;
+    }
+    /// Creates the texture that is bound in place of incomplete `target` textures sampled as `kind`, if it doesn't exist yet.
+    /// Fallback textures consist of a single texel of (0, 0, 0, 1), or a depth of 0 for depth textures, and are shared by
+    /// targets with the same Metal texture type
+    fn ensure_fallback_texture(&mut self, target: TextureTarget, kind: SampledKind) {
+        let key = (MTLTextureType::from(target), kind);
+        if self.platform_state.fallback_textures.contains_key(&key) {
+            return;
+        }
+        let format = match kind {
+            SampledKind::Float => InternalFormat::Rgba8,
+            SampledKind::Int => InternalFormat::Rgba8i,
+            SampledKind::Uint => InternalFormat::Rgba8ui,
+            SampledKind::Depth => InternalFormat::DepthComponent32f,
+        };
+        gl_debug!("creating {format:?} fallback texture for {target:?}");
+        let tex = if target == TextureTarget::TextureBuffer {
+            let alpha = if kind == SampledKind::Float {
+                u8::MAX
+            } else {
+                1
+            };
+            let texel = [0, 0, 0, alpha];
+            // Safety: `texel` is valid for reads of its length, and its contents are copied into the new buffer
+            let buffer = unsafe {
+                self.platform_state
+                    .device
+                    .newBufferWithBytes_length_options(
+                        NonNull::from(&texel).cast(),
+                        texel.len(),
+                        MTLResourceOptions::StorageModeShared,
+                    )
+            }
+            .expect("failed to allocate fallback buffer texture storage");
+            RealizedTexture::new_buffer(&buffer, format, 0, 1, texel.len()).mtl_tex
+        } else {
+            // multisample textures must have more than one sample, and every Metal device supports 4
+            let samples = if matches!(
+                target,
+                TextureTarget::Texture2DMultisample | TextureTarget::Texture2DMultisampleArray
+            ) {
+                4
+            } else {
+                1
+            };
+            // cube map arrays are sized in layer-faces
+            let depth = if target == TextureTarget::TextureCubeMapArray {
+                6
+            } else {
+                1
+            };
+            let extent = TextureExtent::new(1, 1, depth);
+            let storage =
+                RealizedTexture::new(&self.platform_state, target, format, extent, 1, samples);
+            let value = if kind == SampledKind::Depth {
+                ClearValue::Depth(0.0)
+            } else {
+                ClearValue::Color([0.0, 0.0, 0.0, 1.0])
+            };
+            for slice in 0..extent.layers(target) as usize {
+                self.platform_state.encode_render_pass(&clear_pass(
+                    &storage.mtl_tex,
+                    0,
+                    slice,
+                    0,
+                    value,
+                ));
+            }
+            storage.mtl_tex
+        };
+        self.platform_state.fallback_textures.insert(key, tex);
+    }
+    /// Lazily realizes the backing storage of the texture `name` (see [`Texture::realize`]), copying the contents of any levels
+    /// that are still backed by the storage over from the previous backing storage if it was reallocated, and writing any pending
+    /// writes to levels the storage now covers
+    pub(crate) fn realize_texture(&mut self, name: ObjectName<Texture>) {
+        self.reallocate_texture(name);
+        self.flush_pending_writes(name);
+    }
+    /// Writes the [pending writes](Texture::take_covered_writes) of the texture `name` to the levels of its storage that store them.
+    /// Writes to levels that the storage does not store are kept
+    fn flush_pending_writes(&mut self, name: ObjectName<Texture>) {
+        let tex = self.gl_state.texture_list.get_mut(name);
+        let writes = tex.take_covered_writes();
+        let target = tex.target;
+        for write in writes {
+            let tex = self.gl_state.texture_list.get_mut(name);
+            let Some(storage_level) = tex.storage_level(write.level, write.faces.start) else {
+                tex.pending_writes.push(write);
+                continue;
+            };
+            let mtl_tex = tex
+                .realized
+                .as_ref()
+                .expect("texture should have been realized")
+                .mtl_tex
+                .clone();
+            gl_debug!("writing pending data to level {} of {name:?}", write.level);
+            let planes: Vec<_> = write
+                .planes
+                .iter()
+                .map(|(plane, options)| (&plane[..], *options))
+                .collect();
+            self.write_image_region(
+                &mtl_tex,
+                storage_level,
+                target,
+                write.region.clone(),
+                &planes,
+                write.block,
+            );
+        }
+    }
+    /// Reallocates the backing storage of the texture `name` if it does not cover its consistent mipmap chain (see
+    /// [`realize_texture`](Self::realize_texture))
+    fn reallocate_texture(&mut self, name: ObjectName<Texture>) {
+        let tex = self.gl_state.texture_list.get_mut(name);
+        if tex.target == TextureTarget::TextureBuffer {
+            self.realize_buffer_texture(name);
+            return;
+        }
+        let Some(old) = tex.realize(&self.platform_state) else {
+            return;
+        };
+        let tex = self.gl_state.texture_list.get(name);
+        let new = tex
+            .realized
+            .as_ref()
+            .expect("texture should have been realized");
+        let layers = new.extent.layers(tex.target);
+        if old.format != new.format || old.extent.layers(tex.target) != layers {
+            return;
+        }
+        let copies: Vec<(u32, u32)> = (old.first_level..old.first_level + old.levels)
+            .filter_map(|level| {
+                let old_level = level - old.first_level;
+                let new_level = level
+                    .checked_sub(new.first_level)
+                    .filter(|&l| l < new.levels)?;
+                (old.extent.mip(tex.target, old_level) == new.extent.mip(tex.target, new_level))
+                    .then_some((old_level, new_level))
+            })
+            .collect();
+        if copies.is_empty() {
+            return;
+        }
+        gl_debug!(
+            "preserving {} levels of {name:?} across reallocation",
+            copies.len()
+        );
+        self.platform_state.encode_blit(|enc| {
+            for &(old_level, new_level) in &copies {
+                // Safety: both textures have the same pixel format and `layers` slices, and the copied levels have the same extent
+                unsafe {
+                    enc.copyFromTexture_sourceSlice_sourceLevel_toTexture_destinationSlice_destinationLevel_sliceCount_levelCount(
+                        &old.mtl_tex,
+                        0,
+                        old_level as usize,
+                        &new.mtl_tex,
+                        0,
+                        new_level as usize,
+                        layers as usize,
+                        1,
+                    );
+                }
+            }
+        });
+    }
+    /// Brings the storage of the buffer texture `name` up to date with the buffer range attached to it, recreating it if the buffer's
+    /// storage was reallocated or resized since it was last realized. Buffer textures without a buffer (or whose buffer has no storage)
+    /// have no storage
+    fn realize_buffer_texture(&mut self, name: ObjectName<Texture>) {
+        let tex = self.gl_state.texture_list.get(name);
+        let storage = tex.buffer.and_then(|range| {
+            let alloc = self
+                .gl_state
+                .buffer_list
+                .get_opt(range.buffer)?
+                .allocation
+                .as_ref()?;
+            Some((range, alloc.mtl.clone()))
+        });
+        let Some((range, mtl_buffer)) = storage else {
+            self.gl_state.texture_list.get_mut(name).realized = None;
+            return;
+        };
+        let format = range.format;
+        let mtl_format = format.mtl_texture_format();
+        let texel_size = format
+            .view_class()
+            .expect("buffer texture formats should be uncompressed color formats")
+            .texel_size();
+        if mtl_texel_size(mtl_format) != Some(texel_size) {
+            gl_err!(ty: Error, "OxideGL does not support buffer textures of internal format {format:?}");
+            self.gl_state.texture_list.get_mut(name).realized = None;
+            return;
+        }
+        // the texel count follows the size of the buffer's storage, clamped to the attached range
+        let available = mtl_buffer.length().saturating_sub(range.offset);
+        let texels = (range.size.unwrap_or(available).min(available) / texel_size)
+            .min(MAX_TEXTURE_BUFFER_SIZE as usize);
+        let up_to_date = tex.realized.as_ref().is_some_and(|r| {
+            r.format == format
+                && r.extent.width as usize == texels
+                && r.mtl_tex.bufferOffset() == range.offset
+                && r.mtl_tex
+                    .buffer()
+                    .is_some_and(|b| Retained::as_ptr(&b) == Retained::as_ptr(&mtl_buffer))
+        });
+        if up_to_date {
+            return;
+        }
+        let realized = (texels > 0).then(|| {
+            RealizedTexture::new_buffer(&mtl_buffer, format, range.offset, texels, texel_size)
+        });
+        let tex = self.gl_state.texture_list.get_mut(name);
+        if let Some(r) = &realized {
+            tex.label_storage(r);
+        }
+        tex.realized = realized;
+    }
+    /// Shared implementation of the glTexParameter* and glTextureParameter* commands. `params` points to a single value for the
+    /// scalar commands (`vector` is false) and to as many values as `pname` takes for the vector commands. `color` converts border
+    /// colors as the particular command specifies them
+    unsafe fn tex_parameter_internal<T: SrcType<f32> + SrcType<i32> + SrcType<u32>>(
+        &mut self,
+        name: ObjectName<Texture>,
+        pname: TextureParameterName,
+        params: *const T,
+        vector: bool,
+        color: fn([T; 4]) -> BorderColor,
+    ) -> GlFallible {
+        let tex = self.gl_state.texture_list.get_mut(name);
+        let target = tex.target;
+        gl_assert!(
+            target != TextureTarget::TextureBuffer,
+            InvalidEnum,
+            "buffer textures have no texture parameters"
+        );
+        let multisample = matches!(
+            target,
+            TextureTarget::Texture2DMultisample | TextureTarget::Texture2DMultisampleArray
+        );
+        // Safety: caller ensures `params` points to at least one value
+        let param = unsafe { params.read() };
+        let prev_emulation = tex.sampling_state.shader_emulation();
+        match pname {
+            TextureParameterName::TextureBorderColor | TextureParameterName::TextureSwizzleRgba
+                if !vector =>
+            {
+                gl_err!(ty: Error, "{pname:?} may only be set with the vector glTexParameter commands");
+                return Err(GlError::InvalidEnum.e());
+            }
+            TextureParameterName::TextureBorderColor => {
+                gl_assert!(
+                    !multisample,
+                    InvalidEnum,
+                    "multisample textures have no sampler state"
+                );
+                // Safety: caller ensures `params` points to 4 values for border colors
+                let c = unsafe { params.cast::<[T; 4]>().read_unaligned() };
+                tex.sampling_state.set_border_color(color(c));
+            }
+            TextureParameterName::TextureSwizzleRgba => {
+                // Safety: caller ensures `params` points to 4 values for swizzles
+                let c = unsafe { params.cast::<[T; 4]>().read_unaligned() };
+                let mut swizzle = tex.swizzle;
+                for (dst, src) in swizzle.iter_mut().zip(c) {
+                    *dst = src.try_into_enum()?;
+                }
+                tex.swizzle = swizzle;
+            }
+            TextureParameterName::TextureSwizzleR => tex.swizzle[0] = param.try_into_enum()?,
+            TextureParameterName::TextureSwizzleG => tex.swizzle[1] = param.try_into_enum()?,
+            TextureParameterName::TextureSwizzleB => tex.swizzle[2] = param.try_into_enum()?,
+            TextureParameterName::TextureSwizzleA => tex.swizzle[3] = param.try_into_enum()?,
+            TextureParameterName::TextureBaseLevel | TextureParameterName::TextureMaxLevel => {
+                let level: i32 = param.convert();
+                gl_assert!(level >= 0, InvalidValue, "{pname:?} may not be negative");
+                let base_only = multisample || target == TextureTarget::TextureRectangle;
+                gl_assert!(
+                    level == 0 || pname == TextureParameterName::TextureMaxLevel || !base_only,
+                    InvalidOperation,
+                    "the base level of {target:?} textures must be 0"
+                );
+                #[expect(clippy::cast_sign_loss, reason = "checked above")]
+                let level = level as u32;
+                if pname == TextureParameterName::TextureBaseLevel {
+                    tex.base_level = level;
+                } else {
+                    tex.max_level = level;
+                }
+            }
+            TextureParameterName::DepthStencilTextureMode => {
+                let mode: PixelFormat = param.try_into_enum()?;
+                let valid = matches!(
+                    mode,
+                    PixelFormat::DepthComponent | PixelFormat::StencilIndex
+                );
+                gl_assert!(
+                    valid,
+                    InvalidEnum,
+                    "invalid depth stencil texture mode {mode:?}"
+                );
+                tex.stencil_sampling = mode == PixelFormat::StencilIndex;
+            }
+            TextureParameterName::TextureWidth
+            | TextureParameterName::TextureHeight
+            | TextureParameterName::TextureInternalFormat
+            | TextureParameterName::TextureRedSize
+            | TextureParameterName::TextureGreenSize
+            | TextureParameterName::TextureBlueSize
+            | TextureParameterName::TextureAlphaSize => {
+                gl_err!(ty: Error, "{pname:?} is not a settable texture parameter");
+                return Err(GlError::InvalidEnum.e());
+            }
+            _ => {
+                let pname = SamplerParameter::from_enum(u32::from(pname))
+                    .expect("remaining texture parameters should be sampler parameters");
+                gl_assert!(
+                    !multisample,
+                    InvalidEnum,
+                    "multisample textures have no sampler state"
+                );
+                if target == TextureTarget::TextureRectangle {
+                    check_rectangle_sampling(pname, param)?;
+                }
+                tex.sampling_state.sampler_param(pname, param)?;
+            }
+        }
+        // a change in sampler emulation state changes the specialization of any shader that samples this texture
+        if tex.sampling_state.shader_emulation() != prev_emulation {
+            self.new_pipeline();
+        }
+        Ok(())
+    }
+    /// Shared implementation of the glGetTexParameter* and glGetTextureParameter* commands. `normalize_color` converts floating
+    /// point border colors to normalized integers, as glGetTexParameteriv does
+    unsafe fn get_tex_parameter_internal<T: GlDstType>(
+        &self,
+        name: ObjectName<Texture>,
+        pname: GetTextureParameter,
+        params: *mut T,
+        normalize_color: bool,
+    ) -> GlFallible {
+        let tex = self.gl_state.texture_list.get(name);
+        let sampling = &tex.sampling_state;
+        let view = tex.view.unwrap_or(TextureViewRange {
+            min_level: 0,
+            num_levels: 0,
+            min_layer: 0,
+            num_layers: 0,
+        });
+        // Safety: caller ensures `params` has room for the values of `pname`
+        unsafe {
+            match pname {
+                GetTextureParameter::TextureMagFilter => {
+                    u32::from(sampling.mag_filter).write_out(params);
+                }
+                GetTextureParameter::TextureMinFilter => {
+                    u32::from(sampling.min_filter).write_out(params);
+                }
+                GetTextureParameter::TextureWrapS => {
+                    u32::from(sampling.wrap_mode_s).write_out(params);
+                }
+                GetTextureParameter::TextureWrapT => {
+                    u32::from(sampling.wrap_mode_t).write_out(params);
+                }
+                GetTextureParameter::TextureWrapR => {
+                    u32::from(sampling.wrap_mode_r).write_out(params);
+                }
+                GetTextureParameter::TextureBorderColor => match sampling.border_color {
+                    BorderColor::Float(c) if normalize_color => {
+                        c.map(normalize_color_component).write_out(params);
+                    }
+                    BorderColor::Float(c) => c.write_out(params),
+                    BorderColor::Int(c) => c.write_out(params),
+                    BorderColor::Uint(c) => c.write_out(params),
+                },
+                GetTextureParameter::TextureMinLod => sampling.min_lod.write_out(params),
+                GetTextureParameter::TextureMaxLod => sampling.max_lod.write_out(params),
+                GetTextureParameter::TextureLodBias => sampling.lod_bias.write_out(params),
+                GetTextureParameter::TextureCompareMode => {
+                    u32::from(sampling.compare_mode).write_out(params);
+                }
+                GetTextureParameter::TextureCompareFunc => {
+                    u32::from(sampling.compare_func).write_out(params);
+                }
+                GetTextureParameter::TextureMaxAnisotropy => {
+                    sampling.max_anisotropy.as_float().write_out(params);
+                }
+                GetTextureParameter::TextureBaseLevel => tex.base_level.write_out(params),
+                GetTextureParameter::TextureMaxLevel => tex.max_level.write_out(params),
+                GetTextureParameter::TextureSwizzleR => u32::from(tex.swizzle[0]).write_out(params),
+                GetTextureParameter::TextureSwizzleG => u32::from(tex.swizzle[1]).write_out(params),
+                GetTextureParameter::TextureSwizzleB => u32::from(tex.swizzle[2]).write_out(params),
+                GetTextureParameter::TextureSwizzleA => u32::from(tex.swizzle[3]).write_out(params),
+                GetTextureParameter::TextureSwizzleRgba => {
+                    tex.swizzle.map(u32::from).write_out(params);
+                }
+                GetTextureParameter::DepthStencilTextureMode => {
+                    let mode = if tex.stencil_sampling {
+                        PixelFormat::StencilIndex
+                    } else {
+                        PixelFormat::DepthComponent
+                    };
+                    u32::from(mode).write_out(params);
+                }
+                GetTextureParameter::ImageFormatCompatibilityType => {
+                    let format = tex.realized.as_ref().map(|r| r.format);
+                    let compatibility = if format.and_then(InternalFormat::view_class).is_some() {
+                        GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE
+                    } else {
+                        GL_NONE
+                    };
+                    compatibility.write_out(params);
+                }
+                GetTextureParameter::TextureImmutableFormat => {
+                    tex.immutable_format.write_out(params);
+                }
+                GetTextureParameter::TextureImmutableLevels => {
+                    #[expect(
+                        clippy::cast_possible_truncation,
+                        reason = "level count is bounded by the texture size limits"
+                    )]
+                    let levels = if tex.immutable_format {
+                        tex.images.len() as u32
+                    } else {
+                        0
+                    };
+                    levels.write_out(params);
+                }
+                GetTextureParameter::TextureViewMinLevel => view.min_level.write_out(params),
+                GetTextureParameter::TextureViewNumLevels => view.num_levels.write_out(params),
+                GetTextureParameter::TextureViewMinLayer => view.min_layer.write_out(params),
+                GetTextureParameter::TextureViewNumLayers => view.num_layers.write_out(params),
+                GetTextureParameter::TextureTarget => u32::from(tex.target).write_out(params),
+                GetTextureParameter::TextureWidth
+                | GetTextureParameter::TextureHeight
+                | GetTextureParameter::TextureInternalFormat
+                | GetTextureParameter::TextureRedSize
+                | GetTextureParameter::TextureGreenSize
+                | GetTextureParameter::TextureBlueSize
+                | GetTextureParameter::TextureAlphaSize => {
+                    gl_err!(ty: Error, "{pname:?} is a texture level parameter, use glGetTexLevelParameter to query it");
+                    return Err(GlError::InvalidEnum.e());
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Shared implementation of the glTexBuffer* and glTextureBuffer* commands. Attaches the range of the data store of the buffer
+    /// named `buffer` given by `range` (as an offset and size, or the whole data store if None) to the buffer texture `name`, or
+    /// detaches its buffer if `buffer` is 0
+    fn tex_buffer_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+        range: Option<(GLintptr, GLsizeiptr)>,
+    ) -> GlFallible {
+        let target = self.gl_state.texture_list.get(name).target;
+        gl_assert!(
+            target == TextureTarget::TextureBuffer,
+            InvalidOperation,
+            "{name:?} has target {target:?}, so no buffer may be attached to it"
+        );
+        let format: InternalFormat = internalformat.try_into_enum()?;
+        gl_assert!(
+            format.is_texture_buffer_format(),
+            InvalidEnum,
+            "{format:?} is not a valid internal format for buffer textures"
+        );
+        let attachment = if buffer == 0 {
+            None
+        } else {
+            let buffer_name = ObjectName::try_from_raw(buffer)
+                .ok()
+                .filter(|&b| self.gl_state.buffer_list.is(b))
+                .ok_or_else(|| {
+                    gl_err!(ty: Error, "{buffer} does not name an existing buffer object");
+                    GlError::InvalidOperation.e()
+                })?;
+            let (offset, size) = match range {
+                None => (0, None),
+                Some((offset, size)) => {
+                    gl_assert!(
+                        offset >= 0 && size > 0,
+                        InvalidValue,
+                        "buffer texture range offset must not be negative and its size must be positive (got offset {offset}, size {size})"
+                    );
+                    #[expect(clippy::cast_sign_loss, reason = "values were checked to be positive")]
+                    let (offset, size) = (offset as usize, size as usize);
+                    let buffer_size = self.gl_state.buffer_list.get(buffer_name).size;
+                    gl_assert!(
+                        offset
+                            .checked_add(size)
+                            .is_some_and(|end| end <= buffer_size),
+                        InvalidValue,
+                        "buffer texture range of {size} bytes at offset {offset} exceeds the size of {buffer_name:?} ({buffer_size} bytes)"
+                    );
+                    let alignment =
+                        self.platform_state.props.texture_buffer_offset_alignment() as usize;
+                    gl_assert!(
+                        offset.is_multiple_of(alignment),
+                        InvalidValue,
+                        "buffer texture range offset {offset} is not a multiple of GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT ({alignment})"
+                    );
+                    (offset, Some(size))
+                }
+            };
+            Some(TextureBufferRange {
+                buffer: buffer_name,
+                format,
+                offset,
+                size,
+            })
+        };
+        gl_debug!("attaching {attachment:?} to {name:?}");
+        let tex = self.gl_state.texture_list.get_mut(name);
+        tex.buffer = attachment;
+        tex.realized = None;
+        Ok(())
+    }
+    /// Shared implementation of glTexImage1D/2D/3D. Records the format and extent of the specified image, deferring
+    /// allocation of backing storage until the texture is [realized](Self::realize_texture) by an upload or use
+    /// # Safety
+    /// `pixels` must be null, valid for reads of the specified image, or an offset into the pixel unpack buffer if one is bound
+    unsafe fn tex_image_internal(
+        &mut self,
+        target: TextureTarget,
+        dims: StorageDims,
+        level: GLint,
+        internalformat: InternalFormat,
+        extent: TextureExtent,
+        border: GLint,
+        format: PixelFormat,
+        ty: PixelType,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        let (unproxied, is_proxy) = target.strip_proxy();
+        gl_assert!(
+            dims.accepts_image(unproxied, is_proxy),
+            InvalidEnum,
+            "{target:?} is not a valid target for {dims:?}-dimensional texture images"
+        );
+        let level = validate_image(unproxied, level, internalformat, extent, border)?;
+        check_pixel_format(internalformat, format)?;
+        if is_proxy {
+            // OxideGL does not track proxy texture state, so only validate the parameters
+            return Ok(());
+        }
+        let (name, face) = self.specify_image_internal(target, level, internalformat, extent)?;
+        // Safety: forwarded from caller
+        unsafe {
+            self.texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [0; 3],
+                extent,
+                GlPixelTypeFormat::new(ty, format),
+                pixels,
+            )
+        }
+    }
+    /// Records the format and extent of the image at `level` of the texture bound to `target` (which must be a validated, non-proxy
+    /// glTexImage* or glCompressedTexImage* target). Returns the texture and the cube map face the image belongs to, if any
+    fn specify_image_internal(
+        &mut self,
+        target: TextureTarget,
+        level: u32,
+        internalformat: InternalFormat,
+        extent: TextureExtent,
+    ) -> GlFallible<(ObjectName<Texture>, Option<usize>)> {
+        let (bind_target, face) = match target.cube_face() {
+            Some(face) => (TextureTarget::TextureCubeMap, face),
+            None => (target, 0),
+        };
+        let name = self.bound_texture(bind_target)?;
+        let tex = self.gl_state.texture_list.get_mut(name);
+        gl_assert!(
+            !tex.immutable_format,
+            InvalidOperation,
+            "tried to re-specify an image of immutable-format texture {name:?}"
+        );
+        tex.specify_image(
+            level,
+            face,
+            TextureLevel {
+                format: internalformat,
+                extent,
+            },
+        );
+        Ok((
+            name,
+            (bind_target == TextureTarget::TextureCubeMap).then_some(face),
+        ))
+    }
+    /// Shared implementation of glCompressedTexImage1D/2D/3D. Records the format and extent of the specified image like
+    /// [`tex_image_internal`](Self::tex_image_internal), then uploads `image_size` bytes of blocks of `internalformat` from `data`
+    /// # Safety
+    /// `data` must be null, valid for reads of `image_size` bytes, or an offset into the pixel unpack buffer if one is bound
+    unsafe fn compressed_tex_image_internal(
+        &mut self,
+        target: TextureTarget,
+        dims: StorageDims,
+        level: GLint,
+        internalformat: InternalFormat,
+        extent: TextureExtent,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        let (unproxied, is_proxy) = target.strip_proxy();
+        gl_assert!(
+            dims.accepts_image(unproxied, is_proxy),
+            InvalidEnum,
+            "{target:?} is not a valid target for {dims:?}-dimensional compressed texture images"
+        );
+        let Some(block_size) = internalformat.compressed_block_size() else {
+            gl_err!(ty: Error, "{internalformat:?} is not a supported compressed internal format");
+            return Err(GlError::InvalidEnum.e());
+        };
+        if !compressed_format_supports(internalformat, unproxied) {
+            gl_err!(ty: Error, "compressed internal format {internalformat:?} may not be used with {target:?}");
+            return Err(match unproxied {
+                TextureTarget::Texture1D | TextureTarget::TextureRectangle => GlError::InvalidEnum,
+                _ => GlError::InvalidOperation,
+            }
+            .e());
+        }
+        let level = validate_image(unproxied, level, internalformat, extent, border)?;
+        sizei!(image_size);
+        let expected = compressed_size(block_size, extent);
+        gl_assert!(
+            image_size as usize == expected,
+            InvalidValue,
+            "image size {image_size} does not match the {expected} bytes of {internalformat:?} blocks in an image of extent {extent:?}"
+        );
+        if is_proxy {
+            // OxideGL does not track proxy texture state, so only validate the parameters
+            return Ok(());
+        }
+        let (name, face) = self.specify_image_internal(target, level, internalformat, extent)?;
+        // Safety: forwarded from caller
+        unsafe {
+            self.compressed_texture_sub_image_internal(
+                name,
+                level,
+                face,
+                [0; 3],
+                extent,
+                internalformat,
+                image_size,
+                data,
+            )
+        }
+    }
+    /// Resolves the texture and cube map face targeted by a non-DSA glTexSubImage* command
+    fn sub_image_target(
+        &mut self,
+        target: TextureTarget,
+        dims: StorageDims,
+    ) -> GlFallible<(ObjectName<Texture>, Option<usize>)> {
+        gl_assert!(
+            dims.accepts_image(target, false),
+            InvalidEnum,
+            "{target:?} is not a valid target for {dims:?}-dimensional texture sub-images"
+        );
+        match target.cube_face() {
+            Some(face) => Ok((
+                self.bound_texture(TextureTarget::TextureCubeMap)?,
+                Some(face),
+            )),
+            None => Ok((self.bound_texture(target)?, None)),
+        }
+    }
+    /// Checks that the target of the texture `name` is accepted by a DSA glTextureSubImage* command of the given dimensionality.
+    /// Cube maps are addressed as 6-layer arrays by the 3D variant
+    fn check_sub_image_texture(&self, name: ObjectName<Texture>, dims: StorageDims) -> GlFallible {
+        let target = self.gl_state.texture_list.get(name).target;
+        gl_assert!(
+            dims.accepts(target, false)
+                && (target != TextureTarget::TextureCubeMap || dims == StorageDims::Three),
+            InvalidEnum,
+            "{name:?} has target {target:?}, which is not valid for {dims:?}-dimensional texture sub-images"
+        );
+        Ok(())
+    }
+    /// Validates a region of the image at `level` of the texture `name` (and cube map face `face`, if specified) addressed by a
+    /// texture sub-image command. Returns the specification of the image, the range of cube map faces covered by the region (`0..1`
+    /// for other targets) and its offset. If the texture is a cube map and no face is given, the z offset and depth of the region
+    /// select the faces
+    fn image_region(
+        &self,
+        name: ObjectName<Texture>,
+        level: u32,
+        face: Option<usize>,
+        offset: [GLint; 3],
+        extent: TextureExtent,
+    ) -> GlFallible<(TextureLevel, Range<usize>, [u32; 3])> {
+        gl_assert!(
+            offset.iter().all(|&o| o >= 0),
+            InvalidValue,
+            "texture sub-image offsets must not be negative"
+        );
+        #[expect(clippy::cast_sign_loss, reason = "offsets were checked to be positive")]
+        let [x, y, z] = offset.map(|o| o as u32);
+        let tex = self.gl_state.texture_list.get(name);
+        let target = tex.target;
+        let is_cube = target == TextureTarget::TextureCubeMap;
+        // cube maps addressed by DSA commands are treated as arrays of their faces
+        let faces = match face {
+            Some(face) => face..face + 1,
+            None if is_cube => (z as usize)..(z + extent.depth) as usize,
+            None => 0..1,
+        };
+        gl_assert!(
+            faces.start < tex.faces() && faces.end <= tex.faces(),
+            InvalidValue,
+            "cube map faces {faces:?} out of range"
+        );
+        let image = tex.image(level, faces.start).ok_or_else(|| {
+            gl_err!(ty: Error, "level {level} of {name:?} has not been specified");
+            GlError::InvalidOperation.e()
+        })?;
+        gl_assert!(
+            faces.clone().all(|f| tex.image(level, f) == Some(image)),
+            InvalidOperation,
+            "all targeted faces of {name:?} must be specified with the same format and extent"
+        );
+        let (z_end, depth_limit) = if is_cube {
+            (1, 1)
+        } else {
+            (z + extent.depth, image.extent.depth)
+        };
+        gl_assert!(
+            x + extent.width <= image.extent.width
+                && y + extent.height <= image.extent.height
+                && z_end <= depth_limit,
+            InvalidValue,
+            "sub-image region at {offset:?} with extent {extent:?} lies outside of level {level} of {name:?} (extent {:?})",
+            image.extent
+        );
+        Ok((image, faces, [x, y, z]))
+    }
+    /// Shared implementation of the texture upload commands. Uploads a region of the image at `level` of the texture `name`
+    /// (and cube map face `face`, if specified) from client memory or the pixel unpack buffer. If the texture is a cube map and no
+    /// face is given, the z offset and depth of the region select the faces to upload to.
+    /// # Safety
+    /// `pixels` must be null, valid for reads of the specified image, or an offset into the pixel unpack buffer if one is bound
+    unsafe fn texture_sub_image_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        face: Option<usize>,
+        offset: [GLint; 3],
+        extent: TextureExtent,
+        fmt: GlPixelTypeFormat,
+        pixels: *const GLvoid,
+    ) -> GlFallible {
+        let (image, faces, [x, y, z]) = self.image_region(name, level, face, offset, extent)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        check_pixel_format(image.format, fmt.format())?;
+        gl_assert!(
+            !image.format.is_block_compressed(),
+            InvalidOperation,
+            "uncompressed pixel data may not be uploaded to a texture with compressed internal format {:?}",
+            image.format
+        );
+        let pixel_size = fmt.pixel_size().ok_or_else(|| {
+            gl_err!(ty: Error, "invalid pixel type and format combination {fmt:?}");
+            GlError::InvalidOperation.e()
+        })?;
+        let texels = extent.width as usize * extent.height as usize * extent.depth as usize;
+        if texels == 0 {
+            return Ok(());
+        }
+
+        let modes = self.gl_state.unpack_modes;
+        let layout = modes.layout(pixel_size, extent, transfer_dims(target, face));
+        let len = layout.len();
+
+        let mut unpack_buffer = None;
+        // Safety: forwarded from caller
+        let Some(src) = (unsafe { self.unpack_source(len, pixels, &mut unpack_buffer) })? else {
+            return Ok(());
+        };
+        let packed = layout.gather(src, modes.swap_bytes.then(|| fmt.element_size()));
+
+        let internal = image.format;
+        let data = if fmt
+            .binary_compatible_format()
+            .is_some_and(|f| f.matches(internal))
+        {
+            packed
+        } else {
+            gl_debug!("converting {fmt:?} pixel data to {internal:?} for upload");
+            let Some(converted) = convert_to_internal(&packed, fmt, internal) else {
+                gl_err!(ty: Error, "OxideGL does not support uploading {fmt:?} pixel data to a texture of internal format {internal:?}");
+                return Err(GlError::InvalidOperation.e());
+            };
+            converted
+        };
+
+        let planes = image_planes(&data, texels, internal);
+        let region = storage_region(target, faces.clone(), [x, y, z], extent);
+        let Some((mtl_tex, storage_level)) = self.level_storage(name, level, faces.start) else {
+            self.defer_image_write(name, level, faces, region, &planes, 1);
+            return Ok(());
+        };
+        self.write_image_region(&mtl_tex, storage_level, target, region, &planes, 1);
+        Ok(())
+    }
+    /// Shared implementation of the compressed texture upload commands. Uploads `image_size` bytes of blocks of the block-compressed
+    /// format `format` from client memory or the pixel unpack buffer into a block-aligned region of the image at `level` of the texture
+    /// `name`, addressed like in [`texture_sub_image_internal`](Self::texture_sub_image_internal). The blocks are decoded on the CPU if
+    /// the device does not support the format natively
+    /// # Safety
+    /// `data` must be null, valid for reads of `image_size` bytes, or an offset into the pixel unpack buffer if one is bound
+    unsafe fn compressed_texture_sub_image_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        face: Option<usize>,
+        offset: [GLint; 3],
+        extent: TextureExtent,
+        format: InternalFormat,
+        image_size: u32,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        let (image, faces, [x, y, z]) = self.image_region(name, level, face, offset, extent)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        let Some(block_size) = format.compressed_block_size() else {
+            gl_err!(ty: Error, "{format:?} is not a supported compressed internal format");
+            return Err(GlError::InvalidEnum.e());
+        };
+        gl_assert!(
+            format == image.format,
+            InvalidOperation,
+            "compressed data of format {format:?} does not match the internal format {:?} of level {level} of {name:?}",
+            image.format
+        );
+        check_block_alignment(image, [x, y], extent)?;
+        let len = compressed_size(block_size, extent);
+        gl_assert!(
+            image_size as usize == len,
+            InvalidValue,
+            "image size {image_size} does not match the {len} bytes of {format:?} blocks in a region of extent {extent:?}"
+        );
+        if len == 0 {
+            return Ok(());
+        }
+
+        let mut unpack_buffer = None;
+        // Safety: forwarded from caller
+        let Some(src) = (unsafe { self.unpack_source(len, data, &mut unpack_buffer) })? else {
+            return Ok(());
+        };
+        // storage of formats the device can't sample holds decompressed texels (see `RealizedTexture::new`)
+        let native = format.decompressed_equivalent().is_none()
+            || self
+                .platform_state
+                .props
+                .supports_compressed_format(format.mtl_texture_format());
+        let decoded;
+        let (plane, block) = if native {
+            (src, COMPRESSED_BLOCK_DIM)
+        } else {
+            gl_debug!("decompressing {format:?} blocks for upload to {name:?}");
+            decoded = decompress(
+                format,
+                src,
+                extent.width as usize,
+                extent.height as usize,
+                extent.depth as usize,
+            )
+            .expect("format should be block-compressed");
+            (&decoded[..], 1)
+        };
+        let planes = [(plane, MTLBlitOption::None)];
+        let region = storage_region(target, faces.clone(), [x, y, z], extent);
+        let Some((mtl_tex, storage_level)) = self.level_storage(name, level, faces.start) else {
+            self.defer_image_write(name, level, faces, region, &planes, block);
+            return Ok(());
+        };
+        self.write_image_region(&mtl_tex, storage_level, target, region, &planes, block);
+        Ok(())
+    }
+    /// Returns the source of `len` bytes of client data at `pixels` for an upload: either the pixel unpack buffer (if one is bound),
+    /// which is validated and kept alive by storing it in `unpack_buffer`, or client memory. Returns None if there is no data to
+    /// upload
+    /// # Safety
+    /// `pixels` must be null, valid for reads of `len` bytes, or an offset into the pixel unpack buffer if one is bound
+    unsafe fn unpack_source<'a>(
+        &mut self,
+        len: usize,
+        pixels: *const GLvoid,
+        unpack_buffer: &'a mut Option<ProtoObjRef<dyn MTLBuffer>>,
+    ) -> GlFallible<Option<&'a [u8]>> {
+        let Some(buf_name) = self.gl_state.buffer_bindings.pixel_unpack else {
+            if pixels.is_null() {
+                return Ok(None);
+            }
+            // Safety: caller ensures `pixels` is valid for reads of `len` bytes
+            return Ok(Some(unsafe {
+                slice::from_raw_parts(pixels.cast::<u8>(), len)
+            }));
+        };
+        self.wait_for_gpu_writes(buf_name);
+        let buf = self.gl_state.buffer_list.get(buf_name);
+        let offset = pixels.addr();
+        gl_assert!(
+            offset.checked_add(len).is_some_and(|end| end <= buf.size),
+            InvalidOperation,
+            "{len} bytes of pixel data at offset {offset} exceed the size of the pixel unpack buffer ({} bytes)",
+            buf.size
+        );
+        let Some(alloc) = &buf.allocation else {
+            gl_err!(ty: Error, "pixel unpack buffer {buf_name:?} has no storage");
+            return Err(GlError::InvalidOperation.e());
+        };
+        gl_assert!(
+            alloc.mapping.is_none(),
+            InvalidOperation,
+            "pixel unpack buffer {buf_name:?} may not be mapped during an upload"
+        );
+        let buffer = unpack_buffer.insert(alloc.mtl.clone());
+        // Safety: buffer storage is CPU-visible and the range was checked to lie within the buffer
+        Ok(Some(unsafe {
+            slice::from_raw_parts(buffer.contents().as_ptr().cast::<u8>().add(offset), len)
+        }))
+    }
+    /// Realizes the texture `name` and returns its backing Metal texture along with the level of it that stores `level` (and cube
+    /// map face `face`), or None if that level is not backed by the texture's storage
+    fn level_storage(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        face: usize,
+    ) -> Option<(ProtoObjRef<dyn MTLTexture>, u32)> {
+        self.realize_texture(name);
+        let tex = self.gl_state.texture_list.get(name);
+        let storage_level = tex.storage_level(level, face)?;
+        let realized = tex
+            .realized
+            .as_ref()
+            .expect("texture should have been realized");
+        Some((realized.mtl_tex.clone(), storage_level))
+    }
+    /// Keeps a copy of `planes`, which were to be written to `region` (given by [`storage_region`]) of the image at `level` and cube
+    /// map faces `faces` of the texture `name` by [`write_image_region`](Self::write_image_region), until the texture is realized with
+    /// storage that covers the level
+    fn defer_image_write(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        faces: Range<usize>,
+        region: (Range<usize>, MTLOrigin, MTLSize),
+        planes: &[(&[u8], MTLBlitOption)],
+        block: usize,
+    ) {
+        self.gl_state
+            .texture_list
+            .get_mut(name)
+            .defer_write(PendingWrite {
+                level,
+                faces,
+                region,
+                planes: planes
+                    .iter()
+                    .map(|&(plane, options)| (plane.to_vec(), options))
+                    .collect(),
+                block,
+            });
+    }
+    /// Copies tightly packed image data into the region of `storage_level` of `mtl_tex` given by [`storage_region`]. Each of
+    /// `planes` holds the data of every slice of the region in turn, along with the blit options that select the aspect of the
+    /// texture it is copied to. `block` is the side length of the texel blocks of block-compressed formats, and 1 otherwise
+    fn write_image_region(
+        &mut self,
+        mtl_tex: &ProtoObjRef<dyn MTLTexture>,
+        storage_level: u32,
+        target: TextureTarget,
+        (slices, origin, size): (Range<usize>, MTLOrigin, MTLSize),
+        planes: &[(&[u8], MTLBlitOption)],
+        block: usize,
+    ) {
+        let (first_slice, slices) = (slices.start, slices.len());
+        for &(plane, options) in planes {
+            let slice_bytes = plane.len() / slices;
+            let bytes_per_row = slice_bytes / (size.depth * size.height.div_ceil(block));
+            let bytes_per_image = if target == TextureTarget::Texture3D {
+                bytes_per_row * size.height.div_ceil(block)
+            } else {
+                0
+            };
+            // Metal requires source offsets to be 4-byte aligned on macOS, so pad each slice accordingly
+            let slice_stride = slice_bytes.next_multiple_of(4);
+            let mut staging_data = vec![0u8; slice_stride * slices];
+            for (dst, src) in staging_data
+                .chunks_mut(slice_stride)
+                .zip(plane.chunks(slice_bytes))
+            {
+                dst[..slice_bytes].copy_from_slice(src);
+            }
+            let staging = self
+                .platform_state
+                .device
+                .newBufferWithLength_options(
+                    staging_data.len(),
+                    MTLResourceOptions::StorageModeShared,
+                )
+                .expect("failed to allocate texture upload staging buffer");
+            // Safety: the staging buffer is CPU-visible and was allocated with the same length as `staging_data`
+            unsafe {
+                staging
+                    .contents()
+                    .as_ptr()
+                    .cast::<u8>()
+                    .copy_from_nonoverlapping(staging_data.as_ptr(), staging_data.len());
+            }
+            self.platform_state.encode_blit(|enc| {
+                for slice in 0..slices {
+                    // Safety: the copied region was checked to lie within the destination level, and the staging buffer holds
+                    // `slices` slices of `slice_stride` bytes, each containing tightly packed texels (or texel blocks) in the texture's
+                    // pixel format (or the aspect of it selected by `options`)
+                    unsafe {
+                        enc.copyFromBuffer_sourceOffset_sourceBytesPerRow_sourceBytesPerImage_sourceSize_toTexture_destinationSlice_destinationLevel_destinationOrigin_options(
+                            &staging,
+                            slice * slice_stride,
+                            bytes_per_row,
+                            bytes_per_image,
+                            size,
+                            mtl_tex,
+                            first_slice + slice,
+                            storage_level as usize,
+                            origin,
+                            options,
+                        );
+                    }
+                }
+            });
+        }
+    }
+    /// Resolves the texture and cube map face targeted by a non-DSA texture image readback command
+    fn readback_target(
+        &mut self,
+        target: TextureTarget,
+    ) -> GlFallible<(ObjectName<Texture>, Option<usize>)> {
+        if let Some(face) = target.cube_face() {
+            return Ok((
+                self.bound_texture(TextureTarget::TextureCubeMap)?,
+                Some(face),
+            ));
+        }
+        gl_assert!(
+            matches!(
+                target,
+                TextureTarget::Texture1D
+                    | TextureTarget::Texture2D
+                    | TextureTarget::Texture3D
+                    | TextureTarget::Texture1DArray
+                    | TextureTarget::Texture2DArray
+                    | TextureTarget::TextureRectangle
+                    | TextureTarget::TextureCubeMapArray
+            ),
+            InvalidEnum,
+            "{target:?} is not a valid target for texture image readback"
+        );
+        Ok((self.bound_texture(target)?, None))
+    }
+    /// Checks that the texture `name` may be read back by a DSA texture image readback command. Cube maps are read back as
+    /// 6-layer arrays
+    fn check_readback_texture(&self, name: ObjectName<Texture>) -> GlFallible {
+        let target = self.gl_state.texture_list.get(name).target;
+        gl_assert!(
+            !matches!(
+                target,
+                TextureTarget::TextureBuffer
+                    | TextureTarget::Texture2DMultisample
+                    | TextureTarget::Texture2DMultisampleArray
+            ),
+            InvalidOperation,
+            "{name:?} has target {target:?}, which can not be read back"
+        );
+        Ok(())
+    }
+    /// Shared implementation of the texture image readback commands. Reads back a region of the image at `level` of the texture
+    /// `name` (and cube map face `face`, if specified) into client memory or the pixel pack buffer, as pixels of format `fmt` or
+    /// as raw compressed blocks if `fmt` is None. If `extent` is None the whole image is read back, in which case nothing is read
+    /// back if the image has not been specified. As with uploads, the z offset and depth of the region select the faces of a cube
+    /// map if no face is given.
+    /// # Safety
+    /// `pixels` must be null, valid for writes of the client image, or an offset into the pixel pack buffer if one is bound
+    unsafe fn get_texture_image_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        face: Option<usize>,
+        offset: [GLint; 3],
+        extent: Option<TextureExtent>,
+        fmt: Option<GlPixelTypeFormat>,
+        buf_size: Option<usize>,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        let tex = self.gl_state.texture_list.get(name);
+        let target = tex.target;
+        gl_assert!(
+            level == 0 || target != TextureTarget::TextureRectangle,
+            InvalidValue,
+            "rectangle textures only have a single level"
+        );
+        let whole_image = || {
+            let image = tex.image(level, face.unwrap_or(0))?;
+            let faces = if face.is_none() { tex.faces() } else { 1 };
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "textures have at most 6 faces"
+            )]
+            let depth = image.extent.depth * faces as u32;
+            Some(TextureExtent::new(
+                image.extent.width,
+                image.extent.height,
+                depth,
+            ))
+        };
+        let Some(extent) = extent.or_else(whole_image) else {
+            gl_debug!("level {level} of {name:?} has not been specified, nothing to read back");
+            return Ok(());
+        };
+        let (image, faces, [x, y, z]) = self.image_region(name, level, face, offset, extent)?;
+        if fmt.is_none() {
+            gl_assert!(
+                image.format.is_block_compressed(),
+                InvalidOperation,
+                "tried to read back compressed data from {name:?}, which has uncompressed internal format {:?}",
+                image.format
+            );
+            check_block_alignment(image, [x, y], extent)?;
+        }
+
+        self.realize_texture(name);
+        let tex = self.gl_state.texture_list.get(name);
+        let Some(storage_level) = tex.storage_level(level, faces.start) else {
+            gl_warn!(
+                "not reading back level {level} of {name:?}: the level is not part of the texture's consistent mipmap chain"
+            );
+            return Ok(());
+        };
+        let realized = tex
+            .realized
+            .as_ref()
+            .expect("texture should have been realized");
+        let (slices, origin, size) = storage_region(target, faces, [x, y, z], extent);
+        let region = ReadbackRegion {
+            tex: realized.mtl_tex.clone(),
+            format: image.format,
+            level: storage_level as usize,
+            slices,
+            origin,
+            size,
+            extent,
+            client_offset: [0, 0],
+            flip_y: false,
+        };
+        // Safety: forwarded from caller
+        unsafe {
+            match fmt {
+                Some(fmt) => self.read_back_internal(
+                    &region,
+                    extent,
+                    transfer_dims(target, face),
+                    fmt,
+                    buf_size,
+                    pixels,
+                ),
+                None => self.read_back_compressed_internal(&region, buf_size, pixels),
+            }
+        }
+    }
+    /// Shared implementation of the pixel readback commands. Reads `region` back into client memory or the pixel pack buffer as part of
+    /// a `dims`-dimensional client image of `image` texels, converting from the region's internal format to `fmt` and applying the
+    /// pixel pack modes. `buf_size` limits the size of the client image for the robust (`glReadn*`/`glGetn*`) commands.
+    ///
+    /// When a pixel pack buffer is bound and no CPU-side conversion is needed, the data is copied into the buffer by the GPU without
+    /// waiting for it to complete. Otherwise, this function blocks until the GPU has finished all previously submitted work.
+    /// # Safety
+    /// `pixels` must be null, valid for writes of the packed client image, or an offset into the pixel pack buffer if one is bound
+    pub(crate) unsafe fn read_back_internal(
+        &mut self,
+        region: &ReadbackRegion,
+        image: TextureExtent,
+        dims: u32,
+        fmt: GlPixelTypeFormat,
+        buf_size: Option<usize>,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        let pixel_size = fmt.pixel_size().ok_or_else(|| {
+            gl_err!(ty: Error, "invalid pixel type and format combination {fmt:?}");
+            GlError::InvalidOperation.e()
+        })?;
+        check_pack_format(region.format, fmt.format())?;
+        let modes = self.gl_state.pack_modes;
+        let len = modes.layout(pixel_size, image, dims).len();
+        if let Some(buf_size) = buf_size {
+            gl_assert!(
+                len <= buf_size,
+                InvalidOperation,
+                "{len} bytes of pixel data do not fit in a buffer of {buf_size} bytes"
+            );
+        }
+
+        // validate the destination before doing any work
+        let pack_buffer = self.pack_buffer_target(len, pixels)?;
+        if pack_buffer.is_none() && pixels.is_null() {
+            return Ok(());
+        }
+        let MTLSize {
+            width,
+            height,
+            depth,
+        } = region.size;
+        if width * height * depth * region.slices.len() == 0 {
+            return Ok(());
+        }
+
+        // position the region within the client image
+        let mut region_modes = modes;
+        if region_modes.row_length == 0 {
+            region_modes.row_length = image.width;
+        }
+        if dims >= 3 && region_modes.image_height == 0 {
+            region_modes.image_height = image.height;
+        }
+        region_modes.skip_pixels += region.client_offset[0];
+        region_modes.skip_rows += region.client_offset[1];
+        let layout = region_modes.layout(pixel_size, region.extent, dims);
+        let swap = modes.swap_bytes.then(|| fmt.element_size());
+
+        let internal = region.format;
+        let mtl_format = region.tex.pixelFormat();
+        let is_depth_stencil = internal.has_depth() && internal.has_stencil();
+        let binary_compatible = mtl_format == internal.mtl_texture_format()
+            && fmt
+                .binary_compatible_format()
+                .is_some_and(|f| f.matches(internal));
+
+        if let Some(target) = &pack_buffer {
+            // The texels can be copied straight into the pack buffer if they need no conversion and every row of the client image
+            // satisfies Metal's alignment requirements
+            let direct = mtl_texel_size(mtl_format).filter(|&texel_size| {
+                binary_compatible
+                    && !is_depth_stencil
+                    && swap.is_none()
+                    && (target.offset + layout.offset).is_multiple_of(texel_size)
+                    && layout.row_stride.is_multiple_of(texel_size)
+                    && layout.image_stride.is_multiple_of(texel_size)
+            });
+            if direct.is_some() {
+                gl_debug!(
+                    "reading back {region:?} directly into pixel pack buffer {:?}",
+                    target.name
+                );
+                // Rows of the region in the order that Metal stores them (slice-major, then depth, then rows), mapped to the index of
+                // the corresponding row in the client image
+                let client_row = |slice: usize, z: usize, y: usize| {
+                    let y = if region.flip_y { height - 1 - y } else { y };
+                    (slice * depth + z) * height + y
+                };
+                let client_row_offset = |row: usize| {
+                    layout.offset
+                        + (row / layout.rows) * layout.image_stride
+                        + (row % layout.rows) * layout.row_stride
+                };
+                self.platform_state.encode_blit(|enc| {
+                    for (i, slice) in region.slices.clone().enumerate() {
+                        // flipped regions must be copied row by row, since Metal can only copy rows in ascending order
+                        let copies: Vec<_> = if region.flip_y {
+                            (0..depth)
+                                .flat_map(|z| (0..height).map(move |y| (z, y, 1, 1)))
+                                .collect()
+                        } else {
+                            vec![(0, 0, height, depth)]
+                        };
+                        for (z, y, rows, images) in copies {
+                            let origin = MTLOrigin {
+                                x: region.origin.x,
+                                y: region.origin.y + y,
+                                z: region.origin.z + z,
+                            };
+                            let size = MTLSize {
+                                width,
+                                height: rows,
+                                depth: images,
+                            };
+                            let bytes_per_image = if images > 1 { layout.image_stride } else { 0 };
+                            // Safety: the source region lies within the texture, and the destination range was checked to lie
+                            // within the pack buffer, with offsets and strides that are multiples of the texel size
+                            unsafe {
+                                enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toBuffer_destinationOffset_destinationBytesPerRow_destinationBytesPerImage(
+                                    &region.tex,
+                                    slice,
+                                    region.level,
+                                    origin,
+                                    size,
+                                    &target.mtl,
+                                    target.offset + client_row_offset(client_row(i, z, y)),
+                                    layout.row_stride,
+                                    bytes_per_image,
+                                );
+                            }
+                        }
+                    }
+                });
+                self.mark_gpu_writes_pending(target.name);
+                return Ok(());
+            }
+            gl_warn!(ty: Performance, "pixel pack buffer readback of {region:?} requires CPU-side conversion to {fmt:?} and will stall");
+        }
+
+        let (data, mtl_format) = if internal.is_block_compressed()
+            && mtl_format == internal.mtl_texture_format()
+        {
+            self.read_back_decompressed(region)
+        } else {
+            // depth-stencil images are read back as separate depth and stencil planes, which is the layout the converter expects
+            let planes = if is_depth_stencil {
+                vec![
+                    (size_of::<f32>(), MTLBlitOption::DepthFromDepthStencil),
+                    (size_of::<u8>(), MTLBlitOption::StencilFromDepthStencil),
+                ]
+            } else {
+                let Some(texel_size) = mtl_texel_size(mtl_format) else {
+                    gl_err!(ty: Error, "OxideGL does not support reading back pixels of Metal pixel format {mtl_format:?}");
+                    return Ok(());
+                };
+                vec![(texel_size, MTLBlitOption::None)]
+            };
+            (self.read_back_staged(region, &planes, 1), mtl_format)
+        };
+        let mut packed = if binary_compatible {
+            data
+        } else {
+            gl_debug!(
+                "converting {internal:?} ({mtl_format:?}) pixel data to {fmt:?} for readback"
+            );
+            let Some(converted) = convert_from_internal(&data, internal, mtl_format, fmt) else {
+                gl_err!(ty: Error, "OxideGL does not support reading back pixels of internal format {internal:?} as {fmt:?}");
+                return Ok(());
+            };
+            converted
+        };
+        if region.flip_y {
+            let row_bytes = width * pixel_size;
+            for image in packed.chunks_exact_mut(row_bytes * height) {
+                flip_rows(image, row_bytes);
+            }
+        }
+
+        let dst = if let Some(target) = &pack_buffer {
+            // Safety: the range was checked to lie within the pack buffer
+            unsafe { self.pack_buffer_contents(target, layout.len()) }
+        } else {
+            // Safety: caller ensures `pixels` is valid for writes of the client image, which contains the region
+            unsafe { slice::from_raw_parts_mut(pixels.cast::<u8>(), layout.len()) }
+        };
+        layout.scatter(&packed, dst, swap);
+        Ok(())
+    }
+    /// Shared implementation of the compressed texture image readback commands. Reads the raw blocks of `region`, which must be of a
+    /// block-compressed format, back into client memory or the pixel pack buffer, tightly packed in the order Metal stores them.
+    /// `buf_size` limits the size of the client image for `glGetnCompressedTexImage`
+    /// # Safety
+    /// `pixels` must be null, valid for writes of the compressed image, or an offset into the pixel pack buffer if one is bound
+    pub(crate) unsafe fn read_back_compressed_internal(
+        &mut self,
+        region: &ReadbackRegion,
+        buf_size: Option<usize>,
+        pixels: *mut GLvoid,
+    ) -> GlFallible {
+        let Some(block_size) = region.format.compressed_block_size() else {
+            gl_err!(ty: Error, "tried to read back uncompressed {:?} image as compressed data", region.format);
+            return Err(GlError::InvalidOperation.e());
+        };
+        let MTLSize {
+            width,
+            height,
+            depth,
+        } = region.size;
+        if region.tex.pixelFormat() != region.format.mtl_texture_format() {
+            gl_err!(ty: Error, "OxideGL does not support reading back compressed data of {:?} images, which are stored decompressed on this device", region.format);
+            return Ok(());
+        }
+        let bytes_per_row = width.div_ceil(COMPRESSED_BLOCK_DIM) * block_size;
+        let bytes_per_image = bytes_per_row * height.div_ceil(COMPRESSED_BLOCK_DIM);
+        let slice_bytes = bytes_per_image * depth;
+        let len = slice_bytes * region.slices.len();
+        if let Some(buf_size) = buf_size {
+            gl_assert!(
+                len <= buf_size,
+                InvalidOperation,
+                "{len} bytes of compressed image data do not fit in a buffer of {buf_size} bytes"
+            );
+        }
+        let pack_buffer = self.pack_buffer_target(len, pixels)?;
+        if (pack_buffer.is_none() && pixels.is_null()) || len == 0 {
+            return Ok(());
+        }
+
+        if let Some(target) = &pack_buffer
+            && target.offset.is_multiple_of(block_size)
+        {
+            gl_debug!(
+                "reading back compressed {region:?} directly into pixel pack buffer {:?}",
+                target.name
+            );
+            self.platform_state.encode_blit(|enc| {
+                for (i, slice) in region.slices.clone().enumerate() {
+                    // Safety: the source region lies within the texture and is block-aligned, and the destination range was checked to
+                    // lie within the pack buffer at an offset that is a multiple of the block size
+                    unsafe {
+                        enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toBuffer_destinationOffset_destinationBytesPerRow_destinationBytesPerImage(
+                            &region.tex,
+                            slice,
+                            region.level,
+                            region.origin,
+                            region.size,
+                            &target.mtl,
+                            target.offset + i * slice_bytes,
+                            bytes_per_row,
+                            if depth > 1 { bytes_per_image } else { 0 },
+                        );
+                    }
+                }
+            });
+            self.mark_gpu_writes_pending(target.name);
+            return Ok(());
+        }
+
+        let data = self.read_back_staged(
+            region,
+            &[(block_size, MTLBlitOption::None)],
+            COMPRESSED_BLOCK_DIM,
+        );
+        let dst = if let Some(target) = &pack_buffer {
+            gl_warn!(ty: Performance, "compressed pixel pack buffer readback into misaligned offset {} will stall", target.offset);
+            // Safety: the range was checked to lie within the pack buffer
+            unsafe { self.pack_buffer_contents(target, len) }
+        } else {
+            // Safety: caller ensures `pixels` is valid for writes of the compressed image
+            unsafe { slice::from_raw_parts_mut(pixels.cast::<u8>(), len) }
+        };
+        dst.copy_from_slice(&data);
+        Ok(())
+    }
+    /// Reads back `region` of a texture stored in a block-compressed format and decodes it on the CPU. Returns the tightly packed texels
+    /// of each slice of the region, along with the Metal pixel format they are laid out in
+    fn read_back_decompressed(&mut self, region: &ReadbackRegion) -> (Vec<u8>, MTLPixelFormat) {
+        let format = region.format;
+        let block_size = format
+            .compressed_block_size()
+            .expect("region should be of a block-compressed format");
+        let decoded_format = format
+            .decompressed_equivalent()
+            .expect("block-compressed formats should have a decompressed equivalent")
+            .mtl_texture_format();
+        let texel_size =
+            mtl_texel_size(decoded_format).expect("decompressed formats should not be compressed");
+        // Metal can only copy whole blocks out of compressed textures, so read back the blocks covering the region and crop them
+        let MTLSize {
+            width,
+            height,
+            depth,
+        } = region.size;
+        let level_width = (region.tex.width() >> region.level).max(1);
+        let level_height = (region.tex.height() >> region.level).max(1);
+        let origin = MTLOrigin {
+            x: region.origin.x / COMPRESSED_BLOCK_DIM * COMPRESSED_BLOCK_DIM,
+            y: region.origin.y / COMPRESSED_BLOCK_DIM * COMPRESSED_BLOCK_DIM,
+            z: region.origin.z,
+        };
+        let size = MTLSize {
+            width: (region.origin.x + width)
+                .next_multiple_of(COMPRESSED_BLOCK_DIM)
+                .min(level_width)
+                - origin.x,
+            height: (region.origin.y + height)
+                .next_multiple_of(COMPRESSED_BLOCK_DIM)
+                .min(level_height)
+                - origin.y,
+            depth,
+        };
+        let blocks = ReadbackRegion {
+            tex: region.tex.clone(),
+            slices: region.slices.clone(),
+            origin,
+            size,
+            ..*region
+        };
+        gl_debug!("decompressing {format:?} blocks of {blocks:?} for readback");
+        let data = self.read_back_staged(
+            &blocks,
+            &[(block_size, MTLBlitOption::None)],
+            COMPRESSED_BLOCK_DIM,
+        );
+        let images = depth * region.slices.len();
+        let decoded = decompress(format, &data, size.width, size.height, images)
+            .expect("region should be of a block-compressed format");
+
+        let (skip_x, skip_y) = (region.origin.x - origin.x, region.origin.y - origin.y);
+        let row_bytes = width * texel_size;
+        let mut texels = Vec::with_capacity(row_bytes * height * images);
+        for image in decoded.chunks_exact(size.width * size.height * texel_size) {
+            for row in image
+                .chunks_exact(size.width * texel_size)
+                .skip(skip_y)
+                .take(height)
+            {
+                texels.extend_from_slice(&row[skip_x * texel_size..][..row_bytes]);
+            }
+        }
+        (texels, decoded_format)
+    }
+    /// Validates the pixel pack buffer (if one is bound) as the destination of `len` bytes of client data at offset `pixels`
+    fn pack_buffer_target(
+        &self,
+        len: usize,
+        pixels: *mut GLvoid,
+    ) -> GlFallible<Option<PackBufferTarget>> {
+        let Some(name) = self.gl_state.buffer_bindings.pixel_pack else {
+            return Ok(None);
+        };
+        let buf = self.gl_state.buffer_list.get(name);
+        let offset = pixels.addr();
+        gl_assert!(
+            offset.checked_add(len).is_some_and(|end| end <= buf.size),
+            InvalidOperation,
+            "{len} bytes of pixel data at offset {offset} exceed the size of the pixel pack buffer ({} bytes)",
+            buf.size
+        );
+        let Some(alloc) = &buf.allocation else {
+            gl_err!(ty: Error, "pixel pack buffer {name:?} has no storage");
+            return Err(GlError::InvalidOperation.e());
+        };
+        gl_assert!(
+            alloc.mapping.is_none(),
+            InvalidOperation,
+            "pixel pack buffer {name:?} may not be mapped during a readback"
+        );
+        Ok(Some(PackBufferTarget {
+            name,
+            mtl: alloc.mtl.clone(),
+            offset,
+        }))
+    }
+    /// Returns the `len` bytes of the pixel pack buffer at `target` for writing by the CPU, waiting for any outstanding GPU writes
+    /// to the buffer to complete first
+    /// # Safety
+    /// The range must lie within the buffer, and no other references to its contents may be live
+    unsafe fn pack_buffer_contents(&mut self, target: &PackBufferTarget, len: usize) -> &mut [u8] {
+        self.wait_for_gpu_writes(target.name);
+        // Safety: buffer storage is CPU-visible, the GPU is no longer writing to it and the caller ensures the range is in bounds
+        unsafe {
+            slice::from_raw_parts_mut(
+                target
+                    .mtl
+                    .contents()
+                    .as_ptr()
+                    .cast::<u8>()
+                    .add(target.offset),
+                len,
+            )
+        }
+    }
+    /// Copies `region` into CPU-visible staging memory and waits for the copy to complete. Returns the tightly packed contents of
+    /// each slice of the region, for each of `planes` in turn. A plane is given as the size in bytes of each texel (or of each
+    /// `block`×`block` texel block, for block-compressed formats) along with the blit options that select it
+    fn read_back_staged(
+        &mut self,
+        region: &ReadbackRegion,
+        planes: &[(usize, MTLBlitOption)],
+        block: usize,
+    ) -> Vec<u8> {
+        let MTLSize {
+            width,
+            height,
+            depth,
+        } = region.size;
+        let slices = region.slices.len();
+        let mut staging_buffers = Vec::with_capacity(planes.len());
+        for &(texel_size, options) in planes {
+            let bytes_per_row = texel_size * width.div_ceil(block);
+            let bytes_per_image = bytes_per_row * height.div_ceil(block);
+            let slice_bytes = bytes_per_image * depth;
+            // Metal requires destination offsets to be multiples of the texel size, so pad each slice accordingly
+            let slice_stride = slice_bytes.next_multiple_of(16);
+            let staging = self
+                .platform_state
+                .device
+                .newBufferWithLength_options(
+                    slice_stride * slices,
+                    MTLResourceOptions::StorageModeShared,
+                )
+                .expect("failed to allocate pixel readback staging buffer");
+            self.platform_state.encode_blit(|enc| {
+                for (i, slice) in region.slices.clone().enumerate() {
+                    // Safety: the source region lies within the texture and the staging buffer holds `slices` slices of `slice_stride` bytes
+                    unsafe {
+                        enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toBuffer_destinationOffset_destinationBytesPerRow_destinationBytesPerImage_options(
+                            &region.tex,
+                            slice,
+                            region.level,
+                            region.origin,
+                            region.size,
+                            &staging,
+                            i * slice_stride,
+                            bytes_per_row,
+                            if depth > 1 { bytes_per_image } else { 0 },
+                            options,
+                        );
+                    }
+                }
+            });
+            staging_buffers.push((staging, slice_bytes, slice_stride));
+        }
+        self.platform_state.finish();
+
+        let mut data = Vec::new();
+        for (staging, slice_bytes, slice_stride) in &staging_buffers {
+            // Safety: the staging buffer is CPU-visible and the GPU has finished writing to it
+            let contents = unsafe {
+                slice::from_raw_parts(
+                    staging.contents().as_ptr().cast::<u8>(),
+                    slice_stride * slices,
+                )
+            };
+            for slice in contents.chunks(*slice_stride) {
+                data.extend_from_slice(&slice[..*slice_bytes]);
+            }
+        }
+        data
+    }
+    /// Shared implementation of glCopyTexImage1D/2D. Specifies the image at `level` of the texture bound to `target` (which must be a
+    /// validated, non-proxy target) and fills it with the `extent` rectangle of the read framebuffer whose lower left corner is at
+    /// window coordinates `origin`
+    fn copy_tex_image_internal(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        origin: [GLint; 2],
+        extent: TextureExtent,
+        border: GLint,
+    ) -> GlFallible {
+        let level = validate_image(target, level, internalformat, extent, border)?;
+        gl_assert!(
+            !internalformat.is_block_compressed(),
+            InvalidOperation,
+            "framebuffer pixels may not be copied into an image of compressed internal format {internalformat:?}"
+        );
+        let (name, face) = self.specify_image_internal(target, level, internalformat, extent)?;
+        self.copy_tex_sub_image_internal(name, level, face, [0; 3], origin, extent)
+    }
+    /// Shared implementation of the glCopyTexSubImage* and glCopyTextureSubImage* commands. Copies the `extent` rectangle of the read
+    /// framebuffer whose lower left corner is at window coordinates `origin` into a region of the image at `level` of the texture
+    /// `name`, addressed like in [`texture_sub_image_internal`](Self::texture_sub_image_internal). Rows of 1D array textures are
+    /// copied to consecutive layers
+    fn copy_tex_sub_image_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        face: Option<usize>,
+        offset: [GLint; 3],
+        origin: [GLint; 2],
+        extent: TextureExtent,
+    ) -> GlFallible {
+        let (image, faces, [x, y, z]) = self.image_region(name, level, face, offset, extent)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        let internal = image.format;
+        gl_assert!(
+            !internal.is_block_compressed(),
+            InvalidOperation,
+            "framebuffer pixels may not be copied into an image of compressed internal format {internal:?}"
+        );
+        // the destination format selects the framebuffer image that is read from
+        let format = match (internal.has_depth(), internal.has_stencil()) {
+            (true, true) => PixelFormat::DepthStencil,
+            (true, false) => PixelFormat::DepthComponent,
+            (false, true) => PixelFormat::StencilIndex,
+            (false, false) => PixelFormat::Rgba,
+        };
+        let Some(src) =
+            self.read_framebuffer_region(format, origin, extent.width, extent.height)?
+        else {
+            return Ok(());
+        };
+        gl_assert!(
+            src.format.is_integer() == internal.is_integer(),
+            InvalidOperation,
+            "can't copy framebuffer pixels of format {:?} into an image of format {internal:?}: exactly one of them is an integer format",
+            src.format
+        );
+        if src.extent.is_empty() {
+            return Ok(());
+        }
+        let [dx, dy] = src.client_offset;
+        let dst = storage_region(target, faces.clone(), [x + dx, y + dy, z], src.extent);
+        let Some((mtl_tex, storage_level)) = self.level_storage(name, level, faces.start) else {
+            gl_warn!(ty: Performance, "copying framebuffer pixels to level {level} of {name:?}, which has no storage yet, requires reading them back and will stall");
+            if let Some(texels) = self.read_framebuffer_texels(&src, internal) {
+                let planes = image_planes(&texels, src.size.width * src.size.height, internal);
+                self.defer_image_write(name, level, faces, dst, &planes, 1);
+            }
+            return Ok(());
+        };
+        gl_debug!("copying {src:?} to level {level} of {name:?}");
+        self.copy_framebuffer_region(&src, &mtl_tex, storage_level, target, internal, dst);
+        Ok(())
+    }
+    /// Copies the framebuffer region `src` into the region `dst` (given by [`storage_region`]) of `storage_level` of `dst_tex`, which
+    /// stores images of `target` and internal format `dst_format`. Pixels are converted on the CPU if the Metal pixel formats differ
+    fn copy_framebuffer_region(
+        &mut self,
+        src: &ReadbackRegion,
+        dst_tex: &ProtoObjRef<dyn MTLTexture>,
+        storage_level: u32,
+        target: TextureTarget,
+        dst_format: InternalFormat,
+        (slices, origin, size): (Range<usize>, MTLOrigin, MTLSize),
+    ) {
+        let src_mtl = src.tex.pixelFormat();
+        let (width, height) = (src.size.width, src.size.height);
+        if src_mtl == dst_tex.pixelFormat() {
+            self.platform_state.encode_blit(|enc| {
+                // the default framebuffer's rows are stored top to bottom, so copy them one at a time to flip them
+                for row in 0..height {
+                    let src_row = if src.flip_y { height - 1 - row } else { row };
+                    let src_origin = MTLOrigin {
+                        y: src.origin.y + src_row,
+                        ..src.origin
+                    };
+                    let (slice, dst_origin) = if target == TextureTarget::Texture1DArray {
+                        (slices.start + row, origin)
+                    } else {
+                        (
+                            slices.start,
+                            MTLOrigin {
+                                y: origin.y + row,
+                                ..origin
+                            },
+                        )
+                    };
+                    // Safety: both regions were clipped to lie within their textures, which have the same pixel format
+                    unsafe {
+                        enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toTexture_destinationSlice_destinationLevel_destinationOrigin(
+                            &src.tex,
+                            src.slices.start,
+                            src.level,
+                            src_origin,
+                            MTLSize {
+                                width,
+                                height: 1,
+                                depth: 1,
+                            },
+                            dst_tex,
+                            slice,
+                            storage_level as usize,
+                            dst_origin,
+                        );
+                    }
+                }
+            });
+            return;
+        }
+
+        gl_warn!(ty: Performance, "copying framebuffer pixels of format {:?} to {dst_format:?} requires CPU-side conversion and will stall", src.format);
+        let Some(converted) = self.read_framebuffer_texels(src, dst_format) else {
+            return;
+        };
+        let planes = image_planes(&converted, width * height, dst_format);
+        self.write_image_region(
+            dst_tex,
+            storage_level,
+            target,
+            (slices, origin, size),
+            &planes,
+            1,
+        );
+    }
+    /// Reads the framebuffer region `src` back to the CPU and converts it to tightly packed texels of `dst_format`, with rows
+    /// ordered bottom to top. Returns None if the conversion is not supported
+    fn read_framebuffer_texels(
+        &mut self,
+        src: &ReadbackRegion,
+        dst_format: InternalFormat,
+    ) -> Option<Vec<u8>> {
+        let src_mtl = src.tex.pixelFormat();
+        let width = src.size.width;
+        let is_depth_stencil = src.format.has_depth() && src.format.has_stencil();
+        let planes = if is_depth_stencil {
+            vec![
+                (size_of::<f32>(), MTLBlitOption::DepthFromDepthStencil),
+                (size_of::<u8>(), MTLBlitOption::StencilFromDepthStencil),
+            ]
+        } else {
+            let Some(texel_size) = mtl_texel_size(src_mtl) else {
+                gl_err!(ty: Error, "OxideGL does not support copying framebuffer pixels of Metal pixel format {src_mtl:?}");
+                return None;
+            };
+            vec![(texel_size, MTLBlitOption::None)]
+        };
+        let mut data = self.read_back_staged(src, &planes, 1);
+        let texels = width * src.size.height;
+        let mut rest = &mut data[..];
+        if src.flip_y {
+            for &(texel_size, _) in &planes {
+                let (plane, tail) = rest.split_at_mut(texels * texel_size);
+                flip_rows(plane, width * texel_size);
+                rest = tail;
+            }
+        }
+        let converted = convert_internal(&data, src.format, src_mtl, dst_format);
+        if converted.is_none() {
+            gl_err!(ty: Error, "OxideGL does not support copying framebuffer pixels of format {:?} to {dst_format:?}", src.format);
+        }
+        converted
+    }
+    /// Validates one end of a glCopyImageSubData copy, returning the texture it names and its level
+    fn copy_image_endpoint(
+        &self,
+        name: GLuint,
+        target: CopyImageSubDataTarget,
+        level: GLint,
+    ) -> GlFallible<(ObjectName<Texture>, u32)> {
+        if target == CopyImageSubDataTarget::Renderbuffer {
+            self.renderbuffer_by_name(name)
+                .map_err(|_| GlError::InvalidValue.e())?;
+            gl_err!(ty: Error, "OxideGL does not support copying image data to or from renderbuffers");
+            return Err(GlError::InvalidOperation.e());
+        }
+        let tex_name = self
+            .texture_by_name(name)
+            .map_err(|_| GlError::InvalidValue.e())?;
+        let tex = self.gl_state.texture_list.get(tex_name);
+        gl_assert!(
+            u32::from(tex.target) == u32::from(target),
+            InvalidEnum,
+            "{target:?} does not match the target {:?} of {tex_name:?}",
+            tex.target
+        );
+        gl_assert!(
+            tex.is_complete(&tex.sampling_state),
+            InvalidOperation,
+            "{tex_name:?} is not complete and may not be copied to or from"
+        );
+        sizei!(level);
+        gl_assert!(
+            tex.image(level, 0).is_some(),
+            InvalidValue,
+            "level {level} of {tex_name:?} has not been specified"
+        );
+        Ok((tex_name, level))
+    }
+    /// Copies `src` into the region `dst` (given by [`storage_region`]) of `dst_level` of `dst_tex`, which stores images of `target`
+    /// and internal format `dst_format`. The formats must be copy-compatible, and if exactly one of them is block-compressed each texel
+    /// of the uncompressed image corresponds to a block of the compressed one
+    fn copy_image_region(
+        &mut self,
+        src: &ReadbackRegion,
+        dst_tex: &ProtoObjRef<dyn MTLTexture>,
+        dst_level: u32,
+        target: TextureTarget,
+        dst_format: InternalFormat,
+        (slices, origin, size): (Range<usize>, MTLOrigin, MTLSize),
+    ) {
+        let (src_mtl, dst_mtl) = (src.tex.pixelFormat(), dst_tex.pixelFormat());
+        let src_fallback = src_mtl != src.format.mtl_texture_format();
+        let dst_fallback = dst_mtl != dst_format.mtl_texture_format();
+        if src_fallback && !(dst_fallback && src.format == dst_format) {
+            gl_err!(ty: Error, "OxideGL does not support copying {:?} images that are decompressed on the CPU to {dst_format:?} images", src.format);
+            return;
+        }
+        let src_compressed = src.format.is_block_compressed() && !src_fallback;
+        if dst_fallback && !src_fallback {
+            // the source holds the raw blocks to be decompressed
+            gl_warn!(ty: Performance, "copying to {dst_format:?} image that is stored decompressed requires CPU-side decompression and will stall");
+            let block_size = dst_format
+                .compressed_block_size()
+                .expect("CPU-decompressed formats should be block-compressed");
+            let block = if src_compressed {
+                COMPRESSED_BLOCK_DIM
+            } else {
+                1
+            };
+            let data = self.read_back_staged(src, &[(block_size, MTLBlitOption::None)], block);
+            let decoded = decompress(
+                dst_format,
+                &data,
+                size.width,
+                size.height,
+                size.depth * slices.len(),
+            )
+            .expect("format should be block-compressed");
+            self.write_image_region(
+                dst_tex,
+                dst_level,
+                target,
+                (slices, origin, size),
+                &[(&decoded, MTLBlitOption::None)],
+                1,
+            );
+            return;
+        }
+
+        let pairs: Vec<_> = region_images(src.slices.clone(), src.origin.z, src.size.depth)
+            .into_iter()
+            .zip(region_images(slices, origin.z, size.depth))
+            .collect();
+        let dst_compressed = dst_format.is_block_compressed() && !dst_fallback;
+        if src_compressed == dst_compressed {
+            // Metal only copies between textures of the same pixel format, so reinterpret the source if they differ
+            let src_tex = if src_mtl == dst_mtl {
+                src.tex.clone()
+            } else {
+                src.tex
+                    .newTextureViewWithPixelFormat(dst_mtl)
+                    .expect("failed to create texture view for image copy")
+            };
+            self.platform_state.encode_blit(|enc| {
+                for ((src_slice, src_z), (dst_slice, dst_z)) in pairs {
+                    // Safety: both regions were checked to lie within their images, and the textures have the same pixel format
+                    unsafe {
+                        enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toTexture_destinationSlice_destinationLevel_destinationOrigin(
+                            &src_tex,
+                            src_slice,
+                            src.level,
+                            MTLOrigin { z: src_z, ..src.origin },
+                            MTLSize { depth: 1, ..src.size },
+                            dst_tex,
+                            dst_slice,
+                            dst_level as usize,
+                            MTLOrigin { z: dst_z, ..origin },
+                        );
+                    }
+                }
+            });
+            return;
+        }
+
+        // Metal can't copy between compressed and uncompressed textures directly, so stage the blocks in a buffer
+        let (blocks_wide, blocks_high) = if src_compressed {
+            (
+                src.size.width.div_ceil(COMPRESSED_BLOCK_DIM),
+                src.size.height.div_ceil(COMPRESSED_BLOCK_DIM),
+            )
+        } else {
+            (src.size.width, src.size.height)
+        };
+        let block_size = if src_compressed {
+            src.format.compressed_block_size()
+        } else {
+            dst_format.compressed_block_size()
+        }
+        .expect("one side of the copy should be block-compressed");
+        let bytes_per_row = blocks_wide * block_size;
+        let image_stride = (bytes_per_row * blocks_high).next_multiple_of(16);
+        let staging = self
+            .platform_state
+            .device
+            .newBufferWithLength_options(
+                image_stride * pairs.len(),
+                MTLResourceOptions::StorageModePrivate,
+            )
+            .expect("failed to allocate image copy staging buffer");
+        self.platform_state.encode_blit(|enc| {
+            for (i, &((slice, z), _)) in pairs.iter().enumerate() {
+                // Safety: the source region lies within its image, and the staging buffer holds one padded image per copied image
+                unsafe {
+                    enc.copyFromTexture_sourceSlice_sourceLevel_sourceOrigin_sourceSize_toBuffer_destinationOffset_destinationBytesPerRow_destinationBytesPerImage(
+                        &src.tex,
+                        slice,
+                        src.level,
+                        MTLOrigin { z, ..src.origin },
+                        MTLSize { depth: 1, ..src.size },
+                        &staging,
+                        i * image_stride,
+                        bytes_per_row,
+                        0,
+                    );
+                }
+            }
+        });
+        self.platform_state.encode_blit(|enc| {
+            for (i, &(_, (slice, z))) in pairs.iter().enumerate() {
+                // Safety: the destination region lies within its image and has the same number of texels (or blocks) per row and
+                // rows as the staged source image
+                unsafe {
+                    enc.copyFromBuffer_sourceOffset_sourceBytesPerRow_sourceBytesPerImage_sourceSize_toTexture_destinationSlice_destinationLevel_destinationOrigin(
+                        &staging,
+                        i * image_stride,
+                        bytes_per_row,
+                        0,
+                        MTLSize { depth: 1, ..size },
+                        dst_tex,
+                        slice,
+                        dst_level as usize,
+                        MTLOrigin { z, ..origin },
+                    );
+                }
+            }
+        });
+    }
+    /// Shared implementation of glClearTexImage and glClearTexSubImage. Converts the single pixel at `data` (or zero, if it is null)
+    /// to the internal format of the image at `level` of the texture `name` and fills a region of it with the result, addressed like
+    /// in [`texture_sub_image_internal`](Self::texture_sub_image_internal). Regions that cover whole images are cleared by a render
+    /// pass, others by uploading the value to each texel
+    /// # Safety
+    /// `data` must be null or valid for reads of a single pixel of `fmt`
+    unsafe fn clear_tex_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        level: u32,
+        offset: [GLint; 3],
+        extent: TextureExtent,
+        fmt: GlPixelTypeFormat,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        let (image, faces, [x, y, z]) = self.image_region(name, level, None, offset, extent)?;
+        let target = self.gl_state.texture_list.get(name).target;
+        let internal = image.format;
+        gl_assert!(
+            !internal.is_block_compressed(),
+            InvalidOperation,
+            "images of compressed internal format {internal:?} can't be cleared"
+        );
+        check_pixel_format(internal, fmt.format())?;
+        let pixel_size = fmt.pixel_size().ok_or_else(|| {
+            gl_err!(ty: Error, "invalid pixel type and format combination {fmt:?}");
+            GlError::InvalidOperation.e()
+        })?;
+        let is_depth_stencil = internal.has_depth() && internal.has_stencil();
+        let texel = if data.is_null() {
+            // depth-stencil texels are stored as a float depth value followed by an 8 bit stencil index
+            let texel_size = if is_depth_stencil {
+                Some(size_of::<f32>() + size_of::<u8>())
+            } else {
+                mtl_texel_size(internal.mtl_texture_format())
+            };
+            let Some(texel_size) = texel_size else {
+                gl_err!(ty: Error, "OxideGL does not support clearing images of internal format {internal:?}");
+                return Err(GlError::InvalidOperation.e());
+            };
+            vec![0; texel_size]
+        } else {
+            // Safety: caller ensures `data` is valid for reads of a single pixel
+            let pixel = unsafe { slice::from_raw_parts(data.cast::<u8>(), pixel_size) };
+            if fmt
+                .binary_compatible_format()
+                .is_some_and(|f| f.matches(internal))
+            {
+                pixel.to_vec()
+            } else {
+                let Some(converted) = convert_to_internal(pixel, fmt, internal) else {
+                    gl_err!(ty: Error, "OxideGL does not support clearing images of internal format {internal:?} with {fmt:?} data");
+                    return Err(GlError::InvalidOperation.e());
+                };
+                converted
+            }
+        };
+        if extent.is_empty() {
+            return Ok(());
+        }
+        let region = storage_region(target, faces.clone(), [x, y, z], extent);
+        let Some((mtl_tex, storage_level)) = self.level_storage(name, level, faces.start) else {
+            let texels = region.0.len() * region.2.width * region.2.height * region.2.depth;
+            let filled = fill_planes(&texel, texels, internal);
+            let planes: Vec<_> = filled
+                .iter()
+                .map(|(plane, options)| (&plane[..], *options))
+                .collect();
+            self.defer_image_write(name, level, faces, region, &planes, 1);
+            return Ok(());
+        };
+
+        let (slices, origin, size) = region;
+        let level_size = |dim: usize| (dim >> storage_level).max(1);
+        let whole_images = origin.x == 0
+            && origin.y == 0
+            && origin.z == 0
+            && size.width == level_size(mtl_tex.width())
+            && size.height == level_size(mtl_tex.height())
+            && size.depth == level_size(mtl_tex.depth());
+        if whole_images && let Some(value) = clear_value(&texel, internal) {
+            gl_debug!("clearing slices {slices:?} of level {level} of {name:?} to {value:?}");
+            for slice in slices {
+                for depth_plane in 0..size.depth {
+                    let desc = clear_pass(&mtl_tex, storage_level, slice, depth_plane, value);
+                    self.platform_state.encode_render_pass(&desc);
+                }
+            }
+            return Ok(());
+        }
+        if mtl_tex.sampleCount() > 1 {
+            gl_err!(ty: Error, "OxideGL does not support clearing part of an image of multisample {name:?}");
+            return Ok(());
+        }
+
+        gl_debug!(
+            "filling slices {slices:?} of level {level} of {name:?} with {internal:?} texel {texel:?}"
+        );
+        let texels = size.width * size.height * size.depth * slices.len();
+        let filled = fill_planes(&texel, texels, internal);
+        let planes: Vec<_> = filled
+            .iter()
+            .map(|(plane, options)| (&plane[..], *options))
+            .collect();
+        self.write_image_region(
+            &mtl_tex,
+            storage_level,
+            target,
+            (slices, origin, size),
+            &planes,
+            1,
+        );
+        Ok(())
+    }
+    /// Shared implementation of glGenerateMipmap and glGenerateTextureMipmap. Specifies the levels derived from the base level of
+    /// the texture `name` and fills them in on the GPU, or on the CPU if the device can't generate mipmaps for its Metal pixel format
+    fn generate_mipmap_internal(&mut self, name: ObjectName<Texture>) -> GlFallible {
+        let tex = self.gl_state.texture_list.get(name);
+        let target = tex.target;
+        let (base, _) = tex.level_range();
+        let Some(base_image) = tex.image(base, 0) else {
+            gl_debug!("{name:?} has no base level image, skipping mipmap generation");
+            return Ok(());
+        };
+        if matches!(
+            target,
+            TextureTarget::TextureCubeMap | TextureTarget::TextureCubeMapArray
+        ) {
+            gl_assert!(
+                base_image.extent.width == base_image.extent.height
+                    && (0..tex.faces()).all(|face| tex.image(base, face) == Some(base_image)),
+                InvalidOperation,
+                "can't generate mipmaps for {name:?}, which is not cube complete"
+            );
+        }
+        let format = base_image.format;
+        gl_assert!(
+            !(format.is_integer()
+                || format.has_depth()
+                || format.has_stencil()
+                || format.is_block_compressed()),
+            InvalidOperation,
+            "can't generate mipmaps for {name:?}, as its format {format:?} is not both color-renderable and filterable"
+        );
+        let Some((first, last)) = self
+            .gl_state
+            .texture_list
+            .get_mut(name)
+            .specify_mipmap_levels()
+        else {
+            gl_debug!("{name:?} has an empty base level image, skipping mipmap generation");
+            return Ok(());
+        };
+        if last <= first {
+            return Ok(());
+        }
+        let Some((mtl_tex, first_storage)) = self.level_storage(name, first, 0) else {
+            gl_warn!("failed to realize the storage of {name:?}, skipping mipmap generation");
+            return Ok(());
+        };
+        let count = last - first;
+        let mtl_format = mtl_tex.pixelFormat();
+        if self.platform_state.props.can_generate_mipmaps(mtl_format) {
+            gl_debug!(
+                "generating levels {}..={last} of {name:?} on the GPU",
+                first + 1
+            );
+            let levels = count as usize + 1;
+            let view = if first_storage == 0 && levels == mtl_tex.mipmapLevelCount() {
+                mtl_tex
+            } else {
+                // Metal generates every level of the texture it's given, so restrict it to the GL's mipmap chain with a view
+                let slices = match target {
+                    TextureTarget::TextureCubeMap | TextureTarget::TextureCubeMapArray => {
+                        mtl_tex.arrayLength() * 6
+                    }
+                    _ => mtl_tex.arrayLength(),
+                };
+                // Safety: the level range lies within the texture's storage (it was realized with every level of the chain), the
+                // slice range covers exactly the texture's slices and the view has the texture's own pixel format and type
+                unsafe {
+                    mtl_tex.newTextureViewWithPixelFormat_textureType_levels_slices(
+                        mtl_format,
+                        mtl_tex.textureType(),
+                        NSRange::new(first_storage as usize, levels),
+                        NSRange::new(0, slices),
+                    )
+                }
+                .expect("failed to create mipmap generation texture view")
+            };
+            self.platform_state
+                .encode_blit(|enc| enc.generateMipmapsForTexture(&view));
+            return Ok(());
+        }
+        gl_warn!(ty: Performance, "this device can't generate mipmaps for {mtl_format:?} textures, generating levels {}..={last} of {name:?} on the CPU", first + 1);
+        let Some(texel_size) = mtl_texel_size(mtl_format) else {
+            gl_err!(ty: Error, "OxideGL can't generate mipmaps on the CPU for textures stored as {mtl_format:?}");
+            return Ok(());
+        };
+        let faces = self.gl_state.texture_list.get(name).faces();
+        let rgba_f32 = GlPixelTypeFormat::new(PixelType::Float, PixelFormat::Rgba);
+        for level in 0..count {
+            let src_extent = base_image.extent.mip(target, level);
+            let dst_extent = base_image.extent.mip(target, level + 1);
+            let (slices, origin, size) = storage_region(target, 0..faces, [0; 3], src_extent);
+            let region = ReadbackRegion {
+                tex: mtl_tex.clone(),
+                format,
+                level: (first_storage + level) as usize,
+                slices,
+                origin,
+                size,
+                extent: src_extent,
+                client_offset: [0; 2],
+                flip_y: false,
+            };
+            let data = self.read_back_staged(&region, &[(texel_size, MTLBlitOption::None)], 1);
+            let Some(texels) = convert_from_internal(&data, format, mtl_format, rgba_f32) else {
+                gl_err!(ty: Error, "OxideGL does not support converting {format:?} texels for mipmap generation");
+                return Ok(());
+            };
+            let dst_region = storage_region(target, 0..faces, [0; 3], dst_extent);
+            let downsampled = downsample(&texels, size, dst_region.2, region.slices.len());
+            let Some(converted) = convert_to_internal(&downsampled, rgba_f32, format) else {
+                gl_err!(ty: Error, "OxideGL does not support converting {format:?} texels for mipmap generation");
+                return Ok(());
+            };
+            self.write_image_region(
+                &mtl_tex,
+                first_storage + level + 1,
+                target,
+                dst_region,
+                &[(&converted, MTLBlitOption::None)],
+                1,
+            );
+        }
+        Ok(())
+    }
+    /// Non-DSA entry point for single-sampled immutable storage allocation, handling proxy targets
+    fn tex_storage_internal(
+        &mut self,
+        target: TextureTarget,
+        dims: StorageDims,
+        levels: u32,
+        internalformat: SizedInternalFormat,
+        extent: TextureExtent,
+    ) -> GlFallible {
+        let (unproxied, is_proxy) = target.strip_proxy();
+        gl_assert!(
+            dims.accepts(unproxied, false),
+            InvalidEnum,
+            "{target:?} is not a valid target for {dims:?}-dimensional texture storage"
+        );
+        if is_proxy {
+            // OxideGL does not track proxy texture state, so only validate the parameters
+            validate_storage(self, unproxied, levels, None, internalformat, extent)?;
+            return Ok(());
+        }
+        let name = self.bound_texture(target)?;
+        self.texture_storage_internal(name, dims, levels, None, internalformat, extent)
+    }
+    /// Non-DSA entry point for multisampled immutable storage allocation, handling proxy targets
+    fn tex_storage_multisample_internal(
+        &mut self,
+        target: TextureTarget,
+        dims: StorageDims,
+        samples: u32,
+        internalformat: SizedInternalFormat,
+        extent: TextureExtent,
+    ) -> GlFallible {
+        let (unproxied, is_proxy) = target.strip_proxy();
+        gl_assert!(
+            dims.accepts(unproxied, true),
+            InvalidEnum,
+            "{target:?} is not a valid target for {dims:?}-dimensional multisample texture storage"
+        );
+        if is_proxy {
+            validate_storage(self, unproxied, 1, Some(samples), internalformat, extent)?;
+            return Ok(());
+        }
+        let name = self.bound_texture(target)?;
+        self.texture_storage_internal(name, dims, 1, Some(samples), internalformat, extent)
+    }
+    /// Allocates immutable storage for the texture `name`. `samples` is None for the single-sampled variants
+    pub(crate) fn texture_storage_internal(
+        &mut self,
+        name: ObjectName<Texture>,
+        dims: StorageDims,
+        levels: u32,
+        samples: Option<u32>,
+        internalformat: SizedInternalFormat,
+        extent: TextureExtent,
+    ) -> GlFallible {
+        let tex = self.gl_state.texture_list.get(name);
+        let target = tex.target;
+        gl_assert!(
+            !tex.immutable_format,
+            InvalidOperation,
+            "tried to re-specify the storage of immutable-format texture {name:?}"
+        );
+        // non-DSA commands have already checked the target (generating InvalidEnum), so this can only fail for DSA commands
+        gl_assert!(
+            dims.accepts(target, samples.is_some()),
+            InvalidOperation,
+            "{name:?} has target {target:?}, which is not valid for this texture storage command"
+        );
+        let (format, samples) =
+            validate_storage(self, target, levels, samples, internalformat, extent)?;
+        self.gl_state
+            .texture_list
+            .get_mut(name)
+            .make_immutable_storage(&self.platform_state, format, extent, levels, samples);
+        Ok(())
+    }
+}
+
+impl StorageDims {
+    /// Whether a glTexStorage* command of this dimensionality may allocate storage for a texture of the given target
+    fn accepts(self, target: TextureTarget, multisample: bool) -> bool {
+        match (self, multisample) {
+            (Self::One, false) => target == TextureTarget::Texture1D,
+            (Self::Two, false) => matches!(
+                target,
+                TextureTarget::Texture2D
+                    | TextureTarget::TextureRectangle
+                    | TextureTarget::TextureCubeMap
+                    | TextureTarget::Texture1DArray
+            ),
+            (Self::Three, false) => matches!(
+                target,
+                TextureTarget::Texture3D
+                    | TextureTarget::Texture2DArray
+                    | TextureTarget::TextureCubeMapArray
+            ),
+            (Self::Two, true) => target == TextureTarget::Texture2DMultisample,
+            (Self::Three, true) => target == TextureTarget::Texture2DMultisampleArray,
+            (Self::One, true) => false,
+        }
+    }
+    /// Whether a glTexImage* command of this dimensionality may specify an image for `target` (with any proxy stripped)
+    fn accepts_image(self, target: TextureTarget, is_proxy: bool) -> bool {
+        match self {
+            // images of cube maps are specified one face at a time, except when querying the proxy cube map target
+            Self::Two if target == TextureTarget::TextureCubeMap => is_proxy,
+            Self::Two if target.cube_face().is_some() => true,
+            _ => self.accepts(target, false),
+        }
+    }
+}
+
+/// Validates the parameters of a glTexImage* command against `target` (which must be a non-proxy target accepted by the
+/// command, or a cube map face) and the GL's limits, returning the specified level
+fn validate_image(
+    target: TextureTarget,
+    level: GLint,
+    internalformat: InternalFormat,
+    extent: TextureExtent,
+    border: GLint,
+) -> GlFallible<u32> {
+    let max_size = if target == TextureTarget::Texture3D {
+        MAX_3D_TEXTURE_SIZE
+    } else {
+        MAX_TEXTURE_SIZE
+    };
+    sizei!(level);
+    gl_assert!(
+        level < TextureExtent::new(max_size, max_size, max_size).max_levels(target),
+        InvalidValue,
+        "level {level} exceeds the maximum mipmap level for {target:?}"
+    );
+    gl_assert!(
+        target != TextureTarget::TextureRectangle || level == 0,
+        InvalidValue,
+        "rectangle textures may only have a single level"
+    );
+    gl_assert!(
+        border == 0,
+        InvalidValue,
+        "texture borders are not supported"
+    );
+    gl_assert!(
+        extent.width <= max_size
+            && extent.height <= max_size
+            && extent.depth <= max_size
+            && extent.layers(target) <= MAX_ARRAY_TEXTURE_LAYERS,
+        InvalidValue,
+        "extent {extent:?} exceeds the maximum texture size for {target:?}"
+    );
+    if target.cube_face().is_some() || target == TextureTarget::TextureCubeMapArray {
+        gl_assert!(
+            extent.width == extent.height,
+            InvalidValue,
+            "cube map faces must be square"
+        );
+    }
+    if target == TextureTarget::TextureCubeMapArray {
+        gl_assert!(
+            extent.depth.is_multiple_of(6),
+            InvalidValue,
+            "cube map array layer-face count must be a multiple of 6"
+        );
+    }
+    gl_assert!(
+        internalformat.has_mtl_texture_format(),
+        InvalidValue,
+        "OxideGL does not support textures with internal format {internalformat:?}"
+    );
+    Ok(level)
+}
+
+/// Checks that a client pixel format may be used to specify data for an image of internal format `internalformat`
+fn check_pixel_format(internalformat: InternalFormat, format: PixelFormat) -> GlFallible {
+    // the pixel format must match the kind of data stored by the internal format
+    let format_matches = match (internalformat.has_depth(), internalformat.has_stencil()) {
+        (true, true) => format == PixelFormat::DepthStencil,
+        (true, false) => format == PixelFormat::DepthComponent,
+        (false, true) => format == PixelFormat::StencilIndex,
+        (false, false) => {
+            !matches!(
+                format,
+                PixelFormat::DepthStencil | PixelFormat::DepthComponent | PixelFormat::StencilIndex
+            ) && format.is_integer() == internalformat.is_integer()
+        }
+    };
+    gl_assert!(
+        format_matches,
+        InvalidOperation,
+        "pixel format {format:?} is incompatible with internal format {internalformat:?}"
+    );
+    Ok(())
+}
+
+/// Number of dimensions of the client image transferred by a texture image command for a texture of the given target, which
+/// determines the pixel store modes that apply. `face` is the cube map face addressed by a non-DSA command, if any
+fn transfer_dims(target: TextureTarget, face: Option<usize>) -> u32 {
+    match target {
+        TextureTarget::Texture1D => 1,
+        TextureTarget::Texture3D
+        | TextureTarget::Texture2DArray
+        | TextureTarget::TextureCubeMapArray => 3,
+        TextureTarget::TextureCubeMap if face.is_none() => 3,
+        _ => 2,
+    }
+}
+
+/// Fills `texels` texels of `format` with `texel`, split into planes like by [`image_planes`]
+fn fill_planes(
+    texel: &[u8],
+    texels: usize,
+    format: InternalFormat,
+) -> Vec<(Vec<u8>, MTLBlitOption)> {
+    image_planes(texel, 1, format)
+        .into_iter()
+        .map(|(plane, options)| (plane.repeat(texels), options))
+        .collect()
+}
+/// Splits `texels` tightly packed texels of `format` into the planes that are copied to a texture's storage by
+/// [`write_image_region`](Context::write_image_region). Depth-stencil data is stored as separate depth and stencil planes, which
+/// Metal copies separately
+fn image_planes(data: &[u8], texels: usize, format: InternalFormat) -> Vec<(&[u8], MTLBlitOption)> {
+    if format.has_depth() && format.has_stencil() {
+        let (depth, stencil) = data.split_at(texels * size_of::<f32>());
+        vec![
+            (depth, MTLBlitOption::DepthFromDepthStencil),
+            (stencil, MTLBlitOption::StencilFromDepthStencil),
+        ]
+    } else {
+        vec![(data, MTLBlitOption::None)]
+    }
+}
+
+/// Splits a region of an image of a texture of the given target into the range of Metal texture slices it covers and the origin
+/// and size of the region within each slice. `faces` is the range of cube map faces covered by the region (see
+/// [`Context::image_region`])
+fn storage_region(
+    target: TextureTarget,
+    faces: Range<usize>,
+    [x, y, z]: [u32; 3],
+    extent: TextureExtent,
+) -> (Range<usize>, MTLOrigin, MTLSize) {
+    let [x, y, z] = [x, y, z].map(|v| v as usize);
+    let [width, height, depth] = [extent.width, extent.height, extent.depth].map(|v| v as usize);
+    match target {
+        TextureTarget::Texture1DArray => (
+            y..y + height,
+            MTLOrigin { x, y: 0, z: 0 },
+            MTLSize {
+                width,
+                height: 1,
+                depth: 1,
+            },
+        ),
+        TextureTarget::TextureCubeMap => (
+            faces,
+            MTLOrigin { x, y, z: 0 },
+            MTLSize {
+                width,
+                height,
+                depth: 1,
+            },
+        ),
+        TextureTarget::Texture2DArray | TextureTarget::TextureCubeMapArray => (
+            z..z + depth,
+            MTLOrigin { x, y, z: 0 },
+            MTLSize {
+                width,
+                height,
+                depth: 1,
+            },
+        ),
+        _ => (
+            0..1,
+            MTLOrigin { x, y, z },
+            MTLSize {
+                width,
+                height,
+                depth,
+            },
+        ),
+    }
+}
+
+/// Whether images of the block-compressed format `format` may be specified for `target` (with any proxy stripped). Compressed
+/// formats only support two-dimensional images, except for the BPTC formats which may also be used for 3D textures
+fn compressed_format_supports(format: InternalFormat, target: TextureTarget) -> bool {
+    match target {
+        TextureTarget::Texture2D
+        | TextureTarget::Texture2DArray
+        | TextureTarget::TextureCubeMap
+        | TextureTarget::TextureCubeMapArray => true,
+        TextureTarget::Texture3D => matches!(
+            format,
+            InternalFormat::CompressedRgbaBptcUnorm
+                | InternalFormat::CompressedSrgbAlphaBptcUnorm
+                | InternalFormat::CompressedRgbBptcSignedFloat
+                | InternalFormat::CompressedRgbBptcUnsignedFloat
+        ),
+        t => t.cube_face().is_some(),
+    }
+}
+
+/// Checks that a region of `extent` texels at `[x, y]` within `image` (which must be of a block-compressed format) is made up of
+/// whole blocks, except for those along the right and bottom edges of the image
+fn check_block_alignment(
+    image: TextureLevel,
+    [x, y]: [u32; 2],
+    extent: TextureExtent,
+) -> GlFallible {
+    #[expect(clippy::cast_possible_truncation, reason = "block dimensions are tiny")]
+    let aligned = |offset: u32, len: u32, limit: u32| {
+        offset.is_multiple_of(COMPRESSED_BLOCK_DIM as u32)
+            && (len.is_multiple_of(COMPRESSED_BLOCK_DIM as u32) || offset + len == limit)
+    };
+    gl_assert!(
+        aligned(x, extent.width, image.extent.width)
+            && aligned(y, extent.height, image.extent.height),
+        InvalidOperation,
+        "compressed sub-image region at {:?} with extent {extent:?} is not aligned to the {COMPRESSED_BLOCK_DIM}x{COMPRESSED_BLOCK_DIM} block size of {:?}",
+        [x, y],
+        image.format
+    );
+    Ok(())
+}
+
+/// Size in bytes of the blocks making up a region of `extent` texels (with each layer or face counted as an image of depth 1) of a
+/// block-compressed format with blocks of `block_size` bytes
+fn compressed_size(block_size: usize, extent: TextureExtent) -> usize {
+    (extent.width as usize).div_ceil(COMPRESSED_BLOCK_DIM)
+        * (extent.height as usize).div_ceil(COMPRESSED_BLOCK_DIM)
+        * extent.depth as usize
+        * block_size
+}
+
+/// Checks that a client pixel format may be used to read back data from an image of internal format `internalformat`. In addition to
+/// the formats accepted for uploads, either aspect of a depth-stencil image may be read back on its own
+fn check_pack_format(internalformat: InternalFormat, format: PixelFormat) -> GlFallible {
+    if internalformat.has_depth()
+        && internalformat.has_stencil()
+        && matches!(
+            format,
+            PixelFormat::DepthComponent | PixelFormat::StencilIndex
+        )
+    {
+        return Ok(());
+    }
+    check_pixel_format(internalformat, format)
+}
+
+/// Whether mipmaps may be generated for textures of `target`
+fn generates_mipmaps(target: TextureTarget) -> bool {
+    matches!(
+        target,
+        TextureTarget::Texture1D
+            | TextureTarget::Texture2D
+            | TextureTarget::Texture3D
+            | TextureTarget::Texture1DArray
+            | TextureTarget::Texture2DArray
+            | TextureTarget::TextureCubeMap
+            | TextureTarget::TextureCubeMapArray
+    )
+}
+
+/// Box filters `images` images of `src_size` tightly packed RGBA 32 bit float texels (in native byte order) down to `dst_size`. Each
+/// destination texel averages the source texels it covers along every dimension that was halved, so odd-sized dimensions drop their last
+/// row, column or slice
+fn downsample(src: &[u8], src_size: MTLSize, dst_size: MTLSize, images: usize) -> Vec<u8> {
+    const TEXEL: usize = 4 * size_of::<f32>();
+    let footprint = |i: usize, s: usize, d: usize| {
+        if s > d {
+            i * 2..(i * 2 + 2).min(s)
+        } else {
+            i..i + 1
+        }
+    };
+    let texel = |image: &[u8], x: usize, y: usize, z: usize| -> [f32; 4] {
+        let offset = ((z * src_size.height + y) * src_size.width + x) * TEXEL;
+        let mut out = [0.0; 4];
+        for (c, bytes) in out
+            .iter_mut()
+            .zip(image[offset..offset + TEXEL].chunks_exact(4))
+        {
+            *c = f32::from_ne_bytes(bytes.try_into().expect("chunk should be 4 bytes"));
+        }
+        out
+    };
+    let src_image_len = src_size.width * src_size.height * src_size.depth * TEXEL;
+    let mut dst =
+        Vec::with_capacity(dst_size.width * dst_size.height * dst_size.depth * TEXEL * images);
+    for image in src.chunks_exact(src_image_len).take(images) {
+        for z in 0..dst_size.depth {
+            for y in 0..dst_size.height {
+                for x in 0..dst_size.width {
+                    let mut sum = [0.0f32; 4];
+                    let mut n = 0u8;
+                    for sz in footprint(z, src_size.depth, dst_size.depth) {
+                        for sy in footprint(y, src_size.height, dst_size.height) {
+                            for sx in footprint(x, src_size.width, dst_size.width) {
+                                for (acc, c) in sum.iter_mut().zip(texel(image, sx, sy, sz)) {
+                                    *acc += c;
+                                }
+                                n += 1;
+                            }
+                        }
+                    }
+                    for c in sum {
+                        dst.extend_from_slice(&(c / f32::from(n)).to_ne_bytes());
+                    }
+                }
+            }
+        }
+    }
+    dst
+}
+
+/// Lists the 2D images making up a region of a texture (see [`storage_region`]) as pairs of the Metal texture slice and z offset
+/// of each image, covering `depth` images starting at `z` within each of `slices`
+fn region_images(slices: Range<usize>, z: usize, depth: usize) -> Vec<(usize, usize)> {
+    slices
+        .flat_map(|slice| (z..z + depth).map(move |z| (slice, z)))
+        .collect()
+}
+
+/// Reverses the order of the rows of `row_bytes` bytes each making up `image`
+fn flip_rows(image: &mut [u8], row_bytes: usize) {
+    let rows = image.len() / row_bytes;
+    for y in 0..rows / 2 {
+        let (top, bottom) = image.split_at_mut((rows - 1 - y) * row_bytes);
+        top[y * row_bytes..(y + 1) * row_bytes].swap_with_slice(&mut bottom[..row_bytes]);
+    }
+}
+
+/// Builds a render pass that clears the image at `depth_plane` of `slice` of `level` of `tex` to `value`
+fn clear_pass(
+    tex: &ProtoObjRef<dyn MTLTexture>,
+    level: u32,
+    slice: usize,
+    depth_plane: usize,
+    value: ClearValue,
+) -> Retained<MTLRenderPassDescriptor> {
+    let desc = MTLRenderPassDescriptor::renderPassDescriptor();
+    let setup = |attachment: &MTLRenderPassAttachmentDescriptor| {
+        attachment.setTexture(Some(tex));
+        attachment.setLevel(level as usize);
+        attachment.setSlice(slice);
+        attachment.setDepthPlane(depth_plane);
+        attachment.setLoadAction(MTLLoadAction::Clear);
+        attachment.setStoreAction(MTLStoreAction::Store);
+    };
+    let (depth, stencil) = match value {
+        ClearValue::Color([red, green, blue, alpha]) => {
+            let attachment = MTLRenderPassColorAttachmentDescriptor::new();
+            setup(&attachment);
+            attachment.setClearColor(MTLClearColor {
+                red,
+                green,
+                blue,
+                alpha,
+            });
+            // Safety: every render pass has a color attachment at index 0
+            unsafe {
+                desc.colorAttachments()
+                    .setObject_atIndexedSubscript(Some(&attachment), 0);
+            }
+            (None, None)
+        }
+        ClearValue::Depth(depth) => (Some(depth), None),
+        ClearValue::Stencil(stencil) => (None, Some(stencil)),
+        ClearValue::DepthStencil(depth, stencil) => (Some(depth), Some(stencil)),
+    };
+    if let Some(depth) = depth {
+        let attachment = desc.depthAttachment();
+        setup(&attachment);
+        attachment.setClearDepth(depth);
+    }
+    if let Some(stencil) = stencil {
+        let attachment = desc.stencilAttachment();
+        setup(&attachment);
+        attachment.setClearStencil(stencil);
+    }
+    desc
+}
+
+/// A region of a Metal texture to be read back into client memory or a pixel pack buffer by [`Context::read_back_internal`]
+#[derive(Debug)]
+pub(crate) struct ReadbackRegion {
+    pub(crate) tex: ProtoObjRef<dyn MTLTexture>,
+    /// Internal format describing the contents of `tex`
+    pub(crate) format: InternalFormat,
+    pub(crate) level: usize,
+    /// Texture slices (array layers or cube map faces) covered by the region
+    pub(crate) slices: Range<usize>,
+    pub(crate) origin: MTLOrigin,
+    /// Size of the region within each slice
+    pub(crate) size: MTLSize,
+    /// Extent of the region in the client image
+    pub(crate) extent: TextureExtent,
+    /// Offset (in pixels and rows) of the region within the client image
+    pub(crate) client_offset: [u32; 2],
+    /// Whether the rows of each image are stored upside down relative to the client image, as is the case for the default
+    /// framebuffer (GL window coordinates start at the bottom left, Metal's at the top left)
+    pub(crate) flip_y: bool,
+}
+
+/// A validated pixel pack buffer destination of a readback
+struct PackBufferTarget {
+    name: ObjectName<Buffer>,
+    mtl: ProtoObjRef<dyn MTLBuffer>,
+    /// Offset of the client image within the buffer
+    offset: usize,
+}
+
+/// Validates the parameters of a glTexStorage* command against `target` and the device's limits, returning the [`InternalFormat`] and
+/// sample count to allocate the texture with. The caller is responsible for checking that `target` is accepted by the command
+fn validate_storage(
+    ctx: &Context,
+    target: TextureTarget,
+    levels: u32,
+    samples: Option<u32>,
+    internalformat: SizedInternalFormat,
+    extent: TextureExtent,
+) -> GlFallible<(InternalFormat, u32)> {
+    gl_assert!(
+        levels >= 1 && extent.width >= 1 && extent.height >= 1 && extent.depth >= 1,
+        InvalidValue,
+        "texture levels and dimensions must be at least 1 (got {levels} levels, extent {extent:?})"
+    );
+
+    let max_size = if target == TextureTarget::Texture3D {
+        MAX_3D_TEXTURE_SIZE
+    } else {
+        MAX_TEXTURE_SIZE
+    };
+    let layers = extent.layers(target);
+    gl_assert!(
+        extent.width <= max_size
+            && extent.height <= max_size
+            && extent.depth <= max_size
+            && layers <= MAX_ARRAY_TEXTURE_LAYERS,
+        InvalidValue,
+        "extent {extent:?} exceeds the maximum texture size for {target:?}"
+    );
+    if matches!(
+        target,
+        TextureTarget::TextureCubeMap | TextureTarget::TextureCubeMapArray
+    ) {
+        gl_assert!(
+            extent.width == extent.height,
+            InvalidValue,
+            "cube map faces must be square"
+        );
+    }
+    if target == TextureTarget::TextureCubeMapArray {
+        gl_assert!(
+            extent.depth.is_multiple_of(6),
+            InvalidValue,
+            "cube map array layer-face count must be a multiple of 6"
+        );
+    }
+    if target == TextureTarget::TextureRectangle {
+        gl_assert!(
+            levels == 1,
+            InvalidOperation,
+            "rectangle textures may only have a single level"
+        );
+    }
+    let max_levels = extent.max_levels(target);
+    gl_assert!(
+        levels <= max_levels,
+        InvalidOperation,
+        "{levels} levels requested but a texture of extent {extent:?} may only have {max_levels}"
+    );
+
+    let format: InternalFormat = internalformat.try_into_enum()?;
+    gl_assert!(
+        format.has_mtl_texture_format(),
+        InvalidEnum,
+        "OxideGL does not support textures with internal format {format:?}"
+    );
+    gl_assert!(
+        !format.is_block_compressed()
+            || (samples.is_none() && compressed_format_supports(format, target)),
+        InvalidOperation,
+        "compressed internal format {format:?} may not be used with {target:?}"
+    );
+
+    let samples = match samples {
+        Some(requested) => {
+            gl_assert!(requested >= 1, InvalidValue);
+            ctx.platform_state
+                .props
+                .sample_count_at_least(requested)
+                .ok_or_else(|| {
+                    gl_err!(ty: Error, "requested sample count ({requested}) exceeds the maximum supported sample count");
+                    GlError::InvalidOperation.e()
+                })?
+        }
+        None => 1,
+    };
+    Ok((format, samples))
+}
@@ -1,64 +1,711 @@
-use objc2_metal::MTLTexture;
+use objc2::rc::Retained;
+use objc2_foundation::NSString;
+use objc2_metal::{MTLClearColor, MTLColorWriteMask, MTLResource, MTLTexture};
 
 use crate::{
-    enums::{DrawBufferMode, TextureTarget},
+    dispatch::gl_types::{GLenum, GLint},
+    enums::{
+        DrawBufferMode, FramebufferAttachment as GlAttachment, GL_BACK_LEFT, GL_BACK_RIGHT,
+        GL_COLOR, GL_COLOR_ATTACHMENT0, GL_DEPTH, GL_FRAMEBUFFER_COMPLETE,
+        GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT, GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS,
+        GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT, GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE,
+        GL_FRAMEBUFFER_UNSUPPORTED, GL_FRONT_LEFT, GL_FRONT_RIGHT, GL_INT, GL_STENCIL,
+        GL_UNSIGNED_INT, InternalFormat, ReadBufferMode, TextureTarget,
+    },
     util::ProtoObjRef,
 };
 
-use super::gl_object::{LateInit, NamedObject, ObjectName};
+use super::{
+    Context,
+    gl_object::{LateInit, NamedObject, ObjectName},
+    platform::PlatformState,
+    state::{GLState, PixelAlignedRect},
+    texture::{RealizedTexture, Texture, TextureExtent},
+};
 
 pub const MAX_COLOR_ATTACHMENTS: u32 = 8;
 #[derive(Debug)]
 pub struct Framebuffer {
-    name: ObjectName<Self>,
-    draw_buffers: DrawBuffers,
-    color_attachments: [Option<FramebufferAttachment>; MAX_COLOR_ATTACHMENTS as usize],
-    depth_attachment: Option<FramebufferAttachment>,
-    stencil_attachment: Option<FramebufferAttachment>,
+    pub(crate) name: ObjectName<Self>,
+    pub(crate) draw_buffers: DrawBuffers,
+    /// The color attachment that pixels are read from (`GL_READ_BUFFER`)
+    pub(crate) read_buffer: ReadBufferMode,
+    pub(crate) color_attachments: [Option<FramebufferAttachment>; MAX_COLOR_ATTACHMENTS as usize],
+    pub(crate) depth_attachment: Option<FramebufferAttachment>,
+    pub(crate) stencil_attachment: Option<FramebufferAttachment>,
 }
 impl Framebuffer {
     pub fn new_default(name: ObjectName<Self>) -> Self {
+        let mut draw_buffers = DrawBuffers::new();
+        draw_buffers.modes[0] = Some(DrawBufferMode::ColorAttachment0);
         Self {
             name,
-            draw_buffers: DrawBuffers::new(),
+            draw_buffers,
+            read_buffer: ReadBufferMode::ColorAttachment0,
             color_attachments: [const { None }; MAX_COLOR_ATTACHMENTS as usize],
             depth_attachment: None,
             stencil_attachment: None,
         }
     }
+    /// Returns the attachment at `point`. The depth stencil attachment point reports the depth attachment
+    pub(crate) fn attachment(&self, point: AttachmentPoint) -> Option<&FramebufferAttachment> {
+        match point {
+            AttachmentPoint::Color(idx) => self.color_attachments[idx as usize].as_ref(),
+            AttachmentPoint::Depth | AttachmentPoint::DepthStencil => {
+                self.depth_attachment.as_ref()
+            }
+            AttachmentPoint::Stencil => self.stencil_attachment.as_ref(),
+        }
+    }
+    /// Replaces the attachment at `point` (both the depth and stencil attachments for the depth stencil attachment point)
+    pub(crate) fn attach(
+        &mut self,
+        point: AttachmentPoint,
+        attachment: Option<FramebufferAttachment>,
+    ) {
+        match point {
+            AttachmentPoint::Color(idx) => self.color_attachments[idx as usize] = attachment,
+            AttachmentPoint::Depth => self.depth_attachment = attachment,
+            AttachmentPoint::Stencil => self.stencil_attachment = attachment,
+            AttachmentPoint::DepthStencil => {
+                self.depth_attachment = attachment;
+                self.stencil_attachment = attachment;
+            }
+        }
+    }
+    /// Iterates over the populated attachment points of this framebuffer and their attachments
+    pub(crate) fn attachments(
+        &self,
+    ) -> impl Iterator<Item = (AttachmentPoint, &FramebufferAttachment)> + '_ {
+        (0..MAX_COLOR_ATTACHMENTS)
+            .map(AttachmentPoint::Color)
+            .chain([AttachmentPoint::Depth, AttachmentPoint::Stencil])
+            .filter_map(|point| self.attachment(point).map(|a| (point, a)))
+    }
+    /// Detaches every attachment for which `detach` returns true from this framebuffer. Returns whether anything was detached
+    pub(crate) fn detach_where(&mut self, detach: impl Fn(&FramebufferAttachment) -> bool) -> bool {
+        let mut detached = false;
+        for slot in self
+            .color_attachments
+            .iter_mut()
+            .chain([&mut self.depth_attachment, &mut self.stencil_attachment])
+        {
+            if slot.as_ref().is_some_and(&detach) {
+                *slot = None;
+                detached = true;
+            }
+        }
+        detached
+    }
+    /// Returns the attachment whose image is cleared by a clear of `target`, if there is one
+    pub(crate) fn cleared_attachment(
+        &mut self,
+        target: ClearTarget,
+    ) -> Option<&mut FramebufferAttachment> {
+        match target {
+            ClearTarget::Color(idx) => {
+                let idx = color_attachment_index(self.draw_buffers.modes[idx as usize]?.into())?;
+                self.color_attachments[idx as usize].as_mut()
+            }
+            ClearTarget::Depth => self.depth_attachment.as_mut(),
+            ClearTarget::Stencil => self.stencil_attachment.as_mut(),
+        }
+    }
+    /// Marks the image attached at `point` (both the depth and stencil attachments' for the depth stencil attachment point) as
+    /// invalidated, if there is one
+    pub(crate) fn invalidate(&mut self, point: AttachmentPoint) {
+        let invalidate = |slot: &mut Option<FramebufferAttachment>| {
+            if let Some(attachment) = slot {
+                attachment.invalidated = true;
+            }
+        };
+        match point {
+            AttachmentPoint::Color(idx) => invalidate(&mut self.color_attachments[idx as usize]),
+            AttachmentPoint::Depth => invalidate(&mut self.depth_attachment),
+            AttachmentPoint::Stencil => invalidate(&mut self.stencil_attachment),
+            AttachmentPoint::DepthStencil => {
+                invalidate(&mut self.depth_attachment);
+                invalidate(&mut self.stencil_attachment);
+            }
+        }
+    }
 }
 
 impl NamedObject for Framebuffer {
     type LateInitType = LateInit<Self>;
     const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
 }
-/// GL object wrapping an internal drawable
+/// A renderbuffer object: a single 2D image (possibly multisample) that can only be rendered to, not sampled from
+#[derive(Debug)]
 pub(crate) struct RenderBuffer {
     pub(crate) name: ObjectName<Self>,
-    pub(crate) drawable: InternalDrawable,
+    pub(crate) format: InternalFormat,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    /// Sample count requested by the client (`GL_RENDERBUFFER_SAMPLES`), with 0 meaning a non-multisample image
+    pub(crate) samples: u32,
+    /// Storage of the renderbuffer's image, or None if the image has zero size
+    pub(crate) storage: Option<RealizedTexture>,
+}
+impl RenderBuffer {
+    pub(crate) fn new_default(name: ObjectName<Self>) -> Self {
+        Self {
+            name,
+            format: InternalFormat::Rgba,
+            width: 0,
+            height: 0,
+            samples: 0,
+            storage: None,
+        }
+    }
+    /// Replaces the image of this renderbuffer with a `width` by `height` image of `format` with `samples` samples per pixel
+    /// (0 for a non-multisample image), reallocating its storage. `samples` must be 0 or a sample count supported by the device,
+    /// and the other arguments must already be validated against the GL's limits
+    pub(crate) fn set_storage(
+        &mut self,
+        platform: &PlatformState,
+        format: InternalFormat,
+        width: u32,
+        height: u32,
+        samples: u32,
+    ) {
+        self.format = format;
+        self.width = width;
+        self.height = height;
+        self.samples = samples;
+        self.storage = (width > 0 && height > 0).then(|| {
+            let target = if samples > 1 {
+                TextureTarget::Texture2DMultisample
+            } else {
+                TextureTarget::Texture2D
+            };
+            RealizedTexture::new(
+                platform,
+                target,
+                format,
+                TextureExtent::new(width, height, 1),
+                1,
+                samples.max(1),
+            )
+        });
+    }
+}
+impl NamedObject for RenderBuffer {
+    type LateInitType = LateInit<Self>;
+    const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
+    fn set_debug_label(
+        ctx: &mut Context,
+        name: ObjectName<Self>,
+        label: Option<Retained<NSString>>,
+    ) {
+        if let Some(rb) = ctx.gl_state.renderbuffer_list.get_opt(name) {
+            rb.storage
+                .as_ref()
+                .inspect(|r| r.mtl_tex.setLabel(label.as_deref()));
+        }
+    }
 }
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum ClearValue {
     // float/normalized color
     Float([f32; 4]),
-    // integer (might be bitcast i32) color
-    Integer([u32; 4]),
+    // signed integer color
+    Int([i32; 4]),
+    // unsigned integer color
+    Uint([u32; 4]),
 
     // depth
     Depth(f32),
     // stencil
     Stencil(i32),
 }
-pub trait AttachableTexture {}
-impl AttachableTexture for RenderBuffer {}
+impl ClearValue {
+    /// Whether this value has the type of the values stored in an image of format `format`. Clearing an image to a value of a
+    /// different type has undefined results
+    pub(crate) fn matches_format(self, format: InternalFormat) -> bool {
+        match self {
+            Self::Float(_) => !format.is_integer(),
+            Self::Int(_) => format.is_integer() && format.component_type() == GL_INT,
+            Self::Uint(_) => format.is_integer() && format.component_type() == GL_UNSIGNED_INT,
+            Self::Depth(_) => format.has_depth(),
+            Self::Stencil(_) => format.has_stencil(),
+        }
+    }
+    /// Converts a color clear value into the clear color of a Metal render pass attachment
+    pub(crate) fn clear_color(self) -> MTLClearColor {
+        let [red, green, blue, alpha] = match self {
+            Self::Float(color) => color.map(f64::from),
+            Self::Int(color) => color.map(f64::from),
+            Self::Uint(color) => color.map(f64::from),
+            Self::Depth(_) | Self::Stencil(_) => {
+                unreachable!("{self:?} is not a color clear value")
+            }
+        };
+        MTLClearColor {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+}
+/// A buffer of a framebuffer that is cleared by glClear or glClearBuffer*
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClearTarget {
+    /// The color buffer selected by the draw buffer with the given index
+    Color(u32),
+    Depth,
+    Stencil,
+}
+/// The parts of an image that a clear affects, as restricted by the scissor test and the write masks
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ClearScope {
+    /// The scissor box (in window coordinates), if the scissor test is enabled
+    pub(crate) scissor: Option<PixelAlignedRect>,
+    /// Whether the image is stored upside down relative to window coordinates, as the default framebuffer's are
+    pub(crate) flip_y: bool,
+    /// Color channels that are written
+    pub(crate) color_mask: MTLColorWriteMask,
+    /// Stencil bits that are written
+    pub(crate) stencil_mask: u32,
+}
+impl ClearScope {
+    /// Whether a clear with this scope replaces every value of an image of size `width` by `height`, which a load action can do
+    pub(crate) fn is_whole(&self, width: u32, height: u32) -> bool {
+        self.scissor
+            .is_none_or(|s| s.x == 0 && s.y == 0 && s.width >= width && s.height >= height)
+            && self.color_mask == MTLColorWriteMask::All
+            && self.stencil_mask & 0xFF == 0xFF
+    }
+}
+/// Clears of the default framebuffer's buffers that are folded into the load actions of its next render pass, like the
+/// [`clear`](FramebufferAttachment::clear) of a framebuffer object's attachments
+#[derive(Debug, Default)]
+pub(crate) struct DefaultFramebufferClears {
+    /// Clear of the color buffer selected by each draw buffer
+    pub(crate) color: [Option<ClearValue>; MAX_COLOR_ATTACHMENTS as usize],
+    pub(crate) depth: Option<ClearValue>,
+    pub(crate) stencil: Option<ClearValue>,
+}
+impl DefaultFramebufferClears {
+    /// Returns the pending clear of the buffer selected by `target`
+    pub(crate) fn clear_mut(&mut self, target: ClearTarget) -> &mut Option<ClearValue> {
+        match target {
+            ClearTarget::Color(idx) => &mut self.color[idx as usize],
+            ClearTarget::Depth => &mut self.depth,
+            ClearTarget::Stencil => &mut self.stencil,
+        }
+    }
+}
+/// A buffer of the default framebuffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DefaultBuffer {
+    /// The color buffer selected by a (non-aliased) draw buffer mode, e.g. `GL_FRONT_LEFT`
+    Color(DrawBufferMode),
+    Depth,
+    Stencil,
+}
+impl DefaultBuffer {
+    /// Returns the buffer named by `buffer` (as passed to glInvalidateFramebuffer), or None if it doesn't name one. `GL_COLOR`
+    /// names the front left buffer, since contexts are single-buffered
+    pub(crate) fn new(buffer: GLenum) -> Option<Self> {
+        Some(match buffer {
+            GL_COLOR | GL_FRONT_LEFT => Self::Color(DrawBufferMode::FrontLeft),
+            GL_FRONT_RIGHT => Self::Color(DrawBufferMode::FrontRight),
+            GL_BACK_LEFT => Self::Color(DrawBufferMode::BackLeft),
+            GL_BACK_RIGHT => Self::Color(DrawBufferMode::BackRight),
+            GL_DEPTH => Self::Depth,
+            GL_STENCIL => Self::Stencil,
+            _ => return None,
+        })
+    }
+}
+/// Buffers of the default framebuffer whose contents were invalidated since its last render pass began, which its next render
+/// pass doesn't load. Like the [`invalidated`](FramebufferAttachment::invalidated) flag of a framebuffer object's attachments
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct DefaultFramebufferInvalidations {
+    /// Front left, front right, back left and back right color buffers
+    color: [bool; 4],
+    pub(crate) depth: bool,
+    pub(crate) stencil: bool,
+}
+impl DefaultFramebufferInvalidations {
+    /// Returns the index of the color buffer `mode` in [`color`](Self::color), or None for aliased modes
+    fn color_index(mode: DrawBufferMode) -> Option<usize> {
+        match mode {
+            DrawBufferMode::FrontLeft => Some(0),
+            DrawBufferMode::FrontRight => Some(1),
+            DrawBufferMode::BackLeft => Some(2),
+            DrawBufferMode::BackRight => Some(3),
+            _ => None,
+        }
+    }
+    pub(crate) fn invalidate(&mut self, buffer: DefaultBuffer) {
+        match buffer {
+            DefaultBuffer::Color(mode) => {
+                if let Some(idx) = Self::color_index(mode) {
+                    self.color[idx] = true;
+                }
+            }
+            DefaultBuffer::Depth => self.depth = true,
+            DefaultBuffer::Stencil => self.stencil = true,
+        }
+    }
+    /// Whether the color buffer `mode` was invalidated
+    pub(crate) fn color(self, mode: DrawBufferMode) -> bool {
+        Self::color_index(mode).is_some_and(|idx| self.color[idx])
+    }
+}
+/// The region passed to glInvalidateSubFramebuffer and glInvalidateNamedFramebufferSubData
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InvalidatedRegion {
+    pub(crate) origin: [GLint; 2],
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+impl InvalidatedRegion {
+    /// Whether the region covers an entire image of size `dims`. Only such regions are actually invalidated, since the contents of
+    /// the rest of a partially invalidated image still have to be loaded and stored
+    pub(crate) fn covers(self, dims: (u32, u32)) -> bool {
+        let [x, y] = self.origin.map(i64::from);
+        x <= 0
+            && y <= 0
+            && x + i64::from(self.width) >= i64::from(dims.0)
+            && y + i64::from(self.height) >= i64::from(dims.1)
+    }
+}
+/// An object whose images can be attached to a framebuffer object (a texture or a renderbuffer). `level` and `layer` identify the
+/// attached image as in [`FramebufferAttachment`]
+pub(crate) trait AttachableTexture {
+    /// Describes the attached image for the purpose of framebuffer completeness checking
+    fn attached_image(&self, level: u32, layer: Option<u32>) -> AttachmentImage;
+    /// Returns the backing storage of the attached image, if the object's storage currently covers it
+    fn image_storage(&self, level: u32, layer: Option<u32>) -> Option<AttachmentStorage>;
+    /// Returns the internal format of the attached image, if it has been specified
+    fn image_format(&self, level: u32, layer: Option<u32>) -> Option<InternalFormat>;
+}
+/// Returns the cube map face of `tex` that `layer` selects (always 0 for textures that aren't cube maps)
+fn attached_face(tex: &Texture, layer: Option<u32>) -> usize {
+    if tex.target == TextureTarget::TextureCubeMap {
+        layer.unwrap_or(0) as usize
+    } else {
+        0
+    }
+}
+impl AttachableTexture for Texture {
+    fn attached_image(&self, level: u32, layer: Option<u32>) -> AttachmentImage {
+        let (base, max) = self.level_range();
+        let Some(image) = self.image(level, attached_face(self, layer)) else {
+            return AttachmentImage::Incomplete;
+        };
+        // immutable-format textures may have images outside of the clamped level range attached
+        if !self.immutable_format && (level < base || level > max) {
+            return AttachmentImage::Incomplete;
+        }
+        let extent = image.extent;
+        let layers = match self.target {
+            TextureTarget::Texture3D => extent.depth,
+            t => extent.layers(t),
+        };
+        match layer {
+            Some(layer) if self.target != TextureTarget::TextureCubeMap && layer >= layers => {
+                return AttachmentImage::Incomplete;
+            }
+            // layered cube maps must be cube complete
+            None if self.target == TextureTarget::TextureCubeMap
+                && (1..6).any(|face| self.image(level, face) != Some(image)) =>
+            {
+                return AttachmentImage::Incomplete;
+            }
+            _ => {}
+        }
+        let height = match self.target {
+            TextureTarget::Texture1D | TextureTarget::Texture1DArray => 1,
+            _ => extent.height,
+        };
+        AttachmentImage::Image(AttachedImage {
+            format: image.format,
+            width: extent.width,
+            height,
+            samples: self.realized.as_ref().map_or(1, |r| r.samples),
+            layered: layer.is_none().then_some((self.target, layers)),
+        })
+    }
+    fn image_storage(&self, level: u32, layer: Option<u32>) -> Option<AttachmentStorage> {
+        let level = self.storage_level(level, attached_face(self, layer))?;
+        let realized = self.realized.as_ref()?;
+        let extent = realized.extent.mip(self.target, level);
+        let (height, layers) = match self.target {
+            TextureTarget::Texture1D | TextureTarget::Texture1DArray => {
+                (1, extent.layers(self.target))
+            }
+            TextureTarget::Texture3D => (extent.height, extent.depth),
+            t => (extent.height, extent.layers(t)),
+        };
+        Some(AttachmentStorage {
+            tex: realized.mtl_tex.clone(),
+            format: realized.format,
+            level,
+            slice: layer.unwrap_or(0),
+            depth_plane: self.target == TextureTarget::Texture3D,
+            width: extent.width,
+            height,
+            layers: if layer.is_some() { 1 } else { layers },
+            samples: realized.samples,
+        })
+    }
+    fn image_format(&self, level: u32, layer: Option<u32>) -> Option<InternalFormat> {
+        self.image(level, attached_face(self, layer))
+            .map(|image| image.format)
+    }
+}
+impl AttachableTexture for RenderBuffer {
+    // zero-sized renderbuffers are reported as images so that completeness checking rejects them
+    fn attached_image(&self, _level: u32, _layer: Option<u32>) -> AttachmentImage {
+        AttachmentImage::Image(AttachedImage {
+            format: self.format,
+            width: self.width,
+            height: self.height,
+            samples: self.samples.max(1),
+            layered: None,
+        })
+    }
+    fn image_storage(&self, _level: u32, _layer: Option<u32>) -> Option<AttachmentStorage> {
+        let storage = self.storage.as_ref()?;
+        Some(AttachmentStorage {
+            tex: storage.mtl_tex.clone(),
+            format: storage.format,
+            level: 0,
+            slice: 0,
+            depth_plane: false,
+            width: self.width,
+            height: self.height,
+            layers: 1,
+            samples: storage.samples,
+        })
+    }
+    fn image_format(&self, _level: u32, _layer: Option<u32>) -> Option<InternalFormat> {
+        Some(self.format)
+    }
+}
 
-// TODO uncomment when Texture is impled :3
-// impl AttachableTexture for Texture {}
-#[derive(Debug)]
+/// An image attached to a framebuffer attachment point
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct FramebufferAttachment {
+    /// Clear of the attached image that is folded into the load action of the next render pass that renders to it
     pub(crate) clear: Option<ClearValue>,
+    /// Whether the contents of the attached image were invalidated since the render pass that renders to it began, in which case
+    /// the next one doesn't load them
+    pub(crate) invalidated: bool,
+    /// Target of the attached texture, or [`TextureTarget::Renderbuffer`] if a renderbuffer is attached
     pub(crate) target: TextureTarget,
     pub(crate) tex_name: ObjectName<dyn AttachableTexture>,
+    /// Mipmap level of the attached texture image
+    pub(crate) level: u32,
+    /// Array layer (or cube map face, for cube map textures) of the attached texture image, or None if every layer of the level
+    /// is attached (a layered attachment)
+    pub(crate) layer: Option<u32>,
+}
+impl FramebufferAttachment {
+    pub(crate) fn texture(
+        target: TextureTarget,
+        name: ObjectName<Texture>,
+        level: u32,
+        layer: Option<u32>,
+    ) -> Self {
+        Self {
+            clear: None,
+            invalidated: false,
+            target,
+            tex_name: name.cast(),
+            level,
+            layer,
+        }
+    }
+    pub(crate) fn renderbuffer(name: ObjectName<RenderBuffer>) -> Self {
+        Self {
+            clear: None,
+            invalidated: false,
+            target: TextureTarget::Renderbuffer,
+            tex_name: name.cast(),
+            level: 0,
+            layer: Some(0),
+        }
+    }
+    /// Returns the name of the attached texture, or None if a renderbuffer is attached
+    pub(crate) fn texture_name(&self) -> Option<ObjectName<Texture>> {
+        (self.target != TextureTarget::Renderbuffer).then(|| self.tex_name.cast())
+    }
+    /// Returns the name of the attached renderbuffer, or None if a texture is attached
+    pub(crate) fn renderbuffer_name(&self) -> Option<ObjectName<RenderBuffer>> {
+        (self.target == TextureTarget::Renderbuffer).then(|| self.tex_name.cast())
+    }
+    /// Returns the attached object, or None if it no longer exists
+    pub(crate) fn object<'a>(&self, state: &'a GLState) -> Option<&'a dyn AttachableTexture> {
+        match self.renderbuffer_name() {
+            Some(name) => state
+                .renderbuffer_list
+                .get_opt(name)
+                .map(|rb| rb as &dyn AttachableTexture),
+            None => state
+                .texture_list
+                .get_opt(self.tex_name.cast())
+                .map(|tex| tex as &dyn AttachableTexture),
+        }
+    }
+    /// Returns the backing storage of the attached image, if the attached object's storage currently covers it
+    pub(crate) fn storage(&self, state: &GLState) -> Option<AttachmentStorage> {
+        self.object(state)?.image_storage(self.level, self.layer)
+    }
+    /// Whether this attachment and `other` refer to the same image of the same object
+    pub(crate) fn same_image(&self, other: &Self) -> bool {
+        (self.target, self.tex_name, self.level, self.layer)
+            == (other.target, other.tex_name, other.level, other.layer)
+    }
+}
+/// The Metal texture that stores a framebuffer attachment's image, and where in it the image is stored
+#[derive(Debug, Clone)]
+pub(crate) struct AttachmentStorage {
+    pub(crate) tex: ProtoObjRef<dyn MTLTexture>,
+    /// Internal format describing the contents of `tex`
+    pub(crate) format: InternalFormat,
+    /// Mipmap level of `tex` that stores the attached image
+    pub(crate) level: u32,
+    /// Array slice (or cube map face) of `tex` that stores the attached image, or its depth plane if
+    /// [`depth_plane`](Self::depth_plane) is set. Layered attachments start at slice 0
+    pub(crate) slice: u32,
+    /// Whether `tex` is a 3D texture, whose layers are depth planes rather than array slices
+    pub(crate) depth_plane: bool,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    /// Number of layers that are rendered to (1 unless the attachment is layered)
+    pub(crate) layers: u32,
+    pub(crate) samples: u32,
+}
+/// A framebuffer attachment point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttachmentPoint {
+    Color(u32),
+    Depth,
+    Stencil,
+    /// Both the depth and stencil attachment points (`GL_DEPTH_STENCIL_ATTACHMENT`)
+    DepthStencil,
+}
+impl AttachmentPoint {
+    /// Returns the attachment point named by `attachment`, or None if it names a color attachment beyond
+    /// [`MAX_COLOR_ATTACHMENTS`]
+    pub(crate) fn new(attachment: GlAttachment) -> Option<Self> {
+        Some(match attachment {
+            GlAttachment::DepthAttachment => Self::Depth,
+            GlAttachment::StencilAttachment => Self::Stencil,
+            GlAttachment::DepthStencilAttachment => Self::DepthStencil,
+            color => Self::Color(color_attachment_index(color.into())?),
+        })
+    }
+}
+/// Returns the index of the color attachment named by the `GL_COLOR_ATTACHMENTi` value `attachment`, if it names one of the
+/// supported color attachments (the first [`MAX_COLOR_ATTACHMENTS`])
+pub(crate) fn color_attachment_index(attachment: GLenum) -> Option<u32> {
+    attachment
+        .checked_sub(GL_COLOR_ATTACHMENT0)
+        .filter(|&idx| idx < MAX_COLOR_ATTACHMENTS)
+}
+
+/// The properties of a framebuffer attachment's image that are relevant to framebuffer completeness
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AttachedImage {
+    pub(crate) format: InternalFormat,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    /// Number of samples per pixel (1 for non-multisample images)
+    pub(crate) samples: u32,
+    /// Target of the attached texture and number of layers of its attached level, if the attachment is layered
+    pub(crate) layered: Option<(TextureTarget, u32)>,
+}
+/// The state of a framebuffer attachment point, as evaluated by [`framebuffer_status`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttachmentImage {
+    /// Nothing is attached
+    None,
+    /// An object is attached, but the attached image does not exist or can't be rendered to (e.g. its level was never specified,
+    /// lies outside of the texture's level range or the object was deleted)
+    Incomplete,
+    Image(AttachedImage),
+}
+/// The state of every attachment point of a framebuffer object, as evaluated by [`framebuffer_status`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FramebufferImages {
+    pub(crate) color: [AttachmentImage; MAX_COLOR_ATTACHMENTS as usize],
+    pub(crate) depth: AttachmentImage,
+    pub(crate) stencil: AttachmentImage,
+    /// Whether the depth and stencil attachments are the same image
+    pub(crate) shared_depth_stencil: bool,
+}
+
+/// Determines the completeness of a framebuffer object from the images attached to it (see section 9.4 of the OpenGL 4.6 core spec).
+/// Returns `GL_FRAMEBUFFER_COMPLETE` or the status describing the first completeness rule that is violated. Since OpenGL 4.1 the draw
+/// and read buffers no longer affect completeness
+pub(crate) fn framebuffer_status(images: &FramebufferImages) -> GLenum {
+    fn attachment_complete(image: AttachmentImage, renderable: fn(InternalFormat) -> bool) -> bool {
+        match image {
+            AttachmentImage::None => true,
+            AttachmentImage::Incomplete => false,
+            AttachmentImage::Image(image) => {
+                image.width > 0
+                    && image.height > 0
+                    && image.layered.is_none_or(|(_, layers)| layers > 0)
+                    && renderable(image.format)
+            }
+        }
+    }
+    let attachments_complete = images
+        .color
+        .iter()
+        .all(|&image| attachment_complete(image, InternalFormat::is_color_renderable))
+        && attachment_complete(images.depth, InternalFormat::has_depth)
+        && attachment_complete(images.stencil, InternalFormat::has_stencil);
+    if !attachments_complete {
+        return GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT;
+    }
+    let attached = |image: &AttachmentImage| match *image {
+        AttachmentImage::Image(image) => Some(image),
+        _ => None,
+    };
+    let color: Vec<AttachedImage> = images.color.iter().filter_map(attached).collect();
+    let depth_stencil = [images.depth, images.stencil];
+    let all: Vec<AttachedImage> = color
+        .iter()
+        .copied()
+        .chain(depth_stencil.iter().filter_map(attached))
+        .collect();
+    let Some(first) = all.first() else {
+        return GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT;
+    };
+    // Metal can only pair a packed depth stencil texture with itself
+    if let (AttachmentImage::Image(depth), AttachmentImage::Image(stencil)) =
+        (images.depth, images.stencil)
+        && !images.shared_depth_stencil
+        && (depth.format.has_stencil() || stencil.format.has_depth())
+    {
+        return GL_FRAMEBUFFER_UNSUPPORTED;
+    }
+    if all.iter().any(|image| image.samples != first.samples) {
+        return GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE;
+    }
+    if all.iter().any(|image| image.layered.is_some()) {
+        let color_target = color
+            .first()
+            .and_then(|image| image.layered)
+            .map(|(t, _)| t);
+        if all.iter().any(|image| image.layered.is_none())
+            || color
+                .iter()
+                .any(|image| image.layered.map(|(t, _)| t) != color_target)
+        {
+            return GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS;
+        }
+    }
+    GL_FRAMEBUFFER_COMPLETE
 }
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DrawBuffers {
@@ -108,3 +755,218 @@ impl InternalDrawable {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn image(format: InternalFormat) -> AttachedImage {
+        AttachedImage {
+            format,
+            width: 64,
+            height: 32,
+            samples: 1,
+            layered: None,
+        }
+    }
+    fn layered(format: InternalFormat, target: TextureTarget, layers: u32) -> AttachedImage {
+        AttachedImage {
+            layered: Some((target, layers)),
+            ..image(format)
+        }
+    }
+    fn images(color: &[AttachedImage]) -> FramebufferImages {
+        let mut images = FramebufferImages {
+            color: [AttachmentImage::None; MAX_COLOR_ATTACHMENTS as usize],
+            depth: AttachmentImage::None,
+            stencil: AttachmentImage::None,
+            shared_depth_stencil: false,
+        };
+        for (slot, &image) in images.color.iter_mut().zip(color) {
+            *slot = AttachmentImage::Image(image);
+        }
+        images
+    }
+
+    #[test]
+    fn complete() {
+        assert_eq!(
+            framebuffer_status(&images(&[image(InternalFormat::Rgba8)])),
+            GL_FRAMEBUFFER_COMPLETE
+        );
+        // color attachments don't have to be contiguous
+        let mut fb = images(&[]);
+        fb.color[3] = AttachmentImage::Image(image(InternalFormat::R32f));
+        fb.depth = AttachmentImage::Image(image(InternalFormat::DepthComponent32f));
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_COMPLETE);
+        // a depth-only framebuffer is complete
+        let mut fb = images(&[]);
+        fb.depth = AttachmentImage::Image(image(InternalFormat::DepthComponent24));
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_COMPLETE);
+    }
+    #[test]
+    fn incomplete_attachment() {
+        let incomplete = |fb: &FramebufferImages| {
+            assert_eq!(
+                framebuffer_status(fb),
+                GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT,
+                "{fb:?}"
+            );
+        };
+        // an object is attached, but not an image of it
+        let mut fb = images(&[image(InternalFormat::Rgba8)]);
+        fb.color[1] = AttachmentImage::Incomplete;
+        incomplete(&fb);
+        // zero-sized images
+        incomplete(&images(&[AttachedImage {
+            width: 0,
+            ..image(InternalFormat::Rgba8)
+        }]));
+        incomplete(&images(&[AttachedImage {
+            height: 0,
+            ..image(InternalFormat::Rgba8)
+        }]));
+        incomplete(&images(&[layered(
+            InternalFormat::Rgba8,
+            TextureTarget::Texture2DArray,
+            0,
+        )]));
+        // formats that can't be rendered to at the attachment point
+        incomplete(&images(&[image(InternalFormat::DepthComponent32f)]));
+        incomplete(&images(&[image(InternalFormat::CompressedRgba8Etc2Eac)]));
+        let mut fb = images(&[]);
+        fb.depth = AttachmentImage::Image(image(InternalFormat::Rgba8));
+        incomplete(&fb);
+        let mut fb = images(&[]);
+        fb.stencil = AttachmentImage::Image(image(InternalFormat::DepthComponent32f));
+        incomplete(&fb);
+        // incomplete attachments take precedence over the other rules
+        let mut fb = images(&[]);
+        fb.depth = AttachmentImage::Incomplete;
+        incomplete(&fb);
+    }
+    #[test]
+    fn missing_attachment() {
+        assert_eq!(
+            framebuffer_status(&images(&[])),
+            GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT
+        );
+    }
+    #[test]
+    fn mismatched_samples() {
+        let multisample = AttachedImage {
+            samples: 4,
+            ..image(InternalFormat::Rgba8)
+        };
+        assert_eq!(
+            framebuffer_status(&images(&[multisample, image(InternalFormat::Rgba8)])),
+            GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE
+        );
+        let mut fb = images(&[multisample]);
+        fb.depth = AttachmentImage::Image(image(InternalFormat::DepthComponent32f));
+        assert_eq!(
+            framebuffer_status(&fb),
+            GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE
+        );
+        fb.depth = AttachmentImage::Image(AttachedImage {
+            samples: 4,
+            ..image(InternalFormat::DepthComponent32f)
+        });
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_COMPLETE);
+    }
+    #[test]
+    fn layer_targets() {
+        let array = layered(InternalFormat::Rgba8, TextureTarget::Texture2DArray, 4);
+        let cube = layered(InternalFormat::Rgba8, TextureTarget::TextureCubeMap, 6);
+        assert_eq!(
+            framebuffer_status(&images(&[array])),
+            GL_FRAMEBUFFER_COMPLETE
+        );
+        // layered and non-layered attachments can't be mixed
+        assert_eq!(
+            framebuffer_status(&images(&[array, image(InternalFormat::Rgba8)])),
+            GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS
+        );
+        let mut fb = images(&[array]);
+        fb.depth = AttachmentImage::Image(image(InternalFormat::DepthComponent32f));
+        assert_eq!(
+            framebuffer_status(&fb),
+            GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS
+        );
+        // all layered color attachments must be from textures of the same target
+        assert_eq!(
+            framebuffer_status(&images(&[array, array])),
+            GL_FRAMEBUFFER_COMPLETE
+        );
+        assert_eq!(
+            framebuffer_status(&images(&[array, cube])),
+            GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS
+        );
+        // but a layered depth attachment doesn't have to match them
+        let mut fb = images(&[array]);
+        fb.depth = AttachmentImage::Image(layered(
+            InternalFormat::DepthComponent32f,
+            TextureTarget::TextureCubeMap,
+            6,
+        ));
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_COMPLETE);
+    }
+    #[test]
+    fn unsupported() {
+        // Metal can't pair a packed depth stencil texture with a different depth or stencil texture
+        let mut fb = images(&[image(InternalFormat::Rgba8)]);
+        fb.depth = AttachmentImage::Image(image(InternalFormat::Depth24Stencil8));
+        fb.stencil = AttachmentImage::Image(image(InternalFormat::StencilIndex8));
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_UNSUPPORTED);
+        fb.depth = AttachmentImage::Image(image(InternalFormat::DepthComponent32f));
+        fb.stencil = AttachmentImage::Image(image(InternalFormat::Depth32fStencil8));
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_UNSUPPORTED);
+        // separate depth and stencil textures are fine
+        fb.stencil = AttachmentImage::Image(image(InternalFormat::StencilIndex8));
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_COMPLETE);
+        // as is attaching the same depth stencil image to both
+        fb.depth = AttachmentImage::Image(image(InternalFormat::Depth24Stencil8));
+        fb.stencil = fb.depth;
+        fb.shared_depth_stencil = true;
+        assert_eq!(framebuffer_status(&fb), GL_FRAMEBUFFER_COMPLETE);
+    }
+    #[test]
+    fn default_framebuffer_invalidations() {
+        let mut invalidations = DefaultFramebufferInvalidations::default();
+        invalidations.invalidate(DefaultBuffer::new(GL_COLOR).unwrap());
+        invalidations.invalidate(DefaultBuffer::new(GL_DEPTH).unwrap());
+        assert!(invalidations.color(DrawBufferMode::FrontLeft));
+        assert!(!invalidations.color(DrawBufferMode::BackLeft));
+        assert!(invalidations.depth);
+        assert!(!invalidations.stencil);
+        // aliased modes don't name a single color buffer
+        assert!(!invalidations.color(DrawBufferMode::Front));
+        assert!(!invalidations.color(DrawBufferMode::FrontAndBack));
+        assert_eq!(DefaultBuffer::new(GL_COLOR_ATTACHMENT0), None);
+        // the next render pass takes the invalidations, resetting them
+        let taken = mem::take(&mut invalidations);
+        assert!(taken.color(DrawBufferMode::FrontLeft) && taken.depth);
+        assert!(!invalidations.color(DrawBufferMode::FrontLeft));
+        assert!(!invalidations.depth);
+    }
+    #[test]
+    fn invalidated_region_covers() {
+        let region = |x, y, width, height| InvalidatedRegion {
+            origin: [x, y],
+            width,
+            height,
+        };
+        assert!(region(0, 0, 64, 32).covers((64, 32)));
+        assert!(region(-8, -8, 100, 100).covers((64, 32)));
+        // sub-rect invalidations leave the image alone
+        assert!(!region(0, 0, 63, 32).covers((64, 32)));
+        assert!(!region(0, 0, 64, 31).covers((64, 32)));
+        assert!(!region(1, 0, 64, 32).covers((64, 32)));
+        assert!(!region(0, 1, 64, 32).covers((64, 32)));
+        // extents past the range of GLint don't overflow
+        assert!(!region(-1, 0, u32::MAX, 32).covers((u32::MAX, 32)));
+        assert!(region(-1, -1, u32::MAX, u32::MAX).covers((u32::MAX - 1, u32::MAX - 1)));
+    }
+}
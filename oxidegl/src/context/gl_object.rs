@@ -431,6 +431,12 @@ impl<Obj: NamedObject> NamedObjectList<Obj> {
     pub(crate) fn is(&self, name: ObjectName<Obj>) -> bool {
         self.get_opt(name).is_some()
     }
+    /// Whether the given object name was generated by this list (e.g. by glGen*), whether or not it has been initialized to an
+    /// object. Names are never reused, so this includes the names of deleted objects
+    #[inline]
+    pub(crate) fn is_reserved(&self, name: ObjectName<Obj>) -> bool {
+        name.to_idx() < self.objects.len()
+    }
     /// Immediately remove an object from the list
     #[inline]
     pub(crate) fn delete(&mut self, name: ObjectName<Obj>) {
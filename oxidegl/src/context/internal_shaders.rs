@@ -0,0 +1,399 @@
+use ahash::{HashMap, HashMapExt};
+use objc2::rc::Retained;
+use objc2_foundation::{NSString, ns_string};
+use objc2_metal::{
+    MTLColorWriteMask, MTLDevice, MTLLibrary, MTLPixelFormat, MTLPrimitiveTopologyClass,
+    MTLRenderPipelineColorAttachmentDescriptor, MTLRenderPipelineDescriptor,
+    MTLRenderPipelineState,
+};
+
+use crate::util::ProtoObjRef;
+
+use super::{debug::gl_debug, framebuffer::MAX_COLOR_ATTACHMENTS};
+
+/// Metal shaders that implement GL commands with no direct Metal equivalent. Every function draws a triangle strip of 4 vertices,
+/// covering the destination rectangle described by [`BlitParams`] for blits and the whole render area (of every layer) for clears
+const INTERNAL_SHADER_SRC: &str = r"
+#include <metal_stdlib>
+using namespace metal;
+
+struct BlitParams {
+    float2 src_min;
+    float2 src_max;
+    float2 dst_min;
+    float2 dst_max;
+};
+
+struct BlitVertex {
+    float4 position [[position]];
+    float2 src_coord;
+};
+
+vertex BlitVertex oxidegl_blit_vertex(uint vid [[vertex_id]], constant BlitParams& params [[buffer(0)]]) {
+    float2 corner = float2(float(vid & 1u), float(vid >> 1u));
+    BlitVertex out;
+    out.position = float4(mix(params.dst_min, params.dst_max, corner), 0.0, 1.0);
+    out.src_coord = mix(params.src_min, params.src_max, corner);
+    return out;
+}
+
+template <typename T>
+static uint2 texel_at(T tex, float2 coord) {
+    return uint2(clamp(coord, float2(0.0), float2(float(tex.get_width() - 1), float(tex.get_height() - 1))));
+}
+
+constexpr sampler blit_nearest(coord::pixel, filter::nearest, address::clamp_to_edge);
+constexpr sampler blit_linear(coord::pixel, filter::linear, address::clamp_to_edge);
+
+fragment float4 oxidegl_blit_float(BlitVertex in [[stage_in]], texture2d<float> src [[texture(0)]]) {
+    return src.sample(blit_nearest, in.src_coord);
+}
+fragment float4 oxidegl_blit_float_linear(BlitVertex in [[stage_in]], texture2d<float> src [[texture(0)]]) {
+    return src.sample(blit_linear, in.src_coord);
+}
+fragment int4 oxidegl_blit_int(BlitVertex in [[stage_in]], texture2d<int> src [[texture(0)]]) {
+    return src.read(texel_at(src, in.src_coord));
+}
+fragment uint4 oxidegl_blit_uint(BlitVertex in [[stage_in]], texture2d<uint> src [[texture(0)]]) {
+    return src.read(texel_at(src, in.src_coord));
+}
+fragment float4 oxidegl_blit_float_ms(BlitVertex in [[stage_in]], texture2d_ms<float> src [[texture(0)]]) {
+    uint2 coord = texel_at(src, in.src_coord);
+    uint samples = src.get_num_samples();
+    float4 sum = float4(0.0);
+    for (uint s = 0; s < samples; s++) {
+        sum += src.read(coord, s);
+    }
+    return sum / float(samples);
+}
+fragment int4 oxidegl_blit_int_ms(BlitVertex in [[stage_in]], texture2d_ms<int> src [[texture(0)]]) {
+    return src.read(texel_at(src, in.src_coord), 0);
+}
+fragment uint4 oxidegl_blit_uint_ms(BlitVertex in [[stage_in]], texture2d_ms<uint> src [[texture(0)]]) {
+    return src.read(texel_at(src, in.src_coord), 0);
+}
+
+struct Depth {
+    float depth [[depth(any)]];
+};
+fragment Depth oxidegl_blit_depth(BlitVertex in [[stage_in]], depth2d<float> src [[texture(0)]]) {
+    return { src.read(texel_at(src, in.src_coord)) };
+}
+fragment Depth oxidegl_blit_depth_ms(BlitVertex in [[stage_in]], depth2d_ms<float> src [[texture(0)]]) {
+    return { src.read(texel_at(src, in.src_coord), 0) };
+}
+
+struct Stencil {
+    uint stencil [[stencil]];
+};
+fragment Stencil oxidegl_blit_stencil(BlitVertex in [[stage_in]], texture2d<uint> src [[texture(0)]]) {
+    return { src.read(texel_at(src, in.src_coord)).r };
+}
+fragment Stencil oxidegl_blit_stencil_ms(BlitVertex in [[stage_in]], texture2d_ms<uint> src [[texture(0)]]) {
+    return { src.read(texel_at(src, in.src_coord), 0).r };
+}
+
+struct ClearParams {
+    float4 color;
+    float depth;
+};
+
+struct ClearVertex {
+    float4 position [[position]];
+    uint layer [[render_target_array_index]];
+};
+
+vertex ClearVertex oxidegl_clear_vertex(uint vid [[vertex_id]], uint iid [[instance_id]], constant ClearParams& params [[buffer(0)]]) {
+    float2 corner = float2(float(vid & 1u), float(vid >> 1u)) * 2.0 - 1.0;
+    ClearVertex out;
+    out.position = float4(corner, saturate(params.depth), 1.0);
+    out.layer = iid;
+    return out;
+}
+
+// integer clear colors are passed as the bits of the float4 color
+#define OXIDEGL_CLEAR_COLOR(idx) \
+    struct ClearFloat##idx { float4 color [[color(idx)]]; }; \
+    struct ClearInt##idx { int4 color [[color(idx)]]; }; \
+    struct ClearUint##idx { uint4 color [[color(idx)]]; }; \
+    fragment ClearFloat##idx oxidegl_clear_float##idx(constant ClearParams& params [[buffer(0)]]) { \
+        return { params.color }; \
+    } \
+    fragment ClearInt##idx oxidegl_clear_int##idx(constant ClearParams& params [[buffer(0)]]) { \
+        return { as_type<int4>(params.color) }; \
+    } \
+    fragment ClearUint##idx oxidegl_clear_uint##idx(constant ClearParams& params [[buffer(0)]]) { \
+        return { as_type<uint4>(params.color) }; \
+    }
+OXIDEGL_CLEAR_COLOR(0)
+OXIDEGL_CLEAR_COLOR(1)
+OXIDEGL_CLEAR_COLOR(2)
+OXIDEGL_CLEAR_COLOR(3)
+OXIDEGL_CLEAR_COLOR(4)
+OXIDEGL_CLEAR_COLOR(5)
+OXIDEGL_CLEAR_COLOR(6)
+OXIDEGL_CLEAR_COLOR(7)
+";
+
+/// Parameters of the internal vertex function, laid out like its `BlitParams` argument. Source coordinates are in texels of the
+/// source texture, destination coordinates in normalized device coordinates. The first corner of each rectangle is mapped onto the
+/// first corner of the other, which mirrors the image if the rectangles are oriented differently
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BlitParams {
+    pub(crate) src_min: [f32; 2],
+    pub(crate) src_max: [f32; 2],
+    pub(crate) dst_min: [f32; 2],
+    pub(crate) dst_max: [f32; 2],
+}
+
+/// A fragment function of the internal shader library that copies texels of a source texture to the destination rectangle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum BlitFunction {
+    /// Copies a float (or normalized) color texture with nearest filtering
+    Float,
+    /// Copies a float (or normalized) color texture with linear filtering
+    FloatLinear,
+    Int,
+    Uint,
+    /// Resolves a multisample float color texture by averaging its samples
+    FloatMultisample,
+    /// Resolves a multisample signed integer color texture by picking its first sample
+    IntMultisample,
+    /// Resolves a multisample unsigned integer color texture by picking its first sample
+    UintMultisample,
+    Depth,
+    DepthMultisample,
+    /// Copies the stencil values of a stencil texture (or a stencil view of a depth stencil texture) with stencil export
+    Stencil,
+    StencilMultisample,
+}
+impl BlitFunction {
+    fn name(self) -> &'static NSString {
+        match self {
+            Self::Float => ns_string!("oxidegl_blit_float"),
+            Self::FloatLinear => ns_string!("oxidegl_blit_float_linear"),
+            Self::Int => ns_string!("oxidegl_blit_int"),
+            Self::Uint => ns_string!("oxidegl_blit_uint"),
+            Self::FloatMultisample => ns_string!("oxidegl_blit_float_ms"),
+            Self::IntMultisample => ns_string!("oxidegl_blit_int_ms"),
+            Self::UintMultisample => ns_string!("oxidegl_blit_uint_ms"),
+            Self::Depth => ns_string!("oxidegl_blit_depth"),
+            Self::DepthMultisample => ns_string!("oxidegl_blit_depth_ms"),
+            Self::Stencil => ns_string!("oxidegl_blit_stencil"),
+            Self::StencilMultisample => ns_string!("oxidegl_blit_stencil_ms"),
+        }
+    }
+    /// Whether this function writes depth or stencil values rather than a color
+    fn writes_depth_stencil(self) -> bool {
+        matches!(
+            self,
+            Self::Depth | Self::DepthMultisample | Self::Stencil | Self::StencilMultisample
+        )
+    }
+}
+
+/// Parameters of the internal clear functions, laid out like their `ClearParams` argument. Integer colors are stored as the bits of
+/// `color`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ClearParams {
+    pub(crate) color: [f32; 4],
+    pub(crate) depth: f32,
+}
+
+/// The attachment formats and sample count of a render pass, which every pipeline used in it has to match. Formats of missing
+/// attachments are [`MTLPixelFormat::Invalid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct PassFormats {
+    pub(crate) color: [MTLPixelFormat; MAX_COLOR_ATTACHMENTS as usize],
+    pub(crate) depth: MTLPixelFormat,
+    pub(crate) stencil: MTLPixelFormat,
+    pub(crate) samples: usize,
+}
+impl Default for PassFormats {
+    fn default() -> Self {
+        Self {
+            color: [MTLPixelFormat::Invalid; MAX_COLOR_ATTACHMENTS as usize],
+            depth: MTLPixelFormat::Invalid,
+            stencil: MTLPixelFormat::Invalid,
+            samples: 1,
+        }
+    }
+}
+
+/// A fragment function of the internal shader library that clears an attachment of the current render pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ClearFunction {
+    /// Writes a float (or normalized) color to the color attachment with the given index
+    Float(u32),
+    /// Writes a signed integer color to the color attachment with the given index
+    Int(u32),
+    /// Writes an unsigned integer color to the color attachment with the given index
+    Uint(u32),
+    /// Writes no color, only the depth of the rasterized primitive (and the stencil reference value, through the depth stencil
+    /// state)
+    DepthStencil,
+}
+impl ClearFunction {
+    fn name(self) -> Option<Retained<NSString>> {
+        let (kind, idx) = match self {
+            Self::Float(idx) => ("float", idx),
+            Self::Int(idx) => ("int", idx),
+            Self::Uint(idx) => ("uint", idx),
+            Self::DepthStencil => return None,
+        };
+        Some(NSString::from_str(&format!("oxidegl_clear_{kind}{idx}")))
+    }
+}
+
+/// Returns whether the Metal pixel format `format` has a depth and a stencil aspect, respectively
+pub(crate) fn depth_stencil_aspects(format: MTLPixelFormat) -> (bool, bool) {
+    match format {
+        MTLPixelFormat::Depth16Unorm | MTLPixelFormat::Depth32Float => (true, false),
+        MTLPixelFormat::Stencil8 => (false, true),
+        MTLPixelFormat::Depth24Unorm_Stencil8 | MTLPixelFormat::Depth32Float_Stencil8 => {
+            (true, true)
+        }
+        _ => (false, false),
+    }
+}
+
+/// The internal shader library, compiled on first use, and the render pipelines built from it
+#[derive(Debug)]
+pub(crate) struct InternalPipelines {
+    library: Option<ProtoObjRef<dyn MTLLibrary>>,
+    pipelines: HashMap<(BlitFunction, MTLPixelFormat), ProtoObjRef<dyn MTLRenderPipelineState>>,
+    clear_pipelines: HashMap<
+        (ClearFunction, MTLColorWriteMask, PassFormats),
+        ProtoObjRef<dyn MTLRenderPipelineState>,
+    >,
+}
+impl InternalPipelines {
+    pub(crate) fn new() -> Self {
+        Self {
+            library: None,
+            pipelines: HashMap::new(),
+            clear_pipelines: HashMap::new(),
+        }
+    }
+    fn library(&mut self, device: &ProtoObjRef<dyn MTLDevice>) -> &ProtoObjRef<dyn MTLLibrary> {
+        self.library.get_or_insert_with(|| {
+            gl_debug!("compiling internal shader library");
+            let lib = device
+                .newLibraryWithSource_options_error(&NSString::from_str(INTERNAL_SHADER_SRC), None)
+                .expect("failed to compile OxideGL internal shaders");
+            lib.setLabel(Some(ns_string!("OxideGL internal shaders")));
+            lib
+        })
+    }
+    /// Returns the pipeline that runs `function` on a render pass with a single (non-multisample) attachment of pixel format
+    /// `format`. Depth stencil formats are bound to both the depth and the stencil attachment if they have both aspects
+    pub(crate) fn pipeline(
+        &mut self,
+        device: &ProtoObjRef<dyn MTLDevice>,
+        function: BlitFunction,
+        format: MTLPixelFormat,
+    ) -> ProtoObjRef<dyn MTLRenderPipelineState> {
+        if let Some(pipeline) = self.pipelines.get(&(function, format)) {
+            return pipeline.clone();
+        }
+        let library = self.library(device);
+        gl_debug!("creating internal {function:?} pipeline for {format:?}");
+        let desc = MTLRenderPipelineDescriptor::new();
+        #[cfg(debug_assertions)]
+        desc.setLabel(Some(ns_string!("OxideGL internal pipeline")));
+        desc.setVertexFunction(Some(
+            &library
+                .newFunctionWithName(ns_string!("oxidegl_blit_vertex"))
+                .expect("internal vertex function missing"),
+        ));
+        desc.setFragmentFunction(Some(
+            &library
+                .newFunctionWithName(function.name())
+                .expect("internal fragment function missing"),
+        ));
+        if function.writes_depth_stencil() {
+            let (depth, stencil) = depth_stencil_aspects(format);
+            if depth {
+                desc.setDepthAttachmentPixelFormat(format);
+            }
+            if stencil {
+                desc.setStencilAttachmentPixelFormat(format);
+            }
+        } else {
+            let attachment = MTLRenderPipelineColorAttachmentDescriptor::new();
+            attachment.setPixelFormat(format);
+            // Safety: index 0 is always a valid color attachment index
+            unsafe {
+                desc.colorAttachments()
+                    .setObject_atIndexedSubscript(Some(&attachment), 0);
+            }
+        }
+        let pipeline = device
+            .newRenderPipelineStateWithDescriptor_error(&desc)
+            .expect("failed to create internal pipeline state");
+        self.pipelines.insert((function, format), pipeline.clone());
+        pipeline
+    }
+    /// Returns the pipeline that runs the clear `function` in a render pass with attachments of `formats`, writing the channels of
+    /// `color_mask` of the color attachment it clears. Other color attachments are left unwritten
+    pub(crate) fn clear_pipeline(
+        &mut self,
+        device: &ProtoObjRef<dyn MTLDevice>,
+        function: ClearFunction,
+        color_mask: MTLColorWriteMask,
+        formats: &PassFormats,
+    ) -> ProtoObjRef<dyn MTLRenderPipelineState> {
+        let key = (function, color_mask, *formats);
+        if let Some(pipeline) = self.clear_pipelines.get(&key) {
+            return pipeline.clone();
+        }
+        let library = self.library(device);
+        gl_debug!("creating internal {function:?} ({color_mask:?}) clear pipeline for {formats:?}");
+        let desc = MTLRenderPipelineDescriptor::new();
+        #[cfg(debug_assertions)]
+        desc.setLabel(Some(ns_string!("OxideGL internal clear pipeline")));
+        desc.setVertexFunction(Some(
+            &library
+                .newFunctionWithName(ns_string!("oxidegl_clear_vertex"))
+                .expect("internal vertex function missing"),
+        ));
+        if let Some(name) = function.name() {
+            desc.setFragmentFunction(Some(
+                &library
+                    .newFunctionWithName(&name)
+                    .expect("internal fragment function missing"),
+            ));
+        }
+        // Safety: the clear vertex function writes the render target array index of triangle strips, which requires a triangle
+        // input topology
+        unsafe { desc.setInputPrimitiveTopology(MTLPrimitiveTopologyClass::Triangle) };
+        desc.setRasterSampleCount(formats.samples);
+        for (idx, &format) in formats.color.iter().enumerate() {
+            let attachment = MTLRenderPipelineColorAttachmentDescriptor::new();
+            attachment.setPixelFormat(format);
+            let cleared = matches!(
+                function,
+                ClearFunction::Float(i) | ClearFunction::Int(i) | ClearFunction::Uint(i) if i as usize == idx
+            );
+            attachment.setWriteMask(if cleared {
+                color_mask
+            } else {
+                MTLColorWriteMask::None
+            });
+            // Safety: `idx` is less than the maximum number of color attachments
+            unsafe {
+                desc.colorAttachments()
+                    .setObject_atIndexedSubscript(Some(&attachment), idx);
+            }
+        }
+        desc.setDepthAttachmentPixelFormat(formats.depth);
+        desc.setStencilAttachmentPixelFormat(formats.stencil);
+        let pipeline = device
+            .newRenderPipelineStateWithDescriptor_error(&desc)
+            .expect("failed to create internal pipeline state");
+        self.clear_pipelines.insert(key, pipeline.clone());
+        pipeline
+    }
+}
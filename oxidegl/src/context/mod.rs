@@ -1,5 +1,5 @@
 use self::error::GetErrorReturnValue;
-use self::state::GLState;
+use self::state::{GLState, TextureUnits};
 use crate::enums::ErrorCode;
 use debug::gl_trace;
 use likely_stable::if_likely;
@@ -26,6 +26,7 @@ pub(crate) mod commands;
 pub(crate) mod debug;
 pub(crate) mod error;
 pub(crate) mod framebuffer;
+pub(crate) mod internal_shaders;
 pub(crate) mod pixel;
 pub(crate) mod program;
 pub(crate) mod shader;
@@ -47,11 +48,18 @@ pub struct Context {
 }
 
 impl Context {
+    /// Creates a context whose default framebuffer has (at least) `samples` samples per pixel, or is single sampled if `samples` is
+    /// 0 or 1
     #[must_use]
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(samples: u32) -> Self {
+        let platform_state =
+            PlatformState::new(MTLPixelFormat::BGRA8Unorm_sRGB, None, None, samples);
         Self {
-            gl_state: GLState::default(),
-            platform_state: PlatformState::new(MTLPixelFormat::BGRA8Unorm_sRGB, None, None),
+            gl_state: GLState {
+                texture_units: TextureUnits::new(&platform_state.props),
+                ..GLState::default()
+            },
+            platform_state,
         }
     }
     pub fn set_view(&mut self, view: &Retained<NSView>) {
@@ -69,7 +77,7 @@ impl Context {
 
 impl Default for Context {
     fn default() -> Self {
-        Self::new()
+        Self::new(1)
     }
 }
 
@@ -4,15 +4,21 @@
 //!
 //!
 //!
-use std::{marker::PhantomData, mem::MaybeUninit};
+use std::{marker::PhantomData, mem::MaybeUninit, ops::Range};
 
 use half::f16;
+use objc2_metal::MTLPixelFormat;
 
 use crate::{
-    context::debug::{gl_trace, gl_warn},
+    context::{
+        debug::{gl_trace, gl_warn},
+        texture::TextureExtent,
+    },
     enums::{InternalFormat, PixelFormat, PixelType},
 };
+mod compression;
 mod internal_formats;
+pub(crate) use compression::{COMPRESSED_BLOCK_DIM, decompress};
 trait ChannelType {}
 macro_rules! decl_channels {
     ($($i:ident),+) => {
@@ -163,6 +169,7 @@ decl_simple_formats! {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
 struct SingleChannelFormat<T, Ctype: ChannelType> {
     value: T,
     phantom: PhantomData<Ctype>,
@@ -229,6 +236,46 @@ impl<Int: GlIntegerType + Copy> ConvertChannel<f32> for NormalizedIntChannel<Int
         self.0.normalized_to_float()
     }
 }
+impl<Int: GlIntegerType + Copy> ConvertChannel<NormalizedIntChannel<Int>> for f32 {
+    fn convert_channel(self) -> NormalizedIntChannel<Int> {
+        NormalizedIntChannel(Int::float_to_normalized(self))
+    }
+}
+impl ConvertChannel<f32> for f16 {
+    fn convert_channel(self) -> f32 {
+        self.to_f32()
+    }
+}
+impl ConvertChannel<f16> for f32 {
+    fn convert_channel(self) -> f16 {
+        f16::from_f32(self)
+    }
+}
+macro_rules! impl_default_channel_values {
+    ( $( $chan:ident => ($float:expr, $int:expr) ),+ ) => {
+        $(
+            impl ConvertChannel<f32> for DefaultChannelValue<$chan> {
+                fn convert_channel(self) -> f32 {
+                    $float
+                }
+            }
+            impl ConvertChannel<i64> for DefaultChannelValue<$chan> {
+                fn convert_channel(self) -> i64 {
+                    $int
+                }
+            }
+        )+
+    };
+}
+// Missing components are filled in with (0, 0, 0, 1)
+impl_default_channel_values! {
+    Red => (0.0, 0),
+    Green => (0.0, 0),
+    Blue => (0.0, 0),
+    Alpha => (1.0, 1),
+    Depth => (0.0, 0),
+    Stencil => (0.0, 0)
+}
 
 // Trait that generalizes over integers of all bitwidths supported as OpenGL pixel types
 trait GlIntegerType: Sized + Copy {
@@ -240,6 +287,8 @@ trait GlIntegerType: Sized + Copy {
     const NORMALIZATION_FACTOR: u8 = Self::BITWIDTH - Self::SIGNED as u8;
     /// Interprets this integer as a normalized fixed-point float according the the GL spec, returning the closest 32 bit floating point value to the normalized result
     fn normalized_to_float(self) -> f32;
+    /// Converts a float into a normalized fixed-point integer according to the GL spec, clamping it to the representable range
+    fn float_to_normalized(val: f32) -> Self;
     /// Creates an instance of this number from a u32, truncating the most significant bits to size
     fn from_bits(bits: u32) -> Self;
     /// bitcasts this number into (the low bits of) a u32
@@ -271,8 +320,16 @@ macro_rules! impl_gl_integer {
                 #[allow(clippy::cast_possible_truncation)]
                 fn normalized_to_float(self) -> f32 {
                     let f1 = <$ftype>::from(self);
-                    let factor = <$ftype>::from(2u8).powi(i32::from(Self::NORMALIZATION_FACTOR) );
-                    (f1 / factor) as f32
+                    let factor = <$ftype>::from(2u8).powi(i32::from(Self::NORMALIZATION_FACTOR)) - 1.0;
+                    // the most negative value of a signed type maps to -1.0 as well
+                    (f1 / factor).max(-1.0) as f32
+                }
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn float_to_normalized(val: f32) -> Self {
+                    let factor = <$ftype>::from(2u8).powi(i32::from(Self::NORMALIZATION_FACTOR)) - 1.0;
+                    let min = if Self::SIGNED { -1.0 } else { 0.0 };
+                    // float to int casts saturate, and NaN maps to 0
+                    ((val as $ftype).clamp(min, 1.0) * factor).round() as $t
                 }
                 fn from_bits(bits: u32) -> Self {
                     const { assert!((Self::BITWIDTH / 8) as usize <= 4, "Bad GlInteger implementation") }
@@ -319,73 +376,941 @@ impl_gl_integer! {
     (i32, true, 32, f64)
 }
 
-unsafe fn convert_pixel_array<T: Copy + ConvertPixel<Out>, Out: Copy>(
-    swap_bytes: bool,
-    buf: &[T],
-) -> Box<[Out]> {
-    let mut out = Vec::with_capacity(buf.len());
-    for i in buf.iter().copied() {
-        out.push(i.convert_pixel());
-    }
-    out.into_boxed_slice()
-}
-//fn internal_format_ispatch<T>(pix_buf: )
-fn convert_dispatch_test(buf: *const u8, fmt: GlPixelTypeFormat, dst: InternalFormat) {
-    // special type handling
-    match fmt.ty {
-        // "normal" pixel types
-        PixelType::Byte => match fmt.fmt {
-            PixelFormat::DepthComponent => todo!(),
-            PixelFormat::Red => todo!(),
-            PixelFormat::Green => todo!(),
-            PixelFormat::Blue => todo!(),
-            PixelFormat::Alpha => todo!(),
-            PixelFormat::Rgb => todo!(),
-            PixelFormat::Rgba => todo!(),
-            PixelFormat::Bgr => todo!(),
-            PixelFormat::Bgra => todo!(),
-            PixelFormat::RedInteger => todo!(),
-            PixelFormat::GreenInteger => todo!(),
-            PixelFormat::BlueInteger => todo!(),
-            PixelFormat::RgbInteger => todo!(),
-            PixelFormat::RgbaInteger => todo!(),
-            PixelFormat::BgrInteger => todo!(),
-            PixelFormat::BgraInteger => todo!(),
-            PixelFormat::DepthStencil => todo!(),
-            PixelFormat::Rg => todo!(),
-            PixelFormat::RgInteger => todo!(),
-            PixelFormat::StencilIndex => todo!(),
-            _ => unreachable!(),
-        },
-        PixelType::UnsignedByte => todo!(),
-        PixelType::Short => todo!(),
-        PixelType::UnsignedShort => todo!(),
-        PixelType::Int => todo!(),
-        PixelType::UnsignedInt => todo!(),
-        PixelType::Float => todo!(),
-        PixelType::HalfFloat => todo!(),
-
-        PixelType::UnsignedByte332 => todo!(),
-        PixelType::UnsignedShort4444 => todo!(),
-        PixelType::UnsignedShort5551 => todo!(),
-        PixelType::UnsignedInt8888 => todo!(),
-        PixelType::UnsignedInt1010102 => todo!(),
-        PixelType::UnsignedByte233Rev => todo!(),
-        PixelType::UnsignedShort565 => todo!(),
-        PixelType::UnsignedShort565Rev => todo!(),
-        PixelType::UnsignedShort4444Rev => todo!(),
-        PixelType::UnsignedShort1555Rev => todo!(),
-        PixelType::UnsignedInt8888Rev => todo!(),
-        PixelType::UnsignedInt2101010Rev => todo!(),
-        PixelType::UnsignedInt5999Rev => todo!(),
-        PixelType::Float32UnsignedInt248Rev => todo!(),
-        PixelType::UnsignedInt248 => todo!(),
-        PixelType::UnsignedInt10F11F11FRev => todo!(),
+macro_rules! impl_integer_channels {
+    ( $( $t:ident ),+ ) => {
+        $(
+            impl ConvertChannel<i64> for $t {
+                fn convert_channel(self) -> i64 {
+                    i64::from(self)
+                }
+            }
+            impl ConvertChannel<$t> for i64 {
+                fn convert_channel(self) -> $t {
+                    // integer conversions clamp to the representable range of the destination type
+                    $t::try_from(self).unwrap_or(if self < 0 { $t::MIN } else { $t::MAX })
+                }
+            }
+        )+
+    };
+}
+impl_integer_channels!(u8, u16, u32, i8, i16, i32);
+
+/// An unsigned normalized (or, with integer formats, unsigned integer) field of a packed pixel type, `BITS` bits wide
+#[derive(Clone, Copy, Debug)]
+struct PackedChannel<const BITS: u32>(u32);
+/// An unsigned floating point field of a packed pixel type with a 5 bit exponent and a `BITS - 5` bit mantissa,
+/// as used by `GL_UNSIGNED_INT_10F_11F_11F_REV`
+#[derive(Clone, Copy, Debug)]
+struct PackedUfloatChannel<const BITS: u32>(u32);
+
+/// A bitfield of a packed pixel type
+trait PackedField: Copy {
+    const BITS: u32;
+    const MASK: u32 = (1 << Self::BITS) - 1;
+    /// Creates this field from the low `BITS` bits of `bits`
+    fn from_bits(bits: u32) -> Self;
+    /// Returns the value of this field in the low `BITS` bits of a u32
+    fn to_bits(self) -> u32;
+}
+impl<const BITS: u32> PackedField for PackedChannel<BITS> {
+    const BITS: u32 = BITS;
+    fn from_bits(bits: u32) -> Self {
+        Self(bits & Self::MASK)
+    }
+    fn to_bits(self) -> u32 {
+        self.0 & Self::MASK
+    }
+}
+impl<const BITS: u32> PackedField for PackedUfloatChannel<BITS> {
+    const BITS: u32 = BITS;
+    fn from_bits(bits: u32) -> Self {
+        Self(bits & Self::MASK)
+    }
+    fn to_bits(self) -> u32 {
+        self.0 & Self::MASK
+    }
+}
+impl<const BITS: u32> ConvertChannel<f32> for PackedChannel<BITS> {
+    #[expect(clippy::cast_possible_truncation)]
+    fn convert_channel(self) -> f32 {
+        (f64::from(self.to_bits()) / f64::from(Self::MASK)) as f32
+    }
+}
+impl<const BITS: u32> ConvertChannel<PackedChannel<BITS>> for f32 {
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn convert_channel(self) -> PackedChannel<BITS> {
+        let max = f64::from(PackedChannel::<BITS>::MASK);
+        // float to int casts saturate, and NaN maps to 0
+        PackedChannel((f64::from(self).clamp(0.0, 1.0) * max).round() as u32)
+    }
+}
+impl<const BITS: u32> ConvertChannel<i64> for PackedChannel<BITS> {
+    fn convert_channel(self) -> i64 {
+        i64::from(self.to_bits())
+    }
+}
+impl<const BITS: u32> ConvertChannel<PackedChannel<BITS>> for i64 {
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn convert_channel(self) -> PackedChannel<BITS> {
+        PackedChannel(self.clamp(0, i64::from(PackedChannel::<BITS>::MASK)) as u32)
+    }
+}
+impl<C: ChannelType, const BITS: u32> ConvertChannel<PackedChannel<BITS>> for DefaultChannelValue<C>
+where
+    Self: ConvertChannel<f32>,
+{
+    fn convert_channel(self) -> PackedChannel<BITS> {
+        <Self as ConvertChannel<f32>>::convert_channel(self).convert_channel()
+    }
+}
+impl<const BITS: u32> ConvertChannel<f32> for PackedUfloatChannel<BITS> {
+    #[expect(clippy::cast_possible_truncation)]
+    fn convert_channel(self) -> f32 {
+        // the exponent is positioned like that of a half float, with the low bits of the mantissa (and the sign bit) cut off
+        f16::from_bits((self.to_bits() << (15 - BITS)) as u16).to_f32()
+    }
+}
+impl<const BITS: u32> ConvertChannel<PackedUfloatChannel<BITS>> for f32 {
+    fn convert_channel(self) -> PackedUfloatChannel<BITS> {
+        // negative values (including negative infinity) clamp to 0 and finite values that are too large clamp to the largest finite
+        // value, positive infinity and NaN are preserved
+        let clamped = if self.is_nan() || self == f32::INFINITY {
+            self
+        } else {
+            self.clamp(0.0, f16::MAX.to_f32())
+        };
+        PackedUfloatChannel::from_bits(u32::from(f16::from_f32(clamped).to_bits()) >> (15 - BITS))
+    }
+}
+
+macro_rules! decl_packed_formats {
+    (   $(
+            $( #[doc = $doc:expr] )*
+            struct $name:ident($int:ty) {
+                $( $chan:ident : $shift:literal => $field:ty ),+ $(,)?
+            } default: [$($dty:ty),*]
+        ),+
+        $(,)?
+    ) => {
+        $(
+            $( #[doc = $doc] )*
+            #[derive(Debug, Clone, Copy)]
+            #[repr(transparent)]
+            struct $name($int);
+            $(
+                impl ChannelSource<$chan> for $name {
+                    type Channel = $field;
+                    #[inline]
+                    fn channel(self) -> Self::Channel {
+                        <$field as PackedField>::from_bits(u32::from(self.0) >> $shift)
+                    }
+                }
+            )+
+            $(
+                channelsource_default! {
+                    default impl ChannelSource<$dty> for $name {}
+                }
+            )*
+            impl<T: $( ChannelSource<$chan, Channel: ConvertChannel<$field>> + )+ Copy> ConvertPixel<$name> for T {
+                #[inline]
+                #[allow(clippy::cast_possible_truncation)]
+                fn convert_pixel(self) -> $name {
+                    let bits = 0 $(
+                        | <$field as PackedField>::to_bits(<Self as ChannelSource<$chan>>::channel(self).convert_channel()) << $shift
+                    )+;
+                    $name(bits as $int)
+                }
+            }
+        )+
+    };
+}
+
+// Fields are listed from most to least significant for the normal packed types, and from least to most significant
+// for the _REV types. Formats with BGR(A) component ordering swap the positions of the red and blue fields
+decl_packed_formats! {
+    /// `GL_UNSIGNED_BYTE_3_3_2` with `GL_RGB`
+    struct Rgb332(u8) { Red: 5 => PackedChannel<3>, Green: 2 => PackedChannel<3>, Blue: 0 => PackedChannel<2> } default: [Alpha, Depth, Stencil],
+    /// `GL_UNSIGNED_BYTE_2_3_3_REV` with `GL_RGB`
+    struct Rgb233Rev(u8) { Red: 0 => PackedChannel<3>, Green: 3 => PackedChannel<3>, Blue: 6 => PackedChannel<2> } default: [Alpha, Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_5_6_5` with `GL_RGB`. Same layout as `MTLPixelFormatB5G6R5Unorm`
+    struct Rgb565(u16) { Red: 11 => PackedChannel<5>, Green: 5 => PackedChannel<6>, Blue: 0 => PackedChannel<5> } default: [Alpha, Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_5_6_5_REV` with `GL_RGB`
+    struct Rgb565Rev(u16) { Red: 0 => PackedChannel<5>, Green: 5 => PackedChannel<6>, Blue: 11 => PackedChannel<5> } default: [Alpha, Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_4_4_4_4` with `GL_RGBA`. Same layout as `MTLPixelFormatABGR4Unorm`
+    struct Rgba4444(u16) { Red: 12 => PackedChannel<4>, Green: 8 => PackedChannel<4>, Blue: 4 => PackedChannel<4>, Alpha: 0 => PackedChannel<4> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_4_4_4_4` with `GL_BGRA`
+    struct Bgra4444(u16) { Blue: 12 => PackedChannel<4>, Green: 8 => PackedChannel<4>, Red: 4 => PackedChannel<4>, Alpha: 0 => PackedChannel<4> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_4_4_4_4_REV` with `GL_RGBA`
+    struct Rgba4444Rev(u16) { Red: 0 => PackedChannel<4>, Green: 4 => PackedChannel<4>, Blue: 8 => PackedChannel<4>, Alpha: 12 => PackedChannel<4> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_4_4_4_4_REV` with `GL_BGRA`
+    struct Bgra4444Rev(u16) { Blue: 0 => PackedChannel<4>, Green: 4 => PackedChannel<4>, Red: 8 => PackedChannel<4>, Alpha: 12 => PackedChannel<4> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_5_5_5_1` with `GL_RGBA`. Same layout as `MTLPixelFormatA1BGR5Unorm`
+    struct Rgba5551(u16) { Red: 11 => PackedChannel<5>, Green: 6 => PackedChannel<5>, Blue: 1 => PackedChannel<5>, Alpha: 0 => PackedChannel<1> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_5_5_5_1` with `GL_BGRA`
+    struct Bgra5551(u16) { Blue: 11 => PackedChannel<5>, Green: 6 => PackedChannel<5>, Red: 1 => PackedChannel<5>, Alpha: 0 => PackedChannel<1> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_1_5_5_5_REV` with `GL_RGBA`
+    struct Rgba1555Rev(u16) { Red: 0 => PackedChannel<5>, Green: 5 => PackedChannel<5>, Blue: 10 => PackedChannel<5>, Alpha: 15 => PackedChannel<1> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_SHORT_1_5_5_5_REV` with `GL_BGRA`. Same layout as `MTLPixelFormatBGR5A1Unorm`
+    struct Bgra1555Rev(u16) { Blue: 0 => PackedChannel<5>, Green: 5 => PackedChannel<5>, Red: 10 => PackedChannel<5>, Alpha: 15 => PackedChannel<1> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_8_8_8_8` with `GL_RGBA`
+    struct Rgba8888(u32) { Red: 24 => PackedChannel<8>, Green: 16 => PackedChannel<8>, Blue: 8 => PackedChannel<8>, Alpha: 0 => PackedChannel<8> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_8_8_8_8` with `GL_BGRA`
+    struct Bgra8888(u32) { Blue: 24 => PackedChannel<8>, Green: 16 => PackedChannel<8>, Red: 8 => PackedChannel<8>, Alpha: 0 => PackedChannel<8> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_8_8_8_8_REV` with `GL_RGBA`
+    struct Rgba8888Rev(u32) { Red: 0 => PackedChannel<8>, Green: 8 => PackedChannel<8>, Blue: 16 => PackedChannel<8>, Alpha: 24 => PackedChannel<8> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_8_8_8_8_REV` with `GL_BGRA`
+    struct Bgra8888Rev(u32) { Blue: 0 => PackedChannel<8>, Green: 8 => PackedChannel<8>, Red: 16 => PackedChannel<8>, Alpha: 24 => PackedChannel<8> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_10_10_10_2` with `GL_RGBA`
+    struct Rgba1010102(u32) { Red: 22 => PackedChannel<10>, Green: 12 => PackedChannel<10>, Blue: 2 => PackedChannel<10>, Alpha: 0 => PackedChannel<2> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_10_10_10_2` with `GL_BGRA`
+    struct Bgra1010102(u32) { Blue: 22 => PackedChannel<10>, Green: 12 => PackedChannel<10>, Red: 2 => PackedChannel<10>, Alpha: 0 => PackedChannel<2> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_2_10_10_10_REV` with `GL_RGBA`. Same layout as `MTLPixelFormatRGB10A2Unorm`
+    struct Rgba2101010Rev(u32) { Red: 0 => PackedChannel<10>, Green: 10 => PackedChannel<10>, Blue: 20 => PackedChannel<10>, Alpha: 30 => PackedChannel<2> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_2_10_10_10_REV` with `GL_BGRA`. Same layout as `MTLPixelFormatBGR10A2Unorm`
+    struct Bgra2101010Rev(u32) { Blue: 0 => PackedChannel<10>, Green: 10 => PackedChannel<10>, Red: 20 => PackedChannel<10>, Alpha: 30 => PackedChannel<2> } default: [Depth, Stencil],
+    /// `GL_UNSIGNED_INT_10F_11F_11F_REV` with `GL_RGB`. Same layout as `MTLPixelFormatRG11B10Float`
+    struct Rgb10F11F11FRev(u32) { Red: 0 => PackedUfloatChannel<11>, Green: 11 => PackedUfloatChannel<11>, Blue: 22 => PackedUfloatChannel<10> } default: [Alpha, Depth, Stencil],
+    /// `GL_UNSIGNED_INT_24_8` with `GL_DEPTH_STENCIL`
+    struct DepthStencil248(u32) { Depth: 8 => PackedChannel<24>, Stencil: 0 => PackedChannel<8> } default: [Red, Green, Blue, Alpha],
+}
+
+/// `GL_UNSIGNED_INT_5_9_9_9_REV` with `GL_RGB`: three 9 bit mantissas sharing a 5 bit exponent. Same layout as `MTLPixelFormatRGB9E5Float`
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+struct Rgb9E5(u32);
+impl Rgb9E5 {
+    const MANTISSA_BITS: i32 = 9;
+    const EXP_BIAS: i32 = 15;
+    const MAX_EXP: i32 = 31;
+    #[expect(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+    fn component(self, shift: u32) -> f32 {
+        let exp = (self.0 >> 27) as i32;
+        let mantissa = (self.0 >> shift) & 0x1ff;
+        (f64::from(mantissa) * 2f64.powi(exp - Self::EXP_BIAS - Self::MANTISSA_BITS)) as f32
+    }
+}
+macro_rules! impl_rgb9e5_channels {
+    ( $( $chan:ident: $shift:literal ),+ ) => {
+        $(
+            impl ChannelSource<$chan> for Rgb9E5 {
+                type Channel = f32;
+                fn channel(self) -> f32 {
+                    self.component($shift)
+                }
+            }
+        )+
+    };
+}
+impl_rgb9e5_channels!(Red: 0, Green: 9, Blue: 18);
+channelsource_default! {
+    default impl ChannelSource<Alpha> for Rgb9E5 {}
+}
+channelsource_default! {
+    default impl ChannelSource<Depth> for Rgb9E5 {}
+}
+channelsource_default! {
+    default impl ChannelSource<Stencil> for Rgb9E5 {}
+}
+impl<T> ConvertPixel<Rgb9E5> for T
+where
+    T: ChannelSource<Red, Channel: ConvertChannel<f32>>
+        + ChannelSource<Green, Channel: ConvertChannel<f32>>
+        + ChannelSource<Blue, Channel: ConvertChannel<f32>>
+        + Copy,
+{
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "the shared exponent and mantissas are computed to fit in their fields"
+    )]
+    fn convert_pixel(self) -> Rgb9E5 {
+        // encoding as specified by the GL spec (section 8.25 of the 4.6 core spec)
+        let (n, b, e_max) = (Rgb9E5::MANTISSA_BITS, Rgb9E5::EXP_BIAS, Rgb9E5::MAX_EXP);
+        let shared_max = f64::from((1 << n) - 1) / f64::from(1 << n) * 2f64.powi(e_max - b);
+        // NaN maps to 0
+        let clamp = |c: f32| {
+            let c = f64::from(c);
+            if c.is_nan() {
+                0.0
+            } else {
+                c.clamp(0.0, shared_max)
+            }
+        };
+        let r = clamp(<Self as ChannelSource<Red>>::channel(self).convert_channel());
+        let g = clamp(<Self as ChannelSource<Green>>::channel(self).convert_channel());
+        let bl = clamp(<Self as ChannelSource<Blue>>::channel(self).convert_channel());
+        let max = r.max(g).max(bl);
+        let exp_p = (max.log2().floor() as i32).max(-b - 1) + 1 + b;
+        let max_s = (max / 2f64.powi(exp_p - b - n) + 0.5).floor();
+        let exp = if max_s >= f64::from(1 << n) {
+            exp_p + 1
+        } else {
+            exp_p
+        };
+        let scale = 2f64.powi(exp - b - n);
+        let encode = |c: f64| ((c / scale + 0.5).floor() as u32).min(0x1ff);
+        Rgb9E5(encode(r) | encode(g) << 9 | encode(bl) << 18 | (exp as u32) << 27)
+    }
+}
+
+/// `GL_FLOAT_32_UNSIGNED_INT_24_8_REV` with `GL_DEPTH_STENCIL`: a float depth value followed by a 32 bit word with the stencil
+/// index in its low 8 bits. Also used as the intermediate representation for combined depth-stencil conversions
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct Float32Stencil8 {
+    depth: f32,
+    stencil: u32,
+}
+impl ChannelSource<Depth> for Float32Stencil8 {
+    type Channel = f32;
+    fn channel(self) -> f32 {
+        self.depth
+    }
+}
+impl ChannelSource<Stencil> for Float32Stencil8 {
+    type Channel = PackedChannel<8>;
+    fn channel(self) -> PackedChannel<8> {
+        PackedChannel::from_bits(self.stencil)
+    }
+}
+channelsource_default! {
+    default impl ChannelSource<Red> for Float32Stencil8 {}
+}
+channelsource_default! {
+    default impl ChannelSource<Green> for Float32Stencil8 {}
+}
+channelsource_default! {
+    default impl ChannelSource<Blue> for Float32Stencil8 {}
+}
+channelsource_default! {
+    default impl ChannelSource<Alpha> for Float32Stencil8 {}
+}
+impl<T> ConvertPixel<Float32Stencil8> for T
+where
+    T: ChannelSource<Depth, Channel: ConvertChannel<f32>>
+        + ChannelSource<Stencil, Channel: ConvertChannel<PackedChannel<8>>>
+        + Copy,
+{
+    fn convert_pixel(self) -> Float32Stencil8 {
+        Float32Stencil8 {
+            depth: <Self as ChannelSource<Depth>>::channel(self).convert_channel(),
+            stencil: <PackedChannel<8> as PackedField>::to_bits(
+                <Self as ChannelSource<Stencil>>::channel(self).convert_channel(),
+            ),
+        }
+    }
+}
+
+/// Reads `src` as a sequence of `T`s and converts them into `Out`s, appending the raw bytes of the results to `dst`
+/// # Safety
+/// `T` must be valid for any bit pattern and `Out` must not contain any padding bytes
+unsafe fn convert_pixels<T: Copy + ConvertPixel<Out>, Out: Copy>(src: &[u8], dst: &mut Vec<u8>) {
+    dst.reserve(src.len() / size_of::<T>() * size_of::<Out>());
+    for chunk in src.chunks_exact(size_of::<T>()) {
+        // Safety: chunk is exactly size_of::<T>() bytes long and the caller ensures any bit pattern is a valid T
+        let pix: T = unsafe { chunk.as_ptr().cast::<T>().read_unaligned() };
+        let out: Out = pix.convert_pixel();
+        // Safety: out is a live, initialized value of type Out, which the caller ensures contains no padding
+        dst.extend_from_slice(unsafe {
+            std::slice::from_raw_parts((&raw const out).cast::<u8>(), size_of::<Out>())
+        });
+    }
+}
+/// Converts between pixels in some memory representation and an intermediate representation, in either direction
+macro_rules! convert_with {
+    (decode, $repr:ty, $intermediate:ty, $src:expr, $buf:expr) => {{
+        let src: &[u8] = $src;
+        // Safety: all pixel representations are plain old data without padding
+        unsafe { convert_pixels::<$repr, $intermediate>(src, $buf) }
+    }};
+    (encode, $repr:ty, $intermediate:ty, $src:expr, $buf:expr) => {{
+        let src: &[u8] = $src;
+        // Safety: all pixel representations are plain old data without padding
+        unsafe { convert_pixels::<$intermediate, $repr>(src, $buf) }
+    }};
+}
+/// Converts tightly packed client pixels to (`decode`) or from (`encode`) an intermediate representation. Evaluates to None if
+/// the client type/format pair is not representable by the intermediate
+/// (e.g. integer client data for a normalized intermediate)
+macro_rules! client_pixels {
+    (
+        $dir:ident, $src:expr, $fmt:expr, $intermediate:ty,
+        formats: $formats:tt,
+        types: [ $( $ty:ident => $ctype:ty ),* $(,)? ],
+        packed: [ $( ($pty:ident, $( $pfmt:ident )|+) => $packed:ty ),* $(,)? ]
+    ) => {{
+        let mut buf = Vec::new();
+        let converted = match ($fmt.ty, $fmt.fmt) {
+            $(
+                $( (PixelType::$pty, PixelFormat::$pfmt) )|+ => {
+                    convert_with!($dir, $packed, $intermediate, $src, &mut buf);
+                    true
+                }
+            )*
+            $(
+                (PixelType::$ty, format) => client_pixels!(@formats $dir, $src, format, $intermediate, $ctype, buf, $formats),
+            )*
+            _ => false,
+        };
+        converted.then_some(buf)
+    }};
+    (
+        @formats $dir:ident, $src:expr, $format:expr, $intermediate:ty, $ctype:ty, $buf:ident,
+        [ $( $pfmt:ident => $repr:ident $(, $chan:ident )? );* $(;)? ]
+    ) => {
+        match $format {
+            $(
+                PixelFormat::$pfmt => {
+                    convert_with!($dir, client_pixels!(@repr $repr, $ctype $(, $chan)?), $intermediate, $src, &mut $buf);
+                    true
+                }
+            )*
+            _ => false,
+        }
+    };
+    (@repr Single, $ctype:ty, $chan:ident) => { SingleChannelFormat<$ctype, $chan> };
+    (@repr $repr:ident, $ctype:ty) => { $repr<$ctype> };
+}
+/// Client formats with normalized or floating point color data, converted through an RGBA float intermediate
+macro_rules! float_client_pixels {
+    ($dir:ident, $src:expr, $fmt:expr) => {
+        client_pixels!(
+            $dir, $src, $fmt, RgbaColorFormat<f32>,
+            formats: [
+                Red => Single, Red;
+                Green => Single, Green;
+                Blue => Single, Blue;
+                Alpha => Single, Alpha;
+                Rg => RgColorFormat;
+                Rgb => RgbColorFormat;
+                Bgr => BgrColorFormat;
+                Rgba => RgbaColorFormat;
+                Bgra => BgraColorFormat;
+            ],
+            types: [
+                UnsignedByte => NormalizedIntChannel<u8>,
+                Byte => NormalizedIntChannel<i8>,
+                UnsignedShort => NormalizedIntChannel<u16>,
+                Short => NormalizedIntChannel<i16>,
+                UnsignedInt => NormalizedIntChannel<u32>,
+                Int => NormalizedIntChannel<i32>,
+                HalfFloat => f16,
+                Float => f32,
+            ],
+            packed: [
+                (UnsignedByte332, Rgb) => Rgb332,
+                (UnsignedByte233Rev, Rgb) => Rgb233Rev,
+                (UnsignedShort565, Rgb) => Rgb565,
+                (UnsignedShort565Rev, Rgb) => Rgb565Rev,
+                (UnsignedShort4444, Rgba) => Rgba4444,
+                (UnsignedShort4444, Bgra) => Bgra4444,
+                (UnsignedShort4444Rev, Rgba) => Rgba4444Rev,
+                (UnsignedShort4444Rev, Bgra) => Bgra4444Rev,
+                (UnsignedShort5551, Rgba) => Rgba5551,
+                (UnsignedShort5551, Bgra) => Bgra5551,
+                (UnsignedShort1555Rev, Rgba) => Rgba1555Rev,
+                (UnsignedShort1555Rev, Bgra) => Bgra1555Rev,
+                (UnsignedInt8888, Rgba) => Rgba8888,
+                (UnsignedInt8888, Bgra) => Bgra8888,
+                (UnsignedInt8888Rev, Rgba) => Rgba8888Rev,
+                (UnsignedInt8888Rev, Bgra) => Bgra8888Rev,
+                (UnsignedInt1010102, Rgba) => Rgba1010102,
+                (UnsignedInt1010102, Bgra) => Bgra1010102,
+                (UnsignedInt2101010Rev, Rgba) => Rgba2101010Rev,
+                (UnsignedInt2101010Rev, Bgra) => Bgra2101010Rev,
+                (UnsignedInt10F11F11FRev, Rgb) => Rgb10F11F11FRev,
+                (UnsignedInt5999Rev, Rgb) => Rgb9E5,
+            ]
+        )
+    };
+}
+/// Client formats with integer color data, converted through an RGBA integer intermediate
+macro_rules! integer_client_pixels {
+    ($dir:ident, $src:expr, $fmt:expr) => {
+        client_pixels!(
+            $dir, $src, $fmt, RgbaColorFormat<i64>,
+            formats: [
+                RedInteger => Single, Red;
+                GreenInteger => Single, Green;
+                BlueInteger => Single, Blue;
+                RgInteger => RgColorFormat;
+                RgbInteger => RgbColorFormat;
+                BgrInteger => BgrColorFormat;
+                RgbaInteger => RgbaColorFormat;
+                BgraInteger => BgraColorFormat;
+            ],
+            types: [
+                UnsignedByte => u8,
+                Byte => i8,
+                UnsignedShort => u16,
+                Short => i16,
+                UnsignedInt => u32,
+                Int => i32,
+            ],
+            packed: [
+                (UnsignedByte332, RgbInteger) => Rgb332,
+                (UnsignedByte233Rev, RgbInteger) => Rgb233Rev,
+                (UnsignedShort565, RgbInteger) => Rgb565,
+                (UnsignedShort565Rev, RgbInteger) => Rgb565Rev,
+                (UnsignedShort4444, RgbaInteger) => Rgba4444,
+                (UnsignedShort4444, BgraInteger) => Bgra4444,
+                (UnsignedShort4444Rev, RgbaInteger) => Rgba4444Rev,
+                (UnsignedShort4444Rev, BgraInteger) => Bgra4444Rev,
+                (UnsignedShort5551, RgbaInteger) => Rgba5551,
+                (UnsignedShort5551, BgraInteger) => Bgra5551,
+                (UnsignedShort1555Rev, RgbaInteger) => Rgba1555Rev,
+                (UnsignedShort1555Rev, BgraInteger) => Bgra1555Rev,
+                (UnsignedInt8888, RgbaInteger) => Rgba8888,
+                (UnsignedInt8888, BgraInteger) => Bgra8888,
+                (UnsignedInt8888Rev, RgbaInteger) => Rgba8888Rev,
+                (UnsignedInt8888Rev, BgraInteger) => Bgra8888Rev,
+                (UnsignedInt1010102, RgbaInteger) => Rgba1010102,
+                (UnsignedInt1010102, BgraInteger) => Bgra1010102,
+                (UnsignedInt2101010Rev, RgbaInteger) => Rgba2101010Rev,
+                (UnsignedInt2101010Rev, BgraInteger) => Bgra2101010Rev,
+            ]
+        )
+    };
+}
+/// Client formats with depth data, converted through a float depth intermediate
+macro_rules! depth_client_pixels {
+    ($dir:ident, $src:expr, $fmt:expr) => {
+        client_pixels!(
+            $dir, $src, $fmt, SingleChannelFormat<f32, Depth>,
+            formats: [ DepthComponent => Single, Depth ],
+            types: [
+                UnsignedByte => NormalizedIntChannel<u8>,
+                Byte => NormalizedIntChannel<i8>,
+                UnsignedShort => NormalizedIntChannel<u16>,
+                Short => NormalizedIntChannel<i16>,
+                UnsignedInt => NormalizedIntChannel<u32>,
+                Int => NormalizedIntChannel<i32>,
+                HalfFloat => f16,
+                Float => f32,
+            ],
+            packed: [
+                (UnsignedInt248, DepthStencil) => DepthStencil248,
+                (Float32UnsignedInt248Rev, DepthStencil) => Float32Stencil8,
+            ]
+        )
+    };
+}
+/// Client formats with stencil data, converted through an integer stencil intermediate
+macro_rules! stencil_client_pixels {
+    ($dir:ident, $src:expr, $fmt:expr) => {
+        client_pixels!(
+            $dir, $src, $fmt, SingleChannelFormat<i64, Stencil>,
+            formats: [ StencilIndex => Single, Stencil ],
+            types: [
+                UnsignedByte => u8,
+                Byte => i8,
+                UnsignedShort => u16,
+                Short => i16,
+                UnsignedInt => u32,
+                Int => i32,
+            ],
+            packed: [
+                (UnsignedInt248, DepthStencil) => DepthStencil248,
+                (Float32UnsignedInt248Rev, DepthStencil) => Float32Stencil8,
+            ]
+        )
+    };
+}
+/// Client formats with combined depth and stencil data, converted through a [`Float32Stencil8`] intermediate
+macro_rules! depth_stencil_client_pixels {
+    ($dir:ident, $src:expr, $fmt:expr) => {
+        client_pixels!(
+            $dir, $src, $fmt, Float32Stencil8,
+            formats: [],
+            types: [],
+            packed: [
+                (UnsignedInt248, DepthStencil) => DepthStencil248,
+                (Float32UnsignedInt248Rev, DepthStencil) => Float32Stencil8,
+            ]
+        )
+    };
+}
+
+/// Converts pixels in the memory layout of a Metal pixel format to (`decode`) or from (`encode`) an intermediate representation.
+/// Returns None from the enclosing function if the Metal format is not supported
+macro_rules! mtl_pixels {
+    ( size, $src:expr, $mtl:expr, $intermediate:ty, [ $( $( $fmt:ident )|+ => $repr:ty ),+ $(,)? ] ) => {
+        match $mtl {
+            $(
+                $( MTLPixelFormat::$fmt )|+ => Some(size_of::<$repr>()),
+            )+
+            _ => None,
+        }
+    };
+    ( $dir:ident, $src:expr, $mtl:expr, $intermediate:ty, [ $( $( $fmt:ident )|+ => $repr:ty ),+ $(,)? ] ) => {{
+        let mut buf = Vec::new();
+        match $mtl {
+            $(
+                $( MTLPixelFormat::$fmt )|+ => convert_with!($dir, $repr, $intermediate, $src, &mut buf),
+            )+
+            _ => return None,
+        }
+        Some(buf)
+    }};
+}
+macro_rules! float_mtl_pixels {
+    ($dir:ident, $src:expr, $mtl:expr) => {{
+        type Unorm<T> = NormalizedIntChannel<T>;
+        mtl_pixels!($dir, $src, $mtl, RgbaColorFormat<f32>, [
+            R8Unorm => SingleChannelFormat<Unorm<u8>, Red>,
+            R8Snorm => SingleChannelFormat<Unorm<i8>, Red>,
+            R16Unorm => SingleChannelFormat<Unorm<u16>, Red>,
+            R16Snorm => SingleChannelFormat<Unorm<i16>, Red>,
+            R16Float => SingleChannelFormat<f16, Red>,
+            R32Float => SingleChannelFormat<f32, Red>,
+            RG8Unorm => RgColorFormat<Unorm<u8>>,
+            RG8Snorm => RgColorFormat<Unorm<i8>>,
+            RG16Unorm => RgColorFormat<Unorm<u16>>,
+            RG16Snorm => RgColorFormat<Unorm<i16>>,
+            RG16Float => RgColorFormat<f16>,
+            RG32Float => RgColorFormat<f32>,
+            RGBA8Unorm | RGBA8Unorm_sRGB => RgbaColorFormat<Unorm<u8>>,
+            BGRA8Unorm | BGRA8Unorm_sRGB => BgraColorFormat<Unorm<u8>>,
+            RGBA8Snorm => RgbaColorFormat<Unorm<i8>>,
+            RGBA16Unorm => RgbaColorFormat<Unorm<u16>>,
+            RGBA16Snorm => RgbaColorFormat<Unorm<i16>>,
+            RGBA16Float => RgbaColorFormat<f16>,
+            RGBA32Float => RgbaColorFormat<f32>,
+            B5G6R5Unorm => Rgb565,
+            ABGR4Unorm => Rgba4444,
+            A1BGR5Unorm => Rgba5551,
+            BGR5A1Unorm => Bgra1555Rev,
+            RGB10A2Unorm => Rgba2101010Rev,
+            BGR10A2Unorm => Bgra2101010Rev,
+            RG11B10Float => Rgb10F11F11FRev,
+            RGB9E5Float => Rgb9E5,
+        ])
+    }};
+}
+macro_rules! integer_mtl_pixels {
+    ($dir:ident, $src:expr, $mtl:expr) => {
+        mtl_pixels!($dir, $src, $mtl, RgbaColorFormat<i64>, [
+            R8Uint => SingleChannelFormat<u8, Red>,
+            R8Sint => SingleChannelFormat<i8, Red>,
+            R16Uint => SingleChannelFormat<u16, Red>,
+            R16Sint => SingleChannelFormat<i16, Red>,
+            R32Uint => SingleChannelFormat<u32, Red>,
+            R32Sint => SingleChannelFormat<i32, Red>,
+            RG8Uint => RgColorFormat<u8>,
+            RG8Sint => RgColorFormat<i8>,
+            RG16Uint => RgColorFormat<u16>,
+            RG16Sint => RgColorFormat<i16>,
+            RG32Uint => RgColorFormat<u32>,
+            RG32Sint => RgColorFormat<i32>,
+            RGBA8Uint => RgbaColorFormat<u8>,
+            RGBA8Sint => RgbaColorFormat<i8>,
+            RGBA16Uint => RgbaColorFormat<u16>,
+            RGBA16Sint => RgbaColorFormat<i16>,
+            RGBA32Uint => RgbaColorFormat<u32>,
+            RGBA32Sint => RgbaColorFormat<i32>,
+            RGB10A2Uint => Rgba2101010Rev,
+        ])
+    };
+}
+macro_rules! depth_mtl_pixels {
+    ($dir:ident, $src:expr, $mtl:expr) => {
+        mtl_pixels!($dir, $src, $mtl, SingleChannelFormat<f32, Depth>, [
+            Depth16Unorm => SingleChannelFormat<NormalizedIntChannel<u16>, Depth>,
+            Depth32Float => SingleChannelFormat<f32, Depth>,
+        ])
+    };
+}
+macro_rules! stencil_mtl_pixels {
+    ($dir:ident, $src:expr, $mtl:expr) => {
+        mtl_pixels!($dir, $src, $mtl, SingleChannelFormat<i64, Stencil>, [
+            Stencil8 => SingleChannelFormat<u8, Stencil>,
+        ])
+    };
+}
+
+/// Size in bytes of a texel of a Metal pixel format that the pixel converter supports, or None if it is not supported.
+/// Combined depth-stencil formats are not included, since their aspects are copied separately
+pub(crate) fn mtl_texel_size(format: MTLPixelFormat) -> Option<usize> {
+    float_mtl_pixels!(size, (), format)
+        .or(integer_mtl_pixels!(size, (), format))
+        .or(depth_mtl_pixels!(size, (), format))
+        .or(stencil_mtl_pixels!(size, (), format))
+}
+
+/// The kind of data an image holds, which determines the intermediate representation used to convert it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConversionClass {
+    Float,
+    Integer,
+    Depth,
+    Stencil,
+    DepthStencil,
+}
+impl ConversionClass {
+    fn of(format: InternalFormat) -> Self {
+        match (format.has_depth(), format.has_stencil()) {
+            (true, true) => Self::DepthStencil,
+            (true, false) => Self::Depth,
+            (false, true) => Self::Stencil,
+            (false, false) if format.is_integer() => Self::Integer,
+            (false, false) => Self::Float,
+        }
+    }
+}
+
+/// Converts tightly packed client pixels described by `fmt` into the memory layout of `dst`'s Metal pixel format, following the
+/// GL's pixel unpacking rules. Returns None if this conversion is not supported.
+///
+/// Since Metal copies the depth and stencil aspects of combined depth-stencil textures separately, data for those formats
+/// is returned as a plane of 32 bit float depth values followed by a plane of 8 bit stencil indices
+pub(crate) fn convert_to_internal(
+    src: &[u8],
+    fmt: GlPixelTypeFormat,
+    dst: InternalFormat,
+) -> Option<Vec<u8>> {
+    gl_trace!(
+        "converting {} bytes of {fmt:?} pixels to {dst:?}",
+        src.len()
+    );
+    let mtl = dst.mtl_texture_format();
+    match ConversionClass::of(dst) {
+        ConversionClass::Float => {
+            float_mtl_pixels!(encode, &float_client_pixels!(decode, src, fmt)?, mtl)
+        }
+        ConversionClass::Integer => {
+            integer_mtl_pixels!(encode, &integer_client_pixels!(decode, src, fmt)?, mtl)
+        }
+        ConversionClass::Depth => {
+            depth_mtl_pixels!(encode, &depth_client_pixels!(decode, src, fmt)?, mtl)
+        }
+        ConversionClass::Stencil => {
+            stencil_mtl_pixels!(encode, &stencil_client_pixels!(decode, src, fmt)?, mtl)
+        }
+        ConversionClass::DepthStencil => {
+            if mtl != MTLPixelFormat::Depth32Float_Stencil8 {
+                return None;
+            }
+            Some(split_depth_stencil(&depth_stencil_client_pixels!(
+                decode, src, fmt
+            )?))
+        }
+    }
+}
+
+/// Converts pixels of internal format `src_format`, stored in the memory layout of the Metal pixel format `mtl`, into tightly packed
+/// client pixels described by `fmt`, following the GL's pixel packing rules. `mtl` is usually `src_format`'s own Metal pixel format,
+/// but may differ in channel order for images that the context did not allocate itself (e.g. BGRA drawables). Returns None if this conversion
+/// is not supported. Depth-stencil data is expected in the planar layout produced by [`convert_to_internal`]
+pub(crate) fn convert_from_internal(
+    src: &[u8],
+    src_format: InternalFormat,
+    mtl: MTLPixelFormat,
+    fmt: GlPixelTypeFormat,
+) -> Option<Vec<u8>> {
+    gl_trace!(
+        "converting {} bytes of {src_format:?} pixels (stored as {mtl:?}) to {fmt:?}",
+        src.len()
+    );
+    let intermediate = match ConversionClass::of(src_format) {
+        ConversionClass::Float => float_mtl_pixels!(decode, src, mtl)?,
+        ConversionClass::Integer => integer_mtl_pixels!(decode, src, mtl)?,
+        ConversionClass::Depth => depth_mtl_pixels!(decode, src, mtl)?,
+        ConversionClass::Stencil => stencil_mtl_pixels!(decode, src, mtl)?,
+        ConversionClass::DepthStencil => {
+            if mtl != MTLPixelFormat::Depth32Float_Stencil8 {
+                return None;
+            }
+            merge_depth_stencil(src)
+        }
+    };
+    // the client format may select a single aspect of a depth-stencil image
+    match (ConversionClass::of(src_format), fmt.fmt) {
+        (ConversionClass::Float, _) => float_client_pixels!(encode, &intermediate, fmt),
+        (ConversionClass::Integer, _) => integer_client_pixels!(encode, &intermediate, fmt),
+        (ConversionClass::Depth, _) => depth_client_pixels!(encode, &intermediate, fmt),
+        (ConversionClass::Stencil, _) => stencil_client_pixels!(encode, &intermediate, fmt),
+        (ConversionClass::DepthStencil, PixelFormat::DepthComponent) => {
+            let depth = {
+                let mut buf = Vec::new();
+                // Safety: Float32Stencil8 is plain old data without padding
+                unsafe {
+                    convert_pixels::<Float32Stencil8, SingleChannelFormat<f32, Depth>>(
+                        &intermediate,
+                        &mut buf,
+                    );
+                }
+                buf
+            };
+            depth_client_pixels!(encode, &depth, fmt)
+        }
+        (ConversionClass::DepthStencil, PixelFormat::StencilIndex) => {
+            let stencil = {
+                let mut buf = Vec::new();
+                // Safety: Float32Stencil8 is plain old data without padding
+                unsafe {
+                    convert_pixels::<Float32Stencil8, SingleChannelFormat<i64, Stencil>>(
+                        &intermediate,
+                        &mut buf,
+                    );
+                }
+                buf
+            };
+            stencil_client_pixels!(encode, &stencil, fmt)
+        }
+        (ConversionClass::DepthStencil, _) => {
+            depth_stencil_client_pixels!(encode, &intermediate, fmt)
+        }
+    }
+}
+
+/// Converts pixels of internal format `src_format`, stored in the memory layout of the Metal pixel format `mtl`, directly into the
+/// memory layout of `dst`'s Metal pixel format, as done by copies between images of different formats (e.g. glCopyTexSubImage*).
+/// Returns None if the formats hold different kinds of data or the conversion is not supported. Depth-stencil data uses the
+/// planar layout produced by [`convert_to_internal`]
+pub(crate) fn convert_internal(
+    src: &[u8],
+    src_format: InternalFormat,
+    mtl: MTLPixelFormat,
+    dst: InternalFormat,
+) -> Option<Vec<u8>> {
+    gl_trace!(
+        "converting {} bytes of {src_format:?} pixels (stored as {mtl:?}) to {dst:?}",
+        src.len()
+    );
+    let dst_mtl = dst.mtl_texture_format();
+    match (ConversionClass::of(src_format), ConversionClass::of(dst)) {
+        (ConversionClass::Float, ConversionClass::Float) => {
+            float_mtl_pixels!(encode, &float_mtl_pixels!(decode, src, mtl)?, dst_mtl)
+        }
+        (ConversionClass::Integer, ConversionClass::Integer) => {
+            integer_mtl_pixels!(encode, &integer_mtl_pixels!(decode, src, mtl)?, dst_mtl)
+        }
+        (ConversionClass::Depth, ConversionClass::Depth) => {
+            depth_mtl_pixels!(encode, &depth_mtl_pixels!(decode, src, mtl)?, dst_mtl)
+        }
+        (ConversionClass::Stencil, ConversionClass::Stencil) => {
+            stencil_mtl_pixels!(encode, &stencil_mtl_pixels!(decode, src, mtl)?, dst_mtl)
+        }
+        // the only depth-stencil format with a CPU-side layout is Depth32Float_Stencil8
+        (ConversionClass::DepthStencil, ConversionClass::DepthStencil)
+            if mtl == MTLPixelFormat::Depth32Float_Stencil8
+                && dst_mtl == MTLPixelFormat::Depth32Float_Stencil8 =>
+        {
+            Some(src.to_vec())
+        }
+        _ => None,
     }
 }
 
+/// A single texel in the form that Metal render pass attachments are cleared to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ClearValue {
+    /// Normalized, floating point or integer color components
+    Color([f64; 4]),
+    Depth(f64),
+    Stencil(u32),
+    DepthStencil(f64, u32),
+}
+
+/// Reads a single texel of internal format `format`, in the memory layout produced by [`convert_to_internal`], back as the value
+/// that a render pass clear would have to write to store it. Returns None if the conversion is not supported or a clear could not
+/// reproduce the texel exactly (i.e. for sRGB formats, whose clear colors Metal encodes)
+pub(crate) fn clear_value(texel: &[u8], format: InternalFormat) -> Option<ClearValue> {
+    let mtl = format.mtl_texture_format();
+    let f32_at = |bytes: &[u8], i: usize| {
+        f64::from(f32::from_ne_bytes(
+            bytes[i * 4..(i + 1) * 4]
+                .try_into()
+                .expect("slice is 4 bytes long"),
+        ))
+    };
+    let i64_at = |bytes: &[u8], i: usize| {
+        i64::from_ne_bytes(
+            bytes[i * 8..(i + 1) * 8]
+                .try_into()
+                .expect("slice is 8 bytes long"),
+        )
+    };
+    Some(match ConversionClass::of(format) {
+        ConversionClass::Float => {
+            if matches!(
+                mtl,
+                MTLPixelFormat::RGBA8Unorm_sRGB | MTLPixelFormat::BGRA8Unorm_sRGB
+            ) {
+                return None;
+            }
+            let rgba = float_mtl_pixels!(decode, texel, mtl)?;
+            ClearValue::Color([0, 1, 2, 3].map(|i| f32_at(&rgba, i)))
+        }
+        ConversionClass::Integer => {
+            let rgba = integer_mtl_pixels!(decode, texel, mtl)?;
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "integer formats have at most 32 bits per channel"
+            )]
+            let color = [0, 1, 2, 3].map(|i| i64_at(&rgba, i) as f64);
+            ClearValue::Color(color)
+        }
+        ConversionClass::Depth => {
+            ClearValue::Depth(f32_at(&depth_mtl_pixels!(decode, texel, mtl)?, 0))
+        }
+        ConversionClass::Stencil => {
+            let stencil = stencil_mtl_pixels!(decode, texel, mtl)?;
+            ClearValue::Stencil(u32::try_from(i64_at(&stencil, 0)).ok()?)
+        }
+        ConversionClass::DepthStencil => {
+            if mtl != MTLPixelFormat::Depth32Float_Stencil8 {
+                return None;
+            }
+            ClearValue::DepthStencil(f32_at(texel, 0), u32::from(texel[size_of::<f32>()]))
+        }
+    })
+}
+
+/// Splits packed [`Float32Stencil8`] pixels into a plane of depth values followed by a plane of 8 bit stencil indices
+fn split_depth_stencil(src: &[u8]) -> Vec<u8> {
+    let pixels = src.chunks_exact(size_of::<Float32Stencil8>());
+    let mut out = Vec::with_capacity(pixels.len() * 5);
+    for pixel in pixels.clone() {
+        out.extend_from_slice(&pixel[..4]);
+    }
+    // the stencil index is in the least significant (first, on little endian platforms) byte of the second word
+    out.extend(pixels.map(|pixel| pixel[4]));
+    out
+}
+/// Inverse of [`split_depth_stencil`]
+fn merge_depth_stencil(src: &[u8]) -> Vec<u8> {
+    let count = src.len() / 5;
+    let (depth, stencil) = src.split_at(count * 4);
+    let mut out = Vec::with_capacity(count * size_of::<Float32Stencil8>());
+    for (depth, &stencil) in depth.chunks_exact(4).zip(stencil) {
+        out.extend_from_slice(depth);
+        out.extend_from_slice(&u32::from(stencil).to_le_bytes());
+    }
+    out
+}
+
+/// Returns the linear (non-sRGB) counterpart of the Metal pixel format `fmt`, which has the same bit layout
+pub(crate) fn strip_srgb(fmt: MTLPixelFormat) -> MTLPixelFormat {
+    match fmt {
+        MTLPixelFormat::RGBA8Unorm_sRGB => MTLPixelFormat::RGBA8Unorm,
+        MTLPixelFormat::BGRA8Unorm_sRGB => MTLPixelFormat::BGRA8Unorm,
+        f => f,
+    }
+}
+
+/// An [`InternalFormat`] whose Metal pixel format has exactly the same memory layout as some client pixel type/format pair,
+/// allowing client data to be copied into textures of a matching format without conversion
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub(crate) struct BinaryCompatibleFormat(InternalFormat);
+impl BinaryCompatibleFormat {
+    /// Whether pixel data in this format may be copied as-is into a texture with internal format `format`
+    pub(crate) fn matches(self, format: InternalFormat) -> bool {
+        // sRGB decoding happens on sampling, so the encoded bits are the same
+        format.has_mtl_texture_format()
+            && strip_srgb(self.0.mtl_texture_format()) == strip_srgb(format.mtl_texture_format())
+    }
+}
 
 #[derive(Debug, Copy, Clone)]
 /// A combination of a [`PixelType`] and a [`PixelFormat`], used to specify input and output formats.
@@ -395,133 +1320,1003 @@ pub struct GlPixelTypeFormat {
     fmt: PixelFormat,
 }
 
+impl PixelFormat {
+    /// Whether this is one of the `*_INTEGER` pixel formats, which may only be used with integer internal formats
+    pub(crate) fn is_integer(self) -> bool {
+        matches!(
+            self,
+            Self::RedInteger
+                | Self::GreenInteger
+                | Self::BlueInteger
+                | Self::RgInteger
+                | Self::RgbInteger
+                | Self::RgbaInteger
+                | Self::BgrInteger
+                | Self::BgraInteger
+        )
+    }
+}
 impl GlPixelTypeFormat {
     pub fn new(ty: PixelType, fmt: PixelFormat) -> Self {
         Self { ty, fmt }
     }
+    pub(crate) fn format(self) -> PixelFormat {
+        self.fmt
+    }
+    /// Size in bytes of a single pixel in this format, or None if this is not a valid type/format combination
+    /// (e.g. a packed pixel type with a format that has the wrong number of components)
+    pub(crate) fn pixel_size(self) -> Option<usize> {
+        use PixelFormat as F;
+        use PixelType as T;
+        let components = match self.fmt {
+            F::Red
+            | F::Green
+            | F::Blue
+            | F::Alpha
+            | F::RedInteger
+            | F::GreenInteger
+            | F::BlueInteger
+            | F::DepthComponent
+            | F::StencilIndex => 1,
+            F::Rg | F::RgInteger => 2,
+            F::Rgb | F::Bgr | F::RgbInteger | F::BgrInteger => 3,
+            F::Rgba | F::Bgra | F::RgbaInteger | F::BgraInteger => 4,
+            // only valid with packed depth-stencil types
+            F::DepthStencil => 0,
+            F::UnsignedShort | F::UnsignedInt => return None,
+        };
+        let rgb = matches!(self.fmt, F::Rgb | F::RgbInteger);
+        let rgba = matches!(
+            self.fmt,
+            F::Rgba | F::Bgra | F::RgbaInteger | F::BgraInteger
+        );
+        let (size, valid) = match self.ty {
+            T::Byte | T::UnsignedByte => (components, components != 0),
+            T::Short | T::UnsignedShort | T::HalfFloat => (2 * components, components != 0),
+            T::Int | T::UnsignedInt | T::Float => (4 * components, components != 0),
+            T::UnsignedByte332 | T::UnsignedByte233Rev => (1, rgb),
+            T::UnsignedShort565 | T::UnsignedShort565Rev => (2, rgb),
+            T::UnsignedShort4444
+            | T::UnsignedShort4444Rev
+            | T::UnsignedShort5551
+            | T::UnsignedShort1555Rev => (2, rgba),
+            T::UnsignedInt8888
+            | T::UnsignedInt8888Rev
+            | T::UnsignedInt1010102
+            | T::UnsignedInt2101010Rev => (4, rgba),
+            T::UnsignedInt10F11F11FRev | T::UnsignedInt5999Rev => (4, self.fmt == PixelFormat::Rgb),
+            T::UnsignedInt248 => (4, self.fmt == PixelFormat::DepthStencil),
+            T::Float32UnsignedInt248Rev => (8, self.fmt == PixelFormat::DepthStencil),
+        };
+        valid.then_some(size)
+    }
+    /// Size in bytes of a single element of this pixel type, i.e. a component for unpacked types or a whole pixel for packed types.
+    /// This is the unit that is byte-swapped by `GL_(UN)PACK_SWAP_BYTES`
+    pub(crate) fn element_size(self) -> usize {
+        use PixelType as T;
+        match self.ty {
+            T::Byte | T::UnsignedByte | T::UnsignedByte332 | T::UnsignedByte233Rev => 1,
+            T::Short
+            | T::UnsignedShort
+            | T::HalfFloat
+            | T::UnsignedShort565
+            | T::UnsignedShort565Rev
+            | T::UnsignedShort4444
+            | T::UnsignedShort4444Rev
+            | T::UnsignedShort5551
+            | T::UnsignedShort1555Rev => 2,
+            // the depth and stencil halves of FLOAT_32_UNSIGNED_INT_24_8_REV are swapped separately
+            _ => 4,
+        }
+    }
+    /// Returns the [`BinaryCompatibleFormat`] whose memory layout matches this type/format pair exactly, if any
+    pub(crate) fn binary_compatible_format(self) -> Option<BinaryCompatibleFormat> {
+        use InternalFormat as IF;
+        use PixelFormat as F;
+        use PixelType as T;
+        let format = match (self.ty, self.fmt) {
+            (T::UnsignedByte | T::UnsignedInt8888Rev, F::Rgba) => IF::Rgba8,
+            (T::UnsignedByte, F::Rg) => IF::Rg8,
+            (T::UnsignedByte, F::Red) => IF::R8,
+            (T::Byte, F::Rgba) => IF::Rgba8Snorm,
+            (T::Byte, F::Rg) => IF::Rg8Snorm,
+            (T::Byte, F::Red) => IF::R8Snorm,
+            (T::UnsignedShort, F::Rgba) => IF::Rgba16,
+            (T::UnsignedShort, F::Rg) => IF::Rg16,
+            (T::UnsignedShort, F::Red) => IF::R16,
+            (T::Short, F::Rgba) => IF::Rgba16Snorm,
+            (T::Short, F::Rg) => IF::Rg16Snorm,
+            (T::Short, F::Red) => IF::R16Snorm,
+            (T::HalfFloat, F::Rgba) => IF::Rgba16f,
+            (T::HalfFloat, F::Rg) => IF::Rg16f,
+            (T::HalfFloat, F::Red) => IF::R16f,
+            (T::Float, F::Rgba) => IF::Rgba32f,
+            (T::Float, F::Rg) => IF::Rg32f,
+            (T::Float, F::Red) => IF::R32f,
+
+            (T::UnsignedByte | T::UnsignedInt8888Rev, F::RgbaInteger) => IF::Rgba8ui,
+            (T::UnsignedByte, F::RgInteger) => IF::Rg8ui,
+            (T::UnsignedByte, F::RedInteger) => IF::R8ui,
+            (T::Byte, F::RgbaInteger) => IF::Rgba8i,
+            (T::Byte, F::RgInteger) => IF::Rg8i,
+            (T::Byte, F::RedInteger) => IF::R8i,
+            (T::UnsignedShort, F::RgbaInteger) => IF::Rgba16ui,
+            (T::UnsignedShort, F::RgInteger) => IF::Rg16ui,
+            (T::UnsignedShort, F::RedInteger) => IF::R16ui,
+            (T::Short, F::RgbaInteger) => IF::Rgba16i,
+            (T::Short, F::RgInteger) => IF::Rg16i,
+            (T::Short, F::RedInteger) => IF::R16i,
+            (T::UnsignedInt, F::RgbaInteger) => IF::Rgba32ui,
+            (T::UnsignedInt, F::RgInteger) => IF::Rg32ui,
+            (T::UnsignedInt, F::RedInteger) => IF::R32ui,
+            (T::Int, F::RgbaInteger) => IF::Rgba32i,
+            (T::Int, F::RgInteger) => IF::Rg32i,
+            (T::Int, F::RedInteger) => IF::R32i,
+
+            (T::UnsignedShort4444, F::Rgba) => IF::Rgba4,
+            (T::UnsignedShort5551, F::Rgba) => IF::Rgb5A1,
+            (T::UnsignedInt2101010Rev, F::Rgba) => IF::Rgb10A2,
+            (T::UnsignedInt2101010Rev, F::RgbaInteger) => IF::Rgb10A2ui,
+            (T::UnsignedInt10F11F11FRev, F::Rgb) => IF::R11fG11fB10f,
+            (T::UnsignedInt5999Rev, F::Rgb) => IF::Rgb9E5,
+
+            (T::UnsignedShort, F::DepthComponent) => IF::DepthComponent16,
+            (T::Float, F::DepthComponent) => IF::DepthComponent32f,
+            (T::UnsignedByte, F::StencilIndex) => IF::StencilIndex8,
+            _ => return None,
+        };
+        Some(BinaryCompatibleFormat(format))
+    }
     /// Returns the `InternalFormat` that matches this type format pair, if any. No ABI compatability guarantees are made about the format of this [`GlPixelTypeFormat`] and the returned [`InternalFormat`].
+    /// Formats that are [binary compatible](Self::binary_compatible_format) with this pair are preferred, otherwise the format
+    /// that can represent the client data with the least precision loss is picked
     pub(crate) fn equivalent_internal_format(self) -> Option<InternalFormat> {
-        
-        
-
+        use InternalFormat as IF;
+        use PixelFormat as F;
+        use PixelType as T;
         gl_trace!(
             "attempting to lower (type {:?}, fmt {:?}) tuple to a metal pixel format",
             self.ty,
             self.fmt
         );
-        // 'none: {
-        //     return Some(match self.ty {
-        //         PixelType::Byte => match self.fmt {
-        //             Red => IF::R8Snorm,
-        //             Rg => IF::Rg8Snorm,
-        //             Rgba => IF::Rgb8,
-        //             RedInteger => IF::R8i,
-        //             RgInteger => IF::Rg8i,
-        //             RgbaInteger => IF::Rgba8i,
-        //             StencilIndex => IF::StencilIndex8,
-        //             _ => break 'none,
-        //         },
-        //         PixelType::UnsignedByte => match self.fmt {
-        //             Red => IF::R8,
-        //             Rg => IF::Rg8,
-        //             Rgba => IF::Rgba8,
-        //             RedInteger => IF::R8ui,
-        //             RgInteger => IF::Rg8ui,
-        //             RgbaInteger => IF::Rgba8ui,
-        //             StencilIndex => IF::StencilIndex8,
-        //             _ => break 'none,
-        //         },
-        //         PixelType::Short => match self.fmt {
-        //             Red => IF::R16Snorm,
-        //             Rg => IF::Rg16Snorm,
-        //             Rgba => IF::Rgba16Snorm,
-        //             RedInteger => IF::R16i,
-        //             RgInteger => IF::Rg16i,
-        //             RgbaInteger => IF::Rgba16i,
-
-        //             DepthComponent => IF::DepthComponent16,
-        //             StencilIndex => IF::StencilIndex16,
-        //             _ => break 'none,
-        //         },
-        //         PixelType::UnsignedShort => match self.fmt {
-        //             Red => IF::R16,
-        //             Rg => IF::Rg16,
-        //             Rgba => IF::Rgba16,
-        //             RedInteger => IF::R16ui,
-        //             RgInteger => IF::Rg16ui,
-        //             RgbaInteger => IF::Rgba16ui,
-
-        //             DepthComponent => IF::DepthComponent16,
-        //             StencilIndex => IF::StencilIndex16,
-        //             _ => break 'none,
-        //         },
-
-        //         // TODO: emulate GL normalization behavior for (U)Int,
-        //         // those formats are not supported for now
-        //         PixelType::Int => match self.fmt {
-        //             // Red => IF::R32ui,
-        //             // Rg => IF::RG32Snorm,
-        //             // Rgba => IF::Rgba32Snorm,
-        //             RedInteger => IF::R32i,
-        //             RgInteger => IF::Rg32i,
-        //             RgbaInteger => IF::Rgba32i,
-
-        //             DepthComponent => IF::DepthComponent32,
-        //             _ => break 'none,
-        //         },
-        //         PixelType::UnsignedInt => match self.fmt {
-        //             // Red => IF::R32Unorm,
-        //             // Rg => IF::RG32Unorm,
-        //             // Rgba => IF::Rgba32Unorm,
-        //             RedInteger => IF::R32ui,
-        //             RgInteger => IF::Rg32ui,
-        //             RgbaInteger => IF::Rgba32ui,
-
-        //             DepthComponent => IF::DepthComponent32,
-        //             _ => break 'none,
-        //         },
-        //         PixelType::Float => match self.fmt {
-        //             Red => IF::R32f,
-        //             Rg => IF::Rg32f,
-        //             Rgba => IF::Rgba32f,
-        //             DepthComponent => IF::DepthComponent32f,
-        //             DepthStencil => IF::Depth24Stencil8,
-        //             _ => break 'none,
-        //         },
-        //         PixelType::HalfFloat => match self.fmt {
-        //             Red => IF::R16f,
-        //             Rg => IF::Rg16f,
-        //             Rgba => IF::Rgba16f,
-        //             _ => break 'none,
-        //         },
-        //         // special-purpose formats
-        //         PixelType::UnsignedInt10F11F11FRev => todo!(),
-        //         PixelType::UnsignedShort565 => IF::Rgb565,
-        //         PixelType::UnsignedInt8888 => IF::Rgba8,
-        //         PixelType::UnsignedInt1010102 => IF::Rgb10A2,
-        //         PixelType::UnsignedInt2101010Rev => IF::BGR10A2Unorm,
-        //         PixelType::UnsignedShort4444Rev => IF::ABGR4Unorm,
-        //         PixelType::UnsignedShort1555Rev => IF::A1BGR5Unorm,
-
-        //         PixelType::UnsignedInt8888Rev => break 'none,
-        //         PixelType::UnsignedByte332 => todo!(),
-        //         PixelType::UnsignedShort4444 => todo!(),
-        //         PixelType::UnsignedShort5551 => todo!(),
-        //         PixelType::UnsignedByte233Rev => todo!(),
-        //         PixelType::UnsignedInt5999Rev => todo!(),
-        //         PixelType::Float32UnsignedInt248Rev => todo!(),
-        //         PixelType::UnsignedInt248 => todo!(),
-        //         PixelType::UnsignedShort565Rev => todo!(),
-        //     });
-        // }
-        todo!();
-        gl_warn!(
-            ty: Performance,
-            "failed to lower format+type tuple (type {:?}, fmt {:?}) to an internal format.
-            OxideGL will attempt to perform conversions but good performance is not guaranteed",
-            self.ty,
-            self.fmt
+        if let Some(BinaryCompatibleFormat(format)) = self.binary_compatible_format() {
+            return Some(format);
+        }
+        let color = matches!(self.fmt, F::Rgb | F::Bgr | F::Rgba | F::Bgra);
+        let integer_color = matches!(
+            self.fmt,
+            F::RgbInteger | F::BgrInteger | F::RgbaInteger | F::BgraInteger
+        );
+        let format = match self.ty {
+            _ if self.pixel_size().is_none() => None,
+            T::UnsignedByte if color => Some(IF::Rgba8),
+            T::Byte if color => Some(IF::Rgba8Snorm),
+            T::UnsignedShort if color => Some(IF::Rgba16),
+            T::Short if color => Some(IF::Rgba16Snorm),
+            T::HalfFloat if color => Some(IF::Rgba16f),
+            // Metal has no 32 bit normalized formats, so use floats to preserve as much precision as possible
+            T::UnsignedInt | T::Int | T::Float if color => Some(IF::Rgba32f),
+            T::UnsignedInt | T::Int if self.fmt == F::Red => Some(IF::R32f),
+            T::UnsignedInt | T::Int if self.fmt == F::Rg => Some(IF::Rg32f),
+
+            T::UnsignedByte if integer_color => Some(IF::Rgba8ui),
+            T::Byte if integer_color => Some(IF::Rgba8i),
+            T::UnsignedShort if integer_color => Some(IF::Rgba16ui),
+            T::Short if integer_color => Some(IF::Rgba16i),
+            T::UnsignedInt if integer_color => Some(IF::Rgba32ui),
+            T::Int if integer_color => Some(IF::Rgba32i),
+
+            T::Byte | T::UnsignedByte | T::Short if self.fmt == F::DepthComponent => {
+                Some(IF::DepthComponent16)
+            }
+            T::UnsignedInt | T::Int | T::HalfFloat if self.fmt == F::DepthComponent => {
+                Some(IF::DepthComponent32f)
+            }
+            _ if self.fmt == F::StencilIndex => Some(IF::StencilIndex8),
+            T::UnsignedInt248 | T::Float32UnsignedInt248Rev => Some(IF::Depth32fStencil8),
+
+            // packed types
+            T::UnsignedShort4444 | T::UnsignedShort4444Rev if color => Some(IF::Rgba4),
+            T::UnsignedShort5551 | T::UnsignedShort1555Rev if color => Some(IF::Rgb5A1),
+            T::UnsignedInt1010102 | T::UnsignedInt2101010Rev if color => Some(IF::Rgb10A2),
+            T::UnsignedInt1010102 | T::UnsignedInt2101010Rev => Some(IF::Rgb10A2ui),
+            T::UnsignedByte332
+            | T::UnsignedByte233Rev
+            | T::UnsignedShort565
+            | T::UnsignedShort565Rev
+            | T::UnsignedInt8888
+            | T::UnsignedInt8888Rev
+                if color =>
+            {
+                Some(IF::Rgba8)
+            }
+            T::UnsignedByte332
+            | T::UnsignedByte233Rev
+            | T::UnsignedShort565
+            | T::UnsignedShort565Rev
+            | T::UnsignedShort4444
+            | T::UnsignedShort4444Rev
+            | T::UnsignedShort5551
+            | T::UnsignedShort1555Rev
+            | T::UnsignedInt8888
+            | T::UnsignedInt8888Rev => Some(IF::Rgba8ui),
+            _ => None,
+        };
+        if format.is_none() {
+            gl_warn!(
+                ty: Performance,
+                "failed to lower format+type tuple (type {:?}, fmt {:?}) to an internal format.
+                OxideGL will attempt to perform conversions but good performance is not guaranteed",
+                self.ty,
+                self.fmt
+            );
+        }
+        format
+    }
+}
+
+/// Pixel storage modes set by glPixelStore, which control how images are addressed in client memory or in a pixel
+/// pack/unpack buffer. The context holds one set for packing (readback) and one for unpacking (uploads)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PixelStoreModes {
+    pub(crate) swap_bytes: bool,
+    /// Only affects bitmap data, which is not supported by the core profile. Stored for queries
+    pub(crate) lsb_first: bool,
+    pub(crate) row_length: u32,
+    pub(crate) image_height: u32,
+    pub(crate) skip_pixels: u32,
+    pub(crate) skip_rows: u32,
+    pub(crate) skip_images: u32,
+    pub(crate) alignment: u32,
+}
+impl Default for PixelStoreModes {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            swap_bytes: false,
+            lsb_first: false,
+            row_length: 0,
+            image_height: 0,
+            skip_pixels: 0,
+            skip_rows: 0,
+            skip_images: 0,
+            alignment: 4,
+        }
+    }
+}
+
+impl PixelStoreModes {
+    /// Computes the memory layout of a `dims`-dimensional image with the given extent and pixel size, as addressed by these storage modes.
+    /// Per the spec, `SKIP_ROWS` only applies to images with at least 2 dimensions and `SKIP_IMAGES`/`IMAGE_HEIGHT` only
+    /// apply to 3 dimensional images (including array textures specified by 3D commands)
+    pub(crate) fn layout(self, pixel_size: usize, extent: TextureExtent, dims: u32) -> PixelLayout {
+        let width = extent.width as usize;
+        let height = extent.height as usize;
+        let row_length = if self.row_length > 0 {
+            self.row_length as usize
+        } else {
+            width
+        };
+        // the alignment is always a power of 2 that is either smaller than (and thus evenly divides) the element size
+        // or larger than it, so rounding the row size in bytes up to the alignment is equivalent to the spec's formula
+        let row_stride = (row_length * pixel_size).next_multiple_of(self.alignment as usize);
+        let image_height = if self.image_height > 0 && dims >= 3 {
+            self.image_height as usize
+        } else {
+            height
+        };
+        let image_stride = row_stride * image_height;
+
+        let mut offset = self.skip_pixels as usize * pixel_size;
+        if dims >= 2 {
+            offset += self.skip_rows as usize * row_stride;
+        }
+        if dims >= 3 {
+            offset += self.skip_images as usize * image_stride;
+        }
+        PixelLayout {
+            offset,
+            row_bytes: width * pixel_size,
+            row_stride,
+            image_stride,
+            rows: height,
+            images: extent.depth as usize,
+        }
+    }
+}
+
+/// Location of the pixel data of an image in memory, as computed by [`PixelStoreModes::layout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PixelLayout {
+    /// Offset in bytes of the first pixel of the image
+    pub(crate) offset: usize,
+    /// Number of bytes of pixel data in each row
+    pub(crate) row_bytes: usize,
+    /// Distance in bytes between the starts of consecutive rows
+    pub(crate) row_stride: usize,
+    /// Distance in bytes between the starts of consecutive images
+    pub(crate) image_stride: usize,
+    pub(crate) rows: usize,
+    pub(crate) images: usize,
+}
+
+impl PixelLayout {
+    /// Number of bytes that must be accessible (starting from offset 0) to address the entire image
+    pub(crate) fn len(&self) -> usize {
+        if self.row_bytes == 0 || self.rows == 0 || self.images == 0 {
+            return 0;
+        }
+        self.offset
+            + (self.images - 1) * self.image_stride
+            + (self.rows - 1) * self.row_stride
+            + self.row_bytes
+    }
+    /// Number of bytes the image occupies when tightly packed
+    pub(crate) fn packed_len(&self) -> usize {
+        self.row_bytes * self.rows * self.images
+    }
+    /// Iterator over the byte ranges of each row of the image, in order
+    pub(crate) fn row_ranges(&self) -> impl Iterator<Item = Range<usize>> + use<> {
+        let Self {
+            offset,
+            row_bytes,
+            row_stride,
+            image_stride,
+            rows,
+            images,
+        } = *self;
+        // empty images have no rows to address, wherever their offset points
+        let images = if row_bytes == 0 || rows == 0 {
+            0
+        } else {
+            images
+        };
+        (0..images).flat_map(move |image| {
+            (0..rows).map(move |row| {
+                let start = offset + image * image_stride + row * row_stride;
+                start..start + row_bytes
+            })
+        })
+    }
+    /// Copies the rows of the image out of `src` into a tightly packed buffer, reversing the byte order of each `swap`-byte element
+    /// if specified. `src` must be at least [`len`](Self::len) bytes long
+    pub(crate) fn gather(&self, src: &[u8], swap: Option<usize>) -> Vec<u8> {
+        let mut packed = Vec::with_capacity(self.packed_len());
+        for row in self.row_ranges() {
+            packed.extend_from_slice(&src[row]);
+        }
+        if let Some(size) = swap {
+            swap_elements(&mut packed, size);
+        }
+        packed
+    }
+    /// Inverse of [`gather`](Self::gather): copies tightly packed rows from `src` into their locations in `dst`, leaving any
+    /// padding or skipped bytes untouched. `dst` must be at least [`len`](Self::len) bytes long
+    pub(crate) fn scatter(&self, src: &[u8], dst: &mut [u8], swap: Option<usize>) {
+        for (row, data) in self
+            .row_ranges()
+            .zip(src.chunks_exact(self.row_bytes.max(1)))
+        {
+            let out = &mut dst[row];
+            out.copy_from_slice(data);
+            if let Some(size) = swap {
+                swap_elements(out, size);
+            }
+        }
+    }
+}
+
+/// Reverses the byte order of each `size`-byte element of `data`
+fn swap_elements(data: &mut [u8], size: usize) {
+    if size > 1 {
+        for element in data.chunks_exact_mut(size) {
+            element.reverse();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modes(f: impl FnOnce(&mut PixelStoreModes)) -> PixelStoreModes {
+        let mut modes = PixelStoreModes::default();
+        f(&mut modes);
+        modes
+    }
+    /// Source bytes whose values are their own offsets
+    fn counting(len: usize) -> Vec<u8> {
+        (0..=u8::MAX).cycle().take(len).collect()
+    }
+
+    struct LayoutCase {
+        modes: PixelStoreModes,
+        pixel_size: usize,
+        extent: (u32, u32, u32),
+        dims: u32,
+        offset: usize,
+        row_stride: usize,
+        image_stride: usize,
+        len: usize,
+        /// The bytes of a [`counting`] source that make up the image, in order
+        rows: &'static [(usize, usize)],
+    }
+
+    fn layout_cases() -> Vec<LayoutCase> {
+        vec![
+            // the default alignment of 4 pads rows of 3 byte pixels
+            LayoutCase {
+                modes: PixelStoreModes::default(),
+                pixel_size: 3,
+                extent: (3, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 12,
+                image_stride: 24,
+                len: 21,
+                rows: &[(0, 9), (12, 21)],
+            },
+            LayoutCase {
+                modes: modes(|m| m.alignment = 1),
+                pixel_size: 3,
+                extent: (3, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 9,
+                image_stride: 18,
+                len: 18,
+                rows: &[(0, 9), (9, 18)],
+            },
+            LayoutCase {
+                modes: modes(|m| m.alignment = 2),
+                pixel_size: 3,
+                extent: (3, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 10,
+                image_stride: 20,
+                len: 19,
+                rows: &[(0, 9), (10, 19)],
+            },
+            LayoutCase {
+                modes: modes(|m| m.alignment = 8),
+                pixel_size: 3,
+                extent: (3, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 16,
+                image_stride: 32,
+                len: 25,
+                rows: &[(0, 9), (16, 25)],
+            },
+            LayoutCase {
+                modes: modes(|m| m.alignment = 8),
+                pixel_size: 4,
+                extent: (3, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 16,
+                image_stride: 32,
+                len: 28,
+                rows: &[(0, 12), (16, 28)],
+            },
+            // rows that are a multiple of the alignment aren't padded
+            LayoutCase {
+                modes: modes(|m| m.alignment = 8),
+                pixel_size: 8,
+                extent: (1, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 8,
+                image_stride: 16,
+                len: 16,
+                rows: &[(0, 16)],
+            },
+            // the row length overrides the width, and is padded to the alignment
+            LayoutCase {
+                modes: modes(|m| m.row_length = 5),
+                pixel_size: 1,
+                extent: (3, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 8,
+                image_stride: 16,
+                len: 11,
+                rows: &[(0, 3), (8, 11)],
+            },
+            LayoutCase {
+                modes: modes(|m| {
+                    m.alignment = 1;
+                    m.row_length = 4;
+                    m.skip_pixels = 1;
+                    m.skip_rows = 2;
+                }),
+                pixel_size: 2,
+                extent: (2, 2, 1),
+                dims: 2,
+                offset: 18,
+                row_stride: 8,
+                image_stride: 16,
+                len: 30,
+                rows: &[(18, 22), (26, 30)],
+            },
+            // 1D images ignore the skipped rows and the image height
+            LayoutCase {
+                modes: modes(|m| {
+                    m.skip_pixels = 1;
+                    m.skip_rows = 3;
+                    m.skip_images = 2;
+                    m.image_height = 7;
+                }),
+                pixel_size: 4,
+                extent: (3, 1, 1),
+                dims: 1,
+                offset: 4,
+                row_stride: 12,
+                image_stride: 12,
+                len: 16,
+                rows: &[(4, 16)],
+            },
+            // 2D images ignore the skipped images and the image height
+            LayoutCase {
+                modes: modes(|m| {
+                    m.alignment = 1;
+                    m.skip_images = 2;
+                    m.image_height = 5;
+                }),
+                pixel_size: 1,
+                extent: (2, 2, 1),
+                dims: 2,
+                offset: 0,
+                row_stride: 2,
+                image_stride: 4,
+                len: 4,
+                rows: &[(0, 4)],
+            },
+            LayoutCase {
+                modes: modes(|m| m.alignment = 1),
+                pixel_size: 1,
+                extent: (2, 2, 3),
+                dims: 3,
+                offset: 0,
+                row_stride: 2,
+                image_stride: 4,
+                len: 12,
+                rows: &[(0, 12)],
+            },
+            LayoutCase {
+                modes: modes(|m| {
+                    m.alignment = 1;
+                    m.image_height = 4;
+                    m.skip_images = 1;
+                    m.skip_rows = 1;
+                    m.skip_pixels = 1;
+                }),
+                pixel_size: 1,
+                extent: (2, 2, 3),
+                dims: 3,
+                offset: 11,
+                row_stride: 2,
+                image_stride: 8,
+                len: 31,
+                rows: &[(11, 15), (19, 23), (27, 31)],
+            },
+            LayoutCase {
+                modes: modes(|m| {
+                    m.row_length = 3;
+                    m.image_height = 3;
+                }),
+                pixel_size: 2,
+                extent: (2, 2, 2),
+                dims: 3,
+                offset: 0,
+                row_stride: 8,
+                image_stride: 24,
+                len: 36,
+                rows: &[(0, 4), (8, 12), (24, 28), (32, 36)],
+            },
+        ]
+    }
+
+    fn decode<P: ConvertPixel<RgbaColorFormat<f32>>>(pixel: P) -> [f32; 4] {
+        pixel.convert_pixel().colors
+    }
+    fn encode<P>(colors: [f32; 4]) -> P
+    where
+        RgbaColorFormat<f32>: ConvertPixel<P>,
+    {
+        RgbaColorFormat { colors }.convert_pixel()
+    }
+    fn decode_int<P: ConvertPixel<RgbaColorFormat<i64>>>(pixel: P) -> [i64; 4] {
+        pixel.convert_pixel().colors
+    }
+    fn encode_int<P>(colors: [i64; 4]) -> P
+    where
+        RgbaColorFormat<i64>: ConvertPixel<P>,
+    {
+        RgbaColorFormat { colors }.convert_pixel()
+    }
+    fn ufloat<const BITS: u32>(value: f32) -> u32 {
+        <f32 as ConvertChannel<PackedUfloatChannel<BITS>>>::convert_channel(value).to_bits()
+    }
+    fn from_ufloat<const BITS: u32>(bits: u32) -> f32 {
+        PackedUfloatChannel::<BITS>::from_bits(bits).convert_channel()
+    }
+    /// Samples of the 32 bit patterns, including the lowest and highest ones
+    fn u32_samples() -> impl Iterator<Item = u32> {
+        (0..=u32::MAX).step_by(65_521).chain([u32::MAX])
+    }
+
+    /// Every bit pattern of a packed normalized format survives unpacking to float and packing again
+    macro_rules! assert_packed_round_trip {
+        ($($ty:ident($int:ty)),+ $(,)?) => {$(
+            let samples: Box<dyn Iterator<Item = $int>> = match size_of::<$int>() {
+                4 => Box::new(u32_samples().map(|b| b as $int)),
+                _ => Box::new(<$int>::MIN..=<$int>::MAX),
+            };
+            for bits in samples {
+                let rgba = decode($ty(bits));
+                assert!(rgba.iter().all(|c| (0.0..=1.0).contains(c)), "{} {bits:#x} decoded to {rgba:?}", stringify!($ty));
+                let packed: $ty = encode(rgba);
+                assert_eq!(packed.0, bits, "{} {bits:#x} did not round trip through {rgba:?}", stringify!($ty));
+            }
+        )+};
+    }
+
+    #[test]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "samples are only cast to the size of the packed type"
+    )]
+    fn packed_normalized_round_trip() {
+        assert_packed_round_trip!(
+            Rgb332(u8),
+            Rgb233Rev(u8),
+            Rgb565(u16),
+            Rgb565Rev(u16),
+            Rgba4444(u16),
+            Bgra4444(u16),
+            Rgba4444Rev(u16),
+            Bgra4444Rev(u16),
+            Rgba5551(u16),
+            Bgra5551(u16),
+            Rgba1555Rev(u16),
+            Bgra1555Rev(u16),
+            Rgba8888(u32),
+            Bgra8888(u32),
+            Rgba8888Rev(u32),
+            Bgra8888Rev(u32),
+            Rgba1010102(u32),
+            Bgra1010102(u32),
+            Rgba2101010Rev(u32),
+            Bgra2101010Rev(u32),
+        );
+    }
+    #[test]
+    fn packed_normalized_values() {
+        assert_eq!(decode(Rgb565(0xF800)), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(decode(Rgb565(0x07E0)), [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(decode(Rgb565Rev(0xF800)), [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(decode(Rgba4444(0x000F)), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(decode(Bgra4444(0xF000)), [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(decode(Rgba4444Rev(0x0F00))[2], 1.0);
+        assert_eq!(decode(Rgba5551(0x0001)), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(decode(Rgba1555Rev(0x8000)), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(decode(Bgra1555Rev(0x7C00)), [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(decode(Rgba2101010Rev(0xC000_03FF)), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(decode(Rgba1010102(0x0000_0001))[3], 1.0 / 3.0);
+        assert_eq!(decode(Bgra1010102(0xFFC0_0000)), [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(
+            decode(Rgb332(0b0100_1001)),
+            [2.0 / 7.0, 2.0 / 7.0, 1.0 / 3.0, 1.0]
+        );
+
+        assert_eq!(encode::<Rgb565>([1.0, 0.0, 0.0, 0.0]).0, 0xF800);
+        assert_eq!(encode::<Rgb565>([0.0, 0.5, 0.0, 0.0]).0, 32 << 5);
+        assert_eq!(encode::<Rgba4444>([0.2, 0.4, 0.6, 0.8]).0, 0x369C);
+        // values are clamped to [0, 1], and NaN maps to 0
+        assert_eq!(encode::<Rgba5551>([2.0, -1.0, f32::NAN, 0.75]).0, 0xF801);
+        assert_eq!(
+            encode::<Rgba2101010Rev>([f32::INFINITY, f32::NEG_INFINITY, 1.0, 0.0]).0,
+            0x3FF0_03FF
         );
-        None
+        assert_eq!(encode::<Rgba1010102>([0.0, 0.0, 0.0, 0.4]).0, 1);
+    }
+    #[test]
+    fn packed_integer_values() {
+        assert_eq!(decode_int(Rgba2101010Rev(0xC000_03FF)), [1023, 0, 0, 3]);
+        assert_eq!(decode_int(Bgra8888(0x0102_0304)), [3, 2, 1, 4]);
+        assert_eq!(decode_int(Rgb565(0xFFFF)), [31, 63, 31, 1]);
+        // out of range values are clamped to the range of each field
+        assert_eq!(
+            encode_int::<Rgba2101010Rev>([2000, -5, 7, 9]).0,
+            0xC070_03FF
+        );
+        assert_eq!(encode_int::<Rgba5551>([31, 32, -1, 1]).0, 0xFFC1);
+        for bits in u32_samples() {
+            let packed: Rgba2101010Rev = encode_int(decode_int(Rgba2101010Rev(bits)));
+            assert_eq!(packed.0, bits);
+        }
+    }
+    #[test]
+    fn unsigned_float_values() {
+        // 11 bit floats: 5 bit exponent, 6 bit mantissa
+        assert_eq!(from_ufloat::<11>(0), 0.0);
+        assert_eq!(from_ufloat::<11>(15 << 6), 1.0);
+        assert_eq!(from_ufloat::<11>(0x7BF), 65024.0);
+        assert_eq!(from_ufloat::<11>(0x001), 2f32.powi(-20));
+        assert_eq!(from_ufloat::<11>(0x03F), 63.0 * 2f32.powi(-20));
+        assert_eq!(from_ufloat::<11>(0x7C0), f32::INFINITY);
+        assert!(from_ufloat::<11>(0x7C1).is_nan());
+        // 10 bit floats: 5 bit exponent, 5 bit mantissa
+        assert_eq!(from_ufloat::<10>(15 << 5), 1.0);
+        assert_eq!(from_ufloat::<10>(0x3DF), 64512.0);
+        assert_eq!(from_ufloat::<10>(0x001), 2f32.powi(-19));
+        assert_eq!(from_ufloat::<10>(0x3E0), f32::INFINITY);
+
+        assert_eq!(ufloat::<11>(1.0), 15 << 6);
+        assert_eq!(ufloat::<11>(0.0), 0);
+        assert_eq!(ufloat::<11>(-0.0), 0);
+        assert_eq!(ufloat::<11>(2f32.powi(-20)), 0x001);
+        assert_eq!(ufloat::<10>(2f32.powi(-19)), 0x001);
+        // negative values clamp to 0 and large finite values to the largest finite value
+        assert_eq!(ufloat::<11>(-1.0), 0);
+        assert_eq!(ufloat::<11>(f32::NEG_INFINITY), 0);
+        assert_eq!(ufloat::<11>(65024.0), 0x7BF);
+        assert_eq!(ufloat::<11>(1e10), 0x7BF);
+        assert_eq!(ufloat::<10>(f32::MAX), 0x3DF);
+        // positive infinity is preserved, and NaN of either sign is converted to (positive) NaN
+        assert_eq!(ufloat::<11>(f32::INFINITY), 0x7C0);
+        assert_eq!(ufloat::<10>(f32::INFINITY), 0x3E0);
+        for nan in [f32::NAN, -f32::NAN] {
+            let bits = ufloat::<11>(nan);
+            assert!(
+                bits & 0x7C0 == 0x7C0 && bits & 0x3F != 0,
+                "{nan} encoded to {bits:#x}"
+            );
+            assert!(from_ufloat::<11>(bits).is_nan());
+        }
+    }
+    #[test]
+    fn unsigned_float_round_trip() {
+        for bits in 0..1 << 11 {
+            let value = from_ufloat::<11>(bits);
+            if value.is_nan() {
+                assert!(from_ufloat::<11>(ufloat::<11>(value)).is_nan());
+            } else {
+                assert!(value >= 0.0);
+                assert_eq!(ufloat::<11>(value), bits, "{bits:#x} decoded to {value}");
+            }
+        }
+        for bits in 0..1 << 10 {
+            let value = from_ufloat::<10>(bits);
+            if !value.is_nan() {
+                assert_eq!(ufloat::<10>(value), bits, "{bits:#x} decoded to {value}");
+            }
+        }
+        // packed pixels whose fields are all finite survive unpacking and packing again
+        let finite = |bits: u32| {
+            [0, 11].iter().all(|s| (bits >> s) & 0x7C0 != 0x7C0) && (bits >> 22) & 0x3E0 != 0x3E0
+        };
+        for bits in u32_samples().filter(|&b| finite(b)) {
+            let packed: Rgb10F11F11FRev = encode(decode(Rgb10F11F11FRev(bits)));
+            assert_eq!(packed.0, bits);
+        }
+        assert_eq!(
+            decode(Rgb10F11F11FRev(15 << 6 | (15 << 5) << 22)),
+            [1.0, 0.0, 1.0, 1.0]
+        );
+    }
+    #[test]
+    fn rgb9e5_values() {
+        // the exponent is biased by 15 and the mantissas have no implicit leading 1
+        let pixel = |r: u32, g: u32, b: u32, e: u32| Rgb9E5(r | g << 9 | b << 18 | e << 27);
+        assert_eq!(decode(pixel(0, 0, 0, 0)), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(decode(pixel(256, 128, 64, 16)), [1.0, 0.5, 0.25, 1.0]);
+        assert_eq!(decode(pixel(511, 0, 0, 31))[0], 65408.0);
+        assert_eq!(decode(pixel(1, 0, 0, 0))[0], 2f32.powi(-24));
+
+        assert_eq!(
+            encode::<Rgb9E5>([1.0, 0.5, 0.25, 0.0]).0,
+            pixel(256, 128, 64, 16).0
+        );
+        assert_eq!(encode::<Rgb9E5>([0.0; 4]).0, 0);
+        assert_eq!(
+            encode::<Rgb9E5>([2f32.powi(-24), 0.0, 0.0, 0.0]).0,
+            pixel(1, 0, 0, 0).0
+        );
+        // values are clamped to [0, the largest representable value], and NaN maps to 0
+        assert_eq!(
+            encode::<Rgb9E5>([1e10, -1.0, f32::NAN, 0.0]).0,
+            pixel(511, 0, 0, 31).0
+        );
+        assert_eq!(
+            encode::<Rgb9E5>([f32::INFINITY, f32::NEG_INFINITY, 0.0, 0.0]).0,
+            pixel(511, 0, 0, 31).0
+        );
+        // components much smaller than the largest one lose their precision to the shared exponent
+        assert_eq!(
+            encode::<Rgb9E5>([1.0, 2f32.powi(-10), 0.0, 0.0]).0,
+            pixel(256, 0, 0, 16).0
+        );
+        // rounding the largest component up can carry into the exponent
+        assert_eq!(
+            encode::<Rgb9E5>([511.9 / 256.0, 0.0, 0.0, 0.0]).0,
+            pixel(256, 0, 0, 17).0
+        );
+    }
+    #[test]
+    fn rgb9e5_round_trip() {
+        // canonical encodings (whose largest mantissa uses all 9 bits unless the exponent is minimal) survive unpacking and
+        // packing again
+        let canonical = |bits: u32| {
+            let max = [0, 9, 18]
+                .map(|s| (bits >> s) & 0x1FF)
+                .into_iter()
+                .max()
+                .unwrap_or(0);
+            bits >> 27 == 0 || max >= 256
+        };
+        for bits in u32_samples().filter(|&b| canonical(b)) {
+            let packed: Rgb9E5 = encode(decode(Rgb9E5(bits)));
+            assert_eq!(packed.0, bits, "{bits:#x}");
+        }
+        // packing loses at most half a unit in the last place of the largest component (or of the smallest exponent)
+        for rgb in [
+            [0.3, 0.7, 0.1],
+            [1234.5, 1.0, 0.001],
+            [1e-6, 3e-7, 0.0],
+            [60000.0, 60000.0, 1.0],
+        ] {
+            let [r, g, b, _] = decode(encode::<Rgb9E5>([rgb[0], rgb[1], rgb[2], 1.0]));
+            let max = rgb.into_iter().fold(0.0, f32::max);
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "the exponent of an f32 fits in an i32"
+            )]
+            let ulp = 2f32.powi((max.log2().floor() as i32 - 8).max(-24));
+            for (unpacked, original) in [r, g, b].into_iter().zip(rgb) {
+                assert!(
+                    (unpacked - original).abs() <= ulp / 2.0,
+                    "{rgb:?} became {:?}",
+                    [r, g, b]
+                );
+            }
+        }
+    }
+    #[test]
+    fn depth_stencil_round_trip() {
+        for bits in (0..=u32::MAX).step_by(251).chain([u32::MAX]) {
+            let unpacked: Float32Stencil8 = DepthStencil248(bits).convert_pixel();
+            assert!((0.0..=1.0).contains(&unpacked.depth));
+            assert_eq!(unpacked.stencil, bits & 0xFF);
+            let packed: DepthStencil248 = unpacked.convert_pixel();
+            assert_eq!(
+                packed.0, bits,
+                "{bits:#x} did not round trip through {unpacked:?}"
+            );
+        }
+        let pack = |depth: f32, stencil: u32| -> u32 {
+            let packed: DepthStencil248 = Float32Stencil8 { depth, stencil }.convert_pixel();
+            packed.0
+        };
+        assert_eq!(pack(0.0, 0), 0);
+        assert_eq!(pack(1.0, 0xFF), u32::MAX);
+        assert_eq!(pack(0.5, 7), 0x80_0000 << 8 | 7);
+        // depth values are clamped to [0, 1] (NaN maps to 0), and only the low 8 bits of the stencil word are kept
+        assert_eq!(pack(2.0, 0x1FF), u32::MAX);
+        assert_eq!(pack(-1.0, 0x100), 0);
+        assert_eq!(pack(f32::NAN, 0xABCD_EF01), 1);
+
+        let unpacked: Float32Stencil8 = DepthStencil248(0xFFFF_FF00).convert_pixel();
+        assert_eq!((unpacked.depth, unpacked.stencil), (1.0, 0));
+        // float depth is passed through unclamped between float representations, while the stencil index keeps its 8 bits
+        let same: Float32Stencil8 = Float32Stencil8 {
+            depth: 2.5,
+            stencil: 0x1_0003,
+        }
+        .convert_pixel();
+        assert_eq!((same.depth, same.stencil), (2.5, 3));
+    }
+    #[test]
+    fn layouts() {
+        for (i, case) in layout_cases().into_iter().enumerate() {
+            let (width, height, depth) = case.extent;
+            let layout = case.modes.layout(
+                case.pixel_size,
+                TextureExtent::new(width, height, depth),
+                case.dims,
+            );
+            assert_eq!(layout.offset, case.offset, "offset of case {i}");
+            assert_eq!(layout.row_stride, case.row_stride, "row stride of case {i}");
+            assert_eq!(
+                layout.image_stride, case.image_stride,
+                "image stride of case {i}"
+            );
+            assert_eq!(layout.len(), case.len, "length of case {i}");
+            assert_eq!(
+                layout.packed_len(),
+                (width * height * depth) as usize * case.pixel_size,
+                "packed length of case {i}"
+            );
+            assert_eq!(
+                layout.row_ranges().last().map(|r| r.end),
+                Some(case.len),
+                "end of the last row of case {i}"
+            );
+
+            let src = counting(case.len);
+            let expected: Vec<u8> = case
+                .rows
+                .iter()
+                .flat_map(|&(start, end)| &src[start..end])
+                .copied()
+                .collect();
+            assert_eq!(
+                layout.gather(&src, None),
+                expected,
+                "gathered bytes of case {i}"
+            );
+
+            // scattering leaves padding and skipped bytes untouched
+            let mut dst = vec![0xFF; case.len];
+            layout.scatter(&expected, &mut dst, None);
+            let untouched = |b: usize| {
+                !case
+                    .rows
+                    .iter()
+                    .any(|&(start, end)| (start..end).contains(&b))
+            };
+            for (b, &byte) in dst.iter().enumerate() {
+                if untouched(b) {
+                    assert_eq!(byte, 0xFF, "byte {b} of case {i} was overwritten");
+                } else {
+                    assert_eq!(byte, src[b], "byte {b} of case {i} was not scattered");
+                }
+            }
+        }
+    }
+    #[test]
+    fn empty_layouts() {
+        let modes = modes(|m| m.skip_pixels = 4);
+        for extent in [(0, 2, 1), (2, 0, 1), (2, 2, 0)] {
+            let layout = modes.layout(4, TextureExtent::new(extent.0, extent.1, extent.2), 3);
+            assert_eq!(layout.len(), 0);
+            assert_eq!(layout.packed_len(), 0);
+            assert!(layout.gather(&[], None).is_empty());
+            layout.scatter(&[], &mut [], None);
+        }
+    }
+    #[test]
+    fn swap_bytes() {
+        let modes = modes(|m| {
+            m.alignment = 8;
+            m.skip_pixels = 1;
+        });
+        // two rows of three 2-byte pixels
+        let layout = modes.layout(2, TextureExtent::new(3, 2, 1), 2);
+        assert_eq!(layout.len(), 16);
+        let src = counting(layout.len());
+        assert_eq!(
+            layout.gather(&src, Some(2)),
+            [3, 2, 5, 4, 7, 6, 11, 10, 13, 12, 15, 14]
+        );
+        // 4-byte elements
+        let layout = modes.layout(4, TextureExtent::new(1, 2, 1), 2);
+        assert_eq!(
+            layout.gather(&counting(layout.len()), Some(4)),
+            [7, 6, 5, 4, 15, 14, 13, 12]
+        );
+        // single bytes aren't swapped
+        assert_eq!(
+            layout.gather(&counting(layout.len()), Some(1)),
+            [4, 5, 6, 7, 12, 13, 14, 15]
+        );
+
+        let packed = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut dst = [0; 16];
+        layout.scatter(&packed, &mut dst, Some(2));
+        assert_eq!(dst, [0, 0, 0, 0, 2, 1, 4, 3, 0, 0, 0, 0, 6, 5, 8, 7]);
+        assert_eq!(layout.gather(&dst, Some(2)), packed);
     }
 }
@@ -0,0 +1,1721 @@
+//! # Block-compressed texture decoding
+//! CPU decoders for the block-compressed formats exposed by the GL (ETC2/EAC, S3TC, RGTC and BPTC). These are used to emulate formats that
+//! the device can't sample natively, and to read back compressed images as uncompressed pixel data. Decoded texels are laid out
+//! like the Metal pixel format of the format's [decompressed equivalent](InternalFormat::decompressed_equivalent)
+
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    reason = "decoders work on fixed-width bit fields whose ranges are known"
+)]
+
+use crate::enums::InternalFormat;
+
+/// Side length in texels of the blocks of every block-compressed format supported by Metal
+pub(crate) const COMPRESSED_BLOCK_DIM: usize = 4;
+
+/// Decodes the texels of a block, in row-major order
+type BlockDecoder = fn(&[u8], &mut [u8]);
+
+/// Decodes `images` images of `width`x`height` texels, each stored as tightly packed blocks of the block-compressed format
+/// `format`, into tightly packed texels of its [decompressed equivalent](InternalFormat::decompressed_equivalent).
+/// Returns None if `format` isn't block-compressed
+#[allow(clippy::enum_glob_use)]
+pub(crate) fn decompress(
+    format: InternalFormat,
+    src: &[u8],
+    width: usize,
+    height: usize,
+    images: usize,
+) -> Option<Vec<u8>> {
+    use InternalFormat::*;
+    // (bytes per block, bytes per decoded texel, block decoder)
+    let (block_size, texel_size, decode): (usize, usize, BlockDecoder) = match format {
+        CompressedRgb8Etc2 | CompressedSrgb8Etc2 => (8, 4, |b, out| etc2_rgb8(b, out, false)),
+        CompressedRgb8PunchthroughAlpha1Etc2 | CompressedSrgb8PunchthroughAlpha1Etc2 => {
+            (8, 4, |b, out| etc2_rgb8(b, out, true))
+        }
+        CompressedRgba8Etc2Eac | CompressedSrgb8Alpha8Etc2Eac => (16, 4, |b, out| {
+            etc2_rgb8(&b[8..], out, false);
+            eac_alpha8(&b[..8], out);
+        }),
+        CompressedR11Eac => (8, 2, |b, out| eac_r11(b, out, 0, 1, false)),
+        CompressedSignedR11Eac => (8, 2, |b, out| eac_r11(b, out, 0, 1, true)),
+        CompressedRg11Eac => (16, 4, |b, out| {
+            eac_r11(&b[..8], out, 0, 2, false);
+            eac_r11(&b[8..], out, 1, 2, false);
+        }),
+        CompressedSignedRg11Eac => (16, 4, |b, out| {
+            eac_r11(&b[..8], out, 0, 2, true);
+            eac_r11(&b[8..], out, 1, 2, true);
+        }),
+        CompressedRgbS3tcDxt1Ext | CompressedSrgbS3tcDxt1Ext => {
+            (8, 4, |b, out| bc1(b, out, Some(255)))
+        }
+        CompressedRgbaS3tcDxt1Ext | CompressedSrgbAlphaS3tcDxt1Ext => {
+            (8, 4, |b, out| bc1(b, out, Some(0)))
+        }
+        CompressedRgbaS3tcDxt3Ext | CompressedSrgbAlphaS3tcDxt3Ext => (16, 4, |b, out| {
+            bc1(&b[8..], out, None);
+            bc2_alpha(&b[..8], out);
+        }),
+        CompressedRgbaS3tcDxt5Ext | CompressedSrgbAlphaS3tcDxt5Ext => (16, 4, |b, out| {
+            bc1(&b[8..], out, None);
+            bc4(&b[..8], out, 3, 4, false);
+        }),
+        CompressedRedRgtc1 => (8, 1, |b, out| bc4(b, out, 0, 1, false)),
+        CompressedSignedRedRgtc1 => (8, 1, |b, out| bc4(b, out, 0, 1, true)),
+        CompressedRgRgtc2 => (16, 2, |b, out| {
+            bc4(&b[..8], out, 0, 2, false);
+            bc4(&b[8..], out, 1, 2, false);
+        }),
+        CompressedSignedRgRgtc2 => (16, 2, |b, out| {
+            bc4(&b[..8], out, 0, 2, true);
+            bc4(&b[8..], out, 1, 2, true);
+        }),
+        CompressedRgbaBptcUnorm | CompressedSrgbAlphaBptcUnorm => (16, 4, bc7),
+        CompressedRgbBptcUnsignedFloat => (16, 8, |b, out| bc6h(b, out, false)),
+        CompressedRgbBptcSignedFloat => (16, 8, |b, out| bc6h(b, out, true)),
+        _ => return None,
+    };
+    let blocks_wide = width.div_ceil(COMPRESSED_BLOCK_DIM);
+    let image_bytes = blocks_wide * height.div_ceil(COMPRESSED_BLOCK_DIM) * block_size;
+    let row_bytes = width * texel_size;
+    let mut out = vec![0u8; row_bytes * height * images];
+    if out.is_empty() {
+        return Some(out);
+    }
+    debug_assert!(
+        src.len() >= image_bytes * images,
+        "not enough compressed data for {images} {width}x{height} images of {format:?}"
+    );
+    let mut texels = [0u8; 16 * 8];
+    let texels = &mut texels[..16 * texel_size];
+    for (image, dst) in src
+        .chunks_exact(image_bytes)
+        .zip(out.chunks_exact_mut(row_bytes * height))
+    {
+        for (i, block) in image.chunks_exact(block_size).enumerate() {
+            let x = (i % blocks_wide) * COMPRESSED_BLOCK_DIM;
+            let y = (i / blocks_wide) * COMPRESSED_BLOCK_DIM;
+            decode(block, texels);
+            // blocks along the right and bottom edges of the image may extend past it
+            let row_len = COMPRESSED_BLOCK_DIM.min(width - x) * texel_size;
+            for row in 0..COMPRESSED_BLOCK_DIM.min(height - y) {
+                let src_row = &texels[row * COMPRESSED_BLOCK_DIM * texel_size..][..row_len];
+                dst[(y + row) * row_bytes + x * texel_size..][..row_len].copy_from_slice(src_row);
+            }
+        }
+    }
+    Some(out)
+}
+
+/// Extends a `bits`-bit color component to 8 bits by replicating its high bits into the low bits
+fn extend(c: u8, bits: u32) -> i32 {
+    let c = u32::from(c);
+    ((c << (8 - bits)) | (c >> (2 * bits - 8))) as i32
+}
+
+/// Sign-extends the low `bits` bits of `v`
+fn sign_extend(v: i32, bits: u32) -> i32 {
+    let shift = 32 - bits;
+    (v << shift) >> shift
+}
+
+/// ETC1 intensity modifier tables, indexed by table codeword. Pixel indices 0 to 3 select `+small`, `+large`, `-small` and `-large`
+const ETC1_MODIFIERS: [[i32; 2]; 8] = [
+    [2, 8],
+    [5, 17],
+    [9, 29],
+    [13, 42],
+    [18, 60],
+    [24, 80],
+    [33, 106],
+    [47, 183],
+];
+/// Distances between the paint colors of the ETC2 T and H modes
+const ETC2_DISTANCES: [i32; 8] = [3, 6, 11, 16, 23, 32, 41, 64];
+
+/// Decodes an ETC2 RGB8 block (or an RGB8 block with punchthrough alpha) into RGBA8 texels
+#[allow(
+    clippy::many_single_char_names,
+    reason = "names follow the ETC2 specification"
+)]
+fn etc2_rgb8(b: &[u8], out: &mut [u8], punchthrough: bool) {
+    let indices = u32::from_be_bytes([b[4], b[5], b[6], b[7]]);
+    // pixel indices are stored in column-major order, with the high bits of all indices preceding the low bits
+    let pixel_index = |x: usize, y: usize| {
+        let i = x * 4 + y;
+        ((((indices >> (16 + i)) & 1) << 1) | ((indices >> i) & 1)) as usize
+    };
+    // punchthrough blocks store the opaque flag in place of the differential mode flag
+    let differential = b[3] & 2 != 0;
+    let opaque = !punchthrough || differential;
+    let mut write = |x: usize, y: usize, rgb: Option<[i32; 3]>| {
+        let texel = match rgb {
+            Some([r, g, b]) => [r, g, b, 255].map(|c| c.clamp(0, 255) as u8),
+            // index 2 of non-opaque punchthrough blocks is transparent black
+            None => [0; 4],
+        };
+        out[(y * 4 + x) * 4..][..4].copy_from_slice(&texel);
+    };
+    let sub_blocks = |colors: [[i32; 3]; 2],
+                      write: &mut dyn FnMut(usize, usize, Option<[i32; 3]>)| {
+        let flip = b[3] & 1 != 0;
+        let tables = [b[3] >> 5, (b[3] >> 2) & 7].map(|t| ETC1_MODIFIERS[t as usize]);
+        for y in 0..4 {
+            for x in 0..4 {
+                let sub = usize::from(if flip { y >= 2 } else { x >= 2 });
+                let [small, large] = tables[sub];
+                // non-opaque punchthrough blocks replace the small modifiers with 0 and transparency
+                let small = if opaque { small } else { 0 };
+                let modifier = match pixel_index(x, y) {
+                    2 if !opaque => {
+                        write(x, y, None);
+                        continue;
+                    }
+                    0 => small,
+                    1 => large,
+                    2 => -small,
+                    _ => -large,
+                };
+                write(x, y, Some(colors[sub].map(|c| c + modifier)));
+            }
+        }
+    };
+    let paint = |colors: [[i32; 3]; 4], write: &mut dyn FnMut(usize, usize, Option<[i32; 3]>)| {
+        for y in 0..4 {
+            for x in 0..4 {
+                let i = pixel_index(x, y);
+                write(x, y, (opaque || i != 2).then_some(colors[i]));
+            }
+        }
+    };
+    let offset = |c: [i32; 3], d: i32| c.map(|v| (v + d).clamp(0, 255));
+
+    if !punchthrough && !differential {
+        // individual mode
+        let colors = [
+            [b[0] >> 4, b[1] >> 4, b[2] >> 4].map(|c| extend(c, 4)),
+            [b[0] & 15, b[1] & 15, b[2] & 15].map(|c| extend(c, 4)),
+        ];
+        sub_blocks(colors, &mut write);
+        return;
+    }
+    let base = [b[0] >> 3, b[1] >> 3, b[2] >> 3];
+    let second = [b[0], b[1], b[2]].map(|v| sign_extend(i32::from(v & 7), 3));
+    let second = [0, 1, 2].map(|c| i32::from(base[c]) + second[c]);
+    // the ETC2 modes are signalled by overflowing the differential second color
+    if !(0..32).contains(&second[0]) {
+        // T mode
+        let c1 = [((b[0] >> 1) & 0xc) | (b[0] & 3), b[1] >> 4, b[1] & 15].map(|c| extend(c, 4));
+        let c2 = [b[2] >> 4, b[2] & 15, b[3] >> 4].map(|c| extend(c, 4));
+        let d = ETC2_DISTANCES[usize::from(((b[3] >> 1) & 6) | (b[3] & 1))];
+        paint([c1, offset(c2, d), c2, offset(c2, -d)], &mut write);
+    } else if !(0..32).contains(&second[1]) {
+        // H mode
+        let c1 = [
+            (b[0] >> 3) & 15,
+            ((b[0] & 7) << 1) | ((b[1] >> 4) & 1),
+            (b[1] & 8) | ((b[1] & 3) << 1) | (b[2] >> 7),
+        ]
+        .map(|c| extend(c, 4));
+        let c2 = [
+            (b[2] >> 3) & 15,
+            ((b[2] & 7) << 1) | (b[3] >> 7),
+            (b[3] >> 3) & 15,
+        ]
+        .map(|c| extend(c, 4));
+        let value = |c: [i32; 3]| (c[0] << 16) | (c[1] << 8) | c[2];
+        let d = ETC2_DISTANCES
+            [usize::from((b[3] & 4) | ((b[3] & 1) << 1)) | usize::from(value(c1) >= value(c2))];
+        paint(
+            [offset(c1, d), offset(c1, -d), offset(c2, d), offset(c2, -d)],
+            &mut write,
+        );
+    } else if !(0..32).contains(&second[2]) {
+        // planar mode, which is always opaque
+        let o = [
+            extend((b[0] >> 1) & 63, 6),
+            extend(((b[0] & 1) << 6) | ((b[1] & 0x7e) >> 1), 7),
+            extend(
+                ((b[1] & 1) << 5) | (b[2] & 0x18) | ((b[2] & 3) << 1) | (b[3] >> 7),
+                6,
+            ),
+        ];
+        let h = [
+            extend(((b[3] & 0x7c) >> 1) | (b[3] & 1), 6),
+            extend(b[4] >> 1, 7),
+            extend(((b[4] & 1) << 5) | (b[5] >> 3), 6),
+        ];
+        let v = [
+            extend(((b[5] & 7) << 3) | (b[6] >> 5), 6),
+            extend(((b[6] & 0x1f) << 2) | (b[7] >> 6), 7),
+            extend(b[7] & 0x3f, 6),
+        ];
+        for y in 0..4 {
+            for x in 0..4 {
+                let [x_, y_] = [x, y].map(|v| v as i32);
+                let rgb = [0, 1, 2]
+                    .map(|c| (x_ * (h[c] - o[c]) + y_ * (v[c] - o[c]) + 4 * o[c] + 2) >> 2);
+                write(x, y, Some(rgb));
+            }
+        }
+    } else {
+        // differential mode
+        let colors = [
+            base.map(|c| extend(c, 5)),
+            second.map(|c| extend(c as u8, 5)),
+        ];
+        sub_blocks(colors, &mut write);
+    }
+}
+
+/// EAC modifier tables, indexed by table codeword and then by pixel index
+const EAC_MODIFIERS: [[i32; 8]; 16] = [
+    [-3, -6, -9, -15, 2, 5, 8, 14],
+    [-3, -7, -10, -13, 2, 6, 9, 12],
+    [-2, -5, -8, -13, 1, 4, 7, 12],
+    [-2, -4, -6, -13, 1, 3, 5, 12],
+    [-3, -6, -8, -12, 2, 5, 7, 11],
+    [-3, -7, -9, -11, 2, 6, 8, 10],
+    [-4, -7, -8, -11, 3, 6, 7, 10],
+    [-3, -5, -8, -11, 2, 4, 7, 10],
+    [-2, -6, -8, -10, 1, 5, 7, 9],
+    [-2, -5, -8, -10, 1, 4, 7, 9],
+    [-2, -4, -8, -10, 1, 3, 7, 9],
+    [-2, -5, -7, -10, 1, 4, 6, 9],
+    [-3, -4, -7, -10, 2, 3, 6, 9],
+    [-1, -2, -3, -10, 0, 1, 2, 9],
+    [-4, -6, -8, -9, 3, 5, 7, 8],
+    [-3, -5, -7, -9, 2, 4, 6, 8],
+];
+
+/// Returns the multiplier and the modifier of each texel (in row-major order) of an EAC block
+fn eac_modifiers(b: &[u8]) -> (i32, [i32; 16]) {
+    let table = &EAC_MODIFIERS[usize::from(b[1] & 15)];
+    let indices = u64::from_be_bytes([0, 0, b[2], b[3], b[4], b[5], b[6], b[7]]);
+    let mut modifiers = [0; 16];
+    for (i, modifier) in modifiers.iter_mut().enumerate() {
+        // indices are stored in column-major order, starting at the most significant bits
+        let (x, y) = (i % 4, i / 4);
+        *modifier = table[((indices >> (45 - 3 * (x * 4 + y))) & 7) as usize];
+    }
+    (i32::from(b[1] >> 4), modifiers)
+}
+
+/// Decodes an 8-bit EAC alpha block into the alpha channel of RGBA8 texels
+fn eac_alpha8(b: &[u8], out: &mut [u8]) {
+    let base = i32::from(b[0]);
+    let (multiplier, modifiers) = eac_modifiers(b);
+    for (i, modifier) in modifiers.into_iter().enumerate() {
+        out[i * 4 + 3] = (base + modifier * multiplier).clamp(0, 255) as u8;
+    }
+}
+
+/// Decodes an 11-bit EAC block into channel `channel` of texels made up of `channels` 16-bit normalized channels
+fn eac_r11(b: &[u8], out: &mut [u8], channel: usize, channels: usize, signed: bool) {
+    let (multiplier, modifiers) = eac_modifiers(b);
+    for (i, modifier) in modifiers.into_iter().enumerate() {
+        // a multiplier of 0 uses the modifiers unscaled
+        let modifier = if multiplier == 0 {
+            modifier
+        } else {
+            modifier * multiplier * 8
+        };
+        let value = if signed {
+            let base = i32::from(b[0].cast_signed()).max(-127);
+            let v = (base * 8 + modifier).clamp(-1023, 1023);
+            // replicate the high bits of the magnitude to expand it to 15 bits
+            let magnitude = (v.abs() << 5) | (v.abs() >> 5);
+            (if v < 0 { -magnitude } else { magnitude }) as i16 as u16
+        } else {
+            let v = (i32::from(b[0]) * 8 + 4 + modifier).clamp(0, 2047);
+            ((v << 5) | (v >> 6)) as u16
+        };
+        out[(i * channels + channel) * 2..][..2].copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Decodes a BC1 (S3TC DXT1) block, or the color half of a BC2 or BC3 block, into RGBA8 texels with an alpha of 255. BC1 blocks
+/// whose first endpoint is not greater than the second have 3 colors and a fourth one that is black with an alpha of `black_alpha`;
+/// the color halves of BC2 and BC3 blocks (`black_alpha` of None) always have 4 colors
+fn bc1(b: &[u8], out: &mut [u8], black_alpha: Option<u8>) {
+    let c0 = u16::from_le_bytes([b[0], b[1]]);
+    let c1 = u16::from_le_bytes([b[2], b[3]]);
+    let rgb = |c: u16| {
+        [
+            extend((c >> 11) as u8, 5),
+            extend(((c >> 5) & 0x3f) as u8, 6),
+            extend((c & 0x1f) as u8, 5),
+        ]
+    };
+    let (e0, e1) = (rgb(c0), rgb(c1));
+    let mix = |w0: i32, w1: i32| {
+        let div = w0 + w1;
+        let c = |i: usize| (e0[i] * w0 + e1[i] * w1 + div / 2) / div;
+        [c(0) as u8, c(1) as u8, c(2) as u8, 255]
+    };
+    let palette = match black_alpha {
+        Some(alpha) if c0 <= c1 => [mix(1, 0), mix(0, 1), mix(1, 1), [0, 0, 0, alpha]],
+        _ => [mix(1, 0), mix(0, 1), mix(2, 1), mix(1, 2)],
+    };
+    let indices = u32::from_le_bytes([b[4], b[5], b[6], b[7]]);
+    for (i, texel) in out.chunks_exact_mut(4).enumerate() {
+        texel.copy_from_slice(&palette[((indices >> (2 * i)) & 3) as usize]);
+    }
+}
+
+/// Decodes the explicit 4-bit alpha half of a BC2 (S3TC DXT3) block into the alpha channel of RGBA8 texels
+fn bc2_alpha(b: &[u8], out: &mut [u8]) {
+    let alphas = u64::from_le_bytes(b[..8].try_into().expect("BC2 alpha blocks are 8 bytes"));
+    for (i, texel) in out.chunks_exact_mut(4).enumerate() {
+        texel[3] = ((alphas >> (4 * i)) & 0xf) as u8 * 17;
+    }
+}
+
+/// Decodes a BC4 (RGTC1) block into channel `channel` of texels made up of `channels` 8-bit normalized channels
+fn bc4(b: &[u8], out: &mut [u8], channel: usize, channels: usize, signed: bool) {
+    let (r0, r1) = if signed {
+        // -128 is treated as -127
+        (
+            i32::from(b[0].cast_signed()).max(-127),
+            i32::from(b[1].cast_signed()).max(-127),
+        )
+    } else {
+        (i32::from(b[0]), i32::from(b[1]))
+    };
+    // weighted average of the endpoints, rounded to nearest
+    let lerp = |k: i32, n: i32| (2 * ((n - k) * r0 + k * r1) + n).div_euclid(2 * n);
+    let mut palette = [r0, r1, 0, 0, 0, 0, 0, 0];
+    if r0 > r1 {
+        for k in 1..7 {
+            palette[k as usize + 1] = lerp(k, 7);
+        }
+    } else {
+        for k in 1..5 {
+            palette[k as usize + 1] = lerp(k, 5);
+        }
+        palette[6] = if signed { -127 } else { 0 };
+        palette[7] = if signed { 127 } else { 255 };
+    }
+    let indices = u64::from_le_bytes([b[2], b[3], b[4], b[5], b[6], b[7], 0, 0]);
+    for i in 0..16 {
+        // stored as the two's complement bit pattern for signed formats
+        out[i * channels + channel] = palette[((indices >> (3 * i)) & 7) as usize] as u8;
+    }
+}
+
+/// Reads bit fields from a 128-bit block, starting at its least significant bit
+struct BlockBits {
+    bits: u128,
+    pos: u32,
+}
+impl BlockBits {
+    fn new(block: &[u8]) -> Self {
+        Self {
+            bits: u128::from_le_bytes(block.try_into().expect("blocks should be 16 bytes long")),
+            pos: 0,
+        }
+    }
+    fn read(&mut self, count: u32) -> u32 {
+        let v = (self.bits >> self.pos) & ((1 << count) - 1);
+        self.pos += count;
+        v as u32
+    }
+}
+
+/// Subset of each texel (bit `i` for texel `i`, in row-major order) for the 2-subset BPTC partitions
+const PARTITIONS_2: [u16; 64] = [
+    0xcccc, 0x8888, 0xeeee, 0xecc8, 0xc880, 0xfeec, 0xfec8, 0xec80, 0xc800, 0xffec, 0xfe80, 0xe800,
+    0xffe8, 0xff00, 0xfff0, 0xf000, 0xf710, 0x008e, 0x7100, 0x08ce, 0x008c, 0x7310, 0x3100, 0x8cce,
+    0x088c, 0x3110, 0x6666, 0x366c, 0x17e8, 0x0ff0, 0x718e, 0x399c, 0xaaaa, 0xf0f0, 0x5a5a, 0x33cc,
+    0x3c3c, 0x55aa, 0x9696, 0xa55a, 0x73ce, 0x13c8, 0x324c, 0x3bdc, 0x6996, 0xc33c, 0x9966, 0x0660,
+    0x0272, 0x04e4, 0x4e40, 0x2720, 0xc936, 0x936c, 0x39c6, 0x639c, 0x9336, 0x9cc6, 0x817e, 0xe718,
+    0xccf0, 0x0fcc, 0x7744, 0xee22,
+];
+/// Subset of each texel (in row-major order) for the 3-subset BPTC partitions
+const PARTITIONS_3: [[u8; 16]; 64] = [
+    [0, 0, 1, 1, 0, 0, 1, 1, 0, 2, 2, 1, 2, 2, 2, 2],
+    [0, 0, 0, 1, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2, 2, 1],
+    [0, 0, 0, 0, 2, 0, 0, 1, 2, 2, 1, 1, 2, 2, 1, 1],
+    [0, 2, 2, 2, 0, 0, 2, 2, 0, 0, 1, 1, 0, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2],
+    [0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 2, 2],
+    [0, 0, 2, 2, 0, 0, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 1, 1, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2],
+    [0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2],
+    [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2],
+    [0, 1, 1, 2, 0, 1, 1, 2, 0, 1, 1, 2, 0, 1, 1, 2],
+    [0, 1, 2, 2, 0, 1, 2, 2, 0, 1, 2, 2, 0, 1, 2, 2],
+    [0, 0, 1, 1, 0, 1, 1, 2, 1, 1, 2, 2, 1, 2, 2, 2],
+    [0, 0, 1, 1, 2, 0, 0, 1, 2, 2, 0, 0, 2, 2, 2, 0],
+    [0, 0, 0, 1, 0, 0, 1, 1, 0, 1, 1, 2, 1, 1, 2, 2],
+    [0, 1, 1, 1, 0, 0, 1, 1, 2, 0, 0, 1, 2, 2, 0, 0],
+    [0, 0, 0, 0, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 2, 2],
+    [0, 0, 2, 2, 0, 0, 2, 2, 0, 0, 2, 2, 1, 1, 1, 1],
+    [0, 1, 1, 1, 0, 1, 1, 1, 0, 2, 2, 2, 0, 2, 2, 2],
+    [0, 0, 0, 1, 0, 0, 0, 1, 2, 2, 2, 1, 2, 2, 2, 1],
+    [0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 2, 2, 0, 1, 2, 2],
+    [0, 0, 0, 0, 1, 1, 0, 0, 2, 2, 1, 0, 2, 2, 1, 0],
+    [0, 1, 2, 2, 0, 1, 2, 2, 0, 0, 1, 1, 0, 0, 0, 0],
+    [0, 0, 1, 2, 0, 0, 1, 2, 1, 1, 2, 2, 2, 2, 2, 2],
+    [0, 1, 1, 0, 1, 2, 2, 1, 1, 2, 2, 1, 0, 1, 1, 0],
+    [0, 0, 0, 0, 0, 1, 1, 0, 1, 2, 2, 1, 1, 2, 2, 1],
+    [0, 0, 2, 2, 1, 1, 0, 2, 1, 1, 0, 2, 0, 0, 2, 2],
+    [0, 1, 1, 0, 0, 1, 1, 0, 2, 0, 0, 2, 2, 2, 2, 2],
+    [0, 0, 1, 1, 0, 1, 2, 2, 0, 1, 2, 2, 0, 0, 1, 1],
+    [0, 0, 0, 0, 2, 0, 0, 0, 2, 2, 1, 1, 2, 2, 2, 1],
+    [0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 2, 2, 1, 2, 2, 2],
+    [0, 2, 2, 2, 0, 0, 2, 2, 0, 0, 1, 2, 0, 0, 1, 1],
+    [0, 0, 1, 1, 0, 0, 1, 2, 0, 0, 2, 2, 0, 2, 2, 2],
+    [0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0, 0, 1, 2, 0],
+    [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 0, 0, 0, 0],
+    [0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2, 0],
+    [0, 1, 2, 0, 2, 0, 1, 2, 1, 2, 0, 1, 0, 1, 2, 0],
+    [0, 0, 1, 1, 2, 2, 0, 0, 1, 1, 2, 2, 0, 0, 1, 1],
+    [0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 0, 0, 0, 0, 1, 1],
+    [0, 1, 0, 1, 0, 1, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 2, 1, 2, 1, 2, 1],
+    [0, 0, 2, 2, 1, 1, 2, 2, 0, 0, 2, 2, 1, 1, 2, 2],
+    [0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 2, 2, 0, 0, 1, 1],
+    [0, 2, 2, 0, 1, 2, 2, 1, 0, 2, 2, 0, 1, 2, 2, 1],
+    [0, 1, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0, 1, 0, 1],
+    [0, 0, 0, 0, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1],
+    [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 2, 2, 2, 2],
+    [0, 2, 2, 2, 0, 1, 1, 1, 0, 2, 2, 2, 0, 1, 1, 1],
+    [0, 0, 0, 2, 1, 1, 1, 2, 0, 0, 0, 2, 1, 1, 1, 2],
+    [0, 0, 0, 0, 2, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 2],
+    [0, 2, 2, 2, 0, 1, 1, 1, 0, 1, 1, 1, 0, 2, 2, 2],
+    [0, 0, 0, 2, 1, 1, 1, 2, 1, 1, 1, 2, 0, 0, 0, 2],
+    [0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 2, 2, 2, 2],
+    [0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 2, 2, 1, 1, 2],
+    [0, 1, 1, 0, 0, 1, 1, 0, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 0, 2, 2, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 2, 2],
+    [0, 0, 2, 2, 1, 1, 2, 2, 1, 1, 2, 2, 0, 0, 2, 2],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 2],
+    [0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1],
+    [0, 2, 2, 2, 1, 2, 2, 2, 0, 2, 2, 2, 1, 2, 2, 2],
+    [0, 1, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+    [0, 1, 1, 1, 2, 0, 1, 1, 2, 2, 0, 1, 2, 2, 2, 0],
+];
+/// Anchor texel of the second subset of each 2-subset partition. The first subset's anchor is always texel 0
+const ANCHORS_2: [u8; 64] = [
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 2, 8, 2, 2, 8, 8, 15, 2, 8,
+    2, 2, 8, 8, 2, 2, 15, 15, 6, 8, 2, 8, 15, 15, 2, 8, 2, 2, 2, 15, 15, 6, 6, 2, 6, 8, 15, 15, 2,
+    2, 15, 15, 15, 15, 15, 2, 2, 15,
+];
+/// Anchor texels of the second and third subsets of each 3-subset partition
+const ANCHORS_3: [[u8; 64]; 2] = [
+    [
+        3, 3, 15, 15, 8, 3, 15, 15, 8, 8, 6, 6, 6, 5, 3, 3, 3, 3, 8, 15, 3, 3, 6, 10, 5, 8, 8, 6,
+        8, 5, 15, 15, 8, 15, 3, 5, 6, 10, 8, 15, 15, 3, 15, 5, 15, 15, 15, 15, 3, 15, 5, 5, 5, 8,
+        5, 10, 5, 10, 8, 13, 15, 12, 3, 3,
+    ],
+    [
+        15, 8, 8, 3, 15, 15, 3, 8, 15, 15, 15, 15, 15, 15, 15, 8, 15, 8, 15, 3, 15, 8, 15, 8, 3,
+        15, 6, 10, 15, 15, 10, 8, 15, 3, 15, 10, 10, 8, 9, 10, 6, 15, 8, 15, 3, 6, 6, 8, 15, 3, 15,
+        15, 15, 15, 15, 15, 15, 15, 15, 15, 3, 15, 15, 8,
+    ],
+];
+/// BPTC interpolation weights for 2, 3 and 4-bit indices
+const WEIGHTS_2: [i32; 4] = [0, 21, 43, 64];
+const WEIGHTS_3: [i32; 8] = [0, 9, 18, 27, 37, 46, 55, 64];
+const WEIGHTS_4: [i32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+fn bptc_weight(bits: u32, index: u32) -> i32 {
+    match bits {
+        2 => WEIGHTS_2[index as usize],
+        3 => WEIGHTS_3[index as usize],
+        _ => WEIGHTS_4[index as usize],
+    }
+}
+fn bptc_interpolate(e0: i32, e1: i32, weight: i32) -> i32 {
+    ((64 - weight) * e0 + weight * e1 + 32) >> 6
+}
+/// Subset of texel `i` of a block with `subsets` subsets that uses partition `partition`
+fn bptc_subset(subsets: usize, partition: usize, i: usize) -> usize {
+    match subsets {
+        1 => 0,
+        2 => usize::from((PARTITIONS_2[partition] >> i) & 1 != 0),
+        _ => usize::from(PARTITIONS_3[partition][i]),
+    }
+}
+/// Whether texel `i` is the anchor texel of its subset, which has its index stored with one bit less
+fn bptc_is_anchor(subsets: usize, partition: usize, i: usize) -> bool {
+    let i = i as u8;
+    match subsets {
+        1 => i == 0,
+        2 => i == 0 || i == ANCHORS_2[partition],
+        _ => i == 0 || i == ANCHORS_3[0][partition] || i == ANCHORS_3[1][partition],
+    }
+}
+
+/// Describes the layout of a BC7 block mode
+struct Bc7Mode {
+    subsets: usize,
+    partition_bits: u32,
+    rotation_bits: u32,
+    index_selection_bits: u32,
+    color_bits: u32,
+    alpha_bits: u32,
+    /// Whether each endpoint has a unique P-bit
+    endpoint_pbits: bool,
+    /// Whether each subset has a P-bit shared by its endpoints
+    shared_pbits: bool,
+    index_bits: u32,
+    /// Bits per index of the secondary index set, or 0 if the mode only has one index set
+    index2_bits: u32,
+}
+#[rustfmt::skip]
+const BC7_MODES: [Bc7Mode; 8] = [
+    Bc7Mode { subsets: 3, partition_bits: 4, rotation_bits: 0, index_selection_bits: 0, color_bits: 4, alpha_bits: 0, endpoint_pbits: true, shared_pbits: false, index_bits: 3, index2_bits: 0 },
+    Bc7Mode { subsets: 2, partition_bits: 6, rotation_bits: 0, index_selection_bits: 0, color_bits: 6, alpha_bits: 0, endpoint_pbits: false, shared_pbits: true, index_bits: 3, index2_bits: 0 },
+    Bc7Mode { subsets: 3, partition_bits: 6, rotation_bits: 0, index_selection_bits: 0, color_bits: 5, alpha_bits: 0, endpoint_pbits: false, shared_pbits: false, index_bits: 2, index2_bits: 0 },
+    Bc7Mode { subsets: 2, partition_bits: 6, rotation_bits: 0, index_selection_bits: 0, color_bits: 7, alpha_bits: 0, endpoint_pbits: true, shared_pbits: false, index_bits: 2, index2_bits: 0 },
+    Bc7Mode { subsets: 1, partition_bits: 0, rotation_bits: 2, index_selection_bits: 1, color_bits: 5, alpha_bits: 6, endpoint_pbits: false, shared_pbits: false, index_bits: 2, index2_bits: 3 },
+    Bc7Mode { subsets: 1, partition_bits: 0, rotation_bits: 2, index_selection_bits: 0, color_bits: 7, alpha_bits: 8, endpoint_pbits: false, shared_pbits: false, index_bits: 2, index2_bits: 2 },
+    Bc7Mode { subsets: 1, partition_bits: 0, rotation_bits: 0, index_selection_bits: 0, color_bits: 7, alpha_bits: 7, endpoint_pbits: true, shared_pbits: false, index_bits: 4, index2_bits: 0 },
+    Bc7Mode { subsets: 2, partition_bits: 6, rotation_bits: 0, index_selection_bits: 0, color_bits: 5, alpha_bits: 5, endpoint_pbits: true, shared_pbits: false, index_bits: 2, index2_bits: 0 },
+];
+
+/// Decodes a BC7 (BPTC unorm) block into RGBA8 texels
+fn bc7(b: &[u8], out: &mut [u8]) {
+    // the mode is given by the position of the lowest set bit
+    let Some(mode_index) = (0..8).find(|&m| b[0] & (1 << m) != 0) else {
+        // reserved mode
+        out.fill(0);
+        return;
+    };
+    let mode = &BC7_MODES[mode_index as usize];
+    let mut bits = BlockBits::new(b);
+    bits.read(mode_index + 1);
+    let partition = bits.read(mode.partition_bits) as usize;
+    let rotation = bits.read(mode.rotation_bits);
+    let index_selection = bits.read(mode.index_selection_bits) != 0;
+
+    let endpoint_count = mode.subsets * 2;
+    let mut endpoints = [[0u32; 4]; 6];
+    for channel in 0..4 {
+        let channel_bits = if channel == 3 {
+            mode.alpha_bits
+        } else {
+            mode.color_bits
+        };
+        for endpoint in &mut endpoints[..endpoint_count] {
+            endpoint[channel] = bits.read(channel_bits);
+        }
+    }
+    let mut pbits = [None; 6];
+    if mode.endpoint_pbits {
+        for pbit in &mut pbits[..endpoint_count] {
+            *pbit = Some(bits.read(1));
+        }
+    } else if mode.shared_pbits {
+        for subset in pbits[..endpoint_count].chunks_mut(2) {
+            subset.fill(Some(bits.read(1)));
+        }
+    }
+    // expand the endpoints to 8 bits, appending their P-bits
+    let endpoints: [[i32; 4]; 6] = std::array::from_fn(|e| {
+        std::array::from_fn(|channel| {
+            let channel_bits = if channel == 3 {
+                mode.alpha_bits
+            } else {
+                mode.color_bits
+            };
+            if channel_bits == 0 {
+                // modes without alpha are opaque
+                return 255;
+            }
+            let (v, channel_bits) = match pbits[e] {
+                Some(pbit) => ((endpoints[e][channel] << 1) | pbit, channel_bits + 1),
+                None => (endpoints[e][channel], channel_bits),
+            };
+            ((v << (8 - channel_bits)) | (v >> (2 * channel_bits - 8))) as i32
+        })
+    });
+
+    let mut indices = [0; 16];
+    for (i, index) in indices.iter_mut().enumerate() {
+        *index = bits.read(mode.index_bits - u32::from(bptc_is_anchor(mode.subsets, partition, i)));
+    }
+    let mut indices2 = [0; 16];
+    if mode.index2_bits > 0 {
+        for (i, index) in indices2.iter_mut().enumerate() {
+            *index = bits.read(mode.index2_bits - u32::from(i == 0));
+        }
+    }
+    for i in 0..16 {
+        let subset = bptc_subset(mode.subsets, partition, i);
+        let (e0, e1) = (endpoints[subset * 2], endpoints[subset * 2 + 1]);
+        // modes with two index sets interpolate color and alpha separately, and may swap which set is used for which
+        let ((color_index, color_bits), (alpha_index, alpha_bits)) = match mode.index2_bits {
+            0 => ((indices[i], mode.index_bits), (indices[i], mode.index_bits)),
+            _ if index_selection => (
+                (indices2[i], mode.index2_bits),
+                (indices[i], mode.index_bits),
+            ),
+            _ => (
+                (indices[i], mode.index_bits),
+                (indices2[i], mode.index2_bits),
+            ),
+        };
+        let mut texel: [i32; 4] = std::array::from_fn(|c| {
+            let weight = if c == 3 {
+                bptc_weight(alpha_bits, alpha_index)
+            } else {
+                bptc_weight(color_bits, color_index)
+            };
+            bptc_interpolate(e0[c], e1[c], weight)
+        });
+        // rotation swaps alpha with one of the color channels
+        if rotation > 0 {
+            texel.swap(rotation as usize - 1, 3);
+        }
+        out[i * 4..][..4].copy_from_slice(&texel.map(|c| c as u8));
+    }
+}
+
+// endpoint components of BC6H blocks, named after the endpoint (w, x, y or z) and channel they belong to
+const RW: u8 = 0;
+const GW: u8 = 1;
+const BW: u8 = 2;
+const RX: u8 = 3;
+const GX: u8 = 4;
+const BX: u8 = 5;
+const RY: u8 = 6;
+const GY: u8 = 7;
+const BY: u8 = 8;
+const RZ: u8 = 9;
+const GZ: u8 = 10;
+const BZ: u8 = 11;
+
+/// Describes the layout of a BC6H block mode
+struct Bc6hMode {
+    /// Whether all endpoints but the first are stored as deltas from the first
+    transformed: bool,
+    /// Whether the block has 2 subsets (and a partition index) rather than 1
+    partitioned: bool,
+    endpoint_bits: u32,
+    /// Precision of the endpoints other than the first for each channel
+    delta_bits: [u32; 3],
+    /// The endpoint components stored in the block header following the mode bits, as runs of (component, first bit, bit count)
+    layout: &'static [(u8, u32, u32)],
+}
+
+/// Returns the layout of the BC6H mode with mode bits `mode`, or None if the mode is reserved
+fn bc6h_mode(mode: u32) -> Option<Bc6hMode> {
+    const fn two_subsets(
+        endpoint_bits: u32,
+        delta_bits: [u32; 3],
+        layout: &'static [(u8, u32, u32)],
+    ) -> Bc6hMode {
+        Bc6hMode {
+            transformed: true,
+            partitioned: true,
+            endpoint_bits,
+            delta_bits,
+            layout,
+        }
+    }
+    const fn one_subset(
+        endpoint_bits: u32,
+        delta_bits: u32,
+        layout: &'static [(u8, u32, u32)],
+    ) -> Bc6hMode {
+        Bc6hMode {
+            transformed: endpoint_bits != delta_bits,
+            partitioned: false,
+            endpoint_bits,
+            delta_bits: [delta_bits; 3],
+            layout,
+        }
+    }
+    #[rustfmt::skip]
+    let mode = match mode {
+        0x00 => two_subsets(10, [5, 5, 5], &[
+            (GY, 4, 1), (BY, 4, 1), (BZ, 4, 1), (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 5), (GZ, 4, 1), (GY, 0, 4),
+            (GX, 0, 5), (BZ, 0, 1), (GZ, 0, 4), (BX, 0, 5), (BZ, 1, 1), (BY, 0, 4), (RY, 0, 5), (BZ, 2, 1), (RZ, 0, 5),
+            (BZ, 3, 1),
+        ]),
+        0x01 => two_subsets(7, [6, 6, 6], &[
+            (GY, 5, 1), (GZ, 4, 1), (GZ, 5, 1), (RW, 0, 7), (BZ, 0, 1), (BZ, 1, 1), (BY, 4, 1), (GW, 0, 7), (BY, 5, 1),
+            (BZ, 2, 1), (GY, 4, 1), (BW, 0, 7), (BZ, 3, 1), (BZ, 5, 1), (BZ, 4, 1), (RX, 0, 6), (GY, 0, 4), (GX, 0, 6),
+            (GZ, 0, 4), (BX, 0, 6), (BY, 0, 4), (RY, 0, 6), (RZ, 0, 6),
+        ]),
+        0x02 => two_subsets(11, [5, 4, 4], &[
+            (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 5), (RW, 10, 1), (GY, 0, 4), (GX, 0, 4), (GW, 10, 1), (BZ, 0, 1),
+            (GZ, 0, 4), (BX, 0, 4), (BW, 10, 1), (BZ, 1, 1), (BY, 0, 4), (RY, 0, 5), (BZ, 2, 1), (RZ, 0, 5), (BZ, 3, 1),
+        ]),
+        0x06 => two_subsets(11, [4, 5, 4], &[
+            (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 4), (RW, 10, 1), (GZ, 4, 1), (GY, 0, 4), (GX, 0, 5), (GW, 10, 1),
+            (GZ, 0, 4), (BX, 0, 4), (BW, 10, 1), (BZ, 1, 1), (BY, 0, 4), (RY, 0, 4), (BZ, 0, 1), (BZ, 2, 1), (RZ, 0, 4),
+            (GY, 4, 1), (BZ, 3, 1),
+        ]),
+        0x0a => two_subsets(11, [4, 4, 5], &[
+            (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 4), (RW, 10, 1), (BY, 4, 1), (GY, 0, 4), (GX, 0, 4), (GW, 10, 1),
+            (BZ, 0, 1), (GZ, 0, 4), (BX, 0, 5), (BW, 10, 1), (BY, 0, 4), (RY, 0, 4), (BZ, 1, 1), (BZ, 2, 1), (RZ, 0, 4),
+            (BZ, 4, 1), (BZ, 3, 1),
+        ]),
+        0x0e => two_subsets(9, [5, 5, 5], &[
+            (RW, 0, 9), (BY, 4, 1), (GW, 0, 9), (GY, 4, 1), (BW, 0, 9), (BZ, 4, 1), (RX, 0, 5), (GZ, 4, 1), (GY, 0, 4),
+            (GX, 0, 5), (BZ, 0, 1), (GZ, 0, 4), (BX, 0, 5), (BZ, 1, 1), (BY, 0, 4), (RY, 0, 5), (BZ, 2, 1), (RZ, 0, 5),
+            (BZ, 3, 1),
+        ]),
+        0x12 => two_subsets(8, [6, 5, 5], &[
+            (RW, 0, 8), (GZ, 4, 1), (BY, 4, 1), (GW, 0, 8), (BZ, 2, 1), (GY, 4, 1), (BW, 0, 8), (BZ, 3, 1), (BZ, 4, 1),
+            (RX, 0, 6), (GY, 0, 4), (GX, 0, 5), (BZ, 0, 1), (GZ, 0, 4), (BX, 0, 5), (BZ, 1, 1), (BY, 0, 4), (RY, 0, 6),
+            (RZ, 0, 6),
+        ]),
+        0x16 => two_subsets(8, [5, 6, 5], &[
+            (RW, 0, 8), (BZ, 0, 1), (BY, 4, 1), (GW, 0, 8), (GY, 5, 1), (GY, 4, 1), (BW, 0, 8), (GZ, 5, 1), (BZ, 4, 1),
+            (RX, 0, 5), (GZ, 4, 1), (GY, 0, 4), (GX, 0, 6), (GZ, 0, 4), (BX, 0, 5), (BZ, 1, 1), (BY, 0, 4), (RY, 0, 5),
+            (BZ, 2, 1), (RZ, 0, 5), (BZ, 3, 1),
+        ]),
+        0x1a => two_subsets(8, [5, 5, 6], &[
+            (RW, 0, 8), (BZ, 1, 1), (BY, 4, 1), (GW, 0, 8), (BY, 5, 1), (GY, 4, 1), (BW, 0, 8), (BZ, 5, 1), (BZ, 4, 1),
+            (RX, 0, 5), (GZ, 4, 1), (GY, 0, 4), (GX, 0, 5), (BZ, 0, 1), (GZ, 0, 4), (BX, 0, 6), (BY, 0, 4), (RY, 0, 5),
+            (BZ, 2, 1), (RZ, 0, 5), (BZ, 3, 1),
+        ]),
+        0x1e => Bc6hMode {
+            transformed: false,
+            ..two_subsets(6, [6, 6, 6], &[
+                (RW, 0, 6), (GZ, 4, 1), (BZ, 0, 1), (BZ, 1, 1), (BY, 4, 1), (GW, 0, 6), (GY, 5, 1), (BY, 5, 1), (BZ, 2, 1),
+                (GY, 4, 1), (BW, 0, 6), (GZ, 5, 1), (BZ, 3, 1), (BZ, 5, 1), (BZ, 4, 1), (RX, 0, 6), (GY, 0, 4), (GX, 0, 6),
+                (GZ, 0, 4), (BX, 0, 6), (BY, 0, 4), (RY, 0, 6), (RZ, 0, 6),
+            ])
+        },
+        0x03 => one_subset(10, 10, &[(RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 10), (GX, 0, 10), (BX, 0, 10)]),
+        0x07 => one_subset(11, 9, &[
+            (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 9), (RW, 10, 1), (GX, 0, 9), (GW, 10, 1), (BX, 0, 9), (BW, 10, 1),
+        ]),
+        // the high bits of the first endpoint of the last two modes are stored in reverse order
+        0x0b => one_subset(12, 8, &[
+            (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 8), (RW, 11, 1), (RW, 10, 1), (GX, 0, 8), (GW, 11, 1), (GW, 10, 1),
+            (BX, 0, 8), (BW, 11, 1), (BW, 10, 1),
+        ]),
+        0x0f => one_subset(16, 4, &[
+            (RW, 0, 10), (GW, 0, 10), (BW, 0, 10), (RX, 0, 4), (RW, 15, 1), (RW, 14, 1), (RW, 13, 1), (RW, 12, 1), (RW, 11, 1),
+            (RW, 10, 1), (GX, 0, 4), (GW, 15, 1), (GW, 14, 1), (GW, 13, 1), (GW, 12, 1), (GW, 11, 1), (GW, 10, 1), (BX, 0, 4),
+            (BW, 15, 1), (BW, 14, 1), (BW, 13, 1), (BW, 12, 1), (BW, 11, 1), (BW, 10, 1),
+        ]),
+        _ => return None,
+    };
+    Some(mode)
+}
+
+/// Expands a `bits`-bit BC6H endpoint component to 16 bits (or 15 bits plus sign for signed formats)
+fn bc6h_unquantize(v: i32, bits: u32, signed: bool) -> i32 {
+    if signed {
+        if bits >= 16 {
+            return v;
+        }
+        let magnitude = v.abs();
+        let q = if magnitude == 0 {
+            0
+        } else if magnitude >= (1 << (bits - 1)) - 1 {
+            0x7fff
+        } else {
+            ((magnitude << 15) + 0x4000) >> (bits - 1)
+        };
+        if v < 0 { -q } else { q }
+    } else if bits >= 15 {
+        v
+    } else if v == 0 {
+        0
+    } else if v == (1 << bits) - 1 {
+        0xffff
+    } else {
+        ((v << 16) + 0x8000) >> bits
+    }
+}
+
+/// Decodes a BC6H (BPTC float) block into RGBA16F texels with an alpha of 1
+fn bc6h(b: &[u8], out: &mut [u8], signed: bool) {
+    const ONE: u16 = 0x3c00;
+    let mut bits = BlockBits::new(b);
+    // 2-bit mode numbers 0 and 1 are followed by the 5-bit mode numbers, whose two low bits are 2 or 3
+    let mut mode_bits = bits.read(2);
+    if mode_bits >= 2 {
+        mode_bits |= bits.read(3) << 2;
+    }
+    let Some(mode) = bc6h_mode(mode_bits) else {
+        // reserved modes decode to black
+        for texel in out.chunks_exact_mut(8) {
+            texel[..6].fill(0);
+            texel[6..].copy_from_slice(&ONE.to_le_bytes());
+        }
+        return;
+    };
+    let mut components = [0i32; 12];
+    for &(component, first_bit, count) in mode.layout {
+        components[component as usize] |= (bits.read(count) << first_bit) as i32;
+    }
+    let partition = if mode.partitioned {
+        bits.read(5) as usize
+    } else {
+        0
+    };
+    let endpoint_count = if mode.partitioned { 4 } else { 2 };
+    let mut endpoints = [[0i32; 3]; 4];
+    for (e, endpoint) in endpoints[..endpoint_count].iter_mut().enumerate() {
+        for (c, v) in endpoint.iter_mut().enumerate() {
+            *v = components[e * 3 + c];
+        }
+    }
+    let mask = (1 << mode.endpoint_bits) - 1;
+    let base = endpoints[0];
+    for (e, endpoint) in endpoints[..endpoint_count].iter_mut().enumerate() {
+        for (c, v) in endpoint.iter_mut().enumerate() {
+            if e == 0 {
+                if signed {
+                    *v = sign_extend(*v, mode.endpoint_bits);
+                }
+                continue;
+            }
+            // deltas are always signed
+            if signed || mode.transformed {
+                *v = sign_extend(*v, mode.delta_bits[c]);
+            }
+            if mode.transformed {
+                let base = if signed {
+                    sign_extend(base[c], mode.endpoint_bits)
+                } else {
+                    base[c]
+                };
+                *v = (base + *v) & mask;
+                if signed {
+                    *v = sign_extend(*v, mode.endpoint_bits);
+                }
+            }
+        }
+    }
+    let endpoints = endpoints.map(|e| e.map(|v| bc6h_unquantize(v, mode.endpoint_bits, signed)));
+
+    let subsets = if mode.partitioned { 2 } else { 1 };
+    let index_bits = if mode.partitioned { 3 } else { 4 };
+    for i in 0..16 {
+        let index = bits.read(index_bits - u32::from(bptc_is_anchor(subsets, partition, i)));
+        let subset = bptc_subset(subsets, partition, i);
+        let weight = bptc_weight(index_bits, index);
+        let rgb = [0, 1, 2].map(|c| {
+            let v = bptc_interpolate(
+                endpoints[subset * 2][c],
+                endpoints[subset * 2 + 1][c],
+                weight,
+            );
+            // scale the interpolated value to the range of finite half floats and reinterpret it as one
+            if signed {
+                let magnitude = ((v.abs() * 31) >> 5) as u16;
+                if v < 0 { 0x8000 | magnitude } else { magnitude }
+            } else {
+                ((v * 31) >> 6) as u16
+            }
+        });
+        for (c, v) in rgb.into_iter().chain([ONE]).enumerate() {
+            out[i * 8 + c * 2..][..2].copy_from_slice(&v.to_le_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::GL_SIGNED_NORMALIZED;
+
+    // Expected texels are derived from the decoding rules of the ETC2/EAC (OpenGL 4.6 appendix C), S3TC, RGTC and BPTC specifications,
+    // except for those of `matches_reference_decoder`
+
+    fn decode(format: InternalFormat, block: &[u8]) -> Vec<u8> {
+        decompress(format, block, 4, 4, 1).expect("format should be block-compressed")
+    }
+    fn rgba8(format: InternalFormat, block: &[u8]) -> Vec<[u8; 4]> {
+        decode(format, block)
+            .chunks_exact(4)
+            .map(|t| t.try_into().unwrap())
+            .collect()
+    }
+    fn u16s(format: InternalFormat, block: &[u8]) -> Vec<u16> {
+        decode(format, block)
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect()
+    }
+    /// Texels of a block whose rows are each filled with a single color
+    fn uniform_rows(rows: [[u8; 4]; 4]) -> Vec<[u8; 4]> {
+        rows.iter().flat_map(|&row| [row; 4]).collect()
+    }
+
+    // ETC2 blocks whose pixel indices are equal to the texel's column or row
+    const COLUMN_INDICES: [u8; 4] = [0xff, 0x00, 0xf0, 0xf0];
+    const ROW_INDICES: [u8; 4] = [0xcc, 0xcc, 0xaa, 0xaa];
+    fn etc2_block(header: [u8; 4], indices: [u8; 4]) -> [u8; 8] {
+        let mut block = [0; 8];
+        block[..4].copy_from_slice(&header);
+        block[4..].copy_from_slice(&indices);
+        block
+    }
+    /// A planar mode block with a red gradient along x, a green gradient along y and a blue gradient against y
+    const PLANAR: [u8; 8] = [0x00, 0x01, 0xfb, 0xff, 0x01, 0xf8, 0x1f, 0xc0];
+    fn planar_texels() -> Vec<[u8; 4]> {
+        const RAMP: [u8; 4] = [0, 64, 128, 191];
+        const BLUE: [u8; 4] = [255, 191, 128, 64];
+        (0..16)
+            .map(|i| [RAMP[i % 4], RAMP[i / 4], BLUE[i / 4], 255])
+            .collect()
+    }
+
+    #[test]
+    fn etc2_individual() {
+        // base colors (8, 2, 15) and (4, 12, 0) with tables 0 and 7, split into left and right halves
+        let block = etc2_block([0x84, 0x2c, 0xf0, 0x1c], COLUMN_INDICES);
+        let row = [
+            [138, 36, 255, 255],
+            [144, 42, 255, 255],
+            [21, 157, 0, 255],
+            [0, 21, 0, 255],
+        ];
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgb8Etc2, &block),
+            row.repeat(4)
+        );
+    }
+
+    #[test]
+    fn etc2_differential() {
+        // base colors (10, 31, 0) and (10 + 3, 31 - 4, 0 + 1) with tables 1 and 3, flipped into top and bottom halves
+        let block = etc2_block([0x53, 0xfc, 0x01, 0x2f], ROW_INDICES);
+        let expected = uniform_rows([
+            [87, 255, 5, 255],
+            [99, 255, 17, 255],
+            [94, 209, 0, 255],
+            [65, 180, 0, 255],
+        ]);
+        assert_eq!(rgba8(InternalFormat::CompressedRgb8Etc2, &block), expected);
+        // sRGB blocks decode to the same encoded values
+        assert_eq!(rgba8(InternalFormat::CompressedSrgb8Etc2, &block), expected);
+    }
+
+    #[test]
+    fn etc2_t_mode() {
+        // red overflows; colors (13, 4, 8) and (6, 9, 2) with distance 32
+        let block = etc2_block([0xf9, 0x48, 0x69, 0x2b], COLUMN_INDICES);
+        let row = [
+            [221, 68, 136, 255],
+            [134, 185, 66, 255],
+            [102, 153, 34, 255],
+            [70, 121, 2, 255],
+        ];
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgb8Etc2, &block),
+            row.repeat(4)
+        );
+    }
+
+    #[test]
+    fn etc2_h_mode() {
+        // green overflows; colors (9, 6, 7) and (2, 10, 12), where the first color being larger selects distance 32
+        let block = etc2_block([0x4b, 0x07, 0x95, 0x66], COLUMN_INDICES);
+        let row = [
+            [185, 134, 151, 255],
+            [121, 70, 87, 255],
+            [66, 202, 236, 255],
+            [2, 138, 172, 255],
+        ];
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgb8Etc2, &block),
+            row.repeat(4)
+        );
+    }
+
+    #[test]
+    fn etc2_planar_mode() {
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgb8Etc2, &PLANAR),
+            planar_texels()
+        );
+    }
+
+    #[test]
+    fn etc2_punchthrough() {
+        let format = InternalFormat::CompressedRgb8PunchthroughAlpha1Etc2;
+        // opaque blocks decode like ETC2 RGB8 blocks
+        let opaque = etc2_block([0x53, 0xfc, 0x01, 0x2f], ROW_INDICES);
+        assert_eq!(
+            rgba8(format, &opaque),
+            rgba8(InternalFormat::CompressedRgb8Etc2, &opaque)
+        );
+        // non-opaque differential blocks have no small modifiers and make index 2 transparent black
+        let differential = etc2_block([0x53, 0xfc, 0x01, 0x2d], ROW_INDICES);
+        let expected = uniform_rows([
+            [82, 255, 0, 255],
+            [99, 255, 17, 255],
+            [0, 0, 0, 0],
+            [65, 180, 0, 255],
+        ]);
+        assert_eq!(rgba8(format, &differential), expected);
+        assert_eq!(
+            rgba8(
+                InternalFormat::CompressedSrgb8PunchthroughAlpha1Etc2,
+                &differential
+            ),
+            expected
+        );
+        // so do the paint colors of non-opaque T and H mode blocks
+        let t_mode = etc2_block([0xf9, 0x48, 0x69, 0x29], COLUMN_INDICES);
+        let row = [
+            [221, 68, 136, 255],
+            [134, 185, 66, 255],
+            [0, 0, 0, 0],
+            [70, 121, 2, 255],
+        ];
+        assert_eq!(rgba8(format, &t_mode), row.repeat(4));
+        // planar blocks are always opaque
+        let mut planar = PLANAR;
+        planar[3] &= !2;
+        assert_eq!(rgba8(format, &planar), planar_texels());
+    }
+
+    /// EAC index bits selecting each of the 8 modifiers of a table in turn, in column-major order
+    const EAC_INDICES: [u8; 6] = [0x05, 0x39, 0x77, 0x05, 0x39, 0x77];
+    fn eac_block(base: u8, multiplier: u8, table: u8) -> [u8; 8] {
+        let mut block = [base, (multiplier << 4) | table, 0, 0, 0, 0, 0, 0];
+        block[2..].copy_from_slice(&EAC_INDICES);
+        block
+    }
+    /// Lays out the values decoded from the 8 modifiers of an [`EAC_INDICES`] block in row-major order
+    fn eac_texels<T: Copy>(values: [T; 8]) -> Vec<T> {
+        (0..16).map(|i| values[(i % 2) * 4 + i / 4]).collect()
+    }
+
+    #[test]
+    fn etc2_eac_alpha() {
+        let mut block = [0; 16];
+        block[..8].copy_from_slice(&eac_block(200, 10, 13));
+        block[8..].copy_from_slice(&PLANAR);
+        let alpha = eac_texels([190, 180, 170, 100, 200, 210, 220, 255]);
+        let expected: Vec<_> = planar_texels()
+            .into_iter()
+            .zip(alpha)
+            .map(|([r, g, b, _], a)| [r, g, b, a])
+            .collect();
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgba8Etc2Eac, &block),
+            expected
+        );
+        assert_eq!(
+            rgba8(InternalFormat::CompressedSrgb8Alpha8Etc2Eac, &block),
+            expected
+        );
+    }
+
+    #[test]
+    fn eac_r11() {
+        let blocks = [
+            (
+                eac_block(100, 2, 13),
+                [
+                    0x628c, 0x608c, 0x5e8b, 0x508a, 0x648c, 0x668c, 0x688d, 0x768e,
+                ],
+            ),
+            // a multiplier of 0 uses the modifiers unscaled
+            (
+                eac_block(100, 0, 13),
+                [
+                    0x646c, 0x644c, 0x642c, 0x634c, 0x648c, 0x64ac, 0x64cc, 0x65ac,
+                ],
+            ),
+            (
+                eac_block(255, 1, 13),
+                [
+                    0xfe9f, 0xfd9f, 0xfc9f, 0xf59e, 0xff9f, 0xffff, 0xffff, 0xffff,
+                ],
+            ),
+            (
+                eac_block(0, 15, 13),
+                [
+                    0x0000, 0x0000, 0x0000, 0x0000, 0x0080, 0x0f81, 0x1e83, 0x8790,
+                ],
+            ),
+        ];
+        for (block, values) in blocks {
+            assert_eq!(
+                u16s(InternalFormat::CompressedR11Eac, &block),
+                eac_texels(values)
+            );
+        }
+        let mut rg = [0; 16];
+        rg[..8].copy_from_slice(&blocks[0].0);
+        rg[8..].copy_from_slice(&blocks[1].0);
+        let expected: Vec<_> = eac_texels(blocks[0].1)
+            .into_iter()
+            .zip(eac_texels(blocks[1].1))
+            .flat_map(|(r, g)| [r, g])
+            .collect();
+        assert_eq!(u16s(InternalFormat::CompressedRg11Eac, &rg), expected);
+    }
+
+    #[test]
+    fn eac_signed_r11() {
+        let blocks = [
+            // a base of -128 is treated as -127
+            (
+                eac_block(0x80, 1, 13),
+                [
+                    0x8001, 0x8001, 0x8001, 0x8001, 0x80e1, 0x81e1, 0x82e1, 0x89e3,
+                ],
+            ),
+            (
+                eac_block(100, 2, 13),
+                [
+                    0x6218, 0x6018, 0x5e17, 0x5014, 0x6419, 0x6619, 0x681a, 0x761d,
+                ],
+            ),
+            (
+                eac_block(-5i8 as u8, 0, 13),
+                [
+                    0xfadf, 0xfabf, 0xfa9f, 0xf9bf, 0xfaff, 0xfb1f, 0xfb3f, 0xfc20,
+                ],
+            ),
+        ];
+        for (block, values) in blocks {
+            assert_eq!(
+                u16s(InternalFormat::CompressedSignedR11Eac, &block),
+                eac_texels(values)
+            );
+        }
+        let mut rg = [0; 16];
+        rg[..8].copy_from_slice(&blocks[0].0);
+        rg[8..].copy_from_slice(&blocks[2].0);
+        let expected: Vec<_> = eac_texels(blocks[0].1)
+            .into_iter()
+            .zip(eac_texels(blocks[2].1))
+            .flat_map(|(r, g)| [r, g])
+            .collect();
+        assert_eq!(u16s(InternalFormat::CompressedSignedRg11Eac, &rg), expected);
+    }
+
+    /// RGTC index bits selecting each of the 8 palette entries in turn
+    const RGTC_INDICES: [u8; 6] = [0x88, 0xc6, 0xfa, 0x88, 0xc6, 0xfa];
+    fn rgtc_block(r0: u8, r1: u8) -> [u8; 8] {
+        let mut block = [r0, r1, 0, 0, 0, 0, 0, 0];
+        block[2..].copy_from_slice(&RGTC_INDICES);
+        block
+    }
+    fn rgtc_texels(palette: [i32; 8]) -> Vec<u8> {
+        (0..16).map(|i| palette[i % 8] as u8).collect()
+    }
+
+    #[test]
+    fn rgtc1() {
+        let format = InternalFormat::CompressedRedRgtc1;
+        // 6 interpolated values
+        let block = rgtc_block(70, 0);
+        assert_eq!(
+            decode(format, &block),
+            rgtc_texels([70, 0, 60, 50, 40, 30, 20, 10])
+        );
+        // 4 interpolated values, 0 and 1
+        let block = rgtc_block(0, 100);
+        assert_eq!(
+            decode(format, &block),
+            rgtc_texels([0, 100, 20, 40, 60, 80, 0, 255])
+        );
+    }
+
+    #[test]
+    fn signed_rgtc1() {
+        let format = InternalFormat::CompressedSignedRedRgtc1;
+        let block = rgtc_block(70, -70i8 as u8);
+        assert_eq!(
+            decode(format, &block),
+            rgtc_texels([70, -70, 50, 30, 10, -10, -30, -50])
+        );
+        let block = rgtc_block(-100i8 as u8, 100);
+        assert_eq!(
+            decode(format, &block),
+            rgtc_texels([-100, 100, -60, -20, 20, 60, -127, 127])
+        );
+        // -128 is treated as -127
+        let block = [0x80, 0x80, 0, 0, 0, 0, 0, 0];
+        assert_eq!(decode(format, &block), [(-127i8) as u8; 16]);
+    }
+
+    #[test]
+    fn rgtc2() {
+        let mut block = [0; 16];
+        block[..8].copy_from_slice(&rgtc_block(70, 0));
+        block[8..].copy_from_slice(&rgtc_block(0, 100));
+        let expected: Vec<_> = rgtc_texels([70, 0, 60, 50, 40, 30, 20, 10])
+            .into_iter()
+            .zip(rgtc_texels([0, 100, 20, 40, 60, 80, 0, 255]))
+            .flat_map(|(r, g)| [r, g])
+            .collect();
+        assert_eq!(decode(InternalFormat::CompressedRgRgtc2, &block), expected);
+
+        block[..8].copy_from_slice(&rgtc_block(70, -70i8 as u8));
+        block[8..].copy_from_slice(&rgtc_block(-100i8 as u8, 100));
+        let expected: Vec<_> = rgtc_texels([70, -70, 50, 30, 10, -10, -30, -50])
+            .into_iter()
+            .zip(rgtc_texels([-100, 100, -60, -20, 20, 60, -127, 127]))
+            .flat_map(|(r, g)| [r, g])
+            .collect();
+        assert_eq!(
+            decode(InternalFormat::CompressedSignedRgRgtc2, &block),
+            expected
+        );
+    }
+
+    // BPTC blocks with random endpoints, P-bits and indices
+    #[rustfmt::skip]
+    const BC7_BLOCKS: [([u8; 16], [[u8; 4]; 16]); 9] = [
+        // mode 0, partition 0
+        ([0x81, 0x31, 0x8d, 0xa8, 0xff, 0x69, 0x32, 0x78, 0x13, 0xc7, 0x76, 0x9f, 0x40, 0x8c, 0x77, 0x4d], [
+            [182, 226, 69, 255], [174, 231, 96, 255], [99, 66, 148, 255], [132, 175, 172, 255],
+            [164, 238, 126, 255], [198, 214, 16, 255], [156, 255, 189, 255], [148, 228, 183, 255],
+            [148, 249, 179, 255], [74, 57, 140, 255], [74, 156, 57, 255], [115, 119, 160, 255],
+            [74, 99, 105, 255], [74, 128, 80, 255], [74, 71, 128, 255], [74, 71, 128, 255],
+        ]),
+        // mode 1, partition 17
+        ([0x46, 0x44, 0xe0, 0x13, 0xb6, 0x36, 0xfa, 0xfd, 0x4e, 0xa5, 0x88, 0x55, 0x9b, 0xbc, 0x58, 0x7d], [
+            [13, 185, 243, 255], [249, 141, 80, 255], [151, 187, 116, 255], [183, 171, 104, 255],
+            [7, 136, 239, 255], [6, 120, 238, 255], [6, 120, 238, 255], [114, 203, 129, 255],
+            [9, 152, 240, 255], [4, 104, 237, 255], [13, 185, 243, 255], [9, 152, 240, 255],
+            [7, 136, 239, 255], [13, 185, 243, 255], [4, 104, 237, 255], [11, 169, 242, 255],
+        ]),
+        // mode 2, partition 20
+        ([0xa4, 0x12, 0xd5, 0xc6, 0x37, 0xa8, 0x34, 0xe2, 0xcc, 0x04, 0x42, 0x35, 0xed, 0x9e, 0x22, 0xc8], [
+            [104, 116, 36, 255], [103, 115, 122, 255], [49, 66, 165, 255], [214, 214, 33, 255],
+            [165, 82, 8, 255], [49, 66, 165, 255], [214, 214, 33, 255], [160, 165, 76, 255],
+            [104, 116, 36, 255], [247, 231, 214, 255], [201, 188, 198, 255], [247, 231, 214, 255],
+            [74, 132, 49, 255], [201, 188, 198, 255], [153, 142, 181, 255], [201, 188, 198, 255],
+        ]),
+        // mode 3, partition 34
+        ([0x28, 0x2a, 0x83, 0x60, 0xfb, 0x81, 0x21, 0x0a, 0xd4, 0xca, 0x14, 0x1f, 0x66, 0x32, 0xb2, 0xa9], [
+            [142, 17, 138, 255], [192, 68, 40, 255], [130, 24, 202, 255], [192, 68, 40, 255],
+            [207, 47, 68, 255], [136, 21, 171, 255], [207, 47, 68, 255], [148, 14, 106, 255],
+            [136, 21, 171, 255], [192, 68, 40, 255], [130, 24, 202, 255], [222, 24, 97, 255],
+            [207, 47, 68, 255], [136, 21, 171, 255], [222, 24, 97, 255], [136, 21, 171, 255],
+        ]),
+        // mode 4
+        ([0x10, 0xbd, 0x09, 0xf4, 0x2c, 0x39, 0x26, 0x9a, 0x44, 0x63, 0xec, 0x44, 0x91, 0xd7, 0xee, 0x68], [
+            [196, 32, 142, 145], [239, 16, 123, 143], [196, 32, 142, 144], [239, 16, 123, 145],
+            [196, 32, 142, 144], [107, 66, 181, 145], [239, 16, 123, 144], [196, 32, 142, 144],
+            [150, 50, 162, 142], [239, 16, 123, 145], [150, 50, 162, 144], [150, 50, 162, 142],
+            [196, 32, 142, 143], [239, 16, 123, 145], [107, 66, 181, 145], [239, 16, 123, 144],
+        ]),
+        // mode 4, green rotated into alpha, 3-bit color indices
+        ([0xd0, 0xc7, 0x05, 0x14, 0xcd, 0xb7, 0x04, 0x8a, 0x6e, 0x69, 0xd5, 0x9a, 0x38, 0x2d, 0xf1, 0x38], [
+            [73, 98, 114, 24], [73, 125, 114, 24], [81, 125, 102, 32], [99, 125, 75, 50],
+            [65, 98, 127, 16], [65, 98, 127, 16], [107, 125, 62, 58], [65, 98, 127, 16],
+            [99, 44, 75, 50], [99, 98, 75, 50], [91, 44, 87, 42], [57, 71, 140, 8],
+            [115, 125, 49, 66], [65, 98, 127, 16], [107, 44, 62, 58], [65, 71, 127, 16],
+        ]),
+        // mode 5, red rotated into alpha
+        ([0x60, 0xbe, 0x60, 0xaa, 0xec, 0xb3, 0xdb, 0xbd, 0xde, 0x01, 0x39, 0xf5, 0xdc, 0x00, 0xcd, 0x27], [
+            [118, 122, 161, 126], [175, 203, 237, 131], [137, 163, 200, 129], [175, 203, 237, 131],
+            [118, 82, 124, 124], [118, 82, 124, 124], [118, 82, 124, 124], [118, 163, 200, 129],
+            [137, 82, 124, 124], [175, 203, 237, 131], [118, 122, 161, 126], [175, 163, 200, 129],
+            [175, 163, 200, 129], [137, 163, 200, 129], [156, 203, 237, 131], [118, 122, 161, 126],
+        ]),
+        // mode 6
+        ([0x40, 0xa7, 0x0e, 0xb9, 0x3f, 0xdd, 0x87, 0x55, 0x44, 0x1c, 0x07, 0x5d, 0x94, 0x14, 0x93, 0x04], [
+            [150, 158, 101, 139], [145, 171, 121, 144], [124, 225, 206, 163], [154, 150, 88, 136],
+            [137, 192, 153, 151], [156, 144, 78, 134], [122, 232, 216, 165], [143, 177, 131, 146],
+            [145, 171, 121, 144], [132, 205, 173, 155], [145, 171, 121, 144], [154, 150, 88, 136],
+            [148, 165, 111, 141], [132, 205, 173, 155], [145, 171, 121, 144], [156, 144, 78, 134],
+        ]),
+        // mode 7, partition 13
+        ([0x80, 0xcd, 0x41, 0x2d, 0x4b, 0xaa, 0x49, 0x37, 0x98, 0xf2, 0xf4, 0xe2, 0x58, 0xc3, 0x99, 0x0c], [
+            [60, 150, 239, 231], [69, 166, 52, 77], [66, 161, 113, 128], [66, 161, 113, 128],
+            [63, 155, 178, 180], [60, 150, 239, 231], [66, 161, 113, 128], [69, 166, 52, 77],
+            [105, 211, 195, 121], [203, 32, 162, 138], [105, 211, 195, 121], [137, 152, 184, 127],
+            [171, 91, 173, 132], [137, 152, 184, 127], [105, 211, 195, 121], [105, 211, 195, 121],
+        ]),
+    ];
+    #[rustfmt::skip]
+    const BC6H_UNSIGNED_BLOCKS: [([u8; 16], [[u16; 4]; 16]); 8] = [
+        // mode 0x03 (10-bit endpoints)
+        ([0x83, 0x0c, 0x00, 0xff, 0x07, 0x80, 0x25, 0xc2, 0xe5, 0x5e, 0xbc, 0x3d, 0x23, 0x8e, 0x61, 0x7a], [
+            [0x0a75, 0x3a73, 0x79e5, 0x3c00], [0x00c2, 0x25fe, 0x6dfa, 0x3c00], [0x00c2, 0x25fe, 0x6dfa, 0x3c00], [0x082d, 0x35a3, 0x7717, 0x3c00],
+            [0x0279, 0x299a, 0x7014, 0x3c00], [0x033b, 0x2b35, 0x7104, 0x3c00], [0x01b6, 0x27ff, 0x6f25, 0x3c00], [0x09b2, 0x38d8, 0x78f6, 0x3c00],
+            [0x09b2, 0x38d8, 0x78f6, 0x3c00], [0x0a75, 0x3a73, 0x79e5, 0x3c00], [0x00c2, 0x25fe, 0x6dfa, 0x3c00], [0x05b4, 0x306c, 0x740e, 0x3c00],
+            [0x0b68, 0x3c74, 0x7b10, 0x3c00], [0x0739, 0x33a1, 0x75ec, 0x3c00], [0x03fe, 0x2ccf, 0x71f3, 0x3c00], [0x0677, 0x3206, 0x74fc, 0x3c00],
+        ]),
+        // mode 0x07 (11-bit endpoints, 9-bit deltas)
+        ([0x87, 0x3b, 0x64, 0xfe, 0xe7, 0xfc, 0x9f, 0x84, 0xb7, 0x1e, 0x76, 0xf9, 0xa6, 0x2e, 0x68, 0x25], [
+            [0x599e, 0x0f46, 0x78f4, 0x3c00], [0x5667, 0x177a, 0x70fe, 0x3c00], [0x552c, 0x1a9d, 0x6df3, 0x3c00], [0x5a78, 0x0d1a, 0x7b0f, 0x3c00],
+            [0x5864, 0x1269, 0x75e9, 0x3c00], [0x5803, 0x1360, 0x74f9, 0x3c00], [0x5741, 0x154e, 0x7319, 0x3c00], [0x54cb, 0x1b94, 0x6d03, 0x3c00],
+            [0x5864, 0x1269, 0x75e9, 0x3c00], [0x56c8, 0x1683, 0x71ee, 0x3c00], [0x552c, 0x1a9d, 0x6df3, 0x3c00], [0x59ff, 0x0e4f, 0x79e3, 0x3c00],
+            [0x57a2, 0x1457, 0x7409, 0x3c00], [0x5864, 0x1269, 0x75e9, 0x3c00], [0x58dd, 0x1134, 0x7715, 0x3c00], [0x59ff, 0x0e4f, 0x79e3, 0x3c00],
+        ]),
+        // mode 0x0b (12-bit endpoints, 8-bit deltas)
+        ([0x0b, 0xf7, 0xff, 0x01, 0xe0, 0x2c, 0xf4, 0x3f, 0xb5, 0x55, 0x9c, 0x1d, 0xc5, 0x7e, 0x24, 0x51], [
+            [0x5a68, 0x7b97, 0x1f8e, 0x3c00], [0x589c, 0x79e0, 0x21d6, 0x3c00], [0x59d7, 0x7b0c, 0x2046, 0x3c00], [0x59d7, 0x7b0c, 0x2046, 0x3c00],
+            [0x586c, 0x79b2, 0x2214, 0x3c00], [0x5909, 0x7a48, 0x214c, 0x3c00], [0x583b, 0x7984, 0x2251, 0x3c00], [0x5aa5, 0x7bd1, 0x1f41, 0x3c00],
+            [0x59d7, 0x7b0c, 0x2046, 0x3c00], [0x586c, 0x79b2, 0x2214, 0x3c00], [0x57ff, 0x7949, 0x229e, 0x3c00], [0x596a, 0x7aa4, 0x20d1, 0x3c00],
+            [0x5a08, 0x7b3a, 0x2009, 0x3c00], [0x5a68, 0x7b97, 0x1f8e, 0x3c00], [0x5aa5, 0x7bd1, 0x1f41, 0x3c00], [0x59d7, 0x7b0c, 0x2046, 0x3c00],
+        ]),
+        // mode 0x0f (16-bit endpoints, 4-bit deltas)
+        ([0xef, 0x7f, 0x00, 0xd0, 0xdf, 0xff, 0x02, 0x04, 0x6e, 0x32, 0xec, 0x3a, 0x2f, 0x74, 0x10, 0x37], [
+            [0x7bfe, 0x3e01, 0x01e2, 0x3c00], [0x7bfe, 0x3e01, 0x01e2, 0x3c00], [0x7bff, 0x3e00, 0x01e3, 0x3c00], [0x7bff, 0x3e00, 0x01e3, 0x3c00],
+            [0x7bfd, 0x3e02, 0x01e1, 0x3c00], [0x7bfd, 0x3e03, 0x01e0, 0x3c00], [0x7bfe, 0x3e02, 0x01e1, 0x3c00], [0x7bff, 0x3e00, 0x01e3, 0x3c00],
+            [0x7bfd, 0x3e03, 0x01e0, 0x3c00], [0x7bff, 0x3e00, 0x01e3, 0x3c00], [0x7bff, 0x3e00, 0x01e3, 0x3c00], [0x7bfe, 0x3e01, 0x01e2, 0x3c00],
+            [0x7bff, 0x3e00, 0x01e4, 0x3c00], [0x7bff, 0x3e00, 0x01e4, 0x3c00], [0x7bfe, 0x3e01, 0x01e2, 0x3c00], [0x7bff, 0x3e00, 0x01e3, 0x3c00],
+        ]),
+        // mode 0x00 (10-bit endpoints, 5-bit deltas), partition 5
+        ([0x18, 0x4b, 0x32, 0x08, 0x57, 0xde, 0x8e, 0x37, 0xec, 0xbf, 0xb0, 0xb9, 0x10, 0x14, 0x25, 0x2d], [
+            [0x48b7, 0x0c2b, 0x6d0b, 0x3c00], [0x493a, 0x0ba8, 0x6dcf, 0x3c00], [0x47f7, 0x0d45, 0x6bf1, 0x3c00], [0x4871, 0x0c87, 0x6caf, 0x3c00],
+            [0x4996, 0x0b4c, 0x6e59, 0x3c00], [0x4781, 0x0dfc, 0x6b3a, 0x3c00], [0x47cf, 0x0d82, 0x6bb4, 0x3c00], [0x4781, 0x0dfc, 0x6b3a, 0x3c00],
+            [0x490e, 0x0bd4, 0x6d8e, 0x3c00], [0x47a8, 0x0dbf, 0x6b77, 0x3c00], [0x47cf, 0x0d82, 0x6bb4, 0x3c00], [0x47a8, 0x0dbf, 0x6b77, 0x3c00],
+            [0x47a8, 0x0dbf, 0x6b77, 0x3c00], [0x484a, 0x0cc4, 0x6c72, 0x3c00], [0x484a, 0x0cc4, 0x6c72, 0x3c00], [0x4781, 0x0dfc, 0x6b3a, 0x3c00],
+        ]),
+        // mode 0x01 (7-bit endpoints, 6-bit deltas), partition 30
+        ([0xe1, 0x5f, 0x00, 0x80, 0x2a, 0xf5, 0x2b, 0x54, 0x81, 0xd0, 0x1b, 0x58, 0xaa, 0x52, 0x5c, 0xd9], [
+            [0x7481, 0x0895, 0x37f2, 0x3c00], [0x77b4, 0x097d, 0x4fe8, 0x3c00], [0x7bff, 0x0a2c, 0x57ac, 0x3c00], [0x6a5b, 0x075f, 0x37bf, 0x3c00],
+            [0x68da, 0x15ee, 0x2dc6, 0x3c00], [0x6c99, 0x11a4, 0x310b, 0x3c00], [0x7481, 0x0895, 0x37f2, 0x3c00], [0x6611, 0x06b0, 0x2ffb, 0x3c00],
+            [0x736a, 0x08cf, 0x4824, 0x3c00], [0x7481, 0x0895, 0x37f2, 0x3c00], [0x7840, 0x044a, 0x3b37, 0x3c00], [0x651b, 0x1a39, 0x2a81, 0x3c00],
+            [0x6611, 0x06b0, 0x2ffb, 0x3c00], [0x736a, 0x08cf, 0x4824, 0x3c00], [0x61c6, 0x0602, 0x2837, 0x3c00], [0x651b, 0x1a39, 0x2a81, 0x3c00],
+        ]),
+        // mode 0x06 (11-bit endpoints, 4/5/4-bit deltas), partition 17
+        ([0x06, 0x7a, 0x05, 0x00, 0xb8, 0xec, 0xd1, 0xfc, 0xd0, 0x28, 0x8e, 0xc1, 0xec, 0xf5, 0xb9, 0xf3], [
+            [0x794d, 0x0105, 0x3dda, 0x3c00], [0x78a3, 0x0000, 0x3e74, 0x3c00], [0x78de, 0x0086, 0x3e6d, 0x3c00], [0x78a3, 0x0000, 0x3e74, 0x3c00],
+            [0x795e, 0x0129, 0x3dc9, 0x3c00], [0x792f, 0x00c3, 0x3df8, 0x3c00], [0x794d, 0x0105, 0x3dda, 0x3c00], [0x792f, 0x013d, 0x3e64, 0x3c00],
+            [0x796d, 0x0149, 0x3db9, 0x3c00], [0x797d, 0x016a, 0x3daa, 0x3c00], [0x798c, 0x018b, 0x3d9b, 0x3c00], [0x795e, 0x0129, 0x3dc9, 0x3c00],
+            [0x794d, 0x0105, 0x3dda, 0x3c00], [0x798c, 0x018b, 0x3d9b, 0x3c00], [0x795e, 0x0129, 0x3dc9, 0x3c00], [0x798c, 0x018b, 0x3d9b, 0x3c00],
+        ]),
+        // mode 0x1e (6-bit endpoints without deltas), partition 31
+        ([0xfe, 0x0f, 0x80, 0xc0, 0x57, 0x8c, 0x6a, 0xef, 0x0a, 0xff, 0xe7, 0x29, 0x87, 0x04, 0x3b, 0x4d], [
+            [0x6d6b, 0x0595, 0x3e6c, 0x3c00], [0x22eb, 0x2222, 0x3ba3, 0x3c00], [0x393f, 0x398c, 0x39da, 0x3c00], [0x4a80, 0x4a33, 0x49e5, 0x3c00],
+            [0x29b7, 0x2a90, 0x2b69, 0x3c00], [0x22eb, 0x2222, 0x3ba3, 0x3c00], [0x6d6b, 0x0595, 0x3e6c, 0x3c00], [0x4a80, 0x4a33, 0x49e5, 0x3c00],
+            [0x4a80, 0x4a33, 0x49e5, 0x3c00], [0x7bff, 0x0000, 0x3ef8, 0x3c00], [0x4012, 0x16f6, 0x3cba, 0x3c00], [0x5a08, 0x592f, 0x5856, 0x3c00],
+            [0x393f, 0x398c, 0x39da, 0x3c00], [0x29b7, 0x2a90, 0x2b69, 0x3c00], [0x5044, 0x10c1, 0x3d55, 0x3c00], [0x5ed8, 0x0b2b, 0x3de1, 0x3c00],
+        ]),
+    ];
+    #[rustfmt::skip]
+    const BC6H_SIGNED_BLOCKS: [([u8; 16], [[u16; 4]; 16]); 5] = [
+        // mode 0x03 (10-bit endpoints)
+        ([0x83, 0x41, 0x00, 0xfe, 0x63, 0xe9, 0xff, 0x00, 0x61, 0x40, 0x08, 0x5e, 0xe0, 0xe8, 0x7e, 0x36], [
+            [0xf937, 0x0000, 0x7bff, 0x3c00], [0xaa67, 0x8025, 0x1740, 0x3c00], [0xf937, 0x0000, 0x7bff, 0x3c00], [0xc5af, 0x8018, 0x3a20, 0x3c00],
+            [0x9228, 0x8031, 0x87c0, 0x3c00], [0xf937, 0x0000, 0x7bff, 0x3c00], [0x3ca7, 0x8057, 0xec7f, 0x3c00], [0xb98f, 0x801e, 0x2aa0, 0x3c00],
+            [0xf937, 0x0000, 0x7bff, 0x3c00], [0x3ca7, 0x8057, 0xec7f, 0x3c00], [0x9228, 0x8031, 0x87c0, 0x3c00], [0x3ca7, 0x8057, 0xec7f, 0x3c00],
+            [0x3ca7, 0x8057, 0xec7f, 0x3c00], [0x9e47, 0x802b, 0x07c0, 0x3c00], [0xaa67, 0x8025, 0x1740, 0x3c00], [0xd1cf, 0x8012, 0x499f, 0x3c00],
+        ]),
+        // mode 0x07 (11-bit endpoints, 9-bit deltas)
+        ([0x07, 0x80, 0xff, 0xc9, 0xc0, 0x30, 0x24, 0x9c, 0xa2, 0x0d, 0xa9, 0x8f, 0xfe, 0x4f, 0x1f, 0x67], [
+            [0xfbd1, 0x7a4e, 0x0aa6, 0x3c00], [0xfa15, 0x69d0, 0x842e, 0x3c00], [0xf98e, 0x64be, 0x88be, 0x3c00], [0xfbff, 0x7bff, 0x0c2b, 0x3c00],
+            [0xfa4e, 0x6bed, 0x8248, 0x3c00], [0xfa15, 0x69d0, 0x842e, 0x3c00], [0xf927, 0x60ef, 0x8c2b, 0x3c00], [0xfa7c, 0x6d9e, 0x80c2, 0x3c00],
+            [0xf955, 0x62a0, 0x8aa6, 0x3c00], [0xf927, 0x60ef, 0x8c2b, 0x3c00], [0xf927, 0x60ef, 0x8c2b, 0x3c00], [0xfb3e, 0x74cf, 0x05b4, 0x3c00],
+            [0xf927, 0x60ef, 0x8c2b, 0x3c00], [0xfbd1, 0x7a4e, 0x0aa6, 0x3c00], [0xfaaa, 0x6f4f, 0x00c2, 0x3c00], [0xfad7, 0x7100, 0x0248, 0x3c00],
+        ]),
+        // mode 0x0f (16-bit endpoints, 4-bit deltas)
+        ([0x2f, 0x80, 0xff, 0x01, 0xb8, 0x20, 0xfd, 0x02, 0xfc, 0x6d, 0xc6, 0xd1, 0xee, 0xa1, 0x76, 0x6f], [
+            [0xfbfc, 0x7bfc, 0x0001, 0x3c00], [0xfbf8, 0x7bf8, 0x0004, 0x3c00], [0xfbf9, 0x7bf9, 0x0003, 0x3c00], [0xfbfc, 0x7bfc, 0x0001, 0x3c00],
+            [0xfbfc, 0x7bfc, 0x0001, 0x3c00], [0xfbf9, 0x7bf9, 0x0003, 0x3c00], [0xfbff, 0x7bff, 0x0000, 0x3c00], [0xfbf9, 0x7bf9, 0x0003, 0x3c00],
+            [0xfbf8, 0x7bf8, 0x0004, 0x3c00], [0xfbf8, 0x7bf8, 0x0004, 0x3c00], [0xfbff, 0x7bff, 0x0000, 0x3c00], [0xfbfa, 0x7bfa, 0x0002, 0x3c00],
+            [0xfbfc, 0x7bfc, 0x0001, 0x3c00], [0xfbfc, 0x7bfc, 0x0001, 0x3c00], [0xfbf8, 0x7bf8, 0x0004, 0x3c00], [0xfbfc, 0x7bfc, 0x0001, 0x3c00],
+        ]),
+        // mode 0x00 (10-bit endpoints, 5-bit deltas), partition 9
+        ([0x84, 0x5a, 0x7d, 0xfe, 0x57, 0xe3, 0xfd, 0xe8, 0xa3, 0x2f, 0x81, 0x0f, 0x1c, 0xd6, 0x28, 0xa9], [
+            [0xc8c7, 0x3cab, 0x805d, 0x3c00], [0xc8c7, 0x3cab, 0x805d, 0x3c00], [0xc905, 0x3c6d, 0x0000, 0x3c00], [0xcafa, 0x3a77, 0x0208, 0x3c00],
+            [0xc8c7, 0x3cab, 0x805d, 0x3c00], [0xca73, 0x3afe, 0x017b, 0x3c00], [0xcafa, 0x3a77, 0x0208, 0x3c00], [0xcc69, 0x3909, 0x0383, 0x3c00],
+            [0xcafa, 0x3a77, 0x0208, 0x3c00], [0xc9f9, 0x3b78, 0x00fc, 0x3c00], [0xcbee, 0x3983, 0x0305, 0x3c00], [0xcb74, 0x39fd, 0x0286, 0x3c00],
+            [0xcbee, 0x3983, 0x0305, 0x3c00], [0xcbee, 0x3983, 0x0305, 0x3c00], [0xc9f9, 0x3b78, 0x00fc, 0x3c00], [0xcb74, 0x39fd, 0x0286, 0x3c00],
+        ]),
+        // mode 0x1e (6-bit endpoints without deltas), partition 31
+        ([0x1e, 0xd4, 0x4f, 0x80, 0x50, 0x8c, 0x05, 0x2f, 0xf7, 0xef, 0x9f, 0xe4, 0x37, 0xaa, 0xf3, 0xbc], [
+            [0xb684, 0x262b, 0x31dc, 0x3c00], [0xe4d6, 0x5f63, 0x109e, 0x3c00], [0x80e0, 0x0435, 0x9828, 0x3c00], [0x138e, 0x90c5, 0x9341, 0x3c00],
+            [0x678f, 0xe704, 0x00e8, 0x3c00], [0x28b0, 0xcf70, 0x7630, 0x3c00], [0x85a1, 0x9638, 0x54f2, 0x3c00], [0x80e0, 0x0435, 0x9828, 0x3c00],
+            [0x138e, 0x90c5, 0x9341, 0x3c00], [0x85a1, 0x9638, 0x54f2, 0x3c00], [0x1187, 0xb2d4, 0x6591, 0x3c00], [0x80e0, 0x0435, 0x9828, 0x3c00],
+            [0x7bff, 0xfbff, 0x05d0, 0x3c00], [0x80e0, 0x0435, 0x9828, 0x3c00], [0x28b0, 0xcf70, 0x7630, 0x3c00], [0x85a1, 0x9638, 0x54f2, 0x3c00],
+        ]),
+    ];
+
+    #[test]
+    fn bc7() {
+        for (i, (block, texels)) in BC7_BLOCKS.iter().enumerate() {
+            assert_eq!(
+                rgba8(InternalFormat::CompressedRgbaBptcUnorm, block),
+                texels,
+                "block {i}"
+            );
+            assert_eq!(
+                rgba8(InternalFormat::CompressedSrgbAlphaBptcUnorm, block),
+                texels,
+                "block {i}"
+            );
+        }
+        // blocks without a mode bit are reserved and decode to transparent black
+        let reserved = [0; 16];
+        assert_eq!(
+            decode(InternalFormat::CompressedRgbaBptcUnorm, &reserved),
+            [0; 64]
+        );
+    }
+
+    fn rgba16f(format: InternalFormat, block: &[u8]) -> Vec<[u16; 4]> {
+        u16s(format, block)
+            .chunks_exact(4)
+            .map(|t| t.try_into().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn bc6h() {
+        let blocks = [
+            (
+                InternalFormat::CompressedRgbBptcUnsignedFloat,
+                &BC6H_UNSIGNED_BLOCKS[..],
+            ),
+            (
+                InternalFormat::CompressedRgbBptcSignedFloat,
+                &BC6H_SIGNED_BLOCKS[..],
+            ),
+        ];
+        for (format, blocks) in blocks {
+            for (i, (block, texels)) in blocks.iter().enumerate() {
+                assert_eq!(rgba16f(format, block), texels, "{format:?} block {i}");
+            }
+            // reserved modes decode to opaque black
+            let mut reserved = [0xff; 16];
+            reserved[0] = 0x13;
+            assert_eq!(rgba16f(format, &reserved), [[0, 0, 0, 0x3c00]; 16]);
+        }
+    }
+
+    /// S3TC color endpoints, as (RGB565 value, RGBA8 texel)
+    const BC1_RED: (u16, [u8; 4]) = (0xf800, [255, 0, 0, 255]);
+    const BC1_BLUE: (u16, [u8; 4]) = (0x001f, [0, 0, 255, 255]);
+    /// A BC1 block with endpoints `c0` and `c1` whose texels select each of the 4 palette entries in turn
+    fn bc1_block(c0: u16, c1: u16) -> [u8; 8] {
+        let [a, b] = c0.to_le_bytes();
+        let [c, d] = c1.to_le_bytes();
+        [a, b, c, d, 0xe4, 0xe4, 0xe4, 0xe4]
+    }
+    fn bc1_texels(palette: [[u8; 4]; 4]) -> Vec<[u8; 4]> {
+        palette.repeat(4)
+    }
+
+    #[test]
+    fn bc1() {
+        // the first endpoint is greater, so the palette interpolates thirds of the way between the endpoints
+        let block = bc1_block(BC1_RED.0, BC1_BLUE.0);
+        let expected = bc1_texels([BC1_RED.1, BC1_BLUE.1, [170, 0, 85, 255], [85, 0, 170, 255]]);
+        for format in [
+            InternalFormat::CompressedRgbS3tcDxt1Ext,
+            InternalFormat::CompressedRgbaS3tcDxt1Ext,
+            InternalFormat::CompressedSrgbS3tcDxt1Ext,
+            InternalFormat::CompressedSrgbAlphaS3tcDxt1Ext,
+        ] {
+            assert_eq!(rgba8(format, &block), expected);
+        }
+        // otherwise the palette has the midpoint of the endpoints and black, which is transparent in RGBA blocks
+        let block = bc1_block(BC1_BLUE.0, BC1_RED.0);
+        let palette = [BC1_BLUE.1, BC1_RED.1, [128, 0, 128, 255], [0, 0, 0, 255]];
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgbS3tcDxt1Ext, &block),
+            bc1_texels(palette)
+        );
+        let palette = [BC1_BLUE.1, BC1_RED.1, [128, 0, 128, 255], [0; 4]];
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgbaS3tcDxt1Ext, &block),
+            bc1_texels(palette)
+        );
+    }
+
+    #[test]
+    fn bc2() {
+        // explicit alpha of 17 times the texel's index, followed by a color block that always has 4 colors
+        let mut block = [
+            0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        block[8..].copy_from_slice(&bc1_block(BC1_BLUE.0, BC1_RED.0));
+        let expected: Vec<_> =
+            bc1_texels([BC1_BLUE.1, BC1_RED.1, [85, 0, 170, 255], [170, 0, 85, 255]])
+                .into_iter()
+                .zip(0..)
+                .map(|([r, g, b, _], i)| [r, g, b, i * 17])
+                .collect();
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgbaS3tcDxt3Ext, &block),
+            expected
+        );
+        assert_eq!(
+            rgba8(InternalFormat::CompressedSrgbAlphaS3tcDxt3Ext, &block),
+            expected
+        );
+    }
+
+    #[test]
+    fn bc3() {
+        // RGTC1-style alpha, followed by a color block that always has 4 colors
+        let mut block = [0; 16];
+        block[..8].copy_from_slice(&rgtc_block(70, 0));
+        block[8..].copy_from_slice(&bc1_block(BC1_BLUE.0, BC1_RED.0));
+        let expected: Vec<_> =
+            bc1_texels([BC1_BLUE.1, BC1_RED.1, [85, 0, 170, 255], [170, 0, 85, 255]])
+                .into_iter()
+                .zip(rgtc_texels([70, 0, 60, 50, 40, 30, 20, 10]))
+                .map(|([r, g, b, _], a)| [r, g, b, a])
+                .collect();
+        assert_eq!(
+            rgba8(InternalFormat::CompressedRgbaS3tcDxt5Ext, &block),
+            expected
+        );
+        assert_eq!(
+            rgba8(InternalFormat::CompressedSrgbAlphaS3tcDxt5Ext, &block),
+            expected
+        );
+    }
+
+    #[test]
+    fn partial_blocks() {
+        // a 6x5 image of 2x2 blocks, each filled with a single value
+        let src: Vec<u8> = [10, 20, 30, 40]
+            .into_iter()
+            .flat_map(|v| [v, v, 0, 0, 0, 0, 0, 0])
+            .collect();
+        let out = decompress(InternalFormat::CompressedRedRgtc1, &src, 6, 5, 1).unwrap();
+        let expected: Vec<u8> = (0..30)
+            .map(|i| [[10, 20], [30, 40]][(i / 6) / 4][(i % 6) / 4])
+            .collect();
+        assert_eq!(out, expected);
+        // each image of an array starts at a new block
+        let out = decompress(InternalFormat::CompressedRedRgtc1, &src, 1, 1, 4).unwrap();
+        assert_eq!(out, [10, 20, 30, 40]);
+        assert_eq!(
+            decompress(InternalFormat::CompressedRedRgtc1, &[], 0, 4, 1),
+            Some(Vec::new())
+        );
+        assert_eq!(decompress(InternalFormat::Rgba8, &src, 4, 4, 1), None);
+    }
+
+    /// Blocks of each format family along with the texels that a reference decoder (Mesa 22.3's llvmpipe, read back with
+    /// glGetTexImage) decodes them to, and the largest difference allowed between a decoded component and the reference. S3TC and
+    /// RGTC palettes are specified as weighted averages of the endpoints without a rounding rule, which llvmpipe approximates
+    const REFERENCE_BLOCKS: [(InternalFormat, i32, &str, &str); 15] = [
+        (
+            InternalFormat::CompressedRgb8Etc2,
+            0,
+            "66963634e28f54da",
+            "61942eff77aa44ff7e7e7effb6b6b6ff558822ff6b9e38ff4e4e4eff4e4e4eff61942eff77aa44ffb6b6b6ff161616ff558822ff558822ff7e7e7eff4e4e4eff",
+        ),
+        (
+            InternalFormat::CompressedRgb8PunchthroughAlpha1Etc2,
+            0,
+            "19b89bcd31da597a",
+            "18bd9cff005332ff005332ff005332ff005332ff82ffffff18bd9cff0000000021bdb5ff00938bff21bdb5ff4be7dfff00938bff000000004be7dfff21bdb5ff",
+        ),
+        (
+            InternalFormat::CompressedRgba8Etc2Eac,
+            0,
+            "79f903d71c4a3769133421e6481bdd69",
+            "0000005b000200003a213a013a213a000002005bc7e8d8882e152e012e152eb53f6050ffc7e8d8003a213a88180018b5000000b53f605088180018003a213a2e",
+        ),
+        (
+            InternalFormat::CompressedR11Eac,
+            0,
+            "fe9f484742d7c25e",
+            "97bf95adffff9ad197bfffffffff9ad19ce39ce3ffff95adffff97bfffffffff",
+        ),
+        (
+            InternalFormat::CompressedSignedRg11Eac,
+            0,
+            "6f78bab1bd000d79380b75438b7d1a5d",
+            "ff7fcd3618616d371861cd36ff7f8e38ff7f8e38ff7fce3818612e39ff7f6d37ff7f2d37ff7f6d3718612d37ff7fcd360a292e38ff7fcd3618616d3711458e38",
+        ),
+        (
+            InternalFormat::CompressedRgbS3tcDxt1Ext,
+            1,
+            "2291d8cdc310411e",
+            "000000ff942410ff942410ff000000ff942410ff942410ffcebac6ff942410ffcebac6ff942410ff942410ffcebac6ffb16f6bff000000ffcebac6ff942410ff",
+        ),
+        (
+            InternalFormat::CompressedRgbaS3tcDxt1Ext,
+            1,
+            "62a1d5f32c65b73a",
+            "a52c10ff00000000ce535fffa52c10fff779adfff779adffce535ffff779adff00000000f779adff00000000ce535fffce535fffce535fff00000000a52c10ff",
+        ),
+        (
+            InternalFormat::CompressedRgbaS3tcDxt1Ext,
+            1,
+            "13f02e75c460aa80",
+            "f7009cff73a673fff7009cff9f6e80fff7009cfff7009cffcb378eff73a673ffcb378effcb378effcb378effcb378efff7009cfff7009cfff7009cffcb378eff",
+        ),
+        (
+            InternalFormat::CompressedRgbaS3tcDxt3Ext,
+            1,
+            "6e668df86bd6c106a066f2de246c200f",
+            "63d700eededf94668bd9316663d7006663d700ddb4dc62888bd93188dedf94ff63d700bb63d700668bd9316663d700ddb4dc6211b4dc62cc63d7006663d70000",
+        ),
+        (
+            InternalFormat::CompressedRgbaS3tcDxt5Ext,
+            1,
+            "8ac8037fa0a99673242550667145d59b",
+            "63cb84a221a6218a4cbe62af63cb84ff63cb84ff63cb848a21a6218a63cb84bb63cb84c863cb84bb63cb84964cbe62a24cbe62c836b241ff63cb84af36b241a2",
+        ),
+        (
+            InternalFormat::CompressedRedRgtc1,
+            1,
+            "e6e987c7a2f78819",
+            "ffe600e7e7e8e6e8ff00e7e7e6e700e6",
+        ),
+        (
+            InternalFormat::CompressedSignedRgRgtc2,
+            1,
+            "26e585e6f0b901c47f91ef19b7bde3c6",
+            "01b426f81cb41319f791e5d50af8eef8e5f8eeb4f7d5269126d526f8e591f7d5",
+        ),
+        (
+            InternalFormat::CompressedRgbaBptcUnorm,
+            0,
+            "1351df0efb261a62b89a3e73924eb315",
+            "84a707ff849002ff84a707ff84ca0effb7714dffb7714dffb7714dffe2469effcc5c74ffd8508bffed3bb2ffd8508bff77a6b9ff77a6b9ff70507fff6b185aff",
+        ),
+        (
+            InternalFormat::CompressedRgbBptcUnsignedFloat,
+            0,
+            "2c505590d75611f5ca919e5f1429f3f1",
+            "604d2815ca75003c944dd1147375003c2f4e66145d75003c0b4ee314f976003c284d84152676003cae4da5144775003c284d84152676003c494e0a143074003c944dd1147375003c284d84152676003c424d5815fa75003c944dd1147375003cf44cdb157d76003c604d2815ca75003c424d5815fa75003cf44cdb157d76003c",
+        ),
+        (
+            InternalFormat::CompressedRgbBptcSignedFloat,
+            0,
+            "d4a58933368d9adbf758ce8f722a502c",
+            "df49bebafcb8003c1a4a33bb53b9003cb74a6dbc3fba003cdf49bebafcb8003c1a4a33bb53b9003cab4956baadb8003c824a04bcf0b9003c7749edb95fb8003cb5470bbc38b5003c4e4a9bbba2b9003c434985b911b8003c434985b911b8003c4f4676bc76b7003c0d48f1bbadb4003c07473fbc4fb6003c7749edb95fb8003c",
+        ),
+    ];
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn matches_reference_decoder() {
+        for (format, tolerance, block, expected) in REFERENCE_BLOCKS {
+            let signed = format.component_type() == GL_SIGNED_NORMALIZED;
+            let value = |v: u8| {
+                if signed {
+                    i32::from(v.cast_signed())
+                } else {
+                    i32::from(v)
+                }
+            };
+            let decoded = decode(format, &hex(block));
+            let expected = hex(expected);
+            assert_eq!(decoded.len(), expected.len());
+            for (i, (&d, &e)) in decoded.iter().zip(&expected).enumerate() {
+                assert!(
+                    (value(d) - value(e)).abs() <= tolerance,
+                    "byte {i} of {format:?} block {block} decoded to {d}, expected {e}"
+                );
+            }
+        }
+    }
+}
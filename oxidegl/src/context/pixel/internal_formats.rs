@@ -2,7 +2,13 @@ use super::{
     ConvertPixel, Depth, NormalizedIntChannel, RgColorFormat, RgbaColorFormat, SingleChannelFormat,
     Stencil,
 };
-use crate::enums::InternalFormat;
+use crate::{
+    dispatch::gl_types::GLenum,
+    enums::{
+        GL_FLOAT, GL_INT, GL_SIGNED_NORMALIZED, GL_UNSIGNED_INT, GL_UNSIGNED_NORMALIZED,
+        InternalFormat,
+    },
+};
 use half::f16;
 use objc2_metal::MTLPixelFormat;
 
@@ -451,13 +457,24 @@ impl InternalFormat {
             // etc2 and eac
             CompressedRgb8Etc2 => M::ETC2_RGB8,
             CompressedSrgb8Etc2 => M::ETC2_RGB8_sRGB,
-            CompressedRgba8Etc2Eac => M::ETC2_RGB8A1,
-            CompressedSrgb8Alpha8Etc2Eac => M::ETC2_RGB8A1_sRGB,
+            CompressedRgb8PunchthroughAlpha1Etc2 => M::ETC2_RGB8A1,
+            CompressedSrgb8PunchthroughAlpha1Etc2 => M::ETC2_RGB8A1_sRGB,
+            CompressedRgba8Etc2Eac => M::EAC_RGBA8,
+            CompressedSrgb8Alpha8Etc2Eac => M::EAC_RGBA8_sRGB,
             CompressedR11Eac => M::EAC_R11Unorm,
             CompressedSignedR11Eac => M::EAC_R11Snorm,
             CompressedRg11Eac => M::EAC_RG11Unorm,
             CompressedSignedRg11Eac => M::EAC_RG11Snorm,
 
+            // s3tc (aka BC1, 2 and 3). Metal has no BC1 format without alpha, so the alpha of RGB images is ignored when they are
+            // sampled (see `Texture::sampled_texture`)
+            CompressedRgbS3tcDxt1Ext | CompressedRgbaS3tcDxt1Ext => M::BC1_RGBA,
+            CompressedSrgbS3tcDxt1Ext | CompressedSrgbAlphaS3tcDxt1Ext => M::BC1_RGBA_sRGB,
+            CompressedRgbaS3tcDxt3Ext => M::BC2_RGBA,
+            CompressedSrgbAlphaS3tcDxt3Ext => M::BC2_RGBA_sRGB,
+            CompressedRgbaS3tcDxt5Ext => M::BC3_RGBA,
+            CompressedSrgbAlphaS3tcDxt5Ext => M::BC3_RGBA_sRGB,
+
             // rgtc (aka BC4 and 5)
             CompressedRedRgtc1 => M::BC4_RUnorm,
             CompressedSignedRedRgtc1 => M::BC4_RSnorm,
@@ -521,13 +538,422 @@ impl InternalFormat {
             Rgb565 => todo!(),
             R3G3B2 => todo!(),
             Rgba2 => todo!(),
-
-            // unsupported ETC2 compression modes
-            CompressedRgb8PunchthroughAlpha1Etc2 | CompressedSrgb8PunchthroughAlpha1Etc2 => {
-                panic!("OxideGL does not support ETC2 punchthrough format variations")
+        }
+    }
+    /// Whether this format can be represented by a Metal pixel format (i.e. whether [`mtl_texture_format`](Self::mtl_texture_format) will succeed)
+    pub(crate) fn has_mtl_texture_format(self) -> bool {
+        use InternalFormat::*;
+        !matches!(self, StencilIndex16 | Rgb565 | R3G3B2 | Rgba2)
+    }
+    /// The internal format that describes the contents of a default framebuffer buffer with the Metal pixel format `format`.
+    /// The result's own Metal pixel format may differ from `format` in channel order
+    pub(crate) fn from_drawable_format(format: MTLPixelFormat) -> Option<Self> {
+        use MTLPixelFormat as M;
+        Some(match format {
+            M::RGBA8Unorm | M::BGRA8Unorm => Self::Rgba8,
+            M::RGBA8Unorm_sRGB | M::BGRA8Unorm_sRGB => Self::Srgb8Alpha8,
+            M::RGB10A2Unorm | M::BGR10A2Unorm => Self::Rgb10A2,
+            M::RGBA16Float => Self::Rgba16f,
+            M::RGBA32Float => Self::Rgba32f,
+            M::Depth16Unorm => Self::DepthComponent16,
+            M::Depth32Float => Self::DepthComponent32f,
+            M::Depth24Unorm_Stencil8 => Self::Depth24Stencil8,
+            M::Depth32Float_Stencil8 => Self::Depth32fStencil8,
+            M::Stencil8 => Self::StencilIndex8,
+            _ => return None,
+        })
+    }
+    /// Whether this format is stored as a block-compressed Metal pixel format. Note that the generic
+    /// compressed formats (e.g. `GL_COMPRESSED_RGBA`) are stored uncompressed and are not included
+    pub(crate) fn is_block_compressed(self) -> bool {
+        use InternalFormat::*;
+        matches!(
+            self,
+            CompressedRgb8Etc2
+                | CompressedSrgb8Etc2
+                | CompressedRgb8PunchthroughAlpha1Etc2
+                | CompressedSrgb8PunchthroughAlpha1Etc2
+                | CompressedRgba8Etc2Eac
+                | CompressedSrgb8Alpha8Etc2Eac
+                | CompressedR11Eac
+                | CompressedSignedR11Eac
+                | CompressedRg11Eac
+                | CompressedSignedRg11Eac
+                | CompressedRgbS3tcDxt1Ext
+                | CompressedRgbaS3tcDxt1Ext
+                | CompressedRgbaS3tcDxt3Ext
+                | CompressedRgbaS3tcDxt5Ext
+                | CompressedSrgbS3tcDxt1Ext
+                | CompressedSrgbAlphaS3tcDxt1Ext
+                | CompressedSrgbAlphaS3tcDxt3Ext
+                | CompressedSrgbAlphaS3tcDxt5Ext
+                | CompressedRedRgtc1
+                | CompressedSignedRedRgtc1
+                | CompressedRgRgtc2
+                | CompressedSignedRgRgtc2
+                | CompressedRgbaBptcUnorm
+                | CompressedSrgbAlphaBptcUnorm
+                | CompressedRgbBptcSignedFloat
+                | CompressedRgbBptcUnsignedFloat
+        )
+    }
+    /// Size in bytes of each 4x4 texel block of this format if it is block-compressed (see [`Self::is_block_compressed`])
+    pub(crate) fn compressed_block_size(self) -> Option<usize> {
+        use InternalFormat::*;
+        match self {
+            CompressedRgb8Etc2
+            | CompressedSrgb8Etc2
+            | CompressedRgb8PunchthroughAlpha1Etc2
+            | CompressedSrgb8PunchthroughAlpha1Etc2
+            | CompressedR11Eac
+            | CompressedSignedR11Eac
+            | CompressedRgbS3tcDxt1Ext
+            | CompressedRgbaS3tcDxt1Ext
+            | CompressedSrgbS3tcDxt1Ext
+            | CompressedSrgbAlphaS3tcDxt1Ext
+            | CompressedRedRgtc1
+            | CompressedSignedRedRgtc1 => Some(8),
+            CompressedRgba8Etc2Eac
+            | CompressedSrgb8Alpha8Etc2Eac
+            | CompressedRg11Eac
+            | CompressedSignedRg11Eac
+            | CompressedRgbaS3tcDxt3Ext
+            | CompressedRgbaS3tcDxt5Ext
+            | CompressedSrgbAlphaS3tcDxt3Ext
+            | CompressedSrgbAlphaS3tcDxt5Ext
+            | CompressedRgRgtc2
+            | CompressedSignedRgRgtc2
+            | CompressedRgbaBptcUnorm
+            | CompressedSrgbAlphaBptcUnorm
+            | CompressedRgbBptcSignedFloat
+            | CompressedRgbBptcUnsignedFloat => Some(16),
+            _ => None,
+        }
+    }
+    /// The uncompressed format that images of this block-compressed format are decompressed into when the device can't sample
+    /// them natively. Returns None for formats that aren't block-compressed
+    pub(crate) fn decompressed_equivalent(self) -> Option<Self> {
+        use InternalFormat::*;
+        Some(match self {
+            CompressedRgb8Etc2
+            | CompressedRgb8PunchthroughAlpha1Etc2
+            | CompressedRgba8Etc2Eac
+            | CompressedRgbS3tcDxt1Ext
+            | CompressedRgbaS3tcDxt1Ext
+            | CompressedRgbaS3tcDxt3Ext
+            | CompressedRgbaS3tcDxt5Ext
+            | CompressedRgbaBptcUnorm => Rgba8,
+            CompressedSrgb8Etc2
+            | CompressedSrgb8PunchthroughAlpha1Etc2
+            | CompressedSrgb8Alpha8Etc2Eac
+            | CompressedSrgbS3tcDxt1Ext
+            | CompressedSrgbAlphaS3tcDxt1Ext
+            | CompressedSrgbAlphaS3tcDxt3Ext
+            | CompressedSrgbAlphaS3tcDxt5Ext
+            | CompressedSrgbAlphaBptcUnorm => Srgb8Alpha8,
+            CompressedR11Eac => R16,
+            CompressedSignedR11Eac => R16Snorm,
+            CompressedRg11Eac => Rg16,
+            CompressedSignedRg11Eac => Rg16Snorm,
+            CompressedRedRgtc1 => R8,
+            CompressedSignedRedRgtc1 => R8Snorm,
+            CompressedRgRgtc2 => Rg8,
+            CompressedSignedRgRgtc2 => Rg8Snorm,
+            CompressedRgbBptcSignedFloat | CompressedRgbBptcUnsignedFloat => Rgba16f,
+            _ => return None,
+        })
+    }
+    /// Whether this is an (unnormalized) integer format. Stencil index formats are considered integer formats
+    pub(crate) fn is_integer(self) -> bool {
+        use InternalFormat::*;
+        matches!(
+            self,
+            R8i | R8ui
+                | R16i
+                | R16ui
+                | R32i
+                | R32ui
+                | Rg8i
+                | Rg8ui
+                | Rg16i
+                | Rg16ui
+                | Rg32i
+                | Rg32ui
+                | Rgb8i
+                | Rgb8ui
+                | Rgb16i
+                | Rgb16ui
+                | Rgb32i
+                | Rgb32ui
+                | Rgba8i
+                | Rgba8ui
+                | Rgba16i
+                | Rgba16ui
+                | Rgba32i
+                | Rgba32ui
+                | Rgb10A2ui
+                | StencilIndex
+                | StencilIndex1
+                | StencilIndex4
+                | StencilIndex8
+                | StencilIndex16
+        )
+    }
+    /// Whether this format has a depth component
+    pub(crate) fn has_depth(self) -> bool {
+        use InternalFormat::*;
+        matches!(
+            self,
+            DepthComponent
+                | DepthComponent16
+                | DepthComponent24
+                | DepthComponent32
+                | DepthComponent32f
+                | DepthStencil
+                | Depth24Stencil8
+                | Depth32fStencil8
+        )
+    }
+    /// Whether this format has a stencil component
+    pub(crate) fn has_stencil(self) -> bool {
+        use InternalFormat::*;
+        matches!(
+            self,
+            StencilIndex
+                | StencilIndex1
+                | StencilIndex4
+                | StencilIndex8
+                | StencilIndex16
+                | DepthStencil
+                | Depth24Stencil8
+                | Depth32fStencil8
+        )
+    }
+    /// Whether images of this format may be attached to the color attachments of a framebuffer object (see table 8.12 of the
+    /// OpenGL 4.6 core spec)
+    pub(crate) fn is_color_renderable(self) -> bool {
+        use InternalFormat::*;
+        !(self.has_depth()
+            || self.has_stencil()
+            || self.is_block_compressed()
+            || !self.has_mtl_texture_format()
+            || matches!(
+                self,
+                CompressedRed
+                    | CompressedRg
+                    | CompressedRgb
+                    | CompressedRgba
+                    | CompressedSrgb
+                    | CompressedSrgbAlpha
+                    | Srgb
+                    | Srgb8
+                    | Rgb9E5
+                    | R8Snorm
+                    | Rg8Snorm
+                    | Rgb8Snorm
+                    | Rgba8Snorm
+                    | R16Snorm
+                    | Rg16Snorm
+                    | Rgb16Snorm
+                    | Rgba16Snorm
+            ))
+    }
+    /// Sizes in bits of the red, green, blue, alpha, depth and stencil components of images of this format as they are stored,
+    /// with 0 for components the format does not have
+    pub(crate) fn component_sizes(self) -> [u32; 6] {
+        use InternalFormat::*;
+        match self {
+            R8
+            | R8i
+            | R8ui
+            | R8Snorm
+            | Red
+            | CompressedRed
+            | CompressedRedRgtc1
+            | CompressedSignedRedRgtc1 => [8, 0, 0, 0, 0, 0],
+            R16 | R16i | R16ui | R16f | R16Snorm | CompressedR11Eac | CompressedSignedR11Eac => {
+                [16, 0, 0, 0, 0, 0]
             }
+            R32f | R32i | R32ui => [32, 0, 0, 0, 0, 0],
+            Rg8
+            | Rg8i
+            | Rg8ui
+            | Rg8Snorm
+            | Rg
+            | CompressedRg
+            | CompressedRgRgtc2
+            | CompressedSignedRgRgtc2 => [8, 8, 0, 0, 0, 0],
+            Rg16
+            | Rg16i
+            | Rg16ui
+            | Rg16f
+            | Rg16Snorm
+            | CompressedRg11Eac
+            | CompressedSignedRg11Eac => [16, 16, 0, 0, 0, 0],
+            Rg32f | Rg32i | Rg32ui => [32, 32, 0, 0, 0, 0],
+            R3G3B2 => [3, 3, 2, 0, 0, 0],
+            Rgb4 => [4, 4, 4, 0, 0, 0],
+            Rgb5 => [5, 5, 5, 0, 0, 0],
+            Rgb565 => [5, 6, 5, 0, 0, 0],
+            Rgb8
+            | Rgb8i
+            | Rgb8ui
+            | Rgb8Snorm
+            | Rgb
+            | Srgb
+            | Srgb8
+            | CompressedRgb
+            | CompressedSrgb
+            | CompressedRgb8Etc2
+            | CompressedSrgb8Etc2
+            | CompressedRgbS3tcDxt1Ext
+            | CompressedSrgbS3tcDxt1Ext => [8, 8, 8, 0, 0, 0],
+            Rgb10 => [10, 10, 10, 0, 0, 0],
+            Rgb12
+            | Rgb16
+            | Rgb16i
+            | Rgb16ui
+            | Rgb16f
+            | Rgb16Snorm
+            | CompressedRgbBptcSignedFloat
+            | CompressedRgbBptcUnsignedFloat => [16, 16, 16, 0, 0, 0],
+            Rgb32f | Rgb32i | Rgb32ui => [32, 32, 32, 0, 0, 0],
+            R11fG11fB10f => [11, 11, 10, 0, 0, 0],
+            Rgb9E5 => [9, 9, 9, 0, 0, 0],
+            Rgba2 => [2, 2, 2, 2, 0, 0],
+            Rgba4 => [4, 4, 4, 4, 0, 0],
+            Rgb5A1 => [5, 5, 5, 1, 0, 0],
+            Rgba8
+            | Rgba8i
+            | Rgba8ui
+            | Rgba8Snorm
+            | Rgba
+            | SrgbAlpha
+            | Srgb8Alpha8
+            | CompressedRgba
+            | CompressedSrgbAlpha
+            | CompressedRgbaBptcUnorm
+            | CompressedSrgbAlphaBptcUnorm
+            | CompressedRgb8PunchthroughAlpha1Etc2
+            | CompressedSrgb8PunchthroughAlpha1Etc2
+            | CompressedRgba8Etc2Eac
+            | CompressedSrgb8Alpha8Etc2Eac
+            | CompressedRgbaS3tcDxt1Ext
+            | CompressedRgbaS3tcDxt3Ext
+            | CompressedRgbaS3tcDxt5Ext
+            | CompressedSrgbAlphaS3tcDxt1Ext
+            | CompressedSrgbAlphaS3tcDxt3Ext
+            | CompressedSrgbAlphaS3tcDxt5Ext => [8, 8, 8, 8, 0, 0],
+            Rgb10A2 | Rgb10A2ui => [10, 10, 10, 2, 0, 0],
+            Rgba12 | Rgba16 | Rgba16i | Rgba16ui | Rgba16f | Rgba16Snorm => [16, 16, 16, 16, 0, 0],
+            Rgba32f | Rgba32i | Rgba32ui => [32, 32, 32, 32, 0, 0],
+            DepthComponent16 => [0, 0, 0, 0, 16, 0],
+            DepthComponent24 => [0, 0, 0, 0, 24, 0],
+            DepthComponent | DepthComponent32 | DepthComponent32f => [0, 0, 0, 0, 32, 0],
+            Depth24Stencil8 | DepthStencil => [0, 0, 0, 0, 24, 8],
+            Depth32fStencil8 => [0, 0, 0, 0, 32, 8],
+            StencilIndex | StencilIndex1 | StencilIndex4 | StencilIndex8 => [0, 0, 0, 0, 0, 8],
+            StencilIndex16 => [0, 0, 0, 0, 0, 16],
         }
     }
+    /// The data type of the color or depth components of this format (`GL_FLOAT`, `GL_INT`, `GL_UNSIGNED_INT`,
+    /// `GL_SIGNED_NORMALIZED` or `GL_UNSIGNED_NORMALIZED`). Stencil index formats have unsigned integer components
+    pub(crate) fn component_type(self) -> GLenum {
+        use InternalFormat::*;
+        match self {
+            R16f
+            | R32f
+            | Rg16f
+            | Rg32f
+            | Rgb16f
+            | Rgb32f
+            | Rgba16f
+            | Rgba32f
+            | R11fG11fB10f
+            | Rgb9E5
+            | CompressedRgbBptcSignedFloat
+            | CompressedRgbBptcUnsignedFloat
+            | DepthComponent
+            | DepthComponent32
+            | DepthComponent32f
+            | Depth32fStencil8 => GL_FLOAT,
+            R8Snorm
+            | R16Snorm
+            | Rg8Snorm
+            | Rg16Snorm
+            | Rgb8Snorm
+            | Rgb16Snorm
+            | Rgba8Snorm
+            | Rgba16Snorm
+            | CompressedSignedRedRgtc1
+            | CompressedSignedRgRgtc2
+            | CompressedSignedR11Eac
+            | CompressedSignedRg11Eac => GL_SIGNED_NORMALIZED,
+            R8i | R16i | R32i | Rg8i | Rg16i | Rg32i | Rgb8i | Rgb16i | Rgb32i | Rgba8i
+            | Rgba16i | Rgba32i => GL_INT,
+            v if v.is_integer() => GL_UNSIGNED_INT,
+            _ => GL_UNSIGNED_NORMALIZED,
+        }
+    }
+    /// Whether the color components of this format are sRGB encoded
+    pub(crate) fn is_srgb(self) -> bool {
+        use InternalFormat::*;
+        matches!(
+            self,
+            Srgb | Srgb8
+                | SrgbAlpha
+                | Srgb8Alpha8
+                | CompressedSrgb
+                | CompressedSrgbAlpha
+                | CompressedSrgb8Etc2
+                | CompressedSrgb8PunchthroughAlpha1Etc2
+                | CompressedSrgb8Alpha8Etc2Eac
+                | CompressedSrgbS3tcDxt1Ext
+                | CompressedSrgbAlphaS3tcDxt1Ext
+                | CompressedSrgbAlphaS3tcDxt3Ext
+                | CompressedSrgbAlphaS3tcDxt5Ext
+                | CompressedSrgbAlphaBptcUnorm
+        )
+    }
+    /// Whether buffer textures may have this format (see table 8.16 of the OpenGL 4.6 core spec)
+    pub(crate) fn is_texture_buffer_format(self) -> bool {
+        use InternalFormat::*;
+        matches!(
+            self,
+            R8 | R16
+                | R16f
+                | R32f
+                | R8i
+                | R16i
+                | R32i
+                | R8ui
+                | R16ui
+                | R32ui
+                | Rg8
+                | Rg16
+                | Rg16f
+                | Rg32f
+                | Rg8i
+                | Rg16i
+                | Rg32i
+                | Rg8ui
+                | Rg16ui
+                | Rg32ui
+                | Rgb32f
+                | Rgb32i
+                | Rgb32ui
+                | Rgba8
+                | Rgba16
+                | Rgba16f
+                | Rgba32f
+                | Rgba8i
+                | Rgba16i
+                | Rgba32i
+                | Rgba8ui
+                | Rgba16ui
+                | Rgba32ui
+        )
+    }
     pub(crate) fn is_gl_copyable(self) -> bool {
         use InternalFormat::*;
         !matches!(
@@ -544,6 +970,24 @@ impl InternalFormat {
                 | CompressedSignedRg11Eac
         )
     }
+    /// Whether glCopyImageSubData may copy between images of this format and `other`: the formats must either be the same, be
+    /// in the same [view class](Self::view_class), or be an uncompressed and a block-compressed format whose texel and block sizes match
+    pub(crate) fn is_copy_compatible(self, other: Self) -> bool {
+        if !(self.is_gl_copyable() && other.is_gl_copyable()) {
+            return false;
+        }
+        if self == other {
+            return true;
+        }
+        match (self.view_class(), other.view_class()) {
+            (Some(class), Some(other_class)) => {
+                class == other_class
+                    || (self.is_block_compressed() != other.is_block_compressed()
+                        && class.texel_size() == other_class.texel_size())
+            }
+            _ => false,
+        }
+    }
     pub(crate) fn view_class(self) -> Option<TextureViewClass> {
         // Note: Metal additionally requires that the bit length of a pixel format is one of: 8, 16, 32, 64, or 128
         use InternalFormat::*;
@@ -563,6 +1007,18 @@ impl InternalFormat {
                 Some(TextureViewClass::Bits16)
             }
             R8ui | R8i | R8 | R8Snorm => Some(TextureViewClass::Bits8),
+            CompressedRgbS3tcDxt1Ext | CompressedSrgbS3tcDxt1Ext => {
+                Some(TextureViewClass::S3tcDxt1Rgb)
+            }
+            CompressedRgbaS3tcDxt1Ext | CompressedSrgbAlphaS3tcDxt1Ext => {
+                Some(TextureViewClass::S3tcDxt1Rgba)
+            }
+            CompressedRgbaS3tcDxt3Ext | CompressedSrgbAlphaS3tcDxt3Ext => {
+                Some(TextureViewClass::S3tcDxt3Rgba)
+            }
+            CompressedRgbaS3tcDxt5Ext | CompressedSrgbAlphaS3tcDxt5Ext => {
+                Some(TextureViewClass::S3tcDxt5Rgba)
+            }
             CompressedRedRgtc1 | CompressedSignedRedRgtc1 => Some(TextureViewClass::RgtcRed),
             CompressedRgRgtc2 | CompressedSignedRgRgtc2 => Some(TextureViewClass::RgtcRg),
             CompressedRgbaBptcUnorm | CompressedSrgbAlphaBptcUnorm => {
@@ -585,8 +1041,32 @@ pub(crate) enum TextureViewClass {
     Bits24,
     Bits16,
     Bits8,
+    S3tcDxt1Rgb,
+    S3tcDxt1Rgba,
+    S3tcDxt3Rgba,
+    S3tcDxt5Rgba,
     RgtcRed,
     RgtcRg,
     BptcUnorm,
     BptcFloat,
 }
+impl TextureViewClass {
+    /// Size in bytes of a texel of the uncompressed formats in this class, or of a block of the block-compressed formats in it
+    pub(crate) fn texel_size(self) -> usize {
+        match self {
+            Self::Bits128
+            | Self::S3tcDxt3Rgba
+            | Self::S3tcDxt5Rgba
+            | Self::RgtcRg
+            | Self::BptcUnorm
+            | Self::BptcFloat => 16,
+            Self::Bits96 => 12,
+            Self::Bits64 | Self::S3tcDxt1Rgb | Self::S3tcDxt1Rgba | Self::RgtcRed => 8,
+            Self::Bits48 => 6,
+            Self::Bits32 => 4,
+            Self::Bits24 => 3,
+            Self::Bits16 => 2,
+            Self::Bits8 => 1,
+        }
+    }
+}
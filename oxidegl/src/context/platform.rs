@@ -1,4 +1,4 @@
-use std::mem;
+use std::{mem, ptr::NonNull};
 
 use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use log::{info, trace};
@@ -8,14 +8,15 @@ use objc2_foundation::{NSString, ns_string};
 use objc2_metal::{
     MTLBlitCommandEncoder, MTLColorWriteMask, MTLCommandBuffer, MTLCommandBufferDescriptor,
     MTLCommandBufferErrorOption, MTLCommandEncoder, MTLCommandQueue, MTLCompareFunction,
-    MTLCreateSystemDefaultDevice, MTLCullMode, MTLDepthStencilDescriptor, MTLDevice,
-    MTLPixelFormat, MTLRenderCommandEncoder, MTLRenderPassColorAttachmentDescriptor,
+    MTLCreateSystemDefaultDevice, MTLCullMode, MTLDepthStencilDescriptor, MTLDepthStencilState,
+    MTLDevice, MTLLoadAction, MTLPixelFormat, MTLPrimitiveType, MTLRenderCommandEncoder,
+    MTLRenderPassAttachmentDescriptor, MTLRenderPassColorAttachmentDescriptor,
     MTLRenderPassDepthAttachmentDescriptor, MTLRenderPassDescriptor,
     MTLRenderPassStencilAttachmentDescriptor, MTLRenderPipelineColorAttachmentDescriptor,
-    MTLRenderPipelineDescriptor, MTLRenderPipelineState, MTLStencilDescriptor, MTLStencilOperation,
-    MTLStorageMode, MTLTexture, MTLTextureDescriptor, MTLTextureUsage,
-    MTLVertexAttributeDescriptor, MTLVertexBufferLayoutDescriptor, MTLVertexDescriptor,
-    MTLViewport,
+    MTLRenderPipelineDescriptor, MTLRenderPipelineState, MTLScissorRect, MTLStencilDescriptor,
+    MTLStencilOperation, MTLStorageMode, MTLStoreAction, MTLTexture, MTLTextureDescriptor,
+    MTLTextureType, MTLTextureUsage, MTLVertexAttributeDescriptor, MTLVertexBufferLayoutDescriptor,
+    MTLVertexDescriptor, MTLViewport, MTLWinding,
 };
 use objc2_quartz_core::{CAMetalDrawable, CAMetalLayer, kCAFilterNearest};
 
@@ -25,17 +26,28 @@ use crate::{
         state::{Capabilities, StencilFaceState},
     },
     device_properties::MetalProperties,
-    enums::{DepthFunction, DrawBufferMode, ShaderType, StencilFunction, StencilOp, TriangleFace},
+    enums::{
+        DepthFunction, DrawBufferMode, ShaderType, StencilFunction, StencilOp, TextureTarget,
+        TriangleFace,
+    },
     util::{ProtoObjRef, bitflag_bits},
 };
 
 use super::{
     Context,
     commands::buffer::Buffer,
-    framebuffer::InternalDrawable,
+    framebuffer::{
+        AttachmentPoint, AttachmentStorage, ClearScope, ClearTarget, ClearValue,
+        FramebufferAttachment, InternalDrawable, MAX_COLOR_ATTACHMENTS, color_attachment_index,
+    },
     gl_object::{NamedObject, ObjectName},
-    program::LinkedStage,
-    state::{ColorWriteMask, DrawbufferBlendState, GLState},
+    internal_shaders::{
+        BlitParams, ClearFunction, ClearParams, InternalPipelines, PassFormats,
+        depth_stencil_aspects,
+    },
+    program::{LinkedStage, SampledImageBinding, SampledKind},
+    state::{ColorWriteMask, DrawbufferBlendState, GLState, PixelAlignedRect, TextureUnit},
+    texture::{SamplerEmulation, SamplerParams, Texture},
 };
 
 #[derive(Debug)]
@@ -83,6 +95,33 @@ pub struct PlatformState {
     /// Current render pipeline state
     pub(crate) render_pipeline_state: Option<ProtoObjRef<dyn MTLRenderPipelineState>>,
 
+    /// Size of the render area of the current render encoder, which bounds its scissor rectangle
+    pub(crate) render_target_dims: (u32, u32),
+
+    /// Number of layers rendered to by the current render encoder (1 unless the draw framebuffer is layered)
+    pub(crate) render_target_layers: u32,
+
+    /// Attachment formats of the current render encoder
+    pub(crate) render_pass_formats: PassFormats,
+
+    /// Deferred store actions of the attachments of the current render encoder
+    pub(crate) render_pass_stores: PassStoreActions,
+
+    /// Pipelines of the internal shaders used to implement framebuffer blits and clears
+    pub(crate) internal_pipelines: InternalPipelines,
+
+    /// Textures bound in place of incomplete (or missing) textures, by the type and kind of texture they stand in for
+    pub(crate) fallback_textures:
+        HashMap<(MTLTextureType, SampledKind), ProtoObjRef<dyn MTLTexture>>,
+
+    /// The (unit, target, bound texture) of each sampled texture unit that was replaced by a fallback texture during the
+    /// last draw, so that only changes in which units fall back are reported
+    pub(crate) reported_fallbacks: Vec<(u32, TextureTarget, Option<ObjectName<Texture>>)>,
+
+    /// Sampling state of the default texture objects, used for texture units that have neither a complete texture nor a
+    /// sampler object bound
+    pub(crate) default_sampling: SamplerParams,
+
     /// Mapping from buffer name to metal vertex shader argument index
     pub(crate) vertex_buffer_map: ResourceMap<Buffer, MTL_MAX_ARGUMENT_BINDINGS>,
 
@@ -103,6 +142,151 @@ pub struct PlatformState {
 
     /// Stencil buffer format of the default framebuffer
     pub(crate) stencil_format: Option<MTLPixelFormat>,
+
+    /// Number of samples per pixel of the default framebuffer's buffers (1 if it isn't multisampled). The color buffers of a
+    /// multisampled default framebuffer are all internal drawables, the front left one is resolved into the layer's drawable on swap
+    pub(crate) samples: u32,
+}
+/// Converts the scissor box `scissor` (in window coordinates) into a Metal scissor rectangle for a render target of size `dims`,
+/// clamped to its bounds. Covers the whole render target if `scissor` is None. `flip_y` is set for render targets that store their
+/// images upside down (i.e. those of the default framebuffer)
+pub(crate) fn scissor_rect(
+    scissor: Option<PixelAlignedRect>,
+    dims: (u32, u32),
+    flip_y: bool,
+) -> MTLScissorRect {
+    let Some(scissor) = scissor else {
+        return MTLScissorRect {
+            x: 0,
+            y: 0,
+            width: dims.0 as usize,
+            height: dims.1 as usize,
+        };
+    };
+    let x0 = scissor.x.min(dims.0);
+    let x1 = scissor.x.saturating_add(scissor.width).min(dims.0);
+    let y0 = scissor.y.min(dims.1);
+    let y1 = scissor.y.saturating_add(scissor.height).min(dims.1);
+    MTLScissorRect {
+        x: x0 as usize,
+        y: if flip_y { dims.1 - y1 } else { y0 } as usize,
+        width: (x1 - x0) as usize,
+        height: (y1 - y0) as usize,
+    }
+}
+/// Points the render pass attachment `a_desc` at the image stored in `storage`
+fn set_attachment_storage(a_desc: &MTLRenderPassAttachmentDescriptor, storage: &AttachmentStorage) {
+    a_desc.setTexture(Some(&storage.tex));
+    a_desc.setLevel(storage.level as usize);
+    if storage.depth_plane {
+        a_desc.setDepthPlane(storage.slice as usize);
+    } else {
+        a_desc.setSlice(storage.slice as usize);
+    }
+}
+/// Sets the load and store actions of the render pass attachment `a_desc`, which clears its image if there is a `clear` for it and
+/// preserves its contents otherwise. The clear value itself is set by the caller, since its type depends on the kind of attachment
+fn set_attachment_actions(a_desc: &MTLRenderPassAttachmentDescriptor, clear: Option<ClearValue>) {
+    a_desc.setLoadAction(if clear.is_some() {
+        MTLLoadAction::Clear
+    } else {
+        MTLLoadAction::Load
+    });
+    a_desc.setStoreAction(MTLStoreAction::Store);
+}
+/// Returns the load action of an attachment of a draw framebuffer's render pass, and the store action that it starts out with
+/// (see [`PassStoreActions`]). Like [`set_attachment_actions`], except that the contents of an `invalidated` image are neither
+/// loaded nor stored (unless it's cleared)
+fn pass_attachment_actions(
+    clear: Option<ClearValue>,
+    invalidated: bool,
+) -> (MTLLoadAction, MTLStoreAction) {
+    match (clear, invalidated) {
+        (Some(_), _) => (MTLLoadAction::Clear, MTLStoreAction::Store),
+        (None, true) => (MTLLoadAction::DontCare, MTLStoreAction::DontCare),
+        (None, false) => (MTLLoadAction::Load, MTLStoreAction::Store),
+    }
+}
+/// Sets up the actions of the render pass attachment `a_desc` of a draw framebuffer's render pass (see
+/// [`pass_attachment_actions`]), deferring its store action. Returns the store action that it starts out with
+fn set_pass_attachment_actions(
+    a_desc: &MTLRenderPassAttachmentDescriptor,
+    clear: Option<ClearValue>,
+    invalidated: bool,
+) -> MTLStoreAction {
+    let (load, store) = pass_attachment_actions(clear, invalidated);
+    a_desc.setLoadAction(load);
+    a_desc.setStoreAction(MTLStoreAction::Unknown);
+    store
+}
+/// Store actions of the attachments of the current render encoder (None for those that aren't attached). The render pass is
+/// created with unknown store actions which are only set when it ends, so that the contents of attachments that were invalidated
+/// (and not rendered to since) don't have to be stored
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct PassStoreActions {
+    pub(crate) color: [Option<MTLStoreAction>; MAX_COLOR_ATTACHMENTS as usize],
+    pub(crate) depth: Option<MTLStoreAction>,
+    pub(crate) stencil: Option<MTLStoreAction>,
+}
+impl PassStoreActions {
+    fn actions_mut(&mut self) -> impl Iterator<Item = &mut MTLStoreAction> {
+        self.color
+            .iter_mut()
+            .chain([&mut self.depth, &mut self.stencil])
+            .flatten()
+    }
+    /// Stores the contents of every attachment, e.g. because they were rendered to
+    pub(crate) fn store_all(&mut self) {
+        for action in self.actions_mut() {
+            *action = MTLStoreAction::Store;
+        }
+    }
+    /// Discards the contents of the color attachments (by index), depth and stencil attachments that are set in `color`, `depth`
+    /// and `stencil`, e.g. because they were invalidated
+    pub(crate) fn discard(
+        &mut self,
+        color: [bool; MAX_COLOR_ATTACHMENTS as usize],
+        depth: bool,
+        stencil: bool,
+    ) {
+        let discarded = color.into_iter().chain([depth, stencil]);
+        let actions = self
+            .color
+            .iter_mut()
+            .chain([&mut self.depth, &mut self.stencil]);
+        for (action, discard) in actions.zip(discarded) {
+            if let Some(action) = action.as_mut().filter(|_| discard) {
+                *action = MTLStoreAction::DontCare;
+            }
+        }
+    }
+    /// Returns the store action of each attachment of the render pass
+    fn actions(&self) -> impl Iterator<Item = (AttachmentPoint, MTLStoreAction)> + '_ {
+        let color = (0..MAX_COLOR_ATTACHMENTS).map(AttachmentPoint::Color);
+        color
+            .chain([AttachmentPoint::Depth, AttachmentPoint::Stencil])
+            .zip(self.color.iter().chain([&self.depth, &self.stencil]))
+            .filter_map(|(point, action)| Some((point, (*action)?)))
+    }
+    /// Sets the store actions of `enc`, which must have been created with unknown store actions for these attachments
+    fn apply(&self, enc: &ProtoObjRef<dyn MTLRenderCommandEncoder>) {
+        for (point, action) in self.actions() {
+            match point {
+                // Safety: the color attachment at `idx` exists, since it has a store action
+                AttachmentPoint::Color(idx) => unsafe {
+                    enc.setColorStoreAction_atIndex(action, idx as usize);
+                },
+                AttachmentPoint::Depth => enc.setDepthStoreAction(action),
+                AttachmentPoint::Stencil => enc.setStencilStoreAction(action),
+                AttachmentPoint::DepthStencil => unreachable!(),
+            }
+        }
+    }
+}
+/// Converts a GL stencil clear value into a Metal one. GL masks the value to the bits of the stencil buffer, which are always 8 for
+/// Metal stencil formats
+fn stencil_clear_value(stencil: i32) -> u32 {
+    stencil.cast_unsigned() & 0xFF
 }
 #[derive(Default, Debug, Clone)]
 pub struct InternalDrawables {
@@ -140,6 +324,13 @@ impl Dirty {
     pub(crate) fn unset(&mut self, other: Self) {
         *self = self.difference(other);
     }
+    /// Whether presenting has to bring the render encoder up to date first (given whether one is open). A new render pass that
+    /// is merely pending, e.g. because attachments of the draw framebuffer were invalidated, has nothing to render and isn't
+    /// begun, since it would only load and store the invalidated attachments
+    #[inline]
+    pub(crate) fn update_before_present(self, encoder_open: bool) -> bool {
+        !encoder_open || !self.any_set(Self::NEW_RENDER_ENCODER)
+    }
 }
 impl Context {
     #[inline]
@@ -247,10 +438,19 @@ impl PlatformState {
         trace!("injected layer {:?} into NSView", &self.layer);
     }
     pub(crate) fn swap_buffers(&mut self, state: &mut GLState) {
-        self.update_state(state, false);
+        if self
+            .dirty_state
+            .update_before_present(self.render_encoder.is_some())
+        {
+            self.update_state(state, false);
+        }
 
         self.end_encoding();
 
+        if self.samples > 1 {
+            self.resolve_default_framebuffer();
+        }
+
         if let Some(drawable) = self.drawable.take() {
             self.current_command_buffer()
                 .presentDrawable(drawable.as_ref());
@@ -263,6 +463,7 @@ impl PlatformState {
         pixel_format: MTLPixelFormat,
         depth_format: Option<MTLPixelFormat>,
         stencil_format: Option<MTLPixelFormat>,
+        samples: u32,
     ) -> Self {
         let device = MTLCreateSystemDefaultDevice().unwrap();
 
@@ -283,6 +484,10 @@ impl PlatformState {
         queue.setLabel(Some(ns_string!("OxideGL command queue")));
 
         let props = MetalProperties::new(&device);
+        let samples = props
+            .sample_count_at_least(samples)
+            .unwrap_or_else(|| props.max_sample_count());
+        info!("default framebuffer sample count: {samples}");
         Self {
             dirty_state: Dirty::all(),
 
@@ -300,6 +505,14 @@ impl PlatformState {
 
             render_encoder: None,
             render_pipeline_state: None,
+            render_target_dims: (0, 0),
+            render_target_layers: 1,
+            render_pass_formats: PassFormats::default(),
+            render_pass_stores: PassStoreActions::default(),
+            internal_pipelines: InternalPipelines::new(),
+            fallback_textures: HashMap::new(),
+            reported_fallbacks: Vec::new(),
+            default_sampling: SamplerParams::default(),
 
             vertex_buffer_map: ResourceMap::new(),
             vertex_buffer_offsets: HashMap::new(),
@@ -309,6 +522,7 @@ impl PlatformState {
             pixel_format,
             depth_format,
             stencil_format,
+            samples,
         }
     }
     #[inline]
@@ -357,10 +571,362 @@ impl PlatformState {
     #[inline]
     pub(crate) fn end_encoding(&mut self) {
         if let Some(enc) = &self.render_encoder {
+            mem::take(&mut self.render_pass_stores).apply(enc);
             enc.endEncoding();
         }
         self.render_encoder = None;
     }
+    /// Runs `f` with a blit command encoder that encodes into the current command buffer. Ends the current render encoder first so that
+    /// the blit commands are ordered correctly relative to any previously encoded rendering commands
+    pub(crate) fn encode_blit(&mut self, f: impl FnOnce(&ProtoObjRef<dyn MTLBlitCommandEncoder>)) {
+        self.end_encoding();
+        let enc = self
+            .current_command_buffer()
+            .blitCommandEncoder()
+            .expect("failed to create blit command encoder");
+        #[cfg(debug_assertions)]
+        enc.setLabel(Some(ns_string!("OxideGL blit encoder")));
+        f(&enc);
+        enc.endEncoding();
+    }
+    /// Encodes a render pass that only performs the load and store actions of `desc` (e.g. clearing its attachments) into the current
+    /// command buffer. Ends the current render encoder first, like [`encode_blit`](Self::encode_blit)
+    pub(crate) fn encode_render_pass(&mut self, desc: &MTLRenderPassDescriptor) {
+        self.end_encoding();
+        let enc = self
+            .current_command_buffer()
+            .renderCommandEncoderWithDescriptor(desc)
+            .expect("failed to create render command encoder");
+        #[cfg(debug_assertions)]
+        enc.setLabel(Some(ns_string!("OxideGL render pass encoder")));
+        enc.endEncoding();
+    }
+    /// Encodes a render pass with the attachments of `desc` that draws the destination rectangle of `params` with `pipeline` (one of
+    /// the [`internal pipelines`](InternalPipelines)), reading from `texture`. Ends the current render encoder first, like
+    /// [`encode_blit`](Self::encode_blit)
+    pub(crate) fn encode_internal_draw(
+        &mut self,
+        desc: &MTLRenderPassDescriptor,
+        pipeline: &ProtoObjRef<dyn MTLRenderPipelineState>,
+        depth_stencil: Option<&ProtoObjRef<dyn MTLDepthStencilState>>,
+        scissor: MTLScissorRect,
+        params: &BlitParams,
+        texture: &ProtoObjRef<dyn MTLTexture>,
+    ) {
+        self.end_encoding();
+        let enc = self
+            .current_command_buffer()
+            .renderCommandEncoderWithDescriptor(desc)
+            .expect("failed to create render command encoder");
+        #[cfg(debug_assertions)]
+        enc.setLabel(Some(ns_string!("OxideGL internal draw encoder")));
+        enc.setRenderPipelineState(pipeline);
+        if let Some(ds) = depth_stencil {
+            enc.setDepthStencilState(Some(ds));
+        }
+        enc.setScissorRect(scissor);
+        // Safety: `params` is a live reference to a value of the size passed as the length, index 0 is the parameter buffer of
+        // the internal vertex function and `texture` matches the kind of texture the pipeline's fragment function expects
+        unsafe {
+            enc.setVertexBytes_length_atIndex(
+                NonNull::from(params).cast(),
+                size_of::<BlitParams>(),
+                0,
+            );
+            enc.setFragmentTexture_atIndex(Some(texture), 0);
+            enc.drawPrimitives_vertexStart_vertexCount(MTLPrimitiveType::TriangleStrip, 0, 4);
+        }
+        enc.endEncoding();
+    }
+    /// Ends the current render encoder and begins the render pass of the current draw framebuffer, folding the clears recorded for
+    /// its images into the load actions of their attachments
+    pub(crate) fn begin_render_pass(&mut self, state: &mut GLState) {
+        gl_trace!("beginning render pass to apply clears");
+        self.end_encoding();
+        self.render_encoder = Some(self.build_render_encoder(state));
+        self.dirty_state.unset(Dirty::NEW_RENDER_ENCODER);
+        self.dirty_state.set_bits(Dirty::UPDATE_RENDER_ENCODER);
+    }
+    /// Encodes a render pass that clears `image` to `value`, for images that aren't rendered to by the render pass of the current
+    /// draw framebuffer. The clear is performed by the pass's load action if it covers the whole image (according to `scope`), and
+    /// drawn with an internal clear pipeline otherwise. Ends the current render encoder first, like [`encode_blit`](Self::encode_blit)
+    pub(crate) fn encode_clear_pass(
+        &mut self,
+        image: &AttachmentStorage,
+        value: ClearValue,
+        scope: &ClearScope,
+    ) {
+        let whole = scope.is_whole(image.width, image.height);
+        let clear = whole.then_some(value);
+        let desc = MTLRenderPassDescriptor::renderPassDescriptor();
+        let setup = |a_desc: &MTLRenderPassAttachmentDescriptor, clear: Option<ClearValue>| {
+            set_attachment_storage(a_desc, image);
+            set_attachment_actions(a_desc, clear);
+        };
+        let format = image.tex.pixelFormat();
+        let mut formats = PassFormats {
+            samples: image.samples as usize,
+            ..PassFormats::default()
+        };
+        // the aspect of a packed depth stencil image that isn't cleared is attached as well, preserving its contents
+        let packed = depth_stencil_aspects(format) == (true, true);
+        let target = match value {
+            ClearValue::Depth(depth) => {
+                let a_desc = desc.depthAttachment();
+                setup(&a_desc, clear);
+                a_desc.setClearDepth(depth.into());
+                formats.depth = format;
+                if packed {
+                    setup(&desc.stencilAttachment(), None);
+                    formats.stencil = format;
+                }
+                ClearTarget::Depth
+            }
+            ClearValue::Stencil(stencil) => {
+                let a_desc = desc.stencilAttachment();
+                setup(&a_desc, clear);
+                a_desc.setClearStencil(stencil_clear_value(stencil));
+                formats.stencil = format;
+                if packed {
+                    setup(&desc.depthAttachment(), None);
+                    formats.depth = format;
+                }
+                ClearTarget::Stencil
+            }
+            color => {
+                let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
+                setup(&a_desc, clear);
+                a_desc.setClearColor(color.clear_color());
+                formats.color[0] = format;
+                // Safety: every render pass has a color attachment at index 0
+                unsafe {
+                    desc.colorAttachments()
+                        .setObject_atIndexedSubscript(Some(&a_desc), 0);
+                }
+                ClearTarget::Color(0)
+            }
+        };
+        if image.layers > 1 {
+            desc.setRenderTargetArrayLength(image.layers as usize);
+        }
+        if whole {
+            self.encode_render_pass(&desc);
+            return;
+        }
+        self.end_encoding();
+        let enc = self
+            .current_command_buffer()
+            .renderCommandEncoderWithDescriptor(&desc)
+            .expect("failed to create render command encoder");
+        #[cfg(debug_assertions)]
+        enc.setLabel(Some(ns_string!("OxideGL clear encoder")));
+        self.encode_clear_draw(
+            &enc,
+            &formats,
+            (image.width, image.height),
+            image.layers,
+            target,
+            value,
+            scope,
+        );
+        enc.endEncoding();
+    }
+    /// Clears the attachment of the current render pass selected by `target` to `value` within `scope` by drawing over its render
+    /// area (in every layer) with an internal clear pipeline. This replaces dynamic state of the render encoder, which has to be
+    /// marked for updating afterwards
+    pub(crate) fn draw_clear(
+        &mut self,
+        target: ClearTarget,
+        value: ClearValue,
+        scope: &ClearScope,
+    ) {
+        let enc = self.current_render_encoder().clone();
+        let formats = self.render_pass_formats;
+        self.encode_clear_draw(
+            &enc,
+            &formats,
+            self.render_target_dims,
+            self.render_target_layers,
+            target,
+            value,
+            scope,
+        );
+        self.render_pass_stores.store_all();
+    }
+    /// Encodes a draw into `enc` (with a render area of size `dims` and `layers` layers, and attachments of `formats`) that clears
+    /// its attachment selected by `target` to `value` within `scope`
+    #[expect(clippy::too_many_arguments)]
+    fn encode_clear_draw(
+        &mut self,
+        enc: &ProtoObjRef<dyn MTLRenderCommandEncoder>,
+        formats: &PassFormats,
+        dims: (u32, u32),
+        layers: u32,
+        target: ClearTarget,
+        value: ClearValue,
+        scope: &ClearScope,
+    ) {
+        let scissor = scissor_rect(scope.scissor, dims, scope.flip_y);
+        if scissor.width == 0 || scissor.height == 0 {
+            return;
+        }
+        let (function, color, depth) = match (target, value) {
+            (ClearTarget::Color(idx), ClearValue::Float(color)) => {
+                (ClearFunction::Float(idx), color, 0.0)
+            }
+            (ClearTarget::Color(idx), ClearValue::Int(color)) => (
+                ClearFunction::Int(idx),
+                color.map(|c| f32::from_bits(c.cast_unsigned())),
+                0.0,
+            ),
+            (ClearTarget::Color(idx), ClearValue::Uint(color)) => {
+                (ClearFunction::Uint(idx), color.map(f32::from_bits), 0.0)
+            }
+            (ClearTarget::Depth, ClearValue::Depth(depth)) => {
+                (ClearFunction::DepthStencil, [0.0; 4], depth)
+            }
+            (ClearTarget::Stencil, ClearValue::Stencil(_)) => {
+                (ClearFunction::DepthStencil, [0.0; 4], 0.0)
+            }
+            _ => unreachable!("{target:?} can't be cleared to {value:?}"),
+        };
+        // both the depth and the stencil test always pass, replacing the cleared values
+        let ds_desc = MTLDepthStencilDescriptor::new();
+        match value {
+            ClearValue::Depth(_) => ds_desc.setDepthWriteEnabled(true),
+            ClearValue::Stencil(_) => {
+                let stencil = MTLStencilDescriptor::new();
+                stencil.setDepthStencilPassOperation(MTLStencilOperation::Replace);
+                stencil.setWriteMask(scope.stencil_mask);
+                ds_desc.setFrontFaceStencil(Some(&stencil));
+                ds_desc.setBackFaceStencil(Some(&stencil));
+            }
+            _ => {}
+        }
+        let ds_state = self
+            .device
+            .newDepthStencilStateWithDescriptor(&ds_desc)
+            .expect("failed to create MTLDepthStencilState");
+        let pipeline = self.internal_pipelines.clear_pipeline(
+            &self.device,
+            function,
+            scope.color_mask,
+            formats,
+        );
+        let params = ClearParams { color, depth };
+        enc.setRenderPipelineState(&pipeline);
+        enc.setDepthStencilState(Some(&ds_state));
+        if let ClearValue::Stencil(stencil) = value {
+            enc.setStencilReferenceValue(stencil_clear_value(stencil));
+        }
+        enc.setCullMode(MTLCullMode::None);
+        enc.setViewport(MTLViewport {
+            originX: 0.0,
+            originY: 0.0,
+            width: dims.0.into(),
+            height: dims.1.into(),
+            znear: 0.0,
+            zfar: 1.0,
+        });
+        enc.setScissorRect(scissor);
+        // Safety: `params` is a live reference to a value of the size passed as the length, index 0 is the parameter buffer of the
+        // internal clear functions and the pipeline's vertex function selects the layer from the instance index
+        unsafe {
+            enc.setVertexBytes_length_atIndex(
+                NonNull::from(&params).cast(),
+                size_of::<ClearParams>(),
+                0,
+            );
+            enc.setFragmentBytes_length_atIndex(
+                NonNull::from(&params).cast(),
+                size_of::<ClearParams>(),
+                0,
+            );
+            enc.drawPrimitives_vertexStart_vertexCount_instanceCount(
+                MTLPrimitiveType::TriangleStrip,
+                0,
+                4,
+                layers as usize,
+            );
+        }
+    }
+    /// Resolves the front left color buffer of a multisampled default framebuffer into the current drawable, so that it can be
+    /// presented
+    fn resolve_default_framebuffer(&mut self) {
+        let drawable_tex = self.current_drawable().texture();
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "drawable dimensions fit in a u32"
+        )]
+        let dims = (drawable_tex.width() as u32, drawable_tex.height() as u32);
+        let tex = self
+            .get_internal_drawbuffer(DrawBufferMode::FrontLeft, dims)
+            .tex
+            .clone();
+        gl_trace!("resolving multisampled default framebuffer into the drawable");
+        self.encode_resolve(&tex, &drawable_tex);
+    }
+    /// Resolves the multisampled image `tex` into a new single sampled texture, e.g. for reading back its contents
+    pub(crate) fn resolve_texture(
+        &mut self,
+        tex: &ProtoObjRef<dyn MTLTexture>,
+    ) -> ProtoObjRef<dyn MTLTexture> {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "texture dimensions fit in a u32"
+        )]
+        let dims = (tex.width() as u32, tex.height() as u32);
+        let resolved =
+            Self::new_drawbuffer_size_format(&self.device, dims, tex.pixelFormat(), 1, true);
+        self.encode_resolve(tex, &resolved);
+        resolved
+    }
+    /// Encodes a render pass that resolves the multisampled image `src` into `dst` (which must have the same size and pixel format)
+    /// with the pass's store action. Depth and stencil values are resolved by picking those of the first sample (Metal's default
+    /// resolve filters)
+    fn encode_resolve(
+        &mut self,
+        src: &ProtoObjRef<dyn MTLTexture>,
+        dst: &ProtoObjRef<dyn MTLTexture>,
+    ) {
+        let desc = MTLRenderPassDescriptor::renderPassDescriptor();
+        let setup = |a_desc: &MTLRenderPassAttachmentDescriptor| {
+            a_desc.setTexture(Some(src));
+            a_desc.setResolveTexture(Some(dst));
+            a_desc.setLoadAction(MTLLoadAction::Load);
+            a_desc.setStoreAction(MTLStoreAction::MultisampleResolve);
+        };
+        match depth_stencil_aspects(src.pixelFormat()) {
+            (false, false) => {
+                let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
+                setup(&a_desc);
+                // Safety: every render pass has a color attachment at index 0
+                unsafe {
+                    desc.colorAttachments()
+                        .setObject_atIndexedSubscript(Some(&a_desc), 0);
+                }
+            }
+            (depth, stencil) => {
+                if depth {
+                    setup(&desc.depthAttachment());
+                }
+                if stencil {
+                    setup(&desc.stencilAttachment());
+                }
+            }
+        }
+        self.encode_render_pass(&desc);
+    }
+    /// Commits the current command buffer and blocks until the GPU has finished executing it, making the results of all previously
+    /// encoded commands visible to the CPU
+    pub(crate) fn finish(&mut self) {
+        self.end_encoding();
+        if let Some(buf) = self.command_buffer.take() {
+            gl_trace!("waiting for GPU to finish executing the current command buffer");
+            buf.commit();
+            buf.waitUntilCompleted();
+        }
+    }
     //TODO: use onresized or something for updating drawable size instead of effectively polling every frame
     #[inline]
     #[track_caller]
@@ -415,6 +981,11 @@ impl PlatformState {
             self.render_encoder = Some(self.build_render_encoder(state));
             self.dirty_state.unset(Dirty::NEW_RENDER_ENCODER);
         }
+        if is_draw_command {
+            // the draw renders to the attachments of the current render pass, whose contents have to be stored even if they were
+            // invalidated before
+            self.render_pass_stores.store_all();
+        }
         // this code path is taken if we have a new encoder and need to finish initializing it, or if we just need to update the dynamic state of the current encoder
         if all_dirty.any_set(Dirty::UPDATE_RENDER_ENCODER | Dirty::NEW_RENDER_ENCODER) {
             self.update_encoder(state);
@@ -428,6 +999,7 @@ impl PlatformState {
         let ps = self.render_pipeline_state.as_ref().unwrap();
         let enc = self.render_encoder.as_ref().unwrap();
         enc.setRenderPipelineState(ps);
+        self.bind_textures_to_render_encoder(state);
     }
     //preconditions: buffer maps built, renderable program present
     pub(crate) fn build_render_pipeline_state(
@@ -446,8 +1018,36 @@ impl PlatformState {
             );
         };
         let attachments = desc.colorAttachments();
-        if state.framebuffer_binding.is_some() {
-            todo!()
+        if let Some(fb) = state.draw_framebuffer_binding {
+            let framebuffer = state.framebufer_list.get(fb);
+            let mut samples = 1;
+            for (i, mode) in framebuffer.draw_buffers.modes.iter().enumerate() {
+                let Some(storage) = mode
+                    .and_then(|mode| color_attachment_index(mode.into()))
+                    .and_then(|idx| framebuffer.color_attachments[idx as usize])
+                    .and_then(|a| a.storage(state))
+                else {
+                    continue;
+                };
+                let attachment_desc = unsafe { MTLRenderPipelineColorAttachmentDescriptor::new() };
+                attachment_desc.setPixelFormat(storage.tex.pixelFormat());
+                state.blend.drawbuffer_states[i].apply_to_mtl_desc(&attachment_desc);
+                unsafe { attachments.setObject_atIndexedSubscript(Some(&attachment_desc), i) };
+                samples = storage.samples;
+            }
+            if let Some(storage) = framebuffer.depth_attachment.and_then(|a| a.storage(state)) {
+                desc.setDepthAttachmentPixelFormat(storage.tex.pixelFormat());
+                samples = storage.samples;
+            }
+            if let Some(storage) = framebuffer
+                .stencil_attachment
+                .and_then(|a| a.storage(state))
+            {
+                desc.setStencilAttachmentPixelFormat(storage.tex.pixelFormat());
+                samples = storage.samples;
+            }
+            // completeness guarantees that every attachment has the same number of samples
+            desc.setRasterSampleCount(samples as usize);
         } else {
             for (i, mode) in state.default_draw_buffers.modes.iter().enumerate() {
                 if mode.is_some() {
@@ -467,9 +1067,14 @@ impl PlatformState {
                 );
             }
             //TODO depth/stencil attachment formats
+            desc.setRasterSampleCount(self.samples as usize);
         }
-        desc.setVertexFunction(Some(&v.function));
-        desc.setFragmentFunction(Some(&f.function));
+        desc.setVertexFunction(Some(
+            &v.specialized_function(&Self::shader_emulation(state, v)),
+        ));
+        desc.setFragmentFunction(Some(
+            &f.specialized_function(&Self::shader_emulation(state, f)),
+        ));
         //TODO: primitive topology real
         // unsafe { desc.setInputPrimitiveTopology(MTLPrimitiveTopologyClass::Triangle) };
         let v_desc = self.build_vertex_descriptor(state);
@@ -497,39 +1102,43 @@ impl PlatformState {
             desc
         }
         self.bind_buffers_to_render_encoder(state);
-        let enc;
-        if state
-            .caps
-            .is_any_enabled(Capabilities::DEPTH_TEST | Capabilities::STENCIL_TEST)
-        {
-            let desc = unsafe { MTLDepthStencilDescriptor::new() };
-            if state.caps.is_any_enabled(Capabilities::DEPTH_TEST) {
-                desc.setDepthCompareFunction(state.depth_func.into());
-                desc.setDepthWriteEnabled(state.writemasks.depth);
-            }
-            if state.caps.is_any_enabled(Capabilities::STENCIL_TEST) {
-                let front = stencil_descriptor_for_stencil_state(
-                    &state.stencil.front,
-                    state.writemasks.stencil_front,
-                );
-                desc.setFrontFaceStencil(Some(&front));
-                let back = stencil_descriptor_for_stencil_state(
-                    &state.stencil.back,
-                    state.writemasks.stencil_back,
-                );
-                desc.setBackFaceStencil(Some(&back));
-            }
-            let ds_state = self
-                .device
-                .newDepthStencilStateWithDescriptor(&desc)
-                .expect("failed to create MTLDepthStencilState");
-            enc = self.current_render_encoder();
-            enc.setDepthStencilState(Some(&ds_state));
-        } else {
-            enc = self.current_render_encoder();
+        let scissor = scissor_rect(
+            state.scissor_test.then_some(state.scissor_box),
+            self.render_target_dims,
+            state.draw_framebuffer_binding.is_none(),
+        );
+        // the depth stencil state is always set (even with both tests disabled) since internal clears may have replaced it
+        let desc = unsafe { MTLDepthStencilDescriptor::new() };
+        if state.caps.is_any_enabled(Capabilities::DEPTH_TEST) {
+            desc.setDepthCompareFunction(state.depth_func.into());
+            desc.setDepthWriteEnabled(state.writemasks.depth);
         }
+        if state.caps.is_any_enabled(Capabilities::STENCIL_TEST) {
+            let front = stencil_descriptor_for_stencil_state(
+                &state.stencil.front,
+                state.writemasks.stencil_front,
+            );
+            desc.setFrontFaceStencil(Some(&front));
+            let back = stencil_descriptor_for_stencil_state(
+                &state.stencil.back,
+                state.writemasks.stencil_back,
+            );
+            desc.setBackFaceStencil(Some(&back));
+        }
+        let ds_state = self
+            .device
+            .newDepthStencilStateWithDescriptor(&desc)
+            .expect("failed to create MTLDepthStencilState");
+        let enc = self.current_render_encoder();
+        enc.setDepthStencilState(Some(&ds_state));
+        enc.setStencilFrontReferenceValue_backReferenceValue(
+            state.stencil.front.reference,
+            state.stencil.back.reference,
+        );
         if state.caps.is_any_enabled(Capabilities::CULL_FACE) {
             enc.setCullMode(state.cull_face_mode.into());
+        } else {
+            enc.setCullMode(MTLCullMode::None);
         }
 
         // we *could* set this only when blending is actually enabled, but that's done on a per-attachment basis anyways (and
@@ -542,16 +1151,31 @@ impl PlatformState {
             blend_col[3],
         );
 
-        // TODO scissor test
+        enc.setScissorRect(scissor);
+
         #[expect(
             clippy::cast_lossless,
             reason = "pixel aligned rect values are always 32 bits, and as such are exactly representable as f64"
         )]
+        let [x, y, width, height] = [
+            state.viewport.x as f64,
+            state.viewport.y as f64,
+            state.viewport.width as f64,
+            state.viewport.height as f64,
+        ];
+        // framebuffer objects store their images in GL row order, which is upside down from Metal's point of view. Flip the
+        // viewport so that rendered images come out the right way up, which also reverses the winding of every primitive
+        let (origin_y, height, winding) = if state.draw_framebuffer_binding.is_some() {
+            (y + height, -height, MTLWinding::CounterClockwise)
+        } else {
+            (y, height, MTLWinding::Clockwise)
+        };
+        enc.setFrontFacingWinding(winding);
         enc.setViewport(MTLViewport {
-            originX: state.viewport.x as f64,
-            originY: state.viewport.y as f64,
-            width: state.viewport.width as f64,
-            height: state.viewport.height as f64,
+            originX: x,
+            originY: origin_y,
+            width,
+            height,
             // TODO: depth range
             znear: 0.0,
             zfar: 1.0,
@@ -573,6 +1197,121 @@ impl PlatformState {
             }
         }
     }
+    /// Binds the texture and sampler state of the texture units sampled by the current program's stages to the argument
+    /// table slots the stages expect them in. Units without a complete texture bound to the sampled target get the
+    /// matching fallback texture instead (see [`Context::prepare_sampled_textures`])
+    fn bind_textures_to_render_encoder(&mut self, state: &mut GLState) {
+        let enc = self.render_encoder.as_ref().unwrap();
+        for shader_type in [ShaderType::VertexShader, ShaderType::FragmentShader] {
+            // index into the stage's bindings instead of iterating them, since sampled textures are created lazily through a
+            // mutable reference to the state they are borrowed from
+            for idx in 0.. {
+                let Some(binding) = Self::sampled_image_binding(state, shader_type, idx) else {
+                    break;
+                };
+                let unit = state
+                    .texture_units
+                    .units
+                    .get(binding.unit as usize)
+                    .copied()
+                    .unwrap_or_default();
+                let complete = Self::complete_unit_texture(state, &binding).and_then(|name| {
+                    Some((name, state.texture_list.get_mut(name).sampled_texture()?))
+                });
+                let tex = if let Some((_, tex)) = &complete {
+                    tex.clone()
+                } else if let Some(fallback) = self
+                    .fallback_textures
+                    .get(&(binding.target.into(), binding.kind))
+                {
+                    fallback.clone()
+                } else {
+                    continue;
+                };
+                let sampler = match (unit.sampler, complete) {
+                    (Some(sampler), _) => {
+                        state.sampler_list.get(sampler).sampler_state(&self.device)
+                    }
+                    (None, Some((name, _))) => state
+                        .texture_list
+                        .get(name)
+                        .sampling_state
+                        .sampler_state(&self.device, None),
+                    (None, None) => self.default_sampling.sampler_state(&self.device, None),
+                };
+                gl_trace!(
+                    "binding texture unit {} to {shader_type:?} texture index {}",
+                    binding.unit,
+                    binding.texture_index
+                );
+                let (tex_idx, smp_idx) = (
+                    binding.texture_index as usize,
+                    binding.sampler_index.map(|idx| idx as usize),
+                );
+                // Safety: indices come from the reflected argument table layout of the stage
+                unsafe {
+                    if shader_type == ShaderType::VertexShader {
+                        enc.setVertexTexture_atIndex(Some(&tex), tex_idx);
+                        if let Some(idx) = smp_idx {
+                            enc.setVertexSamplerState_atIndex(Some(&sampler), idx);
+                        }
+                    } else {
+                        enc.setFragmentTexture_atIndex(Some(&tex), tex_idx);
+                        if let Some(idx) = smp_idx {
+                            enc.setFragmentSamplerState_atIndex(Some(&sampler), idx);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    /// Returns the texture bound to the texture unit and target sampled through `binding`, if there is one and it is complete
+    /// under the unit's sampling state
+    pub(crate) fn complete_unit_texture(
+        state: &GLState,
+        binding: &SampledImageBinding,
+    ) -> Option<ObjectName<Texture>> {
+        let unit = state.texture_units.units.get(binding.unit as usize)?;
+        let name = state
+            .texture_units
+            .texture(binding.unit as usize, binding.target)?;
+        let tex = state.texture_list.get(name);
+        tex.is_complete(Self::unit_sampling_state(state, unit, tex))
+            .then_some(name)
+    }
+    /// Returns the sampling state used when sampling `texture` through the texture unit `unit`. A sampler object bound
+    /// to the unit supersedes the texture's own sampling state
+    fn unit_sampling_state<'a>(
+        state: &'a GLState,
+        unit: &TextureUnit,
+        texture: &'a Texture,
+    ) -> &'a SamplerParams {
+        unit.sampler.map_or(&texture.sampling_state, |sampler| {
+            &state.sampler_list.get(sampler).params
+        })
+    }
+    /// Gathers the shader-side sampler emulation state of `linked` from the texture units it samples, as expected by
+    /// [`LinkedStage::specialized_function`]
+    fn shader_emulation(state: &GLState, linked: &LinkedStage) -> Vec<(u32, SamplerEmulation)> {
+        let mut emulation = linked
+            .resources
+            .sampled_image_bindings
+            .iter()
+            .filter_map(|binding| {
+                let unit = state.texture_units.units.get(binding.unit as usize)?;
+                let tex = state.texture_list.get(
+                    state
+                        .texture_units
+                        .texture(binding.unit as usize, binding.target)?,
+                );
+                let border = Self::unit_sampling_state(state, unit, tex).shader_emulation()?;
+                Some((binding.unit, border))
+            })
+            .collect::<Vec<_>>();
+        emulation.sort_unstable_by_key(|&(unit, _)| unit);
+        emulation.dedup_by_key(|&mut (unit, _)| unit);
+        emulation
+    }
     #[expect(
         clippy::cast_possible_truncation,
         clippy::cast_sign_loss,
@@ -603,9 +1342,106 @@ impl PlatformState {
     ) -> ProtoObjRef<dyn MTLRenderCommandEncoder> {
         let desc = unsafe { MTLRenderPassDescriptor::new() };
 
-        if let Some(_) = state.framebuffer_binding {
+        if let Some(fb) = state.draw_framebuffer_binding {
             // user-defined FBO
-            todo!()
+            // clears and invalidations recorded since the last render pass are folded into the load actions of this one
+            let framebuffer = state.framebufer_list.get_mut(fb);
+            let take =
+                |a: &mut FramebufferAttachment| (a.clear.take(), mem::take(&mut a.invalidated));
+            let color_loads = framebuffer.draw_buffers.modes.map(|mode| {
+                let idx = color_attachment_index(mode?.into())?;
+                framebuffer.color_attachments[idx as usize]
+                    .as_mut()
+                    .map(take)
+            });
+            let depth_load = framebuffer.depth_attachment.as_mut().map(take);
+            let stencil_load = framebuffer.stencil_attachment.as_mut().map(take);
+            let framebuffer = state.framebufer_list.get(fb);
+            // attachments that are missing storage (e.g. levels outside of their texture's consistent mipmap chain) are
+            // left out of the render pass
+            let color = framebuffer
+                .draw_buffers
+                .modes
+                .iter()
+                .enumerate()
+                .filter_map(|(i, mode)| {
+                    let idx = color_attachment_index((*mode)?.into())?;
+                    Some((
+                        i,
+                        framebuffer.color_attachments[idx as usize]?.storage(state)?,
+                    ))
+                });
+            let depth = framebuffer.depth_attachment.and_then(|a| a.storage(state));
+            let stencil = framebuffer
+                .stencil_attachment
+                .and_then(|a| a.storage(state));
+            // the render area is the intersection of the attached images
+            let mut formats = PassFormats::default();
+            let mut stores = PassStoreActions::default();
+            let mut dims: Option<(u32, u32, u32)> = None;
+            let mut samples = 1;
+            let mut fit = |storage: &AttachmentStorage| {
+                let (width, height, layers) = dims.unwrap_or((u32::MAX, u32::MAX, u32::MAX));
+                dims = Some((
+                    width.min(storage.width),
+                    height.min(storage.height),
+                    layers.min(storage.layers),
+                ));
+                samples = storage.samples;
+            };
+            for (i, storage) in color {
+                fit(&storage);
+                formats.color[i] = storage.tex.pixelFormat();
+                let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
+                set_attachment_storage(&a_desc, &storage);
+                let (clear, invalidated) = color_loads[i].unwrap_or_default();
+                stores.color[i] = Some(set_pass_attachment_actions(&a_desc, clear, invalidated));
+                if let Some(clear) = clear {
+                    a_desc.setClearColor(clear.clear_color());
+                }
+                unsafe {
+                    desc.colorAttachments()
+                        .setObject_atIndexedSubscript(Some(&a_desc), i);
+                };
+            }
+            if let Some(storage) = &depth {
+                fit(storage);
+                formats.depth = storage.tex.pixelFormat();
+                let a_desc = unsafe { MTLRenderPassDepthAttachmentDescriptor::new() };
+                set_attachment_storage(&a_desc, storage);
+                let (clear, invalidated) = depth_load.unwrap_or_default();
+                stores.depth = Some(set_pass_attachment_actions(&a_desc, clear, invalidated));
+                if let Some(ClearValue::Depth(depth)) = clear {
+                    a_desc.setClearDepth(depth.into());
+                }
+                desc.setDepthAttachment(Some(&a_desc));
+            }
+            if let Some(storage) = &stencil {
+                fit(storage);
+                formats.stencil = storage.tex.pixelFormat();
+                let a_desc = unsafe { MTLRenderPassStencilAttachmentDescriptor::new() };
+                set_attachment_storage(&a_desc, storage);
+                let (clear, invalidated) = stencil_load.unwrap_or_default();
+                stores.stencil = Some(set_pass_attachment_actions(&a_desc, clear, invalidated));
+                if let Some(ClearValue::Stencil(stencil)) = clear {
+                    a_desc.setClearStencil(stencil_clear_value(stencil));
+                }
+                desc.setStencilAttachment(Some(&a_desc));
+            }
+            let (width, height, layers) = dims.unwrap_or((1, 1, 1));
+            self.render_target_dims = (width, height);
+            desc.setRenderTargetWidth(width as usize);
+            desc.setRenderTargetHeight(height as usize);
+            // completeness guarantees that either every attachment is layered or none of them are
+            self.render_target_layers = 1;
+            if framebuffer.attachments().any(|(_, a)| a.layer.is_none()) {
+                desc.setRenderTargetArrayLength(layers as usize);
+                self.render_target_layers = layers;
+            }
+            desc.setDefaultRasterSampleCount(samples as usize);
+            formats.samples = samples as usize;
+            self.render_pass_formats = formats;
+            self.render_pass_stores = stores;
         } else {
             // default FBO
             let mut iter = state
@@ -617,7 +1453,7 @@ impl PlatformState {
                 .filter_map(|(idx, v)| v.map(|v| (idx, v)))
                 .peekable();
             //FIXME this expect contradicts the spec, should be an early return of some kind
-            let &(_, first) = iter.peek().expect("No draw buffer set");
+            iter.peek().expect("No draw buffer set");
             let ca_drawable_tex = unsafe { self.current_drawable().texture() };
 
             // Use the current drawable size as the targeted size for rendering. If the drawable size changes, a new
@@ -627,52 +1463,67 @@ impl PlatformState {
                 ca_drawable_tex.height() as u32,
             );
 
+            let clears = mem::take(&mut state.default_framebuffer_clears);
+            let invalidations = mem::take(&mut state.default_framebuffer_invalidations);
+            let mut formats = PassFormats::default();
+            let mut stores = PassStoreActions::default();
             if state.caps.is_any_enabled(Capabilities::DEPTH_TEST) {
                 let a_desc = unsafe { MTLRenderPassDepthAttachmentDescriptor::new() };
-                a_desc.setTexture(Some(&self.get_internal_depthbuffer(dims).tex));
-
+                let tex = &self.get_internal_depthbuffer(dims).tex;
+                formats.depth = tex.pixelFormat();
+                a_desc.setTexture(Some(tex));
+                stores.depth = Some(set_pass_attachment_actions(
+                    &a_desc,
+                    clears.depth,
+                    invalidations.depth,
+                ));
+                if let Some(ClearValue::Depth(depth)) = clears.depth {
+                    a_desc.setClearDepth(depth.into());
+                }
                 desc.setDepthAttachment(Some(&a_desc));
             }
             if state.caps.is_any_enabled(Capabilities::STENCIL_TEST) {
                 let a_desc = unsafe { MTLRenderPassStencilAttachmentDescriptor::new() };
-                a_desc.setTexture(Some(&self.get_internal_stencilbuffer(dims).tex));
+                let tex = &self.get_internal_stencilbuffer(dims).tex;
+                formats.stencil = tex.pixelFormat();
+                a_desc.setTexture(Some(tex));
+                stores.stencil = Some(set_pass_attachment_actions(
+                    &a_desc,
+                    clears.stencil,
+                    invalidations.stencil,
+                ));
+                if let Some(ClearValue::Stencil(stencil)) = clears.stencil {
+                    a_desc.setClearStencil(stencil_clear_value(stencil));
+                }
                 desc.setStencilAttachment(Some(&a_desc));
             }
 
-            let drawbuffer = self.get_internal_drawbuffer(first, dims);
             for (idx, buf) in iter {
                 let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
-                // set attachment texture
-                if buf == DrawBufferMode::FrontLeft {
-                    // Replace the texture with the current drawable
-                    debug_assert_eq!(
-                        (
-                            ca_drawable_tex.width() as u32,
-                            ca_drawable_tex.height() as u32
-                        ),
-                        drawbuffer.dimensions,
-                        "Metal drawable had different dimensions than associated drawbuffer!"
-                    );
-                    a_desc.setTexture(Some(&ca_drawable_tex));
-                } else {
-                    a_desc.setTexture(Some(&drawbuffer.tex));
+                let tex = self.default_color_buffer(buf, dims);
+                formats.color[idx] = tex.pixelFormat();
+                a_desc.setTexture(Some(&tex));
+                stores.color[idx] = Some(set_pass_attachment_actions(
+                    &a_desc,
+                    clears.color[idx],
+                    invalidations.color(buf),
+                ));
+                if let Some(clear) = clears.color[idx] {
+                    a_desc.setClearColor(clear.clear_color());
                 }
-
-                // FIXME need to do load action real
-                // a_desc.setLoadAction(MTLLoadAction::Clear);
-                // a_desc.setClearColor(MTLClearColor {
-                //     red: 1.0,
-                //     green: 0.0,
-                //     blue: 0.0,
-                //     alpha: 0.5,
-                // });
                 unsafe {
                     desc.colorAttachments()
                         .setObject_atIndexedSubscript(Some(&a_desc), idx);
                 };
             }
+            self.render_target_dims = dims;
+            self.render_target_layers = 1;
+            formats.samples = self.samples as usize;
+            self.render_pass_formats = formats;
+            self.render_pass_stores = stores;
             desc.setRenderTargetWidth(dims.0 as usize);
             desc.setRenderTargetHeight(dims.1 as usize);
+            desc.setDefaultRasterSampleCount(self.samples as usize);
         }
         let enc = self
             .current_command_buffer()
@@ -687,12 +1538,14 @@ impl PlatformState {
         device: &ProtoObjRef<dyn MTLDevice>,
         dims: (u32, u32),
         pixel_format: MTLPixelFormat,
+        samples: u32,
         gpu_private: bool,
         r: &'a mut Option<InternalDrawable>,
     ) -> &'a InternalDrawable {
         if r.as_ref().is_none_or(|v| v.dimensions != dims) {
             // Need a new internal drawable
-            let new_tex = Self::new_drawbuffer_size_format(device, dims, pixel_format, gpu_private);
+            let new_tex =
+                Self::new_drawbuffer_size_format(device, dims, pixel_format, samples, gpu_private);
             let mut replacement = Some(InternalDrawable::new(new_tex, dims));
             mem::swap(r, &mut replacement);
             drop(replacement);
@@ -713,7 +1566,28 @@ impl PlatformState {
             DrawBufferMode::BackRight => &mut self.internal_drawables.back_right,
             _ => todo!("oxidegl does not support aliased draw buffer modes"),
         };
-        Self::check_and_resize_drawable(&self.device, dims, self.pixel_format, false, r)
+        // multisample textures can't be CPU accessible
+        Self::check_and_resize_drawable(
+            &self.device,
+            dims,
+            self.pixel_format,
+            self.samples,
+            self.samples > 1,
+            r,
+        )
+    }
+    /// Returns the texture that backs the color buffer `mode` of the default framebuffer. The front left buffer is the current
+    /// drawable, unless the default framebuffer is multisampled
+    pub(crate) fn default_color_buffer(
+        &mut self,
+        mode: DrawBufferMode,
+        dims: (u32, u32),
+    ) -> ProtoObjRef<dyn MTLTexture> {
+        if mode == DrawBufferMode::FrontLeft && self.samples == 1 {
+            self.current_drawable().texture()
+        } else {
+            self.get_internal_drawbuffer(mode, dims).tex.clone()
+        }
     }
     // precondition: user specifies depth format for defaultfb
     pub(crate) fn get_internal_depthbuffer(&mut self, dims: (u32, u32)) -> &InternalDrawable {
@@ -721,6 +1595,7 @@ impl PlatformState {
             &self.device,
             dims,
             self.depth_format.expect("tried to generate a depth buffer for the default framebuffer, but no depth format was specified at context creation!"),
+            self.samples,
             true,
             &mut self.internal_drawables.depth,
         )
@@ -730,7 +1605,8 @@ impl PlatformState {
         Self::check_and_resize_drawable(
             &self.device,
             dims,
-            self.depth_format.expect("tried to generate a stencil buffer for the default framebuffer, but no stencil format was specified at context creation!"),
+            self.stencil_format.expect("tried to generate a stencil buffer for the default framebuffer, but no stencil format was specified at context creation!"),
+            self.samples,
             true,
             &mut self.internal_drawables.stencil,
         )
@@ -739,10 +1615,11 @@ impl PlatformState {
         device: &ProtoObjRef<dyn MTLDevice>,
         size: (u32, u32),
         format: MTLPixelFormat,
+        samples: u32,
         gpu_private: bool,
     ) -> ProtoObjRef<dyn MTLTexture> {
         gl_debug!(
-            "creating new {}x{} {format:?} drawable texture",
+            "creating new {}x{} {format:?} drawable texture with {samples} samples",
             size.0,
             size.1
         );
@@ -751,10 +1628,19 @@ impl PlatformState {
             desc.setStorageMode(MTLStorageMode::Private);
             desc.setAllowGPUOptimizedContents(true);
         }
+        if samples > 1 {
+            desc.setTextureType(MTLTextureType::Type2DMultisample);
+            unsafe { desc.setSampleCount(samples as usize) };
+        }
         unsafe { desc.setWidth(size.0 as usize) };
         unsafe { desc.setHeight(size.1 as usize) };
         desc.setPixelFormat(format);
-        desc.setUsage(MTLTextureUsage::RenderTarget);
+        // drawables can be the source of framebuffer blits, which may need to view them in a different (e.g. non-sRGB) format
+        desc.setUsage(
+            MTLTextureUsage::RenderTarget
+                | MTLTextureUsage::ShaderRead
+                | MTLTextureUsage::PixelFormatView,
+        );
         device
             .newTextureWithDescriptor(&desc)
             .expect("failed to create drawable texture")
@@ -780,6 +1666,19 @@ impl PlatformState {
         }
         .as_ref()
     }
+    /// Returns the `idx`th sampled image binding of the current program's `shader_type` stage, if it has one
+    #[inline]
+    pub(crate) fn sampled_image_binding(
+        state: &GLState,
+        shader_type: ShaderType,
+        idx: usize,
+    ) -> Option<SampledImageBinding> {
+        Self::linked_stage(state, shader_type)?
+            .resources
+            .sampled_image_bindings
+            .get(idx)
+            .copied()
+    }
 
     /// precondition: has program
     pub(crate) fn remap_buffer_arguments(&mut self, state: &mut GLState) {
@@ -974,3 +1873,156 @@ impl From<TriangleFace> for MTLCullMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::framebuffer::{Framebuffer, InvalidatedRegion};
+
+    fn stores(actions: &PassStoreActions) -> Vec<(AttachmentPoint, MTLStoreAction)> {
+        actions.actions().collect()
+    }
+
+    #[test]
+    fn invalidated_attachments_are_not_loaded_or_stored() {
+        let clear = Some(ClearValue::Float([0.0; 4]));
+        assert_eq!(
+            pass_attachment_actions(None, false),
+            (MTLLoadAction::Load, MTLStoreAction::Store)
+        );
+        assert_eq!(
+            pass_attachment_actions(None, true),
+            (MTLLoadAction::DontCare, MTLStoreAction::DontCare)
+        );
+        // a clear recorded after the invalidation defines the contents again
+        assert_eq!(
+            pass_attachment_actions(clear, false),
+            (MTLLoadAction::Clear, MTLStoreAction::Store)
+        );
+        assert_eq!(
+            pass_attachment_actions(clear, true),
+            (MTLLoadAction::Clear, MTLStoreAction::Store)
+        );
+    }
+    #[test]
+    fn partial_invalidation_keeps_store() {
+        let region = InvalidatedRegion {
+            origin: [16, 16],
+            width: 32,
+            height: 32,
+        };
+        // sub-rect invalidations aren't recorded, so the attachment's contents are loaded and stored as usual
+        let invalidated = region.covers((64, 64));
+        assert_eq!(
+            pass_attachment_actions(None, invalidated),
+            (MTLLoadAction::Load, MTLStoreAction::Store)
+        );
+        let mut actions = PassStoreActions {
+            depth: Some(MTLStoreAction::Store),
+            ..Default::default()
+        };
+        actions.discard([false; MAX_COLOR_ATTACHMENTS as usize], invalidated, false);
+        assert_eq!(
+            stores(&actions),
+            [(AttachmentPoint::Depth, MTLStoreAction::Store)]
+        );
+    }
+    #[test]
+    fn discard_and_store_all() {
+        let mut actions = PassStoreActions::default();
+        actions.color[0] = Some(MTLStoreAction::Store);
+        actions.color[2] = Some(MTLStoreAction::Store);
+        actions.stencil = Some(MTLStoreAction::Store);
+        let mut color = [false; MAX_COLOR_ATTACHMENTS as usize];
+        color[2] = true;
+        // attachments that aren't part of the render pass stay that way
+        color[3] = true;
+        actions.discard(color, true, true);
+        assert_eq!(
+            stores(&actions),
+            [
+                (AttachmentPoint::Color(0), MTLStoreAction::Store),
+                (AttachmentPoint::Color(2), MTLStoreAction::DontCare),
+                (AttachmentPoint::Stencil, MTLStoreAction::DontCare),
+            ]
+        );
+        // a draw or clear renders to every attachment of the render pass, so their contents have to be stored again
+        actions.store_all();
+        assert_eq!(
+            stores(&actions),
+            [
+                (AttachmentPoint::Color(0), MTLStoreAction::Store),
+                (AttachmentPoint::Color(2), MTLStoreAction::Store),
+                (AttachmentPoint::Stencil, MTLStoreAction::Store),
+            ]
+        );
+    }
+    #[test]
+    fn invalidation_before_swap_begins_no_render_pass() {
+        // the open render pass of the default framebuffer has been drawn to
+        let mut actions = PassStoreActions {
+            depth: Some(MTLStoreAction::Store),
+            ..Default::default()
+        };
+        actions.color[0] = Some(MTLStoreAction::Store);
+        let mut dirty = Dirty::empty();
+        assert!(dirty.update_before_present(true));
+        // invalidating the depth buffer discards it from the open pass and leaves the next pass pending, like
+        // invalidate_framebuffer_internal
+        actions.discard([false; MAX_COLOR_ATTACHMENTS as usize], true, false);
+        dirty.set_bits(Dirty::NEW_RENDER_ENCODER);
+        // so presenting only ends the open pass, with the invalidated attachment discarded
+        assert!(!dirty.update_before_present(true));
+        assert_eq!(
+            stores(&actions),
+            [
+                (AttachmentPoint::Color(0), MTLStoreAction::Store),
+                (AttachmentPoint::Depth, MTLStoreAction::DontCare),
+            ]
+        );
+        // without an open pass, presenting begins one as before
+        assert!(dirty.update_before_present(false));
+    }
+    #[test]
+    fn invalidation_resets_after_draw() {
+        let mut framebuffer = Framebuffer::new_default(ObjectName::from_raw(1));
+        framebuffer.color_attachments[0] =
+            Some(FramebufferAttachment::renderbuffer(ObjectName::from_raw(1)));
+        // render passes take the clears and invalidations of the attachments, like build_render_encoder
+        let begin_pass = |framebuffer: &mut Framebuffer| {
+            let attachment = framebuffer.color_attachments[0].as_mut().unwrap();
+            pass_attachment_actions(
+                attachment.clear.take(),
+                mem::take(&mut attachment.invalidated),
+            )
+        };
+        framebuffer.invalidate(AttachmentPoint::Color(0));
+        let (load, store) = begin_pass(&mut framebuffer);
+        assert_eq!(load, MTLLoadAction::DontCare);
+        let mut actions = PassStoreActions::default();
+        actions.color[0] = Some(store);
+        assert_eq!(
+            stores(&actions),
+            [(AttachmentPoint::Color(0), MTLStoreAction::DontCare)]
+        );
+        // drawing to it stores its contents
+        actions.store_all();
+        assert_eq!(
+            stores(&actions),
+            [(AttachmentPoint::Color(0), MTLStoreAction::Store)]
+        );
+        // and the next render pass loads them again
+        assert_eq!(
+            begin_pass(&mut framebuffer),
+            (MTLLoadAction::Load, MTLStoreAction::Store)
+        );
+        // unless it's invalidated again, after which a clear defines them
+        framebuffer.invalidate(AttachmentPoint::Color(0));
+        framebuffer.color_attachments[0].as_mut().unwrap().clear =
+            Some(ClearValue::Float([0.0; 4]));
+        assert_eq!(
+            begin_pass(&mut framebuffer),
+            (MTLLoadAction::Clear, MTLStoreAction::Store)
+        );
+    }
+}
@@ -1,21 +1,24 @@
-use std::{mem, ptr::NonNull};
+use std::{cell::RefCell, fmt::Write, mem, ptr::NonNull};
 
-use ahash::{HashSet, HashSetExt};
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use glslang::Compiler as GlslLangCompiler;
 use objc2::{rc::Retained, AllocAnyThread};
 //use naga::back::msl::{Options, PipelineOptions};
 use crate::{
     context::{
-        debug::{gl_debug, gl_trace, with_debug_state},
+        debug::{gl_debug, gl_trace, gl_warn, with_debug_state},
         shader::ShaderInternal,
+        texture::SamplerEmulation,
     },
-    enums::ShaderType, util::{NoDebug, ProtoObjRef},
+    device_properties::MetalProperties,
+    enums::{ShaderType, TextureTarget}, util::{NoDebug, ProtoObjRef},
 };
 use objc2_foundation::NSString;
-use objc2_metal::{MTLDevice, MTLFunction, MTLLibrary};
+use objc2_metal::{MTLDataType, MTLDevice, MTLFunction, MTLFunctionConstantValues, MTLLibrary};
 use spirv_cross2::{
     compile::{msl::CompilerOptions, CompiledArtifact},
-    reflect::ResourceIter,
+    reflect::{ArrayDimension, Resource, ResourceIter, Scalar, ScalarKind, TypeInner},
+    spirv::Dim,
     targets::Msl,
     Compiler, Module, SpirvCrossError,
 };
@@ -175,6 +178,7 @@ impl Program {
     fn link_stage(
         shader_list: &NamedObjectList<Shader>,
         device: &ProtoObjRef<dyn MTLDevice>,
+        props: &MetalProperties,
         binding: &mut ProgramStageBinding,
         glslang_compiler: &GlslLangCompiler,
         label: Option<&Retained<NSString>>,
@@ -218,6 +222,11 @@ impl Program {
         stage_spirv
             .add_discrete_descriptor_set(3)
             .map_err(|e| e.to_string().into_boxed_str())?;
+        // GL resources all live in descriptor set 0, keep it out of argument buffers so that textures and samplers
+        // get plain argument table slots that texture units can be bound to directly
+        stage_spirv
+            .add_discrete_descriptor_set(0)
+            .map_err(|e| e.to_string().into_boxed_str())?;
 
         let entry_name = format!("{:?}_{}_main", stage.unwrap(), used_shaders.join("_"));
 
@@ -236,8 +245,25 @@ impl Program {
         opts.version = (2, 1).into();
         opts.argument_buffers = true;
         let artifact = stage_spirv.compile(&opts).map_err(|e| e.to_string())?;
+        let resources = LinkedProgramResources::get_from_compiler(&artifact)
+            .expect("failed to get resource bindings during program linkage!");
+        // texture units are bound to the argument table slots they were assigned here, which must exist on the device
+        if let Some(binding) = resources.sampled_image_bindings.iter().find(|b| {
+            b.texture_index >= props.max_texture_arguments()
+                || b.sampler_index
+                    .is_some_and(|idx| idx >= props.max_sampler_arguments())
+        }) {
+            err_ret!(format!(
+                "stage samples from too many textures: texture unit {} needs texture slot {} and sampler slot {:?}, but the device only has {} texture and {} sampler slots",
+                binding.unit,
+                binding.texture_index,
+                binding.sampler_index,
+                props.max_texture_arguments(),
+                props.max_sampler_arguments()
+            ));
+        }
 
-        let msl_src = format!("{artifact}");
+        let msl_src = inject_shader_emulation(&format!("{artifact}"), &resources.sampled_images);
         gl_trace!(src: ShaderCompiler, "transformed metal sources for stage:\n{msl_src}");
 
         let lib = device
@@ -246,15 +272,24 @@ impl Program {
         if let Some(label) = label {
             lib.setLabel(Some(label));
         }
+        let entry_name = NSString::from_str(&entry_name);
+        // stages with sampled images use function constants for sampler emulation and must always be specialized
+        let function = if resources.sampled_images.is_empty() {
+            lib.newFunctionWithName(&entry_name).unwrap()
+        } else {
+            lib.newFunctionWithName_constantValues_error(
+                &entry_name,
+                &MTLFunctionConstantValues::new(),
+            )
+            .map_err(|e| e.to_string())?
+        };
         // TODO: coalesce ungrouped (named) uniforms into a single uniform block with a hashmap for by-identifier uniform lookup
         Ok(LinkedStage {
-            function: lib
-                .newFunctionWithName(&NSString::from_str(&entry_name))
-                .unwrap(),
+            function,
             lib,
-            resources: LinkedProgramResources::get_from_compiler(&artifact)
-                .expect("failed to get resource bindings during program linkage!"),
+            resources,
             artifact: artifact.into(),
+            specializations: RefCell::new(HashMap::new()),
         })
     }
     //TODO async shader compilation
@@ -263,6 +298,7 @@ impl Program {
         &mut self,
         shader_list: &mut NamedObjectList<Shader>,
         device: &ProtoObjRef<dyn MTLDevice>,
+        props: &MetalProperties,
     ) {
         //TODO errors
         self.latest_linkage = None;
@@ -293,6 +329,7 @@ impl Program {
             match Self::link_stage(
                 shader_list,
                 device,
+                props,
                 &mut self.vertex_shaders,
                 glslang_compiler,
                 label.as_ref(),
@@ -309,6 +346,7 @@ impl Program {
             match Self::link_stage(
                 shader_list,
                 device,
+                props,
                 &mut self.fragment_shaders,
                 glslang_compiler,
                 label.as_ref(),
@@ -325,6 +363,7 @@ impl Program {
             match Self::link_stage(
                 shader_list,
                 device,
+                props,
                 &mut self.compute_shaders,
                 glslang_compiler,
                 label.as_ref(),
@@ -369,6 +408,96 @@ fn to_resource_vec(
     }
     Ok(vec)
 }
+/// Reflects the texture target and Metal argument table slots of each combined image sampler. Each element of an array of
+/// samplers gets its own binding
+#[inline]
+fn to_sampled_image_bindings(
+    iter: ResourceIter<'_>,
+    compiler: &Compiler<Msl>,
+) -> Result<Vec<SampledImageBinding>, SpirvCrossError> {
+    let mut vec = Vec::with_capacity(iter.len());
+    for v in iter {
+        let TypeInner::SampledImage(image) = compiler.type_description(v.base_type_id)?.inner else {
+            continue;
+        };
+        let target = match (image.dimension, image.arrayed, image.multisampled) {
+            (Dim::Dim1D, false, _) => TextureTarget::Texture1D,
+            (Dim::Dim1D, true, _) => TextureTarget::Texture1DArray,
+            (Dim::Dim2D, false, false) => TextureTarget::Texture2D,
+            (Dim::Dim2D, true, false) => TextureTarget::Texture2DArray,
+            (Dim::Dim2D, false, true) => TextureTarget::Texture2DMultisample,
+            (Dim::Dim2D, true, true) => TextureTarget::Texture2DMultisampleArray,
+            (Dim::Dim3D, ..) => TextureTarget::Texture3D,
+            (Dim::DimCube, false, _) => TextureTarget::TextureCubeMap,
+            (Dim::DimCube, true, _) => TextureTarget::TextureCubeMapArray,
+            (Dim::DimRect, ..) => TextureTarget::TextureRectangle,
+            (Dim::DimBuffer, ..) => TextureTarget::TextureBuffer,
+            _ => continue,
+        };
+        // Metal only has depth textures of these dimensionalities
+        let depth_dim = matches!(image.dimension, Dim::Dim2D | Dim::DimCube | Dim::DimRect);
+        let kind = match compiler.type_description(image.sampled_type)?.inner {
+            _ if image.depth && depth_dim => SampledKind::Depth,
+            TypeInner::Scalar(Scalar {
+                kind: ScalarKind::Int,
+                ..
+            }) => SampledKind::Int,
+            TypeInner::Scalar(Scalar {
+                kind: ScalarKind::Uint,
+                ..
+            }) => SampledKind::Uint,
+            _ => SampledKind::Float,
+        };
+        // resources that were optimized out of the stage have no argument table slot
+        let Some(texture_index) = compiler.automatic_resource_binding(v.id) else {
+            continue;
+        };
+        let unit = compiler
+            .decoration(v.id, spirv_cross2::spirv::Decoration::Binding)?
+            .map_or(0, |v| v.as_literal().expect("failed to convert literal"));
+        let sampler_index = compiler.automatic_resource_binding_secondary(v.id);
+        let elements = array_elements(&v, compiler)?.unwrap_or_else(|| {
+            gl_warn!(
+                "the size of sampler array {} is a specialization constant, only its first element will be bound",
+                v.name
+            );
+            1
+        });
+        // the elements of sampler arrays take consecutive texture units, and consecutive argument table slots following
+        // those of the first element
+        vec.extend((0..elements).map(|i| SampledImageBinding {
+            unit: unit + i,
+            target,
+            kind,
+            texture_index: texture_index + i,
+            sampler_index: sampler_index.map(|idx| idx + i),
+        }));
+    }
+    Ok(vec)
+}
+/// Returns the number of elements of the (possibly arrayed) type of `resource`, or None if the size of one of its array
+/// dimensions is a specialization constant
+fn array_elements(
+    resource: &Resource<'_>,
+    compiler: &Compiler<Msl>,
+) -> Result<Option<u32>, SpirvCrossError> {
+    let mut ty = compiler.type_description(resource.type_id)?;
+    loop {
+        match ty.inner {
+            TypeInner::Pointer { base, .. } => ty = compiler.type_description(base)?,
+            TypeInner::Array { dimensions, .. } => {
+                return Ok(dimensions
+                    .iter()
+                    .map(|dim| match dim {
+                        ArrayDimension::Literal(len) => Some(*len),
+                        ArrayDimension::Constant(_) => None,
+                    })
+                    .product());
+            }
+            _ => return Ok(Some(1)),
+        }
+    }
+}
 #[derive(Debug)]
 pub struct LinkedProgramResources {
     pub(crate) uniform_buffers: Vec<ProgramResource>,
@@ -376,6 +505,8 @@ pub struct LinkedProgramResources {
     pub(crate) atomic_counter_buffers: Vec<ProgramResource>,
     pub(crate) stage_inputs: Vec<ProgramResource>,
     pub(crate) plain_uniforms: Vec<ProgramResource>,
+    pub(crate) sampled_images: Vec<ProgramResource>,
+    pub(crate) sampled_image_bindings: Vec<SampledImageBinding>,
 }
 impl LinkedProgramResources {
     //TODO XFBs
@@ -401,12 +532,22 @@ impl LinkedProgramResources {
             value.resources_for_type(spirv_cross2::reflect::ResourceType::GlPlainUniform)?,
             spirvc,
         )?;
+        let sampled_images = to_resource_vec(
+            value.resources_for_type(spirv_cross2::reflect::ResourceType::SampledImage)?,
+            spirvc,
+        )?;
+        let sampled_image_bindings = to_sampled_image_bindings(
+            value.resources_for_type(spirv_cross2::reflect::ResourceType::SampledImage)?,
+            spirvc,
+        )?;
         Ok(Self {
             uniform_buffers,
             shader_storage_buffers,
             atomic_counter_buffers,
             stage_inputs,
             plain_uniforms,
+            sampled_images,
+            sampled_image_bindings,
         })
     }
 }
@@ -416,6 +557,32 @@ pub struct ProgramResource {
     pub(crate) binding: Option<u32>,
     pub(crate) location: Option<u32>,
 }
+/// Where a stage expects the texture and sampler state of the texture unit one of its combined image samplers samples from
+#[derive(Debug, Clone, Copy)]
+pub struct SampledImageBinding {
+    /// The texture unit sampled from (the binding of the sampler uniform)
+    pub(crate) unit: u32,
+    /// The target of the texture unit that is sampled from
+    pub(crate) target: TextureTarget,
+    /// The type of texture the stage declares the image as
+    pub(crate) kind: SampledKind,
+    /// Index of the texture in the Metal texture argument table
+    pub(crate) texture_index: u32,
+    /// Index of the sampler state in the Metal sampler argument table, if the image is sampled with a sampler
+    pub(crate) sampler_index: Option<u32>,
+}
+/// The type of Metal texture a stage samples a combined image sampler as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampledKind {
+    /// Floating point or normalized texture (e.g. `texture2d<float>`)
+    Float,
+    /// Signed integer texture (e.g. `texture2d<int>`)
+    Int,
+    /// Unsigned integer texture (e.g. `texture2d<uint>`)
+    Uint,
+    /// Depth texture sampled with a depth comparison (e.g. `depth2d<float>`)
+    Depth,
+}
 #[derive(Debug)]
 pub struct LinkedStage {
     /// the entry point for this stage
@@ -426,4 +593,210 @@ pub struct LinkedStage {
     pub(crate) artifact: NoDebug<CompiledArtifact<Msl>>,
     /// Resources
     pub(crate) resources: LinkedProgramResources,
+    /// Cache of entry points specialized for a given sampler emulation state
+    specializations: SpecializationCache,
+}
+type SpecializationCache =
+    RefCell<HashMap<Box<[(u32, SamplerEmulation)]>, ProtoObjRef<dyn MTLFunction>>>;
+impl LinkedStage {
+    /// Returns the entry point specialized for the given shader-side sampler emulation state, as a list of
+    /// (sampler binding, emulation state) pairs sorted by binding
+    pub(crate) fn specialized_function(
+        &self,
+        emulation: &[(u32, SamplerEmulation)],
+    ) -> ProtoObjRef<dyn MTLFunction> {
+        if emulation.is_empty() {
+            return self.function.clone();
+        }
+        if let Some(f) = self.specializations.borrow().get(emulation) {
+            return f.clone();
+        }
+        gl_trace!(src: ShaderCompiler, "specializing {:?} for sampler emulation state {emulation:?}", self.function.name());
+        let values = MTLFunctionConstantValues::new();
+        for (binding, border) in emulation {
+            let (color_idx, axes_idx, bias_idx) = emulation_constant_indices(*binding);
+            // Safety: pointers are valid for reads of the specified data type, values are copied by Metal
+            unsafe {
+                values.setConstantValue_type_atIndex(
+                    NonNull::from(&border.color).cast(),
+                    MTLDataType::UInt4,
+                    color_idx,
+                );
+                values.setConstantValue_type_atIndex(
+                    NonNull::from(&border.axes).cast(),
+                    MTLDataType::UInt,
+                    axes_idx,
+                );
+                values.setConstantValue_type_atIndex(
+                    NonNull::from(&border.lod_bias).cast(),
+                    MTLDataType::Float,
+                    bias_idx,
+                );
+            }
+        }
+        let f = self
+            .lib
+            .newFunctionWithName_constantValues_error(&self.function.name(), &values)
+            .expect("failed to specialize shader function for sampler emulation");
+        self.specializations
+            .borrow_mut()
+            .insert(emulation.into(), f.clone());
+        f
+    }
+}
+
+/// Function constant indices of the border color, border axes and LOD bias of the sampler at the given binding
+fn emulation_constant_indices(binding: u32) -> (usize, usize, usize) {
+    let base = binding as usize * 3;
+    (base, base + 1, base + 2)
+}
+
+/// MSL helpers for sampler emulation, inserted into every translated shader that samples textures. Sample calls are
+/// redirected to `oxidegl_sample`, which applies the sampler's LOD bias and emulates clamp to border.
+///
+/// Samplers with emulated border colors clamp to the edge texels natively, and `oxidegl_sample` substitutes the border
+/// color when a border-clamped coordinate falls outside of [0, 1].
+/// Note: this does not blend the border color into the filter footprint like a native border color would,
+/// and is skipped for cube maps and rectangle textures
+///
+/// The LOD bias is added to the `bias` or `level` option of a sample call, or passed as a `bias` if the call computes
+/// its LOD implicitly. Calls with explicit gradients are not biased
+const SAMPLER_EMULATION_PRELUDE: &str = r"
+template <typename T>
+inline T oxidegl_border_value(uint4 bits) { return T(as_type<float4>(bits)); }
+template <>
+inline int4 oxidegl_border_value<int4>(uint4 bits) { return as_type<int4>(bits); }
+template <>
+inline uint4 oxidegl_border_value<uint4>(uint4 bits) { return bits; }
+template <>
+inline float oxidegl_border_value<float>(uint4 bits) { return as_type<float>(bits.x); }
+
+inline bool oxidegl_outside_border(float c, uint axes) { return (axes & 1u) != 0 && (c < 0.0 || c > 1.0); }
+inline bool oxidegl_outside_border(float2 c, uint axes) { return oxidegl_outside_border(c.x, axes) || oxidegl_outside_border(c.y, axes >> 1); }
+inline bool oxidegl_outside_border(float3 c, uint axes) { return oxidegl_outside_border(c.xy, axes) || oxidegl_outside_border(c.z, axes >> 2); }
+
+template <typename Tex>
+inline bool oxidegl_has_border(Tex) { return true; }
+template <typename T, access A>
+inline bool oxidegl_has_border(texturecube<T, A>) { return false; }
+template <typename T, access A>
+inline bool oxidegl_has_border(texturecube_array<T, A>) { return false; }
+template <typename T, access A>
+inline bool oxidegl_has_border(depthcube<T, A>) { return false; }
+template <typename T, access A>
+inline bool oxidegl_has_border(depthcube_array<T, A>) { return false; }
+
+inline bias oxidegl_add_bias(float b, bias option) { return bias(option.value + b); }
+inline level oxidegl_add_bias(float b, level option) { return level(option.lod + b); }
+template <typename T>
+inline T oxidegl_add_bias(float, T arg) { return arg; }
+
+template <typename Tex, typename Coord, typename... Ts>
+inline auto oxidegl_sample_biased(float b, Tex tex, sampler smp, Coord coord, Ts... args)
+    -> decltype(tex.sample(smp, coord, args...))
+{
+    return tex.sample(smp, coord, oxidegl_add_bias(b, args)...);
+}
+template <typename Tex, typename Coord>
+inline auto oxidegl_sample_biased(float b, Tex tex, sampler smp, Coord coord)
+    -> decltype(tex.sample(smp, coord))
+{
+    return tex.sample(smp, coord, bias(b));
+}
+template <typename Tex, typename Coord>
+inline auto oxidegl_sample_biased(float b, Tex tex, sampler smp, Coord coord, uint array)
+    -> decltype(tex.sample(smp, coord, array))
+{
+    return tex.sample(smp, coord, array, bias(b));
+}
+template <typename Tex, typename Coord, typename T, int N>
+inline auto oxidegl_sample_biased(float b, Tex tex, sampler smp, Coord coord, vec<T, N> offset)
+    -> decltype(tex.sample(smp, coord, offset))
+{
+    return tex.sample(smp, coord, bias(b), offset);
+}
+template <typename Tex, typename Coord, typename T, int N>
+inline auto oxidegl_sample_biased(float b, Tex tex, sampler smp, Coord coord, uint array, vec<T, N> offset)
+    -> decltype(tex.sample(smp, coord, array, offset))
+{
+    return tex.sample(smp, coord, array, bias(b), offset);
+}
+
+template <typename Tex, typename Coord, typename... Ts>
+inline auto oxidegl_sample(bool border, uint axes, uint4 bits, float lod_bias, Tex tex, sampler smp, Coord coord, Ts... args)
+    -> decltype(tex.sample(smp, coord, args...))
+{
+    if (border && oxidegl_has_border(tex) && oxidegl_outside_border(coord, axes))
+    {
+        return oxidegl_border_value<decltype(tex.sample(smp, coord, args...))>(bits);
+    }
+    if (lod_bias != 0.0)
+    {
+        return oxidegl_sample_biased(lod_bias, tex, smp, coord, args...);
+    }
+    return tex.sample(smp, coord, args...);
+}
+";
+
+/// Rewrites the `.sample(...)` calls on the given sampled images in `msl` to go through the sampler emulation
+/// helper, and declares the function constants that control it
+fn inject_shader_emulation(msl: &str, images: &[ProgramResource]) -> String {
+    const SAMPLE: &str = ".sample(";
+    let bindings: HashMap<&str, u32> = images
+        .iter()
+        .filter_map(|img| Some((&*img.name, img.binding?)))
+        .collect();
+    if bindings.is_empty() {
+        return msl.to_owned();
+    }
+    let mut used_bindings = bindings.values().copied().collect::<Vec<_>>();
+    used_bindings.sort_unstable();
+    used_bindings.dedup();
+    let mut prelude = SAMPLER_EMULATION_PRELUDE.to_owned();
+    for binding in used_bindings {
+        let (color_idx, axes_idx, bias_idx) = emulation_constant_indices(binding);
+        let _ = write!(
+            prelude,
+            "
+constant uint4 oxidegl_border_color_in_{binding} [[function_constant({color_idx})]];
+constant uint4 oxidegl_border_color_{binding} = is_function_constant_defined(oxidegl_border_color_in_{binding}) ? oxidegl_border_color_in_{binding} : uint4(0);
+constant uint oxidegl_border_axes_in_{binding} [[function_constant({axes_idx})]];
+constant bool oxidegl_border_{binding} = is_function_constant_defined(oxidegl_border_axes_in_{binding});
+constant uint oxidegl_border_axes_{binding} = oxidegl_border_{binding} ? oxidegl_border_axes_in_{binding} : 0;
+constant float oxidegl_lod_bias_in_{binding} [[function_constant({bias_idx})]];
+constant float oxidegl_lod_bias_{binding} = is_function_constant_defined(oxidegl_lod_bias_in_{binding}) ? oxidegl_lod_bias_in_{binding} : 0.0;
+"
+        );
+    }
+
+    let mut out = String::with_capacity(msl.len() + prelude.len());
+    let mut rest = msl;
+    while let Some(idx) = rest.find(SAMPLE) {
+        let (head, tail) = rest.split_at(idx);
+        // the receiver is the trailing member access expression (e.g. `spvDescriptorSet0.tex`), textures that are
+        // indexed out of arrays are not emulated
+        let recv_start = head
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .map_or(0, |i| i + 1);
+        let recv = &head[recv_start..];
+        let ident = recv.rsplit('.').next().unwrap_or(recv);
+        if let Some(binding) = bindings.get(ident) {
+            out.push_str(&head[..recv_start]);
+            let _ = write!(
+                out,
+                "oxidegl_sample(oxidegl_border_{binding}, oxidegl_border_axes_{binding}, oxidegl_border_color_{binding}, oxidegl_lod_bias_{binding}, {recv}, "
+            );
+        } else {
+            out.push_str(head);
+            out.push_str(SAMPLE);
+        }
+        rest = &tail[SAMPLE.len()..];
+    }
+    out.push_str(rest);
+
+    let insert_at = out
+        .find("using namespace metal;")
+        .map_or(0, |i| i + "using namespace metal;".len());
+    out.insert_str(insert_at, &prelude);
+    out
 }
@@ -4,22 +4,31 @@ use ahash::HashSet;
 use objc2_metal::{MTLBlendFactor, MTLBlendOperation};
 
 use crate::{
-    dispatch::gl_types::GLenum,
+    device_properties::MetalProperties,
+    dispatch::gl_types::{GLenum, GLuint},
     enums::{
-        BlendEquationModeEXT, BlendingFactor, ClearBufferMask, DepthFunction, ErrorCode,
+        BlendEquationModeEXT, BlendingFactor, DepthFunction, ErrorCode,
         GL_CONTEXT_CORE_PROFILE_BIT, GL_CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT,
-        GL_CONTEXT_FLAG_NO_ERROR_BIT, StencilFunction, StencilOp, TriangleFace,
+        GL_CONTEXT_FLAG_NO_ERROR_BIT, ReadBufferMode, StencilFunction, StencilOp, TextureTarget,
+        TriangleFace,
     },
     util::bitflag_bits,
 };
 
 use super::{
+    Context,
     commands::buffer::Buffer,
     debug::DebugState,
-    framebuffer::{DrawBuffers, Framebuffer, MAX_COLOR_ATTACHMENTS},
+    error::{GlError, GlFallible, gl_assert},
+    framebuffer::{
+        DefaultFramebufferClears, DefaultFramebufferInvalidations, DrawBuffers, Framebuffer,
+        MAX_COLOR_ATTACHMENTS, RenderBuffer,
+    },
     gl_object::{NamedObjectList, ObjectName},
+    pixel::PixelStoreModes,
     program::Program,
     shader::Shader,
+    texture::{Sampler, Texture},
     vao::Vao,
 };
 
@@ -52,15 +61,43 @@ pub(crate) struct GLState {
     /// Current program to render with
     pub(crate) program_binding: Option<ObjectName<Program>>,
 
+    /// List of texture object states
+    pub(crate) texture_list: NamedObjectList<Texture>,
+    /// Current state of the texture and sampler bindings of each texture unit
+    pub(crate) texture_units: TextureUnits,
+
+    /// List of sampler object states
+    pub(crate) sampler_list: NamedObjectList<Sampler>,
+
+    /// Pixel storage modes used when reading pixel data back into client memory or a pixel pack buffer
+    pub(crate) pack_modes: PixelStoreModes,
+    /// Pixel storage modes used when uploading pixel data from client memory or a pixel unpack buffer
+    pub(crate) unpack_modes: PixelStoreModes,
+
     /// List of framebuffer object states
     pub(crate) framebufer_list: NamedObjectList<Framebuffer>,
     /// The current framebuffer to render to (None: default FB)
-    pub(crate) framebuffer_binding: Option<ObjectName<Framebuffer>>,
+    pub(crate) draw_framebuffer_binding: Option<ObjectName<Framebuffer>>,
+    /// The current framebuffer to read pixels from (None: default FB)
+    pub(crate) read_framebuffer_binding: Option<ObjectName<Framebuffer>>,
+
+    /// List of renderbuffer object states
+    pub(crate) renderbuffer_list: NamedObjectList<RenderBuffer>,
+    /// The renderbuffer bound to `GL_RENDERBUFFER`
+    pub(crate) renderbuffer_binding: Option<ObjectName<RenderBuffer>>,
     /// draw buffer/attachment tracking for the default framebuffer
     pub(crate) default_draw_buffers: DrawBuffers,
+    /// The color buffer of the default framebuffer that pixels are read from
+    pub(crate) default_read_buffer: ReadBufferMode,
+    /// Clears of the default framebuffer's buffers that are waiting for its next render pass
+    pub(crate) default_framebuffer_clears: DefaultFramebufferClears,
+    /// Buffers of the default framebuffer that were invalidated since its render pass began
+    pub(crate) default_framebuffer_invalidations: DefaultFramebufferInvalidations,
 
     //TODO: these should be arrays in order to support viewport arrays
     pub(crate) scissor_box: PixelAlignedRect,
+    /// Whether the scissor test (`GL_SCISSOR_TEST`) is enabled. Kept out of [`Capabilities`] since it is an indexed capability
+    pub(crate) scissor_test: bool,
     pub(crate) viewport: PixelAlignedRect,
 
     pub(crate) clear_values: ClearState,
@@ -99,13 +136,19 @@ impl Default for TriangleFace {
         TriangleFace::Back
     }
 }
+impl Default for ReadBufferMode {
+    // OxideGL renders the default framebuffer's front left buffer directly into the drawable
+    #[inline]
+    fn default() -> Self {
+        ReadBufferMode::FrontLeft
+    }
+}
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct ClearState {
     pub(crate) color: [f32; 4],
     pub(crate) depth: f32,
     pub(crate) stencil: u32,
-    pub(crate) mask: ClearBufferMask,
 }
 impl Default for ClearState {
     #[inline]
@@ -114,7 +157,6 @@ impl Default for ClearState {
             color: [0.0; 4],
             depth: 1.0,
             stencil: 0,
-            mask: ClearBufferMask::empty(),
         }
     }
 }
@@ -348,6 +390,18 @@ pub const MAX_SHADER_STORAGE_BUFFER_BINDINGS: usize = 16;
 pub const MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS: usize = 16;
 pub const MAX_UNIFORM_BUFFER_BINDINGS: usize = 16;
 
+pub const MAX_TEXTURE_SIZE: u32 = 16384;
+pub const MAX_3D_TEXTURE_SIZE: u32 = 2048;
+pub const MAX_ARRAY_TEXTURE_LAYERS: u32 = 2048;
+pub const MAX_TEXTURE_BUFFER_SIZE: u32 = 64_000_000;
+pub const MAX_RENDERBUFFER_SIZE: u32 = MAX_TEXTURE_SIZE;
+pub const MAX_TEXTURE_MAX_ANISOTROPY: f32 = 16.0;
+/// The minimum number of texture units a single shader stage may sample from that GL 4.6 requires
+pub const MIN_TEXTURE_IMAGE_UNITS: u32 = 16;
+/// The minimum number of texture units GL 4.6 requires. Each stage's sampled texture units are assigned their own Metal
+/// argument table slots when it's linked, so the argument table sizes don't limit how many units there are
+pub const MIN_COMBINED_TEXTURE_IMAGE_UNITS: u32 = 80;
+
 /// Keeps track of all buffer bindings to this OpenGL context
 #[derive(Debug, Clone, Copy, Default)]
 pub struct BufferBindings {
@@ -384,6 +438,198 @@ pub struct BufferBindings {
     pub(crate) uniform: [Option<ObjectName<Buffer>>; MAX_UNIFORM_BUFFER_BINDINGS],
 }
 
+/// Keeps track of the texture and sampler bindings of each texture unit
+#[derive(Debug)]
+pub(crate) struct TextureUnits {
+    /// Index of the texture unit that non-DSA texture commands and texture binding queries refer to (`GL_ACTIVE_TEXTURE`)
+    pub(crate) active: usize,
+    /// The texture units of this context (`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS` of them)
+    pub(crate) units: Box<[TextureUnit]>,
+    /// The number of texture units a single shader stage may sample from (`GL_MAX_TEXTURE_IMAGE_UNITS`)
+    pub(crate) stage_units: u32,
+    /// The default texture of each texture target (the texture object named zero), which is used through every unit that has
+    /// no other texture bound to the target. Created when it is first used, under a name that is never returned by
+    /// glGenTextures
+    pub(crate) defaults: TextureBindings,
+}
+impl Default for TextureUnits {
+    fn default() -> Self {
+        Self::with_limits(MIN_TEXTURE_IMAGE_UNITS, MIN_COMBINED_TEXTURE_IMAGE_UNITS)
+    }
+}
+impl TextureUnits {
+    /// Creates the texture units of a context on the device described by `props`. A stage may sample as many units as its
+    /// argument table has both texture and sampler slots for
+    pub(crate) fn new(props: &MetalProperties) -> Self {
+        let stage_units = props
+            .max_texture_arguments()
+            .min(props.max_sampler_arguments());
+        // GL counts the sampled units of its 5 graphics stages towards the combined limit
+        Self::with_limits(
+            stage_units,
+            (stage_units * 5).max(MIN_COMBINED_TEXTURE_IMAGE_UNITS),
+        )
+    }
+    fn with_limits(stage_units: u32, combined_units: u32) -> Self {
+        Self {
+            active: 0,
+            units: vec![TextureUnit::default(); combined_units as usize].into_boxed_slice(),
+            stage_units,
+            defaults: TextureBindings::default(),
+        }
+    }
+    /// Returns the texture bindings of the active texture unit
+    #[inline]
+    pub(crate) fn active(&self) -> &TextureBindings {
+        &self.units[self.active].textures
+    }
+    /// Returns the texture bindings of the active texture unit
+    #[inline]
+    pub(crate) fn active_mut(&mut self) -> &mut TextureBindings {
+        &mut self.units[self.active].textures
+    }
+    /// Returns the texture used through `target` of the texture unit with index `unit`, which is its default texture if no
+    /// other texture is bound to it. None if there is no such unit or target, or the default texture hasn't been created yet
+    #[inline]
+    pub(crate) fn texture(
+        &self,
+        unit: usize,
+        target: TextureTarget,
+    ) -> Option<ObjectName<Texture>> {
+        let bound = self.units.get(unit)?.textures.get(target).ok()?;
+        bound.or(self.defaults.get(target).ok()?)
+    }
+    /// Whether `name` is the default texture of a texture target, which the application can't refer to by name
+    #[inline]
+    pub(crate) fn is_default(&self, name: ObjectName<Texture>) -> bool {
+        self.defaults.iter().any(|default| default == name)
+    }
+    /// Returns `unit` as an index into [`units`](Self::units), or an `InvalidValue` error if there is no such texture unit
+    #[inline]
+    pub(crate) fn index(&self, unit: GLuint) -> GlFallible<usize> {
+        let count = self.units.len();
+        let unit = unit as usize;
+        gl_assert!(
+            unit < count,
+            InvalidValue,
+            "texture unit {unit} exceeds the number of texture units ({count})"
+        );
+        Ok(unit)
+    }
+    /// Breaks every binding of the given texture on every texture unit (e.g. because it was deleted)
+    #[inline]
+    pub(crate) fn unbind_texture(&mut self, name: ObjectName<Texture>) {
+        for unit in &mut self.units {
+            unit.textures.unbind_all(name);
+        }
+    }
+}
+/// The bindings of a single texture unit
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TextureUnit {
+    /// The textures bound to each texture target of this unit
+    pub(crate) textures: TextureBindings,
+    /// The sampler object bound to this unit, whose state supersedes the sampling state of the unit's textures
+    pub(crate) sampler: Option<ObjectName<Sampler>>,
+}
+
+/// Keeps track of the texture objects bound to each texture target
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextureBindings {
+    pub(crate) texture_1d: Option<ObjectName<Texture>>,
+    pub(crate) texture_2d: Option<ObjectName<Texture>>,
+    pub(crate) texture_3d: Option<ObjectName<Texture>>,
+    pub(crate) texture_1d_array: Option<ObjectName<Texture>>,
+    pub(crate) texture_2d_array: Option<ObjectName<Texture>>,
+    pub(crate) rectangle: Option<ObjectName<Texture>>,
+    pub(crate) cube_map: Option<ObjectName<Texture>>,
+    pub(crate) cube_map_array: Option<ObjectName<Texture>>,
+    pub(crate) buffer: Option<ObjectName<Texture>>,
+    pub(crate) texture_2d_multisample: Option<ObjectName<Texture>>,
+    pub(crate) texture_2d_multisample_array: Option<ObjectName<Texture>>,
+}
+impl TextureBindings {
+    /// Returns the binding slot for the given target, or an `InvalidEnum` error if `target` is not a texture binding point
+    /// (e.g. a proxy target or a single cube map face)
+    #[inline]
+    pub(crate) fn get_mut(
+        &mut self,
+        target: TextureTarget,
+    ) -> GlFallible<&mut Option<ObjectName<Texture>>> {
+        Ok(match target {
+            TextureTarget::Texture1D => &mut self.texture_1d,
+            TextureTarget::Texture2D => &mut self.texture_2d,
+            TextureTarget::Texture3D => &mut self.texture_3d,
+            TextureTarget::Texture1DArray => &mut self.texture_1d_array,
+            TextureTarget::Texture2DArray => &mut self.texture_2d_array,
+            TextureTarget::TextureRectangle => &mut self.rectangle,
+            TextureTarget::TextureCubeMap => &mut self.cube_map,
+            TextureTarget::TextureCubeMapArray => &mut self.cube_map_array,
+            TextureTarget::TextureBuffer => &mut self.buffer,
+            TextureTarget::Texture2DMultisample => &mut self.texture_2d_multisample,
+            TextureTarget::Texture2DMultisampleArray => &mut self.texture_2d_multisample_array,
+            _ => return Err(GlError::InvalidEnum.e()),
+        })
+    }
+    #[inline]
+    pub(crate) fn get(&self, target: TextureTarget) -> GlFallible<Option<ObjectName<Texture>>> {
+        Ok(match target {
+            TextureTarget::Texture1D => self.texture_1d,
+            TextureTarget::Texture2D => self.texture_2d,
+            TextureTarget::Texture3D => self.texture_3d,
+            TextureTarget::Texture1DArray => self.texture_1d_array,
+            TextureTarget::Texture2DArray => self.texture_2d_array,
+            TextureTarget::TextureRectangle => self.rectangle,
+            TextureTarget::TextureCubeMap => self.cube_map,
+            TextureTarget::TextureCubeMapArray => self.cube_map_array,
+            TextureTarget::TextureBuffer => self.buffer,
+            TextureTarget::Texture2DMultisample => self.texture_2d_multisample,
+            TextureTarget::Texture2DMultisampleArray => self.texture_2d_multisample_array,
+            _ => return Err(GlError::InvalidEnum.e()),
+        })
+    }
+    /// Returns an iterator over the textures bound to any target
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = ObjectName<Texture>> {
+        [
+            self.texture_1d,
+            self.texture_2d,
+            self.texture_3d,
+            self.texture_1d_array,
+            self.texture_2d_array,
+            self.rectangle,
+            self.cube_map,
+            self.cube_map_array,
+            self.buffer,
+            self.texture_2d_multisample,
+            self.texture_2d_multisample_array,
+        ]
+        .into_iter()
+        .flatten()
+    }
+    /// Breaks every binding of the given texture (e.g. because it was deleted)
+    #[inline]
+    pub(crate) fn unbind_all(&mut self, name: ObjectName<Texture>) {
+        for binding in [
+            &mut self.texture_1d,
+            &mut self.texture_2d,
+            &mut self.texture_3d,
+            &mut self.texture_1d_array,
+            &mut self.texture_2d_array,
+            &mut self.rectangle,
+            &mut self.cube_map,
+            &mut self.cube_map_array,
+            &mut self.buffer,
+            &mut self.texture_2d_multisample,
+            &mut self.texture_2d_multisample_array,
+        ] {
+            if *binding == Some(name) {
+                *binding = None;
+            }
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct Writemasks {
     pub(crate) color: [ColorWriteMask; MAX_COLOR_ATTACHMENTS as usize],
@@ -446,7 +692,7 @@ impl Default for Characteristics {
             point_size_granularity: 0.0001,
             context_flags: GL_CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT | GL_CONTEXT_FLAG_NO_ERROR_BIT,
             context_profile_mask: GL_CONTEXT_CORE_PROFILE_BIT,
-            num_extensions: 1,
+            num_extensions: Context::NUM_EXTENSIONS,
             point_size: 1.0,
             line_width: 1.0,
         }
@@ -1,52 +1,782 @@
-use std::{cell::Cell, fmt::Debug, num::NonZeroU32};
+use std::{cell::Cell, fmt::Debug, mem, ops::Range};
 
 use objc2::rc::Retained;
+use objc2_foundation::{NSRange, NSString};
 use objc2_metal::{
+    MTLBlitOption, MTLBuffer, MTLDevice, MTLOrigin, MTLPixelFormat, MTLResource,
     MTLSamplerAddressMode, MTLSamplerBorderColor, MTLSamplerDescriptor, MTLSamplerMinMagFilter,
-    MTLSamplerMipFilter, MTLTexture, MTLTextureSwizzle, MTLTextureType,
+    MTLSamplerMipFilter, MTLSamplerState, MTLSize, MTLStorageMode, MTLTexture,
+    MTLTextureDescriptor, MTLTextureSwizzle, MTLTextureSwizzleChannels, MTLTextureType,
+    MTLTextureUsage,
 };
 
 use crate::{
     dispatch::conversions::{GLenumExt, SrcType},
     enums::{
-        DepthFunction, InternalFormat, SamplerParameter, TextureMagFilter, TextureMinFilter,
-        TextureSwizzle, TextureTarget, TextureWrapMode,
+        DepthFunction, InternalFormat, SamplerParameter, TextureCompareMode, TextureMagFilter,
+        TextureMinFilter, TextureSwizzle, TextureTarget, TextureWrapMode,
     },
     util::ProtoObjRef,
 };
 
-use super::{debug::gl_err, error::GlFallible, gl_object::ObjectName};
+use super::{
+    Context,
+    commands::buffer::Buffer,
+    debug::{gl_debug, gl_err, gl_warn},
+    error::{GlError, GlFallible},
+    gl_object::{NamedObject, NoLateInit, ObjectName},
+    platform::PlatformState,
+    state::MAX_TEXTURE_MAX_ANISOTROPY,
+};
 
 /// * named: name is reserved, object is considered uninitialized
 /// * bound: object is initialized to default state, has no storage
-/// * complete: TODO
+/// * realized: object has backing storage, either specified all at once ([`immutable_format`](Texture::immutable_format))
+///   or assembled level by level (see [`Texture::realize`])
+/// * complete: object may be sampled (see [`Texture::is_complete`])
 #[derive(Debug)]
 pub struct Texture {
-    name: ObjectName<Self>,
-    target: TextureTarget,
-    sampling_state: SamplerParams,
-    realized: Option<RealizedTexture>,
+    pub(crate) name: ObjectName<Self>,
+    pub(crate) target: TextureTarget,
+    pub(crate) sampling_state: SamplerParams,
+    /// Whether this texture's storage was specified by glTexStorage* (`GL_TEXTURE_IMMUTABLE_FORMAT`).
+    /// Immutable textures may not have their storage re-specified
+    pub(crate) immutable_format: bool,
+    /// Format and extent of each specified image, indexed by mipmap level and then by cube map face.
+    /// Textures that are not cube maps only use the first face
+    pub(crate) images: Vec<[Option<TextureLevel>; 6]>,
+    /// `GL_TEXTURE_BASE_LEVEL`
+    pub(crate) base_level: u32,
+    /// `GL_TEXTURE_MAX_LEVEL`
+    pub(crate) max_level: u32,
+    /// `GL_TEXTURE_SWIZZLE_R`, `GL_TEXTURE_SWIZZLE_G`, `GL_TEXTURE_SWIZZLE_B` and `GL_TEXTURE_SWIZZLE_A`
+    pub(crate) swizzle: [TextureSwizzle; 4],
+    /// Whether depth-stencil textures are sampled as stencil (`GL_DEPTH_STENCIL_TEXTURE_MODE` is `GL_STENCIL_INDEX`)
+    /// rather than depth
+    pub(crate) stencil_sampling: bool,
+    pub(crate) realized: Option<RealizedTexture>,
+    /// Image data written to levels that are not backed by [`realized`](Self::realized), in the order it was written
+    /// (see [`Texture::defer_write`])
+    pub(crate) pending_writes: Vec<PendingWrite>,
+    /// The range of the origin texture's storage that this texture views, if it was initialized by glTextureView
+    pub(crate) view: Option<TextureViewRange>,
+    /// The buffer range that this buffer texture aliases, if one was attached by glTexBuffer*
+    pub(crate) buffer: Option<TextureBufferRange>,
+    /// Debug label set by glObjectLabel, which is applied to every storage this texture is given
+    label: Option<Retained<NSString>>,
+}
+/// The levels and layers of a texture's storage that a texture view covers (`GL_TEXTURE_VIEW_MIN_LEVEL`, `GL_TEXTURE_VIEW_NUM_LEVELS`,
+/// `GL_TEXTURE_VIEW_MIN_LAYER` and `GL_TEXTURE_VIEW_NUM_LAYERS`). Ranges of views of views are relative to the original storage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TextureViewRange {
+    pub(crate) min_level: u32,
+    pub(crate) num_levels: u32,
+    pub(crate) min_layer: u32,
+    pub(crate) num_layers: u32,
+}
+/// The data store of a buffer object that a buffer texture aliases (`GL_TEXTURE_BUFFER_DATA_STORE_BINDING`,
+/// `GL_TEXTURE_BUFFER_OFFSET` and `GL_TEXTURE_BUFFER_SIZE`), along with the format its texels are read with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TextureBufferRange {
+    pub(crate) buffer: ObjectName<Buffer>,
+    pub(crate) format: InternalFormat,
+    pub(crate) offset: usize,
+    /// Size of the range in bytes, or None if the texture aliases the whole data store (glTexBuffer), in which case it follows
+    /// the size of the buffer's storage
+    pub(crate) size: Option<usize>,
+}
+/// Image data written to a level of a texture before its storage covered that level, such as a mipmap level uploaded before
+/// the base level. Each of `planes` holds tightly packed data for every slice of `region` in turn, along with the blit options
+/// that select the aspect of the texture it is copied to
+#[derive(Debug, Clone)]
+pub(crate) struct PendingWrite {
+    pub(crate) level: u32,
+    /// The cube map faces written to (`0..1` for textures that are not cube maps)
+    pub(crate) faces: Range<usize>,
+    /// The slices, origin and size of the written region of the level's storage
+    pub(crate) region: (Range<usize>, MTLOrigin, MTLSize),
+    pub(crate) planes: Vec<(Vec<u8>, MTLBlitOption)>,
+    /// Side length of the texel blocks of block-compressed data, and 1 otherwise
+    pub(crate) block: usize,
 }
 /// Represents a realized texture's storage
 #[derive(Debug)]
 pub struct RealizedTexture {
-    mtl_tex: Option<ProtoObjRef<dyn MTLTexture>>,
-    format: InternalFormat,
-    width: u32,
-    height: Option<NonZeroU32>,
-    depth: Option<NonZeroU32>,
-    array_length: Option<NonZeroU32>,
+    pub(crate) mtl_tex: ProtoObjRef<dyn MTLTexture>,
+    /// GL mipmap level that corresponds to level 0 of [`mtl_tex`](Self::mtl_tex)
+    pub(crate) first_level: u32,
+    pub(crate) format: InternalFormat,
+    /// Extent of the base level of this texture
+    pub(crate) extent: TextureExtent,
+    /// Number of mipmap levels in the backing texture
+    pub(crate) levels: u32,
+    /// Number of samples per texel (1 for non-multisample textures)
+    pub(crate) samples: u32,
+    /// Cached view of [`mtl_tex`](Self::mtl_tex) that applies the texture's sampling-related parameters (see
+    /// [`Texture::sampled_texture`])
+    sampled_view: Option<(SampledViewKey, ProtoObjRef<dyn MTLTexture>)>,
+}
+/// Parameters of a sampled view of a texture's storage
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SampledViewKey {
+    format: MTLPixelFormat,
+    levels: Range<u32>,
+    swizzle: [TextureSwizzle; 4],
+}
+
+/// The size of a texture image as specified by the client. For array textures the layer count occupies the
+/// dimension following the last "real" dimension (e.g. `height` for 1D array textures)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TextureExtent {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) depth: u32,
+}
+
+impl TextureExtent {
+    #[inline]
+    pub(crate) fn new(width: u32, height: u32, depth: u32) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+        }
+    }
+    /// The number of levels in a full mipmap pyramid for an image of this extent bound to `target`
+    #[inline]
+    pub(crate) fn max_levels(self, target: TextureTarget) -> u32 {
+        let largest = match target {
+            TextureTarget::Texture1D | TextureTarget::Texture1DArray => self.width,
+            TextureTarget::Texture3D => self.width.max(self.height).max(self.depth),
+            _ => self.width.max(self.height),
+        };
+        u32::BITS - largest.leading_zeros()
+    }
+    /// The extent of mipmap level `level` of an image whose base level has this extent. Array layers are not minified
+    #[inline]
+    pub(crate) fn mip(self, target: TextureTarget, level: u32) -> Self {
+        let minify = |v: u32| v.checked_shr(level).unwrap_or(0).max(1);
+        match target {
+            TextureTarget::Texture1D | TextureTarget::Texture1DArray => {
+                Self::new(minify(self.width), self.height, self.depth)
+            }
+            TextureTarget::Texture3D => {
+                Self::new(minify(self.width), minify(self.height), minify(self.depth))
+            }
+            _ => Self::new(minify(self.width), minify(self.height), self.depth),
+        }
+    }
+    #[inline]
+    pub(crate) fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0 || self.depth == 0
+    }
+    /// Number of array layers (or cube faces, for cube maps) described by this extent
+    #[inline]
+    pub(crate) fn layers(self, target: TextureTarget) -> u32 {
+        match target {
+            TextureTarget::Texture1DArray => self.height,
+            TextureTarget::Texture2DArray
+            | TextureTarget::TextureCubeMapArray
+            | TextureTarget::Texture2DMultisampleArray => self.depth,
+            TextureTarget::TextureCubeMap => 6,
+            _ => 1,
+        }
+    }
 }
+
 impl Texture {
-    fn new_named(name: ObjectName<Self>, target: TextureTarget) -> Self {
+    pub(crate) fn new_named(name: ObjectName<Self>, target: TextureTarget) -> Self {
         Self {
             name,
             target,
             sampling_state: SamplerParams::default(),
+            immutable_format: false,
+            images: Vec::new(),
+            base_level: 0,
+            max_level: 1000,
+            swizzle: IDENTITY_SWIZZLE,
+            stencil_sampling: false,
             realized: None,
+            pending_writes: Vec::new(),
+            view: None,
+            label: None,
+            buffer: None,
+        }
+    }
+    /// Number of cube map faces of this texture (1 if it is not a cube map)
+    #[inline]
+    pub(crate) fn faces(&self) -> usize {
+        if self.target == TextureTarget::TextureCubeMap {
+            6
+        } else {
+            1
+        }
+    }
+    /// Returns the format and extent of the image at `level` of cube map face `face`, if it has been specified
+    #[inline]
+    pub(crate) fn image(&self, level: u32, face: usize) -> Option<TextureLevel> {
+        self.images.get(level as usize).and_then(|l| l[face])
+    }
+    /// Records the format and extent of the image at `level` of cube map face `face`, as specified by glTexImage*.
+    /// Backing storage is not touched until the texture is next [realized](Self::realize). Pending writes to the image are
+    /// dropped if its format or extent changed
+    pub(crate) fn specify_image(&mut self, level: u32, face: usize, image: TextureLevel) {
+        if self.image(level, face) != Some(image) {
+            self.pending_writes
+                .retain(|w| w.level != level || !w.faces.contains(&face));
+        }
+        let level = level as usize;
+        if self.images.len() <= level {
+            self.images.resize(level + 1, [None; 6]);
+        }
+        self.images[level][face] = Some(image);
+    }
+    /// Keeps image data written to a level that is not backed by this texture's storage, so that it can be written once storage
+    /// covering the level is allocated (see [`take_covered_writes`](Self::take_covered_writes))
+    pub(crate) fn defer_write(&mut self, write: PendingWrite) {
+        gl_debug!(
+            "deferring write to level {} of {:?} until its storage is allocated",
+            write.level,
+            self.name
+        );
+        self.pending_writes.push(write);
+    }
+    /// Removes and returns the pending writes to levels of the consistent mipmap chain, which [`realize`](Self::realize) allocates
+    /// storage for, in the order they were made
+    pub(crate) fn take_covered_writes(&mut self) -> Vec<PendingWrite> {
+        if self.pending_writes.is_empty() {
+            return Vec::new();
+        }
+        let (base, _) = self.level_range();
+        let covered = base..base + self.consistent_levels();
+        let (writes, pending) = mem::take(&mut self.pending_writes)
+            .into_iter()
+            .partition(|w| covered.contains(&w.level));
+        self.pending_writes = pending;
+        writes
+    }
+    /// The effective base and max level of this texture. Immutable-format textures have their levels clamped to the
+    /// range of levels they were allocated with
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "level count is bounded by the texture size limits"
+    )]
+    pub(crate) fn level_range(&self) -> (u32, u32) {
+        if self.immutable_format {
+            let last = (self.images.len() as u32).saturating_sub(1);
+            let base = self.base_level.min(last);
+            (base, self.max_level.clamp(base, last))
+        } else {
+            (self.base_level, self.max_level)
+        }
+    }
+    /// Number of consecutive levels, starting at the base level, whose images form a consistent mipmap chain: each
+    /// level must be specified for every face with the same format as the base level and the extent expected of its level.
+    /// Returns 0 if the base level (or, for cube maps, any of its faces) is missing, empty or not cube complete
+    pub(crate) fn consistent_levels(&self) -> u32 {
+        let (base, max) = self.level_range();
+        if base > max {
+            return 0;
+        }
+        let Some(base_image) = self.image(base, 0) else {
+            return 0;
+        };
+        if base_image.extent.is_empty()
+            || (self.faces() == 6 && base_image.extent.width != base_image.extent.height)
+        {
+            return 0;
+        }
+        let last = max.min(base + base_image.extent.max_levels(self.target) - 1);
+        let mut levels = 0;
+        for level in base..=last {
+            let expected = TextureLevel {
+                format: base_image.format,
+                extent: base_image.extent.mip(self.target, level - base),
+            };
+            if (0..self.faces()).any(|face| self.image(level, face) != Some(expected)) {
+                break;
+            }
+            levels += 1;
+        }
+        levels
+    }
+    /// Specifies the images that glGenerateMipmap derives from the base level, from the level after it up to the effective max
+    /// level (or the end of the mipmap pyramid), with the format of the base level. Returns the first and last level of the resulting
+    /// mipmap chain, or None if the base level is missing or empty
+    pub(crate) fn specify_mipmap_levels(&mut self) -> Option<(u32, u32)> {
+        let (base, max) = self.level_range();
+        let base_image = self.image(base, 0).filter(|i| !i.extent.is_empty())?;
+        let last = max.min(base + base_image.extent.max_levels(self.target) - 1);
+        // immutable-format textures already have every level specified
+        if !self.immutable_format {
+            for level in base + 1..=last {
+                let image = TextureLevel {
+                    format: base_image.format,
+                    extent: base_image.extent.mip(self.target, level - base),
+                };
+                for face in 0..self.faces() {
+                    self.specify_image(level, face, image);
+                }
+            }
+        }
+        Some((base, last))
+    }
+    /// Evaluates whether this texture is complete when sampled with `sampling` (see section 8.17 of the OpenGL 4.6 core spec).
+    /// Sampling an incomplete texture behaves as if it had no image bound
+    pub(crate) fn is_complete(&self, sampling: &SamplerParams) -> bool {
+        // buffer textures have no levels and are not filtered
+        if self.target == TextureTarget::TextureBuffer {
+            return self.buffer.is_some();
+        }
+        let levels = self.consistent_levels();
+        if levels == 0 {
+            return false;
+        }
+        // multisample textures have a single level and are not filtered
+        if matches!(
+            self.target,
+            TextureTarget::Texture2DMultisample | TextureTarget::Texture2DMultisampleArray
+        ) {
+            return true;
+        }
+        let (base, max) = self.level_range();
+        let Some(base_image) = self.image(base, 0) else {
+            return false;
+        };
+        if sampling.min_filter.uses_mipmaps() {
+            let required = (max - base)
+                .saturating_add(1)
+                .min(base_image.extent.max_levels(self.target));
+            if levels < required {
+                return false;
+            }
+        }
+        // integer textures may only be sampled with nearest filtering, which includes depth-stencil textures that sample
+        // their stencil indices
+        let format = base_image.format;
+        let integer = format.is_integer()
+            || (self.stencil_sampling && format.has_depth() && format.has_stencil());
+        !(integer
+            && (sampling.mag_filter != TextureMagFilter::Nearest
+                || !matches!(
+                    sampling.min_filter,
+                    TextureMinFilter::Nearest | TextureMinFilter::NearestMipmapNearest
+                )))
+    }
+    /// Returns the level of the backing Metal texture that stores `level` of this texture, if the current backing storage
+    /// contains an image matching that level's specification
+    pub(crate) fn storage_level(&self, level: u32, face: usize) -> Option<u32> {
+        let realized = self.realized.as_ref()?;
+        let storage_level = level
+            .checked_sub(realized.first_level)
+            .filter(|&l| l < realized.levels)?;
+        let expected = TextureLevel {
+            format: realized.format,
+            extent: realized.extent.mip(self.target, storage_level),
+        };
+        (self.image(level, face) == Some(expected)).then_some(storage_level)
+    }
+    /// Lazily (re)allocates backing storage for this texture's consistent level chain, if there is one and the current storage
+    /// does not already cover it. Returns the previous storage if it was replaced so that the caller may copy over the contents
+    /// of any levels that are still backed by the new storage
+    pub(crate) fn realize(&mut self, platform: &PlatformState) -> Option<RealizedTexture> {
+        if self.immutable_format {
+            return None;
+        }
+        let levels = self.consistent_levels();
+        if levels == 0 {
+            return None;
+        }
+        let (base, max) = self.level_range();
+        let base_image = self.image(base, 0)?;
+        if let Some(r) = &self.realized
+            && r.first_level == base
+            && r.format == base_image.format
+            && r.extent == base_image.extent
+            && r.levels >= levels
+        {
+            return None;
+        }
+        // Clients usually specify mipmapped textures one level at a time. If the texture will be sampled with mipmaps,
+        // allocate the whole chain up front so it isn't reallocated every time another level is specified
+        let levels = if self.sampling_state.min_filter.uses_mipmaps()
+            && self.target != TextureTarget::TextureRectangle
+        {
+            levels.max(
+                (max - base)
+                    .saturating_add(1)
+                    .min(base_image.extent.max_levels(self.target)),
+            )
+        } else {
+            levels
+        };
+        let new = RealizedTexture {
+            first_level: base,
+            ..RealizedTexture::new(
+                platform,
+                self.target,
+                base_image.format,
+                base_image.extent,
+                levels,
+                1,
+            )
+        };
+        self.label_storage(&new);
+        self.realized.replace(new)
+    }
+    /// Allocates immutable backing storage for this texture. Parameters must have already been validated against this texture's target
+    pub(crate) fn make_immutable_storage(
+        &mut self,
+        platform: &PlatformState,
+        format: InternalFormat,
+        extent: TextureExtent,
+        levels: u32,
+        samples: u32,
+    ) {
+        debug_assert!(
+            !self.immutable_format,
+            "tried to re-specify the storage of an immutable texture"
+        );
+        self.set_immutable_storage(RealizedTexture::new(
+            platform,
+            self.target,
+            format,
+            extent,
+            levels,
+            samples,
+        ));
+    }
+    /// Initializes this texture as a view of `range` of another texture's storage. `storage` must have been created by
+    /// [`RealizedTexture::new_view`] for this texture's target
+    pub(crate) fn make_view(&mut self, storage: RealizedTexture, range: TextureViewRange) {
+        self.set_immutable_storage(storage);
+        self.view = Some(range);
+    }
+    /// Returns the Metal texture to bind when sampling this texture, or None if it has no storage covering its base level.
+    /// The storage is bound as-is unless the texture's level range, swizzle or depth stencil texture mode call for a
+    /// texture view, which is created lazily and cached until those parameters change
+    pub(crate) fn sampled_texture(&mut self) -> Option<ProtoObjRef<dyn MTLTexture>> {
+        let (base, max) = self.level_range();
+        let realized = self.realized.as_mut()?;
+        // buffer textures can't be viewed, and have no levels or swizzle anyways
+        if self.target == TextureTarget::TextureBuffer {
+            return Some(realized.mtl_tex.clone());
+        }
+        let first = base.checked_sub(realized.first_level)?;
+        let last = max
+            .saturating_sub(realized.first_level)
+            .min(realized.levels - 1);
+        if first > last {
+            return None;
+        }
+        let storage_format = realized.mtl_tex.pixelFormat();
+        let format = match storage_format {
+            MTLPixelFormat::Depth32Float_Stencil8 if self.stencil_sampling => {
+                MTLPixelFormat::X32_Stencil8
+            }
+            MTLPixelFormat::Depth24Unorm_Stencil8 if self.stencil_sampling => {
+                MTLPixelFormat::X24_Stencil8
+            }
+            f => f,
+        };
+        // RGB S3TC images are stored in Metal's BC1 formats, whose punchthrough alpha must read as one
+        let swizzle = if matches!(
+            realized.format,
+            InternalFormat::CompressedRgbS3tcDxt1Ext | InternalFormat::CompressedSrgbS3tcDxt1Ext
+        ) {
+            self.swizzle.map(|s| match s {
+                TextureSwizzle::Alpha => TextureSwizzle::One,
+                s => s,
+            })
+        } else {
+            self.swizzle
+        };
+        let key = SampledViewKey {
+            format,
+            levels: first..last + 1,
+            swizzle,
+        };
+        if key.format == storage_format
+            && key.levels == (0..realized.levels)
+            && key.swizzle == IDENTITY_SWIZZLE
+        {
+            return Some(realized.mtl_tex.clone());
+        }
+        if let Some((cached_key, view)) = &realized.sampled_view
+            && *cached_key == key
+        {
+            return Some(view.clone());
+        }
+        gl_debug!(
+            "creating sampled view of {:?} with parameters {key:?}",
+            self.name
+        );
+        let tex = &realized.mtl_tex;
+        let slices = match tex.textureType() {
+            MTLTextureType::TypeCube | MTLTextureType::TypeCubeArray => tex.arrayLength() * 6,
+            _ => tex.arrayLength(),
+        };
+        let [red, green, blue, alpha] = key.swizzle.map(MTLTextureSwizzle::from);
+        // Safety: the level range lies within the storage, the slice range covers all of its slices, and the view format is
+        // either the storage format or the stencil aspect of a depth-stencil storage format. All non-buffer storage is
+        // allocated with `MTLTextureUsage::PixelFormatView`
+        let view = unsafe {
+            tex.newTextureViewWithPixelFormat_textureType_levels_slices_swizzle(
+                key.format,
+                tex.textureType(),
+                NSRange::new(key.levels.start as usize, key.levels.len()),
+                NSRange::new(0, slices),
+                MTLTextureSwizzleChannels {
+                    red,
+                    green,
+                    blue,
+                    alpha,
+                },
+            )
+        }
+        .expect("failed to create Metal texture view");
+        realized.sampled_view = Some((key, view.clone()));
+        Some(view)
+    }
+    /// Applies this texture's debug label to `storage`, which is about to become its storage
+    pub(crate) fn label_storage(&self, storage: &RealizedTexture) {
+        if self.label.is_some() {
+            storage.mtl_tex.setLabel(self.label.as_deref());
+        }
+    }
+    fn set_immutable_storage(&mut self, storage: RealizedTexture) {
+        self.images = (0..storage.levels)
+            .map(|level| {
+                let image = Some(TextureLevel {
+                    format: storage.format,
+                    extent: storage.extent.mip(self.target, level),
+                });
+                let mut faces = [None; 6];
+                faces[..self.faces()].fill(image);
+                faces
+            })
+            .collect();
+        self.label_storage(&storage);
+        self.realized = Some(storage);
+        self.immutable_format = true;
+    }
+}
+impl RealizedTexture {
+    /// Allocates a new Metal texture in GPU private memory matching the given description. Block-compressed formats that the
+    /// device can't sample are stored in their [decompressed equivalent](InternalFormat::decompressed_equivalent) instead
+    pub(crate) fn new(
+        platform: &PlatformState,
+        target: TextureTarget,
+        format: InternalFormat,
+        extent: TextureExtent,
+        levels: u32,
+        samples: u32,
+    ) -> Self {
+        gl_debug!(
+            "allocating {target:?} storage with format {format:?}, extent {extent:?}, {levels} levels and {samples} samples"
+        );
+        let mut mtl_format = format.mtl_texture_format();
+        if let Some(fallback) = format.decompressed_equivalent()
+            && !platform.props.supports_compressed_format(mtl_format)
+        {
+            gl_warn!(ty: Performance, "{format:?} is not supported by this device, storing it as {fallback:?} and decompressing image data on the CPU");
+            mtl_format = fallback.mtl_texture_format();
+        }
+        let desc = MTLTextureDescriptor::new();
+        desc.setTextureType(target.into());
+        desc.setPixelFormat(mtl_format);
+        desc.setStorageMode(MTLStorageMode::Private);
+        let mut usage = MTLTextureUsage::ShaderRead | MTLTextureUsage::PixelFormatView;
+        // block compressed formats can't be rendered to
+        if !format.is_block_compressed() {
+            usage |= MTLTextureUsage::RenderTarget;
+        }
+        desc.setUsage(usage);
+        let (height, depth) = match target {
+            TextureTarget::Texture1D | TextureTarget::Texture1DArray => (1, 1),
+            TextureTarget::Texture3D => (extent.height, extent.depth),
+            _ => (extent.height, 1),
+        };
+        // Metal counts cube map array length in cubes rather than layer-faces, and plain cube maps have an array length of 1
+        let array_length = match target {
+            TextureTarget::TextureCubeMap => 1,
+            TextureTarget::TextureCubeMapArray => extent.depth / 6,
+            t => extent.layers(t),
+        };
+        // Safety: dimensions, level count and sample count were validated against the GL's advertised limits
+        // (which lie within Metal's limits) by the caller
+        unsafe {
+            desc.setWidth(extent.width as usize);
+            desc.setHeight(height as usize);
+            desc.setDepth(depth as usize);
+            desc.setArrayLength(array_length as usize);
+            desc.setMipmapLevelCount(levels as usize);
+            desc.setSampleCount(samples as usize);
+        }
+        let mtl_tex = platform
+            .device
+            .newTextureWithDescriptor(&desc)
+            .expect("failed to allocate Metal texture");
+        Self {
+            mtl_tex,
+            first_level: 0,
+            format,
+            extent,
+            levels,
+            samples,
+            sampled_view: None,
+        }
+    }
+    /// Creates a buffer texture of `texels` texels of `format` that aliases the storage of `buffer`, starting `offset` bytes into it.
+    /// The Metal texture keeps `buffer` alive on its own, so it must be recreated whenever the buffer object's storage is reallocated.
+    ///
+    /// `format`'s Metal pixel format must have the same texel size as `format`, and the aliased range must lie within `buffer`
+    pub(crate) fn new_buffer(
+        buffer: &ProtoObjRef<dyn MTLBuffer>,
+        format: InternalFormat,
+        offset: usize,
+        texels: usize,
+        texel_size: usize,
+    ) -> Self {
+        gl_debug!(
+            "creating {format:?} buffer texture of {texels} texels at offset {offset} of a {} byte buffer",
+            buffer.length()
+        );
+        let usage = MTLTextureUsage::ShaderRead | MTLTextureUsage::ShaderWrite;
+        // Safety: buffer textures have a single row, which holds at most as many texels as Metal allows in a texture buffer
+        let desc = unsafe {
+            MTLTextureDescriptor::textureBufferDescriptorWithPixelFormat_width_resourceOptions_usage(
+                format.mtl_texture_format(),
+                texels,
+                buffer.resourceOptions(),
+                usage,
+            )
+        };
+        let mtl_tex = buffer
+            .newTextureWithDescriptor_offset_bytesPerRow(&desc, offset, texels * texel_size)
+            .expect("failed to create Metal texture buffer");
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "texel count is limited by MAX_TEXTURE_BUFFER_SIZE"
+        )]
+        let extent = TextureExtent::new(texels as u32, 1, 1);
+        Self {
+            mtl_tex,
+            first_level: 0,
+            format,
+            extent,
+            levels: 1,
+            samples: 1,
+            sampled_view: None,
+        }
+    }
+    /// Creates storage that aliases `levels` and `slices` (array layers or cube map layer-faces) of this storage, reinterpreted
+    /// with `format` and the texture type of `target`. The resulting Metal texture view keeps this storage alive on its own, so
+    /// it remains valid after the texture this storage belongs to is deleted.
+    ///
+    /// `format` must be view-compatible with this storage's format, `target` must be a compatible view target and `extent` must be
+    /// the extent of the first viewed level with the viewed layer count
+    pub(crate) fn new_view(
+        &self,
+        target: TextureTarget,
+        format: InternalFormat,
+        extent: TextureExtent,
+        levels: Range<u32>,
+        slices: Range<u32>,
+    ) -> Self {
+        // views of block-compressed storage that was decompressed by the CPU must use the decompressed view format as well
+        let mtl_format = match format.decompressed_equivalent() {
+            Some(fallback) if self.mtl_tex.pixelFormat() != self.format.mtl_texture_format() => {
+                fallback.mtl_texture_format()
+            }
+            _ => format.mtl_texture_format(),
+        };
+        gl_debug!(
+            "creating {target:?} view of levels {levels:?} and slices {slices:?} of {:?} storage as {format:?}",
+            self.format
+        );
+        // Safety: the caller ensures that the level and slice ranges lie within this storage, and that the view's pixel format and
+        // texture type are compatible with it. All storage is allocated with `MTLTextureUsage::PixelFormatView`
+        let mtl_tex = unsafe {
+            self.mtl_tex
+                .newTextureViewWithPixelFormat_textureType_levels_slices(
+                    mtl_format,
+                    target.into(),
+                    NSRange::new(levels.start as usize, levels.len()),
+                    NSRange::new(slices.start as usize, slices.len()),
+                )
+        }
+        .expect("failed to create Metal texture view");
+        Self {
+            mtl_tex,
+            first_level: 0,
+            format,
+            extent,
+            levels: levels.end - levels.start,
+            samples: self.samples,
+            sampled_view: None,
         }
     }
-    fn make_immutable_storage(&mut self, levels: u32) {}
+}
+impl TextureTarget {
+    /// Whether a texture view with target `view` may be created from a texture with this target (see table 8.22 of the
+    /// OpenGL 4.6 core spec)
+    pub(crate) fn accepts_view(self, view: Self) -> bool {
+        use TextureTarget as T;
+        matches!(
+            (self, view),
+            (
+                T::Texture1D | T::Texture1DArray,
+                T::Texture1D | T::Texture1DArray
+            ) | (T::Texture2D, T::Texture2D | T::Texture2DArray)
+                | (T::Texture3D, T::Texture3D)
+                | (
+                    T::TextureCubeMap | T::Texture2DArray | T::TextureCubeMapArray,
+                    T::Texture2D | T::Texture2DArray | T::TextureCubeMap | T::TextureCubeMapArray
+                )
+                | (T::TextureRectangle, T::TextureRectangle)
+                | (
+                    T::Texture2DMultisample | T::Texture2DMultisampleArray,
+                    T::Texture2DMultisample | T::Texture2DMultisampleArray
+                )
+        )
+    }
+    /// Splits a possibly-proxy texture target into its non-proxy equivalent and whether it was a proxy target
+    pub(crate) fn strip_proxy(self) -> (Self, bool) {
+        match self {
+            Self::ProxyTexture1D => (Self::Texture1D, true),
+            Self::ProxyTexture2D => (Self::Texture2D, true),
+            Self::ProxyTexture3D => (Self::Texture3D, true),
+            Self::ProxyTexture1DArray => (Self::Texture1DArray, true),
+            Self::ProxyTexture2DArray => (Self::Texture2DArray, true),
+            Self::ProxyTextureRectangle => (Self::TextureRectangle, true),
+            Self::ProxyTextureCubeMap => (Self::TextureCubeMap, true),
+            Self::ProxyTextureCubeMapArray => (Self::TextureCubeMapArray, true),
+            Self::ProxyTexture2DMultisample => (Self::Texture2DMultisample, true),
+            Self::ProxyTexture2DMultisampleArray => (Self::Texture2DMultisampleArray, true),
+            t => (t, false),
+        }
+    }
+    /// Returns the index of the cube map face this target refers to (in the order of `GL_TEXTURE_CUBE_MAP_POSITIVE_X` through
+    /// `GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`, which matches Metal's slice order), or None if it is not a cube map face
+    pub(crate) fn cube_face(self) -> Option<usize> {
+        match self {
+            Self::TextureCubeMapPositiveX => Some(0),
+            Self::TextureCubeMapNegativeX => Some(1),
+            Self::TextureCubeMapPositiveY => Some(2),
+            Self::TextureCubeMapNegativeY => Some(3),
+            Self::TextureCubeMapPositiveZ => Some(4),
+            Self::TextureCubeMapNegativeZ => Some(5),
+            _ => None,
+        }
+    }
+}
+impl TextureMinFilter {
+    /// Whether this minification filter samples from mipmap levels other than the base level
+    #[inline]
+    pub(crate) fn uses_mipmaps(self) -> bool {
+        !matches!(self, Self::Nearest | Self::Linear)
+    }
 }
 impl From<TextureTarget> for MTLTextureType {
     fn from(value: TextureTarget) -> Self {
@@ -79,14 +809,74 @@ impl From<TextureTarget> for MTLTextureType {
     }
 }
 
-struct TextureLevel {
-    /// Whether this level is considered "complete" (see the spec for a definition)
-    complete: bool,
+impl NamedObject for Texture {
+    type LateInitType = NoLateInit<Self>;
+    fn set_debug_label(
+        ctx: &mut Context,
+        name: ObjectName<Self>,
+        label: Option<Retained<NSString>>,
+    ) {
+        if let Some(tex) = ctx.gl_state.texture_list.get_opt_mut(name) {
+            // textures usually get their storage after they are labeled, so the label is kept around to apply to it
+            tex.label = label;
+            if let Some(r) = &tex.realized {
+                r.mtl_tex.setLabel(tex.label.as_deref());
+            }
+        }
+    }
+}
+
+/// The initial value of [`Texture::swizzle`]
+pub(crate) const IDENTITY_SWIZZLE: [TextureSwizzle; 4] = [
+    TextureSwizzle::Red,
+    TextureSwizzle::Green,
+    TextureSwizzle::Blue,
+    TextureSwizzle::Alpha,
+];
+
+/// Format and extent of a single texture image (one mipmap level of one cube map face)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TextureLevel {
+    pub(crate) format: InternalFormat,
+    pub(crate) extent: TextureExtent,
 }
+/// A sampler object, whose sampling state overrides that of the textures bound to the texture units it is bound to
 #[derive(Debug)]
 pub struct Sampler {
-    name: ObjectName<Self>,
-    params: SamplerParams,
+    pub(crate) name: ObjectName<Self>,
+    pub(crate) params: SamplerParams,
+    /// Debug label applied to the sampler states created from this sampler's state
+    label: Option<Retained<NSString>>,
+}
+impl Sampler {
+    pub(crate) fn new_named(name: ObjectName<Self>) -> Self {
+        Self {
+            name,
+            params: SamplerParams::default(),
+            label: None,
+        }
+    }
+    /// Returns a Metal sampler state with this sampler's state
+    pub(crate) fn sampler_state(
+        &self,
+        device: &ProtoObjRef<dyn MTLDevice>,
+    ) -> ProtoObjRef<dyn MTLSamplerState> {
+        self.params.sampler_state(device, self.label.as_deref())
+    }
+}
+impl NamedObject for Sampler {
+    type LateInitType = NoLateInit<Self>;
+    fn set_debug_label(
+        ctx: &mut Context,
+        name: ObjectName<Self>,
+        label: Option<Retained<NSString>>,
+    ) {
+        if let Some(sampler) = ctx.gl_state.sampler_list.get_opt_mut(name) {
+            sampler.label = label;
+            // the label is baked into the cached sampler state
+            sampler.params.mark_dirty();
+        }
+    }
 }
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Anisotropy {
@@ -96,6 +886,13 @@ pub(crate) enum Anisotropy {
 }
 
 impl Anisotropy {
+    /// The value of `GL_TEXTURE_MAX_ANISOTROPY` that corresponds to this setting
+    pub(crate) fn as_float(self) -> f32 {
+        match self {
+            Self::NoAnisotropic => 1.0,
+            Self::Samples(n) => f32::from(n),
+        }
+    }
     fn from_float(val: f32) -> Option<Self> {
         // Next floating point value from 2.0
         const VAL: f32 = 2.0_f32.next_up();
@@ -116,9 +913,11 @@ impl Anisotropy {
 /// Note: must call [`SamplerParams::mark_dirty`] after modifying values in this struct
 pub struct SamplerParams {
     /// Border color for border wrap mode
-    pub(crate) border_color: [f32; 4],
-    /// Depth comparison mode if depth comparison is enabled
-    pub(crate) depth_compare: Option<DepthFunction>,
+    pub(crate) border_color: BorderColor,
+    /// Whether depth textures are sampled by comparing against a reference value (`GL_TEXTURE_COMPARE_MODE`)
+    pub(crate) compare_mode: TextureCompareMode,
+    /// Comparison function used when [`compare_mode`](Self::compare_mode) is `GL_COMPARE_REF_TO_TEXTURE`
+    pub(crate) compare_func: DepthFunction,
     /// Magnification filter
     pub(crate) mag_filter: TextureMagFilter,
     /// Minification filter and mipmap filter for mipmapped sampling
@@ -132,10 +931,11 @@ pub struct SamplerParams {
     pub(crate) wrap_mode_t: TextureWrapMode,
     pub(crate) wrap_mode_r: TextureWrapMode,
     descriptor_cache: CloneOptionCell<Retained<MTLSamplerDescriptor>>,
+    state_cache: CloneOptionCell<ProtoObjRef<dyn MTLSamplerState>>,
 }
 
 impl SamplerParams {
-    fn sampler_param(
+    pub(crate) fn sampler_param(
         &mut self,
         pname: SamplerParameter,
         param: impl SrcType<f32> + SrcType<i32> + SrcType<u32>,
@@ -156,43 +956,90 @@ impl SamplerParams {
             SamplerParameter::TextureWrapR => {
                 self.wrap_mode_r = param.try_into_enum()?;
             }
-            SamplerParameter::TextureMinLod => todo!(),
-            SamplerParameter::TextureMaxLod => todo!(),
-            SamplerParameter::TextureLodBias => todo!(),
-            SamplerParameter::TextureCompareMode => todo!(),
-            SamplerParameter::TextureCompareFunc => todo!(),
-            SamplerParameter::TextureMaxAnisotropy => todo!(),
+            SamplerParameter::TextureMinLod => {
+                self.min_lod = param.convert();
+            }
+            SamplerParameter::TextureMaxLod => {
+                self.max_lod = param.convert();
+            }
+            SamplerParameter::TextureLodBias => {
+                // Metal samplers have no LOD bias, it is applied by the shader instead (see `shader_emulation`)
+                self.lod_bias = param.convert();
+            }
+            SamplerParameter::TextureCompareMode => {
+                self.compare_mode = param.try_into_enum()?;
+            }
+            SamplerParameter::TextureCompareFunc => {
+                self.compare_func = param.try_into_enum()?;
+            }
+            SamplerParameter::TextureMaxAnisotropy => {
+                let val: f32 = param.convert();
+                // values above the implementation's maximum are clamped to it
+                self.max_anisotropy = Anisotropy::from_float(val.min(MAX_TEXTURE_MAX_ANISOTROPY))
+                    .ok_or_else(|| {
+                    gl_err!(ty: Error, "max anisotropy must be at least 1.0 (got {val})");
+                    GlError::InvalidValue.e()
+                })?;
+            }
             SamplerParameter::TextureBorderColor => {
                 unreachable!()
             }
         }
+        self.mark_dirty();
         Ok(())
     }
 
-    fn sampler_desc(&self) -> Retained<MTLSamplerDescriptor> {
+    /// Bitmask of the texture coordinates (s, t, r) whose clamp to border must be emulated by the shader, which is
+    /// empty if Metal can express the border color natively (or no wrap mode uses the border color at all)
+    fn emulated_border_axes(&self) -> u32 {
+        if self.border_color.native().is_some() {
+            return 0;
+        }
+        [self.wrap_mode_s, self.wrap_mode_t, self.wrap_mode_r]
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, mode)| mode == TextureWrapMode::ClampToBorder)
+            .fold(0, |mask, (i, _)| mask | (1 << i))
+    }
+    /// Returns the state this sampler needs emulated by the shaders that sample through it (border colors Metal can't
+    /// express and the LOD bias), or `None` if Metal samplers handle all of it
+    pub(crate) fn shader_emulation(&self) -> Option<SamplerEmulation> {
+        let axes = self.emulated_border_axes();
+        (axes != 0 || self.lod_bias != 0.0).then(|| SamplerEmulation {
+            color: self.border_color.bits(),
+            axes,
+            lod_bias: self.lod_bias.to_bits(),
+        })
+    }
+    pub(crate) fn set_border_color(&mut self, color: BorderColor) {
+        self.border_color = color;
+        self.mark_dirty();
+    }
+
+    pub(crate) fn sampler_desc(&self) -> Retained<MTLSamplerDescriptor> {
         if let Some(d) = self.descriptor_cache.clone_out() {
             return d;
         }
         let desc = MTLSamplerDescriptor::new();
-        if [self.wrap_mode_r, self.wrap_mode_s, self.wrap_mode_t]
-            .contains(&TextureWrapMode::ClampToBorder)
-        {
-            let border_color = match self.border_color {
-                [0.0, 0.0, 0.0, v] => match v {
-                    1.0 => MTLSamplerBorderColor::OpaqueBlack,
-                    _ => MTLSamplerBorderColor::TransparentBlack,
-                },
-                [1.0, 1.0, 1.0, 1.0] => MTLSamplerBorderColor::OpaqueWhite,
-                _ => {
-                    gl_err!(ty: Error, "unsupported texture border color used, defaulting to opaque white");
-                    MTLSamplerBorderColor::OpaqueWhite
-                }
-            };
+        let emulated = self.emulated_border_axes() != 0;
+        if let Some(border_color) = self.border_color.native() {
             desc.setBorderColor(border_color);
         }
-        if let Some(depth_compare_func) = self.depth_compare {
-            desc.setCompareFunction(depth_compare_func.into());
+        // emulated border colors are applied by the shader, which needs the sampler to clamp to the edge
+        // texels instead
+        let address_mode = |mode: TextureWrapMode| {
+            if emulated && mode == TextureWrapMode::ClampToBorder {
+                MTLSamplerAddressMode::ClampToEdge
+            } else {
+                mode.into()
+            }
+        };
+        if self.compare_mode == TextureCompareMode::CompareRefToTexture {
+            desc.setCompareFunction(self.compare_func.into());
         }
+        // Metal doesn't support negative LOD clamps, but the LOD can't be negative anyways
+        desc.setLodMinClamp(self.min_lod.max(0.0));
+        desc.setLodMaxClamp(self.max_lod.max(0.0));
         desc.setMagFilter(self.mag_filter.into());
         if let Anisotropy::Samples(n) = self.max_anisotropy {
             desc.setMaxAnisotropy(n as usize);
@@ -200,21 +1047,92 @@ impl SamplerParams {
         let (minification, mip) = self.min_filter.into();
         desc.setMinFilter(minification);
         desc.setMipFilter(mip);
-        desc.setSAddressMode(self.wrap_mode_s.into());
-        desc.setTAddressMode(self.wrap_mode_t.into());
-        desc.setRAddressMode(self.wrap_mode_r.into());
+        desc.setSAddressMode(address_mode(self.wrap_mode_s));
+        desc.setTAddressMode(address_mode(self.wrap_mode_t));
+        desc.setRAddressMode(address_mode(self.wrap_mode_r));
         self.descriptor_cache.set(Some(desc.clone()));
         desc
     }
-    fn mark_dirty(&self) {
+    /// Returns a Metal sampler state with these sampling parameters, which is cached until they change
+    pub(crate) fn sampler_state(
+        &self,
+        device: &ProtoObjRef<dyn MTLDevice>,
+        label: Option<&NSString>,
+    ) -> ProtoObjRef<dyn MTLSamplerState> {
+        if let Some(s) = self.state_cache.clone_out() {
+            return s;
+        }
+        let desc = self.sampler_desc();
+        desc.setLabel(label);
+        let state = device
+            .newSamplerStateWithDescriptor(&desc)
+            .expect("failed to create sampler state");
+        self.state_cache.set(Some(state.clone()));
+        state
+    }
+    pub(crate) fn mark_dirty(&self) {
         self.descriptor_cache.set(None);
+        self.state_cache.set(None);
+    }
+}
+/// Texture border color, kept in the representation it was specified with since integer textures
+/// sample integer border colors as-is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BorderColor {
+    /// Set via `glTexParameterfv`/`glTexParameteriv` (integer values are normalized)
+    Float([f32; 4]),
+    /// Set via `glTexParameterIiv`
+    Int([i32; 4]),
+    /// Set via `glTexParameterIuiv`
+    Uint([u32; 4]),
+}
+impl BorderColor {
+    /// Returns the equivalent Metal border color, if there is one
+    pub(crate) fn native(self) -> Option<MTLSamplerBorderColor> {
+        match self {
+            Self::Float([0.0, 0.0, 0.0, 0.0])
+            | Self::Int([0, 0, 0, 0])
+            | Self::Uint([0, 0, 0, 0]) => Some(MTLSamplerBorderColor::TransparentBlack),
+            Self::Float([0.0, 0.0, 0.0, 1.0])
+            | Self::Int([0, 0, 0, 1])
+            | Self::Uint([0, 0, 0, 1]) => Some(MTLSamplerBorderColor::OpaqueBlack),
+            Self::Float([1.0, 1.0, 1.0, 1.0])
+            | Self::Int([1, 1, 1, 1])
+            | Self::Uint([1, 1, 1, 1]) => Some(MTLSamplerBorderColor::OpaqueWhite),
+            _ => None,
+        }
+    }
+    /// Bit pattern of this color as seen by the emulated border color lookup in translated shaders,
+    /// which reinterprets it as the sampled texture's component type
+    pub(crate) fn bits(self) -> [u32; 4] {
+        match self {
+            Self::Float(c) => c.map(f32::to_bits),
+            #[expect(
+                clippy::cast_sign_loss,
+                reason = "reinterpreted as signed by the shader"
+            )]
+            Self::Int(c) => c.map(|v| v as u32),
+            Self::Uint(c) => c,
+        }
     }
 }
+/// Shader-side sampler state (clamp to border and LOD bias) for a single texture unit, passed to translated shaders as
+/// function constants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct SamplerEmulation {
+    /// Raw border color bits (see [`BorderColor::bits`])
+    pub(crate) color: [u32; 4],
+    /// Bitmask of the texture coordinates (s, t, r) that clamp to the border, 0 if border clamping isn't emulated
+    pub(crate) axes: u32,
+    /// Bits of the LOD bias that is added to the level of detail of every sample
+    pub(crate) lod_bias: u32,
+}
 impl Default for SamplerParams {
     fn default() -> Self {
         Self {
-            border_color: [0.0; 4],
-            depth_compare: None,
+            border_color: BorderColor::Float([0.0; 4]),
+            compare_mode: TextureCompareMode::None,
+            compare_func: DepthFunction::Lequal,
             mag_filter: TextureMagFilter::Linear,
             min_filter: TextureMinFilter::NearestMipmapLinear,
             lod_bias: 0.0,
@@ -225,6 +1143,7 @@ impl Default for SamplerParams {
             wrap_mode_t: TextureWrapMode::Repeat,
             wrap_mode_r: TextureWrapMode::Repeat,
             descriptor_cache: CloneOptionCell::new(None),
+            state_cache: CloneOptionCell::new(None),
         }
     }
 }
@@ -309,3 +1228,333 @@ impl<T> CloneOptionCell<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RGBA8: InternalFormat = InternalFormat::Rgba8;
+
+    fn texture(target: TextureTarget) -> Texture {
+        Texture::new_named(ObjectName::from_raw(1), target)
+    }
+    fn level(format: InternalFormat, width: u32, height: u32) -> TextureLevel {
+        TextureLevel {
+            format,
+            extent: TextureExtent::new(width, height, 1),
+        }
+    }
+    /// Specifies `levels` levels of a full mipmap chain of a `size`x`size` image of `format`, starting at `first`, for every face
+    fn specify_chain(
+        tex: &mut Texture,
+        format: InternalFormat,
+        size: u32,
+        first: u32,
+        levels: u32,
+    ) {
+        for l in 0..levels {
+            let size = (size >> l).max(1);
+            for face in 0..tex.faces() {
+                tex.specify_image(first + l, face, level(format, size, size));
+            }
+        }
+    }
+    /// Gives `tex` the image specification of immutable storage with `levels` levels of a `size`x`size` image
+    fn make_immutable(tex: &mut Texture, size: u32, levels: u32) {
+        specify_chain(tex, RGBA8, size, 0, levels);
+        tex.immutable_format = true;
+    }
+    fn filters(min_filter: TextureMinFilter, mag_filter: TextureMagFilter) -> SamplerParams {
+        SamplerParams {
+            mag_filter,
+            min_filter,
+            ..SamplerParams::default()
+        }
+    }
+    fn linear() -> SamplerParams {
+        filters(TextureMinFilter::Linear, TextureMagFilter::Linear)
+    }
+    fn mipmapped() -> SamplerParams {
+        filters(
+            TextureMinFilter::LinearMipmapLinear,
+            TextureMagFilter::Linear,
+        )
+    }
+
+    #[test]
+    fn mutable_level_range_is_not_clamped() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        assert_eq!(tex.level_range(), (0, 1000));
+        tex.base_level = 3;
+        tex.max_level = 1;
+        assert_eq!(tex.level_range(), (3, 1));
+        assert_eq!(tex.consistent_levels(), 0);
+    }
+    #[test]
+    fn immutable_level_range_is_clamped_to_storage() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        make_immutable(&mut tex, 8, 4);
+        assert_eq!(tex.level_range(), (0, 3));
+        tex.base_level = 10;
+        assert_eq!(tex.level_range(), (3, 3));
+        tex.base_level = 2;
+        tex.max_level = 1;
+        assert_eq!(tex.level_range(), (2, 2));
+        tex.base_level = 1;
+        tex.max_level = 2;
+        assert_eq!(tex.level_range(), (1, 2));
+    }
+    #[test]
+    fn immutable_texture_is_complete_with_default_max_level() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        // a partial mipmap chain, which mutable textures with the default max level can't sample with mipmaps
+        make_immutable(&mut tex, 8, 2);
+        assert_eq!(tex.consistent_levels(), 2);
+        assert!(tex.is_complete(&mipmapped()));
+        tex.base_level = 5;
+        assert_eq!(tex.consistent_levels(), 1);
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn full_mipmap_chain_is_complete() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        specify_chain(&mut tex, RGBA8, 8, 0, 4);
+        assert_eq!(tex.consistent_levels(), 4);
+        assert!(tex.is_complete(&mipmapped()));
+        assert!(tex.is_complete(&linear()));
+    }
+    #[test]
+    fn non_square_mipmap_chain_is_complete() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        for (l, (w, h)) in (0..).zip([(8, 2), (4, 1), (2, 1), (1, 1)]) {
+            tex.specify_image(l, 0, level(RGBA8, w, h));
+        }
+        assert_eq!(tex.consistent_levels(), 4);
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn missing_base_level_is_incomplete() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        assert!(!tex.is_complete(&linear()));
+        specify_chain(&mut tex, RGBA8, 4, 1, 3);
+        assert_eq!(tex.consistent_levels(), 0);
+        assert!(!tex.is_complete(&linear()));
+        // moving the base level to the first specified level makes it complete
+        tex.base_level = 1;
+        assert_eq!(tex.consistent_levels(), 3);
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn empty_base_level_is_incomplete() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        tex.specify_image(0, 0, level(RGBA8, 0, 4));
+        assert_eq!(tex.consistent_levels(), 0);
+        assert!(!tex.is_complete(&linear()));
+    }
+    #[test]
+    fn missing_mip_level_only_matters_when_mipmapping() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        tex.specify_image(0, 0, level(RGBA8, 8, 8));
+        tex.specify_image(2, 0, level(RGBA8, 2, 2));
+        tex.specify_image(3, 0, level(RGBA8, 1, 1));
+        assert_eq!(tex.consistent_levels(), 1);
+        assert!(!tex.is_complete(&mipmapped()));
+        assert!(tex.is_complete(&linear()));
+        // limiting the max level to the consistent levels makes it mipmap complete
+        tex.max_level = 0;
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn inconsistent_mip_levels_are_incomplete() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        specify_chain(&mut tex, RGBA8, 8, 0, 4);
+        tex.specify_image(1, 0, level(RGBA8, 4, 3));
+        assert_eq!(tex.consistent_levels(), 1);
+        assert!(!tex.is_complete(&mipmapped()));
+
+        let mut tex = texture(TextureTarget::Texture2D);
+        specify_chain(&mut tex, RGBA8, 8, 0, 4);
+        tex.specify_image(2, 0, level(InternalFormat::R8, 2, 2));
+        assert_eq!(tex.consistent_levels(), 2);
+        assert!(!tex.is_complete(&mipmapped()));
+        tex.max_level = 1;
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn levels_beyond_max_level_are_ignored() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        specify_chain(&mut tex, RGBA8, 8, 0, 2);
+        tex.specify_image(2, 0, level(InternalFormat::R8, 7, 7));
+        tex.max_level = 1;
+        assert_eq!(tex.consistent_levels(), 2);
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn complete_cube_map() {
+        let mut tex = texture(TextureTarget::TextureCubeMap);
+        specify_chain(&mut tex, RGBA8, 4, 0, 3);
+        assert_eq!(tex.consistent_levels(), 3);
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn cube_map_with_missing_face_is_incomplete() {
+        let mut tex = texture(TextureTarget::TextureCubeMap);
+        for face in 0..5 {
+            tex.specify_image(0, face, level(RGBA8, 4, 4));
+        }
+        assert_eq!(tex.consistent_levels(), 0);
+        assert!(!tex.is_complete(&linear()));
+        tex.specify_image(0, 5, level(RGBA8, 4, 4));
+        assert!(tex.is_complete(&linear()));
+    }
+    #[test]
+    fn cube_map_with_inconsistent_faces_is_incomplete() {
+        let mut tex = texture(TextureTarget::TextureCubeMap);
+        specify_chain(&mut tex, RGBA8, 4, 0, 1);
+        tex.specify_image(0, 3, level(InternalFormat::R8, 4, 4));
+        assert!(!tex.is_complete(&linear()));
+
+        let mut tex = texture(TextureTarget::TextureCubeMap);
+        specify_chain(&mut tex, RGBA8, 4, 0, 1);
+        tex.specify_image(0, 2, level(RGBA8, 2, 2));
+        assert!(!tex.is_complete(&linear()));
+
+        // a face missing a mip level only breaks mipmap completeness
+        let mut tex = texture(TextureTarget::TextureCubeMap);
+        specify_chain(&mut tex, RGBA8, 4, 0, 3);
+        tex.images[1][4] = None;
+        assert_eq!(tex.consistent_levels(), 1);
+        assert!(tex.is_complete(&linear()));
+        assert!(!tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn non_square_cube_map_is_incomplete() {
+        let mut tex = texture(TextureTarget::TextureCubeMap);
+        for face in 0..6 {
+            tex.specify_image(0, face, level(RGBA8, 4, 2));
+        }
+        assert_eq!(tex.consistent_levels(), 0);
+        assert!(!tex.is_complete(&linear()));
+    }
+    #[test]
+    fn integer_textures_require_nearest_filtering() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        specify_chain(&mut tex, InternalFormat::Rgba8ui, 4, 0, 3);
+        assert!(!tex.is_complete(&SamplerParams::default()));
+        assert!(!tex.is_complete(&linear()));
+        assert!(!tex.is_complete(&filters(
+            TextureMinFilter::Nearest,
+            TextureMagFilter::Linear
+        )));
+        assert!(!tex.is_complete(&filters(
+            TextureMinFilter::Linear,
+            TextureMagFilter::Nearest
+        )));
+        assert!(!tex.is_complete(&filters(
+            TextureMinFilter::NearestMipmapLinear,
+            TextureMagFilter::Nearest
+        )));
+        assert!(tex.is_complete(&filters(
+            TextureMinFilter::Nearest,
+            TextureMagFilter::Nearest
+        )));
+        assert!(tex.is_complete(&filters(
+            TextureMinFilter::NearestMipmapNearest,
+            TextureMagFilter::Nearest
+        )));
+    }
+    #[test]
+    fn stencil_sampling_requires_nearest_filtering() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        specify_chain(&mut tex, InternalFormat::Depth24Stencil8, 4, 0, 3);
+        let nearest = filters(TextureMinFilter::Nearest, TextureMagFilter::Nearest);
+        assert!(tex.is_complete(&linear()));
+        tex.stencil_sampling = true;
+        assert!(!tex.is_complete(&linear()));
+        assert!(!tex.is_complete(&filters(
+            TextureMinFilter::Nearest,
+            TextureMagFilter::Linear
+        )));
+        assert!(tex.is_complete(&nearest));
+        tex.stencil_sampling = false;
+        assert!(tex.is_complete(&mipmapped()));
+    }
+    #[test]
+    fn lod_bias_is_emulated_by_shaders() {
+        let mut params = SamplerParams::default();
+        assert_eq!(params.shader_emulation(), None);
+        params
+            .sampler_param(SamplerParameter::TextureLodBias, 1.5f32)
+            .unwrap();
+        assert_eq!(
+            params.shader_emulation(),
+            Some(SamplerEmulation {
+                color: [0; 4],
+                axes: 0,
+                lod_bias: 1.5f32.to_bits(),
+            })
+        );
+    }
+    #[test]
+    fn buffer_texture_without_buffer_is_incomplete() {
+        let tex = texture(TextureTarget::TextureBuffer);
+        assert!(!tex.is_complete(&linear()));
+    }
+    /// A write of `data` to the whole `size`x`size` image at `level` of a 2D texture
+    fn write(level: u32, size: usize, data: Vec<u8>) -> PendingWrite {
+        PendingWrite {
+            level,
+            faces: 0..1,
+            region: (
+                0..1,
+                MTLOrigin { x: 0, y: 0, z: 0 },
+                MTLSize {
+                    width: size,
+                    height: size,
+                    depth: 1,
+                },
+            ),
+            planes: vec![(data, MTLBlitOption::None)],
+            block: 1,
+        }
+    }
+    #[test]
+    fn level_written_before_base_level_survives_realization() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        tex.specify_image(1, 0, level(RGBA8, 2, 2));
+        let data: Vec<u8> = (0..16).collect();
+        tex.defer_write(write(1, 2, data.clone()));
+        // without a base level there is no storage to write level 1 to yet
+        assert!(tex.take_covered_writes().is_empty());
+        assert_eq!(tex.pending_writes.len(), 1);
+        // specifying the base level completes the chain, so realization covers level 1
+        tex.specify_image(0, 0, level(RGBA8, 4, 4));
+        let writes = tex.take_covered_writes();
+        assert_eq!(writes.len(), 1);
+        assert_eq!(writes[0].level, 1);
+        assert_eq!(writes[0].planes[0].0, data);
+        assert!(tex.pending_writes.is_empty());
+    }
+    #[test]
+    fn pending_writes_wait_for_consistent_level() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        tex.specify_image(0, 0, level(RGBA8, 4, 4));
+        // level 2 is not part of the chain while level 1 is missing
+        tex.specify_image(2, 0, level(RGBA8, 1, 1));
+        tex.defer_write(write(2, 1, vec![0; 4]));
+        assert!(tex.take_covered_writes().is_empty());
+        tex.specify_image(1, 0, level(RGBA8, 2, 2));
+        assert_eq!(tex.take_covered_writes().len(), 1);
+    }
+    #[test]
+    fn respecifying_image_drops_pending_writes() {
+        let mut tex = texture(TextureTarget::Texture2D);
+        tex.specify_image(1, 0, level(RGBA8, 2, 2));
+        tex.defer_write(write(1, 2, vec![0; 16]));
+        // re-specifying the same image keeps its contents
+        tex.specify_image(1, 0, level(RGBA8, 2, 2));
+        assert_eq!(tex.pending_writes.len(), 1);
+        tex.specify_image(1, 0, level(RGBA8, 3, 3));
+        assert!(tex.pending_writes.is_empty());
+    }
+}
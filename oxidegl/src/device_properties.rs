@@ -63,8 +63,13 @@ pub(crate) struct MetalProperties {
     sample_counts: SupportedSampleCounts,
     /// The maximum amount of supported vertex amplifications for multiview/layered rendering
     max_vertex_amp: u32,
+    /// The number of texture slots in a shader function's argument table
     max_texture_arguments: u32,
+    /// The number of sampler state slots in a shader function's argument table
+    max_sampler_arguments: u32,
     apple7_8_supports_bc: bool,
+    /// The largest alignment Metal requires of the offset of a texture buffer of any pixel format usable by buffer textures
+    texture_buffer_offset_alignment: u32,
 }
 
 impl MetalProperties {
@@ -111,9 +116,63 @@ impl MetalProperties {
             } else {
                 31
             },
+            // every GPU family has 16 sampler state slots
+            max_sampler_arguments: 16,
             apple7_8_supports_bc: device.supportsBCTextureCompression(),
+            texture_buffer_offset_alignment: get_texture_buffer_offset_alignment(device),
         }
     }
+    /// Returns the smallest sample count supported by this device that is greater than or equal to `requested`,
+    /// or None if `requested` exceeds the maximum supported sample count
+    pub(crate) fn sample_count_at_least(&self, requested: u32) -> Option<u32> {
+        if requested <= 1 {
+            return Some(1);
+        }
+        [
+            (2, SupportedSampleCounts::SAMPLE_X2),
+            (4, SupportedSampleCounts::SAMPLE_X4),
+            (8, SupportedSampleCounts::SAMPLE_X8),
+            (16, SupportedSampleCounts::SAMPLE_X16),
+        ]
+        .into_iter()
+        .find(|&(count, flag)| count >= requested && self.sample_counts.intersects(flag))
+        .map(|(count, _)| count)
+    }
+    /// The largest sample count supported by this device
+    pub(crate) fn max_sample_count(&self) -> u32 {
+        [
+            (16, SupportedSampleCounts::SAMPLE_X16),
+            (8, SupportedSampleCounts::SAMPLE_X8),
+            (4, SupportedSampleCounts::SAMPLE_X4),
+            (2, SupportedSampleCounts::SAMPLE_X2),
+        ]
+        .into_iter()
+        .find(|&(_, flag)| self.sample_counts.intersects(flag))
+        .map_or(1, |(count, _)| count)
+    }
+    /// The maximum number of textures a single shader function may bind
+    pub(crate) fn max_texture_arguments(&self) -> u32 {
+        self.max_texture_arguments
+    }
+    /// The maximum number of sampler states a single shader function may bind
+    pub(crate) fn max_sampler_arguments(&self) -> u32 {
+        self.max_sampler_arguments
+    }
+    /// `GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`: the alignment that offsets of buffer texture ranges must have
+    pub(crate) fn texture_buffer_offset_alignment(&self) -> u32 {
+        self.texture_buffer_offset_alignment
+    }
+    /// Whether textures of the block-compressed format `format` can be created and sampled on this device
+    pub(crate) fn supports_compressed_format(&self, format: MTLPixelFormat) -> bool {
+        self.get_texture_caps(format)
+            .is_some_and(|caps| caps.contains(TextureCaps::FILTER))
+    }
+    /// Whether Metal can generate mipmaps for textures of the color format `format`, which requires the format to be both
+    /// filterable and color-renderable
+    pub(crate) fn can_generate_mipmaps(&self, format: MTLPixelFormat) -> bool {
+        self.get_texture_caps(format)
+            .is_some_and(|caps| caps.contains(TextureCaps::FILTER | TextureCaps::COLOR))
+    }
     fn get_texture_caps(&self, format: MTLPixelFormat) -> Option<TextureCaps> {
         use MTLPixelFormat as MF;
 
@@ -329,6 +388,33 @@ impl MetalProperties {
                     None
                 }
             }
+            // Apple GPUs only
+            MF::ETC2_RGB8
+            | MF::ETC2_RGB8_sRGB
+            | MF::ETC2_RGB8A1
+            | MF::ETC2_RGB8A1_sRGB
+            | MF::EAC_RGBA8
+            | MF::EAC_RGBA8_sRGB
+            | MF::EAC_R11Unorm
+            | MF::EAC_R11Snorm
+            | MF::EAC_RG11Unorm
+            | MF::EAC_RG11Snorm => {
+                if device_families.intersects(
+                    Families::APPLE1
+                        | Families::APPLE2
+                        | Families::APPLE3
+                        | Families::APPLE4
+                        | Families::APPLE5
+                        | Families::APPLE6
+                        | Families::APPLE7
+                        | Families::APPLE8
+                        | Families::APPLE9,
+                ) {
+                    Some(TextureCaps::FILTER | sparse)
+                } else {
+                    None
+                }
+            }
             _ => panic!("invalid pixel format"),
         }
         // TODO 64 bit formats, 128 bit formats and
@@ -455,3 +541,48 @@ fn get_supported_sample_counts(device: &ProtoObjRef<dyn MTLDevice>) -> Supported
     }
     sample_counts
 }
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "texture buffer alignments are small powers of two"
+)]
+fn get_texture_buffer_offset_alignment(device: &ProtoObjRef<dyn MTLDevice>) -> u32 {
+    use MTLPixelFormat as F;
+    // Metal pixel formats of the buffer texture internal formats
+    [
+        F::R8Unorm,
+        F::R8Sint,
+        F::R8Uint,
+        F::R16Unorm,
+        F::R16Float,
+        F::R16Sint,
+        F::R16Uint,
+        F::R32Float,
+        F::R32Sint,
+        F::R32Uint,
+        F::RG8Unorm,
+        F::RG8Sint,
+        F::RG8Uint,
+        F::RG16Unorm,
+        F::RG16Float,
+        F::RG16Sint,
+        F::RG16Uint,
+        F::RG32Float,
+        F::RG32Sint,
+        F::RG32Uint,
+        F::RGBA8Unorm,
+        F::RGBA8Sint,
+        F::RGBA8Uint,
+        F::RGBA16Unorm,
+        F::RGBA16Float,
+        F::RGBA16Sint,
+        F::RGBA16Uint,
+        F::RGBA32Float,
+        F::RGBA32Sint,
+        F::RGBA32Uint,
+    ]
+    .into_iter()
+    .map(|format| device.minimumTextureBufferAlignmentForPixelFormat(format) as u32)
+    .max()
+    .expect("format list should not be empty")
+}
@@ -1,6 +1,10 @@
-use std::{ffi::c_void, ptr::NonNull, sync::Once};
+use std::{
+    ffi::c_void,
+    ptr::{self, NonNull},
+    sync::Once,
+};
 
-use log::{debug, info};
+use log::{debug, error, info};
 use objc2::rc::Retained;
 use objc2_app_kit::NSView;
 
@@ -10,7 +14,7 @@ use crate::{
         debug::{self, gl_trace},
         with_ctx_mut,
     },
-    dispatch::gl_types::GLenum,
+    dispatch::gl_types::{GLenum, GLsizei},
 };
 
 #[unsafe(no_mangle)]
@@ -100,7 +104,39 @@ unsafe extern "C" fn oxidegl_create_context(
     stencil_format: GLenum,
     stencil_type: GLenum,
 ) -> *mut c_void {
-    let mut ctx = Context::new();
+    // Safety: caller upholds the same contract
+    unsafe {
+        oxidegl_create_context_with_samples(
+            view,
+            format,
+            typ,
+            depth_format,
+            depth_type,
+            stencil_format,
+            stencil_type,
+            1,
+        )
+    }
+}
+
+#[unsafe(no_mangle)]
+/// Like `oxidegl_create_context`, but the default framebuffer of the created context has (at least) `samples` samples per
+/// pixel, or is single sampled if `samples` is 0 or 1. Returns null without creating a context if `samples` is negative
+unsafe extern "C" fn oxidegl_create_context_with_samples(
+    view: *mut NSView,
+    format: GLenum,
+    typ: GLenum,
+    depth_format: GLenum,
+    depth_type: GLenum,
+    stencil_format: GLenum,
+    stencil_type: GLenum,
+    samples: GLsizei,
+) -> *mut c_void {
+    let Ok(samples) = u32::try_from(samples) else {
+        error!("failed to create context: the requested sample count ({samples}) is negative");
+        return ptr::null_mut();
+    };
+    let mut ctx = Context::new(samples);
     // Safety: caller ensures ptr is a pointer to a valid, initialized NSView.
     let view = unsafe { Retained::retain(view).unwrap() };
 
@@ -1367,6 +1367,14 @@ pub const GL_TEXTURE_MAX_ANISOTROPY: GLenum = 34046;
 pub const GL_MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 34047;
 pub const GL_TRANSFORM_FEEDBACK_OVERFLOW: GLenum = 33516;
 pub const GL_TRANSFORM_FEEDBACK_STREAM_OVERFLOW: GLenum = 33517;
+pub const GL_COMPRESSED_RGB_S3TC_DXT1_EXT: GLenum = 33776;
+pub const GL_COMPRESSED_RGBA_S3TC_DXT1_EXT: GLenum = 33777;
+pub const GL_COMPRESSED_RGBA_S3TC_DXT3_EXT: GLenum = 33778;
+pub const GL_COMPRESSED_RGBA_S3TC_DXT5_EXT: GLenum = 33779;
+pub const GL_COMPRESSED_SRGB_S3TC_DXT1_EXT: GLenum = 35916;
+pub const GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT: GLenum = 35917;
+pub const GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT: GLenum = 35918;
+pub const GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT: GLenum = 35919;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ::strum_macros::FromRepr)]
 #[repr(u32)]
 pub enum UniformType {
@@ -3264,6 +3272,14 @@ pub enum SizedInternalFormat {
     CompressedSignedR11Eac = GL_COMPRESSED_SIGNED_R11_EAC,
     CompressedRg11Eac = GL_COMPRESSED_RG11_EAC,
     CompressedSignedRg11Eac = GL_COMPRESSED_SIGNED_RG11_EAC,
+    CompressedRgbS3tcDxt1Ext = GL_COMPRESSED_RGB_S3TC_DXT1_EXT,
+    CompressedRgbaS3tcDxt1Ext = GL_COMPRESSED_RGBA_S3TC_DXT1_EXT,
+    CompressedRgbaS3tcDxt3Ext = GL_COMPRESSED_RGBA_S3TC_DXT3_EXT,
+    CompressedRgbaS3tcDxt5Ext = GL_COMPRESSED_RGBA_S3TC_DXT5_EXT,
+    CompressedSrgbS3tcDxt1Ext = GL_COMPRESSED_SRGB_S3TC_DXT1_EXT,
+    CompressedSrgbAlphaS3tcDxt1Ext = GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+    CompressedSrgbAlphaS3tcDxt3Ext = GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+    CompressedSrgbAlphaS3tcDxt5Ext = GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
     StencilIndex8 = GL_STENCIL_INDEX8,
 }
 impl GlEnumGroup for SizedInternalFormat {
@@ -4162,6 +4178,7 @@ pub enum GetPName {
     TextureBinding3D = GL_TEXTURE_BINDING_3D,
     TextureBindingBuffer = GL_TEXTURE_BINDING_BUFFER,
     TextureBindingCubeMap = GL_TEXTURE_BINDING_CUBE_MAP,
+    TextureBindingCubeMapArray = GL_TEXTURE_BINDING_CUBE_MAP_ARRAY,
     TextureBindingRectangle = GL_TEXTURE_BINDING_RECTANGLE,
     ParameterBufferBinding = GL_PARAMETER_BUFFER_BINDING,
 }
@@ -4199,6 +4216,29 @@ pub enum GetTextureParameter {
     TextureGreenSize = GL_TEXTURE_GREEN_SIZE,
     TextureBlueSize = GL_TEXTURE_BLUE_SIZE,
     TextureAlphaSize = GL_TEXTURE_ALPHA_SIZE,
+    TextureWrapR = GL_TEXTURE_WRAP_R,
+    TextureMinLod = GL_TEXTURE_MIN_LOD,
+    TextureMaxLod = GL_TEXTURE_MAX_LOD,
+    TextureBaseLevel = GL_TEXTURE_BASE_LEVEL,
+    TextureMaxLevel = GL_TEXTURE_MAX_LEVEL,
+    TextureLodBias = GL_TEXTURE_LOD_BIAS,
+    TextureCompareMode = GL_TEXTURE_COMPARE_MODE,
+    TextureCompareFunc = GL_TEXTURE_COMPARE_FUNC,
+    TextureSwizzleR = GL_TEXTURE_SWIZZLE_R,
+    TextureSwizzleG = GL_TEXTURE_SWIZZLE_G,
+    TextureSwizzleB = GL_TEXTURE_SWIZZLE_B,
+    TextureSwizzleA = GL_TEXTURE_SWIZZLE_A,
+    TextureSwizzleRgba = GL_TEXTURE_SWIZZLE_RGBA,
+    DepthStencilTextureMode = GL_DEPTH_STENCIL_TEXTURE_MODE,
+    TextureMaxAnisotropy = GL_TEXTURE_MAX_ANISOTROPY,
+    ImageFormatCompatibilityType = GL_IMAGE_FORMAT_COMPATIBILITY_TYPE,
+    TextureImmutableFormat = GL_TEXTURE_IMMUTABLE_FORMAT,
+    TextureImmutableLevels = GL_TEXTURE_IMMUTABLE_LEVELS,
+    TextureViewMinLevel = GL_TEXTURE_VIEW_MIN_LEVEL,
+    TextureViewNumLevels = GL_TEXTURE_VIEW_NUM_LEVELS,
+    TextureViewMinLayer = GL_TEXTURE_VIEW_MIN_LAYER,
+    TextureViewNumLayers = GL_TEXTURE_VIEW_NUM_LAYERS,
+    TextureTarget = GL_TEXTURE_TARGET,
 }
 impl GlEnumGroup for GetTextureParameter {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -4598,6 +4638,7 @@ pub enum Buffer {
     Color = GL_COLOR,
     Depth = GL_DEPTH,
     Stencil = GL_STENCIL,
+    DepthStencil = GL_DEPTH_STENCIL,
 }
 impl GlEnumGroup for Buffer {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -4917,6 +4958,14 @@ pub enum InternalFormat {
     CompressedSignedR11Eac = GL_COMPRESSED_SIGNED_R11_EAC,
     CompressedRg11Eac = GL_COMPRESSED_RG11_EAC,
     CompressedSignedRg11Eac = GL_COMPRESSED_SIGNED_RG11_EAC,
+    CompressedRgbS3tcDxt1Ext = GL_COMPRESSED_RGB_S3TC_DXT1_EXT,
+    CompressedRgbaS3tcDxt1Ext = GL_COMPRESSED_RGBA_S3TC_DXT1_EXT,
+    CompressedRgbaS3tcDxt3Ext = GL_COMPRESSED_RGBA_S3TC_DXT3_EXT,
+    CompressedRgbaS3tcDxt5Ext = GL_COMPRESSED_RGBA_S3TC_DXT5_EXT,
+    CompressedSrgbS3tcDxt1Ext = GL_COMPRESSED_SRGB_S3TC_DXT1_EXT,
+    CompressedSrgbAlphaS3tcDxt1Ext = GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+    CompressedSrgbAlphaS3tcDxt3Ext = GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+    CompressedSrgbAlphaS3tcDxt5Ext = GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
     StencilIndex = GL_STENCIL_INDEX,
     StencilIndex8 = GL_STENCIL_INDEX8,
 }
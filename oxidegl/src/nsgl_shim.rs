@@ -62,7 +62,7 @@ impl OXGLOxideGlCtxShim {
     unsafe extern "C-unwind" fn init_with_format_share_ctx(
         this: *mut NSOpenGLContext,
         _sel: Sel,
-        _format: *const AnyClass,
+        format: Option<&NSOpenGLPixelFormat>,
         share: Option<NonNull<NSOpenGLContext>>,
     ) -> objc2::rc::Retained<objc2_foundation::NSObject> {
         trace!("initialized OBJC context shim");
@@ -76,7 +76,7 @@ impl OXGLOxideGlCtxShim {
                 this,
             )
         });
-        let ctx_ptr = box_ctx(Context::new());
+        let ctx_ptr = box_ctx(Context::new(format.map_or(1, requested_samples)));
         Self::set_assoc_obj(
             Retained::as_ptr(&this).cast_mut().cast(),
             OXGLOxideGLCtxAssociatedObject::new_with_ctx(ctx_ptr.cast())
@@ -87,7 +87,7 @@ impl OXGLOxideGlCtxShim {
     unsafe extern "C-unwind" fn init_with_cgl_pf_obj(
         this: *mut NSOpenGLContext,
         _sel: Sel,
-        _obj: *const c_void,
+        obj: *const c_void,
     ) -> objc2::rc::Retained<objc2_foundation::NSObject> {
         trace!("initialized OBJC context shim");
 
@@ -98,7 +98,11 @@ impl OXGLOxideGlCtxShim {
                 this,
             )
         });
-        let ctx_ptr = box_ctx(Context::new());
+        let format = NSOpenGLPixelFormat::alloc();
+        // Safety: caller ensures obj is a valid CGLPixelFormatObj
+        let format: Option<Retained<NSOpenGLPixelFormat>> =
+            unsafe { msg_send![format, initWithCGLPixelFormatObj: obj] };
+        let ctx_ptr = box_ctx(Context::new(format.as_deref().map_or(1, requested_samples)));
         Self::set_assoc_obj(
             Retained::as_ptr(&this).cast_mut().cast(),
             OXGLOxideGLCtxAssociatedObject::new_with_ctx(ctx_ptr.cast())
@@ -168,6 +172,39 @@ extern_class!(
 // Safety: NSOpenGLContext conforms to its superclass protocol, NSObjectProtocol
 unsafe impl NSObjectProtocol for NSOpenGLContext {}
 
+// the shim leaves NSOpenGLPixelFormat alone, since it only describes the requested pixel format
+extern_class!(
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    #[unsafe(super(NSObject))]
+    pub(crate) struct NSOpenGLPixelFormat;
+);
+// Safety: NSOpenGLPixelFormat conforms to its superclass protocol, NSObjectProtocol
+unsafe impl NSObjectProtocol for NSOpenGLPixelFormat {}
+
+/// `NSOpenGLPFASampleBuffers`
+const NSOPENGL_PFA_SAMPLE_BUFFERS: u32 = 55;
+/// `NSOpenGLPFASamples`
+const NSOPENGL_PFA_SAMPLES: u32 = 56;
+
+/// Returns the number of samples per pixel that the pixel format `format` requests for the default
+/// framebuffer, which is 1 unless it requests a multisample buffer
+fn requested_samples(format: &NSOpenGLPixelFormat) -> u32 {
+    let get = |attribute: u32| {
+        let mut value = 0i32;
+        // Safety: NSOpenGLPixelFormat has a getValues:forAttribute:forVirtualScreen: method, and the pointer is valid for
+        // writes of a single GLint
+        unsafe {
+            let () = msg_send![format, getValues: ptr::from_mut(&mut value), forAttribute: attribute, forVirtualScreen: 0i32];
+        }
+        value
+    };
+    if get(NSOPENGL_PFA_SAMPLE_BUFFERS) > 0 {
+        get(NSOPENGL_PFA_SAMPLES).try_into().unwrap_or(1)
+    } else {
+        1
+    }
+}
+
 fn get_sel(sel: Sel) -> *const c_void {
     // Safety: Sel is a repr(transparent) wrapper on NonNull<c_void> which has a compatible layout for transmutes with
     unsafe { mem::transmute(sel) }
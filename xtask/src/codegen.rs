@@ -481,7 +481,13 @@ pub fn get_vals<'a>(
                     .map(|g| enum_merge_map.get(g).map(|v| &**v).unwrap_or(g))
                 {
                     let g2: &str = group;
-                    if new_map.contains_key(g2) {
+                    // arrays of enums (e.g. the attachments of glInvalidateFramebuffer) are passed through as pointers to
+                    // raw GLenums, since their elements can't be converted up front
+                    // texture units are selected with GL_TEXTURE0 + i for any unit, not just the ones there are tokens for
+                    if new_map.contains_key(g2)
+                        && !param.parameter_type.is_pointer()
+                        && g2 != "TextureUnit"
+                    {
                         param.parameter_type = GLTypes::EnumWrapped(g2.to_string());
                     }
                 }